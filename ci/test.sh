@@ -54,6 +54,9 @@ check() {
     cd ../lexical-write-integer
     cargo check --tests
 
+    cd ../lexical-capi
+    cargo check --tests
+
     cd ..
 }
 
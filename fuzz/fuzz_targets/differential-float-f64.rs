@@ -0,0 +1,19 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_float::FromLexical;
+
+// The grammars differ slightly, so only compare values both accept.
+fuzz_target!(|data: &[u8]| {
+    let string = match core::str::from_utf8(data) {
+        Ok(string) => string,
+        Err(_) => return,
+    };
+    if let (Ok(expected), Ok(actual)) = (string.parse::<f64>(), f64::from_lexical(data)) {
+        if expected.is_nan() {
+            assert!(actual.is_nan());
+        } else {
+            assert_eq!(expected.to_bits(), actual.to_bits());
+        }
+    }
+});
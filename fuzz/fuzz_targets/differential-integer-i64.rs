@@ -0,0 +1,15 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_integer::FromLexical;
+
+// The grammars differ slightly, so only compare values both accept.
+fuzz_target!(|data: &[u8]| {
+    let string = match core::str::from_utf8(data) {
+        Ok(string) => string,
+        Err(_) => return,
+    };
+    if let (Ok(expected), Ok(actual)) = (string.parse::<i64>(), i64::from_lexical(data)) {
+        assert_eq!(expected, actual);
+    }
+});
@@ -0,0 +1,41 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+
+/// Special strings the options can use, since they must be static.
+const SPECIAL: [Option<&[u8]>; 5] =
+    [None, Some(b"NaN"), Some(b"inf"), Some(b"Infinity"), Some(b"")];
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    lossy: bool,
+    exponent: u8,
+    decimal_point: u8,
+    nan_string: u8,
+    inf_string: u8,
+    infinity_string: u8,
+    bytes: Vec<u8>,
+}
+
+fn special(index: u8) -> Option<&'static [u8]> {
+    SPECIAL[index as usize % SPECIAL.len()]
+}
+
+fuzz_target!(|input: Input| {
+    let options = Options::builder()
+        .lossy(input.lossy)
+        .exponent(input.exponent)
+        .decimal_point(input.decimal_point)
+        .nan_string(special(input.nan_string))
+        .inf_string(special(input.inf_string))
+        .infinity_string(special(input.infinity_string))
+        .build();
+    // Invalid options must be rejected by the builder, so skip them.
+    if let Ok(options) = options {
+        let _ = f32::from_lexical_with_options::<{ STANDARD }>(&input.bytes, &options);
+        let _ = f64::from_lexical_with_options::<{ STANDARD }>(&input.bytes, &options);
+    }
+});
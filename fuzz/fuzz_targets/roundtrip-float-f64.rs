@@ -0,0 +1,18 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_float::FromLexical;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_write_float::ToLexical;
+
+fuzz_target!(|value: f64| {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let bytes = value.to_lexical(&mut buffer);
+    let roundtrip = f64::from_lexical(bytes).unwrap();
+    if value.is_nan() {
+        assert!(roundtrip.is_nan());
+    } else {
+        // Negative zero is written without a sign, so compare values.
+        assert_eq!(value, roundtrip);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_integer::FromLexical;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_write_integer::ToLexical;
+
+fuzz_target!(|value: i64| {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let bytes = value.to_lexical(&mut buffer);
+    assert_eq!(value, i64::from_lexical(bytes).unwrap());
+});
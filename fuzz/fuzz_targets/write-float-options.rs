@@ -0,0 +1,72 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use core::num;
+use lexical_util::format::{is_valid_options_punctuation, STANDARD};
+use lexical_util::options::WriteOptions;
+use lexical_write_float::{Options, RoundMode, ToLexicalWithOptions};
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+
+/// Special strings the options can use, since they must be static.
+const SPECIAL: [Option<&[u8]>; 4] = [None, Some(b"NaN"), Some(b"inf"), Some(b"")];
+
+/// Limit the number of significant digits, to bound the buffer size.
+const MAX_DIGITS: usize = 1024;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    value: f64,
+    max_significant_digits: usize,
+    min_significant_digits: usize,
+    positive_exponent_break: i32,
+    negative_exponent_break: i32,
+    truncate: bool,
+    trim_floats: bool,
+    exponent: u8,
+    decimal_point: u8,
+    nan_string: u8,
+    inf_string: u8,
+    min_exponent_digits: u8,
+}
+
+fn special(index: u8) -> Option<&'static [u8]> {
+    SPECIAL[index as usize % SPECIAL.len()]
+}
+
+fuzz_target!(|input: Input| {
+    let round_mode = match input.truncate {
+        true => RoundMode::Truncate,
+        false => RoundMode::Round,
+    };
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(input.max_significant_digits % MAX_DIGITS))
+        .min_significant_digits(num::NonZeroUsize::new(input.min_significant_digits % MAX_DIGITS))
+        .positive_exponent_break(num::NonZeroI32::new(input.positive_exponent_break))
+        .negative_exponent_break(num::NonZeroI32::new(input.negative_exponent_break))
+        .round_mode(round_mode)
+        .trim_floats(input.trim_floats)
+        .exponent(input.exponent)
+        .decimal_point(input.decimal_point)
+        .nan_string(special(input.nan_string))
+        .inf_string(special(input.inf_string))
+        .min_exponent_digits(num::NonZeroUsize::new(input.min_exponent_digits as usize % 24))
+        .build();
+    // Invalid options must be rejected by the builder, so skip them.
+    let options = match options {
+        Ok(options) => options,
+        Err(_) => return,
+    };
+    // Punctuation invalid for the format, or disabled special strings,
+    // are documented to panic when writing.
+    if !is_valid_options_punctuation(STANDARD, options.exponent(), options.decimal_point())
+        || (input.value.is_nan() && options.nan_string().is_none())
+        || (input.value.is_infinite() && options.inf_string().is_none())
+    {
+        return;
+    }
+    // The documented buffer size must always be sufficient.
+    let size = options.buffer_size::<f64, { STANDARD }>();
+    let mut buffer = vec![b'\x00'; size];
+    let bytes = input.value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert!(bytes.len() <= size);
+});
@@ -0,0 +1,47 @@
+use core::time::Duration;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fastrand::Rng;
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+
+/// Generate random decimals with 20 to 38 significant digits.
+fn new_decimals(rng: &Rng, count: usize) -> Vec<String> {
+    (0..count)
+        .map(|_| {
+            let digits = rng.usize(20..=38);
+            let mantissa: String = (0..digits).map(|_| (b'0' + rng.u8(0..10)) as char).collect();
+            format!("{}e{}", mantissa, rng.i32(-340..280))
+        })
+        .collect()
+}
+
+// GENERATORS
+
+macro_rules! generator {
+    ($group:ident, $name:expr, $data:ident, $cb:expr) => {{
+        $group.bench_function($name, |bench| {
+            bench.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box($cb(x.as_bytes()));
+                })
+            })
+        });
+    }};
+}
+
+fn many_digits(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("many_digits");
+    group.measurement_time(Duration::from_secs(5));
+
+    let rng = Rng::with_seed(fastrand::u64(..));
+    let data = new_decimals(&rng, 1000);
+    let lossy = Options::builder().lossy(true).build().unwrap();
+
+    generator!(group, "slow_path", data, |x| f64::from_lexical(x).unwrap());
+    generator!(group, "float160", data, |x| {
+        f64::from_lexical_with_options::<STANDARD>(x, &lossy).unwrap()
+    });
+}
+
+criterion_group!(float160_benches, many_digits);
+criterion_main!(float160_benches);
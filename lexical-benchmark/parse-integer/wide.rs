@@ -0,0 +1,42 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fastrand::Rng;
+use lexical_parse_integer::FromLexical;
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Generate 128-bit values that don't fit in a 64-bit integer.
+//
+// These are dominated by the cost of 128-bit multiplication, which is
+// a slow library call on targets like wasm32 or thumb.
+fn wide_data<T, F>(count: usize, seed: u64, gen: F) -> Vec<String>
+where
+    T: ToString,
+    F: Fn(&mut Rng) -> T,
+{
+    let mut rng = Rng::with_seed(seed);
+    (0..count).map(|_| gen(&mut rng).to_string()).collect()
+}
+
+// BENCHES
+
+fn wide(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("random:wide");
+    group.measurement_time(Duration::from_secs(5));
+    let seed = fastrand::u64(..);
+
+    let u128_data = wide_data(COUNT, seed, |rng| rng.u128(u64::MAX as u128 + 1..));
+    let i128_data = wide_data(COUNT, seed, |rng| rng.i128(i64::MAX as i128 + 1..));
+    let neg_i128_data = wide_data(COUNT, seed, |rng| rng.i128(..i64::MIN as i128));
+
+    parse_integer_generator!(group, "u128", u128_data.iter(), u128);
+    parse_integer_generator!(group, "i128", i128_data.iter(), i128);
+    parse_integer_generator!(group, "neg_i128", neg_i128_data.iter(), i128);
+}
+
+criterion_group!(wide_benches, wide);
+criterion_main!(wide_benches);
@@ -0,0 +1,13 @@
+include!("src/abi.rs");
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/abi.rs");
+
+    // Set the soname, so the dynamic linker only loads a compatible
+    // library, and distributions can install multiple ABI versions.
+    let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if os == "linux" || os == "android" || os.ends_with("bsd") {
+        let soname = format!("liblexical.so.{}", LEXICAL_ABI_VERSION);
+        println!("cargo:rustc-cdylib-link-arg=-Wl,-soname,{}", soname);
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * Stable C ABI for lexical, a library to parse and write numbers.
+ *
+ * Every function, other than `lexical_abi_version`, is exported with the
+ * ABI version as a suffix, such as `lexical_parse_i32_v1`, and the macros
+ * below map the unversioned names to the exported functions. Programs
+ * compiled against this header only link against a compatible library.
+ *
+ * The parsers return `LEXICAL_SUCCESS`, or `0`, on success, and an error
+ * code otherwise. The numbers are in the standard format, and are not
+ * null-terminated: `len` is the number of bytes, and `bytes` may be null
+ * if `len` is `0`.
+ */
+
+#ifndef LEXICAL_H
+#define LEXICAL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Version of the ABI, which is the suffix for every function. */
+#define LEXICAL_ABI_VERSION 1
+
+/* Number of bytes needed to write any number. */
+#define LEXICAL_BUFFER_SIZE 64
+
+/* Error codes. */
+#define LEXICAL_SUCCESS 0
+#define LEXICAL_OVERFLOW 1
+#define LEXICAL_UNDERFLOW 2
+#define LEXICAL_INVALID_DIGIT 3
+#define LEXICAL_EMPTY 4
+#define LEXICAL_EMPTY_MANTISSA 5
+#define LEXICAL_EMPTY_EXPONENT 6
+#define LEXICAL_EMPTY_INTEGER 7
+#define LEXICAL_EMPTY_FRACTION 8
+#define LEXICAL_INVALID_POSITIVE_MANTISSA_SIGN 9
+#define LEXICAL_MISSING_MANTISSA_SIGN 10
+#define LEXICAL_INVALID_EXPONENT 11
+#define LEXICAL_INVALID_POSITIVE_EXPONENT_SIGN 12
+#define LEXICAL_MISSING_EXPONENT_SIGN 13
+#define LEXICAL_EXPONENT_WITHOUT_FRACTION 14
+#define LEXICAL_INVALID_LEADING_ZEROS 15
+#define LEXICAL_MISSING_EXPONENT 16
+#define LEXICAL_MISSING_SIGN 17
+#define LEXICAL_INVALID_POSITIVE_SIGN 18
+#define LEXICAL_INVALID_NEGATIVE_SIGN 19
+#define LEXICAL_NUMBER_TOO_LONG 20
+#define LEXICAL_OUT_OF_RANGE 21
+#define LEXICAL_EXPONENT_TOO_LONG 22
+#define LEXICAL_EXPONENT_OVERFLOW 23
+#define LEXICAL_CONVERSION_FAILED 24
+#define LEXICAL_BUFFER_TOO_SMALL 25
+
+/* Get the ABI version of the library, which is never versioned. */
+uint32_t lexical_abi_version(void);
+
+/*
+ * Parse a number from the entire buffer.
+ *
+ * Stores the number in `value` and returns `LEXICAL_SUCCESS` on success.
+ * Otherwise, returns the error code, and stores the index of the error
+ * in `index`, if it's not null.
+ */
+uint32_t lexical_parse_i8_v1(const uint8_t* bytes, size_t len, int8_t* value, size_t* index);
+uint32_t lexical_parse_i16_v1(const uint8_t* bytes, size_t len, int16_t* value, size_t* index);
+uint32_t lexical_parse_i32_v1(const uint8_t* bytes, size_t len, int32_t* value, size_t* index);
+uint32_t lexical_parse_i64_v1(const uint8_t* bytes, size_t len, int64_t* value, size_t* index);
+uint32_t lexical_parse_u8_v1(const uint8_t* bytes, size_t len, uint8_t* value, size_t* index);
+uint32_t lexical_parse_u16_v1(const uint8_t* bytes, size_t len, uint16_t* value, size_t* index);
+uint32_t lexical_parse_u32_v1(const uint8_t* bytes, size_t len, uint32_t* value, size_t* index);
+uint32_t lexical_parse_u64_v1(const uint8_t* bytes, size_t len, uint64_t* value, size_t* index);
+uint32_t lexical_parse_f32_v1(const uint8_t* bytes, size_t len, float* value, size_t* index);
+uint32_t lexical_parse_f64_v1(const uint8_t* bytes, size_t len, double* value, size_t* index);
+
+/*
+ * Parse a number from the start of the buffer.
+ *
+ * Stores the number in `value`, the number of bytes parsed in `count`,
+ * and returns `LEXICAL_SUCCESS` on success. Otherwise, returns the error
+ * code, and stores the index of the error in `count`.
+ */
+uint32_t lexical_parse_partial_i8_v1(const uint8_t* bytes, size_t len, int8_t* value, size_t* count);
+uint32_t lexical_parse_partial_i16_v1(const uint8_t* bytes, size_t len, int16_t* value, size_t* count);
+uint32_t lexical_parse_partial_i32_v1(const uint8_t* bytes, size_t len, int32_t* value, size_t* count);
+uint32_t lexical_parse_partial_i64_v1(const uint8_t* bytes, size_t len, int64_t* value, size_t* count);
+uint32_t lexical_parse_partial_u8_v1(const uint8_t* bytes, size_t len, uint8_t* value, size_t* count);
+uint32_t lexical_parse_partial_u16_v1(const uint8_t* bytes, size_t len, uint16_t* value, size_t* count);
+uint32_t lexical_parse_partial_u32_v1(const uint8_t* bytes, size_t len, uint32_t* value, size_t* count);
+uint32_t lexical_parse_partial_u64_v1(const uint8_t* bytes, size_t len, uint64_t* value, size_t* count);
+uint32_t lexical_parse_partial_f32_v1(const uint8_t* bytes, size_t len, float* value, size_t* count);
+uint32_t lexical_parse_partial_f64_v1(const uint8_t* bytes, size_t len, double* value, size_t* count);
+
+/*
+ * Write a number to the buffer, without a null terminator.
+ *
+ * Returns the number of bytes written, or `0` if the buffer is too small.
+ * A buffer of `LEXICAL_BUFFER_SIZE` bytes is always large enough.
+ */
+size_t lexical_write_i8_v1(int8_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_i16_v1(int16_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_i32_v1(int32_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_i64_v1(int64_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_u8_v1(uint8_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_u16_v1(uint16_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_u32_v1(uint32_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_u64_v1(uint64_t value, uint8_t* buffer, size_t len);
+size_t lexical_write_f32_v1(float value, uint8_t* buffer, size_t len);
+size_t lexical_write_f64_v1(double value, uint8_t* buffer, size_t len);
+
+/* Unversioned names. */
+#define lexical_parse_i8 lexical_parse_i8_v1
+#define lexical_parse_i16 lexical_parse_i16_v1
+#define lexical_parse_i32 lexical_parse_i32_v1
+#define lexical_parse_i64 lexical_parse_i64_v1
+#define lexical_parse_u8 lexical_parse_u8_v1
+#define lexical_parse_u16 lexical_parse_u16_v1
+#define lexical_parse_u32 lexical_parse_u32_v1
+#define lexical_parse_u64 lexical_parse_u64_v1
+#define lexical_parse_f32 lexical_parse_f32_v1
+#define lexical_parse_f64 lexical_parse_f64_v1
+#define lexical_parse_partial_i8 lexical_parse_partial_i8_v1
+#define lexical_parse_partial_i16 lexical_parse_partial_i16_v1
+#define lexical_parse_partial_i32 lexical_parse_partial_i32_v1
+#define lexical_parse_partial_i64 lexical_parse_partial_i64_v1
+#define lexical_parse_partial_u8 lexical_parse_partial_u8_v1
+#define lexical_parse_partial_u16 lexical_parse_partial_u16_v1
+#define lexical_parse_partial_u32 lexical_parse_partial_u32_v1
+#define lexical_parse_partial_u64 lexical_parse_partial_u64_v1
+#define lexical_parse_partial_f32 lexical_parse_partial_f32_v1
+#define lexical_parse_partial_f64 lexical_parse_partial_f64_v1
+#define lexical_write_i8 lexical_write_i8_v1
+#define lexical_write_i16 lexical_write_i16_v1
+#define lexical_write_i32 lexical_write_i32_v1
+#define lexical_write_i64 lexical_write_i64_v1
+#define lexical_write_u8 lexical_write_u8_v1
+#define lexical_write_u16 lexical_write_u16_v1
+#define lexical_write_u32 lexical_write_u32_v1
+#define lexical_write_u64 lexical_write_u64_v1
+#define lexical_write_f32 lexical_write_f32_v1
+#define lexical_write_f64 lexical_write_f64_v1
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LEXICAL_H */
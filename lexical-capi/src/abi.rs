@@ -0,0 +1,7 @@
+/// Version of the C ABI.
+///
+/// This is incremented for any change that breaks the ABI, such as
+/// changing the signature of an exported function, and is appended to
+/// the name of every exported function other than `lexical_abi_version`.
+/// It's shared with the build script, which uses it for the soname.
+pub const LEXICAL_ABI_VERSION: u32 = 1;
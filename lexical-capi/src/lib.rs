@@ -0,0 +1,220 @@
+//! Stable C ABI for lexical.
+//!
+//! This builds `liblexical_capi`, as a shared and a static library, which
+//! only exports `extern "C"` functions to parse and write numbers, with the
+//! declarations in `include/lexical.h`. It's meant for distributions that
+//! ship a single `liblexical.so`, and C programs that link to it: the shared
+//! library should be installed as `liblexical.so.1`, after its soname, with
+//! a `liblexical.so` symlink.
+//!
+//! # Versioning
+//!
+//! The ABI has a version, [`LEXICAL_ABI_VERSION`], which is separate
+//! from the crate version and is only incremented when the ABI breaks.
+//! Every exported function, other than `lexical_abi_version`, has the
+//! ABI version as a suffix, such as `lexical_parse_i32_v1`, and the header
+//! defines macros without the suffix, such as `lexical_parse_i32`. C
+//! programs compiled against one version of the header therefore fail to
+//! link against an incompatible library, rather than calling functions
+//! with the wrong signatures, and a library can export multiple versions
+//! of the ABI at once. On ELF targets, the soname also has the ABI version,
+//! such as `liblexical.so.1`.
+//!
+//! # Errors
+//!
+//! The parsers return an error code, which is `0` on success, and the
+//! code from [`ErrorCode`] otherwise. The numbers are always in the
+//! standard format, with the default options, so only the parse errors
+//! from [`ErrorCode`] can occur.
+//!
+//! # Safety
+//!
+//! Every pointer must be valid for the number of elements given, and
+//! properly aligned, other than byte pointers, which may be null if the
+//! length is `0`. Output pointers must not be null unless the function
+//! documents otherwise.
+
+mod abi;
+
+use core::{ptr, slice};
+pub use lexical_core::ErrorCode;
+use lexical_core::{FormattedSize, BUFFER_SIZE};
+
+pub use self::abi::LEXICAL_ABI_VERSION;
+
+/// Number of bytes needed to write any number with the writers.
+pub const LEXICAL_BUFFER_SIZE: usize = f64::FORMATTED_SIZE_DECIMAL;
+
+/// Get the version of the C ABI.
+///
+/// This is never versioned, so it can be used to check the version
+/// of the library at runtime, before calling any other functions.
+#[no_mangle]
+pub extern "C" fn lexical_abi_version() -> u32 {
+    LEXICAL_ABI_VERSION
+}
+
+/// Get the bytes from a pointer and length, allowing null for empty input.
+///
+/// # Safety
+///
+/// Safe if `bytes` is valid for `len` bytes, or `len` is `0`.
+// The unsafe blocks in unsafe functions document each unsafe operation,
+// but compilers before 1.65 report them as unused.
+#[inline(always)]
+#[allow(unused_unsafe)]
+unsafe fn as_slice<'a>(bytes: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        // SAFETY: safe if the caller provides a valid pointer and length.
+        unsafe { slice::from_raw_parts(bytes, len) }
+    }
+}
+
+/// Store a value in an output pointer, if it's not null.
+///
+/// # Safety
+///
+/// Safe if `ptr` is null or valid for writes.
+#[inline(always)]
+#[allow(unused_unsafe)]
+unsafe fn store<T>(ptr: *mut T, value: T) {
+    if !ptr.is_null() {
+        // SAFETY: safe if the caller provides a valid pointer.
+        unsafe { ptr.write(value) };
+    }
+}
+
+macro_rules! parse_abi {
+    ($($t:ty, $complete:ident, $partial:ident ;)*) => ($(
+        /// Parse a number from the entire buffer.
+        ///
+        /// Stores the number in `value` and returns `0` on success, and
+        /// returns the error code otherwise. The index of the error is
+        /// stored in `index`, if it's not null, or `0` if the error has
+        /// no index.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `bytes` is valid for `len` bytes, `value` is valid for
+        /// writes, and `index` is null or valid for writes.
+        #[no_mangle]
+        #[allow(unused_unsafe)]
+        pub unsafe extern "C" fn $complete(
+            bytes: *const u8,
+            len: usize,
+            value: *mut $t,
+            index: *mut usize,
+        ) -> u32 {
+            // SAFETY: safe if the caller provides a valid pointer and length.
+            let bytes = unsafe { as_slice(bytes, len) };
+            match lexical_core::parse::<$t>(bytes) {
+                Ok(result) => {
+                    // SAFETY: safe if the caller provides a valid pointer.
+                    unsafe { value.write(result) };
+                    ErrorCode::Success.to_code()
+                },
+                Err(error) => {
+                    let error_index = error.index().copied().unwrap_or(0);
+                    // SAFETY: safe if the caller provides a valid or null pointer.
+                    unsafe { store(index, error_index) };
+                    error.code()
+                },
+            }
+        }
+
+        /// Parse a number from the start of the buffer.
+        ///
+        /// Stores the number in `value`, the number of bytes parsed in
+        /// `count`, and returns `0` on success. Otherwise, returns the
+        /// error code, and stores the index of the error in `count`, or
+        /// `0` if the error has no index.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `bytes` is valid for `len` bytes, and `value` and
+        /// `count` are valid for writes.
+        #[no_mangle]
+        #[allow(unused_unsafe)]
+        pub unsafe extern "C" fn $partial(
+            bytes: *const u8,
+            len: usize,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> u32 {
+            // SAFETY: safe if the caller provides a valid pointer and length.
+            let bytes = unsafe { as_slice(bytes, len) };
+            match lexical_core::parse_partial::<$t>(bytes) {
+                Ok((result, processed)) => {
+                    // SAFETY: safe if the caller provides valid pointers.
+                    unsafe {
+                        value.write(result);
+                        count.write(processed);
+                    }
+                    ErrorCode::Success.to_code()
+                },
+                Err(error) => {
+                    let error_index = error.index().copied().unwrap_or(0);
+                    // SAFETY: safe if the caller provides a valid pointer.
+                    unsafe { count.write(error_index) };
+                    error.code()
+                },
+            }
+        }
+    )*);
+}
+
+parse_abi! {
+    i8, lexical_parse_i8_v1, lexical_parse_partial_i8_v1 ;
+    i16, lexical_parse_i16_v1, lexical_parse_partial_i16_v1 ;
+    i32, lexical_parse_i32_v1, lexical_parse_partial_i32_v1 ;
+    i64, lexical_parse_i64_v1, lexical_parse_partial_i64_v1 ;
+    u8, lexical_parse_u8_v1, lexical_parse_partial_u8_v1 ;
+    u16, lexical_parse_u16_v1, lexical_parse_partial_u16_v1 ;
+    u32, lexical_parse_u32_v1, lexical_parse_partial_u32_v1 ;
+    u64, lexical_parse_u64_v1, lexical_parse_partial_u64_v1 ;
+    f32, lexical_parse_f32_v1, lexical_parse_partial_f32_v1 ;
+    f64, lexical_parse_f64_v1, lexical_parse_partial_f64_v1 ;
+}
+
+macro_rules! write_abi {
+    ($($t:ty, $write:ident ;)*) => ($(
+        /// Write a number to the buffer.
+        ///
+        /// Returns the number of bytes written, or `0` if the buffer is
+        /// too small, in which case nothing is written. A buffer of
+        /// `LEXICAL_BUFFER_SIZE` bytes is always large enough. The number
+        /// isn't terminated with a null byte.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `buffer` is valid for writes of `len` bytes.
+        #[no_mangle]
+        #[allow(unused_unsafe)]
+        pub unsafe extern "C" fn $write(value: $t, buffer: *mut u8, len: usize) -> usize {
+            let mut digits = [0u8; BUFFER_SIZE];
+            let digits = lexical_core::write(value, &mut digits);
+            if digits.len() > len {
+                return 0;
+            }
+            // SAFETY: safe if the caller provides a valid pointer and length,
+            // since `digits.len() <= len`.
+            unsafe { ptr::copy_nonoverlapping(digits.as_ptr(), buffer, digits.len()) };
+            digits.len()
+        }
+    )*);
+}
+
+write_abi! {
+    i8, lexical_write_i8_v1 ;
+    i16, lexical_write_i16_v1 ;
+    i32, lexical_write_i32_v1 ;
+    i64, lexical_write_i64_v1 ;
+    u8, lexical_write_u8_v1 ;
+    u16, lexical_write_u16_v1 ;
+    u32, lexical_write_u32_v1 ;
+    u64, lexical_write_u64_v1 ;
+    f32, lexical_write_f32_v1 ;
+    f64, lexical_write_f64_v1 ;
+}
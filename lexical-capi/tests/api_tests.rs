@@ -0,0 +1,135 @@
+use core::ptr;
+use lexical_capi::*;
+
+const HEADER: &str = include_str!("../include/lexical.h");
+
+#[test]
+fn abi_version_test() {
+    assert_eq!(lexical_abi_version(), LEXICAL_ABI_VERSION);
+}
+
+#[test]
+fn parse_test() {
+    let mut value = 0i32;
+    let mut index = 0usize;
+    let bytes = b"-123";
+    let code = unsafe { lexical_parse_i32_v1(bytes.as_ptr(), bytes.len(), &mut value, &mut index) };
+    assert_eq!(code, 0);
+    assert_eq!(value, -123);
+
+    let bytes = b"12a";
+    let code = unsafe { lexical_parse_i32_v1(bytes.as_ptr(), bytes.len(), &mut value, &mut index) };
+    assert_eq!(code, ErrorCode::InvalidDigit.to_code());
+    assert_eq!(index, 2);
+
+    let bytes = b"256";
+    let mut value = 0u8;
+    let code =
+        unsafe { lexical_parse_u8_v1(bytes.as_ptr(), bytes.len(), &mut value, ptr::null_mut()) };
+    assert_eq!(code, ErrorCode::Overflow.to_code());
+
+    let mut value = 0f64;
+    let code = unsafe { lexical_parse_f64_v1(ptr::null(), 0, &mut value, &mut index) };
+    assert_eq!(code, ErrorCode::Empty.to_code());
+    assert_eq!(index, 0);
+
+    let bytes = b"1.5e3";
+    let code = unsafe { lexical_parse_f64_v1(bytes.as_ptr(), bytes.len(), &mut value, &mut index) };
+    assert_eq!(code, 0);
+    assert_eq!(value, 1.5e3);
+}
+
+#[test]
+fn parse_partial_test() {
+    let mut value = 0u64;
+    let mut count = 0usize;
+    let bytes = b"18446744073709551615,1";
+    let code = unsafe {
+        lexical_parse_partial_u64_v1(bytes.as_ptr(), bytes.len(), &mut value, &mut count)
+    };
+    assert_eq!(code, 0);
+    assert_eq!(value, u64::MAX);
+    assert_eq!(count, 20);
+
+    let mut value = 0f32;
+    let bytes = b"-x";
+    let code = unsafe {
+        lexical_parse_partial_f32_v1(bytes.as_ptr(), bytes.len(), &mut value, &mut count)
+    };
+    assert_eq!(code, ErrorCode::EmptyMantissa.to_code());
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn write_test() {
+    let mut buffer = [b'\x00'; LEXICAL_BUFFER_SIZE];
+    let count = unsafe { lexical_write_i64_v1(i64::MIN, buffer.as_mut_ptr(), buffer.len()) };
+    assert_eq!(&buffer[..count], b"-9223372036854775808");
+
+    let count = unsafe { lexical_write_f64_v1(1.5e300, buffer.as_mut_ptr(), buffer.len()) };
+    assert_eq!(&buffer[..count], b"1.5e300");
+
+    let count = unsafe {
+        lexical_write_f64_v1(-2.2250738585072014e-308, buffer.as_mut_ptr(), buffer.len())
+    };
+    assert_eq!(&buffer[..count], b"-2.2250738585072014e-308");
+
+    let count = unsafe { lexical_write_u8_v1(255, buffer.as_mut_ptr(), 3) };
+    assert_eq!(&buffer[..count], b"255");
+
+    // Nothing is written if the buffer is too small.
+    let mut buffer = [b'\x00'; 2];
+    let count = unsafe { lexical_write_u8_v1(255, buffer.as_mut_ptr(), buffer.len()) };
+    assert_eq!(count, 0);
+    assert_eq!(&buffer, b"\x00\x00");
+}
+
+/// Get the value of a `#define` in the header.
+fn define(name: &str) -> Option<&'static str> {
+    HEADER.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("#define"), Some(x), Some(value)) if x == name => Some(value),
+            _ => None,
+        }
+    })
+}
+
+#[test]
+fn header_constants_test() {
+    assert_eq!(define("LEXICAL_ABI_VERSION"), Some(&*LEXICAL_ABI_VERSION.to_string()));
+    assert_eq!(define("LEXICAL_BUFFER_SIZE"), Some(&*LEXICAL_BUFFER_SIZE.to_string()));
+
+    // Every parse error has a code.
+    let parse_errors = ErrorCode::ALL.iter().filter(|code| code.to_code() < 100);
+    for &code in parse_errors {
+        let mut name = String::from("LEXICAL");
+        for c in format!("{:?}", code).chars() {
+            if c.is_ascii_uppercase() {
+                name.push('_');
+            }
+            name.push(c.to_ascii_uppercase());
+        }
+        assert_eq!(define(&name), Some(&*code.to_code().to_string()), "{}", name);
+    }
+}
+
+#[test]
+fn header_symbols_test() {
+    // Every function is versioned, and has an unversioned alias.
+    let suffix = format!("_v{}", LEXICAL_ABI_VERSION);
+    let functions: Vec<&str> = HEADER
+        .lines()
+        .filter(|line| !line.starts_with('#') && line.contains('('))
+        .filter_map(|line| line.split('(').next()?.split_whitespace().last())
+        .collect();
+    assert_eq!(functions.len(), 31);
+    for &function in functions.iter() {
+        if function == "lexical_abi_version" {
+            continue;
+        }
+        assert!(function.ends_with(&suffix), "{}", function);
+        let alias = &function[..function.len() - suffix.len()];
+        assert_eq!(define(alias), Some(function));
+    }
+}
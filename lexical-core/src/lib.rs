@@ -84,6 +84,11 @@
 #![cfg_attr(feature = "write", doc = " - [`write_unchecked`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options_unchecked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_split`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_split_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_len32`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_len32_with_options`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`write_reserve`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -91,6 +96,142 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_complete`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_complete_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`Parser`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_len32`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_len32_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_carry`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_carry_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_then`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_then_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_skipping`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_skipping_with_options`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_c_str`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_partial_c_str`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_c_str_with_options`]")]
+#![cfg_attr(
+    all(feature = "parse", feature = "std"),
+    doc = " - [`parse_partial_c_str_with_options`]"
+)]
+#![cfg_attr(all(feature = "parse-floats", feature = "std"), doc = " - [`strtod_compat`]")]
+#![cfg_attr(all(feature = "parse-floats", feature = "std"), doc = " - [`strtof_compat`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_unicode`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_partial_unicode`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_unicode_with_options`]")]
+#![cfg_attr(
+    all(feature = "parse", feature = "unicode"),
+    doc = " - [`parse_partial_unicode_with_options`]"
+)]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_integral`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_integral_with_options`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_integer_valued_float`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_magnitude`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_magnitude_with_options`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`DigitAccumulator`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_power_of_ten`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`scale_by_pow10`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_number`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_number_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_float_parts`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_float_parts_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_sign_special`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_sign_special_with_options`]")]
+#![cfg_attr(
+    all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")),
+    doc = " - [`parse_prefixed`]"
+)]
+#![cfg_attr(
+    all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")),
+    doc = " - [`parse_prefixed_partial`]"
+)]
+#![cfg_attr(feature = "parse-floats", doc = " - [`normalize`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`normalize_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`quantize`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`quantize_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_rounded`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_rounded_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_significant`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_significant_with_options`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_zeroizing`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_zeroizing_with_options`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_partial_zeroizing`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_partial_zeroizing_with_options`]")]
+#![cfg_attr(feature = "varint", doc = " - [`parse_varint`]")]
+#![cfg_attr(feature = "varint", doc = " - [`parse_varint_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`cmp_numeric`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`cmp_numeric_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`hash_numeric`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`hash_numeric_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`count_digits`]")]
+#![cfg_attr(feature = "parse", doc = " - [`is_all_digits`]")]
+#![cfg_attr(feature = "parse", doc = " - [`char_to_digit`]")]
+#![cfg_attr(feature = "write", doc = " - [`digit_to_char`]")]
+#![cfg_attr(
+    any(feature = "parse-integers", feature = "write-integers"),
+    doc = " - [`checked_pow`]"
+)]
+#![cfg_attr(
+    any(feature = "parse-integers", feature = "write-integers"),
+    doc = " - [`checked_pow10`]"
+)]
+#![cfg_attr(
+    any(feature = "parse-integers", feature = "write-integers"),
+    doc = " - [`checked_mul_pow10`]"
+)]
+#![cfg_attr(
+    any(feature = "parse-integers", feature = "write-integers"),
+    doc = " - [`div_rem_1e8`]"
+)]
+#![cfg_attr(
+    any(feature = "parse-integers", feature = "write-integers"),
+    doc = " - [`div_rem_1e19`]"
+)]
+#![cfg_attr(feature = "parse", doc = " - [`NumberMatcher`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`Numbers`]")]
+#![cfg_attr(feature = "floats", doc = " - [`Decimal64`]")]
+#![cfg_attr(feature = "floats", doc = " - [`Decimal128`]")]
+#![cfg_attr(all(feature = "posit", feature = "parse-floats"), doc = " - [`parse_posit`]")]
+#![cfg_attr(
+    all(feature = "posit", feature = "parse-floats"),
+    doc = " - [`parse_posit_with_options`]"
+)]
+#![cfg_attr(all(feature = "posit", feature = "write-floats"), doc = " - [`write_posit`]")]
+#![cfg_attr(
+    all(feature = "posit", feature = "write-floats"),
+    doc = " - [`write_posit_with_options`]"
+)]
+#![cfg_attr(feature = "posit", doc = " - [`Posit16`]")]
+#![cfg_attr(feature = "posit", doc = " - [`Posit32`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_custom_float`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_custom_float_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_double_double`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_double_double_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_x87`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_x87_with_options`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_custom_float`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_custom_float_with_options`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_double_double`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_double_double_with_options`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_x87`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_x87_with_options`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_printf`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_printf_hex`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_width`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_width_with_options`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`RadixDisplay`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`Reservation`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`WriteReserved`]")]
+#![cfg_attr(feature = "floats", doc = " - [`LexicalFloat`]")]
+//!
+//! **Build Information**
+//!
+//! - [`capabilities`]
+//! - [`MINIMAL`]
 //!
 //! # Features
 //!
@@ -179,6 +320,10 @@
 //! for esoteric programming languages which use duodecimal floats, for
 //! example.
 //!
+//! Integers may also use radixes from 37 to 62, with the extended,
+//! case-sensitive alphabet `0-9A-Za-z`, which is useful for decoding
+//! identifiers like short links. Floats only support radixes up to 36.
+//!
 //! ### compact
 //!
 //! Reduce the generated code size at the cost of performance. This minimizes
@@ -196,6 +341,126 @@
 //! safe feature enabled and disabled, with the tests verified by Miri
 //! and Valgrind.
 //!
+//! ### deterministic
+//!
+//! Only use integer arithmetic when parsing floats, avoiding the native
+//! float fast-path. On targets with only an x87 FPU, native float
+//! arithmetic may use extended-precision intermediates, leading to
+//! double rounding. With this feature, parsed floats are bit-identical
+//! across all targets, which is useful for lockstep simulations, at
+//! a small performance cost for simple floats.
+//!
+//! ### lazy-tables
+//!
+//! Compute the cached powers for non-decimal radixes at runtime, the first
+//! time each radix is used, rather than embedding the tables in the binary.
+//! This trades a one-time initialization cost for a smaller binary, which
+//! is useful for size-sensitive targets like WebAssembly that still need
+//! the `radix` feature. This has no effect without the `radix` feature.
+//!
+//! ### unicode
+//!
+//! Add [`parse_unicode`] and related functions, which accept the
+//! `U+2212 MINUS SIGN` as a minus sign, and non-breaking spaces as digit
+//! separators, which are common in data copy-pasted from documents. A lone
+//! `0xA0` byte is accepted as a Windows-1252 non-breaking space. This
+//! requires the `std` feature, and never changes the behavior of [`parse`].
+//!
+//! ### posit
+//!
+//! Add support for 16-bit and 32-bit posits, or type III unums, which
+//! are an alternative to IEEE-754 floats with tapered precision. Parsing
+//! is correctly rounded, and writing uses the shortest digits that parse
+//! back to the same posit. This is meant for research and experimentation.
+//!
+//! ### fuzz
+//!
+//! Promote the debug assertions for internal invariants to checks, so
+//! they are verified in optimized builds. This is meant for fuzzing, and
+//! has a performance cost, so it should not be enabled otherwise.
+//!
+//! ### test-vectors
+//!
+//! Add the `test_vectors` module, which generates the exact decimal
+//! values of floats and of the halfway points between them, which must
+//! round to known floats. This is meant for downstream tests and fuzzers
+//! of float parsers, and requires the standard library.
+//!
+//! ### serde
+//!
+//! Implement `Serialize` and `Deserialize` for [`ParseFloatOptions`],
+//! [`WriteFloatOptions`], [`ParseIntegerOptions`] and [`WriteIntegerOptions`],
+//! so number dialects can be configured from files rather than code. Every
+//! float field is optional, and overrides the options of a named `preset`,
+//! such as `"JSON"`, before the options are validated. The integer options
+//! have no fields, and are an empty map. Special strings that differ from
+//! the preset are interned, since the options only store static strings,
+//! so each distinct string is allocated once. Named options can be
+//! collected in a `Registry`. This requires the standard library.
+//!
+//! ### hooks
+//!
+//! Add the `parse_float_hooks` module, to set a global callback invoked
+//! with the algorithm used to parse each float, such as the fast or slow
+//! path, and the number of digits. This is meant for services that export
+//! latency metrics by path. Without the feature, the hooks are compiled out.
+//!
+//! ### float160
+//!
+//! Parse floats with more than 19 significant digits using 160-bit extended
+//! floats when the `lossy` option is set, rather than only the first 19
+//! digits. This is almost always correctly rounded, and much faster than
+//! the arbitrary-precision algorithms used without `lossy`.
+//!
+//! ### zeroize
+//!
+//! Add [`parse_zeroizing`] and the related functions, which clear the big
+//! integers used for the digits in the slow path before returning, with
+//! the `zeroize` crate. This is meant for parsing secrets, such as private
+//! amounts, without leaving their digits in memory after parsing.
+//!
+//! ### bcd
+//!
+//! Add the `bcd` module, to convert decimal strings to and from big-endian
+//! BCD and packed decimal fields, or COBOL `COMP-3`, with an implied scale.
+//! This is meant for reading and writing mainframe records.
+//!
+//! ### varint
+//!
+//! Add the `varint` module, with a canonical binary encoding of floats,
+//! and [`parse_varint`], to parse floats directly to the encoding. This
+//! is meant for caching parse results between stages of a pipeline.
+//!
+//! ### panic-free
+//!
+//! Guarantee the parsers and the checked writers, such as [`try_write`],
+//! never panic, which is verified by tests that fail to link if any
+//! panic is reachable. This removes the internal checks of the writers
+//! that may panic, relying on the validated buffer size instead. This is
+//! useful for FFI and kernels, where unwinding is undefined behavior or
+//! unavailable. The `safe` and `fuzz` features intentionally add
+//! panicking checks, so the guarantee does not hold with them: check
+//! [`Capabilities::PANIC_FREE`] to confirm it at runtime.
+//!
+//! ### minimal
+//!
+//! Restrict the build to a minimal profile, for size-constrained firmware
+//! such as `thumbv7em-none-eabihf` or `wasm32-unknown-unknown` targets
+//! without the standard library. This enables `compact`, so only decimal
+//! conversions with the small, compact tables are compiled, and the
+//! profile is built for both targets in CI. Since features are additive,
+//! another crate in the dependency graph may enable a feature that adds
+//! tables or code paths, such as `radix`, `power-of-two`, `format`,
+//! `lazy-tables`, `f16`, `posit`, `unicode`, `nightly`, `safe`, `fuzz`,
+//! `test-vectors`, `serde`, `hooks`, `float160`, `zeroize`, `bcd`, or
+//! `varint`.
+//! [`MINIMAL`] is only `true` if none of them are enabled, so size budgets
+//! can be enforced at compile time (this requires Rust 1.57 or later):
+//!
+//! ```rust,ignore
+//! const _: () = assert!(lexical_core::MINIMAL);
+//! ```
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -243,6 +508,7 @@
 #![cfg_attr(feature = "parse-integers", doc = " - [`ParseIntegerOptions`]")]
 #![cfg_attr(feature = "write-floats", doc = " - [`WriteFloatOptions`]")]
 #![cfg_attr(feature = "write-integers", doc = " - [`WriteIntegerOptions`]")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " - [`BoolOptions`]")]
 //!
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules.
@@ -315,16 +581,121 @@
 //! [`write_unchecked`]: crate::write_unchecked
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
+//! [`write_split`]: crate::write_split
+//! [`write_split_with_options`]: crate::write_split_with_options
+//! [`write_len32`]: crate::write_len32
+//! [`write_len32_with_options`]: crate::write_len32_with_options
+//! [`write_reserve`]: crate::write_reserve
 //! [`parse`]: crate::parse
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
+//! [`parse_complete`]: crate::parse_complete
+//! [`parse_complete_with_options`]: crate::parse_complete_with_options
+//! [`Parser`]: crate::Parser
+//! [`parse_partial_len32`]: crate::parse_partial_len32
+//! [`parse_partial_len32_with_options`]: crate::parse_partial_len32_with_options
+//! [`parse_until`]: crate::parse_until
+//! [`parse_until_with_options`]: crate::parse_until_with_options
+//! [`parse_partial_carry`]: crate::parse_partial_carry
+//! [`parse_partial_carry_with_options`]: crate::parse_partial_carry_with_options
+//! [`parse_in_range`]: crate::parse_in_range
+//! [`parse_in_range_with_options`]: crate::parse_in_range_with_options
+//! [`parse_then`]: crate::parse_then
+//! [`parse_then_with_options`]: crate::parse_then_with_options
+//! [`parse_skipping`]: crate::parse_skipping
+//! [`parse_skipping_with_options`]: crate::parse_skipping_with_options
+//! [`parse_c_str`]: crate::parse_c_str
+//! [`parse_partial_c_str`]: crate::parse_partial_c_str
+//! [`parse_c_str_with_options`]: crate::parse_c_str_with_options
+//! [`parse_partial_c_str_with_options`]: crate::parse_partial_c_str_with_options
+//! [`strtod_compat`]: crate::strtod_compat
+//! [`strtof_compat`]: crate::strtof_compat
+//! [`parse_unicode`]: crate::parse_unicode
+//! [`parse_partial_unicode`]: crate::parse_partial_unicode
+//! [`parse_unicode_with_options`]: crate::parse_unicode_with_options
+//! [`parse_partial_unicode_with_options`]: crate::parse_partial_unicode_with_options
+//! [`parse_integral`]: crate::parse_integral
+//! [`parse_integral_with_options`]: crate::parse_integral_with_options
+//! [`parse_integer_valued_float`]: crate::parse_integer_valued_float
+//! [`parse_magnitude`]: crate::parse_magnitude
+//! [`parse_magnitude_with_options`]: crate::parse_magnitude_with_options
+//! [`DigitAccumulator`]: crate::DigitAccumulator
+//! [`parse_power_of_ten`]: crate::parse_power_of_ten
+//! [`scale_by_pow10`]: crate::scale_by_pow10
+//! [`parse_number`]: crate::parse_number
+//! [`parse_number_with_options`]: crate::parse_number_with_options
+//! [`parse_float_parts`]: crate::parse_float_parts
+//! [`parse_float_parts_with_options`]: crate::parse_float_parts_with_options
+//! [`parse_sign_special`]: crate::parse_sign_special
+//! [`parse_sign_special_with_options`]: crate::parse_sign_special_with_options
+//! [`parse_prefixed`]: crate::parse_prefixed
+//! [`parse_prefixed_partial`]: crate::parse_prefixed_partial
+//! [`normalize`]: crate::normalize
+//! [`normalize_with_options`]: crate::normalize_with_options
+//! [`quantize`]: crate::quantize
+//! [`quantize_with_options`]: crate::quantize_with_options
+//! [`parse_rounded`]: crate::parse_rounded
+//! [`parse_rounded_with_options`]: crate::parse_rounded_with_options
+//! [`parse_significant`]: crate::parse_significant
+//! [`parse_significant_with_options`]: crate::parse_significant_with_options
+//! [`parse_zeroizing`]: crate::parse_zeroizing
+//! [`parse_zeroizing_with_options`]: crate::parse_zeroizing_with_options
+//! [`parse_partial_zeroizing`]: crate::parse_partial_zeroizing
+//! [`parse_partial_zeroizing_with_options`]: crate::parse_partial_zeroizing_with_options
+//! [`parse_varint`]: crate::parse_varint
+//! [`parse_varint_with_options`]: crate::parse_varint_with_options
+//! [`cmp_numeric`]: crate::cmp_numeric
+//! [`cmp_numeric_with_options`]: crate::cmp_numeric_with_options
+//! [`hash_numeric`]: crate::hash_numeric
+//! [`hash_numeric_with_options`]: crate::hash_numeric_with_options
+//! [`count_digits`]: crate::count_digits
+//! [`is_all_digits`]: crate::is_all_digits
+//! [`char_to_digit`]: crate::char_to_digit
+//! [`digit_to_char`]: crate::digit_to_char
+//! [`checked_pow`]: crate::checked_pow
+//! [`checked_pow10`]: crate::checked_pow10
+//! [`checked_mul_pow10`]: crate::checked_mul_pow10
+//! [`div_rem_1e8`]: crate::div_rem_1e8
+//! [`div_rem_1e19`]: crate::div_rem_1e19
+//! [`NumberMatcher`]: crate::NumberMatcher
+//! [`Numbers`]: crate::Numbers
+//! [`Decimal64`]: crate::Decimal64
+//! [`Decimal128`]: crate::Decimal128
+//! [`parse_posit`]: crate::parse_posit
+//! [`parse_posit_with_options`]: crate::parse_posit_with_options
+//! [`write_posit`]: crate::write_posit
+//! [`write_posit_with_options`]: crate::write_posit_with_options
+//! [`Posit16`]: crate::Posit16
+//! [`Posit32`]: crate::Posit32
+//! [`parse_custom_float`]: crate::parse_custom_float
+//! [`parse_custom_float_with_options`]: crate::parse_custom_float_with_options
+//! [`parse_double_double`]: crate::parse_double_double
+//! [`parse_double_double_with_options`]: crate::parse_double_double_with_options
+//! [`parse_x87`]: crate::parse_x87
+//! [`parse_x87_with_options`]: crate::parse_x87_with_options
+//! [`write_custom_float`]: crate::write_custom_float
+//! [`write_custom_float_with_options`]: crate::write_custom_float_with_options
+//! [`write_double_double`]: crate::write_double_double
+//! [`write_double_double_with_options`]: crate::write_double_double_with_options
+//! [`write_x87`]: crate::write_x87
+//! [`write_x87_with_options`]: crate::write_x87_with_options
+//! [`write_printf`]: crate::write_printf
+//! [`write_printf_hex`]: crate::write_printf_hex
+//! [`write_width`]: crate::write_width
+//! [`write_width_with_options`]: crate::write_width_with_options
+//! [`RadixDisplay`]: crate::RadixDisplay
+//! [`Reservation`]: crate::Reservation
+//! [`WriteReserved`]: crate::WriteReserved
+//! [`LexicalFloat`]: crate::LexicalFloat
+//! [`capabilities`]: crate::capabilities
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
 //! [`ParseIntegerOptions`]: crate::ParseIntegerOptions
 //! [`WriteFloatOptions`]: crate::WriteFloatOptions
 //! [`WriteIntegerOptions`]: crate::WriteIntegerOptions
+//! [`BoolOptions`]: crate::BoolOptions
 
 // We want to have the same safety guarantees as Rust core,
 // so we allow unused unsafe to clearly document safety guarantees.
@@ -332,6 +703,20 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "parse-floats")]
+use core::cmp::Ordering;
+#[cfg(feature = "parse")]
+use core::convert::TryFrom;
+#[cfg(feature = "parse-floats")]
+use core::hash::Hasher;
+#[cfg(feature = "parse-floats")]
+use core::num::NonZeroUsize;
+#[cfg(feature = "parse-floats")]
+use lexical_parse_float::integral::check_integral;
+#[cfg(feature = "parse-floats")]
+use lexical_parse_float::parse::ParseFloat;
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+use lexical_parse_float::strtod::strtod;
 #[cfg(feature = "parse-floats")]
 use lexical_parse_float::{
     FromLexical as FromFloat,
@@ -343,6 +728,24 @@ use lexical_parse_integer::{
     FromLexicalWithOptions as FromIntegerWithOptions,
 };
 #[cfg(feature = "parse")]
+use lexical_util::ascii;
+#[cfg(any(feature = "parse", feature = "write"))]
+use lexical_util::boolean;
+#[cfg(all(feature = "parse", feature = "std"))]
+use lexical_util::c_str::c_str_to_bytes;
+#[cfg(feature = "parse-floats")]
+use lexical_util::canonical::NumericStr;
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+use lexical_util::num::Float;
+#[cfg(feature = "parse-floats")]
+use lexical_util::num::Integer;
+#[cfg(feature = "parse")]
+use lexical_util::result;
+#[cfg(all(feature = "parse", feature = "unicode"))]
+use lexical_util::unicode::Normalized;
+#[cfg(feature = "parse")]
+use lexical_util::wrapper::shift_error;
+#[cfg(feature = "parse")]
 use lexical_util::{from_lexical, from_lexical_with_options};
 #[cfg(feature = "write")]
 use lexical_util::{to_lexical, to_lexical_with_options};
@@ -350,35 +753,105 @@ use lexical_util::{to_lexical, to_lexical_with_options};
 use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatWithOptions};
 #[cfg(feature = "write-integers")]
 use lexical_write_integer::{ToLexical as ToInteger, ToLexicalWithOptions as ToIntegerWithOptions};
+#[cfg(all(feature = "parse", feature = "std"))]
+use std::os::raw::c_char;
 
 // Re-exports
 #[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::extract::Numbers;
+#[cfg(feature = "hooks")]
+pub use lexical_parse_float::hooks as parse_float_hooks;
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::mixed::Number;
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub use lexical_parse_float::tune as parse_float_tune;
+#[cfg(feature = "test-vectors")]
+pub use lexical_parse_float::vectors as test_vectors;
+#[cfg(all(feature = "parse-floats", not(feature = "parse-integers")))]
+pub use lexical_parse_float::Sign;
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::Special;
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub use lexical_parse_float::StrtodStatus;
+#[cfg(feature = "parse-floats")]
 pub use lexical_parse_float::{
     options as parse_float_options,
     Options as ParseFloatOptions,
     OptionsBuilder as ParseFloatOptionsBuilder,
 };
 #[cfg(feature = "parse-integers")]
+pub use lexical_parse_integer::parse_integer_valued_float;
+#[cfg(feature = "parse-integers")]
+pub use lexical_parse_integer::Sign;
+#[cfg(feature = "parse-integers")]
 pub use lexical_parse_integer::{
     options as parse_integer_options,
     Options as ParseIntegerOptions,
     OptionsBuilder as ParseIntegerOptionsBuilder,
 };
+#[cfg(feature = "parse-integers")]
+pub use lexical_parse_integer::{Accumulation, DigitAccumulator};
+#[cfg(feature = "bcd")]
+pub use lexical_util::bcd;
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_util::boolean::{self as bool_options, BoolOptions, BoolOptionsBuilder};
+#[cfg(feature = "parse-floats")]
+pub use lexical_util::canonical::Rounding;
+#[cfg(feature = "parse")]
+pub use lexical_util::carry::{Carry, CARRY_SIZE};
 #[cfg(feature = "write")]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+#[cfg(feature = "floats")]
+pub use lexical_util::custom_float::LexicalFloat;
+#[cfg(feature = "floats")]
+pub use lexical_util::decimal::{Decimal128, Decimal64};
 #[cfg(feature = "parse")]
-pub use lexical_util::error::Error;
+pub use lexical_util::delimiter::Delimiters;
+#[cfg(feature = "write")]
+pub use lexical_util::digit::DigitCase;
+#[cfg(feature = "parse")]
+pub use lexical_util::error::{Error, ErrorCode};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, format_error, format_is_valid, NumberFormatBuilder};
+pub use lexical_util::log;
+#[cfg(feature = "parse")]
+pub use lexical_util::matcher::{Matches, NumberMatcher};
 #[cfg(feature = "parse")]
 pub use lexical_util::options::ParseOptions;
+#[cfg(feature = "std")]
+pub use lexical_util::options::Registry;
 #[cfg(feature = "write")]
 pub use lexical_util::options::WriteOptions;
+#[cfg(feature = "posit")]
+pub use lexical_util::posit::{Posit, Posit16, Posit32};
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_util::pow::{
+    checked_mul_pow10,
+    checked_pow,
+    checked_pow10,
+    div_rem_1e19,
+    div_rem_1e8,
+    Pow10,
+    POW10_U128,
+    POW10_U32,
+    POW10_U64,
+};
+pub use lexical_util::radix::Radix;
 #[cfg(feature = "parse")]
 pub use lexical_util::result::Result;
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_util::step::DigitCount;
+#[cfg(feature = "varint")]
+pub use lexical_util::varint;
+#[cfg(feature = "write-floats")]
+pub use lexical_write_float::double_double::DOUBLE_DOUBLE_BUFFER_SIZE;
+#[cfg(feature = "write-floats")]
+pub use lexical_write_float::printf::{HexSignificand, PrintfStyle, PRINTF_BUFFER_SIZE};
+#[cfg(feature = "write-floats")]
+pub use lexical_write_float::x87::X87_BUFFER_SIZE;
 #[cfg(feature = "write-floats")]
 pub use lexical_write_float::{
     options as write_float_options,
@@ -386,6 +859,10 @@ pub use lexical_write_float::{
     OptionsBuilder as WriteFloatOptionsBuilder,
 };
 #[cfg(feature = "write-integers")]
+pub use lexical_write_integer::display::RadixDisplay;
+#[cfg(feature = "write-integers")]
+pub use lexical_write_integer::reserve::{Reservation, WriteReserved};
+#[cfg(feature = "write-integers")]
 pub use lexical_write_integer::{
     options as write_integer_options,
     Options as WriteIntegerOptions,
@@ -395,14 +872,15 @@ pub use lexical_write_integer::{
 // API
 // ---
 
+// The traits are also implemented for `bool`, which is not a number.
 #[cfg(feature = "parse")]
-from_lexical!();
+from_lexical!(lexical_util::num::Scalar);
 #[cfg(feature = "parse")]
-from_lexical_with_options!();
+from_lexical_with_options!(lexical_util::num::Scalar);
 #[cfg(feature = "write")]
-to_lexical!();
+to_lexical!(FormattedSize + lexical_util::num::Scalar);
 #[cfg(feature = "write")]
-to_lexical_with_options!();
+to_lexical_with_options!(FormattedSize + lexical_util::num::Scalar);
 
 /// Implement `FromLexical` and `FromLexicalWithOptions` for numeric type.
 #[cfg(feature = "parse")]
@@ -464,6 +942,41 @@ macro_rules! float_from_lexical {
 #[cfg(feature = "parse-floats")]
 float_from_lexical! { f32 f64 }
 
+// The number format is ignored for booleans.
+#[cfg(feature = "parse")]
+impl FromLexical for bool {
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical(bytes: &[u8]) -> Result<Self> {
+        boolean::parse_bool(bytes, &boolean::STANDARD)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+        boolean::parse_bool_partial(bytes, &boolean::STANDARD)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl FromLexicalWithOptions for bool {
+    type Options = BoolOptions;
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> Result<Self> {
+        boolean::parse_bool(bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> Result<(Self, usize)> {
+        boolean::parse_bool_partial(bytes, options)
+    }
+}
+
 // Implement ToLexical for numeric type.
 #[cfg(feature = "write")]
 macro_rules! to_lexical_impl {
@@ -479,6 +992,11 @@ macro_rules! to_lexical_impl {
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
                 <Self as $to>::to_lexical(self, bytes)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8]) -> Option<&'a mut [u8]> {
+                <Self as $to>::try_to_lexical(self, bytes)
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -506,6 +1024,15 @@ macro_rules! to_lexical_impl {
             ) -> &'a mut [u8] {
                 <Self as $to_options>::to_lexical_with_options::<FORMAT>(self, bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Option<&'a mut [u8]> {
+                <Self as $to_options>::try_to_lexical_with_options::<FORMAT>(self, bytes, options)
+            }
         }
     };
 }
@@ -532,6 +1059,57 @@ macro_rules! float_to_lexical {
 #[cfg(feature = "write-floats")]
 float_to_lexical! { f32 f64 }
 
+// The number format is ignored for booleans.
+#[cfg(feature = "write")]
+impl ToLexical for bool {
+    #[cfg_attr(not(feature = "compact"), inline)]
+    unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+        boolean::write_bool(self, bytes, &boolean::STANDARD)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+        boolean::write_bool(self, bytes, &boolean::STANDARD)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn try_to_lexical(self, bytes: &mut [u8]) -> Option<&mut [u8]> {
+        boolean::try_write_bool(self, bytes, &boolean::STANDARD)
+    }
+}
+
+#[cfg(feature = "write")]
+impl ToLexicalWithOptions for bool {
+    type Options = BoolOptions;
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        boolean::write_bool(self, bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn to_lexical_with_options<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        boolean::write_bool(self, bytes, options)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> Option<&'a mut [u8]> {
+        boolean::try_write_bool(self, bytes, options)
+    }
+}
+
 /// Write number to string.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -584,6 +1162,41 @@ pub fn write<N: ToLexical>(n: N, bytes: &mut [u8]) -> &mut [u8] {
     n.to_lexical(bytes)
 }
 
+/// Write number to string, checking the buffer is large enough.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or
+/// `None` if the buffer is not large enough to hold the serialized number.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// This never panics, and nothing is written if `None` is returned.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+/// let digits = lexical_core::try_write(float, &mut buffer);
+/// assert_eq!(digits.map(|x| &*x), Some(&b"3.1415927"[..]));
+///
+/// // The buffer is only one byte large.
+/// let mut buffer = [0u8; 1];
+/// assert_eq!(lexical_core::try_write(float, &mut buffer), None);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn try_write<N: ToLexical>(n: N, bytes: &mut [u8]) -> Option<&mut [u8]> {
+    n.try_to_lexical(bytes)
+}
+
 /// Write number to string, without bounds checking the buffer.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -696,6 +1309,52 @@ pub fn write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
     n.to_lexical_with_options::<FORMAT>(bytes, options)
 }
 
+/// Write number to string with custom options, checking for errors.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or
+/// `None` if the number cannot be written.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// This never panics: every case where [`write_with_options`] would
+/// panic returns `None` instead, including a buffer that may not be
+/// large enough to hold the serialized number, or an invalid `FORMAT`.
+/// Nothing is written if `None` is returned.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let float = f32::NAN;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .nan_string(None)
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::try_write_with_options::<_, FORMAT>(float, &mut buffer, &options);
+/// assert_eq!(result, None);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn try_write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> Option<&'a mut [u8]> {
+    n.try_to_lexical_with_options::<FORMAT>(bytes, options)
+}
+
 /// Write number to string with custom options.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -756,6 +1415,211 @@ pub unsafe fn write_with_options_unchecked<'a, N: ToLexicalWithOptions, const FO
     unsafe { n.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
 }
 
+/// Write number to string, returning the written bytes and the rest of the buffer.
+///
+/// Returns a tuple of the subslice containing the written bytes, and
+/// the remainder of the buffer after them. This allows writing multiple
+/// numbers and delimiters into a single buffer without tracking indexes.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements, after
+/// any bytes already written.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; 2 * BUFFER_SIZE + 1];
+/// let (x, rest) = lexical_core::write_split(1.5f64, &mut buffer);
+/// let x_len = x.len();
+/// rest[0] = b',';
+/// let (y, _) = lexical_core::write_split(-2.25f64, &mut rest[1..]);
+/// let y_len = y.len();
+///
+/// assert_eq!(&buffer[..x_len + 1 + y_len], b"1.5,-2.25");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_split<N: ToLexical>(n: N, bytes: &mut [u8]) -> (&mut [u8], &mut [u8]) {
+    let count = n.to_lexical(bytes).len();
+    bytes.split_at_mut(count)
+}
+
+/// Write number to string with custom options, returning the written bytes and the rest of the buffer.
+///
+/// Returns a tuple of the subslice containing the written bytes, and
+/// the remainder of the buffer after them. This allows writing multiple
+/// numbers and delimiters into a single buffer without tracking indexes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid. See [`write_with_options`] for the size of
+/// the buffer required.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+/// let mut buffer = [0u8; 3 * BUFFER_SIZE];
+/// let mut rest = &mut buffer[..];
+/// let mut count = 0;
+/// for &value in [1u32, 22, 333].iter() {
+///     let (digits, tail) = lexical_core::write_split_with_options::<_, FORMAT>(value, rest, &options);
+///     count += digits.len();
+///     rest = tail;
+/// }
+///
+/// assert_eq!(&buffer[..count], b"122333");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_split_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> (&'a mut [u8], &'a mut [u8]) {
+    let count = n.to_lexical_with_options::<FORMAT>(bytes, options).len();
+    bytes.split_at_mut(count)
+}
+
+/// Write number to string, returning the number of bytes written as a `u32`.
+///
+/// This is like [`write`], but returns a `u32` length rather than a
+/// slice, for 16-bit and 32-bit microcontrollers, where `usize` lengths
+/// and fat pointers generate extra code at FFI boundaries.
+///
+/// * `n`       - Number to convert to string.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size. The caller
+/// must provide a slice of sufficient size. In order to ensure
+/// the function will not panic, ensure the buffer has at least
+/// [`FORMATTED_SIZE_DECIMAL`] elements.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// let count = lexical_core::write_len32(-1234i32, &mut buffer);
+/// assert_eq!(count, 5);
+/// assert_eq!(&buffer[..count as usize], b"-1234");
+/// # }
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: crate::FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_len32<N: ToLexical>(n: N, bytes: &mut [u8]) -> u32 {
+    // The formatted size of every number is less than `u32::MAX`.
+    n.to_lexical(bytes).len() as u32
+}
+
+/// Write number to string with custom options, returning the number of bytes written as a `u32`.
+///
+/// This is like [`write_with_options`], but returns a `u32` length
+/// rather than a slice. See [`write_len32`] for details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid. See [`write_with_options`] for the size of
+/// the buffer required.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// let count = lexical_core::write_len32_with_options::<_, FORMAT>(65535u16, &mut buffer, &options);
+/// assert_eq!(&buffer[..count as usize], b"65535");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_len32_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &mut [u8],
+    options: &N::Options,
+) -> u32 {
+    // The formatted size of every number is less than `u32::MAX`.
+    n.to_lexical_with_options::<FORMAT>(bytes, options).len() as u32
+}
+
+/// Reserve the exact number of bytes needed to write an integer, to write it later.
+///
+/// This is the first phase of a two-phase write: the number of digits is
+/// counted, which is cheap compared to writing them, and the returned
+/// [`Reservation`] is then committed into a slot of exactly
+/// [`formatted_len`] bytes, such as in a ring buffer, or after a length
+/// prefix. Unlike [`write`], no temporary buffer of
+/// [`FORMATTED_SIZE_DECIMAL`] bytes is required.
+///
+/// * `n`       - Integer to convert to string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut ring = [0u8; 8];
+/// let reservation = lexical_core::write_reserve(-42i64);
+/// assert_eq!(reservation.formatted_len(), 3);
+///
+/// // Commit at the end of the ring buffer, with a length prefix.
+/// let start = ring.len() - reservation.formatted_len();
+/// ring[start - 1] = reservation.formatted_len() as u8;
+/// reservation.commit(&mut ring[start..]);
+/// assert_eq!(&ring[start - 1..], b"\x03-42");
+/// # }
+/// # }
+/// ```
+///
+/// [`formatted_len`]: crate::Reservation::formatted_len
+/// [`FORMATTED_SIZE_DECIMAL`]: crate::FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+#[cfg(feature = "write-integers")]
+pub fn write_reserve<N: WriteReserved>(n: N) -> Reservation<N> {
+    Reservation::new(n)
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -867,3 +1731,3323 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Parse number from string, requiring the entire string is consumed.
+///
+/// This parses the longest valid number like [`parse_partial`], and
+/// returns an error if any bytes are left, with the index of the first
+/// unconsumed byte. Unlike [`parse`], errors are reported identically
+/// for integers and floats: if the string ends before the number is
+/// complete, such as `"1e"` or `"-"`, the error is an empty error at
+/// the end of the string, and otherwise it is [`Error::InvalidDigit`]
+/// at the first byte that isn't part of the number. For example,
+/// `"infx"` is an invalid digit at index 3, and `"--1"` is an invalid
+/// digit at index 1, for any numeric type.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "parse-integers"))] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_complete::<f64>(b"1.5"), Ok(1.5));
+/// assert_eq!(lexical_core::parse_complete::<f64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+/// assert_eq!(lexical_core::parse_complete::<f64>(b"infx"), Err(Error::InvalidDigit(3)));
+/// assert_eq!(lexical_core::parse_complete::<f64>(b" 1"), Err(Error::InvalidDigit(0)));
+/// assert_eq!(lexical_core::parse_complete::<i32>(b" 1"), Err(Error::InvalidDigit(0)));
+/// assert_eq!(lexical_core::parse_complete::<f64>(b"--1"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(lexical_core::parse_complete::<i32>(b"--1"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(lexical_core::parse_complete::<f64>(b"1e"), Err(Error::EmptyExponent(2)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_complete<N: FromLexical>(bytes: &[u8]) -> Result<N> {
+    result::check_complete(bytes, N::from_lexical_partial(bytes))
+}
+
+/// Parse number from string with custom parsing options, requiring the entire string is consumed.
+///
+/// This method is like [`parse_complete`], but uses a custom number
+/// format and options, like [`parse_partial_with_options`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Error;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_complete_with_options::<f64, FORMAT>(b"1,5", &options);
+/// assert_eq!(result, Ok(1.5));
+/// let result = lexical_core::parse_complete_with_options::<f64, FORMAT>(b"1,5.", &options);
+/// assert_eq!(result, Err(Error::InvalidDigit(3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_complete_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<N> {
+    result::check_complete(bytes, N::from_lexical_partial_with_options::<FORMAT>(bytes, options))
+}
+
+/// Parser for a number type, with the number format and options chosen at runtime.
+///
+/// The number format is a const generic parameter, so every format is
+/// parsed by a different function. A parser stores the functions for a
+/// format, and the options, so the format can be chosen at runtime, such
+/// as from a configuration file. The format and the options are validated
+/// when the parser is created. A parser is immutable, so it can be shared
+/// between threads.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::{format, ParseFloatOptions, Parser};
+///
+/// let dialect = "european";
+/// let parser = match dialect {
+///     "json" => Parser::<f64>::new::<{ format::JSON }>(ParseFloatOptions::new()),
+///     _ => {
+///         let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+///         Parser::<f64>::new::<{ format::STANDARD }>(options)
+///     },
+/// }
+/// .unwrap();
+///
+/// for string in ["3,5", "-1,25", "1e3"].iter() {
+///     assert!(parser.parse(string.as_bytes()).is_ok());
+/// }
+/// assert_eq!(parser.parse_partial(b"3,5;2,5"), Ok((3.5, 3)));
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "parse")]
+pub struct Parser<N: FromLexicalWithOptions> {
+    options: N::Options,
+    parse: ParseFn<N>,
+    parse_partial: ParsePartialFn<N>,
+}
+
+/// Complete parser for a number format.
+#[cfg(feature = "parse")]
+type ParseFn<N> = fn(&[u8], &<N as FromLexicalWithOptions>::Options) -> Result<N>;
+
+/// Partial parser for a number format.
+#[cfg(feature = "parse")]
+type ParsePartialFn<N> = fn(&[u8], &<N as FromLexicalWithOptions>::Options) -> Result<(N, usize)>;
+
+#[cfg(feature = "parse")]
+impl<N: FromLexicalWithOptions> Parser<N> {
+    /// Create a parser for the number format, returning an error if the format or options are invalid.
+    ///
+    /// * `FORMAT`  - Packed struct containing the number format.
+    /// * `options` - Options to customize number parsing.
+    #[inline]
+    pub fn new<const FORMAT: u128>(options: N::Options) -> Result<Self> {
+        if !format_is_valid::<FORMAT>() {
+            return Err(format_error::<FORMAT>());
+        }
+        options.validate()?;
+        Ok(Self {
+            options,
+            parse: N::from_lexical_with_options::<FORMAT>,
+            parse_partial: N::from_lexical_partial_with_options::<FORMAT>,
+        })
+    }
+
+    /// Get the options to customize number parsing.
+    #[inline(always)]
+    pub fn options(&self) -> &N::Options {
+        &self.options
+    }
+
+    /// Parse a complete number from bytes, like [`parse_with_options`].
+    #[inline(always)]
+    pub fn parse(&self, bytes: &[u8]) -> Result<N> {
+        (self.parse)(bytes, &self.options)
+    }
+
+    /// Parse a partial number from bytes, like [`parse_partial_with_options`].
+    #[inline(always)]
+    pub fn parse_partial(&self, bytes: &[u8]) -> Result<(N, usize)> {
+        (self.parse_partial)(bytes, &self.options)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl<N: FromLexicalWithOptions> Clone for Parser<N>
+where
+    N::Options: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+            parse: self.parse,
+            parse_partial: self.parse_partial,
+        }
+    }
+}
+
+/// Parse partial number from string, returning the number of processed bytes as a `u32`.
+///
+/// This is like [`parse_partial`], but returns a `u32` count, for 16-bit
+/// and 32-bit microcontrollers, where `usize` conversions generate extra
+/// code at FFI boundaries. Only the first `u32::MAX` bytes are parsed,
+/// so the count and [`Error::index_u32`] are never truncated.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let result = lexical_core::parse_partial_len32::<u16>(b"1234 rpm");
+/// assert_eq!(result, Ok((1234, 4)));
+/// let error = lexical_core::parse_partial_len32::<u16>(b"70000").unwrap_err();
+/// assert_eq!(error.index_u32(), Some(4));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_len32<N: FromLexical>(bytes: &[u8]) -> Result<(N, u32)> {
+    result::parse_partial_len32(bytes, N::from_lexical_partial)
+}
+
+/// Parse partial number from string with custom parsing options, returning the number of processed bytes as a `u32`.
+///
+/// This is like [`parse_partial_with_options`], but returns a `u32`
+/// count. See [`parse_partial_len32`] for details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let result = lexical_core::parse_partial_len32_with_options::<i32, FORMAT>(b"-42,", &options);
+/// assert_eq!(result, Ok((-42, 3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_len32_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<(N, u32)> {
+    result::parse_partial_len32(bytes, |bytes| {
+        N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+    })
+}
+
+/// Parse number from string, up to a delimiter.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), like [`parse_partial`], but returns an error if the
+/// invalid digit is not one of the delimiters. This is useful for
+/// tokenizers, where a number must be followed by a separator.
+///
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `delimiters`  - Set of bytes that may follow the number.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Delimiters;
+///
+/// let result = lexical_core::parse_until::<u32>(b"15,3]", &Delimiters::JSON);
+/// assert_eq!(result, Ok((15, 2)));
+/// let result = lexical_core::parse_until::<u32>(b"15", &Delimiters::JSON);
+/// assert_eq!(result, Ok((15, 2)));
+/// let result = lexical_core::parse_until::<u32>(b"15a", &Delimiters::JSON);
+/// assert_eq!(result, Err(lexical_core::Error::InvalidDigit(2)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until<N: FromLexical>(bytes: &[u8], delimiters: &Delimiters) -> Result<(N, usize)> {
+    let (value, count) = N::from_lexical_partial(bytes)?;
+    delimiters.check_partial(bytes, value, count)
+}
+
+/// Parse number from string with custom parsing options, up to a delimiter.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), like [`parse_partial_with_options`], but returns an
+/// error if the invalid digit is not one of the delimiters.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Byte slice containing a numeric string.
+/// * `delimiters`  - Set of bytes that may follow the number.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::Delimiters;
+///
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = b"3.5}";
+/// let result = lexical_core::parse_until_with_options::<f32, JSON>(
+///     string,
+///     &Delimiters::JSON,
+///     &options,
+/// );
+/// assert_eq!(result, Ok((3.5_f32, 3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiters: &Delimiters,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    let (value, count) = N::from_lexical_partial_with_options::<FORMAT>(bytes, options)?;
+    delimiters.check_partial(bytes, value, count)
+}
+
+/// Parse partial number split across a carry and the next buffer.
+///
+/// This method parses the bytes of the carry followed by the bytes of
+/// the next buffer, as if they were contiguous, until an invalid digit
+/// is found (or the end of the string), like [`parse_partial`]. This is
+/// useful for readers of memory-mapped files, where the end of one chunk
+/// is copied into a [`Carry`] before the next chunk is available.
+///
+/// Returns the value and the number of processed bytes, counted from
+/// the start of the carry, so the number ends at `count - carry.len()`
+/// in the next buffer. If the carry is empty, this is the same as
+/// [`parse_partial`]. Otherwise, the number must fit in [`CARRY_SIZE`]
+/// bytes, or [`Error::NumberTooLong`] is returned.
+///
+/// * `carry`   - Start of the number, from the end of the previous buffer.
+/// * `bytes`   - Byte slice containing the rest of the numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Carry;
+///
+/// let mut carry = Carry::new();
+/// carry.extend(b"3.14").unwrap();
+/// let result = lexical_core::parse_partial_carry::<f64>(&carry, b"159,2.7");
+/// assert_eq!(result, Ok((3.14159, 7)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_carry<N: FromLexical>(carry: &Carry, bytes: &[u8]) -> Result<(N, usize)> {
+    carry.parse_partial(bytes, N::from_lexical_partial)
+}
+
+/// Parse partial number split across a carry and the next buffer, with custom parsing options.
+///
+/// This method parses the bytes of the carry followed by the bytes of
+/// the next buffer, as if they were contiguous, like
+/// [`parse_partial_with_options`]. See [`parse_partial_carry`] for details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `carry`   - Start of the number, from the end of the previous buffer.
+/// * `bytes`   - Byte slice containing the rest of the numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Carry;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let mut carry = Carry::new();
+/// carry.extend(b"-12").unwrap();
+/// let result = lexical_core::parse_partial_carry_with_options::<i32, FORMAT>(
+///     &carry,
+///     b"34\n",
+///     &options,
+/// );
+/// assert_eq!(result, Ok((-1234, 5)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_carry_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    carry: &Carry,
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    carry.parse_partial(bytes, |bytes| {
+        N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+    })
+}
+
+/// Parse complete number from string, within a range of values.
+///
+/// This method parses the entire string, like [`parse`], but returns
+/// [`Error::OutOfRange`] if the value is below `min` or above `max`,
+/// or the value overflows or underflows the type. The error index is
+/// the start of the number. `NaN` is never within the range.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `min`     - Smallest valid value, inclusive.
+/// * `max`     - Largest valid value, inclusive.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_in_range::<u16>(b"8080", 1, 65535), Ok(8080));
+/// assert_eq!(lexical_core::parse_in_range::<u16>(b"0", 1, 65535), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical_core::parse_in_range::<u16>(b"70000", 1, 65535), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical_core::parse_in_range::<u16>(b"8O", 1, 65535), Err(Error::InvalidDigit(1)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_in_range<N: FromLexical + PartialOrd>(bytes: &[u8], min: N, max: N) -> Result<N> {
+    result::check_range(N::from_lexical(bytes), min, max)
+}
+
+/// Parse complete number from string with custom parsing options, within a range of values.
+///
+/// This method parses the entire string, like [`parse_with_options`],
+/// but returns [`Error::OutOfRange`] if the value is outside of the
+/// range. See [`parse_in_range`] for details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `min`     - Smallest valid value, inclusive.
+/// * `max`     - Largest valid value, inclusive.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Error;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let parse = |bytes| {
+///     lexical_core::parse_in_range_with_options::<f64, FORMAT>(bytes, 0.0, 1.0, &options)
+/// };
+/// assert_eq!(parse(b"0,25"), Ok(0.25));
+/// assert_eq!(parse(b"1,5"), Err(Error::OutOfRange(0)));
+/// assert_eq!(parse(b"NaN"), Err(Error::OutOfRange(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_in_range_with_options<N: FromLexicalWithOptions + PartialOrd, const FORMAT: u128>(
+    bytes: &[u8],
+    min: N,
+    max: N,
+    options: &N::Options,
+) -> Result<N> {
+    result::check_range(N::from_lexical_with_options::<FORMAT>(bytes, options), min, max)
+}
+
+/// Parse complete number from string, and convert it to another type.
+///
+/// This method parses the entire string as a `T`, like [`parse`], and
+/// converts the value to a `U` with [`TryFrom`], such as to narrow a
+/// `u64` to a smaller integer, or to a type that only accepts valid
+/// values. Parse errors are returned unchanged, and conversion failures
+/// return [`Error::ConversionFailed`]. The error index is the start of
+/// the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use core::convert::TryFrom;
+/// use lexical_core::Error;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Port(u16);
+///
+/// impl TryFrom<u64> for Port {
+///     type Error = ();
+///
+///     fn try_from(value: u64) -> Result<Self, ()> {
+///         match value {
+///             1..=65535 => Ok(Port(value as u16)),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(lexical_core::parse_then::<u64, Port>(b"8080"), Ok(Port(8080)));
+/// assert_eq!(lexical_core::parse_then::<u64, Port>(b"0"), Err(Error::ConversionFailed(0)));
+/// assert_eq!(lexical_core::parse_then::<u64, u8>(b"256"), Err(Error::ConversionFailed(0)));
+/// assert_eq!(lexical_core::parse_then::<u64, Port>(b"8O"), Err(Error::InvalidDigit(1)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_then<T: FromLexical, U: TryFrom<T>>(bytes: &[u8]) -> Result<U> {
+    result::try_convert(T::from_lexical(bytes))
+}
+
+/// Parse complete number from string with custom parsing options, and convert it to another type.
+///
+/// This method parses the entire string as a `T`, like
+/// [`parse_with_options`], and converts the value to a `U` with
+/// [`TryFrom`]. See [`parse_then`] for details.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let parse = |bytes| lexical_core::parse_then_with_options::<i64, u32, FORMAT>(bytes, &options);
+/// assert_eq!(parse(b"42"), Ok(42));
+/// assert_eq!(parse(b"-1"), Err(Error::ConversionFailed(0)));
+/// assert_eq!(parse(b"-"), Err(Error::Empty(1)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_then_with_options<T: FromLexicalWithOptions, U: TryFrom<T>, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &T::Options,
+) -> Result<U> {
+    result::try_convert(T::from_lexical_with_options::<FORMAT>(bytes, options))
+}
+
+/// Parse the magnitude and sign of an integer from string.
+///
+/// This parses as many digits as possible, like [`parse_partial`] for a
+/// signed integer, but returns the magnitude as a `u64` and the sign
+/// separately, along with the number of processed bytes. This allows
+/// custom numeric types, such as saturating counters, to apply their
+/// own sign and overflow semantics. Negative zero keeps its sign.
+///
+/// If the magnitude does not fit in a `u64`, returns [`Error::Overflow`]
+/// for positive numbers, and [`Error::Underflow`] for negative numbers.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Sign;
+///
+/// assert_eq!(lexical_core::parse_magnitude(b"-5 apples"), Ok((5, Sign::Negative, 2)));
+/// assert_eq!(lexical_core::parse_magnitude(b"+12"), Ok((12, Sign::Positive, 3)));
+/// assert_eq!(
+///     lexical_core::parse_magnitude(b"-18446744073709551615"),
+///     Ok((u64::MAX, Sign::Negative, 21))
+/// );
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-integers")]
+pub fn parse_magnitude(bytes: &[u8]) -> Result<(u64, Sign, usize)> {
+    parse_magnitude_with_options::<{ format::STANDARD }>(bytes, &ParseIntegerOptions::new())
+}
+
+/// Parse the magnitude and sign of an integer from string with custom parsing options.
+///
+/// This method is like [`parse_magnitude`], but uses a custom number
+/// format, such as for digit separators or base prefixes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "format"))] {
+/// use lexical_core::Sign;
+///
+/// const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+///     .digit_separator(core::num::NonZeroU8::new(b'_'))
+///     .integer_internal_digit_separator(true)
+///     .build();
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let result = lexical_core::parse_magnitude_with_options::<FORMAT>(b"-1_000", &options);
+/// assert_eq!(result, Ok((1000, Sign::Negative, 6)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-integers")]
+pub fn parse_magnitude_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    _: &ParseIntegerOptions,
+) -> Result<(u64, Sign, usize)> {
+    lexical_parse_integer::parse_magnitude::<FORMAT>(bytes)
+}
+
+/// Parse the exponent of a power of ten, such as `e-3` or `1E+6`, from string.
+///
+/// This parses an exponent-only form, such as for unit prefixes: an
+/// optional `1` mantissa, the `e` or `E` exponent character, and the
+/// signed, decimal exponent. The exponent can then be applied to a
+/// value with [`scale_by_pow10`], which, unlike multiplying by
+/// `10.0.powi(exponent)`, rounds only once.
+///
+/// * `bytes`   - Byte slice containing the power of ten.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_power_of_ten(b"e-3"), Ok(-3));
+/// assert_eq!(lexical_core::parse_power_of_ten(b"1E+6"), Ok(6));
+/// assert_eq!(lexical_core::parse_power_of_ten(b"10"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(lexical_core::parse_power_of_ten(b"1e"), Err(Error::EmptyExponent(2)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-integers")]
+pub fn parse_power_of_ten(bytes: &[u8]) -> Result<i32> {
+    lexical_parse_integer::parse_power_of_ten(bytes)
+}
+
+/// Scale a float by a power of ten, with correct rounding.
+///
+/// This returns the float nearest to `value * 10^exponent`, as if the
+/// exact product was written as a decimal string and parsed, using the
+/// same cached powers of ten as the parser. Multiplying by
+/// `10.0.powi(exponent)` instead rounds twice, once for the power of
+/// ten, which is inexact above `1e22`, and once for the product.
+///
+/// Special values and zeros are returned unchanged, and the sign is
+/// preserved if the result overflows or underflows.
+///
+/// * `value`       - Float to scale.
+/// * `exponent`    - Power of ten to scale by.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical_core::scale_by_pow10(2.5, -3), 0.0025);
+/// assert_eq!(lexical_core::scale_by_pow10(3.0, -310), 3e-310);
+/// assert_eq!(lexical_core::scale_by_pow10(-1.0, 400), f64::NEG_INFINITY);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn scale_by_pow10(value: f64, exponent: i32) -> f64 {
+    lexical_parse_float::scale::scale_by_pow10(value, exponent)
+}
+
+/// Parse a number from string as an integer, or as a float if it is not an integer.
+///
+/// Numbers without a fraction or exponent are parsed as [`Number::Int`]
+/// if they fit in an `i64`, or [`Number::UInt`] for positive numbers
+/// that fit in a `u64`. Any other number, including integers that
+/// overflow, special values, and negative zero, is parsed as
+/// [`Number::Float`]. This is the number model of JSON, TOML, and YAML
+/// implementations, and the digits are only scanned once, rather than
+/// parsing an integer and then a float if the integer overflows.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Number;
+///
+/// assert_eq!(lexical_core::parse_number(b"-42"), Ok(Number::Int(-42)));
+/// assert_eq!(lexical_core::parse_number(b"18446744073709551615"), Ok(Number::UInt(u64::MAX)));
+/// let result = lexical_core::parse_number(b"18446744073709551616");
+/// assert_eq!(result, Ok(Number::Float(1.8446744073709552e19)));
+/// assert_eq!(lexical_core::parse_number(b"2.5"), Ok(Number::Float(2.5)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_number(bytes: &[u8]) -> Result<Number> {
+    lexical_parse_float::mixed::parse_number(bytes)
+}
+
+/// Parse a number from string as an integer, or as a float, with custom parsing options.
+///
+/// This method is like [`parse_number`], but uses a custom number format
+/// and float parsing options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Number;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_number_with_options::<FORMAT>(b"1,5", &options);
+/// assert_eq!(result, Ok(Number::Float(1.5)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_number_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<Number> {
+    lexical_parse_float::mixed::parse_number_with_options::<FORMAT>(bytes, options)
+}
+
+/// Parse a float from the separated integer and fraction digits, and an exponent.
+///
+/// Tokenizers, such as for JSON documents, often split a number on the
+/// decimal point and the exponent character before converting it. This
+/// parses the float from the digits in place, without joining them into
+/// a single buffer, and with the same rounding as [`parse`] for the
+/// joined string: `parse_float_parts(b"-12", b"5", 3)` is the same as
+/// `parse(b"-12.5e3")`.
+///
+/// The integer digits may start with a sign, and either the integer or
+/// the fraction digits may be empty, but not both. Special values, such
+/// as `NaN`, are not supported. The index of an error is the index in the
+/// digits joined by a decimal point, so the first fraction digit is at
+/// `integer.len() + 1`.
+///
+/// * `integer`     - Byte slice containing the integer digits.
+/// * `fraction`    - Byte slice containing the fraction digits.
+/// * `exponent`    - Power of ten to scale the digits by.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_float_parts::<f64>(b"-12", b"5", 3), Ok(-12.5e3));
+/// assert_eq!(lexical_core::parse_float_parts::<f64>(b"", b"1", 0), Ok(0.1));
+/// let result = lexical_core::parse_float_parts::<f64>(b"9007199254740993", b"", 0);
+/// assert_eq!(result, Ok(9007199254740992.0));
+/// assert_eq!(lexical_core::parse_float_parts::<f32>(b"1", b"2x", 0), Err(Error::InvalidDigit(3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_float_parts<F: ParseFloat>(
+    integer: &[u8],
+    fraction: &[u8],
+    exponent: i32,
+) -> Result<F> {
+    let options = ParseFloatOptions::new();
+    parse_float_parts_with_options::<F, { format::STANDARD }>(integer, fraction, exponent, &options)
+}
+
+/// Parse a float from the separated integer and fraction digits, and an exponent, with custom parsing options.
+///
+/// This method is like [`parse_float_parts`], but uses the radix and
+/// the mantissa sign rules of a custom number format, and float parsing
+/// options. The exponent is a power of the exponent base of the format.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `integer`     - Byte slice containing the integer digits.
+/// * `fraction`    - Byte slice containing the fraction digits.
+/// * `exponent`    - Power of the exponent base to scale the digits by.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .flush_subnormals(true)
+///     .build()
+///     .unwrap();
+/// let parse = |integer, fraction, exponent| {
+///     lexical_core::parse_float_parts_with_options::<f64, FORMAT>(integer, fraction, exponent, &options)
+/// };
+/// assert_eq!(parse(b"1", b"5", -310), Ok(0.0));
+/// assert_eq!(parse(b"1", b"5", -300), Ok(1.5e-300));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_float_parts_with_options<F: ParseFloat, const FORMAT: u128>(
+    integer: &[u8],
+    fraction: &[u8],
+    exponent: i32,
+    options: &ParseFloatOptions,
+) -> Result<F> {
+    F::parse_parts::<FORMAT>(integer, fraction, exponent, options)
+}
+
+/// Parse the sign of a float from string, and detect special values.
+///
+/// This validates the sign and detects NaN and infinity like [`parse`]
+/// for a float, and returns the sign, the special value if any, and the
+/// remaining bytes. For finite numbers, the remaining bytes are the
+/// digits, so custom numeric types, such as decimal or big floats, can
+/// share the sign and special value handling before parsing the
+/// magnitude. For special values, the remaining bytes are the bytes
+/// after the special string.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{Sign, Special};
+///
+/// let result = lexical_core::parse_sign_special(b"-1.5");
+/// assert_eq!(result, Ok((Sign::Negative, None, &b"1.5"[..])));
+/// let result = lexical_core::parse_sign_special(b"-inf");
+/// assert_eq!(result, Ok((Sign::Negative, Some(Special::Infinity), &b""[..])));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_sign_special(bytes: &[u8]) -> Result<(Sign, Option<Special>, &[u8])> {
+    parse_sign_special_with_options::<{ format::STANDARD }>(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse the sign of a float from string, and detect special values with custom parsing options.
+///
+/// This method is like [`parse_sign_special`], but uses a custom number
+/// format, such as for required signs or case-sensitive special values,
+/// and custom special strings.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{Sign, Special};
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .nan_string(Some(b"NA"))
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_sign_special_with_options::<FORMAT>(b"NA", &options);
+/// assert_eq!(result, Ok((Sign::Positive, Some(Special::Nan), &b""[..])));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_sign_special_with_options<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &ParseFloatOptions,
+) -> Result<(Sign, Option<Special>, &'a [u8])> {
+    lexical_parse_float::parse_sign_special::<FORMAT>(bytes, options)
+}
+
+/// Parse a float from string with an optional radix prefix.
+///
+/// The radix is detected from the prefix, which is `0b` for binary, `0o`
+/// for octal, `0x` for hexadecimal, and no prefix for decimal, and is
+/// returned with the float. Prefixed floats have an optional exponent
+/// after `p`, which is a decimal power of two, like C's hexadecimal float
+/// literals, such as `0x1.8p1`. The sign comes before the prefix. This
+/// requires the `power-of-two` feature.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))] {
+/// use lexical_core::Radix;
+///
+/// assert_eq!(lexical_core::parse_prefixed::<f64>(b"0x1.8p1"), Ok((3.0, Radix::HEXADECIMAL)));
+/// assert_eq!(lexical_core::parse_prefixed::<f64>(b"-0b101.1p2"), Ok((-22.0, Radix::BINARY)));
+/// assert_eq!(lexical_core::parse_prefixed::<f32>(b"0o7.4p1"), Ok((15.0, Radix::OCTAL)));
+/// assert_eq!(lexical_core::parse_prefixed::<f64>(b"2.5e-1"), Ok((0.25, Radix::DECIMAL)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))]
+pub fn parse_prefixed<F>(bytes: &[u8]) -> Result<(F, Radix)>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    lexical_parse_float::prefixed::parse_prefixed(bytes)
+}
+
+/// Parse a float from string with an optional radix prefix, until an invalid digit is found.
+///
+/// This method is like [`parse_prefixed`], but returns the number of
+/// bytes processed, including the sign and prefix, like
+/// [`parse_partial`]. A prefix without any digits is an error.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))] {
+/// use lexical_core::Radix;
+///
+/// let result = lexical_core::parse_prefixed_partial::<f32>(b"0x1p-2, r0");
+/// assert_eq!(result, Ok((0.25, Radix::HEXADECIMAL, 6)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))]
+pub fn parse_prefixed_partial<F>(bytes: &[u8]) -> Result<(F, Radix, usize)>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    lexical_parse_float::prefixed::parse_prefixed_partial(bytes)
+}
+
+/// Parse number from string, skipping padding before and after it.
+///
+/// This method skips the leading and trailing bytes for which `skip`
+/// returns `true`, such as the `*` or spaces padding the fields of
+/// fixed-format files, and parses the remaining bytes like [`parse`].
+/// Padding is only skipped around the number, never within it, so
+/// `b"1 2"` is an invalid number even if spaces are skipped. Error
+/// indexes refer to the original bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `skip`    - Predicate for the padding bytes to skip.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let is_padding = |c: u8| c == b'*' || c == b' ';
+/// let result = lexical_core::parse_skipping::<i32>(b"** -15 *", is_padding);
+/// assert_eq!(result, Ok(-15));
+/// let result = lexical_core::parse_skipping::<i32>(b"**1*5", is_padding);
+/// assert_eq!(result, Err(lexical_core::Error::InvalidDigit(3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_skipping<N: FromLexical>(bytes: &[u8], skip: fn(u8) -> bool) -> Result<N> {
+    let (start, number) = ascii::trim_skipped(bytes, skip);
+    N::from_lexical(number).map_err(|error| shift_error(error, start))
+}
+
+/// Parse number from string with custom parsing options, skipping padding before and after it.
+///
+/// This method skips the leading and trailing bytes for which `skip`
+/// returns `true`, and parses the remaining bytes like
+/// [`parse_with_options`]. Padding is only skipped around the number,
+/// never within it. Error indexes refer to the original bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `skip`    - Predicate for the padding bytes to skip.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_skipping_with_options::<f64, FORMAT>(
+///     b"  3,5**",
+///     |c| c == b'*' || c == b' ',
+///     &options,
+/// );
+/// assert_eq!(result, Ok(3.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_skipping_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    skip: fn(u8) -> bool,
+    options: &N::Options,
+) -> Result<N> {
+    let (start, number) = ascii::trim_skipped(bytes, skip);
+    N::from_lexical_with_options::<FORMAT>(number, options)
+        .map_err(|error| shift_error(error, start))
+}
+
+/// Parse number from a NUL-terminated C string.
+///
+/// This method parses the entire string up to the NUL terminator,
+/// returning an error if any invalid digits are found, like [`parse`].
+/// A null pointer is treated as an empty string. This requires the
+/// `std` feature.
+///
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let string = b"3.5\0";
+/// let result = unsafe { lexical_core::parse_c_str::<f32>(string.as_ptr() as *const _) };
+/// assert_eq!(result, Ok(3.5_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub unsafe fn parse_c_str<N: FromLexical>(ptr: *const c_char) -> Result<N> {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    N::from_lexical(unsafe { c_str_to_bytes(ptr) })
+}
+
+/// Parse partial number from a NUL-terminated C string.
+///
+/// This method parses until an invalid digit or the NUL terminator is
+/// found, like [`parse_partial`], returning the number of processed
+/// digits and the parsed value until that point. This can be used to
+/// replace `strtod` or `strtol`, where the end pointer is `ptr + count`.
+///
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let string = b"15 apples\0";
+/// let result = unsafe { lexical_core::parse_partial_c_str::<u32>(string.as_ptr() as *const _) };
+/// assert_eq!(result, Ok((15, 2)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub unsafe fn parse_partial_c_str<N: FromLexical>(ptr: *const c_char) -> Result<(N, usize)> {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    N::from_lexical_partial(unsafe { c_str_to_bytes(ptr) })
+}
+
+/// Parse number from a NUL-terminated C string with custom parsing options.
+///
+/// This is like [`parse_c_str`], but uses a custom number format and options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let string = b"3,5\0";
+/// let result = unsafe {
+///     lexical_core::parse_c_str_with_options::<f32, FORMAT>(string.as_ptr() as *const _, &options)
+/// };
+/// assert_eq!(result, Ok(3.5_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub unsafe fn parse_c_str_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    ptr: *const c_char,
+    options: &N::Options,
+) -> Result<N> {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    N::from_lexical_with_options::<FORMAT>(unsafe { c_str_to_bytes(ptr) }, options)
+}
+
+/// Parse partial number from a NUL-terminated C string with custom parsing options.
+///
+/// This is like [`parse_partial_c_str`], but uses a custom number format and options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = b"-1.5e3;\0";
+/// let result = unsafe {
+///     lexical_core::parse_partial_c_str_with_options::<f64, FORMAT>(
+///         string.as_ptr() as *const _,
+///         &options,
+///     )
+/// };
+/// assert_eq!(result, Ok((-1500.0, 6)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub unsafe fn parse_partial_c_str_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    ptr: *const c_char,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    N::from_lexical_partial_with_options::<FORMAT>(unsafe { c_str_to_bytes(ptr) }, options)
+}
+
+/// Parse a float from a NUL-terminated C string like `strtod`, without `errno`.
+///
+/// Leading whitespace is skipped, and the longest prefix that forms a
+/// float is parsed. Returns the value, the end pointer after the parsed
+/// bytes, and the status that `strtod` reports through `errno`. Unlike
+/// `strtod`, this is independent of the C locale, so the decimal point
+/// is always `.`, and hexadecimal floats are not supported, so `0x1p3`
+/// is parsed as `0`.
+///
+/// If no number is found, returns `0.0` and the start of the string,
+/// with [`StrtodStatus::NoConversion`]. If the number is too large,
+/// returns infinity, and if it is too small, returns zero or a subnormal
+/// float, with [`StrtodStatus::OutOfRange`], like `ERANGE` for glibc.
+/// NaN may be followed by a parenthesized sequence of letters, digits
+/// and underscores, which is skipped. A null pointer is treated as an
+/// empty string. This requires the `std` feature.
+///
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::StrtodStatus;
+///
+/// let string = b"  1.5e3 apples\0";
+/// let ptr = string.as_ptr() as *const _;
+/// let (value, end, status) = unsafe { lexical_core::strtod_compat(ptr) };
+/// assert_eq!(value, 1500.0);
+/// assert_eq!(end, unsafe { ptr.add(7) });
+/// assert_eq!(status, StrtodStatus::Success);
+///
+/// let string = b"1e999\0";
+/// let (value, _, status) = unsafe { lexical_core::strtod_compat(string.as_ptr() as *const _) };
+/// assert_eq!(value, f64::INFINITY);
+/// assert_eq!(status, StrtodStatus::OutOfRange);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub unsafe fn strtod_compat(ptr: *const c_char) -> (f64, *const c_char, StrtodStatus) {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    unsafe { strtod(ptr) }
+}
+
+/// Parse a float from a NUL-terminated C string like `strtof`, without `errno`.
+///
+/// This is like [`strtod_compat`], but parses an `f32`, so the range
+/// is checked for an `f32`.
+///
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::StrtodStatus;
+///
+/// let string = b"1e-50\0";
+/// let (value, _, status) = unsafe { lexical_core::strtof_compat(string.as_ptr() as *const _) };
+/// assert_eq!(value, 0.0);
+/// assert_eq!(status, StrtodStatus::OutOfRange);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub unsafe fn strtof_compat(ptr: *const c_char) -> (f32, *const c_char, StrtodStatus) {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    unsafe { strtod(ptr) }
+}
+
+/// Parse number from string, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse`], but first replaces the `U+2212 MINUS SIGN`
+/// with `-`, and removes non-breaking spaces between digits, which are
+/// common in data copy-pasted from documents. The spaces may be
+/// `U+00A0 NO-BREAK SPACE`, `U+202F NARROW NO-BREAK SPACE`, or a
+/// Windows-1252 `0xA0` byte. Error indexes refer to the original bytes.
+///
+/// This requires the `unicode` feature.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "unicode"))] {
+/// let string = "\u{2212}1\u{a0}234";
+/// let result = lexical_core::parse_unicode::<i32>(string.as_bytes());
+/// assert_eq!(result, Ok(-1234));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_unicode<N: FromLexical>(bytes: &[u8]) -> Result<N> {
+    match Normalized::new::<{ format::STANDARD }>(bytes) {
+        Some(normalized) => {
+            N::from_lexical(normalized.as_slice()).map_err(|e| normalized.map_error(e))
+        },
+        None => N::from_lexical(bytes),
+    }
+}
+
+/// Parse partial number from string, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_partial`], but normalizes the string like
+/// [`parse_unicode`]. The number of processed bytes refers to the
+/// original bytes.
+///
+/// This requires the `unicode` feature.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "unicode"))] {
+/// let string = "\u{2212}1.5 m";
+/// let result = lexical_core::parse_partial_unicode::<f64>(string.as_bytes());
+/// assert_eq!(result, Ok((-1.5, 6)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_partial_unicode<N: FromLexical>(bytes: &[u8]) -> Result<(N, usize)> {
+    match Normalized::new::<{ format::STANDARD }>(bytes) {
+        Some(normalized) => N::from_lexical_partial(normalized.as_slice())
+            .map(|(value, count)| (value, normalized.original_index(count)))
+            .map_err(|e| normalized.map_error(e)),
+        None => N::from_lexical_partial(bytes),
+    }
+}
+
+/// Parse number from string with custom parsing options, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_unicode`], but uses a custom number format and
+/// options. If the format has a digit separator, non-breaking spaces
+/// are replaced with it, so the format determines where they are valid.
+///
+/// This requires the `unicode` feature.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "unicode"))] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let string = "\u{2212}1\u{202f}234,5";
+/// let result = lexical_core::parse_unicode_with_options::<f64, FORMAT>(string.as_bytes(), &options);
+/// assert_eq!(result, Ok(-1234.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_unicode_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<N> {
+    match Normalized::new::<FORMAT>(bytes) {
+        Some(normalized) => N::from_lexical_with_options::<FORMAT>(normalized.as_slice(), options)
+            .map_err(|e| normalized.map_error(e)),
+        None => N::from_lexical_with_options::<FORMAT>(bytes, options),
+    }
+}
+
+/// Parse partial number from string with custom parsing options, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_partial_unicode`], but uses a custom number
+/// format and options.
+///
+/// This requires the `unicode` feature.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "unicode"))] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let string = "\u{2212}12;";
+/// let result =
+///     lexical_core::parse_partial_unicode_with_options::<i32, FORMAT>(string.as_bytes(), &options);
+/// assert_eq!(result, Ok((-12, 5)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_partial_unicode_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    match Normalized::new::<FORMAT>(bytes) {
+        Some(normalized) => {
+            N::from_lexical_partial_with_options::<FORMAT>(normalized.as_slice(), options)
+                .map(|(value, count)| (value, normalized.original_index(count)))
+                .map_err(|e| normalized.map_error(e))
+        },
+        None => N::from_lexical_partial_with_options::<FORMAT>(bytes, options),
+    }
+}
+
+/// Parse a finite float from string, and determine if it's integral.
+///
+/// This is useful for formats such as JSON, which do not distinguish
+/// between integers and floats, to store numbers as the integer `I`
+/// when possible. Unlike [`parse`], special values such as `NaN` or
+/// `inf` are rejected, as are values too large to be finite. The
+/// returned flag is `true` if the float has no fractional part, and is
+/// within the range of `I`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let result = lexical_core::parse_integral::<f64, i64>(b"1.5e3");
+/// assert_eq!(result, Ok((1500.0, true)));
+/// let result = lexical_core::parse_integral::<f64, i64>(b"1.5");
+/// assert_eq!(result, Ok((1.5, false)));
+/// let result = lexical_core::parse_integral::<f64, i64>(b"1e20");
+/// assert_eq!(result, Ok((1e20, false)));
+/// let result = lexical_core::parse_integral::<f64, i64>(b"NaN");
+/// assert_eq!(result, Err(lexical_core::Error::InvalidDigit(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_integral<F, I>(bytes: &[u8]) -> Result<(F, bool)>
+where
+    F: FromLexical + Float,
+    I: Integer,
+{
+    let value = F::from_lexical(bytes)?;
+    check_integral::<F, I, { format::STANDARD }>(bytes, value)
+}
+
+/// Parse a finite float from string with custom parsing options,
+/// and determine if it's integral.
+///
+/// This is like [`parse_integral`], but uses a custom number format
+/// and options. Custom special strings are rejected, unless they
+/// start with a valid digit.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let result =
+///     lexical_core::parse_integral_with_options::<f64, u8, FORMAT>(b"255", &options);
+/// assert_eq!(result, Ok((255.0, true)));
+/// let result =
+///     lexical_core::parse_integral_with_options::<f64, u8, FORMAT>(b"-1", &options);
+/// assert_eq!(result, Ok((-1.0, false)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_integral_with_options<F, I, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &F::Options,
+) -> Result<(F, bool)>
+where
+    F: FromLexicalWithOptions + Float,
+    I: Integer,
+{
+    let value = F::from_lexical_with_options::<FORMAT>(bytes, options)?;
+    check_integral::<F, I, FORMAT>(bytes, value)
+}
+
+/// Write the canonical form of a decimal numeric string.
+///
+/// This rewrites the number without converting it to a float, so equal
+/// values have the same canonical form regardless of formatting, which
+/// is useful to deduplicate numeric strings. The canonical form uses
+/// scientific notation, with a single leading digit, no trailing zeros
+/// in the fraction, and a lowercase `e`, which is omitted if the
+/// exponent is 0. Zero is always written as `0`, without a sign.
+/// Special values, such as `NaN`, are rejected.
+///
+/// Returns the number of bytes written to `buffer`, or
+/// [`Error::BufferTooSmall`] with the required length if the buffer is
+/// too small for the canonical form. A buffer that is 24 bytes longer
+/// than `bytes` is always large enough.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the canonical form to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let mut buffer = [0u8; 64];
+/// let count = lexical_core::normalize(b"-0012.50E+1", &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"-1.25e2");
+/// let count = lexical_core::normalize(b"1000", &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"1e3");
+/// let count = lexical_core::normalize(b"-0.0", &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"0");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn normalize(bytes: &[u8], buffer: &mut [u8]) -> Result<usize> {
+    normalize_with_options::<{ format::STANDARD }>(bytes, buffer, &ParseFloatOptions::new())
+}
+
+/// Write the canonical form of a decimal numeric string with custom parsing options.
+///
+/// This is like [`normalize`], but uses a custom number format and the
+/// exponent and decimal point characters from the options. The format
+/// must be decimal, and its digit separator is ignored within digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the canonical form to.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; 64];
+/// let result =
+///     lexical_core::normalize_with_options::<FORMAT>(b"0,0250", &mut buffer, &options);
+/// assert_eq!(&buffer[..result.unwrap()], b"2.5e-2");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn normalize_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    buffer: &mut [u8],
+    options: &ParseFloatOptions,
+) -> Result<usize> {
+    let number = NumericStr::parse::<FORMAT>(bytes, options.exponent(), options.decimal_point())?;
+    number.write(buffer)
+}
+
+/// Round a decimal numeric string to a fixed number of fractional digits.
+///
+/// The number is rounded exactly in the decimal domain, without
+/// converting it to a float, so amounts like `1.005` round as written,
+/// which is required for monetary values. The result is written in
+/// fixed-point notation with exactly `decimals` fractional digits, and
+/// without a decimal point if `decimals` is 0. Numbers that round to
+/// zero are written without a sign. Special values, such as `NaN`, are
+/// rejected.
+///
+/// Returns the number of bytes written to `buffer`, or
+/// [`Error::BufferTooSmall`] with the required length if the buffer is
+/// too small for the rounded number, which has at most
+/// `max(exponent, 0) + decimals + 4` bytes, where `exponent` is the
+/// decimal exponent of the first significant digit.
+///
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `decimals` - Number of fractional digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+/// * `buffer`   - Buffer to write the rounded number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Rounding;
+///
+/// let mut buffer = [0u8; 64];
+/// let count = lexical_core::quantize(b"1.005", 2, Rounding::HalfUp, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"1.01");
+/// let count = lexical_core::quantize(b"1.005", 2, Rounding::HalfEven, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"1.00");
+/// let count = lexical_core::quantize(b"-9.995e1", 1, Rounding::Floor, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..count], b"-100.0");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn quantize(
+    bytes: &[u8],
+    decimals: usize,
+    rounding: Rounding,
+    buffer: &mut [u8],
+) -> Result<usize> {
+    let options = ParseFloatOptions::new();
+    quantize_with_options::<{ format::STANDARD }>(bytes, decimals, rounding, buffer, &options)
+}
+
+/// Round a decimal numeric string to fixed fractional digits with custom parsing options.
+///
+/// This is like [`quantize`], but uses a custom number format and the
+/// exponent and decimal point characters from the options. The format
+/// must be decimal, and its digit separator is ignored within digits.
+/// The rounded number is always written with a `.` decimal point.
+///
+/// * `FORMAT`   - Packed struct containing the number format.
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `decimals` - Number of fractional digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+/// * `buffer`   - Buffer to write the rounded number to.
+/// * `options`  - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Rounding;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; 64];
+/// let result = lexical_core::quantize_with_options::<FORMAT>(
+///     b"2,675",
+///     2,
+///     Rounding::HalfEven,
+///     &mut buffer,
+///     &options,
+/// );
+/// assert_eq!(&buffer[..result.unwrap()], b"2.68");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn quantize_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    decimals: usize,
+    rounding: Rounding,
+    buffer: &mut [u8],
+    options: &ParseFloatOptions,
+) -> Result<usize> {
+    let number = NumericStr::parse::<FORMAT>(bytes, options.exponent(), options.decimal_point())?;
+    number.quantize(decimals, rounding, buffer)
+}
+
+/// Parse a float from a decimal string, rounded to a number of decimal places.
+///
+/// The digits are rounded exactly in the decimal domain, to a multiple
+/// of `10^-places`, and the rounded number is then parsed to the nearest
+/// float, in a single pass. This is required for sample values that must
+/// round ties half-even before conversion, such as in metering systems,
+/// since rounding the parsed float rounds twice: the nearest `f64` to
+/// `2.675` is below the tie, so it would round down to `2.67`. Numbers
+/// that round to zero keep their sign, and special values, such as
+/// `NaN`, are parsed without rounding.
+///
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `places`   - Number of fractional digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Rounding;
+///
+/// assert_eq!(lexical_core::parse_rounded::<f64>(b"2.675", 2, Rounding::HalfEven), Ok(2.68));
+/// assert_eq!(lexical_core::parse_rounded::<f64>(b"2.665", 2, Rounding::HalfEven), Ok(2.66));
+/// assert_eq!(lexical_core::parse_rounded::<f32>(b"-1.25", 1, Rounding::Floor), Ok(-1.3));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_rounded<F>(bytes: &[u8], places: usize, rounding: Rounding) -> Result<F>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    let options = ParseFloatOptions::new();
+    parse_rounded_with_options::<F, { format::STANDARD }>(bytes, places, rounding, &options)
+}
+
+/// Parse a float from a decimal string, rounded to decimal places with custom parsing options.
+///
+/// This is like [`parse_rounded`], but uses a custom number format and
+/// the options. The format must be decimal.
+///
+/// * `FORMAT`   - Packed struct containing the number format.
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `places`   - Number of fractional digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+/// * `options`  - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::Rounding;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_rounded_with_options::<f64, FORMAT>(
+///     b"0,0625",
+///     3,
+///     Rounding::HalfEven,
+///     &options,
+/// );
+/// assert_eq!(result, Ok(0.062));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_rounded_with_options<F, const FORMAT: u128>(
+    bytes: &[u8],
+    places: usize,
+    rounding: Rounding,
+    options: &ParseFloatOptions,
+) -> Result<F>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    lexical_parse_float::rounded::parse_rounded::<F, FORMAT>(bytes, places, rounding, options)
+}
+
+/// Parse a float from a decimal string, rounded to a number of significant digits.
+///
+/// The digits are rounded exactly in the decimal domain, to `digits`
+/// significant digits, and the rounded number is then parsed to the
+/// nearest float. This emulates floats that were stored as text with
+/// fewer digits, such as 7 significant digits for single-precision
+/// values in legacy systems, so the parsed float matches the stored
+/// value bit-for-bit. Numbers that round to zero keep their sign, and
+/// special values, such as `NaN`, are parsed without rounding.
+///
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `digits`   - Number of significant digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use core::num::NonZeroUsize;
+/// use lexical_core::Rounding;
+///
+/// let digits = NonZeroUsize::new(4).unwrap();
+/// let result = lexical_core::parse_significant::<f64>(b"3.14159265", digits, Rounding::HalfUp);
+/// assert_eq!(result, Ok(3.142));
+/// let result = lexical_core::parse_significant::<f64>(b"9.9999", digits, Rounding::HalfEven);
+/// assert_eq!(result, Ok(10.0));
+/// let result = lexical_core::parse_significant::<f32>(b"-123456", digits, Rounding::Floor);
+/// assert_eq!(result, Ok(-123500.0));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_significant<F>(bytes: &[u8], digits: NonZeroUsize, rounding: Rounding) -> Result<F>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    let options = ParseFloatOptions::new();
+    parse_significant_with_options::<F, { format::STANDARD }>(bytes, digits, rounding, &options)
+}
+
+/// Parse a float from a decimal string, rounded to significant digits with custom parsing options.
+///
+/// This is like [`parse_significant`], but uses a custom number format
+/// and the options. The format must be decimal.
+///
+/// * `FORMAT`   - Packed struct containing the number format.
+/// * `bytes`    - Byte slice containing a numeric string.
+/// * `digits`   - Number of significant digits to round to.
+/// * `rounding` - Rounding mode for the truncated digits.
+/// * `options`  - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use core::num::NonZeroUsize;
+/// use lexical_core::Rounding;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_significant_with_options::<f64, FORMAT>(
+///     b"0,00123456",
+///     NonZeroUsize::new(3).unwrap(),
+///     Rounding::HalfEven,
+///     &options,
+/// );
+/// assert_eq!(result, Ok(0.00123));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_significant_with_options<F, const FORMAT: u128>(
+    bytes: &[u8],
+    digits: NonZeroUsize,
+    rounding: Rounding,
+    options: &ParseFloatOptions,
+) -> Result<F>
+where
+    F: FromFloatWithOptions<Options = ParseFloatOptions> + Float,
+{
+    lexical_parse_float::rounded::parse_significant::<F, FORMAT>(bytes, digits, rounding, options)
+}
+
+/// Parse a complete float from bytes, and zeroize the digits of the slow path.
+///
+/// This is like [`parse`], but the big integers used for the significant
+/// digits of numbers that need the slow path, such as numbers with many
+/// digits, are cleared before returning, even if parsing failed. The
+/// entire buffers are cleared, so the time taken doesn't depend on the
+/// number of digits. The input and the parsed float are not cleared.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "zeroize")] {
+/// let bytes = b"9007199254740993.0000000000000000000000000000001";
+/// assert_eq!(lexical_core::parse_zeroizing::<f64>(bytes), Ok(9007199254740994.0));
+/// assert_eq!(lexical_core::parse_zeroizing::<f32>(b"1.5"), Ok(1.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "zeroize")]
+pub fn parse_zeroizing<F: ParseFloat>(bytes: &[u8]) -> Result<F> {
+    let options = ParseFloatOptions::new();
+    parse_zeroizing_with_options::<F, { format::STANDARD }>(bytes, &options)
+}
+
+/// Parse a complete float from bytes with custom parsing options, and zeroize the slow path.
+///
+/// This is like [`parse_with_options`], but the big integers used for
+/// the significant digits in the slow path are cleared before returning.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "zeroize")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_zeroizing_with_options::<f64, FORMAT>(b"1,25", &options);
+/// assert_eq!(result, Ok(1.25));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "zeroize")]
+pub fn parse_zeroizing_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<F> {
+    lexical_parse_float::zeroizing::parse_zeroizing::<F, FORMAT>(bytes, options)
+}
+
+/// Parse a partial float from bytes, and zeroize the digits of the slow path.
+///
+/// This is like [`parse_partial`], but the big integers used for the
+/// significant digits in the slow path are cleared before returning.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "zeroize")] {
+/// assert_eq!(lexical_core::parse_partial_zeroizing::<f64>(b"2.5,1"), Ok((2.5, 3)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "zeroize")]
+pub fn parse_partial_zeroizing<F: ParseFloat>(bytes: &[u8]) -> Result<(F, usize)> {
+    let options = ParseFloatOptions::new();
+    parse_partial_zeroizing_with_options::<F, { format::STANDARD }>(bytes, &options)
+}
+
+/// Parse a partial float from bytes with custom parsing options, and zeroize the slow path.
+///
+/// This is like [`parse_partial_with_options`], but the big integers used
+/// for the significant digits in the slow path are cleared before returning.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "zeroize")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let result = lexical_core::parse_partial_zeroizing_with_options::<f64, FORMAT>(
+///     b"1e-3;",
+///     &options,
+/// );
+/// assert_eq!(result, Ok((1e-3, 4)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "zeroize")]
+pub fn parse_partial_zeroizing_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(F, usize)> {
+    lexical_parse_float::zeroizing::parse_partial_zeroizing::<F, FORMAT>(bytes, options)
+}
+
+/// Parse a complete float from bytes, and encode it in the canonical binary encoding.
+///
+/// This is like [`parse`], but the float is written to the buffer in the
+/// encoding of the [`varint`] module, which is independent of the
+/// endianness of the target and of the float type, and can be decoded
+/// exactly with [`varint::decode_float`]. Returns the encoded bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the encoded float to.
+///
+/// # Errors
+///
+/// Returns the error from parsing the float, or [`Error::BufferTooSmall`]
+/// with the required length if the buffer is smaller than the encoded
+/// float. A buffer of [`VARINT_BUFFER_SIZE`] bytes is always large enough.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "varint")] {
+/// use lexical_core::varint;
+///
+/// let mut buffer = [0u8; varint::VARINT_BUFFER_SIZE];
+/// let encoded = lexical_core::parse_varint::<f64>(b"-1.5", &mut buffer).unwrap();
+/// assert_eq!(encoded, &[0x03, 0x01, 0x03]);
+/// assert_eq!(varint::decode_float::<f32>(encoded), Ok((-1.5, 3)));
+/// # }
+/// # }
+/// ```
+///
+/// [`VARINT_BUFFER_SIZE`]: varint::VARINT_BUFFER_SIZE
+#[inline]
+#[cfg(feature = "varint")]
+pub fn parse_varint<'a, F>(bytes: &[u8], buffer: &'a mut [u8]) -> Result<&'a mut [u8]>
+where
+    F: ParseFloat + FromLexicalWithOptions<Options = ParseFloatOptions>,
+{
+    let options = ParseFloatOptions::new();
+    parse_varint_with_options::<F, { format::STANDARD }>(bytes, buffer, &options)
+}
+
+/// Parse a complete float from bytes with custom parsing options, and encode it in the canonical binary encoding.
+///
+/// This is like [`parse_with_options`], but the float is written to the
+/// buffer in the encoding of the [`varint`] module, so the text is only
+/// validated and parsed once. Returns the encoded bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `buffer`  - Buffer to write the encoded float to.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns the error from parsing the float, or [`Error::BufferTooSmall`]
+/// with the required length if the buffer is smaller than the encoded
+/// float. A buffer of [`VARINT_BUFFER_SIZE`] bytes is always large enough.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "varint")] {
+/// use lexical_core::varint;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; varint::VARINT_BUFFER_SIZE];
+/// let encoded =
+///     lexical_core::parse_varint_with_options::<f32, FORMAT>(b"0,25", &mut buffer, &options);
+/// assert_eq!(encoded.map(|x| &*x), Ok(&[0x02, 0x03, 0x01][..]));
+/// # }
+/// # }
+/// ```
+///
+/// [`VARINT_BUFFER_SIZE`]: varint::VARINT_BUFFER_SIZE
+#[inline]
+#[cfg(feature = "varint")]
+pub fn parse_varint_with_options<'a, F, const FORMAT: u128>(
+    bytes: &[u8],
+    buffer: &'a mut [u8],
+    options: &ParseFloatOptions,
+) -> Result<&'a mut [u8]>
+where
+    F: ParseFloat + FromLexicalWithOptions<Options = ParseFloatOptions>,
+{
+    let float = parse_with_options::<F, FORMAT>(bytes, options)?;
+    let count = varint::try_encode_float(float, buffer)?;
+    Ok(&mut buffer[..count])
+}
+
+/// Compare the values of two decimal numeric strings exactly.
+///
+/// This compares the numbers without converting them to floats, so
+/// there is no loss of precision, regardless of the number of digits
+/// or the magnitude of the exponents. For example, `1e3` is equal to
+/// `1000.0`, and `0.1000000000000000000001` is greater than `0.1`.
+/// Zeros are equal regardless of sign, and special values, such as
+/// `NaN`, are rejected.
+///
+/// If either string is not a valid number, the error for the first
+/// invalid string is returned.
+///
+/// * `x`   - Byte slice containing the first numeric string.
+/// * `y`   - Byte slice containing the second numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(lexical_core::cmp_numeric(b"1e3", b"1000.0"), Ok(Ordering::Equal));
+/// assert_eq!(lexical_core::cmp_numeric(b"-2", b"-10"), Ok(Ordering::Greater));
+/// assert_eq!(
+///     lexical_core::cmp_numeric(b"0.1000000000000000000001", b"0.1"),
+///     Ok(Ordering::Greater)
+/// );
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn cmp_numeric(x: &[u8], y: &[u8]) -> Result<Ordering> {
+    cmp_numeric_with_options::<{ format::STANDARD }>(x, y, &ParseFloatOptions::new())
+}
+
+/// Compare the values of two decimal numeric strings exactly, with custom parsing options.
+///
+/// This is like [`cmp_numeric`], but uses a custom number format and the
+/// exponent and decimal point characters from the options. The format
+/// must be decimal, and its digit separator is ignored within digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `x`       - Byte slice containing the first numeric string.
+/// * `y`       - Byte slice containing the second numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use core::cmp::Ordering;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::cmp_numeric_with_options::<FORMAT>(b"1,5", b"1,25", &options);
+/// assert_eq!(result, Ok(Ordering::Greater));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn cmp_numeric_with_options<const FORMAT: u128>(
+    x: &[u8],
+    y: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<Ordering> {
+    let exponent = options.exponent();
+    let decimal_point = options.decimal_point();
+    let x = NumericStr::parse::<FORMAT>(x, exponent, decimal_point)?;
+    let y = NumericStr::parse::<FORMAT>(y, exponent, decimal_point)?;
+    Ok(x.compare(&y))
+}
+
+/// Hash the value of a decimal numeric string.
+///
+/// Numbers with equal values hash equally, regardless of formatting,
+/// so `1e3`, `1000`, and `+1000.00` produce identical hashes. This uses
+/// the same rules as [`normalize`] and [`cmp_numeric`], without
+/// converting the number to a float, which is useful to deduplicate
+/// numeric strings. Special values, such as `NaN`, are rejected.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `state`   - Hasher to feed the value into.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "std"))] {
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// let hash = |bytes: &[u8]| {
+///     let mut hasher = DefaultHasher::new();
+///     lexical_core::hash_numeric(bytes, &mut hasher).unwrap();
+///     hasher.finish()
+/// };
+/// assert_eq!(hash(b"1e3"), hash(b"1000.0"));
+/// assert_ne!(hash(b"1e3"), hash(b"1001"));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn hash_numeric<H: Hasher>(bytes: &[u8], state: &mut H) -> Result<()> {
+    hash_numeric_with_options::<H, { format::STANDARD }>(bytes, state, &ParseFloatOptions::new())
+}
+
+/// Hash the value of a decimal numeric string with custom parsing options.
+///
+/// This is like [`hash_numeric`], but uses a custom number format and the
+/// exponent and decimal point characters from the options. The format
+/// must be decimal, and its digit separator is ignored within digits.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `state`   - Hasher to feed the value into.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "std"))] {
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let hash = |bytes: &[u8]| {
+///     let mut hasher = DefaultHasher::new();
+///     lexical_core::hash_numeric_with_options::<_, FORMAT>(bytes, &mut hasher, &options)
+///         .unwrap();
+///     hasher.finish()
+/// };
+/// assert_eq!(hash(b"1,50"), hash(b"15e-1"));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn hash_numeric_with_options<H: Hasher, const FORMAT: u128>(
+    bytes: &[u8],
+    state: &mut H,
+    options: &ParseFloatOptions,
+) -> Result<()> {
+    let number = NumericStr::parse::<FORMAT>(bytes, options.exponent(), options.decimal_point())?;
+    number.hash_value(state);
+    Ok(())
+}
+
+/// Parse a complete decimal string to the nearest posit.
+///
+/// Ties round to the posit with an even bit pattern, using the exact
+/// value of the string. Non-zero numbers are clamped to the smallest and
+/// largest positive posits, rather than rounding to zero or NaR, while
+/// NaN and infinity are parsed as NaR.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "parse-floats"))] {
+/// use lexical_core::{Posit16, Posit32};
+///
+/// assert_eq!(lexical_core::parse_posit::<Posit16>(b"1"), Ok(Posit16::from_bits(0x4000)));
+/// assert_eq!(lexical_core::parse_posit::<Posit32>(b"1e300"), Ok(Posit32::MAX));
+/// assert_eq!(lexical_core::parse_posit::<Posit16>(b"NaN"), Ok(Posit16::NAR));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "parse-floats"))]
+pub fn parse_posit<P: Posit>(bytes: &[u8]) -> Result<P> {
+    parse_posit_with_options::<P, { format::STANDARD }>(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse a complete decimal string to the nearest posit with custom parsing options.
+///
+/// This is like [`parse_posit`], but uses a custom number format and
+/// parsing options. The format must be decimal.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "parse-floats"))] {
+/// use lexical_core::Posit16;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_posit_with_options::<Posit16, FORMAT>(b"0,5", &options);
+/// assert_eq!(result, Ok(Posit16::from_bits(0x3800)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "parse-floats"))]
+pub fn parse_posit_with_options<P: Posit, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<P> {
+    lexical_parse_float::posit::parse_posit::<P, FORMAT>(bytes, options)
+}
+
+/// Write a posit to a decimal string, with the shortest representation.
+///
+/// The posit is written with the fewest significant digits that parse
+/// back to the same posit. Zero is written as `0.0`, and NaR as `NaN`.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Posit to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is the same
+/// as for an `f64`.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "write-floats"))] {
+/// use lexical_core::{Posit16, BUFFER_SIZE};
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let posit = Posit16::from_bits(0x4001);
+/// assert_eq!(lexical_core::write_posit(posit, &mut buffer), b"1.0005");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "write-floats"))]
+pub fn write_posit<P: Posit>(value: P, bytes: &mut [u8]) -> &mut [u8] {
+    write_posit_with_options::<P, { format::STANDARD }>(value, bytes, &WriteFloatOptions::new())
+}
+
+/// Write a posit to a decimal string with custom writing options.
+///
+/// This is like [`write_posit`], but uses a custom number format and
+/// writing options. The maximum significant digits in the options may
+/// shorten the digits further, so the posit may no longer round-trip.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Posit to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid or not decimal.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "write-floats"))] {
+/// use lexical_core::{Posit32, BUFFER_SIZE};
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let posit = Posit32::from_f64(0.1);
+/// let digits = lexical_core::write_posit_with_options::<_, FORMAT>(posit, &mut buffer, &options);
+/// assert_eq!(digits, b"0,1");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "write-floats"))]
+pub fn write_posit_with_options<'a, P: Posit, const FORMAT: u128>(
+    value: P,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> &'a mut [u8] {
+    lexical_write_float::posit::write_posit::<P, FORMAT>(value, bytes, options)
+}
+
+/// Parse a complete decimal string to the nearest value of a custom float.
+///
+/// This reuses the correct float parser for third-party float types,
+/// such as software floats, which describe their layout by implementing
+/// [`LexicalFloat`]. Ties round to even, using the exact value of the
+/// string, and numbers overflow to infinity or underflow to zero.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// // The nearest `f64` is exactly halfway between two `f32`s.
+/// let bytes = b"1.0000000596046447753906250000000001";
+/// assert_eq!(lexical_core::parse_custom_float::<f32>(bytes), Ok(1.0000001));
+/// assert_eq!(lexical_core::parse_custom_float::<f32>(b"1e39"), Ok(f32::INFINITY));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_custom_float<F: LexicalFloat>(bytes: &[u8]) -> Result<F> {
+    parse_custom_float_with_options::<F, { format::STANDARD }>(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse a complete decimal string to the nearest value of a custom float with custom parsing options.
+///
+/// This is like [`parse_custom_float`], but uses a custom number format
+/// and parsing options. The format must be decimal, and the `lossy`
+/// option is ignored, since it would not round correctly.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_custom_float_with_options::<f32, FORMAT>(b"0,1", &options);
+/// assert_eq!(result, Ok(0.1));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_custom_float_with_options<F: LexicalFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<F> {
+    lexical_parse_float::custom::parse_custom::<F, FORMAT>(bytes, options)
+}
+
+/// Parse a complete decimal string to the nearest double-double number.
+///
+/// Returns the high and low parts of the unevaluated sum `hi + lo`, with
+/// about 106 bits of precision, as used by double-double libraries. The
+/// high part is the nearest `f64`, and the low part is the nearest `f64`
+/// to the exact remainder, so both parts are correctly rounded. Special
+/// values, zeros, and numbers that overflow have a low part of zero.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let result = lexical_core::parse_double_double(b"0.1");
+/// assert_eq!(result, Ok((0.1, -5.551115123125783e-18)));
+/// let pi = b"3.14159265358979323846264338327950288";
+/// let result = lexical_core::parse_double_double(pi);
+/// assert_eq!(result, Ok((3.141592653589793, 1.2246467991473532e-16)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_double_double(bytes: &[u8]) -> Result<(f64, f64)> {
+    parse_double_double_with_options::<{ format::STANDARD }>(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse a complete decimal string to the nearest double-double number with custom parsing options.
+///
+/// This is like [`parse_double_double`], but uses a custom number format
+/// and parsing options. The format must be decimal, and the `lossy`
+/// option is ignored, since it would not round correctly.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_double_double_with_options::<FORMAT>(b"0,1", &options);
+/// assert_eq!(result, Ok((0.1, -5.551115123125783e-18)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_double_double_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(f64, f64)> {
+    lexical_parse_float::double_double::parse_double_double::<FORMAT>(bytes, options)
+}
+
+/// Parse a complete decimal string to the nearest x87 extended float.
+///
+/// The 80-bit x87 extended format has a sign bit, a 15-bit biased
+/// exponent, and a 64-bit significand with an explicit integer bit. Returns
+/// the raw sign and exponent, and the significand, as stored in memory.
+/// Ties round to even, and NaN is parsed as the quiet NaN with only the
+/// integer and quiet bits set.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let result = lexical_core::parse_x87(b"1");
+/// assert_eq!(result, Ok((0x3FFF, 0x8000000000000000)));
+/// let result = lexical_core::parse_x87(b"-0.1");
+/// assert_eq!(result, Ok((0xBFFB, 0xCCCCCCCCCCCCCCCD)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_x87(bytes: &[u8]) -> Result<(u16, u64)> {
+    parse_x87_with_options::<{ format::STANDARD }>(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse a complete decimal string to the nearest x87 extended float with custom parsing options.
+///
+/// This is like [`parse_x87`], but uses a custom number format and
+/// parsing options. The format must be decimal, and the `lossy` option
+/// is ignored, since it would not round correctly.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let result = lexical_core::parse_x87_with_options::<FORMAT>(b"1,5", &options);
+/// assert_eq!(result, Ok((0x3FFF, 0xC000000000000000)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_x87_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(u16, u64)> {
+    lexical_parse_float::x87::parse_x87::<FORMAT>(bytes, options)
+}
+
+/// Write a custom float to a decimal string, with the shortest representation.
+///
+/// This reuses the float writer for third-party float types, which
+/// describe their layout by implementing [`LexicalFloat`]. The float is
+/// written with the fewest significant digits that parse back to the
+/// same value. Returns a subslice of the input buffer containing the
+/// written bytes, starting from the same address in memory as the input
+/// slice.
+///
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is the same
+/// as for an `f64`.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_custom_float(0.1f32, &mut buffer), b"0.1");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_custom_float<F: LexicalFloat>(value: F, bytes: &mut [u8]) -> &mut [u8] {
+    write_custom_float_with_options::<F, { format::STANDARD }>(
+        value,
+        bytes,
+        &WriteFloatOptions::new(),
+    )
+}
+
+/// Write a custom float to a decimal string with custom writing options.
+///
+/// This is like [`write_custom_float`], but uses a custom number format
+/// and writing options. The maximum significant digits in the options
+/// may shorten the digits further, so the float may no longer round-trip.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid or not decimal.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::BUFFER_SIZE;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let value = 1.5f32;
+/// let digits =
+///     lexical_core::write_custom_float_with_options::<_, FORMAT>(value, &mut buffer, &options);
+/// assert_eq!(digits, b"1,5");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_custom_float_with_options<'a, F: LexicalFloat, const FORMAT: u128>(
+    value: F,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> &'a mut [u8] {
+    lexical_write_float::custom::write_custom::<F, FORMAT>(value, bytes, options)
+}
+
+/// Write a double-double number to a decimal string, with the shortest representation.
+///
+/// The pair `hi + lo` is written with the fewest significant digits that
+/// parse back to the same pair with [`parse_double_double`], which may
+/// need many more digits than an `f64`. If the low part is more than half
+/// the gap to the float adjacent to the high part, the pair is normalized
+/// first. Returns a subslice of the input buffer containing the written
+/// bytes, starting from the same address in memory as the input slice.
+///
+/// * `hi`      - High part of the number to serialize.
+/// * `lo`      - Low part of the number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is never the
+/// case for [`DOUBLE_DOUBLE_BUFFER_SIZE`].
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::DOUBLE_DOUBLE_BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; DOUBLE_DOUBLE_BUFFER_SIZE];
+/// let digits = lexical_core::write_double_double(0.1, -5.551115123125783e-18, &mut buffer);
+/// assert_eq!(digits, b"0.1");
+/// let digits = lexical_core::write_double_double(1.0, 1e-32, &mut buffer);
+/// assert_eq!(digits, b"1.00000000000000000000000000000001");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_double_double(hi: f64, lo: f64, bytes: &mut [u8]) -> &mut [u8] {
+    write_double_double_with_options::<{ format::STANDARD }>(
+        hi,
+        lo,
+        bytes,
+        &WriteFloatOptions::new(),
+    )
+}
+
+/// Write a double-double number to a decimal string with custom writing options.
+///
+/// This is like [`write_double_double`], but uses a custom number format
+/// and writing options. The significant digit options are ignored, since
+/// the digits must round-trip.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `hi`      - High part of the number to serialize.
+/// * `lo`      - Low part of the number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid or not decimal.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::DOUBLE_DOUBLE_BUFFER_SIZE;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .exponent(b'E')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; DOUBLE_DOUBLE_BUFFER_SIZE];
+/// let digits = lexical_core::write_double_double_with_options::<FORMAT>(
+///     1e23,
+///     8388608.0,
+///     &mut buffer,
+///     &options,
+/// );
+/// assert_eq!(digits, b"1.0E23");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_double_double_with_options<'a, const FORMAT: u128>(
+    hi: f64,
+    lo: f64,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> &'a mut [u8] {
+    lexical_write_float::double_double::write_double_double::<FORMAT>(hi, lo, bytes, options)
+}
+
+/// Write an x87 extended float to a decimal string, with the shortest representation.
+///
+/// The float is given as the raw sign and biased exponent, and the
+/// significand with the explicit integer bit, as stored in memory, and
+/// is written with the fewest significant digits that parse back to the
+/// same float with [`parse_x87`]. Returns a subslice of the input buffer
+/// containing the written bytes, starting from the same address in memory
+/// as the input slice.
+///
+/// * `sign_exponent`   - Sign bit and 15-bit biased exponent.
+/// * `mant`            - Significand, with the explicit integer bit.
+/// * `bytes`           - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is never the
+/// case for [`X87_BUFFER_SIZE`].
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::X87_BUFFER_SIZE;
+///
+/// let mut buffer = [0u8; X87_BUFFER_SIZE];
+/// let digits = lexical_core::write_x87(0x3FFB, 0xCCCCCCCCCCCCCCCD, &mut buffer);
+/// assert_eq!(digits, b"0.1");
+/// let digits = lexical_core::write_x87(0x4000, 0xC90FDAA22168C235, &mut buffer);
+/// assert_eq!(digits, b"3.1415926535897932385");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_x87(sign_exponent: u16, mant: u64, bytes: &mut [u8]) -> &mut [u8] {
+    write_x87_with_options::<{ format::STANDARD }>(
+        sign_exponent,
+        mant,
+        bytes,
+        &WriteFloatOptions::new(),
+    )
+}
+
+/// Write an x87 extended float to a decimal string with custom writing options.
+///
+/// This is like [`write_x87`], but uses a custom number format and
+/// writing options. The significant digit options are ignored, since
+/// the digits must round-trip.
+///
+/// * `FORMAT`          - Packed struct containing the number format.
+/// * `sign_exponent`   - Sign bit and 15-bit biased exponent.
+/// * `mant`            - Significand, with the explicit integer bit.
+/// * `bytes`           - Buffer to write number to.
+/// * `options`         - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, or if the provided
+/// `FORMAT` is not valid or not decimal.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::X87_BUFFER_SIZE;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .exponent(b'E')
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; X87_BUFFER_SIZE];
+/// let digits = lexical_core::write_x87_with_options::<FORMAT>(
+///     0x7FFE,
+///     0xFFFFFFFFFFFFFFFF,
+///     &mut buffer,
+///     &options,
+/// );
+/// assert_eq!(digits, b"1.189731495357231765E4932");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_x87_with_options<'a, const FORMAT: u128>(
+    sign_exponent: u16,
+    mant: u64,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> &'a mut [u8] {
+    lexical_write_float::x87::write_x87::<FORMAT>(sign_exponent, mant, bytes, options)
+}
+
+/// Write a float like the `%g` conversion of C's `printf`, byte-for-byte.
+///
+/// This writes the same bytes as `printf("%.*g", precision, value)`, to
+/// compare against golden files generated by C programs, typically with
+/// `%.17g`. The exact value is rounded to `precision` significant digits,
+/// and the exponent has at least 2 digits for [`PrintfStyle::Glibc`], like
+/// `1e+08`, or 3 digits for [`PrintfStyle::Msvc`], like `1e+008`. Special
+/// values are written as `inf`, `-inf`, `nan` and `-nan` for both styles.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`       - Float to serialize.
+/// * `precision`   - Number of significant digits, as in `%.17g`.
+/// * `style`       - C runtime to replicate the output of.
+/// * `bytes`       - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is never the
+/// case for [`PRINTF_BUFFER_SIZE`].
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::{PrintfStyle, PRINTF_BUFFER_SIZE};
+///
+/// let mut buffer = [0u8; PRINTF_BUFFER_SIZE];
+/// let digits = lexical_core::write_printf(0.1, 17, PrintfStyle::Glibc, &mut buffer);
+/// assert_eq!(digits, b"0.10000000000000001");
+/// let digits = lexical_core::write_printf(1e-5, 17, PrintfStyle::Glibc, &mut buffer);
+/// assert_eq!(digits, b"1.0000000000000001e-05");
+/// let digits = lexical_core::write_printf(1e-5, 17, PrintfStyle::Msvc, &mut buffer);
+/// assert_eq!(digits, b"1.0000000000000001e-005");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_printf(
+    value: f64,
+    precision: usize,
+    style: PrintfStyle,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    lexical_write_float::printf::write_printf(value, precision, style, bytes)
+}
+
+/// Write a float like the `%a` conversion of C's `printf`, as a hexadecimal float.
+///
+/// This writes the same bytes as `printf("%.*a", precision, value)`, such
+/// as `0x1.8p+1` for `3.0`. If `precision` is `None`, trailing zeros of
+/// the hex digits are removed, like glibc and musl, otherwise exactly
+/// `precision` hex digits are written, rounded with ties to even, such
+/// as `Some(13)` for the Microsoft C runtime. Subnormal floats have a
+/// leading `0` for [`HexSignificand::Denormalized`], like glibc, or are
+/// normalized to a leading `1` for [`HexSignificand::Normalized`], like
+/// musl. Returns a subslice of the input buffer containing the written
+/// bytes, starting from the same address in memory as the input slice.
+///
+/// * `value`       - Float to serialize.
+/// * `precision`   - Number of hex digits after the point, as in `%.13a`.
+/// * `significand` - Whether to normalize the significand of subnormals.
+/// * `bytes`       - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not of sufficient size, which is never the
+/// case for [`PRINTF_BUFFER_SIZE`] if the precision is at most 780.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::{HexSignificand, PRINTF_BUFFER_SIZE};
+///
+/// let mut buffer = [0u8; PRINTF_BUFFER_SIZE];
+/// let glibc = HexSignificand::Denormalized;
+/// let musl = HexSignificand::Normalized;
+/// let digits = lexical_core::write_printf_hex(0.1, None, glibc, &mut buffer);
+/// assert_eq!(digits, b"0x1.999999999999ap-4");
+/// let digits = lexical_core::write_printf_hex(1.5, Some(13), glibc, &mut buffer);
+/// assert_eq!(digits, b"0x1.8000000000000p+0");
+/// let digits = lexical_core::write_printf_hex(5e-324, None, glibc, &mut buffer);
+/// assert_eq!(digits, b"0x0.0000000000001p-1022");
+/// let digits = lexical_core::write_printf_hex(5e-324, None, musl, &mut buffer);
+/// assert_eq!(digits, b"0x1p-1074");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_printf_hex(
+    value: f64,
+    precision: Option<usize>,
+    significand: HexSignificand,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    lexical_write_float::printf::write_printf_hex(value, precision, significand, bytes)
+}
+
+/// Write a float with the most significant digits that fit in a width.
+///
+/// The float is written with the shortest representation if it fits
+/// in `width` bytes. Otherwise, it is rounded to fewer significant
+/// digits until it fits, preferring the float without scientific
+/// notation for the same number of digits, so fixed-width columns
+/// keep as much precision as possible. If no rounding fits, it is
+/// written with a single digit and only an exponent, like `1e1`, and
+/// then as a trimmed float, like `10`. Returns a subslice of the input
+/// buffer containing the written bytes, starting from the same address
+/// in memory as the input slice, or `None` if the float cannot fit in
+/// `width` bytes, or in the buffer, even with 1 significant digit.
+///
+/// * `value`   - Float to serialize.
+/// * `width`   - Maximum number of bytes to write.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// let mut buffer = [0u8; 16];
+/// let digits = lexical_core::write_width(core::f64::consts::PI, 6, &mut buffer);
+/// assert_eq!(digits.as_deref(), Some(&b"3.1416"[..]));
+/// let digits = lexical_core::write_width(123456.789f64, 6, &mut buffer);
+/// assert_eq!(digits.as_deref(), Some(&b"1.23e5"[..]));
+/// let digits = lexical_core::write_width(1e-300f64, 6, &mut buffer);
+/// assert_eq!(digits.as_deref(), Some(&b"1e-300"[..]));
+/// let digits = lexical_core::write_width(1e-300f64, 5, &mut buffer);
+/// assert_eq!(digits, None);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_width<F>(value: F, width: usize, bytes: &mut [u8]) -> Option<&mut [u8]>
+where
+    F: ToFloatWithOptions<Options = WriteFloatOptions> + Float,
+{
+    write_width_with_options::<F, { format::STANDARD }>(
+        value,
+        width,
+        bytes,
+        &WriteFloatOptions::new(),
+    )
+}
+
+/// Write a float with the most significant digits that fit in a width, with custom options.
+///
+/// This is like [`write_width`], but uses a custom number format and
+/// writing options. The exponent breaks of the options are used if the
+/// shortest representation fits, the maximum significant digits limit
+/// the digits, and the minimum significant digits are ignored.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Float to serialize.
+/// * `width`   - Maximum number of bytes to write.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the provided `FORMAT` is not valid or not decimal.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::{format, WriteFloatOptions};
+///
+/// let options = WriteFloatOptions::builder().trim_floats(true).build().unwrap();
+/// let mut buffer = [0u8; 16];
+/// let digits = lexical_core::write_width_with_options::<_, { format::STANDARD }>(
+///     123456.789f64,
+///     6,
+///     &mut buffer,
+///     &options,
+/// );
+/// assert_eq!(digits.as_deref(), Some(&b"123457"[..]));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_width_with_options<'a, F, const FORMAT: u128>(
+    value: F,
+    width: usize,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> Option<&'a mut [u8]>
+where
+    F: ToFloatWithOptions<Options = WriteFloatOptions> + Float,
+{
+    lexical_write_float::width::write_width::<F, FORMAT>(value, width, bytes, options)
+}
+
+/// Convert a character to a digit in the radix.
+///
+/// This uses the same alphabet as the parsers: radixes up to 36 use the
+/// case-insensitive alphabet `0-9A-Z`, and larger radixes use the
+/// case-sensitive alphabet `0-9A-Za-z`, where `a` has the value 36.
+/// Returns `None` if the character is not a digit in the radix. This
+/// does not depend on the radixes the parsers were compiled with.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse")] {
+/// use lexical_core::Radix;
+///
+/// assert_eq!(lexical_core::char_to_digit(b'7', Radix::DECIMAL), Some(7));
+/// assert_eq!(lexical_core::char_to_digit(b'f', Radix::HEXADECIMAL), Some(15));
+/// assert_eq!(lexical_core::char_to_digit(b'F', Radix::HEXADECIMAL), Some(15));
+/// assert_eq!(lexical_core::char_to_digit(b'a', Radix::MAX), Some(36));
+/// assert_eq!(lexical_core::char_to_digit(b'8', Radix::OCTAL), None);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub const fn char_to_digit(c: u8, radix: Radix) -> Option<u32> {
+    lexical_util::digit::char_to_digit(c, radix.get())
+}
+
+/// Convert a digit to a character in the radix, with the case of the letters.
+///
+/// This uses the same alphabet as the writers, which write uppercase
+/// letters, but lowercase letters may be requested for radixes up to 36.
+/// Larger radixes always use the case-sensitive alphabet `0-9A-Za-z`.
+/// Returns `None` if the digit is not below the radix. The conversion
+/// is a lookup into a table, without branching on the digit.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write")] {
+/// use lexical_core::{DigitCase, Radix};
+///
+/// assert_eq!(lexical_core::digit_to_char(7, Radix::DECIMAL, DigitCase::Upper), Some(b'7'));
+/// assert_eq!(lexical_core::digit_to_char(15, Radix::HEXADECIMAL, DigitCase::Upper), Some(b'F'));
+/// assert_eq!(lexical_core::digit_to_char(15, Radix::HEXADECIMAL, DigitCase::Lower), Some(b'f'));
+/// assert_eq!(lexical_core::digit_to_char(36, Radix::MAX, DigitCase::Upper), Some(b'a'));
+/// assert_eq!(lexical_core::digit_to_char(8, Radix::OCTAL, DigitCase::Upper), None);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub const fn digit_to_char(digit: u32, radix: Radix, case: DigitCase) -> Option<u8> {
+    lexical_util::digit::digit_to_char_case(digit, radix.get(), case)
+}
+
+/// Count the number of leading digits in the radix.
+///
+/// This is useful for tokenizers to find the end of a run of digits
+/// before calling a parser. For radixes up to 10, this validates
+/// 8 bytes at a time. Digits use the same alphabet as [`char_to_digit`].
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse")] {
+/// use lexical_core::Radix;
+///
+/// assert_eq!(lexical_core::count_digits(b"1234567890,1", Radix::DECIMAL), 10);
+/// assert_eq!(lexical_core::count_digits(b"ff.8", Radix::HEXADECIMAL), 2);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn count_digits(bytes: &[u8], radix: Radix) -> usize {
+    lexical_util::digit::count_digits(bytes, radix.get())
+}
+
+/// Determine if all bytes are digits in the radix.
+///
+/// This is like [`count_digits`], and returns true for an empty slice.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse")] {
+/// use lexical_core::Radix;
+///
+/// assert!(lexical_core::is_all_digits(b"0101", Radix::BINARY));
+/// assert!(!lexical_core::is_all_digits(b"0121", Radix::BINARY));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn is_all_digits(bytes: &[u8], radix: Radix) -> bool {
+    lexical_util::digit::is_all_digits(bytes, radix.get())
+}
+
+/// Features and algorithms lexical-core was compiled with.
+///
+/// This allows applications and FFI consumers to verify at runtime that
+/// the build matches their requirements, for example, that non-decimal
+/// radixes are supported, or that the pre-computed tables are not
+/// minimized. The flags are stored as a `u32`, which is stable across
+/// releases: new flags may be added, but existing flags never change.
+///
+/// Float parsing is always correct, and never uses lossy algorithms
+/// unless requested at runtime via the `lossy` parse option.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capabilities(u32);
+
+impl Capabilities {
+    /// Use the standard library.
+    pub const STD: Self = Self(1 << 0);
+    /// Support for writing integers.
+    pub const WRITE_INTEGERS: Self = Self(1 << 1);
+    /// Support for writing floats.
+    pub const WRITE_FLOATS: Self = Self(1 << 2);
+    /// Support for parsing integers.
+    pub const PARSE_INTEGERS: Self = Self(1 << 3);
+    /// Support for parsing floats.
+    pub const PARSE_FLOATS: Self = Self(1 << 4);
+    /// Support for power-of-two radixes.
+    pub const POWER_OF_TWO: Self = Self(1 << 5);
+    /// Support for all radixes from 2 to 62 for integers, and from 2 to 36 for floats.
+    pub const RADIX: Self = Self(1 << 6);
+    /// Support for custom number formats.
+    pub const FORMAT: Self = Self(1 << 7);
+    /// Use algorithms and tables optimized for binary size.
+    pub const COMPACT: Self = Self(1 << 8);
+    /// Only use memory-safe indexing.
+    pub const SAFE: Self = Self(1 << 9);
+    /// Use nightly-only features, such as SIMD or inline assembly.
+    pub const NIGHTLY: Self = Self(1 << 10);
+    /// Support for 16-bit floats.
+    pub const F16: Self = Self(1 << 11);
+    /// Only use integer arithmetic to parse floats.
+    pub const DETERMINISTIC: Self = Self(1 << 12);
+    /// Compute the radix power tables at runtime.
+    pub const LAZY_TABLES: Self = Self(1 << 13);
+    /// The parsers and checked writers never panic.
+    pub const PANIC_FREE: Self = Self(1 << 14);
+    /// Support for exotic minus signs and non-breaking spaces when parsing.
+    pub const UNICODE: Self = Self(1 << 15);
+    /// Support for parsing and writing posits.
+    pub const POSIT: Self = Self(1 << 16);
+    /// Built with the minimal profile, without any features that add tables or code paths.
+    pub const MINIMAL: Self = Self(1 << 17);
+    /// Support for generating float test vectors with known results.
+    pub const TEST_VECTORS: Self = Self(1 << 18);
+    /// Support for serializing and deserializing the options.
+    pub const SERDE: Self = Self(1 << 19);
+    /// Support for hooks to observe the algorithm used to parse each float.
+    pub const HOOKS: Self = Self(1 << 20);
+    /// Use 160-bit extended floats for lossy parsing of floats with many digits.
+    pub const FLOAT160: Self = Self(1 << 21);
+    /// Support for zeroizing the big integers of the slow path.
+    pub const ZEROIZE: Self = Self(1 << 22);
+    /// Support for converting decimal strings to and from BCD and packed decimals.
+    pub const BCD: Self = Self(1 << 23);
+    /// Support for the canonical binary encoding of floats.
+    pub const VARINT: Self = Self(1 << 24);
+
+    /// Create capabilities without any flags set.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Create capabilities from raw bits, ignoring any unknown flags.
+    #[inline(always)]
+    pub const fn from_bits_truncate(bits: u32) -> Self {
+        Self(bits & (Self::VARINT.0 << 1).wrapping_sub(1))
+    }
+
+    /// Get the raw bits for the flags.
+    #[inline(always)]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Determine if all the flags in `other` are set.
+    #[inline(always)]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Combine the flags from both capabilities.
+    #[inline(always)]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl core::ops::BitOr for Capabilities {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// Add a flag to the capabilities if a feature is enabled.
+macro_rules! add_capability {
+    ($caps:ident, $flag:ident, $($cfg:tt)*) => {
+        if cfg!($($cfg)*) {
+            $caps = $caps.union(Capabilities::$flag);
+        }
+    };
+}
+
+/// Get the features and algorithms lexical-core was compiled with.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::Capabilities;
+///
+/// let caps = lexical_core::capabilities();
+/// if caps.contains(Capabilities::PARSE_FLOATS | Capabilities::RADIX) {
+///     // Parse floats with non-decimal radixes.
+/// }
+/// ```
+#[inline]
+pub const fn capabilities() -> Capabilities {
+    let mut caps = Capabilities::empty();
+    add_capability!(caps, STD, feature = "std");
+    add_capability!(caps, WRITE_INTEGERS, feature = "write-integers");
+    add_capability!(caps, WRITE_FLOATS, feature = "write-floats");
+    add_capability!(caps, PARSE_INTEGERS, feature = "parse-integers");
+    add_capability!(caps, PARSE_FLOATS, feature = "parse-floats");
+    add_capability!(caps, POWER_OF_TWO, any(feature = "power-of-two", feature = "radix"));
+    add_capability!(caps, RADIX, feature = "radix");
+    add_capability!(caps, FORMAT, feature = "format");
+    add_capability!(caps, COMPACT, feature = "compact");
+    add_capability!(caps, SAFE, feature = "safe");
+    add_capability!(caps, NIGHTLY, feature = "nightly");
+    add_capability!(caps, F16, feature = "f16");
+    add_capability!(caps, DETERMINISTIC, feature = "deterministic");
+    add_capability!(caps, LAZY_TABLES, feature = "lazy-tables");
+    add_capability!(
+        caps,
+        PANIC_FREE,
+        all(feature = "panic-free", not(feature = "safe"), not(feature = "fuzz"))
+    );
+    add_capability!(caps, UNICODE, feature = "unicode");
+    add_capability!(caps, POSIT, feature = "posit");
+    if MINIMAL {
+        caps = caps.union(Capabilities::MINIMAL);
+    }
+    add_capability!(caps, TEST_VECTORS, feature = "test-vectors");
+    add_capability!(caps, SERDE, feature = "serde");
+    add_capability!(caps, HOOKS, feature = "hooks");
+    add_capability!(caps, FLOAT160, feature = "float160");
+    add_capability!(caps, ZEROIZE, feature = "zeroize");
+    add_capability!(caps, BCD, feature = "bcd");
+    add_capability!(caps, VARINT, feature = "varint");
+    caps
+}
+
+/// If lexical-core was built with the minimal profile.
+///
+/// This is `true` if the `minimal` feature is enabled, and no features
+/// that add tables or code paths are enabled by any crate in the build.
+/// See the [minimal](crate#minimal) feature for details.
+pub const MINIMAL: bool = cfg!(all(
+    feature = "minimal",
+    not(any(
+        feature = "power-of-two",
+        feature = "radix",
+        feature = "format",
+        feature = "lazy-tables",
+        feature = "f16",
+        feature = "posit",
+        feature = "unicode",
+        feature = "nightly",
+        feature = "safe",
+        feature = "fuzz",
+        feature = "test-vectors",
+        feature = "serde",
+        feature = "hooks",
+        feature = "float160",
+        feature = "zeroize",
+        feature = "bcd",
+        feature = "varint",
+    ))
+));
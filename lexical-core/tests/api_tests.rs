@@ -24,6 +24,48 @@ fn float_to_string_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "write-integers")]
+fn try_integer_to_string_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::try_write(12345u32, &mut buffer).as_deref(), Some(&b"12345"[..]));
+    assert_eq!(lexical_core::try_write(12345u32, &mut buffer[..5]), None);
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result = lexical_core::try_write_with_options::<_, FORMAT>(12345u32, &mut buffer, &options);
+    assert_eq!(result.as_deref(), Some(&b"12345"[..]));
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_split_test() {
+    let mut buffer = [b'0'; 2 * lexical_core::BUFFER_SIZE + 1];
+    let (digits, rest) = lexical_core::write_split(12345u32, &mut buffer);
+    assert_eq!(digits, b"12345");
+    rest[0] = b',';
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let (digits, rest) =
+        lexical_core::write_split_with_options::<_, FORMAT>(-67i8, &mut rest[1..], &options);
+    assert_eq!(digits, b"-67");
+    assert_eq!(rest.len(), 2 * lexical_core::BUFFER_SIZE + 1 - 9);
+    assert_eq!(&buffer[..9], b"12345,-67");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn try_float_to_string_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::try_write(12345.0f32, &mut buffer).as_deref(), Some(&b"12345.0"[..]));
+    assert_eq!(lexical_core::try_write(12345.0f32, &mut buffer[..7]), None);
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder().nan_string(None).build().unwrap();
+    let result = lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer, &options);
+    assert_eq!(result.as_deref(), Some(&b"1.5"[..]));
+    let result = lexical_core::try_write_with_options::<_, FORMAT>(f64::NAN, &mut buffer, &options);
+    assert_eq!(result, None);
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
@@ -53,3 +95,1081 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_until_test() {
+    use lexical_core::{Delimiters, Error};
+
+    let delimiters = Delimiters::JSON;
+    assert_eq!(lexical_core::parse_until(b"12345", &delimiters), Ok((12345u32, 5)));
+    assert_eq!(lexical_core::parse_until(b"12345,", &delimiters), Ok((12345u32, 5)));
+    assert_eq!(lexical_core::parse_until(b"12345 ]", &delimiters), Ok((12345u32, 5)));
+    assert_eq!(
+        lexical_core::parse_until::<u32>(b"12345a", &delimiters),
+        Err(Error::InvalidDigit(5))
+    );
+    assert_eq!(lexical_core::parse_until::<u32>(b",", &delimiters), Err(Error::Empty(0)));
+    assert_eq!(lexical_core::parse_until::<i32>(b"-,", &delimiters), Err(Error::Empty(1)));
+    assert_eq!(lexical_core::parse_until::<i32>(b"+", &delimiters), Err(Error::Empty(1)));
+    assert_eq!(lexical_core::parse_until::<u32>(b"-,", &delimiters), Err(Error::Empty(0)));
+    assert_eq!(lexical_core::parse_until::<u8>(b"256,", &delimiters), Err(Error::Overflow(2)));
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::parse_until_with_options::<_, FORMAT>(b"-12}", &delimiters, &options),
+        Ok((-12i32, 3))
+    );
+    assert_eq!(
+        lexical_core::parse_until_with_options::<i32, FORMAT>(b"-12:", &delimiters, &options),
+        Err(Error::InvalidDigit(3))
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn string_to_float_until_test() {
+    use lexical_core::{Delimiters, Error};
+
+    let delimiters = Delimiters::CSV;
+    assert_eq!(lexical_core::parse_until(b"1.5", &delimiters), Ok((1.5f32, 3)));
+    assert_eq!(lexical_core::parse_until(b"1.5e3,2", &delimiters), Ok((1500f32, 5)));
+    assert_eq!(
+        lexical_core::parse_until::<f32>(b"1.5 ,", &delimiters),
+        Err(Error::InvalidDigit(3))
+    );
+
+    let options = lexical_core::ParseFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::parse_until_with_options::<_, FORMAT>(b"-1.5\r\n", &delimiters, &options),
+        Ok((-1.5f64, 4))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+fn string_to_number_complete_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_complete::<i32>(b"-12"), Ok(-12));
+    assert_eq!(lexical_core::parse_complete::<f32>(b"-1.5e3"), Ok(-1500.0));
+    assert_eq!(lexical_core::parse_complete::<f64>(b"infx"), Err(Error::InvalidDigit(3)));
+    assert_eq!(lexical_core::parse_complete::<f64>(b"nan(1)"), Err(Error::InvalidDigit(3)));
+    assert_eq!(lexical_core::parse_complete::<f64>(b"1e+x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(lexical_core::parse_complete::<f64>(b"1e+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(lexical_core::parse_complete::<u8>(b"256"), Err(Error::Overflow(2)));
+
+    // Integers and floats report the same errors for the same strings.
+    let strings: [&[u8]; 12] =
+        [b"", b"-", b"+", b" 1", b"1 ", b"--1", b"+-1", b"-x", b"12a", b"1-", b"0x10", b"1_000"];
+    for &string in strings.iter() {
+        let integer = lexical_core::parse_complete::<i64>(string).map(|x| x as f64);
+        assert_eq!(integer, lexical_core::parse_complete::<f64>(string));
+    }
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::parse_complete_with_options::<u64, FORMAT>(b"18446744073709551615", &options),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        lexical_core::parse_complete_with_options::<u64, FORMAT>(b"1.", &options),
+        Err(Error::InvalidDigit(1))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "std"))]
+fn c_str_to_integer_test() {
+    use lexical_core::Error;
+
+    let string = b"-15 apples\0";
+    let ptr = string.as_ptr() as *const _;
+    unsafe {
+        assert_eq!(lexical_core::parse_partial_c_str::<i32>(ptr), Ok((-15, 3)));
+        assert_eq!(lexical_core::parse_c_str::<i32>(ptr), Err(Error::InvalidDigit(3)));
+        assert_eq!(lexical_core::parse_c_str::<i32>(b"42\0".as_ptr() as *const _), Ok(42));
+        assert_eq!(
+            lexical_core::parse_c_str::<i32>(b"\0".as_ptr() as *const _),
+            Err(Error::Empty(0))
+        );
+        assert_eq!(lexical_core::parse_c_str::<i32>(std::ptr::null()), Err(Error::Empty(0)));
+        assert_eq!(lexical_core::parse_c_str::<i32>(b"4\x002\0".as_ptr() as *const _), Ok(4));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn c_str_to_float_test() {
+    let options = lexical_core::ParseFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let string = std::ffi::CString::new("1.5e3, 2").unwrap();
+    unsafe {
+        assert_eq!(
+            lexical_core::parse_partial_c_str_with_options::<f64, FORMAT>(
+                string.as_ptr(),
+                &options
+            ),
+            Ok((1500.0, 5))
+        );
+        assert!(lexical_core::parse_c_str_with_options::<f64, FORMAT>(string.as_ptr(), &options)
+            .is_err());
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn strtod_compat_test() {
+    use lexical_core::StrtodStatus;
+
+    let strtod = |string: &[u8]| {
+        let ptr = string.as_ptr() as *const _;
+        let (value, end, status) = unsafe { lexical_core::strtod_compat(ptr) };
+        (value, end as usize - ptr as usize, status)
+    };
+    assert_eq!(strtod(b" \t-2.5x\0"), (-2.5, 6, StrtodStatus::Success));
+    assert_eq!(strtod(b"1e+\0"), (1.0, 1, StrtodStatus::Success));
+    assert_eq!(strtod(b"1.5E\0"), (1.5, 3, StrtodStatus::Success));
+    assert_eq!(strtod(b"  x\0"), (0.0, 0, StrtodStatus::NoConversion));
+    assert_eq!(strtod(b"-\0"), (0.0, 0, StrtodStatus::NoConversion));
+    assert_eq!(strtod(b"\0"), (0.0, 0, StrtodStatus::NoConversion));
+    assert_eq!(strtod(b"-1e999\0"), (f64::NEG_INFINITY, 6, StrtodStatus::OutOfRange));
+    assert_eq!(strtod(b"infinity\0"), (f64::INFINITY, 8, StrtodStatus::Success));
+    assert_eq!(strtod(b"1e-999\0"), (0.0, 6, StrtodStatus::OutOfRange));
+    assert_eq!(strtod(b"1e-310\0"), (1e-310, 6, StrtodStatus::OutOfRange));
+    assert_eq!(strtod(b"0e-999\0"), (0.0, 6, StrtodStatus::Success));
+    assert_eq!(strtod(b"0x1p3\0"), (0.0, 1, StrtodStatus::Success));
+
+    let (value, end, status) = strtod(b"nan(123)\0");
+    assert!(value.is_nan());
+    assert_eq!((end, status), (8, StrtodStatus::Success));
+    let (value, end, _) = strtod(b"nan(1 2)\0");
+    assert!(value.is_nan());
+    assert_eq!(end, 3);
+
+    let (value, _, status) = unsafe { lexical_core::strtod_compat(std::ptr::null()) };
+    assert_eq!((value, status), (0.0, StrtodStatus::NoConversion));
+    let string = b"3.4e39\0";
+    let (value, _, status) = unsafe { lexical_core::strtof_compat(string.as_ptr() as *const _) };
+    assert_eq!((value, status), (f32::INFINITY, StrtodStatus::OutOfRange));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn string_to_integral_float_test() {
+    use lexical_core::{parse_integral, Error};
+
+    assert_eq!(parse_integral::<f64, i64>(b"12"), Ok((12.0, true)));
+    assert_eq!(parse_integral::<f64, i64>(b"-1.2e1"), Ok((-12.0, true)));
+    assert_eq!(parse_integral::<f64, i64>(b"-0.0"), Ok((-0.0, true)));
+    assert_eq!(parse_integral::<f64, i64>(b"1.25"), Ok((1.25, false)));
+    assert_eq!(parse_integral::<f64, i64>(b"9007199254740992"), Ok((9007199254740992.0, true)));
+    assert_eq!(
+        parse_integral::<f64, i64>(b"-9223372036854775808"),
+        Ok((-9223372036854775808.0, true))
+    );
+    assert_eq!(
+        parse_integral::<f64, i64>(b"9223372036854775808"),
+        Ok((9223372036854775808.0, false))
+    );
+    assert_eq!(
+        parse_integral::<f64, u64>(b"18446744073709551616"),
+        Ok((18446744073709551616.0, false))
+    );
+    assert_eq!(parse_integral::<f64, u8>(b"255"), Ok((255.0, true)));
+    assert_eq!(parse_integral::<f64, u8>(b"256"), Ok((256.0, false)));
+    assert_eq!(parse_integral::<f64, u8>(b"-1"), Ok((-1.0, false)));
+    assert_eq!(parse_integral::<f32, i32>(b"2147483648"), Ok((2147483648.0, false)));
+    assert_eq!(parse_integral::<f32, i32>(b"-2147483648"), Ok((-2147483648.0, true)));
+
+    assert_eq!(parse_integral::<f64, i64>(b"NaN"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_integral::<f64, i64>(b"-inf"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_integral::<f64, i64>(b"1e400"), Err(Error::Overflow(0)));
+    assert_eq!(parse_integral::<f64, i64>(b"-1e400"), Err(Error::Overflow(1)));
+    assert_eq!(parse_integral::<f64, i64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+}
+
+/// Check the matcher accepts exactly the strings the parser accepts.
+#[cfg(feature = "parse-floats")]
+fn check_matcher<const FORMAT: u128>(alphabet: &[u8]) {
+    let matcher = lexical_core::NumberMatcher::new::<FORMAT>(b'e', b'.').unwrap();
+    let options = lexical_core::ParseFloatOptions::new();
+    let mut strings = vec![Vec::new()];
+    for _ in 0..5 {
+        strings = strings
+            .iter()
+            .flat_map(|s| alphabet.iter().map(move |&c| [&s[..], &[c]].concat()))
+            .collect();
+        for s in strings.iter() {
+            let result = lexical_core::parse_with_options::<f64, FORMAT>(s, &options);
+            assert_eq!(matcher.is_match(s), result.is_ok(), "{:?}", s);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn matcher_test() {
+    check_matcher::<{ lexical_core::format::STANDARD }>(b"01.e+-");
+    #[cfg(feature = "format")]
+    check_matcher::<{ lexical_core::format::JSON }>(b"01.e+-");
+    #[cfg(feature = "format")]
+    check_matcher::<{ lexical_core::format::RUST_LITERAL }>(b"01._");
+}
+
+#[test]
+fn capabilities_test() {
+    use lexical_core::Capabilities;
+
+    let caps = lexical_core::capabilities();
+    assert_eq!(caps.contains(Capabilities::STD), cfg!(feature = "std"));
+    assert_eq!(caps.contains(Capabilities::WRITE_INTEGERS), cfg!(feature = "write-integers"));
+    assert_eq!(caps.contains(Capabilities::WRITE_FLOATS), cfg!(feature = "write-floats"));
+    assert_eq!(caps.contains(Capabilities::PARSE_INTEGERS), cfg!(feature = "parse-integers"));
+    assert_eq!(caps.contains(Capabilities::PARSE_FLOATS), cfg!(feature = "parse-floats"));
+    assert_eq!(caps.contains(Capabilities::RADIX), cfg!(feature = "radix"));
+    assert_eq!(caps.contains(Capabilities::FORMAT), cfg!(feature = "format"));
+    assert_eq!(caps.contains(Capabilities::COMPACT), cfg!(feature = "compact"));
+    assert_eq!(caps.contains(Capabilities::LAZY_TABLES), cfg!(feature = "lazy-tables"));
+    assert_eq!(caps.contains(Capabilities::UNICODE), cfg!(feature = "unicode"));
+    assert_eq!(caps.contains(Capabilities::POSIT), cfg!(feature = "posit"));
+    assert_eq!(caps.contains(Capabilities::MINIMAL), lexical_core::MINIMAL);
+    assert_eq!(caps.contains(Capabilities::TEST_VECTORS), cfg!(feature = "test-vectors"));
+    assert_eq!(caps.contains(Capabilities::SERDE), cfg!(feature = "serde"));
+    assert_eq!(caps.contains(Capabilities::HOOKS), cfg!(feature = "hooks"));
+    assert_eq!(caps.contains(Capabilities::FLOAT160), cfg!(feature = "float160"));
+    assert_eq!(caps.contains(Capabilities::ZEROIZE), cfg!(feature = "zeroize"));
+    assert_eq!(caps.contains(Capabilities::BCD), cfg!(feature = "bcd"));
+    assert_eq!(caps.contains(Capabilities::VARINT), cfg!(feature = "varint"));
+    if lexical_core::MINIMAL {
+        assert!(caps.contains(Capabilities::COMPACT));
+        assert!(!caps.contains(Capabilities::POWER_OF_TWO | Capabilities::FORMAT));
+    }
+    if caps.contains(Capabilities::PANIC_FREE) {
+        assert!(cfg!(feature = "panic-free"));
+        assert!(!caps.contains(Capabilities::SAFE));
+    }
+    if cfg!(feature = "radix") {
+        assert!(caps.contains(Capabilities::POWER_OF_TWO));
+    }
+
+    assert!(caps.contains(Capabilities::empty()));
+    assert_eq!(Capabilities::from_bits_truncate(caps.bits()), caps);
+    assert_eq!(Capabilities::from_bits_truncate(u32::MAX).bits(), 0x1FFFFFF);
+    let both = Capabilities::RADIX | Capabilities::FORMAT;
+    assert!(both.contains(Capabilities::RADIX));
+    assert!(!Capabilities::RADIX.contains(both));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "unicode"))]
+fn unicode_to_integer_test() {
+    use lexical_core::Error;
+
+    let minus = "\u{2212}1\u{a0}234".as_bytes();
+    assert_eq!(lexical_core::parse_unicode::<i32>(minus), Ok(-1234));
+    assert_eq!(lexical_core::parse_unicode::<i32>(b"1234"), Ok(1234));
+    assert_eq!(lexical_core::parse_unicode::<i32>(b"1\xa0234"), Ok(1234));
+    assert_eq!(lexical_core::parse_unicode::<u32>(minus), Err(Error::InvalidDigit(0)));
+    let trailing = "\u{2212}12\u{a0}".as_bytes();
+    assert_eq!(lexical_core::parse_unicode::<i32>(trailing), Err(Error::InvalidDigit(5)));
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>(trailing), Ok((-12, 5)));
+    assert_eq!(lexical_core::parse_partial_unicode::<i32>(minus), Ok((-1234, minus.len())));
+    assert_eq!(lexical_core::parse::<i32>(minus), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "unicode"))]
+fn unicode_to_float_test() {
+    use lexical_core::Error;
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let string = "\u{2212}1\u{202f}234,5e\u{2212}1".as_bytes();
+    let result = lexical_core::parse_unicode_with_options::<f64, FORMAT>(string, &options);
+    assert_eq!(result, Ok(-123.45));
+    let result = lexical_core::parse_partial_unicode_with_options::<f64, FORMAT>(string, &options);
+    assert_eq!(result, Ok((-123.45, string.len())));
+    let string = "1,5e\u{2212}".as_bytes();
+    let result = lexical_core::parse_unicode_with_options::<f64, FORMAT>(string, &options);
+    assert_eq!(result, Err(Error::EmptyExponent(7)));
+}
+
+#[test]
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+fn pow10_test() {
+    // Scale a price in dollars to cents.
+    assert_eq!(lexical_core::checked_mul_pow10(1999u64, 2), Some(199900));
+    assert_eq!(lexical_core::checked_pow10::<u128>(38), Some(lexical_core::POW10_U128[38]));
+    assert_eq!(lexical_core::checked_pow(10u32, 10), None);
+    // Split the fraction of a fixed-point value with 8 decimal places.
+    assert_eq!(lexical_core::div_rem_1e8(123456789012), (1234, 56789012));
+    assert_eq!(lexical_core::div_rem_1e19(u64::MAX as u128 + 1), (1, 8446744073709551616));
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn radix_test() {
+    use core::convert::TryFrom;
+    use lexical_core::{Error, Radix};
+
+    assert_eq!(Radix::new(1), None);
+    assert_eq!(Radix::new(63), None);
+    assert_eq!(Radix::new(10), Some(Radix::DECIMAL));
+    assert_eq!(Radix::try_from(36u8).map(Radix::get), Ok(36));
+    assert_eq!(Radix::try_from(0u32), Err(Error::InvalidRadix));
+    assert_eq!(u32::from(Radix::HEXADECIMAL), 16);
+    assert!(Radix::new(37).unwrap().is_extended());
+    assert!(!Radix::MAX_CASE_INSENSITIVE.is_extended());
+    assert!(Radix::new(32).unwrap().is_power_of_two());
+    assert!(!Radix::DECIMAL.is_power_of_two());
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn radix_display_test() {
+    use lexical_core::{Radix, RadixDisplay};
+
+    assert_eq!(format!("{}", RadixDisplay(48879u16, Radix::HEXADECIMAL)), "BEEF");
+    assert_eq!(format!("{:#06}", RadixDisplay(-5i8, Radix::BINARY)), "-0b101");
+    assert_eq!(format!("[{:>4}]", RadixDisplay(7u32, Radix::OCTAL)), "[   7]");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_reserve_test() {
+    let reservation = lexical_core::write_reserve(-1234i32);
+    assert_eq!(reservation.formatted_len(), 5);
+    let mut buffer = [b'\x00'; 5];
+    assert_eq!(reservation.commit(&mut buffer), b"-1234");
+
+    let reservation = lexical_core::write_reserve(u128::MAX);
+    let mut buffer = [b'\x00'; 38];
+    assert_eq!(reservation.try_commit(&mut buffer), None);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn digit_accumulator_test() {
+    use lexical_core::{Accumulation, DigitAccumulator, Radix};
+
+    let mut acc = DigitAccumulator::<u32>::new(Radix::DECIMAL);
+    assert_eq!(acc.extend(b"0042,17"), (4, Accumulation::InvalidDigit));
+    assert_eq!(acc.value(), 42);
+    let mut acc = DigitAccumulator::<u8>::with_value(25, Radix::DECIMAL);
+    assert_eq!(acc.push(b'6'), Accumulation::Overflow);
+    assert_eq!(acc.push(b'5'), Accumulation::Complete);
+    assert_eq!(acc.value(), 255);
+}
+
+#[test]
+#[cfg(all(feature = "parse", feature = "write"))]
+fn digit_char_test() {
+    use lexical_core::{DigitCase, Radix};
+
+    let radix36 = Radix::MAX_CASE_INSENSITIVE;
+    assert_eq!(lexical_core::char_to_digit(b'z', radix36), Some(35));
+    assert_eq!(lexical_core::char_to_digit(b'z', Radix::MAX), Some(61));
+    assert_eq!(lexical_core::char_to_digit(b'2', Radix::BINARY), None);
+    assert_eq!(lexical_core::digit_to_char(35, radix36, DigitCase::Lower), Some(b'z'));
+    assert_eq!(lexical_core::digit_to_char(35, Radix::MAX, DigitCase::Lower), Some(b'Z'));
+    assert_eq!(lexical_core::digit_to_char(62, Radix::MAX, DigitCase::Upper), None);
+    assert_eq!(lexical_core::count_digits(b"zZ!", Radix::MAX), 2);
+    assert!(lexical_core::is_all_digits(b"", Radix::DECIMAL));
+}
+
+#[test]
+#[cfg(all(feature = "parse", feature = "write"))]
+fn bool_test() {
+    use lexical_core::{Error, FormattedSize};
+
+    let mut buffer = [0u8; bool::FORMATTED_SIZE];
+    assert_eq!(lexical_core::write(true, &mut buffer), b"true");
+    assert_eq!(lexical_core::write(false, &mut buffer), b"false");
+    assert_eq!(lexical_core::parse::<bool>(b"true"), Ok(true));
+    assert_eq!(lexical_core::parse_partial::<bool>(b"false,"), Ok((false, 5)));
+    assert_eq!(lexical_core::parse::<bool>(b"1"), Err(Error::InvalidDigit(0)));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::bool_options::NUMERIC;
+    let result = lexical_core::write_with_options::<_, FORMAT>(true, &mut buffer, &options);
+    assert_eq!(result, b"1");
+    let result = lexical_core::parse_with_options::<bool, FORMAT>(b"0", &options);
+    assert_eq!(result, Ok(false));
+    let options =
+        lexical_core::BoolOptions::builder().true_string(b"Y").false_string(b"N").build().unwrap();
+    let result = lexical_core::parse_with_options::<bool, FORMAT>(b"Y", &options);
+    assert_eq!(result, Ok(true));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fn generic_bounds_test() {
+    use lexical_core::{FormattedSize, FromLexical, ToLexical, BUFFER_SIZE};
+
+    // The traits imply `Copy` and `PartialOrd`, like `Number` did.
+    fn round_trip<T: FromLexical + ToLexical>(value: T) -> bool {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let written = lexical_core::write(value, &mut buffer);
+        let parsed = T::from_lexical(written).unwrap();
+        parsed == value && parsed <= value
+    }
+
+    assert!(round_trip(12345u32));
+    assert!(round_trip(-12345i64));
+    assert!(round_trip(true));
+    assert_eq!(bool::FORMATTED_SIZE, 5);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_float_parts_test() {
+    use lexical_core::Error;
+
+    let json = b"-1234.5678e-2";
+    let (integer, rest) = json.split_at(5);
+    let (fraction, exponent) = (&rest[1..5], -2);
+    assert_eq!(
+        lexical_core::parse_float_parts(integer, fraction, exponent),
+        lexical_core::parse::<f64>(json)
+    );
+    assert_eq!(lexical_core::parse_float_parts::<f32>(b"3", b"4028235", 38), Ok(f32::MAX));
+    assert_eq!(lexical_core::parse_float_parts::<f64>(b"-", b"", 0), Err(Error::EmptyMantissa(1)));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().lossy(true).build().unwrap();
+    let result =
+        lexical_core::parse_float_parts_with_options::<f64, FORMAT>(b"1", b"25", 0, &options);
+    assert_eq!(result, Ok(1.25));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_number_test() {
+    use lexical_core::{Error, Number};
+
+    assert_eq!(lexical_core::parse_number(b"0"), Ok(Number::Int(0)));
+    assert_eq!(lexical_core::parse_number(b"-9223372036854775808"), Ok(Number::Int(i64::MIN)));
+    assert_eq!(lexical_core::parse_number(b"9223372036854775808"), Ok(Number::UInt(1 << 63)));
+    assert_eq!(lexical_core::parse_number(b"1e2"), Ok(Number::Float(100.0)));
+    assert_eq!(lexical_core::parse_number(b"-1.5"), Ok(Number::Float(-1.5)));
+    assert_eq!(lexical_core::parse_number(b"1x"), Err(Error::InvalidDigit(1)));
+
+    let result = lexical_core::parse_number(b"-9223372036854775809");
+    assert_eq!(result, Ok(Number::Float(-9223372036854775809.0)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_sign_special_test() {
+    use lexical_core::{Error, Sign, Special};
+
+    let result = lexical_core::parse_sign_special(b"+12.5");
+    assert_eq!(result, Ok((Sign::Positive, None, &b"12.5"[..])));
+    let result = lexical_core::parse_sign_special(b"-NaN rest");
+    assert_eq!(result, Ok((Sign::Negative, Some(Special::Nan), &b" rest"[..])));
+    let result = lexical_core::parse_sign_special(b"Infinity");
+    assert_eq!(result, Ok((Sign::Positive, Some(Special::Infinity), &b""[..])));
+    assert_eq!(lexical_core::parse_sign_special(b"-"), Err(Error::Empty(1)));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().nan_string(None).build().unwrap();
+    let result = lexical_core::parse_sign_special_with_options::<FORMAT>(b"nan", &options);
+    assert_eq!(result, Ok((Sign::Positive, None, &b"nan"[..])));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))]
+fn parse_prefixed_test() {
+    use lexical_core::{Error, Radix};
+
+    assert_eq!(lexical_core::parse_prefixed::<f64>(b"-0x1.8p1"), Ok((-3.0, Radix::HEXADECIMAL)));
+    assert_eq!(lexical_core::parse_prefixed::<f32>(b"0B0.01"), Ok((0.25, Radix::BINARY)));
+    assert_eq!(lexical_core::parse_prefixed::<f64>(b"1e2"), Ok((100.0, Radix::DECIMAL)));
+    assert_eq!(lexical_core::parse_prefixed::<f64>(b"0o9"), Err(Error::EmptyMantissa(2)));
+    let result = lexical_core::parse_prefixed_partial::<f64>(b"0o7.4p1 ; comment");
+    assert_eq!(result, Ok((15.0, Radix::OCTAL, 7)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn normalize_test() {
+    let mut buffer = [0u8; 64];
+    let mut normalize = |bytes: &[u8]| {
+        let count = lexical_core::normalize(bytes, &mut buffer)?;
+        Ok::<_, lexical_core::Error>(buffer[..count].to_vec())
+    };
+    assert_eq!(normalize(b"1000"), Ok(b"1e3".to_vec()));
+    assert_eq!(normalize(b"1e3"), Ok(b"1e3".to_vec()));
+    assert_eq!(normalize(b"1000.0E0"), Ok(b"1e3".to_vec()));
+    assert_eq!(normalize(b"-.0125"), Ok(b"-1.25e-2".to_vec()));
+    assert_eq!(normalize(b"+0"), Ok(b"0".to_vec()));
+    assert_eq!(normalize(b"1e"), Err(lexical_core::Error::EmptyExponent(2)));
+
+    // Buffers 24 bytes larger than the input are always sufficient.
+    let mut buffer = [0u8; 26];
+    let count = lexical_core::normalize(b".1e-4611686018427387904", &mut buffer);
+    assert_eq!(count, Ok(22));
+    assert_eq!(&buffer[..22], b"1e-4611686018427387905");
+    let count = lexical_core::normalize(b".1e-9223372036854775808", &mut buffer);
+    assert_eq!(count, Err(lexical_core::Error::ExponentOverflow(4)));
+
+    // Small buffers are an error, with the required length.
+    let mut buffer = [0u8; 4];
+    let result = lexical_core::normalize(b"-12.5", &mut buffer);
+    assert_eq!(result, Err(lexical_core::Error::BufferTooSmall(7)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn quantize_test() {
+    use lexical_core::Rounding;
+
+    let mut buffer = [0u8; 64];
+    let mut quantize = |bytes: &[u8], decimals: usize, rounding: Rounding| {
+        let count = lexical_core::quantize(bytes, decimals, rounding, &mut buffer)?;
+        Ok::<_, lexical_core::Error>(buffer[..count].to_vec())
+    };
+    assert_eq!(quantize(b"0.125", 2, Rounding::HalfEven), Ok(b"0.12".to_vec()));
+    assert_eq!(quantize(b"0.125", 2, Rounding::HalfUp), Ok(b"0.13".to_vec()));
+    assert_eq!(quantize(b"19.99", 0, Rounding::Down), Ok(b"19".to_vec()));
+    assert_eq!(quantize(b"-19.99", 0, Rounding::Up), Ok(b"-20".to_vec()));
+    assert_eq!(quantize(b"1e2", 2, Rounding::HalfEven), Ok(b"100.00".to_vec()));
+    assert_eq!(quantize(b"-1e-5", 2, Rounding::HalfEven), Ok(b"0.00".to_vec()));
+    assert_eq!(quantize(b"NaN", 2, Rounding::HalfEven), Err(lexical_core::Error::EmptyMantissa(0)));
+    assert_eq!(
+        quantize(b"1e400", 1, Rounding::HalfEven),
+        Err(lexical_core::Error::BufferTooSmall(403))
+    );
+    assert_eq!(
+        quantize(b"1", usize::MAX, Rounding::HalfEven),
+        Err(lexical_core::Error::BufferTooSmall(usize::MAX))
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_rounded_test() {
+    use lexical_core::Rounding;
+
+    assert_eq!(lexical_core::parse_rounded::<f64>(b"2.675", 2, Rounding::HalfEven), Ok(2.68));
+    assert_eq!(lexical_core::parse_rounded::<f64>(b"0.125", 2, Rounding::HalfEven), Ok(0.12));
+    assert_eq!(lexical_core::parse_rounded::<f64>(b"0.125", 2, Rounding::HalfUp), Ok(0.13));
+    assert_eq!(lexical_core::parse_rounded::<f32>(b"9.9951", 2, Rounding::HalfDown), Ok(10.0));
+    assert_eq!(lexical_core::parse_rounded::<f64>(b"1e-5", 3, Rounding::Ceiling), Ok(0.001));
+    assert_eq!(lexical_core::parse_rounded::<f64>(b"inf", 3, Rounding::Down), Ok(f64::INFINITY));
+    assert_eq!(
+        lexical_core::parse_rounded::<f64>(b"1.0x", 3, Rounding::Down),
+        Err(lexical_core::Error::InvalidDigit(3))
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_significant_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::Rounding;
+
+    let parse = |bytes, digits, rounding| {
+        let digits = NonZeroUsize::new(digits).unwrap();
+        lexical_core::parse_significant::<f64>(bytes, digits, rounding)
+    };
+    assert_eq!(parse(b"2.675", 3, Rounding::HalfEven), Ok(2.68));
+    assert_eq!(parse(b"0.00125", 2, Rounding::HalfEven), Ok(0.0012));
+    assert_eq!(parse(b"0.00125", 2, Rounding::HalfUp), Ok(0.0013));
+    assert_eq!(parse(b"99.5", 2, Rounding::HalfUp), Ok(100.0));
+    assert_eq!(parse(b"987654321", 3, Rounding::Down), Ok(987000000.0));
+    assert_eq!(parse(b"1.2345e-300", 2, Rounding::Ceiling), Ok(1.3e-300));
+    assert_eq!(parse(b"inf", 3, Rounding::Down), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1.0x", 3, Rounding::Down), Err(lexical_core::Error::InvalidDigit(3)));
+
+    // Single-precision values stored with 9 digits round-trip exactly.
+    let digits = NonZeroUsize::new(9).unwrap();
+    let value =
+        lexical_core::parse_significant::<f32>(b"0.100000001490116", digits, Rounding::HalfEven);
+    assert_eq!(value, Ok(0.1f32));
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn parse_zeroizing_test() {
+    let bytes = b"2.4703282292062328e-324";
+    assert_eq!(lexical_core::parse_zeroizing::<f64>(bytes), Ok(5e-324));
+    assert_eq!(
+        lexical_core::parse_zeroizing::<f64>(b"1.5x"),
+        Err(lexical_core::Error::InvalidDigit(3))
+    );
+    assert_eq!(lexical_core::parse_partial_zeroizing::<f32>(b"0.1 "), Ok((0.1, 3)));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().exponent(b'^').build().unwrap();
+    let result = lexical_core::parse_zeroizing_with_options::<f64, FORMAT>(b"1.5^3", &options);
+    assert_eq!(result, Ok(1500.0));
+    let result =
+        lexical_core::parse_partial_zeroizing_with_options::<f64, FORMAT>(b"1.5^3e", &options);
+    assert_eq!(result, Ok((1500.0, 5)));
+}
+
+#[test]
+#[cfg(feature = "varint")]
+fn parse_varint_test() {
+    use lexical_core::varint::{self, VARINT_BUFFER_SIZE};
+
+    let mut buffer = [0u8; VARINT_BUFFER_SIZE];
+    let encoded = lexical_core::parse_varint::<f64>(b"0.1", &mut buffer).unwrap();
+    assert_eq!(varint::decode_float::<f64>(encoded), Ok((0.1, encoded.len())));
+    let encoded = lexical_core::parse_varint::<f32>(b"-inf", &mut buffer).unwrap();
+    assert_eq!(encoded, &[0x05]);
+    let result = lexical_core::parse_varint::<f64>(b"1.5x", &mut buffer);
+    assert_eq!(result, Err(lexical_core::Error::InvalidDigit(3)));
+
+    // The same value encodes the same way for every float type.
+    let mut wide = [0u8; VARINT_BUFFER_SIZE];
+    let narrow = lexical_core::parse_varint::<f32>(b"1e10", &mut buffer).unwrap();
+    let wide = lexical_core::parse_varint::<f64>(b"1e10", &mut wide).unwrap();
+    assert_eq!(narrow, wide);
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().exponent(b'^').build().unwrap();
+    let encoded =
+        lexical_core::parse_varint_with_options::<f64, FORMAT>(b"1.5^3", &mut buffer, &options);
+    assert_eq!(varint::decode_float::<f64>(encoded.unwrap()), Ok((1500.0, 4)));
+
+    // Short buffers are an error, rather than a panic.
+    let mut short = [0u8; 2];
+    let result = lexical_core::parse_varint::<f64>(b"1.5", &mut short);
+    assert_eq!(result, Err(lexical_core::Error::BufferTooSmall(3)));
+    let encoded = lexical_core::parse_varint::<f64>(b"-0", &mut short).unwrap();
+    assert_eq!(encoded, &[0x01]);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn cmp_numeric_test() {
+    use core::cmp::Ordering;
+    use lexical_core::cmp_numeric;
+
+    assert_eq!(cmp_numeric(b"1e3", b"1000.0"), Ok(Ordering::Equal));
+    assert_eq!(cmp_numeric(b"-0", b"+0.0"), Ok(Ordering::Equal));
+    assert_eq!(cmp_numeric(b".5", b"5e-1"), Ok(Ordering::Equal));
+    assert_eq!(cmp_numeric(b"-2", b"-10"), Ok(Ordering::Greater));
+    assert_eq!(cmp_numeric(b"1e-5", b"-1e5"), Ok(Ordering::Greater));
+    assert_eq!(cmp_numeric(b"0.30000000000000004", b"0.3"), Ok(Ordering::Greater));
+    assert_eq!(cmp_numeric(b"1x", b"1y"), Err(lexical_core::Error::InvalidDigit(1)));
+    assert_eq!(cmp_numeric(b"1", b"NaN"), Err(lexical_core::Error::EmptyMantissa(0)));
+
+    // Large exponents are compared exactly, or rejected.
+    let result = cmp_numeric(b"1e4611686018427387904", b"1e4611686018427387903");
+    assert_eq!(result, Ok(Ordering::Greater));
+    let result = cmp_numeric(b"1e99999999999999999999", b"1e99999999999999999998");
+    assert_eq!(result, Err(lexical_core::Error::ExponentOverflow(2)));
+    let result = cmp_numeric(b"1e9223372036854775807", b"1e9223372036854775806");
+    assert_eq!(result, Err(lexical_core::Error::ExponentOverflow(2)));
+
+    let mut values: Vec<&[u8]> = vec![b"10", b"-1.5", b"2e0", b"0.001", b"-20", b"0"];
+    values.sort_by(|x, y| cmp_numeric(x, y).unwrap());
+    assert_eq!(values, [&b"-20"[..], b"-1.5", b"0", b"0.001", b"2e0", b"10"]);
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn hash_numeric_test() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::Hasher;
+
+    let hash = |bytes: &[u8]| {
+        let mut hasher = DefaultHasher::new();
+        lexical_core::hash_numeric(bytes, &mut hasher)?;
+        Ok::<_, lexical_core::Error>(hasher.finish())
+    };
+    assert_eq!(hash(b"1e3"), hash(b"1000"));
+    assert_eq!(hash(b"-0"), hash(b"0.000"));
+    assert_eq!(hash(b"1.50"), hash(b"15E-1"));
+    assert_eq!(hash(b"1e"), Err(lexical_core::Error::EmptyExponent(2)));
+
+    let values: [&[u8]; 6] = [b"1e3", b"1000", b"1000.0", b"2", b"2.00", b"+2"];
+    let unique: HashSet<_> = values.iter().map(|x| hash(x).unwrap()).collect();
+    assert_eq!(unique.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn decimal_test() {
+    let number: lexical_core::Decimal64 = "-1.50".parse().unwrap();
+    assert_eq!(lexical_core::Decimal64::from_bid(number.to_bid()), number);
+    let mut buffer = [0u8; lexical_core::Decimal128::FORMATTED_SIZE];
+    let number = lexical_core::Decimal128::parse::<{ lexical_core::format::STANDARD }>(
+        b"1,000e-6170",
+        b'e',
+        b',',
+    )
+    .unwrap();
+    let count = number.write(&mut buffer);
+    assert_eq!(&buffer[..count], b"1.000E-6170");
+}
+
+#[test]
+#[cfg(all(feature = "posit", feature = "parse-floats", feature = "write-floats"))]
+fn posit_test() {
+    use lexical_core::{Posit16, Posit32, BUFFER_SIZE};
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(lexical_core::parse_posit::<Posit16>(b"0.1"), Ok(Posit16::from_f64(0.1)));
+    assert_eq!(lexical_core::write_posit(Posit16::from_f64(0.1), &mut buffer), b"0.1");
+    for &string in [&b"1.0"[..], b"-2.5", b"0.0", b"NaN", b"1.0e-30", b"3.14159265"].iter() {
+        let posit = lexical_core::parse_posit::<Posit32>(string).unwrap();
+        assert_eq!(lexical_core::write_posit(posit, &mut buffer), string);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn custom_float_test() {
+    use lexical_core::{LexicalFloat, BUFFER_SIZE};
+
+    /// A third-party half-precision float.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Half(u16);
+
+    impl LexicalFloat for Half {
+        const EXPONENT_SIZE: i32 = 5;
+        const MANTISSA_SIZE: i32 = 10;
+
+        fn to_bits(self) -> u64 {
+            self.0 as u64
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            Self(bits as u16)
+        }
+    }
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(lexical_core::parse_custom_float::<Half>(b"1"), Ok(Half(0x3C00)));
+    assert_eq!(lexical_core::parse_custom_float::<Half>(b"65520"), Ok(Half(0x7C00)));
+    assert_eq!(lexical_core::write_custom_float(Half(0x7BFF), &mut buffer), b"65500.0");
+    for &string in [&b"1.0"[..], b"-2.5", b"0.0", b"inf", b"6.0e-8", b"0.1"].iter() {
+        let float = lexical_core::parse_custom_float::<Half>(string).unwrap();
+        assert_eq!(lexical_core::write_custom_float(float, &mut buffer), string);
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn double_double_test() {
+    let e = b"2.71828182845904523536028747135266250";
+    assert_eq!(
+        lexical_core::parse_double_double(e),
+        Ok((core::f64::consts::E, 1.4456468917292502e-16))
+    );
+    assert_eq!(lexical_core::parse_double_double(b"-1"), Ok((-1.0, -0.0)));
+    assert!(lexical_core::parse_double_double(b"1e").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn double_double_roundtrip_test() {
+    use lexical_core::DOUBLE_DOUBLE_BUFFER_SIZE;
+
+    let mut buffer = [b'\x00'; DOUBLE_DOUBLE_BUFFER_SIZE];
+    for &string in [&b"2.71828182845904523536028747135266"[..], b"-0.1", b"1.0e-300", b"1.0"].iter()
+    {
+        let (hi, lo) = lexical_core::parse_double_double(string).unwrap();
+        assert_eq!(lexical_core::write_double_double(hi, lo, &mut buffer), string);
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn x87_test() {
+    let e = b"2.71828182845904523536028747135266250";
+    assert_eq!(lexical_core::parse_x87(e), Ok((0x4000, 0xADF85458A2BB4A9B)));
+    assert_eq!(lexical_core::parse_x87(b"-0"), Ok((0x8000, 0)));
+    assert_eq!(lexical_core::parse_x87(b"1e5000"), Ok((0x7FFF, 0x8000000000000000)));
+    assert!(lexical_core::parse_x87(b"1e").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn x87_roundtrip_test() {
+    use lexical_core::X87_BUFFER_SIZE;
+
+    let mut buffer = [b'\x00'; X87_BUFFER_SIZE];
+    for &string in [&b"2.7182818284590452354"[..], b"-0.1", b"1.0e-4000", b"4.0e-4951"].iter() {
+        let (sign_exponent, mant) = lexical_core::parse_x87(string).unwrap();
+        assert_eq!(lexical_core::write_x87(sign_exponent, mant, &mut buffer), string);
+    }
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn printf_test() {
+    use lexical_core::{PrintfStyle, PRINTF_BUFFER_SIZE};
+
+    let mut buffer = [b'\x00'; PRINTF_BUFFER_SIZE];
+    assert_eq!(lexical_core::write_printf(1e100, 17, PrintfStyle::Glibc, &mut buffer), b"1e+100");
+    assert_eq!(lexical_core::write_printf(1e8, 6, PrintfStyle::Msvc, &mut buffer), b"1e+008");
+    assert_eq!(lexical_core::write_printf(-0.5, 17, PrintfStyle::Msvc, &mut buffer), b"-0.5");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_width_test() {
+    let mut buffer = [b'\x00'; 16];
+    let digits = lexical_core::write_width(2.0f64 / 3.0, 5, &mut buffer);
+    assert_eq!(digits.as_deref(), Some(&b"0.667"[..]));
+    let digits = lexical_core::write_width(1.5e10f32, 6, &mut buffer);
+    assert_eq!(digits.as_deref(), Some(&b"1.5e10"[..]));
+    assert_eq!(lexical_core::write_width(f64::INFINITY, 2, &mut buffer), None);
+
+    let options = lexical_core::WriteFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let digits = lexical_core::write_width_with_options::<_, { lexical_core::format::STANDARD }>(
+        -0.125f64,
+        5,
+        &mut buffer,
+        &options,
+    );
+    assert_eq!(digits.as_deref(), Some(&b"-0,12"[..]));
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn printf_hex_test() {
+    use lexical_core::{HexSignificand, PRINTF_BUFFER_SIZE};
+
+    let mut buffer = [b'\x00'; PRINTF_BUFFER_SIZE];
+    let glibc = HexSignificand::Denormalized;
+    let musl = HexSignificand::Normalized;
+    assert_eq!(lexical_core::write_printf_hex(-3.0, None, glibc, &mut buffer), b"-0x1.8p+1");
+    assert_eq!(lexical_core::write_printf_hex(1.5, Some(0), glibc, &mut buffer), b"0x2p+0");
+    assert_eq!(lexical_core::write_printf_hex(1.5, Some(0), musl, &mut buffer), b"0x1p+1");
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fn len32_test() {
+    use lexical_core::{Error, BUFFER_SIZE};
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let count = lexical_core::write_len32(u32::MAX, &mut buffer);
+    assert_eq!(&buffer[..count as usize], b"4294967295");
+    assert_eq!(
+        lexical_core::parse_partial_len32::<u32>(&buffer[..count as usize]),
+        Ok((u32::MAX, 10))
+    );
+    assert_eq!(lexical_core::parse_partial_len32::<i16>(b"-12x"), Ok((-12, 3)));
+
+    let error = lexical_core::parse_partial_len32::<u8>(b"256").unwrap_err();
+    assert_eq!(error.index_u32(), error.index().map(|&x| x as u32));
+    assert_eq!(Error::InvalidDigit(usize::MAX).index_u32(), Some(u32::MAX));
+    assert_eq!(Error::InvalidMantissaRadix.index_u32(), None);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn carry_test() {
+    use lexical_core::{Carry, Error, CARRY_SIZE};
+
+    let mut carry = Carry::new();
+    assert_eq!(lexical_core::parse_partial_carry::<f64>(&carry, b"1.5,"), Ok((1.5, 3)));
+
+    carry.extend(b"1.5e").unwrap();
+    assert_eq!(lexical_core::parse_partial_carry::<f64>(&carry, b"-3 2"), Ok((1.5e-3, 6)));
+    assert_eq!(lexical_core::parse_partial_carry::<f64>(&carry, b""), Err(Error::EmptyExponent(4)));
+
+    // The number ends in the carry, before the next buffer.
+    carry.clear();
+    carry.extend(b"2.5,").unwrap();
+    assert_eq!(lexical_core::parse_partial_carry::<f64>(&carry, b"7"), Ok((2.5, 3)));
+
+    // The number may continue past the bytes that fit in the carry.
+    let digits = [b'1'; CARRY_SIZE];
+    assert_eq!(lexical_core::parse_partial_carry::<f64>(&carry, &digits), Ok((2.5, 3)));
+    carry.clear();
+    carry.extend(b"1.").unwrap();
+    assert_eq!(
+        lexical_core::parse_partial_carry::<f64>(&carry, &digits),
+        Err(Error::NumberTooLong(CARRY_SIZE))
+    );
+    assert_eq!(
+        lexical_core::parse_partial_carry::<f64>(&carry, &digits[..CARRY_SIZE - 2]),
+        Ok((1.1111111111111112, CARRY_SIZE))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+fn parse_in_range_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_in_range::<i8>(b"-5", -10, 10), Ok(-5));
+    assert_eq!(lexical_core::parse_in_range::<i8>(b"10", -10, 10), Ok(10));
+    assert_eq!(lexical_core::parse_in_range::<i8>(b"11", -10, 10), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range::<i8>(b"-200", -10, 10), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range::<i8>(b"", -10, 10), Err(Error::Empty(0)));
+    assert_eq!(lexical_core::parse_in_range::<f32>(b"1e40", 0.0, 1e38), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range::<f32>(b"-0.0", 0.0, 1.0), Ok(-0.0));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_then_test() {
+    use core::num::NonZeroU32;
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_then::<u64, u8>(b"255"), Ok(255));
+    assert_eq!(lexical_core::parse_then::<u64, u8>(b"256"), Err(Error::ConversionFailed(0)));
+    assert_eq!(lexical_core::parse_then::<i32, u16>(b"-1"), Err(Error::ConversionFailed(0)));
+    assert_eq!(lexical_core::parse_then::<u64, u8>(b"2a5"), Err(Error::InvalidDigit(1)));
+    assert_eq!(lexical_core::parse_then::<u8, u64>(b"256"), Err(Error::Overflow(2)));
+    assert_eq!(lexical_core::parse_then::<u32, NonZeroU32>(b"7"), Ok(NonZeroU32::new(7).unwrap()));
+    assert_eq!(lexical_core::parse_then::<u32, NonZeroU32>(b"0"), Err(Error::ConversionFailed(0)));
+    assert!(Error::ConversionFailed(0).is_conversion_failed());
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+fn parse_power_of_ten_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_power_of_ten(b"e3"), Ok(3));
+    assert_eq!(lexical_core::parse_power_of_ten(b"E-12"), Ok(-12));
+    assert_eq!(lexical_core::parse_power_of_ten(b"1e+06"), Ok(6));
+    assert_eq!(lexical_core::parse_power_of_ten(b""), Err(Error::EmptyExponent(0)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"1"), Err(Error::EmptyExponent(1)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"e"), Err(Error::EmptyExponent(1)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"1e-"), Err(Error::EmptyExponent(3)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"2e3"), Err(Error::InvalidDigit(0)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"e3x"), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse_power_of_ten(b"e3000000000"), Err(Error::Overflow(10)));
+
+    let scale = |string: &[u8], value: f64| {
+        lexical_core::scale_by_pow10(value, lexical_core::parse_power_of_ten(string).unwrap())
+    };
+    assert_eq!(scale(b"e-9", 7.0), 7e-9);
+    assert_eq!(scale(b"e24", 1.5), 1.5e24);
+    assert_eq!(scale(b"e-320", 3.0), 3e-320);
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+fn parse_skipping_test() {
+    use lexical_core::Error;
+
+    fn is_padding(c: u8) -> bool {
+        c == b'*' || c == b' '
+    }
+
+    assert_eq!(lexical_core::parse_skipping::<i32>(b"-15", is_padding), Ok(-15));
+    assert_eq!(lexical_core::parse_skipping::<i32>(b"  **-15** ", is_padding), Ok(-15));
+    assert_eq!(lexical_core::parse_skipping::<i32>(b"**", is_padding), Err(Error::Empty(2)));
+    assert_eq!(lexical_core::parse_skipping::<i32>(b"", is_padding), Err(Error::Empty(0)));
+    assert_eq!(
+        lexical_core::parse_skipping::<i32>(b"* 1 2", is_padding),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(
+        lexical_core::parse_skipping::<i32>(b"**- 1", is_padding),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(lexical_core::parse_skipping::<u8>(b" 256 ", is_padding), Err(Error::Overflow(3)));
+    assert_eq!(lexical_core::parse_skipping::<f64>(b"** 1.5e3", is_padding), Ok(1500.0));
+    assert_eq!(
+        lexical_core::parse_skipping::<f64>(b"*1.5*e3", is_padding),
+        Err(Error::InvalidDigit(4))
+    );
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let result =
+        lexical_core::parse_skipping_with_options::<f64, FORMAT>(b"  2,5 ", is_padding, &options);
+    assert_eq!(result, Ok(2.5));
+    let result =
+        lexical_core::parse_skipping_with_options::<f64, FORMAT>(b"  2.5 ", is_padding, &options);
+    assert_eq!(result, Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn registry_test() {
+    use lexical_core::{parse_float_options, ParseFloatOptions, Registry};
+
+    let mut registry: Registry<_> = parse_float_options::PRESETS.iter().cloned().collect();
+    let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    registry.register("european", options);
+    assert_eq!(registry.len(), parse_float_options::PRESETS.len() + 1);
+    assert_eq!(registry.get("JSON"), Some(&parse_float_options::JSON));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = registry.get("european").unwrap();
+    let result = lexical_core::parse_with_options::<f64, FORMAT>(b"1,5", options);
+    assert_eq!(result, Ok(1.5));
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn parser_test() {
+    use lexical_core::{format, Parser};
+
+    #[cfg(feature = "parse-integers")]
+    {
+        let parser = Parser::<u32>::new::<{ format::STANDARD }>(Default::default()).unwrap();
+        assert_eq!(parser.parse(b"1234"), Ok(1234));
+        assert_eq!(parser.parse_partial(b"12,34"), Ok((12, 2)));
+        assert!(parser.parse(b"12,34").is_err());
+        let parser = parser.clone();
+        assert_eq!(parser.parse(b"0"), Ok(0));
+    }
+
+    #[cfg(feature = "parse-floats")]
+    {
+        use lexical_core::ParseFloatOptions;
+
+        let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+        let parser = Parser::<f64>::new::<{ format::STANDARD }>(options).unwrap();
+        assert_eq!(parser.options().decimal_point(), b',');
+        assert_eq!(parser.parse(b"1,5"), Ok(1.5));
+        assert_eq!(parser.parse_partial(b"1,5;2,5"), Ok((1.5, 3)));
+        for value in [0.0, -1.5, 1e300, 5e-324].iter() {
+            let string = value.to_string().replace('.', ",");
+            assert_eq!(parser.parse(string.as_bytes()), Ok(*value));
+        }
+    }
+
+    #[cfg(all(feature = "parse-floats", feature = "power-of-two"))]
+    {
+        const INVALID: u128 = format::NumberFormatBuilder::new().mantissa_radix(1).build();
+        let result = Parser::<f64>::new::<INVALID>(Default::default());
+        assert_eq!(result.err(), Some(format::format_error::<INVALID>()));
+    }
+
+    #[cfg(feature = "parse-floats")]
+    {
+        use lexical_core::{Error, ParseFloatOptions};
+
+        let options = unsafe { ParseFloatOptions::builder().decimal_point(0x80).build_unchecked() };
+        let result = Parser::<f64>::new::<{ format::STANDARD }>(options);
+        assert_eq!(result.err(), Some(Error::InvalidDecimalPoint));
+        let options =
+            unsafe { ParseFloatOptions::builder().nan_string(Some(b"inf")).build_unchecked() };
+        let result = Parser::<f64>::new::<{ format::STANDARD }>(options);
+        assert_eq!(result.err(), Some(Error::InvalidNanString));
+    }
+}
+
+#[test]
+fn log_test() {
+    use lexical_core::log;
+
+    assert_eq!(log::floor_log2(1000u32), 9);
+    assert_eq!(log::estimate_log10(1000u32), 2);
+    assert_eq!(log::floor_log10(1000u32), 3);
+    assert_eq!(log::digit_count(0u64), 1);
+    assert_eq!(log::digit_count(12345u64), 5);
+    assert_eq!(log::digit_count(i64::MIN.unsigned_abs()), 19);
+    assert_eq!(log::digit_count(u128::MAX), 39);
+}
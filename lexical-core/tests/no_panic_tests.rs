@@ -0,0 +1,178 @@
+//! Verify the parsers and checked writers contain no reachable panics.
+//!
+//! Each function is wrapped in a guard that references an undefined
+//! symbol when dropped, which only occurs when unwinding from a panic.
+//! If the optimizer cannot prove the function never panics, the guard
+//! is kept and the tests fail to link. This requires optimizations, so
+//! run these tests with `cargo test --release --features=panic-free`.
+
+#![cfg(all(
+    feature = "panic-free",
+    not(feature = "safe"),
+    not(feature = "fuzz"),
+    not(debug_assertions)
+))]
+
+use core::hint::black_box;
+#[cfg(feature = "parse")]
+use lexical_core::{FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "write")]
+use lexical_core::{ToLexical, ToLexicalWithOptions};
+
+const FORMAT: u128 = lexical_core::format::STANDARD;
+
+struct NoPanic;
+
+impl Drop for NoPanic {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR[lexical]: function may panic, see no_panic_tests.rs\n\n"]
+            fn trigger() -> !;
+        }
+        // SAFETY: safe, since this is only reachable when unwinding, which fails to link.
+        unsafe { trigger() }
+    }
+}
+
+macro_rules! no_panic {
+    ($(fn $name:ident<$t:ident: $bound:path>($($arg:ident: $arg_t:ty),*) -> $r:ty $body:block)*) => ($(
+        #[inline(never)]
+        fn $name<$t: $bound>($($arg: $arg_t),*) -> $r {
+            let guard = NoPanic;
+            let result = $body;
+            core::mem::forget(guard);
+            result
+        }
+    )*);
+}
+
+#[cfg(feature = "parse")]
+no_panic! {
+    fn parse<T: FromLexical>(bytes: &[u8]) -> lexical_core::Result<T> {
+        lexical_core::parse::<T>(bytes)
+    }
+
+    fn parse_partial<T: FromLexical>(bytes: &[u8]) -> lexical_core::Result<(T, usize)> {
+        lexical_core::parse_partial::<T>(bytes)
+    }
+
+    fn parse_with_options<T: FromLexicalWithOptions>(bytes: &[u8], options: &T::Options)
+        -> lexical_core::Result<T>
+    {
+        lexical_core::parse_with_options::<T, FORMAT>(bytes, options)
+    }
+
+    fn parse_partial_with_options<T: FromLexicalWithOptions>(bytes: &[u8], options: &T::Options)
+        -> lexical_core::Result<(T, usize)>
+    {
+        lexical_core::parse_partial_with_options::<T, FORMAT>(bytes, options)
+    }
+}
+
+#[cfg(feature = "parse-integers")]
+no_panic! {
+    fn parse_integer_valued_float<T: lexical_util::num::Integer>(bytes: &[u8])
+        -> lexical_core::Result<T>
+    {
+        lexical_core::parse_integer_valued_float::<T>(bytes)
+    }
+}
+
+#[cfg(feature = "write")]
+no_panic! {
+    fn try_write<T: ToLexical>(value: T, bytes: &mut [u8]) -> Option<usize> {
+        lexical_core::try_write(value, bytes).map(|x| x.len())
+    }
+
+    fn try_write_with_options<T: ToLexicalWithOptions>(
+        value: T,
+        bytes: &mut [u8],
+        options: &T::Options
+    ) -> Option<usize>
+    {
+        lexical_core::try_write_with_options::<T, FORMAT>(value, bytes, options).map(|x| x.len())
+    }
+}
+
+#[cfg(feature = "parse")]
+fn check_parse<T: FromLexical + FromLexicalWithOptions>() {
+    let options = T::Options::default();
+    for bytes in [&b"1"[..], b"-12.5e3", b"", b"NaN", b"1e999999", b"0x1"] {
+        let bytes = black_box(bytes);
+        let _ = parse::<T>(bytes);
+        let _ = parse_partial::<T>(bytes);
+        let _ = parse_with_options::<T>(bytes, black_box(&options));
+        let _ = parse_partial_with_options::<T>(bytes, black_box(&options));
+    }
+}
+
+#[cfg(feature = "parse-integers")]
+fn check_parse_integer_valued_float<T: lexical_util::num::Integer>() {
+    for bytes in [&b"1"[..], b"-1.5e3", b"", b"1e99999999999999999999", b"1.0e-5", b"1x"] {
+        let _ = parse_integer_valued_float::<T>(black_box(bytes));
+    }
+}
+
+#[cfg(feature = "write")]
+fn check_write<T: ToLexical + ToLexicalWithOptions + Copy>(value: T) {
+    let options = T::Options::default();
+    let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    for size in [0, 1, lexical_core::BUFFER_SIZE] {
+        let size = black_box(size);
+        assert_eq!(try_write(black_box(value), &mut buffer[..size]).is_some(), size > 1);
+        let _ = try_write_with_options(black_box(value), &mut buffer[..size], black_box(&options));
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_integer_test() {
+    check_parse::<u8>();
+    check_parse::<u16>();
+    check_parse::<u32>();
+    check_parse::<u64>();
+    check_parse::<u128>();
+    check_parse::<usize>();
+    check_parse::<i8>();
+    check_parse::<i16>();
+    check_parse::<i32>();
+    check_parse::<i64>();
+    check_parse::<i128>();
+    check_parse::<isize>();
+    check_parse_integer_valued_float::<u64>();
+    check_parse_integer_valued_float::<i64>();
+    check_parse_integer_valued_float::<i128>();
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_float_test() {
+    check_parse::<f32>();
+    check_parse::<f64>();
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_integer_test() {
+    check_write(1u8);
+    check_write(1u16);
+    check_write(1u32);
+    check_write(1u64);
+    check_write(1u128);
+    check_write(1usize);
+    check_write(-1i8);
+    check_write(-1i16);
+    check_write(-1i32);
+    check_write(-1i64);
+    check_write(-1i128);
+    check_write(-1isize);
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_float_test() {
+    check_write(-1.5f32);
+    check_write(f32::NAN);
+    check_write(-1.5f64);
+    check_write(f64::INFINITY);
+}
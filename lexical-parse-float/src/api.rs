@@ -50,8 +50,8 @@ macro_rules! float_from_lexical {
             ) -> lexical_util::result::Result<Self>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
-                if !format.is_valid() {
-                    return Err(format.error());
+                if !format.is_valid_float() {
+                    return Err(format.float_error());
                 } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
                     return Err(Error::InvalidPunctuation);
                 }
@@ -21,7 +21,7 @@ use crate::float::{ExtendedFloat80, RawFloat};
 use crate::mask::{lower_n_halfway, lower_n_mask};
 use crate::number::Number;
 use crate::shared;
-use crate::table::bellerophon_powers;
+use crate::table::{bellerophon_powers, bellerophon_seed};
 use lexical_util::format::NumberFormat;
 
 // ALGORITHM
@@ -37,6 +37,47 @@ use lexical_util::format::NumberFormat;
 ///
 /// This has been modified to return a biased, rather than unbiased exponent.
 pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+    let (mut fp, errors) = match scale::<F, FORMAT>(num) {
+        Ok(scaled) => scaled,
+        Err(fp) => return fp,
+    };
+
+    // Too many errors accumulated, return an error.
+    if !lossy && !error_is_accurate::<F>(errors, &fp) {
+        // Bias the exponent so we know it's invalid.
+        fp.exp += shared::INVALID_FP;
+        return fp;
+    }
+
+    // Check if we have a literal 0 or overflow here.
+    // If we have an exponent of -63, we can still have a valid shift,
+    // giving a case where we have too many errors and need to round-up.
+    if -fp.exp + 1 == 65 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
+        return ExtendedFloat80 {
+            mant: 0,
+            exp: 0,
+        };
+    }
+
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+            is_above || (is_odd && is_halfway)
+        });
+    });
+    fp
+}
+
+/// Scale the significant digits to the extended-precision float.
+///
+/// Returns the normalized float with a biased exponent, and the errors
+/// accumulated while scaling, in units of 1/8 of the last bit. If the
+/// number must underflow to 0 or overflow to infinity, returns the
+/// rounded float as an error.
+#[cfg_attr(not(feature = "compact"), inline)]
+fn scale<F: RawFloat, const FORMAT: u128>(
+    num: &Number,
+) -> Result<(ExtendedFloat80, u32), ExtendedFloat80> {
     let format = NumberFormat::<{ FORMAT }> {};
     debug_assert!(!matches!(format.radix(), 2 | 4 | 8 | 16 | 32));
     debug_assert!(format.mantissa_radix() == format.exponent_base());
@@ -54,25 +95,29 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     // This allows us to avoid narrow casts causing numeric overflow,
     // and is a quick check for any radix.
     if num.mantissa == 0 || num.exponent <= -0x1000 {
-        return fp_zero;
+        return Err(fp_zero);
     } else if num.exponent >= 0x1000 {
-        return fp_inf;
+        return Err(fp_inf);
     }
 
     // Calculate our indexes for our extended-precision multiplication.
-    let powers = bellerophon_powers(format.radix());
+    // The powers for some radixes are derived from a seed radix, where
+    // `radix^e == seed^(power*e) * 2^(shift*e)`, so we scale the exponent
+    // to the seed radix, and then add the binary shift at the end.
+    let (seed, power, binary_shift) = bellerophon_seed(format.radix());
+    let powers = bellerophon_powers(seed);
     // This narrowing cast is safe, since exponent must be in a valid range.
-    let exponent = num.exponent as i32 + powers.bias;
+    let exponent = num.exponent as i32 * power + powers.bias;
     let small_index = exponent % powers.step;
     let large_index = exponent / powers.step;
 
     if exponent < 0 {
         // Guaranteed underflow (assign 0).
-        return fp_zero;
+        return Err(fp_zero);
     }
     if large_index as usize >= powers.large.len() {
         // Overflow (assign infinity)
-        return fp_inf;
+        return Err(fp_inf);
     }
 
     // Within the valid exponent range, multiply by the large and small
@@ -91,11 +136,15 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
         mant: num.mantissa,
         exp: 0,
     };
-    match fp.mant.overflowing_mul(powers.get_small_int(small_index as usize)) {
+    // SAFETY: safe, since `0 <= exponent`, so `small_index < step`, which
+    // is the number of small powers.
+    let small_int = unsafe { powers.get_small_int(small_index as usize) };
+    match fp.mant.overflowing_mul(small_int) {
         // Overflow, multiplication unsuccessful, go slow path.
         (_, true) => {
             normalize(&mut fp);
-            fp = mul(&fp, &powers.get_small(small_index as usize));
+            // SAFETY: safe, since `small_index < step`.
+            fp = mul(&fp, &unsafe { powers.get_small(small_index as usize) });
             errors += error_halfscale();
         },
         // No overflow, multiplication successful.
@@ -106,7 +155,8 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     }
 
     // Multiply by the large power.
-    fp = mul(&fp, &powers.get_large(large_index as usize));
+    // SAFETY: safe, since `large_index < powers.large.len()` was checked above.
+    fp = mul(&fp, &unsafe { powers.get_large(large_index as usize) });
     if errors > 0 {
         errors += 1;
     }
@@ -115,34 +165,14 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     // Normalize the floating point (and the errors).
     let shift = normalize(&mut fp);
     errors <<= shift;
-    fp.exp += F::EXPONENT_BIAS;
+    fp.exp += F::EXPONENT_BIAS + binary_shift * num.exponent as i32;
 
     // Check for literal overflow, even with halfway cases.
     if -fp.exp + 1 > 65 {
-        return fp_zero;
+        return Err(fp_zero);
     }
 
-    // Too many errors accumulated, return an error.
-    if !lossy && !error_is_accurate::<F>(errors, &fp) {
-        // Bias the exponent so we know it's invalid.
-        fp.exp += shared::INVALID_FP;
-        return fp;
-    }
-
-    // Check if we have a literal 0 or overflow here.
-    // If we have an exponent of -63, we can still have a valid shift,
-    // giving a case where we have too many errors and need to round-up.
-    if -fp.exp + 1 == 65 {
-        // Have more than 64 bits below the minimum exponent, must be 0.
-        return fp_zero;
-    }
-
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_odd && is_halfway)
-        });
-    });
-    fp
+    Ok((fp, errors))
 }
 
 // ERRORS
@@ -176,18 +206,9 @@ const fn error_halfscale() -> u32 {
     error_scale() / 2
 }
 
-/// Determine if the number of errors is tolerable for float precision.
-#[cfg_attr(not(feature = "compact"), inline)]
-fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
-    // Check we can't have a literal 0 denormal float.
-    debug_assert!(fp.exp >= -64);
-
-    // Determine if extended-precision float is a good approximation.
-    // If the error has affected too many units, the float will be
-    // inaccurate, or if the representation is too close to halfway
-    // that any operations could affect this halfway representation.
-    // See the documentation for dtoa for more information.
-
+/// Get the number of bits truncated from the extended-precision float.
+#[inline(always)]
+fn extrabits<F: RawFloat>(fp: &ExtendedFloat80) -> i32 {
     // This is always a valid u32, since `fp.exp >= -64`
     // will always be positive and the significand size is {23, 52}.
     let mantissa_shift = 64 - F::MANTISSA_SIZE - 1;
@@ -195,13 +216,28 @@ fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
     // The unbiased exponent checks is `unbiased_exp <= F::MANTISSA_SIZE
     // - F::EXPONENT_BIAS -64 + 1`, or `biased_exp <= F::MANTISSA_SIZE - 63`,
     // or `biased_exp <= mantissa_shift`.
-    let extrabits = match fp.exp <= -mantissa_shift {
+    match fp.exp <= -mantissa_shift {
         // Denormal, since shifting to the hidden bit still has a negative exponent.
         // The unbiased check calculation for bits is `1 - F::EXPONENT_BIAS - unbiased_exp`,
         // or `1 - biased_exp`.
         true => 1 - fp.exp,
         false => 64 - F::MANTISSA_SIZE - 1,
-    };
+    }
+}
+
+/// Determine if the number of errors is tolerable for float precision.
+#[cfg_attr(not(feature = "compact"), inline)]
+fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
+    // Check we can't have a literal 0 denormal float.
+    debug_assert!(fp.exp >= -64);
+
+    // Determine if extended-precision float is a good approximation.
+    // If the error has affected too many units, the float will be
+    // inaccurate, or if the representation is too close to halfway
+    // that any operations could affect this halfway representation.
+    // See the documentation for dtoa for more information.
+
+    let extrabits = extrabits::<F>(fp);
 
     // Our logic is as follows: we want to determine if the actual
     // mantissa and the errors during calculation differ significantly
@@ -274,6 +310,55 @@ fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
     }
 }
 
+// DIAGNOSTICS
+// -----------
+
+/// Rounding diagnostics for the extended-precision float.
+///
+/// These describe why the moderate path could or could not round the
+/// significant digits unambiguously: if the truncated bits are within
+/// the accumulated errors of the halfway point, the slow path is used.
+#[cfg(feature = "diagnostics")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// The extended-precision float, before rounding.
+    pub fp: ExtendedFloat80,
+    /// The accumulated errors, in units of 1/8 of the last bit.
+    pub errors: u32,
+    /// The number of bits truncated when rounding to the float.
+    pub extrabits: i32,
+    /// The truncated bits, or the mantissa if all bits are truncated.
+    pub extra: u64,
+    /// The halfway point of the truncated bits, or 0 if it is above the mantissa.
+    pub halfway: u64,
+    /// If the float can be rounded without the slow path.
+    pub is_accurate: bool,
+}
+
+/// Get the rounding diagnostics of the Bellerophon algorithm.
+///
+/// Returns `None` if the number underflows to 0 or overflows to
+/// infinity, which never needs the slow path.
+#[cfg(feature = "diagnostics")]
+pub fn diagnostics<F: RawFloat, const FORMAT: u128>(num: &Number) -> Option<Diagnostics> {
+    let (fp, errors) = scale::<F, FORMAT>(num).ok()?;
+    let extrabits = extrabits::<F>(&fp);
+    let (extra, halfway) = if extrabits > 64 {
+        (fp.mant, 0)
+    } else {
+        let maskbits = extrabits as u64;
+        (fp.mant & lower_n_mask(maskbits), lower_n_halfway(maskbits))
+    };
+    Some(Diagnostics {
+        fp,
+        errors,
+        extrabits,
+        extra,
+        halfway,
+        is_accurate: error_is_accurate::<F>(errors, &fp),
+    })
+}
+
 // MATH
 // ----
 
@@ -370,9 +455,15 @@ pub struct BellerophonPowers {
 
 /// Allow indexing of values without bounds checking
 impl BellerophonPowers {
+    /// Get the small power as an extended float.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `index < self.small.len()`.
     #[inline]
-    pub const fn get_small(&self, index: usize) -> ExtendedFloat80 {
-        let mant = self.small[index];
+    pub unsafe fn get_small(&self, index: usize) -> ExtendedFloat80 {
+        let small = self.small;
+        let mant = unsafe { index_unchecked!(small[index]) };
         let exp = (1 - 64) + ((self.log2 * index as i64) >> self.log2_shift);
         ExtendedFloat80 {
             mant,
@@ -380,9 +471,15 @@ impl BellerophonPowers {
         }
     }
 
+    /// Get the large power as an extended float.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `index < self.large.len()`.
     #[inline]
-    pub const fn get_large(&self, index: usize) -> ExtendedFloat80 {
-        let mant = self.large[index];
+    pub unsafe fn get_large(&self, index: usize) -> ExtendedFloat80 {
+        let large = self.large;
+        let mant = unsafe { index_unchecked!(large[index]) };
         let biased_e = index as i64 * self.step as i64 - self.bias as i64;
         let exp = (1 - 64) + ((self.log2 * biased_e) >> self.log2_shift);
         ExtendedFloat80 {
@@ -391,8 +488,14 @@ impl BellerophonPowers {
         }
     }
 
+    /// Get the small power as an integer.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `index < self.small_int.len()`.
     #[inline]
-    pub const fn get_small_int(&self, index: usize) -> u64 {
-        self.small_int[index]
+    pub unsafe fn get_small_int(&self, index: usize) -> u64 {
+        let small_int = self.small_int;
+        unsafe { index_unchecked!(small_int[index]) }
     }
 }
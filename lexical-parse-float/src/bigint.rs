@@ -4,13 +4,15 @@
 
 #![doc(hidden)]
 
-#[cfg(feature = "radix")]
 use crate::float::ExtendedFloat80;
 use crate::float::RawFloat;
 use crate::limits::{u32_power_limit, u64_power_limit};
 #[cfg(not(feature = "compact"))]
 use crate::table::get_large_int_power;
 use core::{cmp, mem, ops, ptr, slice};
+use lexical_util::assert::debug_assert_some;
+#[cfg(feature = "zeroize")]
+use zeroize_crate::Zeroize;
 
 // BIGINT
 // ------
@@ -28,7 +30,11 @@ const BIGINT_BITS: usize = 6000;
 const BIGINT_BITS: usize = 4000;
 
 /// The number of limbs for the bigint.
-const BIGINT_LIMBS: usize = BIGINT_BITS / LIMB_BITS;
+///
+/// This is the worst-case number of limbs for any big integer in the
+/// slow path, including all `f64` inputs: `log2(10**1091) ≅ 3600` bits,
+/// or 57 64-bit limbs, for decimal strings.
+pub const BIGINT_LIMBS: usize = BIGINT_BITS / LIMB_BITS;
 
 /// Storage for a big integer type.
 ///
@@ -113,23 +119,32 @@ impl ops::MulAssign<&Bigint> for Bigint {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Zeroize for Bigint {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
 /// Number of bits in a Bigfloat.
 ///
 /// This needs to be at least the number of bits required to store
 /// a Bigint, which is `F::EXPONENT_BIAS + F::BITS`.
 /// Bias ≅ 1075, with 64 extra for the digits.
-#[cfg(feature = "radix")]
 const BIGFLOAT_BITS: usize = 1200;
 
 /// The number of limbs for the Bigfloat.
-#[cfg(feature = "radix")]
-const BIGFLOAT_LIMBS: usize = BIGFLOAT_BITS / LIMB_BITS;
+pub const BIGFLOAT_LIMBS: usize = BIGFLOAT_BITS / LIMB_BITS;
 
 /// Storage for a big floating-point type.
 ///
 /// This is used for the algorithm with a non-finite digit count, which creates
 /// a representation of `b+h` and the float scaled into the range `[1, radix)`.
-#[cfg(feature = "radix")]
+///
+/// It can also be used to apply very large exponents to a number, with
+/// [`pow_radix`](Self::pow_radix), which truncates the least-significant
+/// limbs as needed to keep the value in the buffer.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Bigfloat {
     /// Significant digits for the float, stored in a big integer in LE order.
@@ -144,7 +159,6 @@ pub struct Bigfloat {
     pub exp: i32,
 }
 
-#[cfg(feature = "radix")]
 impl Bigfloat {
     /// Construct a bigfloat representing 0.
     #[inline(always)]
@@ -219,18 +233,79 @@ impl Bigfloat {
     pub fn leading_zeros(&self) -> u32 {
         leading_zeros(&self.data)
     }
+
+    /// Remove the least-significant limbs so at most `limbs` remain.
+    ///
+    /// The binary exponent is adjusted for the removed limbs, so the value
+    /// is truncated, or rounded toward zero. Returns if any of the removed
+    /// limbs were non-zero, so the value is inexact, or `None` if the
+    /// exponent overflows, in which case the value is unchanged.
+    #[inline]
+    pub fn truncate_limbs(&mut self, limbs: usize) -> Option<bool> {
+        truncate_limbs(&mut self.data, &mut self.exp, limbs)
+    }
+
+    /// Multiply and assign as if by exponentiation by a power, truncating
+    /// the result to fit.
+    ///
+    /// Unlike [`pow`](Self::pow), this doesn't fail if the result is too
+    /// large for the buffer. The odd part of the radix is applied in chunks,
+    /// using pre-computed large powers where available, and before each
+    /// chunk the least-significant limbs are removed, like in
+    /// [`truncate_limbs`](Self::truncate_limbs), so the product fits. The
+    /// power-of-two part of the radix only changes the binary exponent.
+    /// This is linear in `exp`, and the result keeps at least the 768
+    /// most-significant bits.
+    ///
+    /// Returns if the result was truncated, so the value is inexact, or
+    /// `None` if the binary exponent overflows, in which case the value
+    /// is unspecified. This shares the chunked loop of [`pow`](Self::pow),
+    /// through [`pow_chunked`].
+    pub fn pow_radix(&mut self, radix: u32, exp: u32) -> Option<bool> {
+        let (odd, shift) = split_radix(radix);
+        if shift != 0 {
+            let bits = exp.checked_mul(shift)?;
+            if bits > i32::MAX as u32 {
+                return None;
+            }
+            self.exp = self.exp.checked_add(bits as i32)?;
+        }
+        if odd == 0 || self.data.is_empty() {
+            return Some(false);
+        }
+
+        let mut truncated = false;
+        let capacity = self.data.capacity();
+        let binary_exp = &mut self.exp;
+        pow_chunked(&mut self.data, odd, exp, |data, limbs| {
+            // Truncating to `capacity - limbs` means the multiplication
+            // cannot fail, since the product has at most
+            // `data.len() + limbs` limbs.
+            truncated |= truncate_limbs(data, binary_exp, capacity - limbs)?;
+            Some(())
+        })?;
+        Some(truncated)
+    }
 }
 
-#[cfg(feature = "radix")]
 impl ops::MulAssign<&Bigfloat> for Bigfloat {
     #[inline]
     #[allow(clippy::suspicious_op_assign_impl)]
     fn mul_assign(&mut self, rhs: &Bigfloat) {
-        large_mul(&mut self.data, &rhs.data).unwrap();
+        debug_assert_some(large_mul(&mut self.data, &rhs.data));
         self.exp += rhs.exp;
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Zeroize for Bigfloat {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+        self.exp.zeroize();
+    }
+}
+
 // VEC
 // ---
 
@@ -565,19 +640,27 @@ impl<const SIZE: usize> StackVec<SIZE> {
     #[inline(always)]
     pub fn from_u64(x: u64) -> Self {
         let mut vec = Self::new();
-        assert!(2 <= vec.capacity());
+        vec.set_u64(x);
+        vec
+    }
+
+    /// Replace the elements of the vector with a u64 value, in place.
+    #[inline(always)]
+    pub fn set_u64(&mut self, x: u64) {
+        assert!(2 <= self.capacity());
+        // SAFETY: safe since `0 <= self.capacity()`.
+        unsafe { self.truncate_unchecked(0) };
         if LIMB_BITS == 32 {
             // SAFETY: safe since we can always add 2 items.
             unsafe {
-                vec.push_unchecked(x as Limb);
-                vec.push_unchecked((x >> 32) as Limb);
+                self.push_unchecked(x as Limb);
+                self.push_unchecked((x >> 32) as Limb);
             }
         } else {
             // SAFETY: safe since we can always add 1 item.
-            unsafe { vec.push_unchecked(x as Limb) };
+            unsafe { self.push_unchecked(x as Limb) };
         }
-        vec.normalize();
-        vec
+        self.normalize();
     }
 
     // INDEX
@@ -697,7 +780,18 @@ impl<const SIZE: usize> ops::DerefMut for StackVec<SIZE> {
 impl<const SIZE: usize> ops::MulAssign<&[Limb]> for StackVec<SIZE> {
     #[inline]
     fn mul_assign(&mut self, rhs: &[Limb]) {
-        large_mul(self, rhs).unwrap();
+        debug_assert_some(large_mul(self, rhs));
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize> Zeroize for StackVec<SIZE> {
+    /// Zero the entire buffer, and not only the initialized elements,
+    /// so the time taken doesn't depend on the length of the vector.
+    #[inline]
+    fn zeroize(&mut self) {
+        self.data[..].zeroize();
+        self.length.zeroize();
     }
 }
 
@@ -915,13 +1009,33 @@ pub const fn u64_to_hi64_2(r0: u64, r1: u64) -> (u64, bool) {
 /// Furthermore, using sufficiently big large powers is also crucial for
 /// performance. This is a tradeoff of binary size and performance, and
 /// using a single value at ~`5^(5 * max_exp)` seems optimal.
-pub fn pow<const SIZE: usize>(x: &mut StackVec<SIZE>, base: u32, mut exp: u32) -> Option<()> {
+pub fn pow<const SIZE: usize>(x: &mut StackVec<SIZE>, base: u32, exp: u32) -> Option<()> {
+    pow_chunked(x, base, exp, |_, _| Some(()))
+}
+
+/// MulAssign by a power, calling `reserve` before each multiplication.
+///
+/// `reserve` is called with the number of limbs in the next multiplier,
+/// so the caller can make room for the product, such as by truncating
+/// the least-significant limbs. Returns `None` if `reserve` or any
+/// multiplication fails.
+#[inline]
+pub fn pow_chunked<const SIZE: usize, F>(
+    x: &mut StackVec<SIZE>,
+    base: u32,
+    mut exp: u32,
+    mut reserve: F,
+) -> Option<()>
+where
+    F: FnMut(&mut StackVec<SIZE>, usize) -> Option<()>,
+{
     // Minimize the number of iterations for large exponents: just
     // do a few steps with a large powers.
     #[cfg(not(feature = "compact"))]
     {
         let (large, step) = get_large_int_power(base);
         while exp >= step {
+            reserve(x, large.len())?;
             large_mul(x, large)?;
             exp -= step;
         }
@@ -935,10 +1049,12 @@ pub fn pow<const SIZE: usize>(x: &mut StackVec<SIZE>, base: u32, mut exp: u32) -
     };
     let max_native = (base as Limb).pow(small_step);
     while exp >= small_step {
+        reserve(x, 1)?;
         small_mul(x, max_native)?;
         exp -= small_step;
     }
     if exp != 0 {
+        reserve(x, 1)?;
         // SAFETY: safe, since `exp < small_step`.
         let small_power = unsafe { f64::int_pow_fast_path(exp as usize, base) };
         small_mul(x, small_power as Limb)?;
@@ -1069,6 +1185,26 @@ pub fn large_add<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Optio
     large_add_from(x, y, 0)
 }
 
+/// Subtract bigint from bigint, where `x >= y`.
+pub fn large_sub<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) {
+    debug_assert!(compare(x, y) != cmp::Ordering::Less);
+
+    // Iteratively subtract elements from y to x, propagating the borrow
+    // past the end of y.
+    let mut borrow = false;
+    for index in 0..x.len() {
+        if index >= y.len() && !borrow {
+            break;
+        }
+        let yi = y.get(index).copied().unwrap_or(0);
+        let (result, overflow1) = x[index].overflowing_sub(yi);
+        let (result, overflow2) = result.overflowing_sub(borrow as Limb);
+        x[index] = result;
+        borrow = overflow1 || overflow2;
+    }
+    x.normalize();
+}
+
 /// Grade-school multiplication algorithm.
 ///
 /// Slow, naive algorithm, using limb-bit bases and just shifting left for
@@ -1331,6 +1467,40 @@ pub fn shl_limbs<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<
     }
 }
 
+/// Shift-right `n` limbs inside a buffer, discarding the low limbs.
+///
+/// Returns if any of the discarded limbs were non-zero.
+#[inline]
+pub fn shr_limbs<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> bool {
+    debug_assert!(n != 0);
+    let n = cmp::min(n, x.len());
+    let truncated = x[..n].iter().any(|&xi| xi != 0);
+    let len = x.len() - n;
+    x.copy_within(n.., 0);
+    // SAFETY: safe, since `len <= x.len()`.
+    unsafe { x.set_len(len) };
+    truncated
+}
+
+/// Remove the least-significant limbs so at most `limbs` remain.
+///
+/// The binary exponent `exp` is adjusted for the removed limbs. Returns if
+/// any of the removed limbs were non-zero, or `None` if the exponent
+/// overflows, in which case the value is unchanged.
+#[inline]
+pub fn truncate_limbs<const SIZE: usize>(
+    x: &mut StackVec<SIZE>,
+    exp: &mut i32,
+    limbs: usize,
+) -> Option<bool> {
+    let n = x.len().saturating_sub(limbs);
+    if n == 0 {
+        return Some(false);
+    }
+    *exp = exp.checked_add((n * LIMB_BITS) as i32)?;
+    Some(shr_limbs(x, n))
+}
+
 /// Shift-left buffer by n bits.
 #[inline]
 pub fn shl<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<()> {
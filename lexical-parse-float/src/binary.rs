@@ -7,7 +7,7 @@
 #![doc(hidden)]
 
 use crate::float::{ExtendedFloat80, RawFloat};
-use crate::mask::lower_n_halfway;
+use crate::mask::{lower_n_halfway, lower_n_mask};
 use crate::number::Number;
 use crate::shared;
 #[cfg(not(feature = "compact"))]
@@ -30,6 +30,9 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
         mant: 0,
         exp: 0,
     };
+    if num.mantissa == 0 {
+        return fp_zero;
+    }
 
     // Normalize our mantissa for simpler results.
     let ctlz = num.mantissa.leading_zeros();
@@ -47,10 +50,10 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     // disambiguate the float. If it's even, and exactly halfway, this
     // step fails.
     let power2 = shared::calculate_power2::<F, FORMAT>(num.exponent, ctlz);
-    if -power2 + 1 >= 64 {
-        // Have more than 63 bits below the minimum exponent, must be 0.
-        // Since we can't have partial digit rounding, this is true always
-        // if the power-of-two >= 64.
+    if -power2 + 1 > 64 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
+        // If we have exactly 64 bits, the leading bit is the halfway
+        // bit of the smallest denormal float, so we might round-up.
         return fp_zero;
     }
 
@@ -60,8 +63,9 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
     let shift = shared::calculate_shift::<F>(power2);
 
     // Determine if we can see if we're at a halfway point.
-    let last_bit = 1u64 << shift;
-    let truncated = last_bit - 1;
+    // If we truncate all 64 bits, the last bit wraps to 0, which is even.
+    let truncated = lower_n_mask(shift as u64);
+    let last_bit = truncated.wrapping_add(1);
     let halfway = lower_n_halfway(shift as u64);
     let is_even = mantissa & last_bit == 0;
     let is_halfway = mantissa & truncated == halfway;
@@ -119,21 +123,19 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
         }
     }
 
-    // Parse single digits at a time.
+    // Parse single digits at a time. We must stop after exactly `step`
+    // digits, the same as when parsing the number, since the exponent
+    // is relative to the truncated significant digits. Otherwise, radixes
+    // like 8 and 32 could fit an extra digit into the mantissa.
     for &c in iter {
         let digit = char_to_valid_digit_const(c, radix as _);
-        if !*overflowed {
-            let result = mantissa.checked_mul(radix as _).and_then(|x| x.checked_add(digit as _));
-            if let Some(mant) = result {
-                *mantissa = mant;
-            } else {
-                *overflowed = true;
-                *zero &= digit == 0;
-            }
+        if !*overflowed && *step > 0 {
+            *mantissa = mantissa.wrapping_mul(radix as _).wrapping_add(digit as _);
+            *step -= 1;
         } else {
+            *overflowed = true;
             *zero &= digit == 0;
         }
-        *step = step.saturating_sub(1);
     }
 }
 
@@ -0,0 +1,123 @@
+//! Parse decimal strings to custom float formats, with correct rounding.
+//!
+//! Every value of the format, and the value halfway between two adjacent
+//! values, is exactly representable as an `f64`, so the number is first
+//! parsed to the nearest `f64`, and then rounded to the format. This can
+//! only round incorrectly if the `f64` is exactly halfway between two
+//! values, and so only these ties compare the significant digits to the
+//! `f64` with arbitrary-precision arithmetic.
+
+#![doc(hidden)]
+
+use crate::bigint::{Bigint, Limb};
+use crate::options::Options;
+use crate::parse::ParseFloat;
+use core::cmp::Ordering;
+use lexical_util::assert::debug_assert_some;
+use lexical_util::canonical::NumericStr;
+use lexical_util::custom_float::{self, LexicalFloat};
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::result::Result;
+
+/// Maximum number of significant digits to compare.
+///
+/// Ties are at most 600 significant digits for formats with up to 10
+/// exponent bits, and at most 790 for the remainder of double-double
+/// numbers, so any digits after these can only break an exact comparison.
+const MAX_DIGITS: usize = 800;
+
+/// Get the significant digits of the number as an integer, the decimal
+/// exponent of the last digit, and if any digits were truncated.
+pub(crate) fn significant_digits(number: &NumericStr) -> (Bigint, i64, bool) {
+    let mut bigdigits = Bigint::new();
+    let mut count = 0;
+    let mut digits = number.digits();
+    for digit in digits.by_ref().take(MAX_DIGITS) {
+        debug_assert_some(bigdigits.data.mul_small(10));
+        debug_assert_some(bigdigits.data.add_small((digit - b'0') as Limb));
+        count += 1;
+    }
+    let is_truncated = digits.next().is_some();
+    (bigdigits, number.exponent() - count + 1, is_truncated)
+}
+
+/// Get the integer mantissa and binary exponent of the magnitude of a float.
+pub(crate) fn decompose(value: f64) -> (u64, i64) {
+    let float_bits = value.to_bits();
+    let biased = ((float_bits >> 52) & 0x7FF) as i64;
+    let fraction = float_bits & ((1 << 52) - 1);
+    if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    }
+}
+
+/// Compare the magnitudes of the number and the non-zero, finite float.
+pub(crate) fn compare(number: &NumericStr, value: f64) -> Ordering {
+    let (mut real_digits, real_exp, is_truncated) = significant_digits(number);
+    let (mant, theor_exp) = decompose(value);
+    let mut theor_digits = Bigint::from_u64(mant);
+
+    // Scale both sides to integers: `digits * 10^real_exp` is compared
+    // to `mant * 2^theor_exp`, by moving negative exponents to the other side.
+    if real_exp > 0 {
+        debug_assert_some(real_digits.pow(10, real_exp as u32));
+    } else if real_exp < 0 {
+        debug_assert_some(theor_digits.pow(10, (-real_exp) as u32));
+    }
+    if theor_exp > 0 {
+        debug_assert_some(theor_digits.pow(2, theor_exp as u32));
+    } else if theor_exp < 0 {
+        debug_assert_some(real_digits.pow(2, (-theor_exp) as u32));
+    }
+
+    match real_digits.data.cmp(&theor_digits.data) {
+        Ordering::Equal if is_truncated => Ordering::Greater,
+        ordering => ordering,
+    }
+}
+
+/// Parse a complete string to the nearest `f64`, and the number if the
+/// string is not a special value.
+///
+/// The estimate must be correctly rounded, so lossy parsing is disabled.
+pub(crate) fn parse_estimate<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(f64, Option<NumericStr<'a>>)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.mantissa_radix() != 10 {
+        return Err(Error::InvalidMantissaRadix);
+    } else if format.exponent_base() != 10 {
+        return Err(Error::InvalidExponentBase);
+    }
+
+    // SAFETY: safe, since the options were already validated.
+    let exact = unsafe { options.rebuild().lossy(false).build_unchecked() };
+    let value = f64::parse_complete::<FORMAT>(bytes, &exact)?;
+    match NumericStr::parse_saturating::<FORMAT>(bytes, options.exponent(), options.decimal_point())
+    {
+        Ok(number) => Ok((value, Some(number))),
+        // Special values, such as `NaN` or `inf`, are not numbers.
+        Err(_) if !value.is_finite() => Ok((value, None)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse a complete decimal string to the nearest value of a custom float.
+///
+/// Ties round to even, and numbers overflow to infinity or underflow
+/// to zero like an IEEE-754 float. The format must be decimal.
+pub fn parse_custom<F: LexicalFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<F> {
+    let (value, number) = parse_estimate::<FORMAT>(bytes, options)?;
+    let direction = match number {
+        Some(number) if custom_float::is_tie::<F>(value) => compare(&number, value),
+        _ => Ordering::Equal,
+    };
+    Ok(custom_float::from_f64(value, direction))
+}
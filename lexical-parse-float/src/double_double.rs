@@ -0,0 +1,173 @@
+//! Parse decimal strings to double-double numbers, with correct rounding.
+//!
+//! A double-double number is the unevaluated sum of two `f64`s, `hi + lo`,
+//! which has about 106 bits of precision. The high part is the number
+//! correctly rounded to an `f64`, and the low part is the remainder
+//! correctly rounded to an `f64`. The remainder is calculated exactly
+//! with arbitrary-precision arithmetic, and then rounded by refining
+//! an estimate with exact comparisons to the halfway points.
+
+#![doc(hidden)]
+
+use crate::bigint::{large_sub, Bigint};
+use crate::custom::{decompose, parse_estimate, significant_digits};
+use crate::options::Options;
+use core::cmp::Ordering;
+use lexical_util::assert::debug_assert_some;
+use lexical_util::canonical::NumericStr;
+use lexical_util::result::Result;
+
+/// Create the power of two, which must be a normal float.
+#[inline(always)]
+fn pow2(exp: i64) -> f64 {
+    debug_assert!((-1022..=1023).contains(&exp));
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Scale a float by a power of two, which may underflow.
+fn scale(mut value: f64, mut exp: i64) -> f64 {
+    while exp > 1000 {
+        value *= pow2(1000);
+        exp -= 1000;
+    }
+    while exp < -1000 {
+        value *= pow2(-1000);
+        exp += 1000;
+    }
+    value * pow2(exp)
+}
+
+/// Multiply the integer by a power of two.
+#[inline(always)]
+fn shl(x: &mut Bigint, exp: i64) {
+    debug_assert!(exp >= 0);
+    debug_assert_some(x.pow(2, exp as u32));
+}
+
+/// Compare `n * 2^c / 5^a` to the value halfway between two adjacent,
+/// non-negative floats.
+fn compare_halfway(n: &Bigint, c: i64, pow5: &Bigint, lower: f64, upper: f64) -> Ordering {
+    // Get the sum of the floats as an integer, which is at most 55 bits.
+    let (lower_mant, lower_exp) = decompose(lower);
+    let (upper_mant, upper_exp) = decompose(upper);
+    let exp = lower_exp.min(upper_exp);
+    let sum = (lower_mant << (lower_exp - exp)) + (upper_mant << (upper_exp - exp));
+
+    // Compare `n * 2^c` to `sum * 2^(exp - 1) * 5^a`.
+    let mut real_digits = n.clone();
+    let mut theor_digits = Bigint::from_u64(sum);
+    theor_digits *= pow5;
+    let shift = c - (exp - 1);
+    if shift > 0 {
+        shl(&mut real_digits, shift);
+    } else {
+        shl(&mut theor_digits, -shift);
+    }
+    real_digits.data.cmp(&theor_digits.data)
+}
+
+/// Round `n * 2^c / 5^a` to the nearest float, with ties to even.
+fn round_ratio(n: &Bigint, c: i64, a: u32) -> f64 {
+    if n.data.is_empty() {
+        return 0.0;
+    }
+    let mut pow5 = Bigint::from_u32(1);
+    debug_assert_some(pow5.pow(5, a));
+
+    // Estimate the ratio from the high 64 bits, which is within a few
+    // ULPs, and then step to the correctly rounded float.
+    let (n_hi, _) = n.hi64();
+    let (pow5_hi, _) = pow5.hi64();
+    let exp = n.bit_length() as i64 - pow5.bit_length() as i64 + c;
+    let mut value = scale(n_hi as f64 / pow5_hi as f64, exp);
+    loop {
+        let bits = value.to_bits();
+        let next = f64::from_bits(bits + 1);
+        match compare_halfway(n, c, &pow5, value, next) {
+            Ordering::Greater => {
+                value = next;
+                continue;
+            },
+            Ordering::Equal if bits & 1 == 1 => return next,
+            Ordering::Equal => return value,
+            Ordering::Less => (),
+        }
+        if bits == 0 {
+            return value;
+        }
+        let previous = f64::from_bits(bits - 1);
+        match compare_halfway(n, c, &pow5, previous, value) {
+            Ordering::Less => value = previous,
+            Ordering::Equal if bits & 1 == 1 => return previous,
+            _ => return value,
+        }
+    }
+}
+
+/// Get the remainder of the magnitudes of the number and the finite float,
+/// correctly rounded to a float.
+fn remainder(number: &NumericStr, value: f64) -> f64 {
+    // Truncated digits are replaced by a sticky digit, since the halfway
+    // points of the remainder have at most 790 significant digits.
+    let (mut digits, mut exp, is_truncated) = significant_digits(number);
+    if is_truncated {
+        debug_assert_some(digits.data.mul_small(10));
+        debug_assert_some(digits.data.add_small(1));
+        exp -= 1;
+    }
+    let (mant, float_exp) = decompose(value);
+
+    // Scale both sides to integers, so the remainder is
+    // `(real - theor) * 2^c / 5^a`.
+    let mut real_digits = digits;
+    let mut theor_digits = Bigint::from_u64(mant);
+    let (a, c) = if exp >= 0 {
+        debug_assert_some(real_digits.pow(10, exp as u32));
+        let c = float_exp.min(0);
+        shl(&mut real_digits, -c);
+        shl(&mut theor_digits, float_exp - c);
+        (0, c)
+    } else {
+        let a = -exp;
+        let shift = float_exp + a;
+        let c = shift.min(0);
+        shl(&mut real_digits, -c);
+        debug_assert_some(theor_digits.pow(5, a as u32));
+        shl(&mut theor_digits, shift - c);
+        (a as u32, c - a)
+    };
+
+    match real_digits.data.cmp(&theor_digits.data) {
+        Ordering::Less => {
+            large_sub(&mut theor_digits.data, &real_digits.data);
+            -round_ratio(&theor_digits, c, a)
+        },
+        _ => {
+            large_sub(&mut real_digits.data, &theor_digits.data);
+            round_ratio(&real_digits, c, a)
+        },
+    }
+}
+
+/// Parse a complete decimal string to the nearest double-double number.
+///
+/// Returns the high and low parts, where the high part is the nearest
+/// `f64`, and the low part is the nearest `f64` to the remainder. Special
+/// values, zeros, and numbers that overflow have a low part of zero.
+/// The format must be decimal.
+pub fn parse_double_double<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(f64, f64)> {
+    let (hi, number) = parse_estimate::<FORMAT>(bytes, options)?;
+    let lo = match number {
+        // Numbers that round to zero have a remainder below the smallest float.
+        Some(number) if hi != 0.0 && hi.is_finite() => remainder(&number, hi),
+        _ => 0.0,
+    };
+    if hi.is_sign_negative() {
+        Ok((hi, -lo))
+    } else {
+        Ok((hi, lo))
+    }
+}
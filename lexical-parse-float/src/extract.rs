@@ -0,0 +1,96 @@
+//! Extract every float from arbitrary text.
+//!
+//! Logs and scraped content mix numbers with other text, such as
+//! `took 12.5ms, retried 3 times`. This locates each number with a
+//! [`NumberMatcher`] generated from the format, skipping any bytes that
+//! cannot start a number, and parses each match.
+//!
+//! The matcher only validates the syntax of the number, so special
+//! values, such as `NaN` or `inf`, are not extracted.
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+use core::ops::Range;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::matcher::NumberMatcher;
+use lexical_util::result::Result;
+
+/// Iterator over the floats in a buffer, with the span of each float.
+///
+/// Numbers do not overlap, and each number is the leftmost, longest
+/// number after the previous one. Numbers may be adjacent to other
+/// characters, so `v1.5` yields `1.5`. Any match that fails to parse
+/// is skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::extract::Numbers;
+///
+/// let haystack = b"took 12.5ms, retried 3 times, delta -1e-3";
+/// let numbers: Vec<_> = Numbers::<f64>::new(haystack).collect();
+/// assert_eq!(numbers, vec![(12.5, 5..9), (3.0, 21..22), (-1e-3, 36..41)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Numbers<'a, F: ParseFloat> {
+    /// Matcher to locate each number.
+    matcher: NumberMatcher,
+    /// Options to parse each number.
+    options: Options,
+    /// Parse a complete number with the format.
+    parse: fn(&[u8], &Options) -> Result<F>,
+    /// Text to extract the numbers from.
+    haystack: &'a [u8],
+    /// Index after the previous number.
+    index: usize,
+}
+
+impl<'a, F: ParseFloat> Numbers<'a, F> {
+    /// Create an iterator over the floats in the standard format.
+    #[inline]
+    pub fn new(haystack: &'a [u8]) -> Self {
+        match Self::with_options::<STANDARD>(haystack, &Options::new()) {
+            Ok(numbers) => numbers,
+            Err(_) => unreachable!("the standard format and default options are valid"),
+        }
+    }
+
+    /// Create an iterator over the floats in a custom format.
+    ///
+    /// Returns an error if the format is invalid, or if the exponent
+    /// or decimal point of the options conflict with the format.
+    pub fn with_options<const FORMAT: u128>(haystack: &'a [u8], options: &Options) -> Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid_float() {
+            return Err(format.float_error());
+        } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point())
+        {
+            return Err(Error::InvalidPunctuation);
+        }
+
+        Ok(Self {
+            matcher: NumberMatcher::new::<FORMAT>(options.exponent(), options.decimal_point())?,
+            options: options.clone(),
+            parse: F::parse_complete::<FORMAT>,
+            haystack,
+            index: 0,
+        })
+    }
+}
+
+impl<'a, F: ParseFloat> Iterator for Numbers<'a, F> {
+    type Item = (F, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (start, end) = self.matcher.find(&self.haystack[self.index..])?;
+            let span = self.index + start..self.index + end;
+            self.index = span.end;
+            if let Ok(value) = (self.parse)(&self.haystack[span.clone()], &self.options) {
+                return Some((value, span));
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::limits::{ExactFloat, MaxDigits};
 use crate::table::{get_small_f32_power, get_small_f64_power, get_small_int_power};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+use lexical_util::exponent::BiasedExponent;
 use lexical_util::extended_float::ExtendedFloat;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
@@ -22,6 +23,10 @@ use lexical_util::num::{AsCast, Float};
 /// a value with a bias of `i32::MIN + F::EXPONENT_BIAS`.
 pub type ExtendedFloat80 = ExtendedFloat<u64>;
 
+/// Alias with ~160 bits of precision, 128 for the mantissa and 32 for exponent.
+/// This is only used by the lossy `float160` algorithm.
+pub type ExtendedFloat160 = ExtendedFloat<u128>;
+
 /// Helper trait to add more float characteristics for parsing floats.
 pub trait RawFloat: Float + ExactFloat + MaxDigits {
     // Maximum mantissa for the fast-path (`1 << 53` for f64).
@@ -194,9 +199,31 @@ pub fn powd(x: f64, y: f64) -> f64 {
 }
 
 /// Converts an `ExtendedFloat` to the closest machine float type.
+///
+/// The extended float must be biased, such as from [`assemble_float`].
 #[inline(always)]
 pub fn extended_to_float<F: Float>(x: ExtendedFloat80) -> F {
-    let mut word = x.mant;
-    word |= (x.exp as u64) << F::MANTISSA_SIZE;
+    assemble_float::<F>(x.mant, x.biased_exponent())
+}
+
+/// Assemble a float from the fraction bits and the biased exponent.
+///
+/// The fraction must only have the fraction bits, or also the hidden bit
+/// with a biased exponent of `0` or `1`, which are both the smallest
+/// normal float, such as after rounding a denormal float up.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::float::assemble_float;
+/// use lexical_util::exponent::BinaryExponent;
+///
+/// let exponent = BinaryExponent::new(-52).to_biased::<f64>();
+/// assert_eq!(assemble_float::<f64>(1 << 51, exponent), 1.5);
+/// ```
+#[inline(always)]
+pub fn assemble_float<F: Float>(fraction: u64, exponent: BiasedExponent) -> F {
+    let mut word = fraction;
+    word |= (exponent.get() as u64) << F::MANTISSA_SIZE;
     F::from_bits(F::Unsigned::as_cast(word))
 }
@@ -0,0 +1,200 @@
+//! Lossy algorithm using 160-bit extended-precision floats.
+//!
+//! The Eisel-Lemire algorithm only uses the first 19 significant digits,
+//! so with lossy parsing, floats with more digits may be off by 1 ULP,
+//! and are otherwise parsed with the slow, big-integer algorithms. This
+//! algorithm uses the first 38 significant digits, in a 128-bit mantissa,
+//! and scales them by the 128-bit powers of 5 of the Eisel-Lemire tables,
+//! for an error below `2^-120` relative to the value. It only differs
+//! from the correct result if the value is within that error of halfway
+//! between two floats, or digits after the first 38 change the rounding,
+//! which is a middle ground between the speed of the lossy algorithms
+//! and the accuracy of the slow path.
+
+#![cfg(all(feature = "float160", not(feature = "compact")))]
+#![doc(hidden)]
+
+use crate::float::{ExtendedFloat160, ExtendedFloat80, LemireFloat};
+use crate::number::Number;
+use crate::shared;
+use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, BytesIter};
+
+/// Maximum number of decimal digits that always fit in a `u128`.
+const MAX_DIGITS: usize = 38;
+
+/// Parse a decimal float from the first 38 significant digits.
+///
+/// This rounds to nearest, tie even, from the digits scaled by a
+/// 128-bit power of ten, and always returns a valid float.
+pub fn float160<F: LemireFloat, const FORMAT: u128>(num: &Number) -> ExtendedFloat80 {
+    let format = NumberFormat::<{ FORMAT }> {};
+    debug_assert!(format.radix() == 10);
+
+    let fp_zero = ExtendedFloat80 {
+        mant: 0,
+        exp: 0,
+    };
+    let fp_inf = ExtendedFloat80 {
+        mant: 0,
+        exp: F::INFINITE_POWER,
+    };
+
+    let (mantissa, count, truncated) = parse_mantissa::<FORMAT>(num);
+    if mantissa == 0 {
+        return fp_zero;
+    }
+    // The first 19 significant digits are the mantissa of the number.
+    let mut exponent = num.exponent - (count as i64 - 19);
+    let mut fp = ExtendedFloat160 {
+        mant: mantissa,
+        exp: 0,
+    };
+    normalize(&mut fp);
+
+    // Short-circuit if the value can only be infinity, since the mantissa
+    // has at least 19 digits, or scale by the powers below the smallest
+    // power of five separately.
+    let mut is_inexact = truncated;
+    if exponent > LARGEST_POWER_OF_FIVE as i64 {
+        return fp_inf;
+    } else if exponent < SMALLEST_POWER_OF_FIVE as i64 {
+        let shift = SMALLEST_POWER_OF_FIVE as i64 - exponent;
+        if shift > 19 {
+            // The value is below `10^-324`, which rounds to zero.
+            return fp_zero;
+        }
+        let (product, is_truncated) = mul(&fp, &power_of_ten(-shift as i32));
+        fp = product;
+        is_inexact |= is_truncated;
+        normalize(&mut fp);
+        exponent = SMALLEST_POWER_OF_FIVE as i64;
+    }
+    let (product, is_truncated) = mul(&fp, &power_of_ten(exponent as i32));
+    fp = product;
+    is_inexact |= is_truncated;
+    normalize(&mut fp);
+
+    // Narrow to 80 bits, keeping a sticky bit for the truncated bits and
+    // digits, so values just above halfway round up.
+    let sticky = (fp.mant as u64 != 0 || is_inexact) as u64;
+    let mut fp = ExtendedFloat80 {
+        mant: (fp.mant >> 64) as u64 | sticky,
+        exp: fp.exp + 64 + F::EXPONENT_BIAS,
+    };
+    if -fp.exp + 1 >= 65 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
+        return fp_zero;
+    }
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+            is_above || (is_odd && is_halfway)
+        });
+    });
+    fp
+}
+
+/// Parse up to 38 significant digits into a 128-bit integer.
+///
+/// Returns the value, the number of digits parsed, and if any of the
+/// truncated digits are non-zero.
+fn parse_mantissa<const FORMAT: u128>(num: &Number) -> (u128, usize, bool) {
+    let mut mantissa: u128 = 0;
+    let mut count = 0;
+    let mut truncated = false;
+    let mut add_digit = |c: u8| {
+        if count < MAX_DIGITS {
+            mantissa = mantissa * 10 + char_to_valid_digit_const(c, 10) as u128;
+            count += 1;
+        } else {
+            truncated |= c != b'0';
+        }
+    };
+
+    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    let skip_fraction_zeros = integer_iter.is_consumed();
+    for &c in integer_iter {
+        add_digit(c);
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction_iter = fraction.fraction_iter();
+        if skip_fraction_zeros {
+            fraction_iter.skip_zeros();
+        }
+        for &c in fraction_iter {
+            add_digit(c);
+        }
+    }
+    (mantissa, count, truncated)
+}
+
+/// Get the power of ten `10^q`, as `5^q * 2^q`, from the 128-bit powers of five.
+///
+/// The power of five is truncated, so the result is exact for `0 <= q <= 55`.
+#[inline(always)]
+fn power_of_ten(q: i32) -> ExtendedFloat160 {
+    debug_assert!((SMALLEST_POWER_OF_FIVE..=LARGEST_POWER_OF_FIVE).contains(&q));
+    let (hi, lo) = POWER_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+    ExtendedFloat160 {
+        mant: ((hi as u128) << 64) | lo as u128,
+        exp: log2_pow5(q) - 127 + q,
+    }
+}
+
+/// Calculate `⌊log2(5^q)⌋`, as `⌊log2(10^q)⌋ - q`, like the Eisel-Lemire algorithm.
+#[inline(always)]
+fn log2_pow5(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) - q
+}
+
+// 160-BIT OPS
+// -----------
+
+/// Normalize a 160-bit extended-precision float, so the most-significant bit is set.
+///
+/// Returns the number of bits shifted.
+#[inline]
+pub fn normalize(fp: &mut ExtendedFloat160) -> i32 {
+    if fp.mant != 0 {
+        let shift = fp.mant.leading_zeros() as i32;
+        fp.mant <<= shift;
+        fp.exp -= shift;
+        shift
+    } else {
+        0
+    }
+}
+
+/// Multiply two 160-bit extended-precision floats, truncating the product.
+///
+/// Returns the high 128 bits of the 256-bit product of the mantissas,
+/// and if any of the truncated bits were non-zero. The result is not
+/// normalized, but the most-significant bit or the bit after it is set
+/// if both inputs are normalized.
+#[inline]
+pub fn mul(x: &ExtendedFloat160, y: &ExtendedFloat160) -> (ExtendedFloat160, bool) {
+    const LOMASK: u128 = u64::MAX as u128;
+    let x1 = x.mant >> 64;
+    let x0 = x.mant & LOMASK;
+    let y1 = y.mant >> 64;
+    let y0 = y.mant & LOMASK;
+
+    let x1_y0 = x1 * y0;
+    let x0_y1 = x0 * y1;
+    let x0_y0 = x0 * y0;
+    let x1_y1 = x1 * y1;
+
+    // The sum of the middle words can't overflow, since each is below `2^64`.
+    let middle = (x1_y0 & LOMASK) + (x0_y1 & LOMASK) + (x0_y0 >> 64);
+    let is_inexact = (middle & LOMASK) != 0 || (x0_y0 & LOMASK) != 0;
+    let fp = ExtendedFloat160 {
+        mant: x1_y1 + (x1_y0 >> 64) + (x0_y1 >> 64) + (middle >> 64),
+        exp: x.exp + y.exp + 128,
+    };
+    (fp, is_inexact)
+}
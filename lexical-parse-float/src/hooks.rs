@@ -0,0 +1,103 @@
+//! Hooks to observe the algorithm used to parse each float.
+//!
+//! A hook is a global callback, invoked as each float is parsed with
+//! the algorithm path that was taken and the number of digits, so services
+//! can export metrics, such as histograms segmented by path. The parsers
+//! that force the fast path, such as [`fast_path_complete`], always report
+//! [`Path::Fast`] for numbers that aren't special values.
+//!
+//! To measure the latency of each path, time the call to the parser, and
+//! record it with the path the hook stored, such as in a thread-local.
+//! Without the `hooks` feature, none of the calls to the hook are compiled.
+//!
+//! [`fast_path_complete`]: crate::parse::fast_path_complete
+
+#![cfg(feature = "hooks")]
+
+use crate::number::Number;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Algorithm used to parse a float.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Path {
+    /// A special value, such as `NaN` or infinity.
+    Special,
+    /// The fast path, using native float arithmetic.
+    Fast,
+    /// The moderate path, using an extended-precision float.
+    Moderate,
+    /// The slow path, using arbitrary-precision arithmetic.
+    Slow,
+}
+
+/// How a float was parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseEvent {
+    /// The algorithm used to parse the float.
+    pub path: Path,
+    /// The number of bytes of integer digits, including digit separators.
+    pub integer_digits: usize,
+    /// The number of bytes of fraction digits, including digit separators.
+    pub fraction_digits: usize,
+    /// If the significant digits did not fit in a 64-bit mantissa.
+    pub many_digits: bool,
+}
+
+/// Callback invoked as each float is parsed.
+pub type Hook = fn(&ParseEvent);
+
+/// The current hook, or null if no hook is set.
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Set the hook invoked as each float is parsed, or remove it with `None`.
+///
+/// The hook is shared by all threads, so it must be fast, and should
+/// not parse floats itself.
+#[inline]
+pub fn set_hook(hook: Option<Hook>) {
+    let hook = match hook {
+        Some(hook) => hook as *mut (),
+        None => ptr::null_mut(),
+    };
+    HOOK.store(hook, Ordering::Release);
+}
+
+/// Get the hook invoked as each float is parsed.
+#[inline]
+pub fn hook() -> Option<Hook> {
+    let hook = HOOK.load(Ordering::Acquire);
+    if hook.is_null() {
+        None
+    } else {
+        // SAFETY: safe, since only a `Hook` is stored as a non-null pointer.
+        Some(unsafe { mem::transmute::<*mut (), Hook>(hook) })
+    }
+}
+
+/// Invoke the hook, if set, for a parsed number.
+#[inline(always)]
+pub(crate) fn emit(path: Path, num: &Number) {
+    if let Some(hook) = hook() {
+        hook(&ParseEvent {
+            path,
+            integer_digits: num.integer.len(),
+            fraction_digits: num.fraction.map_or(0, |fraction| fraction.len()),
+            many_digits: num.many_digits,
+        });
+    }
+}
+
+/// Invoke the hook, if set, for a parsed special value.
+#[inline(always)]
+pub(crate) fn emit_special() {
+    if let Some(hook) = hook() {
+        hook(&ParseEvent {
+            path: Path::Special,
+            integer_digits: 0,
+            fraction_digits: 0,
+            many_digits: false,
+        });
+    }
+}
@@ -0,0 +1,43 @@
+//! Determine if parsed floats are integers.
+//!
+//! Formats such as JSON do not distinguish between integers and floats,
+//! so readers may wish to store numbers as integers when possible. This
+//! checks a parsed float is finite, and if it's an integer in range of
+//! the integer type.
+
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::{as_cast, Float, Integer};
+use lexical_util::result::Result;
+
+/// Reject non-finite floats, and determine if the float is an integer in range.
+///
+/// Special values are reported as an invalid digit at the start of the
+/// number, and infinities from overflowing digits as an overflow.
+///
+/// * `bytes`   - Byte slice the float was parsed from.
+/// * `value`   - Parsed float.
+#[inline(always)]
+pub fn check_integral<F: Float, I: Integer, const FORMAT: u128>(
+    bytes: &[u8],
+    value: F,
+) -> Result<(F, bool)> {
+    if value.is_special() {
+        let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+        let start = matches!(bytes.first(), Some(&b'+') | Some(&b'-')) as usize;
+        return match bytes.get(start) {
+            Some(&c) if value.is_inf() && char_is_digit_const(c, radix) => {
+                Err(Error::Overflow(start))
+            },
+            _ => Err(Error::InvalidDigit(start)),
+        };
+    }
+
+    // Float to integer casts saturate, so only the maximum value may
+    // be out-of-range, if it cannot be exactly represented by the float.
+    let integer: I = as_cast(value);
+    let is_exact = I::BITS - I::IS_SIGNED as usize <= F::MANTISSA_SIZE as usize + 1;
+    let is_integral = as_cast::<F, _>(integer) == value && (is_exact || integer != I::MAX);
+    Ok((value, is_integral))
+}
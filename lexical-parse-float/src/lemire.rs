@@ -208,7 +208,8 @@ fn compute_product_approx(q: i64, w: u64, precision: usize) -> (u64, u64) {
     // That means whenever we need to round ties to even, we always have
     // an exact value.
     let index = (q - SMALLEST_POWER_OF_FIVE as i64) as usize;
-    let (lo5, hi5) = POWER_OF_FIVE_128[index];
+    // SAFETY: safe, since `q` is within the range of the table.
+    let (lo5, hi5) = unsafe { index_unchecked!(POWER_OF_FIVE_128[index]) };
     // Only need one multiplication as long as there is 1 zero but
     // in the explicit mantissa bits, +1 for the hidden bit, +1 to
     // determine the rounding direction, +1 for if the computed
@@ -32,6 +32,63 @@
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
 //! * `nightly` - Enable assembly instructions to control FPU rounding modes.
+//! * `deterministic` - Only use integer arithmetic to parse floats.
+//! * `lazy-tables` - Compute the radix power tables at runtime.
+//! * `fuzz` - Promote debug assertions to checks, for fuzzing.
+//! * `diagnostics` - Expose the rounding diagnostics of the moderate path.
+//! * `test-vectors` - Add generators for test vectors with known results.
+//! * `serde` - Serialize and deserialize the options.
+//! * `hooks` - Observe the algorithm used to parse each float.
+//! * `float160` - Use 160-bit extended floats for lossy parsing.
+//! * `zeroize` - Zeroize the big integers of the slow path after use.
+//! * `system-libc` - Differentially test against the system `strtod`.
+//!
+//! `lazy-tables` computes the cached powers for non-decimal radixes the
+//! first time they're used, rather than embedding them in the binary.
+//! This reduces binary size by ~10 KB with the `radix` feature, at the
+//! cost of a one-time initialization for each radix.
+//!
+//! `deterministic` disables the native float fast-path, which may use
+//! extended-precision intermediates on targets with only an x87 FPU,
+//! guaranteeing bit-identical results across targets. This does not
+//! affect the [Machine Float-Only Algorithm](#machine-float-only-algorithm).
+//!
+//! `diagnostics` exposes the accumulated errors and truncated bits of
+//! the extended-precision float in the Bellerophon algorithm, used with
+//! the `compact` or `radix` features, to understand why a number needs
+//! the slow path. This is meant for debugging, and is not a stable API.
+//!
+//! `test-vectors` exposes the [`vectors`] module, which generates the
+//! exact decimal values of floats and of the halfway points between
+//! them, for use in downstream tests and fuzzers.
+//!
+//! `system-libc` only enables tests, which compare the parsed floats to
+//! the `strtod` and `strtof` of the system libc for generated strings,
+//! and list the strings that differ. This is a conformance check of a
+//! platform: run `cargo test --features system-libc` in this crate.
+//!
+//! `serde` implements `Serialize` and `Deserialize` for [`Options`], so
+//! number dialects can be loaded from configuration files. Deserialized
+//! options override the options of a named `preset` from
+//! [`options::PRESETS`], and are validated like the builder. Special
+//! strings that differ from the preset are interned, so each distinct
+//! string is allocated once.
+//!
+//! `hooks` exposes the [`hooks`] module, to set a callback invoked with
+//! the algorithm path and the number of digits of each parsed float, such
+//! as to export metrics segmented by path.
+//!
+//! `float160` parses floats with more than 19 significant digits using
+//! the first 38 digits and 160-bit extended floats when parsing is lossy,
+//! rather than the Eisel-Lemire algorithm on the first 19 digits. This is
+//! a middle ground between the lossy algorithms and the slow path: it is
+//! correct except for values extremely close to halfway between floats.
+//! It has no effect with the `compact` feature or in other radixes.
+//!
+//! `zeroize` exposes the [`zeroizing`] module, which parses with a
+//! [`Workspace`](workspace::Workspace) that is cleared after use, and
+//! implements `Zeroize` for the workspace and big integers, for parsing
+//! secrets without leaving their digits in memory.
 //!
 //! `safe` has a fairly minimal impact, since all parsers are memory-safe
 //! by default except where unsafe functionality can trivially be proven
@@ -69,6 +126,42 @@
 //! assert_eq!(result, Ok(1.34000));
 //! ```
 //!
+//! # Limits
+//!
+//! The [`limits`] module exposes the exponent and mantissa limits for
+//! each float type and radix, which can be used to validate exponent
+//! ranges without parsing.
+//!
+//! # Scaling
+//!
+//! The [`scale`] module scales floats by powers of ten with a single,
+//! correct rounding, using the same algorithms as the parser, rather
+//! than multiplying by an inexact power of ten.
+//!
+//! # Big Floats
+//!
+//! [`Bigfloat`] is the big float of the slow path. It applies very large
+//! exponents with [`Bigfloat::pow_radix`], which multiplies in chunks and
+//! removes the least-significant limbs as needed to keep the value in a
+//! fixed-size buffer, like [`Bigfloat::truncate_limbs`], and reports if the
+//! result is inexact.
+//!
+//! ```rust
+//! use lexical_parse_float::Bigfloat;
+//!
+//! let mut x = Bigfloat::from_u32(1);
+//! // `10^100000` doesn't fit in the buffer, so the result is truncated.
+//! assert_eq!(x.pow_radix(10, 100000), Some(true));
+//! ```
+//!
+//! # Stack Usage
+//!
+//! The slow path never allocates, but stores its big integers on the
+//! stack. The [`workspace`] module exposes a caller-supplied
+//! [`Workspace`](workspace::Workspace) for these big integers, and the
+//! worst-case stack usage it avoids, for use where hidden stack usage is
+//! unacceptable, such as interrupt handlers or kernels.
+//!
 //! # Version Support
 //!
 //! The minimum, standard, required version is 1.51.0, for const generic
@@ -90,6 +183,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(asm))]
 
+// Promote debug assertions to checks when fuzzing, so internal invariants
+// are verified in optimized builds. These shadow the standard macros.
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert {
+    ($($arg:tt)*) => (assert!($($arg)*));
+}
+
 #[macro_use]
 mod index;
 #[macro_use]
@@ -98,20 +199,38 @@ pub mod shared;
 pub mod bellerophon;
 pub mod bigint;
 pub mod binary;
+pub mod custom;
+pub mod double_double;
+pub mod extract;
 pub mod float;
+pub mod float160;
 pub mod fpu;
+pub mod hooks;
+pub mod integral;
 pub mod lemire;
 pub mod libm;
 pub mod limits;
 pub mod mask;
+pub mod mixed;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod posit;
+pub mod prefixed;
+pub mod rounded;
+pub mod scale;
 pub mod slow;
+pub mod strtod;
 pub mod table;
+pub mod tune;
+pub mod vectors;
+pub mod workspace;
+pub mod x87;
+pub mod zeroizing;
 
 mod api;
 mod table_bellerophon_decimal;
+mod table_bellerophon_lazy;
 mod table_bellerophon_radix;
 mod table_binary;
 mod table_decimal;
@@ -123,7 +242,13 @@ mod table_small;
 // Re-exports
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::bigint::Bigfloat;
+#[doc(inline)]
+pub use self::options::{ExponentMode, Options, OptionsBuilder};
+pub use self::parse::{parse_exponent_digits, parse_sign_special, Special};
+#[cfg(feature = "std")]
+pub use self::strtod::StrtodStatus;
+pub use lexical_parse_integer::Sign;
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::error::Error;
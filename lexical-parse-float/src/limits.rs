@@ -1,6 +1,32 @@
 //! Determine the limits of exact exponent and mantissas for floats.
-
-#![doc(hidden)]
+//!
+//! These are useful to validate exponent ranges without parsing, for
+//! example, to check if a literal can be converted exactly using only
+//! native floats. All values depend on the radix, and are only defined
+//! for the radixes enabled by the current features: otherwise, `0`
+//! (or `1` for the power limits, and `None` for the max digits) is
+//! returned.
+//!
+//! * [`ExactFloat::exponent_limit`]: the range of exponents where the
+//!   power of the radix can be exactly stored in the float.
+//! * [`ExactFloat::mantissa_limit`]: the number of digits that can be
+//!   exactly stored in the float's mantissa.
+//! * [`MaxDigits::max_digits`]: the maximum number of significant digits
+//!   required to exactly represent any float.
+//! * [`u32_power_limit`] and [`u64_power_limit`]: the largest power of
+//!   the radix that fits in the integer type.
+//!
+//! Each of these also have a `const fn` variant, such as
+//! [`f64_exponent_limit`].
+//!
+//! ```rust
+//! use lexical_parse_float::limits::{f64_exponent_limit, ExactFloat};
+//!
+//! // `10^22` is the largest power of 10 that is exactly representable.
+//! assert_eq!(f64::exponent_limit(10), (-22, 22));
+//! assert_eq!(f64_exponent_limit(10), (-22, 22));
+//! assert_eq!(f64::mantissa_limit(10), 15);
+//! ```
 
 use lexical_util::assert::debug_assert_radix;
 #[cfg(feature = "f16")]
@@ -33,12 +59,22 @@ use lexical_util::f16::f16;
 // -----------
 
 /// Get exact exponent limit for radix.
-#[doc(hidden)]
 pub trait ExactFloat {
     /// Get min and max exponent limits (exact) from radix.
+    ///
+    /// Any power of the radix within this range, inclusive, can be
+    /// exactly represented by the float.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `f16` and `bf16`, which are not yet supported.
     fn exponent_limit(radix: u32) -> (i64, i64);
 
     /// Get the number of digits that can be shifted from exponent to mantissa.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `f16` and `bf16`, which are not yet supported.
     fn mantissa_limit(radix: u32) -> i64;
 }
 
@@ -596,6 +632,14 @@ pub const fn u64_power_limit(radix: u32) -> u32 {
 ///     print('}')
 /// ```
 pub trait MaxDigits {
+    /// Get the maximum number of digits, plus one, for the radix.
+    ///
+    /// Returns `None` if the radix is a power of two, or if floats
+    /// can have infinite digits in the radix, such as odd radixes.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `f16` and `bf16`, which are not yet supported.
     fn max_digits(radix: u32) -> Option<usize>;
 }
 
@@ -0,0 +1,124 @@
+//! Parse numbers as integers, or as floats if they overflow.
+//!
+//! JSON, TOML, and YAML have a single number syntax, and implementations
+//! store integers exactly if they fit, and floats otherwise. Parsing the
+//! number as an integer, and then again as a float if that fails, scans
+//! the digits twice. The float parser already accumulates the first 19
+//! significant digits into a 64-bit integer, so the integer is recovered
+//! from the parsed digits when the number has no fraction or exponent.
+
+#![doc(hidden)]
+
+use crate::number;
+use crate::options::Options;
+use crate::parse::parse_complete_mixed;
+use lexical_util::digit::{char_is_digit_const, char_to_digit_const};
+use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::result::Result;
+
+/// Default options for numbers.
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// A number parsed as an integer, or as a float if it is not an integer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    /// An integer that fits in an `i64`.
+    Int(i64),
+    /// A positive integer above `i64::MAX` that fits in a `u64`.
+    UInt(u64),
+    /// A number with a fraction or exponent, a special value, or an
+    /// integer that doesn't fit in a 64-bit integer.
+    Float(f64),
+}
+
+/// Get the integer value of the parsed number, if it is written as an
+/// integer that fits in a 64-bit integer.
+///
+/// Negative zero is not an integer, so its sign is kept as a float.
+pub(crate) fn to_integer<const FORMAT: u128>(bytes: &[u8], num: &number::Number) -> Option<Number> {
+    // Integers end with the integer digits, without a fraction,
+    // exponent, or base suffix. The integer digits exclude the count of
+    // any digit separators, so only the remaining bytes are checked.
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let separator = format.digit_separator();
+    if num.fraction.is_some() {
+        return None;
+    }
+    let end = num.integer.as_ptr() as usize - bytes.as_ptr() as usize + num.integer.len();
+    let is_digit = |&c: &u8| separator != 0 && (c == separator || char_is_digit_const(c, radix));
+    if !bytes[end..].iter().all(is_digit) {
+        return None;
+    }
+
+    // The mantissa has the significant digits that always fit, such as
+    // 19 decimal digits, and the exponent is the number of truncated
+    // digits, which are all zeros unless the mantissa has many digits.
+    // Only a single truncated, non-zero digit can fit.
+    let mut value = num.mantissa;
+    if num.exponent != 0 || num.many_digits {
+        if radix != format.exponent_base() || num.exponent < 0 {
+            return None;
+        } else if num.many_digits {
+            if num.exponent != 1 {
+                return None;
+            }
+            let digit = bytes.iter().rev().find_map(|&c| char_to_digit_const(c, radix))?;
+            value = value.checked_mul(radix as u64)?.checked_add(digit as u64)?;
+        } else {
+            let power = (radix as u64).checked_pow(num.exponent as u32)?;
+            value = value.checked_mul(power)?;
+        }
+    }
+
+    if !num.is_negative {
+        Some(if value <= i64::MAX as u64 {
+            Number::Int(value as i64)
+        } else {
+            Number::UInt(value)
+        })
+    } else if value != 0 && value <= i64::MAX as u64 + 1 {
+        Some(Number::Int((value as i64).wrapping_neg()))
+    } else {
+        None
+    }
+}
+
+/// Parse a number as an integer, or as a float if it is not an integer.
+///
+/// Numbers without a fraction or exponent are integers if they fit in
+/// an `i64`, or for positive numbers, a `u64`. Any other number, including
+/// integers that overflow, special values, and negative zero, is parsed
+/// as an `f64`. The digits are only scanned once. The entire string
+/// must be a valid number.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::mixed::{parse_number, Number};
+///
+/// # pub fn main() {
+/// assert_eq!(parse_number(b"-42"), Ok(Number::Int(-42)));
+/// assert_eq!(parse_number(b"18446744073709551615"), Ok(Number::UInt(u64::MAX)));
+/// assert_eq!(parse_number(b"18446744073709551616"), Ok(Number::Float(1.8446744073709552e19)));
+/// assert_eq!(parse_number(b"1e3"), Ok(Number::Float(1000.0)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_number(bytes: &[u8]) -> Result<Number> {
+    parse_number_with_options::<{ STANDARD }>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Parse a number as an integer, or as a float, with a custom format.
+///
+/// This is like [`parse_number`], but uses a custom number format and
+/// options. For formats where the exponent base differs from the
+/// mantissa radix, integers with more significant digits than always
+/// fit in a `u64` are parsed as floats.
+#[inline]
+pub fn parse_number_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<Number> {
+    parse_complete_mixed::<FORMAT>(bytes, options)
+}
@@ -44,6 +44,28 @@ impl<'a> Number<'a> {
             && !self.many_digits
     }
 
+    /// Move the trailing zeros of the mantissa into the exponent.
+    ///
+    /// The mantissa keeps the trailing zeros of the digits, so `10.00e-30`
+    /// has a mantissa of `1000` and an exponent of `-32`. Removing them
+    /// gives a smaller mantissa and a larger exponent with the same value,
+    /// which may be in range of the fast path when the original is not.
+    #[inline]
+    pub fn normalize_mantissa<const FORMAT: u128>(&self) -> Self {
+        let format = NumberFormat::<FORMAT> {};
+        let radix = format.radix() as u64;
+        let mut number = *self;
+        while number.mantissa != 0 {
+            let quotient = number.mantissa / radix;
+            if quotient * radix != number.mantissa {
+                break;
+            }
+            number.mantissa = quotient;
+            number.exponent += 1;
+        }
+        number
+    }
+
     /// The fast path algorithmn using machine-sized integers and floats.
     ///
     /// This is extracted into a separate function so that it can be attempted before constructing
@@ -52,10 +74,22 @@ impl<'a> Number<'a> {
     /// no rounding will occur.
     ///
     /// There is an exception: disguised fast-path cases, where we can shift
-    /// powers-of-10 from the exponent to the significant digits.
+    /// powers-of-10 from the exponent to the significant digits. Likewise,
+    /// trailing zeros are shifted from the significant digits to the
+    /// exponent, so `100e-24` uses the fast path like `1e-22`.
+    ///
+    /// With the `deterministic` feature, this always returns `None`, so
+    /// the float is always computed using integer arithmetic, and the
+    /// results are identical across all targets.
     pub fn try_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> Option<F> {
         let format = NumberFormat::<FORMAT> {};
         debug_assert!(format.mantissa_radix() == format.exponent_base());
+        // Native float arithmetic may use extended-precision intermediates
+        // on some targets (such as x87 FPUs without SSE2), so it is never
+        // used when bit-identical results are required.
+        if cfg!(feature = "deterministic") {
+            return None;
+        }
         // The fast path crucially depends on arithmetic being rounded to the correct number of bits
         // without any intermediate rounding. On x86 (without SSE or SSE2) this requires the precision
         // of the x87 FPU stack to be changed so that it directly rounds to 64/32 bit.
@@ -64,32 +98,39 @@ impl<'a> Number<'a> {
         #[cfg(feature = "nightly")]
         let _cw = set_precision::<F>();
 
-        if self.is_fast_path::<F, FORMAT>() {
+        // Trailing zeros, such as in `1.2300e-25`, may push a small value
+        // out of range of the fast path, so only normalize if needed.
+        let number = if self.is_fast_path::<F, FORMAT>() {
+            *self
+        } else {
+            self.normalize_mantissa::<FORMAT>()
+        };
+        if number.is_fast_path::<F, FORMAT>() {
             let radix = format.radix();
             let max_exponent = F::max_exponent_fast_path(radix);
-            let mut value = if self.exponent <= max_exponent {
+            let mut value = if number.exponent <= max_exponent {
                 // normal fast path
-                let value = F::as_cast(self.mantissa);
-                if self.exponent < 0 {
+                let value = F::as_cast(number.mantissa);
+                if number.exponent < 0 {
                     // SAFETY: safe, since the `exponent <= max_exponent`.
-                    value / unsafe { F::pow_fast_path((-self.exponent) as _, radix) }
+                    value / unsafe { F::pow_fast_path((-number.exponent) as _, radix) }
                 } else {
                     // SAFETY: safe, since the `exponent <= max_exponent`.
-                    value * unsafe { F::pow_fast_path(self.exponent as _, radix) }
+                    value * unsafe { F::pow_fast_path(number.exponent as _, radix) }
                 }
             } else {
                 // disguised fast path
-                let shift = self.exponent - max_exponent;
+                let shift = number.exponent - max_exponent;
                 // SAFETY: safe, since `shift <= (max_disguised - max_exponent)`.
                 let int_power = unsafe { F::int_pow_fast_path(shift as usize, radix) };
-                let mantissa = self.mantissa.checked_mul(int_power)?;
+                let mantissa = number.mantissa.checked_mul(int_power)?;
                 if mantissa > F::MAX_MANTISSA_FAST_PATH {
                     return None;
                 }
                 // SAFETY: safe, since the `table.len() - 1 == max_exponent`.
                 F::as_cast(mantissa) * unsafe { F::pow_fast_path(max_exponent as _, radix) }
             };
-            if self.is_negative {
+            if number.is_negative {
                 value = -value;
             }
             Some(value)
@@ -99,14 +140,16 @@ impl<'a> Number<'a> {
     }
 
     /// Force a fast-path algorithm, even when it may not be accurate.
+    ///
+    /// The exponent is scaled to the exponent base, which may differ
+    /// from the mantissa radix for power-of-two radixes.
     pub fn force_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> F {
         let format = NumberFormat::<FORMAT> {};
-        debug_assert!(format.mantissa_radix() == format.exponent_base());
 
         #[cfg(feature = "nightly")]
         let _cw = set_precision::<F>();
 
-        let radix = format.radix();
+        let radix = format.exponent_base();
         let mut value = F::as_cast(self.mantissa);
         let max_exponent = F::max_exponent_fast_path(radix);
         let mut exponent = self.exponent.abs();
@@ -1,11 +1,26 @@
 //! Configuration options for parsing floats.
 
+use core::num;
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::error::Error;
 use lexical_util::options::{self, ParseOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
+/// Type with the exact same size as a `usize`.
+pub type OptionUsize = Option<num::NonZeroUsize>;
+
+/// Enumeration for how to handle exponents that do not fit in an `i32`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "lowercase"))]
+pub enum ExponentMode {
+    /// Clamp the exponent, which always overflows or underflows the float.
+    Saturate,
+    /// Return [`Error::ExponentOverflow`] for the exponent.
+    Reject,
+}
+
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
@@ -25,6 +40,15 @@ pub struct OptionsBuilder {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Preserve the sign bit of negative zero and `NaN`.
+    preserve_sign: bool,
+    /// Maximum number of exponent digits, including leading zeros.
+    /// If not set, any number of exponent digits is allowed.
+    max_exponent_digits: OptionUsize,
+    /// How to handle exponents that do not fit in an `i32`.
+    exponent_mode: ExponentMode,
+    /// Flush subnormal results to zero, preserving the sign.
+    flush_subnormals: bool,
 }
 
 impl OptionsBuilder {
@@ -38,6 +62,10 @@ impl OptionsBuilder {
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
             infinity_string: Some(b"infinity"),
+            preserve_sign: true,
+            max_exponent_digits: None,
+            exponent_mode: ExponentMode::Saturate,
+            flush_subnormals: false,
         }
     }
 
@@ -79,6 +107,30 @@ impl OptionsBuilder {
         self.infinity_string
     }
 
+    /// Get if we preserve the sign bit of negative zero and `NaN`.
+    #[inline(always)]
+    pub const fn get_preserve_sign(&self) -> bool {
+        self.preserve_sign
+    }
+
+    /// Get the maximum number of exponent digits.
+    #[inline(always)]
+    pub const fn get_max_exponent_digits(&self) -> OptionUsize {
+        self.max_exponent_digits
+    }
+
+    /// Get how to handle exponents that do not fit in an `i32`.
+    #[inline(always)]
+    pub const fn get_exponent_mode(&self) -> ExponentMode {
+        self.exponent_mode
+    }
+
+    /// Get if subnormal results are flushed to zero.
+    #[inline(always)]
+    pub const fn get_flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -123,6 +175,49 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we preserve the sign bit of negative zero and `NaN`.
+    ///
+    /// If false, `-0.0` and `-NaN` are parsed as positive zero and `NaN`.
+    #[inline(always)]
+    pub const fn preserve_sign(mut self, preserve_sign: bool) -> Self {
+        self.preserve_sign = preserve_sign;
+        self
+    }
+
+    /// Set the maximum number of exponent digits.
+    ///
+    /// Leading zeros are counted, so this limits the length of the
+    /// exponent. Longer exponents return [`Error::ExponentTooLong`].
+    #[inline(always)]
+    pub const fn max_exponent_digits(mut self, max_exponent_digits: OptionUsize) -> Self {
+        self.max_exponent_digits = max_exponent_digits;
+        self
+    }
+
+    /// Set how to handle exponents that do not fit in an `i32`.
+    ///
+    /// By default, these exponents are clamped, so `1e10000000000` is
+    /// infinity, and `1e-10000000000` is zero.
+    #[inline(always)]
+    pub const fn exponent_mode(mut self, exponent_mode: ExponentMode) -> Self {
+        self.exponent_mode = exponent_mode;
+        self
+    }
+
+    /// Set if subnormal results are flushed to zero.
+    ///
+    /// If true, floats that round to a subnormal value are parsed as zero
+    /// with the same sign, like hardware with flush-to-zero (FTZ) and
+    /// denormals-are-zero (DAZ) modes, so `1e-310` is parsed as `0.0` for
+    /// an `f64`. Floats that round up to the smallest normal value are not
+    /// flushed. This applies to every parser with these options, including
+    /// the rounded parsers, which flush the float after rounding the digits.
+    #[inline(always)]
+    pub const fn flush_subnormals(mut self, flush_subnormals: bool) -> Self {
+        self.flush_subnormals = flush_subnormals;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -231,6 +326,10 @@ impl OptionsBuilder {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            preserve_sign: self.preserve_sign,
+            max_exponent_digits: self.max_exponent_digits,
+            exponent_mode: self.exponent_mode,
+            flush_subnormals: self.flush_subnormals,
         }
     }
 
@@ -328,6 +427,15 @@ pub struct Options {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Preserve the sign bit of negative zero and `NaN`.
+    preserve_sign: bool,
+    /// Maximum number of exponent digits, including leading zeros.
+    /// If not set, any number of exponent digits is allowed.
+    max_exponent_digits: OptionUsize,
+    /// How to handle exponents that do not fit in an `i32`.
+    exponent_mode: ExponentMode,
+    /// Flush subnormal results to zero, preserving the sign.
+    flush_subnormals: bool,
 }
 
 impl Options {
@@ -340,6 +448,12 @@ impl Options {
         unsafe { Self::builder().build_unchecked() }
     }
 
+    /// Get the pre-defined options with a name from [`PRESETS`], like `"JSON"`.
+    #[inline]
+    pub fn preset(name: &str) -> Option<Self> {
+        PRESETS.iter().find(|preset| preset.0 == name).map(|preset| preset.1.clone())
+    }
+
     /// Create the default options for a given radix.
     #[inline(always)]
     #[cfg(feature = "power-of-two")]
@@ -399,6 +513,30 @@ impl Options {
         self.infinity_string
     }
 
+    /// Get if we preserve the sign bit of negative zero and `NaN`.
+    #[inline(always)]
+    pub const fn preserve_sign(&self) -> bool {
+        self.preserve_sign
+    }
+
+    /// Get the maximum number of exponent digits.
+    #[inline(always)]
+    pub const fn max_exponent_digits(&self) -> OptionUsize {
+        self.max_exponent_digits
+    }
+
+    /// Get how to handle exponents that do not fit in an `i32`.
+    #[inline(always)]
+    pub const fn exponent_mode(&self) -> ExponentMode {
+        self.exponent_mode
+    }
+
+    /// Get if subnormal results are flushed to zero.
+    #[inline(always)]
+    pub const fn flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -466,6 +604,46 @@ impl Options {
         self.infinity_string = infinity_string
     }
 
+    /// Set if we preserve the sign bit of negative zero and `NaN`.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_preserve_sign(&mut self, preserve_sign: bool) {
+        self.preserve_sign = preserve_sign
+    }
+
+    /// Set the maximum number of exponent digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_max_exponent_digits(&mut self, max_exponent_digits: OptionUsize) {
+        self.max_exponent_digits = max_exponent_digits
+    }
+
+    /// Set how to handle exponents that do not fit in an `i32`.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_exponent_mode(&mut self, exponent_mode: ExponentMode) {
+        self.exponent_mode = exponent_mode
+    }
+
+    /// Set if subnormal results are flushed to zero.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_flush_subnormals(&mut self, flush_subnormals: bool) {
+        self.flush_subnormals = flush_subnormals
+    }
+
     // BUILDERS
 
     /// Get OptionsBuilder as a static function.
@@ -484,6 +662,10 @@ impl Options {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            preserve_sign: self.preserve_sign,
+            max_exponent_digits: self.max_exponent_digits,
+            exponent_mode: self.exponent_mode,
+            flush_subnormals: self.flush_subnormals,
         }
     }
 }
@@ -500,6 +682,11 @@ impl ParseOptions for Options {
     fn is_valid(&self) -> bool {
         Self::is_valid(self)
     }
+
+    #[inline(always)]
+    fn validate(&self) -> Result<()> {
+        self.rebuild().build().map(|_| ())
+    }
 }
 
 /// Unwrap `Option` as a const fn.
@@ -1209,3 +1396,177 @@ pub const MONGODB: Options = unsafe {
         .build_unchecked()
 };
 const_assert!(MONGODB.is_valid());
+
+/// Pre-defined options, with the name of each constant.
+///
+/// Options can be loaded by name with [`Options::preset`].
+pub const PRESETS: [(&str, Options); 66] = [
+    ("STANDARD", STANDARD),
+    ("DECIMAL_COMMA", DECIMAL_COMMA),
+    ("HEX_FLOAT", HEX_FLOAT),
+    ("CARAT_EXPONENT", CARAT_EXPONENT),
+    ("RUST_LITERAL", RUST_LITERAL),
+    ("PYTHON_LITERAL", PYTHON_LITERAL),
+    ("CXX_LITERAL", CXX_LITERAL),
+    ("C_LITERAL", C_LITERAL),
+    ("RUBY_LITERAL", RUBY_LITERAL),
+    ("RUBY_STRING", RUBY_STRING),
+    ("SWIFT_LITERAL", SWIFT_LITERAL),
+    ("GO_LITERAL", GO_LITERAL),
+    ("HASKELL_LITERAL", HASKELL_LITERAL),
+    ("HASKELL_STRING", HASKELL_STRING),
+    ("JAVASCRIPT_LITERAL", JAVASCRIPT_LITERAL),
+    ("JAVASCRIPT_STRING", JAVASCRIPT_STRING),
+    ("PERL_LITERAL", PERL_LITERAL),
+    ("PHP_LITERAL", PHP_LITERAL),
+    ("JAVA_LITERAL", JAVA_LITERAL),
+    ("JAVA_STRING", JAVA_STRING),
+    ("R_LITERAL", R_LITERAL),
+    ("KOTLIN_LITERAL", KOTLIN_LITERAL),
+    ("KOTLIN_STRING", KOTLIN_STRING),
+    ("JULIA_LITERAL", JULIA_LITERAL),
+    ("CSHARP_LITERAL", CSHARP_LITERAL),
+    ("CSHARP_STRING", CSHARP_STRING),
+    ("KAWA_LITERAL", KAWA_LITERAL),
+    ("KAWA_STRING", KAWA_STRING),
+    ("GAMBITC_LITERAL", GAMBITC_LITERAL),
+    ("GAMBITC_STRING", GAMBITC_STRING),
+    ("GUILE_LITERAL", GUILE_LITERAL),
+    ("GUILE_STRING", GUILE_STRING),
+    ("CLOJURE_LITERAL", CLOJURE_LITERAL),
+    ("CLOJURE_STRING", CLOJURE_STRING),
+    ("ERLANG_LITERAL", ERLANG_LITERAL),
+    ("ERLANG_STRING", ERLANG_STRING),
+    ("ELM_LITERAL", ELM_LITERAL),
+    ("ELM_STRING", ELM_STRING),
+    ("SCALA_LITERAL", SCALA_LITERAL),
+    ("SCALA_STRING", SCALA_STRING),
+    ("ELIXIR_LITERAL", ELIXIR_LITERAL),
+    ("ELIXIR_STRING", ELIXIR_STRING),
+    ("FORTRAN_LITERAL", FORTRAN_LITERAL),
+    ("D_LITERAL", D_LITERAL),
+    ("COFFEESCRIPT_LITERAL", COFFEESCRIPT_LITERAL),
+    ("COFFEESCRIPT_STRING", COFFEESCRIPT_STRING),
+    ("COBOL_LITERAL", COBOL_LITERAL),
+    ("COBOL_STRING", COBOL_STRING),
+    ("FSHARP_LITERAL", FSHARP_LITERAL),
+    ("VB_LITERAL", VB_LITERAL),
+    ("VB_STRING", VB_STRING),
+    ("OCAML_LITERAL", OCAML_LITERAL),
+    ("OBJECTIVEC_LITERAL", OBJECTIVEC_LITERAL),
+    ("OBJECTIVEC_STRING", OBJECTIVEC_STRING),
+    ("REASONML_LITERAL", REASONML_LITERAL),
+    ("MATLAB_LITERAL", MATLAB_LITERAL),
+    ("ZIG_LITERAL", ZIG_LITERAL),
+    ("SAGE_LITERAL", SAGE_LITERAL),
+    ("JSON", JSON),
+    ("TOML", TOML),
+    ("YAML", YAML),
+    ("XML", XML),
+    ("SQLITE", SQLITE),
+    ("POSTGRESQL", POSTGRESQL),
+    ("MYSQL", MYSQL),
+    ("MONGODB", MONGODB),
+];
+
+// SERDE
+// -----
+
+// The options are deserialized as overrides for the options of the named
+// `preset`, or the standard options, using the shared utilities in
+// `lexical_util::config`.
+
+#[cfg(feature = "serde")]
+mod config {
+    use super::{ExponentMode, OptionUsize, Options};
+    use lexical_util::config::{present, to_byte, to_static, to_str};
+    use serde_crate::de::{self, Deserializer, Unexpected};
+    use serde_crate::ser::{SerializeStruct, Serializer};
+    use serde_crate::{Deserialize, Serialize};
+    use std::string::String;
+
+    /// Overrides for the fields of preset options.
+    #[derive(Default, Deserialize)]
+    #[serde(crate = "serde_crate", default, deny_unknown_fields)]
+    struct Config {
+        preset: Option<String>,
+        lossy: Option<bool>,
+        exponent: Option<char>,
+        decimal_point: Option<char>,
+        #[serde(deserialize_with = "present")]
+        nan_string: Option<Option<String>>,
+        #[serde(deserialize_with = "present")]
+        inf_string: Option<Option<String>>,
+        #[serde(deserialize_with = "present")]
+        infinity_string: Option<Option<String>>,
+        preserve_sign: Option<bool>,
+        #[serde(deserialize_with = "present")]
+        max_exponent_digits: Option<OptionUsize>,
+        exponent_mode: Option<ExponentMode>,
+        flush_subnormals: Option<bool>,
+    }
+
+    impl Serialize for Options {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Options", 10)?;
+            state.serialize_field("lossy", &self.lossy())?;
+            state.serialize_field("exponent", &(self.exponent() as char))?;
+            state.serialize_field("decimal_point", &(self.decimal_point() as char))?;
+            state.serialize_field("nan_string", &to_str::<S::Error>(self.nan_string())?)?;
+            state.serialize_field("inf_string", &to_str::<S::Error>(self.inf_string())?)?;
+            let infinity_string = to_str::<S::Error>(self.infinity_string())?;
+            state.serialize_field("infinity_string", &infinity_string)?;
+            state.serialize_field("preserve_sign", &self.preserve_sign())?;
+            state.serialize_field("max_exponent_digits", &self.max_exponent_digits())?;
+            state.serialize_field("exponent_mode", &self.exponent_mode())?;
+            state.serialize_field("flush_subnormals", &self.flush_subnormals())?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Options {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let config = Config::deserialize(deserializer)?;
+            let preset = match config.preset {
+                Some(name) => Options::preset(&name).ok_or_else(|| {
+                    de::Error::invalid_value(Unexpected::Str(&name), &"a name from `PRESETS`")
+                })?,
+                None => Options::new(),
+            };
+
+            let mut builder = preset.rebuild();
+            if let Some(lossy) = config.lossy {
+                builder = builder.lossy(lossy);
+            }
+            if let Some(exponent) = config.exponent {
+                builder = builder.exponent(to_byte(exponent)?);
+            }
+            if let Some(decimal_point) = config.decimal_point {
+                builder = builder.decimal_point(to_byte(decimal_point)?);
+            }
+            if let Some(nan_string) = config.nan_string {
+                builder = builder.nan_string(to_static(nan_string, preset.nan_string()));
+            }
+            if let Some(inf_string) = config.inf_string {
+                builder = builder.inf_string(to_static(inf_string, preset.inf_string()));
+            }
+            if let Some(infinity_string) = config.infinity_string {
+                let infinity_string = to_static(infinity_string, preset.infinity_string());
+                builder = builder.infinity_string(infinity_string);
+            }
+            if let Some(preserve_sign) = config.preserve_sign {
+                builder = builder.preserve_sign(preserve_sign);
+            }
+            if let Some(max_exponent_digits) = config.max_exponent_digits {
+                builder = builder.max_exponent_digits(max_exponent_digits);
+            }
+            if let Some(exponent_mode) = config.exponent_mode {
+                builder = builder.exponent_mode(exponent_mode);
+            }
+            if let Some(flush_subnormals) = config.flush_subnormals {
+                builder = builder.flush_subnormals(flush_subnormals);
+            }
+            builder.build().map_err(de::Error::custom)
+        }
+    }
+}
@@ -10,24 +10,35 @@ use crate::bellerophon::bellerophon;
 #[cfg(feature = "power-of-two")]
 use crate::binary::{binary, slow_binary};
 use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
+#[cfg(all(feature = "float160", not(feature = "compact")))]
+use crate::float160::float160;
+#[cfg(feature = "hooks")]
+use crate::hooks::{self, Path};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
+use crate::mixed;
 use crate::number::Number;
-use crate::options::Options;
+use crate::options::{ExponentMode, Options};
 use crate::shared;
-use crate::slow::slow_radix;
+use crate::slow::{slow_radix, slow_radix_in};
+use crate::workspace::Workspace;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
+use lexical_parse_integer::Sign;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
-use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
+use lexical_util::digit::{char_is_digit_const, char_to_digit_const, char_to_valid_digit_const};
 use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
+use lexical_util::hint::cold_error;
 use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
+use lexical_util::num::Float;
 use lexical_util::result::Result;
+use lexical_util::sign;
 use lexical_util::step::u64_step;
+use lexical_util::wrapper;
 
 // API
 // ---
@@ -44,6 +55,15 @@ macro_rules! is_power_two {
 macro_rules! check_radix {
     ($format:ident) => {{
         let format = NumberFormat::<{ $format }> {};
+        #[cfg(feature = "radix")]
+        {
+            // The extended alphabet for radixes above 36 is only
+            // supported for integers.
+            if format.radix() > 36 {
+                return Err(Error::InvalidMantissaRadix);
+            }
+        }
+
         #[cfg(feature = "power-of-two")]
         {
             if format.radix() != format.exponent_base() {
@@ -66,27 +86,124 @@ macro_rules! check_radix {
     }};
 }
 
+/// Flush subnormal floats to zero, if set by the options, and clear the
+/// sign bit of negative zero and `NaN`, unless the options preserve it.
+#[inline(always)]
+pub(crate) fn apply_float_options<F: Float>(float: F, options: &Options) -> F {
+    let mut float = float;
+    if options.flush_subnormals() && float.is_denormal() {
+        float = F::from_bits(float.to_bits() & F::SIGN_MASK);
+    }
+    if !options.preserve_sign() && (float == F::ZERO || float.is_nan()) {
+        F::from_bits(float.to_bits() & !F::SIGN_MASK)
+    } else {
+        float
+    }
+}
+
+/// Parse a float that may be wrapped or have a trailing sign, using a complete parser.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn parse_wrapped_complete<F, Parse, const FORMAT: u128>(bytes: &[u8], parse: Parse) -> Result<F>
+where
+    F: Float,
+    Parse: FnOnce(&[u8]) -> Result<F>,
+{
+    if !wrapper::is_wrapped::<FORMAT>() {
+        return sign::parse_complete::<_, _, FORMAT>(bytes, parse);
+    }
+    wrapper::parse_complete::<_, _, FORMAT>(bytes, |bytes, is_negative| {
+        // Numbers in parentheses cannot have a sign.
+        if is_negative {
+            parse(bytes).map(|float| -float)
+        } else {
+            sign::parse_complete::<_, _, FORMAT>(bytes, parse)
+        }
+    })
+}
+
+/// Parse a float that may be wrapped or have a trailing sign, using a partial parser.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn parse_wrapped_partial<F, Parse, const FORMAT: u128>(
+    bytes: &[u8],
+    parse: Parse,
+) -> Result<(F, usize)>
+where
+    F: Float,
+    Parse: FnOnce(&[u8]) -> Result<(F, usize)>,
+{
+    if !wrapper::is_wrapped::<FORMAT>() {
+        return sign::parse_partial::<_, _, FORMAT>(bytes, parse);
+    }
+    wrapper::parse_partial::<_, _, FORMAT>(bytes, |bytes, is_negative| {
+        // Numbers in parentheses cannot have a sign.
+        if is_negative {
+            parse(bytes).map(|(float, count)| (-float, count))
+        } else {
+            sign::parse_partial::<_, _, FORMAT>(bytes, parse)
+        }
+    })
+}
+
 /// Parse integer trait, implemented in terms of the optimized back-end.
 pub trait ParseFloat: LemireFloat {
     /// Forward complete parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
         check_radix!(FORMAT);
-        parse_complete::<Self, FORMAT>(bytes, options)
+        let float = parse_wrapped_complete::<_, _, FORMAT>(bytes, |bytes| {
+            parse_complete::<Self, FORMAT>(bytes, options)
+        })?;
+        Ok(apply_float_options(float, options))
     }
 
     /// Forward partial parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
         check_radix!(FORMAT);
-        parse_partial::<Self, FORMAT>(bytes, options)
+        let (float, count) = parse_wrapped_partial::<_, _, FORMAT>(bytes, |bytes| {
+            parse_partial::<Self, FORMAT>(bytes, options)
+        })?;
+        Ok((apply_float_options(float, options), count))
+    }
+
+    /// Forward complete parser parameters to the backend, using a
+    /// caller-supplied workspace for the slow path.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_in<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        workspace: &mut Workspace,
+    ) -> Result<Self> {
+        check_radix!(FORMAT);
+        let float = parse_wrapped_complete::<_, _, FORMAT>(bytes, |bytes| {
+            parse_complete_in::<Self, FORMAT>(bytes, options, Some(workspace))
+        })?;
+        Ok(apply_float_options(float, options))
+    }
+
+    /// Forward partial parser parameters to the backend, using a
+    /// caller-supplied workspace for the slow path.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_in<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        workspace: &mut Workspace,
+    ) -> Result<(Self, usize)> {
+        check_radix!(FORMAT);
+        let (float, count) = parse_wrapped_partial::<_, _, FORMAT>(bytes, |bytes| {
+            parse_partial_in::<Self, FORMAT>(bytes, options, Some(workspace))
+        })?;
+        Ok((apply_float_options(float, options), count))
     }
 
     /// Forward complete parser parameters to the backend, using only the fast path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
         check_radix!(FORMAT);
-        fast_path_complete::<Self, FORMAT>(bytes, options)
+        let float = parse_wrapped_complete::<_, _, FORMAT>(bytes, |bytes| {
+            fast_path_complete::<Self, FORMAT>(bytes, options)
+        })?;
+        Ok(apply_float_options(float, options))
     }
 
     /// Forward partial parser parameters to the backend, using only the fast path.
@@ -96,7 +213,23 @@ pub trait ParseFloat: LemireFloat {
         options: &Options,
     ) -> Result<(Self, usize)> {
         check_radix!(FORMAT);
-        fast_path_partial::<Self, FORMAT>(bytes, options)
+        let (float, count) = parse_wrapped_partial::<_, _, FORMAT>(bytes, |bytes| {
+            fast_path_partial::<Self, FORMAT>(bytes, options)
+        })?;
+        Ok((apply_float_options(float, options), count))
+    }
+
+    /// Forward the separated integer and fraction digits, and exponent, to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_parts<const FORMAT: u128>(
+        integer: &[u8],
+        fraction: &[u8],
+        exponent: i32,
+        options: &Options,
+    ) -> Result<Self> {
+        check_radix!(FORMAT);
+        let float = parse_parts::<Self, FORMAT>(integer, fraction, exponent, options)?;
+        Ok(apply_float_options(float, options))
     }
 }
 
@@ -116,7 +249,8 @@ macro_rules! parse_float_as_f32 {
             fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
             {
-                Ok(Self::from_f32(parse_complete::<f32, FORMAT>(bytes, options)?))
+                let float = parse_complete::<f32, FORMAT>(bytes, options)?;
+                Ok(apply_float_options(Self::from_f32(float), options))
             }
 
             #[cfg_attr(not(feature = "compact"), inline(always))]
@@ -124,14 +258,36 @@ macro_rules! parse_float_as_f32 {
                 -> Result<(Self, usize)>
             {
                 let (float, count) = parse_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
+                Ok((apply_float_options(Self::from_f32(float), options), count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_in<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+                workspace: &mut Workspace,
+            ) -> Result<Self> {
+                let float = parse_complete_in::<f32, FORMAT>(bytes, options, Some(workspace))?;
+                Ok(apply_float_options(Self::from_f32(float), options))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_in<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+                workspace: &mut Workspace,
+            ) -> Result<(Self, usize)> {
+                let (float, count) =
+                    parse_partial_in::<f32, FORMAT>(bytes, options, Some(workspace))?;
+                Ok((apply_float_options(Self::from_f32(float), options), count))
             }
 
             #[cfg_attr(not(feature = "compact"), inline(always))]
             fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
             {
-                Ok(Self::from_f32(fast_path_complete::<f32, FORMAT>(bytes, options)?))
+                let float = fast_path_complete::<f32, FORMAT>(bytes, options)?;
+                Ok(apply_float_options(Self::from_f32(float), options))
             }
 
             #[cfg_attr(not(feature = "compact"), inline(always))]
@@ -139,7 +295,18 @@ macro_rules! parse_float_as_f32 {
                 -> Result<(Self, usize)>
             {
                 let (float, count) = fast_path_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
+                Ok((apply_float_options(Self::from_f32(float), options), count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_parts<const FORMAT: u128>(
+                integer: &[u8],
+                fraction: &[u8],
+                exponent: i32,
+                options: &Options,
+            ) -> Result<Self> {
+                let float = parse_parts::<f32, FORMAT>(integer, fraction, exponent, options)?;
+                Ok(apply_float_options(Self::from_f32(float), options))
             }
         }
     )*)
@@ -162,11 +329,11 @@ macro_rules! parse_mantissa_sign {
         match $byte.integer_iter().peek() {
             Some(&b'+') if !$format.no_positive_mantissa_sign() => (false, 1),
             Some(&b'+') if $format.no_positive_mantissa_sign() => {
-                return Err(Error::InvalidPositiveSign($byte.cursor()));
+                return cold_error(Error::InvalidPositiveSign($byte.cursor()));
             },
             Some(&b'-') => (true, 1),
             Some(_) if $format.required_mantissa_sign() => {
-                return Err(Error::MissingSign($byte.cursor()));
+                return cold_error(Error::MissingSign($byte.cursor()));
             },
             _ => (false, 0),
         }
@@ -178,11 +345,11 @@ macro_rules! parse_exponent_sign {
         match $byte.integer_iter().peek() {
             Some(&b'+') if !$format.no_positive_exponent_sign() => (false, 1),
             Some(&b'+') if $format.no_positive_exponent_sign() => {
-                return Err(Error::InvalidPositiveExponentSign($byte.cursor()));
+                return cold_error(Error::InvalidPositiveExponentSign($byte.cursor()));
             },
             Some(&b'-') => (true, 1),
             Some(_) if $format.required_mantissa_sign() => {
-                return Err(Error::MissingExponentSign($byte.cursor()));
+                return cold_error(Error::MissingExponentSign($byte.cursor()));
             },
             _ => (false, 0),
         }
@@ -205,6 +372,8 @@ macro_rules! parse_number {
                 if let Some(value) =
                     $parse_special::<_, $format>($byte.clone(), $is_negative, $options)
                 {
+                    #[cfg(feature = "hooks")]
+                    hooks::emit_special();
                     return Ok(value);
                 } else {
                     return Err(e);
@@ -225,10 +394,36 @@ macro_rules! to_native {
     }};
 }
 
+/// Determine if the float is parsed by concatenating the bits of each digit.
+///
+/// Power-of-two radixes are always exactly represented by the bits of the
+/// significant digits, even for arbitrary-length fractions or if the exponent
+/// base differs from the mantissa radix, so the native float fast-path is
+/// never used.
+#[inline(always)]
+fn is_binary<const FORMAT: u128>() -> bool {
+    #[cfg(feature = "power-of-two")]
+    return is_power_two!(NumberFormat::<{ FORMAT }>::MANTISSA_RADIX);
+
+    #[cfg(not(feature = "power-of-two"))]
+    return false;
+}
+
 /// Parse a float from bytes using a complete parser.
+#[inline(always)]
 pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     bytes: &[u8],
     options: &Options,
+) -> Result<F> {
+    parse_complete_in::<F, FORMAT>(bytes, options, None)
+}
+
+/// Parse a float from bytes using a complete parser, and an optional
+/// workspace for the slow path.
+pub fn parse_complete_in<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    workspace: Option<&mut Workspace>,
 ) -> Result<F> {
     let format = NumberFormat::<{ FORMAT }> {};
     let mut byte = bytes.bytes::<{ FORMAT }>();
@@ -236,14 +431,144 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
     if byte.integer_iter().is_consumed() {
-        return Err(Error::Empty(byte.cursor()));
+        return cold_error(Error::Empty(byte.cursor()));
     }
 
     // Parse our a small representation of our number.
     let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
+    Ok(to_float::<F, FORMAT>(num, options, workspace))
+}
+
+/// Parse a number from bytes using a complete parser, as an integer if
+/// possible, and otherwise as a float.
+///
+/// Numbers without a fraction or exponent that fit in a 64-bit integer
+/// are converted from the parsed significant digits, so the digits are
+/// only scanned once, even if the number overflows and is parsed as a
+/// float.
+pub fn parse_complete_mixed<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<mixed::Number> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return cold_error(Error::Empty(byte.cursor()));
+    }
+
+    let num = match parse_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(num) => num,
+        Err(error) => {
+            return match parse_special::<f64, FORMAT>(byte, is_negative, options) {
+                Some(value) => {
+                    #[cfg(feature = "hooks")]
+                    hooks::emit_special();
+                    Ok(mixed::Number::Float(apply_float_options(value, options)))
+                },
+                None => Err(error),
+            };
+        },
+    };
+    match mixed::to_integer::<FORMAT>(bytes, &num) {
+        Some(integer) => Ok(integer),
+        None => {
+            let float = to_float::<f64, FORMAT>(num, options, None);
+            Ok(mixed::Number::Float(apply_float_options(float, options)))
+        },
+    }
+}
+
+/// Parse a float from the integer and fraction digits, and an exponent.
+///
+/// This is for tokenizers that have already split the number on the
+/// decimal point and the exponent, so the digits don't need to be joined
+/// into a single buffer, and the result is rounded exactly like a float
+/// parsed from the joined string. The integer digits may start with a
+/// sign, and the exponent is a power of the exponent base. Only the
+/// digits of the mantissa radix are accepted: digit separators, special
+/// values, and the other syntax flags of the format aren't supported.
+///
+/// The index of an error is the index in the integer digits joined with
+/// the fraction digits by a decimal point, so the first fraction digit
+/// is at `integer.len() + 1`.
+pub fn parse_parts<F: LemireFloat, const FORMAT: u128>(
+    integer: &[u8],
+    fraction: &[u8],
+    exponent: i32,
+    options: &Options,
+) -> Result<F> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let mut byte = integer.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    let integer = unsafe { integer.get_unchecked(shift..) };
+    if integer.is_empty() && fraction.is_empty() {
+        return cold_error(Error::EmptyMantissa(shift));
+    }
+
+    // Parse the digits that fit in a 64-bit mantissa, skipping leading
+    // zeros, and count the truncated digits after them.
+    let step = u64_step(radix);
+    let mut mantissa = 0_u64;
+    let mut count = 0;
+    let mut truncated = 0_usize;
+    let mut many_digits = false;
+    for (index, &c) in integer.iter().chain(fraction.iter()).enumerate() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) => digit,
+            None if index < integer.len() => return cold_error(Error::InvalidDigit(shift + index)),
+            None => return cold_error(Error::InvalidDigit(shift + index + 1)),
+        };
+        if count < step {
+            mantissa = mantissa * radix as u64 + digit as u64;
+            count += (mantissa != 0) as usize;
+        } else {
+            truncated += 1;
+            many_digits |= digit != 0;
+        }
+    }
+
+    // The truncated digits scale the mantissa, and the fraction digits
+    // are below the decimal point.
+    let implicit_exponent = truncated as i64 - fraction.len() as i64;
+    let implicit_exponent = if format.mantissa_radix() == format.exponent_base() {
+        implicit_exponent
+    } else {
+        let bits_per_digit = shared::log2(format.mantissa_radix()) as i64;
+        let bits_per_base = shared::log2(format.exponent_base()) as i64;
+        debug_assert!(bits_per_digit % bits_per_base == 0);
+        implicit_exponent * bits_per_digit / bits_per_base
+    };
+    let num = Number {
+        exponent: implicit_exponent + exponent as i64,
+        mantissa,
+        is_negative,
+        many_digits,
+        integer,
+        fraction: Some(fraction),
+    };
+    Ok(to_float::<F, FORMAT>(num, options, None))
+}
+
+/// Convert the significant digits and exponent of a number to a float.
+#[inline(always)]
+fn to_float<F: LemireFloat, const FORMAT: u128>(
+    num: Number,
+    options: &Options,
+    workspace: Option<&mut Workspace>,
+) -> F {
+    let is_negative = num.is_negative;
     // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
+    if !is_binary::<FORMAT>() {
+        if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+            #[cfg(feature = "hooks")]
+            hooks::emit(Path::Fast, &num);
+            return value;
+        }
     }
     // Now try the moderate path algorithm.
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
@@ -253,13 +578,18 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     // lossy, we can't be here.
     if fp.exp < 0 {
         debug_assert!(!options.lossy());
+        #[cfg(feature = "hooks")]
+        hooks::emit(Path::Slow, &num);
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        fp = slow_path::<F, FORMAT>(num, fp, workspace);
+    } else {
+        #[cfg(feature = "hooks")]
+        hooks::emit(Path::Moderate, &num);
     }
 
     // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    to_native!(F, fp, is_negative)
 }
 
 /// Parse a float using only the fast path as a complete parser.
@@ -273,18 +603,31 @@ pub fn fast_path_complete<F: LemireFloat, const FORMAT: u128>(
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
     if byte.integer_iter().is_consumed() {
-        return Err(Error::Empty(byte.cursor()));
+        return cold_error(Error::Empty(byte.cursor()));
     }
 
     // Parse our a small representation of our number.
     let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
+    #[cfg(feature = "hooks")]
+    hooks::emit(Path::Fast, &num);
     Ok(num.force_fast_path::<_, FORMAT>())
 }
 
 /// Parse a float from bytes using a partial parser.
+#[inline(always)]
 pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     bytes: &[u8],
     options: &Options,
+) -> Result<(F, usize)> {
+    parse_partial_in::<F, FORMAT>(bytes, options, None)
+}
+
+/// Parse a float from bytes using a partial parser, and an optional
+/// workspace for the slow path.
+pub fn parse_partial_in<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    workspace: Option<&mut Workspace>,
 ) -> Result<(F, usize)> {
     let format = NumberFormat::<{ FORMAT }> {};
     let mut byte = bytes.bytes::<{ FORMAT }>();
@@ -292,7 +635,7 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
     if byte.integer_iter().is_consumed() {
-        return Err(Error::Empty(byte.cursor()));
+        return cold_error(Error::Empty(byte.cursor()));
     }
 
     // Parse our a small representation of our number.
@@ -305,8 +648,12 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         parse_partial_special
     );
     // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+    if !is_binary::<FORMAT>() {
+        if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+            #[cfg(feature = "hooks")]
+            hooks::emit(Path::Fast, &num);
+            return Ok((value, count));
+        }
     }
     // Now try the moderate path algorithm.
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
@@ -316,9 +663,14 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     // lossy, we can't be here.
     if fp.exp < 0 {
         debug_assert!(!options.lossy());
+        #[cfg(feature = "hooks")]
+        hooks::emit(Path::Slow, &num);
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        fp = slow_path::<F, FORMAT>(num, fp, workspace);
+    } else {
+        #[cfg(feature = "hooks")]
+        hooks::emit(Path::Moderate, &num);
     }
 
     // Convert to native float and return result.
@@ -336,7 +688,7 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
     // SAFETY: safe since we shift at most one for a parsed sign byte.
     unsafe { byte.step_by_unchecked(shift) };
     if byte.integer_iter().is_consumed() {
-        return Err(Error::Empty(byte.cursor()));
+        return cold_error(Error::Empty(byte.cursor()));
     }
 
     // Parse our a small representation of our number.
@@ -348,6 +700,8 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
         parse_partial_number,
         parse_partial_special
     );
+    #[cfg(feature = "hooks")]
+    hooks::emit(Path::Fast, &num);
     Ok((num.force_fast_path::<_, FORMAT>(), count))
 }
 
@@ -387,7 +741,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                decimal_moderate_path::<F, FORMAT>(num, lossy)
             } else if is_power_two!(radix) {
                 // Implement the power-of-two backends.
                 binary::<F, FORMAT>(num, lossy)
@@ -402,7 +756,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let radix = format.mantissa_radix();
             debug_assert!(matches!(radix, 2 | 4 | 8 | 10 | 16 | 32));
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                decimal_moderate_path::<F, FORMAT>(num, lossy)
             } else {
                 // Implement the power-of-two backends.
                 binary::<F, FORMAT>(num, lossy)
@@ -411,21 +765,56 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            lemire::<F>(num, lossy)
+            decimal_moderate_path::<F, FORMAT>(num, lossy)
         }
     }
 }
 
+/// Invoke the moderate path algorithm for a decimal radix.
+///
+/// With the `float160` feature, lossy parsing of floats with more than
+/// 19 significant digits uses 160-bit extended floats, which are more
+/// accurate than the Eisel-Lemire algorithm on truncated digits.
+#[inline(always)]
+#[cfg(not(feature = "compact"))]
+fn decimal_moderate_path<F: LemireFloat, const FORMAT: u128>(
+    num: &Number,
+    lossy: bool,
+) -> ExtendedFloat80 {
+    #[cfg(feature = "float160")]
+    {
+        if lossy && num.many_digits {
+            return float160::<F, FORMAT>(num);
+        }
+    }
+    lemire::<F>(num, lossy)
+}
+
+/// Invoke the slow path algorithm for a non-binary radix.
+#[inline(always)]
+fn slow_path_radix<F: LemireFloat, const FORMAT: u128>(
+    num: Number,
+    fp: ExtendedFloat80,
+    workspace: Option<&mut Workspace>,
+) -> ExtendedFloat80 {
+    match workspace {
+        Some(workspace) => slow_radix_in::<F, FORMAT>(num, fp, workspace),
+        None => slow_radix::<F, FORMAT>(num, fp),
+    }
+}
+
 /// Invoke the slow path.
 /// At this point, the float string has already been validated.
-#[inline]
+/// If no workspace is provided, the big integers are created on the stack.
+#[cold]
 pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    workspace: Option<&mut Workspace>,
 ) -> ExtendedFloat80 {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_path_radix::<F, FORMAT>(num, fp, workspace)
     }
 
     #[cfg(feature = "power-of-two")]
@@ -434,7 +823,7 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
         if is_power_two!(format.mantissa_radix()) {
             slow_binary::<F, FORMAT>(num)
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_path_radix::<F, FORMAT>(num, fp, workspace)
         }
     }
 }
@@ -509,7 +898,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 // SAFETY: safe since `byte.len() >= 1`.
                 unsafe { iter.step_unchecked() };
                 if iter.is_done() {
-                    return Err(Error::Empty(iter.cursor()));
+                    return cold_error(Error::Empty(iter.cursor()));
                 }
             }
         }
@@ -520,12 +909,12 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     let start = byte.clone();
     #[cfg(not(feature = "compact"))]
     parse_8digits::<_, FORMAT>(byte.integer_iter(), &mut mantissa);
-    parse_digits::<_, _, FORMAT>(byte.integer_iter(), |digit| {
+    parse_digits::<_, _, FORMAT>(byte.integer_iter(), format.mantissa_radix(), |digit| {
         mantissa = mantissa.wrapping_mul(format.radix() as _).wrapping_add(digit as _);
     });
     let mut n_digits = byte.current_count() - start.current_count();
     if cfg!(feature = "format") && format.required_integer_digits() && n_digits == 0 {
-        return Err(Error::EmptyInteger(byte.cursor()));
+        return cold_error(Error::EmptyInteger(byte.cursor()));
     }
 
     // Store the integer digits for slow-path algorithms.
@@ -536,7 +925,17 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     // Check if integer leading zeros are disabled.
     if cfg!(feature = "format") && !is_prefix && format.no_float_leading_zeros() {
         if integer_digits.len() > 1 && integer_digits.get(0) == Some(&b'0') {
-            return Err(Error::InvalidLeadingZeros(start.cursor()));
+            return cold_error(Error::InvalidLeadingZeros(start.cursor()));
+        }
+    }
+
+    // Check if integer leading zeros are capped: the last integer digit
+    // is never a leading zero.
+    let max_zeros = format.max_leading_zeros() as usize;
+    if cfg!(feature = "format") && !is_prefix && max_zeros != 0 && n_digits > max_zeros {
+        let zeros = integer_digits.iter().take_while(|&&c| c == b'0').count();
+        if zeros.min(n_digits - 1) > max_zeros {
+            return cold_error(Error::InvalidLeadingZeros(start.cursor() + max_zeros));
         }
     }
 
@@ -554,7 +953,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         let before = byte.clone();
         #[cfg(not(feature = "compact"))]
         parse_8digits::<_, FORMAT>(byte.fraction_iter(), &mut mantissa);
-        parse_digits::<_, _, FORMAT>(byte.fraction_iter(), |digit| {
+        parse_digits::<_, _, FORMAT>(byte.fraction_iter(), format.mantissa_radix(), |digit| {
             mantissa = mantissa.wrapping_mul(format.radix() as _).wrapping_add(digit as _);
         });
         n_after_dot = byte.current_count() - before.current_count();
@@ -573,13 +972,13 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             exponent = implicit_exponent * bits_per_digit / bits_per_base;
         };
         if cfg!(feature = "format") && format.required_fraction_digits() && n_after_dot == 0 {
-            return Err(Error::EmptyFraction(byte.cursor()));
+            return cold_error(Error::EmptyFraction(byte.cursor()));
         }
     }
 
     n_digits += n_after_dot;
     if format.required_mantissa_digits() && n_digits == 0 {
-        return Err(Error::EmptyMantissa(byte.cursor()));
+        return cold_error(Error::EmptyMantissa(byte.cursor()));
     }
 
     // EXPONENT
@@ -591,36 +990,68 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     } else {
         byte.case_insensitive_first_is(exponent_character)
     };
-    if is_exponent {
+    let is_d_exponent = if !cfg!(feature = "format") || !format.d_exponent_notation() {
+        false
+    } else if format.case_sensitive_exponent() {
+        // The case of `d` must match the case of the exponent character.
+        byte.first_is(if exponent_character.is_ascii_uppercase() {
+            b'D'
+        } else {
+            b'd'
+        })
+    } else {
+        byte.case_insensitive_first_is(b'd')
+    };
+    let is_sign_exponent = cfg!(feature = "format")
+        && format.sign_exponent_notation()
+        && (byte.first_is(b'+') || byte.first_is(b'-'));
+    if is_exponent || is_d_exponent || is_sign_exponent {
         // Check float format syntax checks.
         if cfg!(feature = "format") {
             if format.no_exponent_notation() {
-                return Err(Error::InvalidExponent(byte.cursor()));
+                return cold_error(Error::InvalidExponent(byte.cursor()));
             }
             // Check if we have no fraction but we required exponent notation.
             if format.no_exponent_without_fraction() && fraction_digits.is_none() {
-                return Err(Error::ExponentWithoutFraction(byte.cursor()));
+                return cold_error(Error::ExponentWithoutFraction(byte.cursor()));
             }
         }
 
-        // SAFETY: byte cannot be empty due to first_is
-        unsafe { byte.step_unchecked() };
+        // The sign is the start of the exponent without an exponent character.
+        if !is_sign_exponent {
+            // SAFETY: byte cannot be empty due to first_is
+            unsafe { byte.step_unchecked() };
+        }
         let (is_negative, shift) = parse_exponent_sign!(byte, format);
         // SAFETY: safe since we shift at most one for a parsed sign byte.
         unsafe { byte.step_by_unchecked(shift) };
         if cfg!(feature = "format") && format.required_exponent_sign() && shift == 0 {
-            return Err(Error::MissingExponentSign(byte.cursor()));
+            return cold_error(Error::MissingExponentSign(byte.cursor()));
         }
 
         let before = byte.current_count();
-        parse_digits::<_, _, FORMAT>(byte.exponent_iter(), |digit| {
-            if explicit_exponent < 0x10000000 {
-                explicit_exponent *= format.radix() as i64;
-                explicit_exponent += digit as i64;
+        let exponent_start = byte.cursor();
+        // Any larger exponent always overflows or underflows, so clamping
+        // it doesn't change the result, unless it must fit in an `i32`.
+        let is_saturating = options.exponent_mode() == ExponentMode::Saturate;
+        let limit = if is_saturating {
+            0x10000000
+        } else {
+            i32::MAX as i64
+        };
+        let (value, is_clamped) = parse_exponent_digits::<_, FORMAT>(byte.exponent_iter(), limit);
+        explicit_exponent = value;
+        let exponent_digits = byte.current_count() - before;
+        if format.required_exponent_digits() && exponent_digits == 0 {
+            return cold_error(Error::EmptyExponent(byte.cursor()));
+        }
+        if let Some(max_digits) = options.max_exponent_digits() {
+            if exponent_digits > max_digits.get() {
+                return cold_error(Error::ExponentTooLong(exponent_start));
             }
-        });
-        if format.required_exponent_digits() && byte.current_count() - before == 0 {
-            return Err(Error::EmptyExponent(byte.cursor()));
+        }
+        if is_clamped && !is_saturating {
+            return cold_error(Error::ExponentOverflow(exponent_start));
         }
         // Handle our sign, and get the explicit part of the exponent.
         explicit_exponent = if is_negative {
@@ -630,7 +1061,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         };
         exponent += explicit_exponent;
     } else if cfg!(feature = "format") && format.required_exponent_notation() {
-        return Err(Error::MissingExponent(byte.cursor()));
+        return cold_error(Error::MissingExponent(byte.cursor()));
     }
 
     // Check to see if we have a valid base suffix.
@@ -697,31 +1128,53 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     // Now, check if we explicitly overflowed.
     if n_digits > 0 {
         // Have more than 19 significant digits, so we overflowed.
-        many_digits = true;
         mantissa = 0;
         let mut integer = integer_digits.bytes::<{ FORMAT }>();
         // Skip leading zeros, so we can use the step properly.
         let mut integer_iter = integer.integer_iter();
         integer_iter.skip_zeros();
         parse_u64_digits::<_, FORMAT>(integer_iter, &mut mantissa, &mut step);
+        // Truncated digits that are all zeros, such as the trailing zeros of
+        // `1.23450000000000000000e-5`, don't change the value, so the mantissa
+        // is exact and can still use the fast path.
+        let truncated_zeros;
         implicit_exponent = if step == 0 {
             // Filled our mantissa with just the integer.
-            int_end - integer.current_count() as i64
+            let count = integer.current_count();
+            let mut integer_iter = integer.integer_iter();
+            integer_iter.skip_zeros();
+            let integer_zeros = integer_iter.is_consumed();
+            truncated_zeros = match fraction_digits {
+                Some(fraction) if integer_zeros => {
+                    let mut fraction = fraction.bytes::<{ FORMAT }>();
+                    let mut fraction_iter = fraction.fraction_iter();
+                    fraction_iter.skip_zeros();
+                    fraction_iter.is_consumed()
+                },
+                _ => integer_zeros,
+            };
+            int_end - count as i64
         } else {
             // We know this can't be a None since we had more than 19
             // digits previously, so we overflowed a 64-bit integer,
             // but parsing only the integral digits produced less
             // than 19 digits. That means we must have a decimal
             // point, and at least 1 fractional digit.
-            let mut fraction = fraction_digits.unwrap().bytes::<{ FORMAT }>();
+            debug_assert!(fraction_digits.is_some());
+            let mut fraction = fraction_digits.unwrap_or(&[]).bytes::<{ FORMAT }>();
             let mut fraction_iter = fraction.fraction_iter();
             // Skip leading zeros, so we can use the step properly.
             if mantissa == 0 {
                 fraction_iter.skip_zeros();
             }
             parse_u64_digits::<_, FORMAT>(fraction_iter, &mut mantissa, &mut step);
-            -(fraction.current_count() as i64)
+            let count = fraction.current_count();
+            let mut fraction_iter = fraction.fraction_iter();
+            fraction_iter.skip_zeros();
+            truncated_zeros = fraction_iter.is_consumed();
+            -(count as i64)
         };
+        many_digits = !truncated_zeros;
         if format.mantissa_radix() == format.exponent_base() {
             exponent = implicit_exponent;
         } else {
@@ -757,7 +1210,7 @@ pub fn parse_number<'a, const FORMAT: u128>(
     if count == length {
         Ok(float)
     } else {
-        Err(Error::InvalidDigit(count))
+        cold_error(Error::InvalidDigit(count))
     }
 }
 
@@ -766,13 +1219,11 @@ pub fn parse_number<'a, const FORMAT: u128>(
 
 /// Iteratively parse and consume digits from bytes.
 #[inline]
-pub fn parse_digits<'a, Iter, Cb, const FORMAT: u128>(mut iter: Iter, mut cb: Cb)
+pub fn parse_digits<'a, Iter, Cb, const FORMAT: u128>(mut iter: Iter, radix: u32, mut cb: Cb)
 where
     Iter: BytesIter<'a>,
     Cb: FnMut(u32),
 {
-    let format = NumberFormat::<{ FORMAT }> {};
-    let radix = format.radix();
     while let Some(&c) = iter.peek() {
         match char_to_digit_const(c, radix) {
             Some(v) => cb(v),
@@ -783,6 +1234,53 @@ where
     }
 }
 
+/// Iteratively parse and consume exponent digits, clamping the value to `limit`.
+///
+/// This does not parse the exponent sign, and `limit` must be non-negative.
+/// Returns the parsed value and if it was clamped, which allows exponents
+/// beyond the range of `i32` to be handled, for example, when the digits
+/// are passed to an arbitrary-precision backend.
+///
+/// The digits are parsed in the exponent radix of the format, from an
+/// exponent iterator, which handles any exponent digit separators. Parsing
+/// stops at the first invalid digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::parse_exponent_digits;
+/// use lexical_util::iterator::AsBytes;
+///
+/// let mut byte = b"4294967296".bytes::<{ STANDARD }>();
+/// let iter = byte.exponent_iter();
+/// assert_eq!(parse_exponent_digits::<_, STANDARD>(iter, i64::MAX), (4294967296, false));
+///
+/// let mut byte = b"309".bytes::<{ STANDARD }>();
+/// let iter = byte.exponent_iter();
+/// assert_eq!(parse_exponent_digits::<_, STANDARD>(iter, 308), (308, true));
+/// ```
+#[inline]
+pub fn parse_exponent_digits<'a, Iter, const FORMAT: u128>(iter: Iter, limit: i64) -> (i64, bool)
+where
+    Iter: BytesIter<'a>,
+{
+    debug_assert!(limit >= 0);
+    let radix = NumberFormat::<{ FORMAT }>::EXPONENT_RADIX;
+    let mut value = 0_i64;
+    let mut is_clamped = false;
+    parse_digits::<_, _, FORMAT>(iter, radix, |digit| {
+        match value.checked_mul(radix as i64).and_then(|v| v.checked_add(digit as i64)) {
+            Some(v) if v <= limit => value = v,
+            _ => {
+                value = limit;
+                is_clamped = true;
+            },
+        }
+    });
+    (value, is_clamped)
+}
+
 /// Iteratively parse and consume digits in intervals of 8.
 #[inline]
 #[cfg(not(feature = "compact"))]
@@ -872,15 +1370,22 @@ pub fn is_special_eq<const FORMAT: u128>(mut byte: Bytes<FORMAT>, string: &'stat
     0
 }
 
-/// Parse a positive representation of a special, non-finite float.
-#[inline]
-pub fn parse_positive_special<F, const FORMAT: u128>(
+/// A special, non-finite float value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Special {
+    /// Not a number, from the NaN string.
+    Nan,
+    /// Infinity, from the infinity or inf strings.
+    Infinity,
+}
+
+/// Determine which special string the input data starts with.
+/// Returns the special value and the byte's cursor after the string.
+#[inline(always)]
+fn parse_special_string<const FORMAT: u128>(
     byte: Bytes<FORMAT>,
     options: &Options,
-) -> Option<(F, usize)>
-where
-    F: LemireFloat,
-{
+) -> Option<(Special, usize)> {
     let format = NumberFormat::<{ FORMAT }> {};
     if cfg!(feature = "format") && format.no_special() {
         return None;
@@ -892,7 +1397,7 @@ where
         if length >= nan_string.len() {
             let count = is_special_eq::<FORMAT>(byte.clone(), nan_string);
             if count != 0 {
-                return Some((F::NAN, count));
+                return Some((Special::Nan, count));
             }
         }
     }
@@ -900,7 +1405,7 @@ where
         if length >= infinity_string.len() {
             let count = is_special_eq::<FORMAT>(byte.clone(), infinity_string);
             if count != 0 {
-                return Some((F::INFINITY, count));
+                return Some((Special::Infinity, count));
             }
         }
     }
@@ -908,7 +1413,7 @@ where
         if length >= inf_string.len() {
             let count = is_special_eq::<FORMAT>(byte.clone(), inf_string);
             if count != 0 {
-                return Some((F::INFINITY, count));
+                return Some((Special::Infinity, count));
             }
         }
     }
@@ -916,6 +1421,26 @@ where
     None
 }
 
+/// Parse a positive representation of a special, non-finite float.
+///
+/// This is only called if the float is not a valid number, so it's
+/// kept out-of-line.
+#[cold]
+pub fn parse_positive_special<F, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    options: &Options,
+) -> Option<(F, usize)>
+where
+    F: LemireFloat,
+{
+    let (special, count) = parse_special_string::<FORMAT>(byte, options)?;
+    let float = match special {
+        Special::Nan => F::NAN,
+        Special::Infinity => F::INFINITY,
+    };
+    Some((float, count))
+}
+
 /// Parse a partial representation of a special, non-finite float.
 #[inline]
 pub fn parse_partial_special<F, const FORMAT: u128>(
@@ -951,3 +1476,46 @@ where
     }
     None
 }
+
+// SIGN
+// ----
+
+/// Parse the sign of a float, and detect special values.
+///
+/// This validates the sign like the float parser, and returns the sign,
+/// the special value if the bytes start with a NaN or infinity string,
+/// and the remaining bytes. For finite numbers, the remaining bytes are
+/// the digits to parse, and for special values, the bytes after the
+/// special string. Like the float parser, special values are only
+/// detected if the number does not start with a digit, so the special
+/// strings are parsed as digits for radixes that contain them.
+pub fn parse_sign_special<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(Sign, Option<Special>, &'a [u8])> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return cold_error(Error::Empty(byte.cursor()));
+    }
+
+    let sign = if is_negative {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    let cursor = byte.cursor();
+    let is_digit = match bytes.get(cursor) {
+        Some(&c) => char_is_digit_const(c, format.mantissa_radix()),
+        None => false,
+    };
+    if !is_digit {
+        if let Some((special, count)) = parse_special_string::<FORMAT>(byte, options) {
+            return Ok((sign, Some(special), &bytes[count..]));
+        }
+    }
+    Ok((sign, None, &bytes[cursor..]))
+}
@@ -0,0 +1,48 @@
+//! Parse decimal strings to posits, with correct rounding.
+//!
+//! Every posit is exactly representable as an `f64`, as is the value
+//! halfway between two adjacent posits, so the number is first parsed
+//! to the nearest `f64`, and then rounded to the nearest posit. This
+//! can only round incorrectly if the `f64` is exactly halfway between
+//! two posits, and so only these ties compare the significant digits
+//! to the `f64` with arbitrary-precision arithmetic.
+
+#![cfg(feature = "posit")]
+#![doc(hidden)]
+
+use crate::custom::{compare, parse_estimate};
+use crate::options::Options;
+use core::cmp::Ordering;
+use lexical_util::posit::{self, Posit};
+use lexical_util::result::Result;
+
+/// Parse a complete decimal string to the nearest posit.
+///
+/// Ties round to the posit with an even bit pattern. Non-zero numbers
+/// are clamped to the smallest and largest positive posits, while NaN
+/// and infinity are parsed as NaR. The format must be decimal.
+pub fn parse_posit<P: Posit, const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<P> {
+    let (value, number) = parse_estimate::<FORMAT>(bytes, options)?;
+    let number = match number {
+        Some(number) => number,
+        None => return Ok(posit::from_f64(value, Ordering::Equal)),
+    };
+    if number.is_zero() {
+        return Ok(posit::from_f64(0.0, Ordering::Equal));
+    }
+
+    // The float may overflow or underflow, but the posit is clamped.
+    let value = match (value.is_infinite(), value == 0.0, value.is_sign_negative()) {
+        (true, _, false) => f64::MAX,
+        (true, _, true) => -f64::MAX,
+        (_, true, false) => f64::MIN_POSITIVE,
+        (_, true, true) => -f64::MIN_POSITIVE,
+        _ => value,
+    };
+    let direction = if posit::is_tie::<P>(value) {
+        compare(&number, value)
+    } else {
+        Ordering::Equal
+    };
+    Ok(posit::from_f64(value, direction))
+}
@@ -0,0 +1,177 @@
+//! Parse floats with an optional radix prefix.
+//!
+//! Assemblers, shader compilers, and other languages derived from C
+//! accept float literals in binary, octal, and hexadecimal, such as
+//! `0x1.8p1`, `0b101.1p2`, or `0o7.4p1`. The radix is selected from the
+//! prefix, and the exponent after `p` is a decimal power of two, like
+//! the `%a` conversion of C's `printf`. Numbers without a prefix are
+//! parsed as standard decimal floats, with an exponent after `e`.
+//!
+//! Prefixes are case-insensitive, and the sign comes before the prefix,
+//! such as `-0x1p-1`. Special values are only parsed without a prefix.
+
+#![cfg(feature = "power-of-two")]
+
+use crate::options::{Options, HEX_FLOAT};
+use crate::FromLexicalWithOptions;
+use core::num;
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::{NumberFormatBuilder, STANDARD};
+use lexical_util::num::Float;
+use lexical_util::radix::Radix;
+use lexical_util::result::Result;
+use lexical_util::wrapper::shift_error;
+
+/// Create the number format for a prefixed float in a power-of-two radix.
+const fn prefixed_format(radix: u8) -> u128 {
+    NumberFormatBuilder::new()
+        .mantissa_radix(radix)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build()
+}
+
+/// Number format for binary floats, like `0b101.1p2`.
+const BINARY: u128 = prefixed_format(2);
+
+/// Number format for octal floats, like `0o7.4p1`.
+const OCTAL: u128 = prefixed_format(8);
+
+/// Number format for hexadecimal floats, like `0x1.8p1`.
+const HEXADECIMAL: u128 = prefixed_format(16);
+
+/// Default options for decimal floats.
+const DECIMAL_OPTIONS: Options = Options::new();
+
+/// Parse a float with an optional radix prefix.
+///
+/// Returns the float and the radix, which is detected from the prefix:
+/// `0b` for binary, `0o` for octal, `0x` for hexadecimal, and decimal
+/// without a prefix. The entire string must be a valid float.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::prefixed::parse_prefixed;
+/// use lexical_util::radix::Radix;
+///
+/// # pub fn main() {
+/// assert_eq!(parse_prefixed::<f64>(b"0x1.8p1"), Ok((3.0, Radix::HEXADECIMAL)));
+/// assert_eq!(parse_prefixed::<f64>(b"-0b101.1p2"), Ok((-22.0, Radix::BINARY)));
+/// assert_eq!(parse_prefixed::<f32>(b"0o7.4p1"), Ok((15.0, Radix::OCTAL)));
+/// assert_eq!(parse_prefixed::<f64>(b"1.5e3"), Ok((1500.0, Radix::DECIMAL)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_prefixed<F>(bytes: &[u8]) -> Result<(F, Radix)>
+where
+    F: FromLexicalWithOptions<Options = Options> + Float,
+{
+    let (offset, is_negative, radix) = parse_prefix(bytes);
+    if radix == Radix::DECIMAL {
+        let float = F::from_lexical_with_options::<STANDARD>(bytes, &DECIMAL_OPTIONS)?;
+        return Ok((float, radix));
+    }
+
+    let digits = check_digits(bytes, offset, radix)?;
+    let result = match radix.get() {
+        2 => F::from_lexical_with_options::<BINARY>(digits, &HEX_FLOAT),
+        8 => F::from_lexical_with_options::<OCTAL>(digits, &HEX_FLOAT),
+        _ => F::from_lexical_with_options::<HEXADECIMAL>(digits, &HEX_FLOAT),
+    };
+    let float = result.map_err(|error| shift_error(error, offset))?;
+    Ok((negate(float, is_negative), radix))
+}
+
+/// Parse a float with an optional radix prefix, until an invalid digit is found.
+///
+/// Returns the float, the radix, and the number of bytes processed,
+/// including the sign and prefix. The prefix is always part of the
+/// number, so a prefix without any digits is an error.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::prefixed::parse_prefixed_partial;
+/// use lexical_util::radix::Radix;
+///
+/// # pub fn main() {
+/// let result = parse_prefixed_partial::<f64>(b"0x1p-2, r0");
+/// assert_eq!(result, Ok((0.25, Radix::HEXADECIMAL, 6)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_prefixed_partial<F>(bytes: &[u8]) -> Result<(F, Radix, usize)>
+where
+    F: FromLexicalWithOptions<Options = Options> + Float,
+{
+    let (offset, is_negative, radix) = parse_prefix(bytes);
+    if radix == Radix::DECIMAL {
+        let (float, count) =
+            F::from_lexical_partial_with_options::<STANDARD>(bytes, &DECIMAL_OPTIONS)?;
+        return Ok((float, radix, count));
+    }
+
+    let digits = check_digits(bytes, offset, radix)?;
+    let result = match radix.get() {
+        2 => F::from_lexical_partial_with_options::<BINARY>(digits, &HEX_FLOAT),
+        8 => F::from_lexical_partial_with_options::<OCTAL>(digits, &HEX_FLOAT),
+        _ => F::from_lexical_partial_with_options::<HEXADECIMAL>(digits, &HEX_FLOAT),
+    };
+    let (float, count) = result.map_err(|error| shift_error(error, offset))?;
+    Ok((negate(float, is_negative), radix, offset + count))
+}
+
+/// Parse the sign and radix prefix.
+///
+/// Returns the number of bytes in the sign and prefix, if the number is
+/// negative, and the radix. Decimal numbers have no prefix, so the sign
+/// is left to the float parser.
+#[inline]
+fn parse_prefix(bytes: &[u8]) -> (usize, bool, Radix) {
+    let (is_negative, start) = match bytes.first() {
+        Some(&b'-') => (true, 1),
+        Some(&b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let radix = match (bytes.get(start), bytes.get(start + 1)) {
+        (Some(&b'0'), Some(&c)) => match c.to_ascii_lowercase() {
+            b'b' => Radix::BINARY,
+            b'o' => Radix::OCTAL,
+            b'x' => Radix::HEXADECIMAL,
+            _ => Radix::DECIMAL,
+        },
+        _ => Radix::DECIMAL,
+    };
+    if radix == Radix::DECIMAL {
+        (0, false, radix)
+    } else {
+        (start + 2, is_negative, radix)
+    }
+}
+
+/// Get the digits after the prefix, which must not start with a sign.
+///
+/// The digits must start with a digit or the decimal point, so the sign
+/// and special values are only parsed before the prefix.
+#[inline]
+fn check_digits(bytes: &[u8], offset: usize, radix: Radix) -> Result<&[u8]> {
+    let digits = &bytes[offset..];
+    match digits.first() {
+        Some(&c) if c != b'.' && !char_is_digit_const(c, radix.get()) => {
+            Err(Error::EmptyMantissa(offset))
+        },
+        _ => Ok(digits),
+    }
+}
+
+/// Negate the float if the number has a negative sign.
+#[inline(always)]
+fn negate<F: Float>(float: F, is_negative: bool) -> F {
+    if is_negative {
+        -float
+    } else {
+        float
+    }
+}
@@ -0,0 +1,275 @@
+//! Parse floats rounded to a number of decimal places or significant digits.
+//!
+//! Metering and other regulated time series require sample values to be
+//! rounded in the decimal domain, such as half-even to 3 places, before
+//! they are converted to binary. Rounding the parsed float instead rounds
+//! twice, and so may round incorrectly: the nearest `f64` to `2.675` is
+//! `2.67499999...`, which is no longer a tie, and rounds down to `2.67`.
+//!
+//! Legacy systems that stored floats as text with fewer significant
+//! digits, such as 7 or 15, are likewise emulated bit-for-bit by rounding
+//! the digits to the stored precision before conversion.
+//!
+//! The digits are rounded exactly, and the rounded number is then parsed
+//! with correct rounding, so the float is the nearest float to the
+//! rounded decimal value.
+
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::parse::apply_float_options;
+use crate::FromLexicalWithOptions;
+use core::num;
+use lexical_util::canonical::{NumericStr, Rounding};
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+
+/// Maximum number of significant digits of the rounded number to parse.
+///
+/// Any float, and the value halfway between two adjacent floats, has at
+/// most 770 significant digits, so any digits after these can only break
+/// a tie, which is preserved by a single non-zero digit.
+const MAX_DIGITS: usize = 800;
+
+/// Size of the buffer for the rounded number: the sign, a leading digit
+/// for the carry, the digits, a non-zero digit for truncated digits, and
+/// the exponent with the sign.
+const ROUNDED_BUFFER_SIZE: usize = MAX_DIGITS + 32;
+
+/// Maximum number of decimal places or significant digits, to avoid
+/// overflow when scaling.
+const MAX_PLACES: usize = 1 << 61;
+
+/// Default options for the rounded number, before the options of the
+/// caller are applied to the parsed float.
+const ROUNDED_OPTIONS: Options = Options::new();
+
+/// Determine if a number rounds away from zero.
+///
+/// `last` is the last kept digit, `first` the first truncated digit,
+/// and `is_above` if any later truncated digit is non-zero.
+#[inline]
+fn is_round_up(rounding: Rounding, is_negative: bool, last: u8, first: u8, is_above: bool) -> bool {
+    let is_inexact = first != 0 || is_above;
+    match rounding {
+        Rounding::HalfEven => first > 5 || (first == 5 && (is_above || last % 2 == 1)),
+        Rounding::HalfUp => first >= 5,
+        Rounding::HalfDown => first > 5 || (first == 5 && is_above),
+        Rounding::Up => is_inexact,
+        Rounding::Down => false,
+        Rounding::Ceiling => is_inexact && !is_negative,
+        Rounding::Floor => is_inexact && is_negative,
+    }
+}
+
+/// Parse a decimal float, rounded to `places` fractional digits.
+///
+/// The digits are first rounded to a multiple of `10^-places` in the
+/// decimal domain, with the rounding mode, and the rounded number is then
+/// parsed to the nearest float. So, `2.675` rounded half-even to 2 places
+/// is `2.68`, even though the nearest `f64` to `2.675` is below the tie.
+/// Numbers that round to zero keep their sign, unless the options don't
+/// preserve it.
+///
+/// The number format must be decimal, and the entire string must be a
+/// valid number, with the exponent character and decimal point of the
+/// options. Special values, such as `NaN`, are parsed without rounding.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::rounded::parse_rounded;
+/// use lexical_parse_float::Options;
+/// use lexical_util::canonical::Rounding;
+/// use lexical_util::format::STANDARD;
+///
+/// # pub fn main() {
+/// let options = Options::new();
+/// let parse = |bytes, rounding| {
+///     parse_rounded::<f64, { STANDARD }>(bytes, 2, rounding, &options)
+/// };
+/// assert_eq!(parse(b"2.675", Rounding::HalfEven), Ok(2.68));
+/// assert_eq!(parse(b"2.665", Rounding::HalfEven), Ok(2.66));
+/// assert_eq!(parse(b"2.665", Rounding::HalfUp), Ok(2.67));
+/// assert_eq!(parse(b"-0.004", Rounding::HalfEven), Ok(-0.0));
+/// # }
+/// ```
+pub fn parse_rounded<F, const FORMAT: u128>(
+    bytes: &[u8],
+    places: usize,
+    rounding: Rounding,
+    options: &Options,
+) -> Result<F>
+where
+    F: FromLexicalWithOptions<Options = Options> + Float,
+{
+    let places = places.min(MAX_PLACES) as i64;
+    parse_quantized::<F, _, FORMAT>(bytes, |_| places, rounding, options)
+}
+
+/// Parse a decimal float, rounded to `digits` significant digits.
+///
+/// The digits are first rounded to the number of significant digits in
+/// the decimal domain, with the rounding mode, and the rounded number is
+/// then parsed to the nearest float. This emulates floats that were
+/// stored as text with fewer digits, so `3.14159265` rounded half-up to
+/// 4 significant digits is parsed as `3.142`. Numbers that round to zero
+/// keep their sign, unless the options don't preserve it, and the number
+/// of significant digits may increase with a carry, as in `9.99` rounded
+/// to 2 digits, which is `10`.
+///
+/// The number format must be decimal, and the entire string must be a
+/// valid number, with the exponent character and decimal point of the
+/// options. Special values, such as `NaN`, are parsed without rounding.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::num;
+/// use lexical_parse_float::rounded::parse_significant;
+/// use lexical_parse_float::Options;
+/// use lexical_util::canonical::Rounding;
+/// use lexical_util::format::STANDARD;
+///
+/// # pub fn main() {
+/// let options = Options::new();
+/// let digits = num::NonZeroUsize::new(4).unwrap();
+/// let parse = |bytes, rounding| {
+///     parse_significant::<f64, { STANDARD }>(bytes, digits, rounding, &options)
+/// };
+/// assert_eq!(parse(b"3.14159265", Rounding::HalfUp), Ok(3.142));
+/// assert_eq!(parse(b"123456", Rounding::Down), Ok(123400.0));
+/// assert_eq!(parse(b"-0.000123456", Rounding::HalfEven), Ok(-0.0001235));
+/// # }
+/// ```
+pub fn parse_significant<F, const FORMAT: u128>(
+    bytes: &[u8],
+    digits: num::NonZeroUsize,
+    rounding: Rounding,
+    options: &Options,
+) -> Result<F>
+where
+    F: FromLexicalWithOptions<Options = Options> + Float,
+{
+    let digits = digits.get().min(MAX_PLACES) as i64;
+    let places = |number: &NumericStr| digits - 1 - number.exponent();
+    parse_quantized::<F, _, FORMAT>(bytes, places, rounding, options)
+}
+
+/// Parse a decimal float, rounded to the decimal places for the number.
+///
+/// `places` is the negated decimal exponent of the last kept digit, which
+/// is negative for digits before the decimal point.
+fn parse_quantized<F, Places, const FORMAT: u128>(
+    bytes: &[u8],
+    places: Places,
+    rounding: Rounding,
+    options: &Options,
+) -> Result<F>
+where
+    F: FromLexicalWithOptions<Options = Options> + Float,
+    Places: FnOnce(&NumericStr) -> i64,
+{
+    let number = match NumericStr::parse_saturating::<FORMAT>(
+        bytes,
+        options.exponent(),
+        options.decimal_point(),
+    ) {
+        Ok(number) => number,
+        Err(error) => {
+            return match F::from_lexical_with_options::<FORMAT>(bytes, options) {
+                Ok(float) if float.is_special() => Ok(float),
+                _ => Err(error),
+            };
+        },
+    };
+
+    // Numbers without non-zero digits after the decimal places are exact.
+    let places = places(&number);
+    let kept = number.exponent().saturating_add(places + 1);
+    let count = number.digits().count() as i64;
+    if kept >= count {
+        return F::from_lexical_with_options::<FORMAT>(bytes, options);
+    }
+
+    // Write the kept digits, after a leading zero for the carry, and
+    // find if any kept digits after the maximum digits are non-zero
+    // or all nines.
+    let mut buffer = [b'0'; ROUNDED_BUFFER_SIZE];
+    let mut index = 0;
+    if number.is_sign_negative() {
+        buffer[index] = b'-';
+        index += 1;
+    }
+    let start = index;
+    index += 1;
+    let mut last = 0;
+    let mut first = 0;
+    let mut is_above = false;
+    let mut is_tail_nonzero = false;
+    let mut is_tail_nines = true;
+    for (position, digit) in number.digits().enumerate() {
+        let position = position as i64;
+        let value = digit - b'0';
+        if position < kept {
+            last = value;
+            if position < MAX_DIGITS as i64 {
+                buffer[index] = digit;
+                index += 1;
+            } else {
+                is_tail_nonzero |= value != 0;
+                is_tail_nines &= value == 9;
+            }
+        } else if position == kept {
+            first = value;
+        } else {
+            is_above |= value != 0;
+        }
+    }
+
+    // Round up the written digits, or add a non-zero digit for any
+    // kept digits after the maximum digits.
+    let round_up = is_round_up(rounding, number.is_negative(), last, first, is_above);
+    let is_truncated = kept > MAX_DIGITS as i64;
+    let mut exponent = -places + (kept - MAX_DIGITS as i64).max(0);
+    if round_up && (!is_truncated || is_tail_nines) {
+        let mut carry = index;
+        loop {
+            carry -= 1;
+            if buffer[carry] == b'9' {
+                buffer[carry] = b'0';
+            } else {
+                buffer[carry] += 1;
+                break;
+            }
+        }
+        debug_assert!(carry >= start);
+    } else if is_truncated && (round_up || is_tail_nonzero) {
+        buffer[index] = b'1';
+        index += 1;
+        exponent -= 1;
+    }
+
+    // Write the exponent, and parse the rounded number.
+    buffer[index] = b'e';
+    index += 1;
+    if exponent < 0 {
+        buffer[index] = b'-';
+        index += 1;
+    }
+    let mut digits = [0u8; 20];
+    let mut length = 0;
+    let mut value = exponent.unsigned_abs();
+    while value != 0 || length == 0 {
+        digits[length] = b'0' + (value % 10) as u8;
+        value /= 10;
+        length += 1;
+    }
+    for &digit in digits[..length].iter().rev() {
+        buffer[index] = digit;
+        index += 1;
+    }
+    let float = F::from_lexical_with_options::<{ STANDARD }>(&buffer[..index], &ROUNDED_OPTIONS)?;
+    Ok(apply_float_options(float, options))
+}
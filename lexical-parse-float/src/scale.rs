@@ -0,0 +1,177 @@
+//! Scale floats by powers of ten, with correct rounding.
+//!
+//! Scaling a float by `10.0.powi(exponent)` rounds twice, once for the
+//! power of ten, which is inexact above `1e22`, and once for the product.
+//! This rounds the exact product only once: if the power of ten is exact,
+//! using a single native multiplication or division, like the fast path
+//! of the parser, and otherwise using big-integer arithmetic with the
+//! cached powers, like the slow path of the parser.
+
+use crate::bigint::Bigint;
+use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
+#[cfg(feature = "nightly")]
+use crate::fpu::set_precision;
+use crate::slow::{negative_digit_comp, positive_digit_comp};
+use lexical_util::assert::debug_assert_some;
+use lexical_util::format::STANDARD;
+use lexical_util::num::AsPrimitive;
+
+/// Largest decimal exponent that can scale a non-zero float to a finite,
+/// non-zero value, rounded up. Larger exponents always overflow or underflow.
+const MAX_EXPONENT: i32 = 800;
+
+/// Scale a float by a power of ten, rounding the exact result to nearest, tie even.
+///
+/// This is the float nearest to `value * 10^exponent`, as if the
+/// product was written as a decimal string and parsed. Special values
+/// and zeros are returned unchanged, and the sign is preserved, even
+/// if the result overflows to infinity or underflows to zero. Only
+/// `f32` and `f64` are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::scale::scale_by_pow10;
+///
+/// # pub fn main() {
+/// assert_eq!(scale_by_pow10(1.5f64, 3), 1500.0);
+/// assert_eq!(scale_by_pow10(7.0f64, -9), 7e-9);
+/// // `powi` rounds the power of ten, and then the product.
+/// assert_ne!(3.0 * 10f64.powi(-310), 3e-310);
+/// assert_eq!(scale_by_pow10(3.0f64, -310), 3e-310);
+/// # }
+/// ```
+pub fn scale_by_pow10<F: RawFloat>(value: F, exponent: i32) -> F {
+    if exponent == 0 || value.is_special() || value == F::ZERO {
+        return value;
+    }
+    if let Some(scaled) = scale_fast_path(value, exponent) {
+        return scaled;
+    }
+
+    let is_negative = value.is_sign_negative();
+    let magnitude = if is_negative {
+        -value
+    } else {
+        value
+    };
+    let scaled = extended_to_float::<F>(scale_slow_path(magnitude, exponent));
+    if is_negative {
+        -scaled
+    } else {
+        scaled
+    }
+}
+
+/// Scale by an exact power of ten using native float arithmetic.
+///
+/// A single multiplication or division is correctly rounded, so this
+/// is exact if the power of ten is exactly representable.
+#[inline]
+fn scale_fast_path<F: RawFloat>(value: F, exponent: i32) -> Option<F> {
+    // Native float arithmetic is never used when bit-identical results
+    // are required, see `Number::try_fast_path`.
+    if cfg!(feature = "deterministic") {
+        return None;
+    }
+    let power = exponent.unsigned_abs() as i64;
+    if power > F::max_exponent_fast_path(10) {
+        return None;
+    }
+
+    #[cfg(feature = "nightly")]
+    let _cw = set_precision::<F>();
+
+    // SAFETY: safe, since `power <= max_exponent_fast_path`.
+    let power = unsafe { F::pow_fast_path(power as usize, 10) };
+    if exponent < 0 {
+        Some(value / power)
+    } else {
+        Some(value * power)
+    }
+}
+
+/// Scale a positive, finite float by a power of ten using big integers.
+///
+/// The float, `m * 2^k`, is represented exactly as the big integer
+/// `m * 2^k` if `k >= 0`, or `m * 5^-k` scaled by `10^k` otherwise,
+/// so the product is `bigmant * 10^exponent` and can be rounded by
+/// the digit comparison algorithms of the slow path.
+fn scale_slow_path<F: RawFloat>(value: F, exponent: i32) -> ExtendedFloat80 {
+    let fp_zero = ExtendedFloat80 {
+        mant: 0,
+        exp: 0,
+    };
+    let fp_inf = ExtendedFloat80 {
+        mant: 0,
+        exp: F::INFINITE_POWER,
+    };
+    if exponent > MAX_EXPONENT {
+        return fp_inf;
+    } else if exponent < -MAX_EXPONENT {
+        return fp_zero;
+    }
+
+    let binary_exp = value.exponent();
+    let mut bigmant = Bigint::from_u64(value.mantissa().as_u64());
+    let exponent = if binary_exp >= 0 {
+        debug_assert_some(bigmant.pow(2, binary_exp as u32));
+        exponent
+    } else {
+        debug_assert_some(bigmant.pow(5, (-binary_exp) as u32));
+        exponent + binary_exp
+    };
+
+    // The product is in `[2^(power2 - 2), 2^(power2 + 2))`, accounting
+    // for the error in the estimate of the power of ten, so short-circuit
+    // if it's always infinite or zero. This also bounds the big integers.
+    let power2 = bigmant.bit_length() as i32 + log2_pow10(exponent);
+    if power2 - 2 >= F::MAX_EXPONENT + F::MANTISSA_SIZE {
+        return fp_inf;
+    } else if power2 + 2 < F::DENORMAL_EXPONENT - 1 {
+        return fp_zero;
+    }
+
+    if exponent >= 0 {
+        positive_digit_comp::<F, STANDARD>(bigmant, exponent)
+    } else {
+        let fp = estimate_quotient::<F>(&bigmant, exponent);
+        // The estimate is accurate to 61 bits, so it's below half the
+        // smallest denormal float if its normalized exponent is.
+        if fp.exp < -64 {
+            return fp_zero;
+        }
+        negative_digit_comp::<F, STANDARD>(bigmant, fp, exponent)
+    }
+}
+
+/// Estimate `bigmant * 10^exponent`, for a negative exponent, from the
+/// high 64 bits of the numerator and denominator.
+///
+/// The result is a normalized extended float, with the most-significant
+/// bit set, which is accurate to at least 61 bits.
+fn estimate_quotient<F: RawFloat>(bigmant: &Bigint, exponent: i32) -> ExtendedFloat80 {
+    debug_assert!(exponent < 0);
+    let mut pow10 = Bigint::from_u32(1);
+    debug_assert_some(pow10.pow(10, (-exponent) as u32));
+
+    // Both high words are normalized, so the quotient is in `(2^63, 2^65)`.
+    let numerator = bigmant.hi64().0 as u128;
+    let denominator = pow10.hi64().0 as u128;
+    let quotient = (numerator << 64) / denominator;
+    let shift = (quotient >> 64) as i32;
+    let bit_length = bigmant.bit_length() as i32 - pow10.bit_length() as i32;
+    ExtendedFloat80 {
+        mant: (quotient >> shift) as u64,
+        exp: bit_length - 64 + shift + F::EXPONENT_BIAS,
+    }
+}
+
+/// Calculate `⌊log2(10^exponent)⌋`, within 1, for exponents within `MAX_EXPONENT`.
+///
+/// This uses the same approximation of `log2(10)`, as `217706 / 2^16`,
+/// as the Eisel-Lemire algorithm.
+#[inline(always)]
+const fn log2_pow10(exponent: i32) -> i32 {
+    (exponent * 217706) >> 16
+}
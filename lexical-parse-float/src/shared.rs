@@ -144,11 +144,10 @@ where
     // are case-insensitive equal, but only if at least 1 of the inputs
     // is an ASCII letter.
     loop {
-        let yi = y.next();
-        if yi.is_none() {
-            return true;
-        }
-        let yi = *yi.unwrap();
+        let yi = match y.next() {
+            Some(&yi) => yi,
+            None => return true,
+        };
         let is_not_equal = x.next().map_or(true, |&xi| {
             let xor = xi ^ yi;
             xor != 0 && xor != 0x20
@@ -13,9 +13,11 @@ use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use crate::limits::{u32_power_limit, u64_power_limit};
 use crate::number::Number;
 use crate::shared;
+use crate::workspace::Workspace;
 use core::cmp;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
+use lexical_util::assert::debug_assert_some;
 use lexical_util::digit::char_to_valid_digit_const;
 #[cfg(feature = "radix")]
 use lexical_util::digit::digit_to_char_const;
@@ -46,6 +48,18 @@ use lexical_util::num::{AsPrimitive, Integer};
 pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+) -> ExtendedFloat80 {
+    let mut workspace = Workspace::new();
+    slow_radix_in::<F, FORMAT>(num, fp, &mut workspace)
+}
+
+/// Parse the significant digits and biased, binary exponent of a float,
+/// using a caller-supplied workspace for the big integers.
+#[inline]
+pub fn slow_radix_in<F: RawFloat, const FORMAT: u128>(
+    num: Number,
+    fp: ExtendedFloat80,
+    workspace: &mut Workspace,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -71,7 +85,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
-            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+            digit_comp_in::<F, FORMAT>(num, fp, sci_exp, max_digits, workspace)
         } else {
             // Fallback to infinite digits.
             byte_comp::<F, FORMAT>(num, fp, sci_exp)
@@ -82,7 +96,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         // Can use our finite number of digit algorithm.
         let max_digits = F::max_digits(format.radix()).unwrap();
-        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+        digit_comp_in::<F, FORMAT>(num, fp, sci_exp, max_digits, workspace)
     }
 }
 
@@ -100,13 +114,29 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
     sci_exp: i32,
     max_digits: usize,
 ) -> ExtendedFloat80 {
-    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    let mut workspace = Workspace::new();
+    digit_comp_in::<F, FORMAT>(num, fp, sci_exp, max_digits, &mut workspace)
+}
+
+/// Algorithm that generates the mantissa for a finite representation,
+/// using a caller-supplied workspace for the big integers.
+#[inline]
+pub fn digit_comp_in<F: RawFloat, const FORMAT: u128>(
+    num: Number,
+    fp: ExtendedFloat80,
+    sci_exp: i32,
+    max_digits: usize,
+    workspace: &mut Workspace,
+) -> ExtendedFloat80 {
+    let digits = parse_mantissa_in::<FORMAT>(num, max_digits, &mut workspace.real_digits);
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
-        positive_digit_comp::<F, FORMAT>(bigmant, exponent)
+        positive_digit_comp_in::<F, FORMAT>(&mut workspace.real_digits, exponent)
     } else {
-        negative_digit_comp::<F, FORMAT>(bigmant, fp, exponent)
+        let real_digits = &mut workspace.real_digits;
+        let theor_digits = &mut workspace.theor_digits;
+        negative_digit_comp_in::<F, FORMAT>(real_digits, theor_digits, fp, exponent)
     }
 }
 
@@ -114,6 +144,15 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
 pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     mut bigmant: Bigint,
     exponent: i32,
+) -> ExtendedFloat80 {
+    positive_digit_comp_in::<F, FORMAT>(&mut bigmant, exponent)
+}
+
+/// Generate the significant digits with a positive exponent relative to
+/// mantissa, scaling the mantissa in place.
+pub fn positive_digit_comp_in<F: RawFloat, const FORMAT: u128>(
+    bigmant: &mut Bigint,
+    exponent: i32,
 ) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
 
@@ -121,7 +160,7 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    bigmant.pow(format.radix(), exponent as u32).unwrap();
+    debug_assert_some(bigmant.pow(format.radix(), exponent as u32));
 
     // Get the exact representation of the float from the big integer.
     // hi64 checks **all** the remaining bits after the mantissa,
@@ -162,9 +201,22 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
 ///
 /// This allows us to compare both floats using integers efficiently
 /// without any loss of precision.
-#[allow(clippy::comparison_chain)]
 pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
-    bigmant: Bigint,
+    mut bigmant: Bigint,
+    fp: ExtendedFloat80,
+    exponent: i32,
+) -> ExtendedFloat80 {
+    let mut theor_digits = Bigint::new();
+    negative_digit_comp_in::<F, FORMAT>(&mut bigmant, &mut theor_digits, fp, exponent)
+}
+
+/// Generate the significant digits with a negative exponent relative to
+/// mantissa, scaling the real digits in place and storing the theoretical
+/// digits in `theor_digits`.
+#[allow(clippy::comparison_chain)]
+pub fn negative_digit_comp_in<F: RawFloat, const FORMAT: u128>(
+    real_digits: &mut Bigint,
+    theor_digits: &mut Bigint,
     mut fp: ExtendedFloat80,
     exponent: i32,
 ) -> ExtendedFloat80 {
@@ -175,8 +227,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
-    // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = bigmant;
+    // Get the radix exponent for the real digits.
     let real_exp = exponent;
     debug_assert!(real_exp < 0);
 
@@ -187,7 +238,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
 
     // Get the significant digits and the binary exponent for `b+h`.
     let theor = bh(b);
-    let mut theor_digits = Bigint::from_u64(theor.mant);
+    theor_digits.data.set_u64(theor.mant);
     let theor_exp = theor.exp;
 
     // We need to scale the real digits and `b+h` digits to be the same
@@ -212,15 +263,15 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     };
 
     if halfradix_exp != 0 {
-        theor_digits.pow(radix / 2, halfradix_exp as u32).unwrap();
+        debug_assert_some(theor_digits.pow(radix / 2, halfradix_exp as u32));
     }
     if radix_exp != 0 {
-        theor_digits.pow(radix, radix_exp as u32).unwrap();
+        debug_assert_some(theor_digits.pow(radix, radix_exp as u32));
     }
     if binary_exp > 0 {
-        theor_digits.pow(2, binary_exp as u32).unwrap();
+        debug_assert_some(theor_digits.pow(2, binary_exp as u32));
     } else if binary_exp < 0 {
-        real_digits.pow(2, (-binary_exp) as u32).unwrap();
+        debug_assert_some(real_digits.pow(2, (-binary_exp) as u32));
     }
 
     // Compare our theoretical and real digits and round nearest, tie even.
@@ -291,8 +342,8 @@ macro_rules! add_digit {
 macro_rules! add_temporary {
     // Multiply by the small power and add the native value.
     (@mul $result:ident, $power:expr, $value:expr) => {
-        $result.data.mul_small($power).unwrap();
-        $result.data.add_small($value).unwrap();
+        debug_assert_some($result.data.mul_small($power));
+        debug_assert_some($result.data.add_small($value));
     };
 
     // # Safety
@@ -354,7 +405,7 @@ macro_rules! round_up_nonzero {
                 if value != 0x3030_3030_3030_3030 {
                     // Have non-zero digits, exit early.
                     round_up_truncated!($format, $result, $count);
-                    return ($result, $count);
+                    return $count;
                 }
             }
         }
@@ -362,7 +413,7 @@ macro_rules! round_up_nonzero {
         for &digit in iter {
             if digit != b'0' {
                 round_up_truncated!($format, $result, $count);
-                return ($result, $count);
+                return $count;
             }
         }
     }};
@@ -373,6 +424,21 @@ macro_rules! round_up_nonzero {
 /// Returns the parsed mantissa and the number of digits in the mantissa.
 /// The max digits is the maximum number of digits plus one.
 pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bigint, usize) {
+    let mut result = Bigint::new();
+    let count = parse_mantissa_in::<FORMAT>(num, max_digits, &mut result);
+    (result, count)
+}
+
+/// Parse the full mantissa into an existing big integer.
+///
+/// Any previous value of `result` is overwritten. Returns the number of
+/// digits in the mantissa. The max digits is the maximum number of digits
+/// plus one.
+pub fn parse_mantissa_in<const FORMAT: u128>(
+    num: Number,
+    max_digits: usize,
+    result: &mut Bigint,
+) -> usize {
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
@@ -383,7 +449,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     let mut counter: usize = 0;
     let mut count: usize = 0;
     let mut value: Limb = 0;
-    let mut result = Bigint::new();
+    result.data.set_u64(0);
 
     // Now use our pre-computed small powers iteratively.
     let step = if LIMB_BITS == 32 {
@@ -420,7 +486,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 let mut fraction = fraction.bytes::<FORMAT>();
                 round_up_nonzero!(format, fraction.fraction_iter(), result, count)
             }
-            return (result, count);
+            return count;
         } else {
             // Add our temporary from the loop.
             // SAFETY: safe since `counter <= step`.
@@ -454,7 +520,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 // SAFETY: safe since `counter <= step`.
                 add_temporary!(@end format, result, counter, value);
                 round_up_nonzero!(format, fraction_iter, result, count);
-                return (result, count);
+                return count;
             } else {
                 // Add our temporary from the loop.
                 // SAFETY: safe since `counter <= step`.
@@ -468,7 +534,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     // SAFETY: safe since `counter <= step`.
     add_temporary!(@end format, result, counter, value);
 
-    (result, count)
+    count
 }
 
 /// Compare actual integer digits to the theoretical digits.
@@ -485,7 +551,7 @@ macro_rules! integer_compare {
             };
             let rem = $num.data.quorem(&$den.data) as u32;
             let expected = digit_to_char_const(rem, $radix);
-            $num.data.mul_small($radix as Limb).unwrap();
+            debug_assert_some($num.data.mul_small($radix as Limb));
             if actual < expected {
                 return cmp::Ordering::Less;
             } else if actual > expected {
@@ -519,7 +585,7 @@ macro_rules! fraction_compare {
             };
             let rem = $num.data.quorem(&$den.data) as u32;
             let expected = digit_to_char_const(rem, $radix);
-            $num.data.mul_small($radix as Limb).unwrap();
+            debug_assert_some($num.data.mul_small($radix as Limb));
             if actual < expected {
                 return cmp::Ordering::Less;
             } else if actual > expected {
@@ -572,7 +638,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
 
     // Now, create a scaling factor for the digit count.
     let mut factor = Bigfloat::from_u32(1);
-    factor.pow(format.radix(), sci_exp.abs() as u32).unwrap();
+    debug_assert_some(factor.pow(format.radix(), sci_exp.abs() as u32));
     let mut num: Bigfloat;
     let mut den: Bigfloat;
 
@@ -594,7 +660,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     let wlz = integral_binary_factor(format.radix());
     let nlz = den.leading_zeros().wrapping_sub(wlz) & (32 - 1);
     if nlz != 0 {
-        den.shl_bits(nlz as usize).unwrap();
+        debug_assert_some(den.shl_bits(nlz as usize));
         den.exp -= nlz as i32;
     }
 
@@ -604,7 +670,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     let shift = diff.abs() as usize;
     if diff < 0 {
         // Need to shift the numerator left.
-        num.shl(shift).unwrap();
+        debug_assert_some(num.shl(shift));
         num.exp -= shift as i32;
     } else if diff > 0 {
         // Need to shift denominator left, go by a power of LIMB_BITS.
@@ -614,11 +680,11 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         let (q, r) = shift.ceil_divmod(LIMB_BITS);
         let r = -r;
         if r != 0 {
-            num.shl_bits(r as usize).unwrap();
+            debug_assert_some(num.shl_bits(r as usize));
             num.exp -= r;
         }
         if q != 0 {
-            den.shl_limbs(q).unwrap();
+            debug_assert_some(den.shl_limbs(q));
             den.exp -= LIMB_BITS as i32 * q as i32;
         }
     }
@@ -657,7 +723,8 @@ pub fn compare_bytes<const FORMAT: u128>(
     integer_iter.skip_zeros();
     if integer_iter.is_done() {
         // Cannot be empty, since we must have at least **some** significant digits.
-        let mut fraction = number.fraction.unwrap().bytes::<{ FORMAT }>();
+        debug_assert!(number.fraction.is_some());
+        let mut fraction = number.fraction.unwrap_or(&[]).bytes::<{ FORMAT }>();
         let mut fraction_iter = fraction.fraction_iter();
         fraction_iter.skip_zeros();
         fraction_compare!(fraction_iter, num, den, radix);
@@ -0,0 +1,112 @@
+//! Parse floats from C strings like `strtod`, without `errno`.
+//!
+//! This reports the status that `strtod` sets through `errno`, and
+//! follows the C conventions for leading whitespace, incomplete exponents
+//! and NaN payloads. Unlike `strtod`, this is independent of the C
+//! locale, and hexadecimal floats are not supported.
+
+#![cfg(feature = "std")]
+
+use crate::api::FromLexical;
+use crate::options::Options;
+use crate::parse::parse_sign_special;
+use lexical_util::c_str::c_str_to_bytes;
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use std::os::raw::c_char;
+
+/// Status of a `strtod`-like conversion, replacing `errno`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StrtodStatus {
+    /// The number was converted.
+    Success,
+    /// No number was found, and the end pointer is the start of the string.
+    NoConversion,
+    /// The number overflowed to infinity, or underflowed to zero or a
+    /// subnormal float, like `ERANGE`.
+    OutOfRange,
+}
+
+/// Determine if the byte is whitespace in the C locale, like `isspace`.
+#[inline(always)]
+pub const fn is_c_space(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')
+}
+
+/// Parse a float like `strtod`, from the bytes after leading whitespace.
+///
+/// Returns the value, the number of processed bytes, and the status.
+/// If no number is found, returns zero and no processed bytes, with
+/// [`StrtodStatus::NoConversion`].
+///
+/// * `bytes`   - Byte slice starting with a numeric string.
+pub fn strtod_bytes<F: FromLexical + Float>(bytes: &[u8]) -> (F, usize, StrtodStatus) {
+    let (value, mut count) = match F::from_lexical_partial(bytes) {
+        Ok(result) => result,
+        // `strtod` stops before an exponent without digits, like `1e+`.
+        Err(Error::EmptyExponent(index)) => {
+            let exponent = bytes[..index].iter().rposition(|&c| c == b'e' || c == b'E');
+            match exponent.map(|exponent| F::from_lexical_partial(&bytes[..exponent])) {
+                Some(Ok(result)) => result,
+                _ => return (F::ZERO, 0, StrtodStatus::NoConversion),
+            }
+        },
+        Err(_) => return (F::ZERO, 0, StrtodStatus::NoConversion),
+    };
+
+    if value.is_nan() {
+        // `strtod` consumes an optional `(n-char-sequence)` after NaN.
+        let rest = &bytes[count..];
+        if rest.first() == Some(&b'(') {
+            let is_char = |&&c: &&u8| c.is_ascii_alphanumeric() || c == b'_';
+            let length = rest[1..].iter().take_while(is_char).count();
+            if rest.get(length + 1) == Some(&b')') {
+                count += length + 2;
+            }
+        }
+        return (value, count, StrtodStatus::Success);
+    }
+
+    let options = Options::new();
+    let special = parse_sign_special::<STANDARD>(&bytes[..count], &options);
+    let is_special = matches!(special, Ok((_, Some(_), _)));
+    let mantissa = bytes[..count].split(|&c| c == b'e' || c == b'E').next().unwrap_or(&[]);
+    let is_nonzero = mantissa.iter().any(|c| (b'1'..=b'9').contains(c));
+    let is_overflow = value.is_inf() && !is_special;
+    let is_underflow = is_nonzero && (value == F::ZERO || value.is_denormal());
+    if is_overflow || is_underflow {
+        (value, count, StrtodStatus::OutOfRange)
+    } else {
+        (value, count, StrtodStatus::Success)
+    }
+}
+
+/// Parse a float from a NUL-terminated C string like `strtod`.
+///
+/// Leading whitespace is skipped, and the longest prefix that forms a
+/// float is parsed. Returns the value, the end pointer after the parsed
+/// bytes, and the status. If no number is found, the end pointer is the
+/// start of the string. A null pointer is treated as an empty string.
+///
+/// * `ptr`     - Pointer to a NUL-terminated numeric string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the duration of the call.
+#[inline]
+pub unsafe fn strtod<F: FromLexical + Float>(
+    ptr: *const c_char,
+) -> (F, *const c_char, StrtodStatus) {
+    // SAFETY: safe as long as the caller upholds the safety contract.
+    let bytes = unsafe { c_str_to_bytes(ptr) };
+    let start = bytes.iter().take_while(|&&c| is_c_space(c)).count();
+    let (value, count, status) = strtod_bytes::<F>(&bytes[start..]);
+    let end = match status {
+        StrtodStatus::NoConversion => ptr,
+        // SAFETY: safe, since the parsed bytes are within the string.
+        _ => unsafe { ptr.add(start + count) },
+    };
+    (value, end, status)
+}
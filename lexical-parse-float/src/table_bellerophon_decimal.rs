@@ -28,6 +28,13 @@ pub const fn bellerophon_powers(_: u32) -> &'static BellerophonPowers {
     &BASE10_POWERS
 }
 
+/// Get the seed radix to derive the Bellerophon powers for a radix.
+#[inline]
+#[cfg(not(feature = "radix"))]
+pub const fn bellerophon_seed(radix: u32) -> (u32, i32, i32) {
+    (radix, 1, 0)
+}
+
 // HIGH LEVEL
 // ----------
 
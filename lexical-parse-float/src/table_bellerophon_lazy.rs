@@ -0,0 +1,291 @@
+//! Lazily-computed cached exponents for basen values with 80-bit extended floats.
+//!
+//! Rather than embedding the powers for each seed radix in the binary,
+//! this computes the powers for a radix the first time they're used,
+//! trading a one-time startup cost for ~10 KB of binary size. The
+//! computed powers are identical to the embedded powers, since they
+//! use the same exact, big-integer calculations.
+//!
+//! The powers are stored in zero-initialized statics, and initialization
+//! is protected by a spin lock, so this works without the standard library.
+
+#![cfg(all(feature = "radix", feature = "lazy-tables"))]
+#![doc(hidden)]
+
+use crate::bellerophon::BellerophonPowers;
+#[cfg(feature = "compact")]
+use crate::table_bellerophon_decimal::BASE10_POWERS;
+use crate::table_bellerophon_radix::*;
+use core::cell::UnsafeCell;
+use core::hint;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Get Bellerophon powers from radix.
+#[inline]
+pub fn bellerophon_powers(radix: u32) -> &'static BellerophonPowers {
+    let index = match radix {
+        3 => 0,
+        5 => 1,
+        7 => 2,
+        #[cfg(feature = "compact")]
+        10 => return &BASE10_POWERS,
+        11 => 3,
+        13 => 4,
+        15 => 5,
+        17 => 6,
+        19 => 7,
+        21 => 8,
+        23 => 9,
+        29 => 10,
+        31 => 11,
+        33 => 12,
+        35 => 13,
+        // Fallback if we have an invalid radix.
+        _ => return &INVALID_POWERS,
+    };
+    LAZY_POWERS[index].get(&SEEDS[index])
+}
+
+// SEEDS
+// -----
+
+/// Maximum number of small powers for any radix.
+const MAX_SMALL: usize = 20;
+/// Maximum number of large powers for any radix.
+const MAX_LARGE: usize = 76;
+
+/// Parameters to calculate the powers for a seed radix.
+struct Seed {
+    radix: u32,
+    step: i32,
+    bias: i32,
+    log2: i64,
+    log2_shift: i32,
+    /// Number of large powers.
+    large: usize,
+    /// Scale of the large powers, to calculate negative powers by division.
+    bitshift: usize,
+}
+
+impl Seed {
+    /// Get the parameters from the embedded powers.
+    ///
+    /// This is evaluated at compile-time, so the arrays are not embedded.
+    const fn new(radix: u32, powers: &BellerophonPowers, bitshift: usize) -> Self {
+        Self {
+            radix,
+            step: powers.step,
+            bias: powers.bias,
+            log2: powers.log2,
+            log2_shift: powers.log2_shift,
+            large: powers.large.len(),
+            bitshift,
+        }
+    }
+}
+
+/// Parameters for all seed radixes.
+static SEEDS: [Seed; 14] = [
+    Seed::new(3, &BASE3_POWERS, 1233),
+    Seed::new(5, &BASE5_POWERS, 1236),
+    Seed::new(7, &BASE7_POWERS, 1234),
+    Seed::new(11, &BASE11_POWERS, 1233),
+    Seed::new(13, &BASE13_POWERS, 1233),
+    Seed::new(15, &BASE15_POWERS, 1236),
+    Seed::new(17, &BASE17_POWERS, 1237),
+    Seed::new(19, &BASE19_POWERS, 1232),
+    Seed::new(21, &BASE21_POWERS, 1236),
+    Seed::new(23, &BASE23_POWERS, 1235),
+    Seed::new(29, &BASE29_POWERS, 1234),
+    Seed::new(31, &BASE31_POWERS, 1233),
+    Seed::new(33, &BASE33_POWERS, 1234),
+    Seed::new(35, &BASE35_POWERS, 1233),
+];
+
+// LAZY
+// ----
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+/// Powers for a radix that are computed on first use.
+struct LazyPowers {
+    state: AtomicU8,
+    small: UnsafeCell<[u64; MAX_SMALL]>,
+    large: UnsafeCell<[u64; MAX_LARGE]>,
+    small_int: UnsafeCell<[u64; MAX_SMALL]>,
+    powers: UnsafeCell<BellerophonPowers>,
+}
+
+// SAFETY: safe, since the cells are only written once, by the thread that
+// changes the state from `UNINIT` to `RUNNING`, and only read after the
+// state is `COMPLETE`.
+unsafe impl Sync for LazyPowers {
+}
+
+impl LazyPowers {
+    const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            small: UnsafeCell::new([0; MAX_SMALL]),
+            large: UnsafeCell::new([0; MAX_LARGE]),
+            small_int: UnsafeCell::new([0; MAX_SMALL]),
+            powers: UnsafeCell::new(INVALID_POWERS),
+        }
+    }
+
+    /// Get the powers, computing them if they haven't been computed.
+    #[inline]
+    fn get(&'static self, seed: &Seed) -> &'static BellerophonPowers {
+        if self.state.load(Ordering::Acquire) != COMPLETE {
+            self.initialize(seed);
+        }
+        // SAFETY: safe, since the state is complete, so the powers are never written again.
+        unsafe { &*self.powers.get() }
+    }
+
+    /// Compute the powers, or wait for another thread to compute them.
+    #[cold]
+    fn initialize(&'static self, seed: &Seed) {
+        loop {
+            match self.state.compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(COMPLETE) => return,
+                Err(_) => hint::spin_loop(),
+            }
+        }
+
+        // SAFETY: safe, since we're the only thread that changed the state
+        // to `RUNNING`, and no references exist until the state is complete.
+        unsafe {
+            let small = &mut *self.small.get();
+            let large = &mut *self.large.get();
+            let small_int = &mut *self.small_int.get();
+            compute_powers(seed, small, large, small_int);
+            *self.powers.get() = BellerophonPowers {
+                small: &small[..seed.step as usize],
+                large: &large[..seed.large],
+                small_int: &small_int[..seed.step as usize],
+                step: seed.step,
+                bias: seed.bias,
+                log2: seed.log2,
+                log2_shift: seed.log2_shift,
+            };
+        }
+        self.state.store(COMPLETE, Ordering::Release);
+    }
+}
+
+/// Lazily-computed powers for all seed radixes.
+static LAZY_POWERS: [LazyPowers; 14] = [
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+    LazyPowers::new(),
+];
+
+// COMPUTE
+// -------
+
+/// Compute the normalized powers for a seed radix.
+///
+/// This uses the same algorithm as the generated tables, so the results
+/// are identical: the small powers are exact, and the negative large powers
+/// are calculated by repeatedly dividing a large power of two.
+fn compute_powers(seed: &Seed, small: &mut [u64], large: &mut [u64], small_int: &mut [u64]) {
+    let radix = seed.radix as u64;
+    let step = seed.step as usize;
+    let mut value = 1u64;
+    for index in 0..step {
+        small_int[index] = value;
+        small[index] = value << value.leading_zeros();
+        value *= radix;
+    }
+
+    // `value` is now `radix^step`.
+    let negative = (seed.bias / seed.step) as usize;
+    let mut x = ScaledPower::power_of_two(seed.bitshift);
+    for index in (0..negative).rev() {
+        x.div_small(value);
+        large[index] = x.hi64();
+    }
+
+    let mut x = ScaledPower::power_of_two(seed.bitshift);
+    large[negative] = x.hi64();
+    for item in large.iter_mut().take(seed.large).skip(negative + 1) {
+        x.mul_small(value);
+        *item = x.hi64();
+    }
+}
+
+/// Number of 64-bit limbs for the scaled powers.
+const LIMBS: usize = 40;
+
+/// Minimal big integer to calculate the scaled powers.
+struct ScaledPower {
+    limbs: [u64; LIMBS],
+    len: usize,
+}
+
+impl ScaledPower {
+    /// Create a big integer from a power of two.
+    fn power_of_two(exp: usize) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[exp / 64] = 1 << (exp % 64);
+        Self {
+            limbs,
+            len: exp / 64 + 1,
+        }
+    }
+
+    /// Multiply the integer by a small value.
+    fn mul_small(&mut self, y: u64) {
+        let mut carry = 0;
+        for limb in self.limbs[..self.len].iter_mut() {
+            let z = (*limb as u128) * (y as u128) + carry;
+            *limb = z as u64;
+            carry = z >> 64;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u64;
+            self.len += 1;
+        }
+    }
+
+    /// Divide the integer by a small value, truncating the result.
+    fn div_small(&mut self, y: u64) {
+        let mut rem = 0u128;
+        for limb in self.limbs[..self.len].iter_mut().rev() {
+            let z = (rem << 64) | (*limb as u128);
+            *limb = (z / y as u128) as u64;
+            rem = z % y as u128;
+        }
+        while self.len > 1 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    /// Get the high 64 bits of the integer, truncating the rest.
+    fn hi64(&self) -> u64 {
+        debug_assert!(self.len >= 2);
+        let hi = self.limbs[self.len - 1];
+        let lo = self.limbs[self.len - 2];
+        let shift = hi.leading_zeros();
+        match shift {
+            0 => hi,
+            _ => (hi << shift) | (lo >> (64 - shift)),
+        }
+    }
+}
@@ -5,6 +5,8 @@
 // Re-export all the feature-specific files.
 #[cfg(feature = "compact")]
 pub use crate::table_bellerophon_decimal::*;
+#[cfg(all(feature = "radix", feature = "lazy-tables"))]
+pub use crate::table_bellerophon_lazy::*;
 #[cfg(feature = "radix")]
 pub use crate::table_bellerophon_radix::*;
 #[cfg(not(feature = "compact"))]
@@ -0,0 +1,171 @@
+//! Recommend parse options from a sample corpus.
+//!
+//! Data pipelines often parse millions of floats with the same
+//! conventions, and the best options depend on the data: a corpus
+//! may use a decimal comma, or have few enough significant digits
+//! that the lossy algorithms are always correct. This samples a
+//! user-provided corpus, measures each candidate configuration,
+//! and recommends options along with the measured trade-offs.
+//!
+//! The measurements use wall-clock time, so they are only useful
+//! for comparisons within a single call.
+
+#![cfg(feature = "std")]
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+use lexical_util::num::{AsPrimitive, Float};
+use std::time::{Duration, Instant};
+
+/// Candidate characters to separate the integer from the fraction.
+const DECIMAL_POINTS: [u8; 2] = [b'.', b','];
+
+/// Measurements for parsing a corpus with a single configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Measurement {
+    /// Number of samples that were parsed successfully.
+    pub parsed: usize,
+    /// Number of samples that failed to parse.
+    pub errors: usize,
+    /// Wall-clock time to parse every sample.
+    pub elapsed: Duration,
+}
+
+/// Recommended options, with the measurements used to choose them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recommendation {
+    /// Recommended options to parse the corpus.
+    pub options: Options,
+    /// Measurements with the recommended options, using the correct algorithms.
+    pub exact: Measurement,
+    /// Measurements with the recommended options, using the lossy algorithms.
+    pub lossy: Measurement,
+    /// Number of samples where the lossy result differed from the correct result.
+    pub lossy_mismatches: usize,
+    /// Largest error of a lossy result, in units in the last place.
+    pub lossy_max_ulps: u64,
+}
+
+/// Parse every sample in the corpus, returning the results and measurements.
+fn measure<F: ParseFloat, const FORMAT: u128>(
+    corpus: &[&[u8]],
+    options: &Options,
+) -> (Vec<Option<F>>, Measurement) {
+    let mut results = Vec::with_capacity(corpus.len());
+    let start = Instant::now();
+    for sample in corpus {
+        results.push(F::parse_complete::<FORMAT>(sample, options).ok());
+    }
+    let elapsed = start.elapsed();
+    let parsed = results.iter().filter(|x| x.is_some()).count();
+    let measurement = Measurement {
+        parsed,
+        errors: results.len() - parsed,
+        elapsed,
+    };
+    (results, measurement)
+}
+
+/// Get the distance between two floats, in units in the last place.
+fn ulps<F: Float>(x: F, y: F) -> u64 {
+    if x.is_nan() || y.is_nan() {
+        return if x.is_nan() && y.is_nan() {
+            0
+        } else {
+            u64::MAX
+        };
+    }
+    let x_bits = x.to_bits().as_u64();
+    let y_bits = y.to_bits().as_u64();
+    if x.is_sign_negative() == y.is_sign_negative() {
+        x_bits.max(y_bits) - x_bits.min(y_bits)
+    } else {
+        // Different signs: the distance is through zero, ignoring the sign bits.
+        let sign_mask = F::SIGN_MASK.as_u64();
+        (x_bits & !sign_mask).saturating_add(y_bits & !sign_mask)
+    }
+}
+
+/// Recommend options to parse a corpus of floats.
+///
+/// Each sample must contain a single, complete number. The decimal
+/// point is chosen to minimize the number of errors, preferring the
+/// decimal point of `options` on ties. The lossy algorithms are
+/// recommended if they are faster, and no lossy result differs from
+/// the correct result by more than `max_ulps` units in the last place.
+/// All other options are taken from `options`.
+///
+/// Pass a representative corpus: each configuration is measured with
+/// a single pass over the samples, after a warm-up pass.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::tune::recommend_options;
+/// use lexical_parse_float::Options;
+///
+/// let corpus: [&[u8]; 3] = [b"1,5", b"-2,25", b"3"];
+/// let recommendation = recommend_options::<f64, { STANDARD }>(&corpus, &Options::new(), 0);
+/// assert_eq!(recommendation.options.decimal_point(), b',');
+/// assert_eq!(recommendation.exact.errors, 0);
+/// ```
+pub fn recommend_options<F: ParseFloat, const FORMAT: u128>(
+    corpus: &[&[u8]],
+    options: &Options,
+    max_ulps: u64,
+) -> Recommendation {
+    // Warm up the caches and branch predictors before measuring.
+    let _ = measure::<F, FORMAT>(corpus, options);
+
+    // Choose the decimal point with the fewest errors.
+    let mut best = options.clone();
+    let mut errors = measure::<F, FORMAT>(corpus, &best).1.errors;
+    for &decimal_point in DECIMAL_POINTS.iter() {
+        if decimal_point == options.decimal_point() || decimal_point == options.exponent() {
+            continue;
+        }
+        let candidate = match options.rebuild().decimal_point(decimal_point).build() {
+            Ok(candidate) => candidate,
+            Err(_) => continue,
+        };
+        let measurement = measure::<F, FORMAT>(corpus, &candidate).1;
+        if measurement.errors < errors {
+            best = candidate;
+            errors = measurement.errors;
+        }
+    }
+
+    // Compare the lossy results to the correct results. Toggling
+    // `lossy` cannot invalidate options that were already valid.
+    let exact_options = best.rebuild().lossy(false).build().unwrap_or_else(|_| best.clone());
+    let lossy_options = best.rebuild().lossy(true).build().unwrap_or_else(|_| best.clone());
+    let (exact_results, exact) = measure::<F, FORMAT>(corpus, &exact_options);
+    let (lossy_results, lossy) = measure::<F, FORMAT>(corpus, &lossy_options);
+    let mut lossy_mismatches = 0;
+    let mut lossy_max_ulps = 0;
+    for (x, y) in exact_results.iter().zip(lossy_results.iter()) {
+        let distance = match (x, y) {
+            (Some(x), Some(y)) => ulps(*x, *y),
+            (None, None) => 0,
+            _ => u64::MAX,
+        };
+        if distance != 0 {
+            lossy_mismatches += 1;
+            lossy_max_ulps = lossy_max_ulps.max(distance);
+        }
+    }
+
+    let use_lossy = lossy_max_ulps <= max_ulps && lossy.elapsed < exact.elapsed;
+    Recommendation {
+        options: if use_lossy {
+            lossy_options
+        } else {
+            exact_options
+        },
+        exact,
+        lossy,
+        lossy_mismatches,
+        lossy_max_ulps,
+    }
+}
@@ -0,0 +1,247 @@
+//! Generate test vectors for float parsers, with correctly rounded results.
+//!
+//! The hardest inputs for a float parser are close to the halfway point
+//! between two floats, where a single bit of error changes the result.
+//! These generators create the exact decimal value of floats and their
+//! halfway points, which are known to round to a specific float without
+//! needing a reference parser, so they can be used from downstream tests
+//! and fuzzers. The exact values are generated with big decimal integers,
+//! so subnormal `f64` values have up to 767 significant digits.
+//!
+//! Golden files of the stratified vectors are in `tests/vectors`.
+
+#![cfg(feature = "test-vectors")]
+
+use core::iter;
+use lexical_util::num::{AsCast, AsPrimitive, Float};
+use std::string::String;
+use std::vec::Vec;
+
+/// A decimal string, and the float it must parse to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestVector<F: Float> {
+    /// The decimal string, in scientific notation.
+    pub string: String,
+    /// The correctly rounded float for the string.
+    pub expected: F,
+}
+
+impl<F: Float> TestVector<F> {
+    /// Create a test vector, writing the sign of the float.
+    fn new(is_negative: bool, digits: &[u8], sci_exp: i32, expected: F) -> Self {
+        let mut string = String::with_capacity(digits.len() + 8);
+        if is_negative {
+            string.push('-');
+        }
+        string.push((digits[0] + b'0') as char);
+        if digits.len() > 1 {
+            string.push('.');
+            string.extend(digits[1..].iter().map(|&digit| (digit + b'0') as char));
+        }
+        string.push('e');
+        string.push_str(&sci_exp.to_string());
+        let expected = if is_negative {
+            -expected
+        } else {
+            expected
+        };
+        Self {
+            string,
+            expected,
+        }
+    }
+}
+
+// DECIMAL
+// -------
+
+/// Largest power of 10 that fits in a limb.
+const LIMB_BASE: u64 = 1_000_000_000;
+
+/// Number of decimal digits in a limb.
+const LIMB_DIGITS: usize = 9;
+
+/// Get the significant decimal digits of `mant * 2^exp`, without
+/// removing trailing zeros, and the decimal exponent of the first digit.
+fn decimal_digits(mant: u64, exp: i32) -> (Vec<u8>, i32) {
+    // The limbs are in base `10^9`, in little-endian order.
+    let mut limbs = vec![mant % LIMB_BASE, (mant / LIMB_BASE) % LIMB_BASE, mant / LIMB_BASE.pow(2)];
+    let mut mul_small = |y: u64| {
+        let mut carry = 0;
+        for limb in limbs.iter_mut() {
+            let value = *limb * y + carry;
+            *limb = value % LIMB_BASE;
+            carry = value / LIMB_BASE;
+        }
+        while carry != 0 {
+            limbs.push(carry % LIMB_BASE);
+            carry /= LIMB_BASE;
+        }
+    };
+
+    // The float is `mant * 2^exp`, or `mant * 5^-exp * 10^exp`.
+    let (base, max_power, mut count): (u64, u32, u32) = if exp >= 0 {
+        (2, 32, exp as u32)
+    } else {
+        (5, 13, (-exp) as u32)
+    };
+    while count != 0 {
+        let power = count.min(max_power);
+        mul_small(base.pow(power));
+        count -= power;
+    }
+
+    let mut digits = Vec::with_capacity(limbs.len() * LIMB_DIGITS);
+    for &limb in limbs.iter().rev() {
+        for index in (0..LIMB_DIGITS).rev() {
+            let digit = (limb / 10u64.pow(index as u32) % 10) as u8;
+            if digit != 0 || !digits.is_empty() {
+                digits.push(digit);
+            }
+        }
+    }
+    if digits.is_empty() {
+        digits.push(0);
+        return (digits, 0);
+    }
+    let last_exp = exp.min(0);
+    let sci_exp = last_exp + digits.len() as i32 - 1;
+    (digits, sci_exp)
+}
+
+/// Get the significand and binary exponent of a finite float, so the
+/// magnitude is `mant * 2^exp`.
+fn decompose<F: Float>(value: F) -> (u64, i32) {
+    let bits = value.to_bits().as_u64();
+    let mant = bits & F::MANTISSA_MASK.as_u64();
+    let biased_exp = ((bits & F::EXPONENT_MASK.as_u64()) >> F::MANTISSA_SIZE) as i32;
+    if biased_exp == 0 {
+        (mant, F::DENORMAL_EXPONENT)
+    } else {
+        (mant | F::HIDDEN_BIT_MASK.as_u64(), biased_exp - F::EXPONENT_BIAS)
+    }
+}
+
+/// Get the magnitude of a float.
+fn abs<F: Float>(value: F) -> F {
+    let bits = value.to_bits().as_u64() & !F::SIGN_MASK.as_u64();
+    F::from_bits(F::Unsigned::as_cast(bits))
+}
+
+/// Get the positive float with the next larger magnitude.
+fn next<F: Float>(value: F) -> F {
+    let bits = abs(value).to_bits().as_u64();
+    F::from_bits(F::Unsigned::as_cast(bits + 1))
+}
+
+// GENERATORS
+// ----------
+
+/// Get the exact decimal value of a float, which must parse to the float.
+///
+/// # Panics
+///
+/// Panics if the float is not finite.
+pub fn exact<F: Float>(value: F) -> TestVector<F> {
+    assert!(!value.is_special(), "the float must be finite");
+    let (mant, exp) = decompose(value);
+    let (mut digits, sci_exp) = decimal_digits(mant, exp);
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    TestVector::new(value.is_sign_negative(), &digits, sci_exp, abs(value))
+}
+
+/// Get the decimal strings around the halfway point between a float and
+/// the float with the next larger magnitude.
+///
+/// Returns the string just below the halfway point, which rounds to the
+/// float, the exact halfway point, which rounds to the float with an
+/// even significand, and the string just above the halfway point, which
+/// rounds to the next float. The next float of the largest finite float
+/// is infinity.
+///
+/// # Panics
+///
+/// Panics if the float is not finite.
+pub fn halfway<F: Float>(value: F) -> [TestVector<F>; 3] {
+    assert!(!value.is_special(), "the float must be finite");
+    let is_negative = value.is_sign_negative();
+    let value = abs(value);
+    let next = next(value);
+    let (mant, exp) = decompose(value);
+    let (digits, sci_exp) = decimal_digits(2 * mant + 1, exp - 1);
+
+    // The halfway point is `(2m + 1) * 2^(e - 1)`, so appending a digit
+    // changes the value by less than half of the distance to either float.
+    let mut below = digits.clone();
+    let index = below.iter().rposition(|&digit| digit != 0).unwrap();
+    below[index] -= 1;
+    for digit in below[index + 1..].iter_mut() {
+        *digit = 9;
+    }
+    below.push(9);
+    let mut above = digits.clone();
+    above.push(1);
+
+    let is_even = value.to_bits().as_u64() & 1 == 0;
+    let rounded = if is_even {
+        value
+    } else {
+        next
+    };
+    [
+        TestVector::new(is_negative, &below, sci_exp, value),
+        TestVector::new(is_negative, &digits, sci_exp, rounded),
+        TestVector::new(is_negative, &above, sci_exp, next),
+    ]
+}
+
+/// Get the exact decimal value of every non-negative, finite `f32`.
+///
+/// This generates `2^31 - 2^23` vectors, in order of the bits.
+pub fn all_f32() -> impl Iterator<Item = TestVector<f32>> {
+    (0..f32::INFINITY.to_bits()).map(|bits| exact(f32::from_bits(bits)))
+}
+
+/// Get the decimal strings around the halfway point between every
+/// non-negative, finite `f32` and the next float.
+///
+/// This generates 3 vectors for each float, in order of the bits, like
+/// [`halfway`].
+pub fn halfway_f32() -> impl Iterator<Item = TestVector<f32>> {
+    (0..f32::INFINITY.to_bits()).flat_map(|bits| {
+        let [below, halfway, above] = halfway(f32::from_bits(bits));
+        iter::once(below).chain(iter::once(halfway)).chain(iter::once(above))
+    })
+}
+
+/// Get the next value from the SplitMix64 generator.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Get vectors for random floats, stratified by the binary exponent.
+///
+/// Each float has a random biased exponent, so every binade, including
+/// the subnormal floats, is equally likely, and a random significand.
+/// This generates 4 vectors for each of the `count` floats: the exact
+/// value, and the strings around the halfway point, like [`halfway`].
+/// The same seed always generates the same vectors.
+pub fn stratified<F: Float>(seed: u64, count: usize) -> impl Iterator<Item = TestVector<F>> {
+    let mut state = seed;
+    let max_exponent = F::EXPONENT_MASK.as_u64() >> F::MANTISSA_SIZE;
+    (0..count).flat_map(move |_| {
+        let biased_exp = splitmix64(&mut state) % max_exponent;
+        let mant = splitmix64(&mut state) & F::MANTISSA_MASK.as_u64();
+        let bits = (biased_exp << F::MANTISSA_SIZE) | mant;
+        let value = F::from_bits(F::Unsigned::as_cast(bits));
+        let [below, halfway, above] = halfway(value);
+        let vectors = iter::once(exact(value)).chain(iter::once(below));
+        vectors.chain(iter::once(halfway)).chain(iter::once(above))
+    })
+}
@@ -0,0 +1,85 @@
+//! Caller-supplied scratch space for the slow path.
+//!
+//! The slow path never allocates: all big integers are fixed-size arrays.
+//! However, they are large, and are created on the stack whenever the
+//! slow path is taken, which is unacceptable in interrupt handlers or
+//! kernels with small, fixed stacks. A [`Workspace`] stores these big
+//! integers, so it can be placed in static memory or reused between
+//! calls, and [`WORKSPACE_SIZE`] documents the stack usage it replaces.
+//!
+//! With the `zeroize` feature, the workspace implements `Zeroize`, to
+//! clear the digits of the last parsed number after use.
+
+#![doc(hidden)]
+
+use crate::bigint::{Bigint, BIGINT_LIMBS};
+use core::mem;
+#[cfg(feature = "zeroize")]
+use zeroize_crate::Zeroize;
+
+/// Worst-case number of limbs in a big integer in the slow path.
+///
+/// This is enough for any `f64` in any supported radix.
+pub const WORKSPACE_LIMBS: usize = BIGINT_LIMBS;
+
+/// Size, in bytes, of the big integers used by the slow path.
+///
+/// This is the worst-case stack usage of the big integers in the slow
+/// path, which is avoided by parsing with a [`Workspace`].
+pub const WORKSPACE_SIZE: usize = mem::size_of::<Workspace>();
+
+/// Scratch space for the big integers in the slow path.
+///
+/// The workspace is reset at the start of every use, so it may be reused
+/// for any number of parses. With the `radix` feature, radixes that
+/// cannot be exactly represented in binary, such as 3, still use a
+/// smaller, separate representation on the stack.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::parse::ParseFloat;
+/// use lexical_parse_float::workspace::Workspace;
+/// use lexical_parse_float::Options;
+///
+/// let mut workspace = Workspace::new();
+/// let options = Options::new();
+/// let bytes = b"9007199254740993.0000000000000000000000000000001";
+/// let result = f64::parse_complete_in::<STANDARD>(bytes, &options, &mut workspace);
+/// assert_eq!(result, Ok(9007199254740994.0));
+/// ```
+#[derive(Clone)]
+pub struct Workspace {
+    /// Significant digits of the parsed number.
+    pub real_digits: Bigint,
+    /// Significant digits of the halfway point between two floats.
+    pub theor_digits: Bigint,
+}
+
+impl Workspace {
+    /// Create an empty workspace.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            real_digits: Bigint::new(),
+            theor_digits: Bigint::new(),
+        }
+    }
+}
+
+impl Default for Workspace {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Workspace {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.real_digits.zeroize();
+        self.theor_digits.zeroize();
+    }
+}
@@ -0,0 +1,198 @@
+//! Parse decimal strings to the 80-bit x87 extended format, with correct rounding.
+//!
+//! The x87 extended format has a sign bit, a 15-bit biased exponent,
+//! and a 64-bit significand with an explicit integer bit, and is stored
+//! as a `(u16, u64)` pair of the sign and exponent, and the significand.
+//! The range is much larger than an `f64`, so the number is scaled to
+//! a ratio of big integers, and the significand is found by an exact,
+//! long division.
+
+#![doc(hidden)]
+
+use crate::bigint::{self, Limb, StackVec, LIMB_BITS};
+use crate::custom::parse_estimate;
+use crate::options::Options;
+use core::cmp::Ordering;
+use lexical_util::assert::debug_assert_some;
+use lexical_util::canonical::NumericStr;
+use lexical_util::result::Result;
+
+/// Bias of the exponent.
+const EXPONENT_BIAS: i64 = 16383;
+
+/// Binary exponent of the last significand bit of the smallest float.
+const MIN_EXPONENT: i64 = 1 - EXPONENT_BIAS - 63;
+
+/// Biased exponent of infinity and NaN.
+const MAX_BIASED_EXPONENT: u16 = 0x7FFF;
+
+/// Bitmask for the sign bit, in the sign and exponent.
+const SIGN_MASK: u16 = 0x8000;
+
+/// Bitmask for the explicit integer bit of the significand.
+const INTEGER_BIT: u64 = 1 << 63;
+
+/// Bitmask for the quiet bit of the significand of a NaN.
+const QUIET_BIT: u64 = 1 << 62;
+
+/// Maximum number of significant digits to compare.
+///
+/// The halfway points between two floats have at most 11516 significant
+/// digits, so any digits after these can only break an exact comparison.
+const MAX_DIGITS: usize = 11600;
+
+/// Number of bits in the big integers.
+///
+/// This fits the significant digits, `log2(10**11601) ≅ 38540`, and
+/// the largest scaled power of 5, `log2(5**16553) ≅ 38440`, with the
+/// bits of the quotient.
+const X87_BITS: usize = 39000;
+
+/// Number of limbs in the big integers.
+const X87_LIMBS: usize = X87_BITS / LIMB_BITS;
+
+/// Big integer for the scaled number.
+type X87Int = StackVec<X87_LIMBS>;
+
+/// Largest power of 10 that fits in a limb.
+const CHUNK_DIGITS: u32 = 9;
+
+/// Get the significant digits of the number as an integer, and the
+/// decimal exponent of the last digit.
+///
+/// Truncated digits are replaced by a sticky digit.
+fn significant_digits(number: &NumericStr) -> (X87Int, i64) {
+    let mut bigdigits = X87Int::new();
+    let mut count: i64 = 0;
+    let mut chunk: Limb = 0;
+    let mut chunk_count = 0;
+    let mut digits = number.digits();
+    for digit in digits.by_ref().take(MAX_DIGITS) {
+        chunk = chunk * 10 + (digit - b'0') as Limb;
+        chunk_count += 1;
+        count += 1;
+        if chunk_count == CHUNK_DIGITS {
+            debug_assert_some(bigdigits.mul_small(10u32.pow(chunk_count) as Limb));
+            debug_assert_some(bigdigits.add_small(chunk));
+            chunk = 0;
+            chunk_count = 0;
+        }
+    }
+    if digits.next().is_some() {
+        chunk = chunk * 10 + 1;
+        chunk_count += 1;
+        count += 1;
+    }
+    if chunk_count != 0 {
+        debug_assert_some(bigdigits.mul_small(10u32.pow(chunk_count) as Limb));
+        debug_assert_some(bigdigits.add_small(chunk));
+    }
+    (bigdigits, number.exponent() - count + 1)
+}
+
+/// Divide the big integers, returning the quotient and if the
+/// remainder is non-zero. The quotient must be less than `2^66`.
+fn divide(mut num: X87Int, den: &X87Int) -> (u128, bool) {
+    let mut quotient = 0u128;
+    for shift in (0..66).rev() {
+        let mut scaled = den.clone();
+        debug_assert_some(bigint::shl(&mut scaled, shift));
+        if bigint::compare(&num, &scaled) != Ordering::Less {
+            bigint::large_sub(&mut num, &scaled);
+            quotient |= 1 << shift;
+        }
+    }
+    num.normalize();
+    (quotient, !num.is_empty())
+}
+
+/// Round the magnitude of the non-zero number to the significand and
+/// biased exponent, with ties to even.
+fn round(number: &NumericStr) -> (u16, u64) {
+    // Numbers below half the smallest float round to zero, and numbers
+    // above the largest float round to infinity.
+    if number.exponent() < -4952 {
+        return (0, 0);
+    } else if number.exponent() > 4933 {
+        return (MAX_BIASED_EXPONENT, INTEGER_BIT);
+    }
+
+    // Scale the number to the ratio `num / den * 2^binary_exp`.
+    let (mut num, exp) = significant_digits(number);
+    let mut den = X87Int::from_u32(1);
+    let mut binary_exp = exp;
+    if exp >= 0 {
+        debug_assert_some(bigint::pow(&mut num, 5, exp as u32));
+    } else {
+        debug_assert_some(bigint::pow(&mut den, 5, (-exp) as u32));
+    }
+
+    // Scale the ratio so the quotient has 65 or 66 bits.
+    let shift = 65 - bigint::bit_length(&num) as i64 + bigint::bit_length(&den) as i64;
+    if shift > 0 {
+        debug_assert_some(bigint::shl(&mut num, shift as usize));
+    } else if shift < 0 {
+        debug_assert_some(bigint::shl(&mut den, (-shift) as usize));
+    }
+    binary_exp -= shift;
+    let (quotient, is_truncated) = divide(num, &den);
+    let bits = 128 - quotient.leading_zeros() as i64;
+
+    // Shift the quotient to the 64-bit significand, or fewer bits for
+    // denormal numbers, and round to nearest, tie even.
+    let shift = (bits - 64).max(MIN_EXPONENT - binary_exp);
+    if shift > bits {
+        return (0, 0);
+    }
+    let mut mant = (quotient >> shift) as u64;
+    let halfway = 1u128 << (shift - 1);
+    let remainder = quotient & ((halfway << 1) - 1);
+    let is_above = remainder > halfway || (remainder == halfway && is_truncated);
+    let is_halfway = remainder == halfway && !is_truncated;
+    let mut last_exp = binary_exp + shift;
+    if is_above || (is_halfway && mant & 1 == 1) {
+        mant = match mant.checked_add(1) {
+            Some(mant) => mant,
+            None => {
+                last_exp += 1;
+                INTEGER_BIT
+            },
+        };
+    }
+
+    // Denormal numbers, and zero, have a biased exponent of 0.
+    if mant & INTEGER_BIT == 0 {
+        (0, mant)
+    } else if last_exp - MIN_EXPONENT + 1 >= MAX_BIASED_EXPONENT as i64 {
+        (MAX_BIASED_EXPONENT, INTEGER_BIT)
+    } else {
+        ((last_exp - MIN_EXPONENT + 1) as u16, mant)
+    }
+}
+
+/// Parse a complete decimal string to the nearest x87 extended float.
+///
+/// Returns the sign and biased exponent, and the significand with the
+/// explicit integer bit. Ties round to even, and numbers overflow to
+/// infinity or underflow to zero. NaN is parsed as the quiet NaN with
+/// only the integer and quiet bits set. The format must be decimal.
+pub fn parse_x87<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(u16, u64)> {
+    let (value, number) = parse_estimate::<FORMAT>(bytes, options)?;
+    let (exponent, mant) = match number {
+        _ if value.is_nan() => (MAX_BIASED_EXPONENT, INTEGER_BIT | QUIET_BIT),
+        Some(ref number) if !number.is_zero() => round(number),
+        Some(_) => (0, 0),
+        None => (MAX_BIASED_EXPONENT, INTEGER_BIT),
+    };
+    // The estimate may underflow to zero, and has the sign options applied.
+    let is_negative = match number {
+        Some(number) if mant != 0 => number.is_negative(),
+        _ => value.is_sign_negative(),
+    };
+    let sign = if is_negative {
+        SIGN_MASK
+    } else {
+        0
+    };
+    Ok((sign | exponent, mant))
+}
@@ -0,0 +1,81 @@
+//! Parse floats, and zeroize the big integers of the slow path after use.
+//!
+//! Numbers with many digits, or close to halfway between two floats,
+//! are parsed by storing their significant digits in big integers. These
+//! are left in memory after parsing, so parsing secrets, such as private
+//! amounts or key material encoded as decimal strings, may leak them
+//! through memory dumps, swap, or later uninitialized reads.
+//!
+//! The parsers here use a [`Workspace`] for the big integers, and clear
+//! it with volatile writes before returning, even if parsing failed. The
+//! entire workspace is cleared, not only the limbs used for the number,
+//! so the time taken to clear it doesn't depend on the number parsed.
+//! Only the big integers are cleared: the input, the parsed float, and
+//! values in registers are the responsibility of the caller. With the
+//! `radix` feature, radixes that cannot be exactly represented in binary
+//! use a separate, smaller representation on the stack, which only
+//! stores the float halfway between the two nearest floats.
+
+#![cfg(feature = "zeroize")]
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+use crate::workspace::Workspace;
+use lexical_util::result::Result;
+use zeroize_crate::Zeroize;
+
+/// Parse a float using a complete parser, and zeroize the slow path.
+///
+/// This is like [`parse_complete`](ParseFloat::parse_complete), but the
+/// big integers used for the digits are cleared before returning.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::zeroizing::parse_zeroizing;
+/// use lexical_parse_float::Options;
+///
+/// let options = Options::new();
+/// let bytes = b"9007199254740993.0000000000000000000000000000001";
+/// let result = parse_zeroizing::<f64, STANDARD>(bytes, &options);
+/// assert_eq!(result, Ok(9007199254740994.0));
+/// ```
+#[inline]
+pub fn parse_zeroizing<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<F> {
+    let mut workspace = Workspace::new();
+    let result = F::parse_complete_in::<FORMAT>(bytes, options, &mut workspace);
+    workspace.zeroize();
+    result
+}
+
+/// Parse a float using a partial parser, and zeroize the slow path.
+///
+/// This is like [`parse_partial`](ParseFloat::parse_partial), but the
+/// big integers used for the digits are cleared before returning.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::zeroizing::parse_partial_zeroizing;
+/// use lexical_parse_float::Options;
+///
+/// let options = Options::new();
+/// let result = parse_partial_zeroizing::<f64, STANDARD>(b"2.5e-3,", &options);
+/// assert_eq!(result, Ok((2.5e-3, 6)));
+/// ```
+#[inline]
+pub fn parse_partial_zeroizing<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize)> {
+    let mut workspace = Workspace::new();
+    let result = F::parse_partial_in::<FORMAT>(bytes, options, &mut workspace);
+    workspace.zeroize();
+    result
+}
@@ -1,6 +1,5 @@
-#[cfg(feature = "format")]
 use core::num;
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_float::{ExponentMode, FromLexical, FromLexicalWithOptions, Options};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::error::Error;
@@ -803,6 +802,12 @@ fn f64_required_integer_digits_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+3.0", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3.0", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b".0", &options).is_err());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"3.", &options).is_ok());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"3.e7", &options).is_ok());
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"-.5", &options),
+        Err(Error::EmptyInteger(1))
+    );
 }
 
 #[test]
@@ -814,6 +819,34 @@ fn f64_required_fraction_digits_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3.0", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3.", &options).is_err());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3", &options).is_ok());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b".5", &options).is_ok());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"3e7", &options).is_ok());
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"3.e7", &options),
+        Err(Error::EmptyFraction(2))
+    );
+}
+
+#[test]
+fn f64_empty_integer_fraction_test() {
+    // Either component may be empty by default, but not both.
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<{ STANDARD }>(b".5", &options), Ok(0.5));
+    assert_eq!(f64::from_lexical_with_options::<{ STANDARD }>(b"5.", &options), Ok(5.0));
+    assert_eq!(f64::from_lexical_with_options::<{ STANDARD }>(b"5.e3", &options), Ok(5000.0));
+    assert_eq!(f64::from_lexical_with_options::<{ STANDARD }>(b"-.5e1", &options), Ok(-5.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<{ STANDARD }>(b".", &options),
+        Err(Error::EmptyMantissa(1))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<{ STANDARD }>(b".e3", &options),
+        Err(Error::EmptyMantissa(1))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<{ STANDARD }>(b"5.,", &options),
+        Ok((5.0, 2))
+    );
 }
 
 #[test]
@@ -932,6 +965,53 @@ fn f64_no_leading_zeros_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"010.0", &options).is_err());
 }
 
+#[test]
+fn f64_exponent_limits_test() {
+    let options =
+        Options::builder().max_exponent_digits(num::NonZeroUsize::new(3)).build().unwrap();
+    assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"1e300", &options), Ok(1e300));
+    assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"1e-5", &options), Ok(1e-5));
+    assert_eq!(
+        f64::from_lexical_with_options::<STANDARD>(b"1e0001", &options),
+        Err(Error::ExponentTooLong(2))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<STANDARD>(b"-1.5e+1000", &options),
+        Err(Error::ExponentTooLong(6))
+    );
+
+    let saturate = Options::new();
+    let reject = Options::builder().exponent_mode(ExponentMode::Reject).build().unwrap();
+    let parse = |bytes, options| f64::from_lexical_with_options::<STANDARD>(bytes, options);
+    assert_eq!(parse(b"1e10000000000", &saturate), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1e-10000000000", &saturate), Ok(0.0));
+    assert_eq!(parse(b"0e10000000000", &saturate), Ok(0.0));
+    assert_eq!(parse(b"1e10000000000", &reject), Err(Error::ExponentOverflow(2)));
+    assert_eq!(parse(b"1e-10000000000", &reject), Err(Error::ExponentOverflow(3)));
+    assert_eq!(parse(b"1e2147483647", &reject), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1e2147483648", &reject), Err(Error::ExponentOverflow(2)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_max_leading_zeros_test() {
+    const FORMAT: u128 =
+        rebuild(format::PERMISSIVE).max_leading_zeros(num::NonZeroU8::new(1)).build();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"0.5", &options), Ok(0.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"00.5", &options), Ok(0.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"-01.5", &options), Ok(-1.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b".5", &options), Ok(0.5));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"000.5", &options),
+        Err(Error::InvalidLeadingZeros(1))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"-001e5", &options),
+        Err(Error::InvalidLeadingZeros(2))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_required_exponent_notation_test() {
@@ -1150,6 +1230,110 @@ fn f64_json_no_leading_zero() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"-012.0", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_accounting_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().negative_parentheses(true).optional_quotes(true).build();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"(123.45)", &options), Ok(-123.45));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"\"(123.45)\"", &options), Ok(-123.45));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"\"-1e5\"", &options), Ok(-1e5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"123.45", &options), Ok(123.45));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"(-1.5)", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"(1.5", &options), Err(Error::Empty(4)));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"(1.5e)", &options),
+        Err(Error::EmptyExponent(5))
+    );
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"(inf)", &options), Ok(f64::NEG_INFINITY));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"(1.5),", &options),
+        Ok((-1.5, 5))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"\"1.5\"x", &options),
+        Ok((1.5, 5))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"\"(1.5\"", &options),
+        Err(Error::InvalidDigit(5))
+    );
+
+    let options = Options::new();
+    assert!(f64::from_lexical_with_options::<{ STANDARD }>(b"(1.5)", &options).is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_trailing_sign_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().trailing_sign(true).build();
+    let options = Options::new();
+    let parse = |bytes| f64::from_lexical_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(parse(b"1.5-"), Ok(-1.5));
+    assert_eq!(parse(b"1.5+"), Ok(1.5));
+    assert_eq!(parse(b"-1.5"), Ok(-1.5));
+    assert_eq!(parse(b"1.5e3-"), Ok(-1.5e3));
+    assert_eq!(parse(b"1.5e-3-"), Ok(-1.5e-3));
+    assert_eq!(parse(b"-1.5-"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"1.5--"), Err(Error::InvalidDigit(3)));
+
+    let partial = |bytes| f64::from_lexical_partial_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(partial(b"1.5- 2"), Ok((-1.5, 4)));
+    assert_eq!(partial(b"1.5 -"), Ok((1.5, 3)));
+    assert_eq!(partial(b"+1.5-"), Err(Error::InvalidDigit(4)));
+
+    // Overpunched digits are only used for integers.
+    const OVERPUNCH: u128 = NumberFormatBuilder::new().overpunch_sign(true).build();
+    assert_eq!(
+        f64::from_lexical_with_options::<OVERPUNCH>(b"12J", &options),
+        Err(Error::InvalidDigit(2))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_fortran_exponent_test() {
+    const FORMAT: u128 = format::FORTRAN_STRING;
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5D+10", &options), Ok(1.5e10));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5d-3", &options), Ok(1.5e-3));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5E3", &options), Ok(1.5e3));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5-10", &options), Ok(1.5e-10));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"-2.5+300", &options), Ok(-2.5e300));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"2+3", &options), Ok(2e3));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"1.5-", &options),
+        Err(Error::EmptyExponent(4))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"1.5D", &options),
+        Err(Error::EmptyExponent(4))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"1.5-10 2.5", &options),
+        Ok((1.5e-10, 6))
+    );
+
+    const LITERAL: u128 = format::FORTRAN_LITERAL;
+    assert_eq!(f64::from_lexical_with_options::<LITERAL>(b"1.5D+10", &options), Ok(1.5e10));
+    assert_eq!(
+        f64::from_lexical_with_options::<LITERAL>(b"1.5-10", &options),
+        Err(Error::InvalidDigit(3))
+    );
+
+    const CASE: u128 =
+        NumberFormatBuilder::new().d_exponent_notation(true).case_sensitive_exponent(true).build();
+    assert_eq!(f64::from_lexical_with_options::<CASE>(b"1.5d3", &options), Ok(1.5e3));
+    assert!(f64::from_lexical_with_options::<CASE>(b"1.5D3", &options).is_err());
+
+    assert!(f64::from_lexical_with_options::<{ STANDARD }>(b"1.5D3", &options).is_err());
+    assert!(f64::from_lexical_with_options::<{ STANDARD }>(b"1.5-10", &options).is_err());
+}
+
 #[test]
 #[cfg(all(feature = "power-of-two", feature = "format"))]
 fn base_prefix_test() {
@@ -1242,6 +1426,104 @@ fn issue68_test() {
     assert_eq!(f64::INFINITY, f64::from_lexical_with_options::<FORMAT>(hex, &options).unwrap());
 }
 
+#[test]
+fn signed_zero_nan_test() {
+    use lexical_parse_float::parse::ParseFloat;
+
+    // Fast, moderate, and slow paths, which round to zero.
+    let zeros: [&[u8]; 6] = [
+        b"-0",
+        b"-0.0e10",
+        b"-1e-400",
+        b"-2.4703282292062327e-324",
+        b"-0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        b"-2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+    ];
+    let sign = Options::new();
+    let no_sign = Options::builder().preserve_sign(false).build().unwrap();
+    let lossy = Options::builder().lossy(true).build().unwrap();
+    for &string in zeros.iter() {
+        for options in [&sign, &lossy] {
+            let x = f64::from_lexical_with_options::<STANDARD>(string, options).unwrap();
+            assert_eq!(x.to_bits(), 0x8000_0000_0000_0000, "{:?}", string);
+            let x = f32::from_lexical_with_options::<STANDARD>(string, options).unwrap();
+            assert_eq!(x.to_bits(), 0x8000_0000, "{:?}", string);
+            let (x, _) =
+                f64::from_lexical_partial_with_options::<STANDARD>(string, options).unwrap();
+            assert_eq!(x.to_bits(), 0x8000_0000_0000_0000, "{:?}", string);
+        }
+        let x = f64::from_lexical_with_options::<STANDARD>(string, &no_sign).unwrap();
+        assert_eq!(x.to_bits(), 0, "{:?}", string);
+        let (x, _) = f64::from_lexical_partial_with_options::<STANDARD>(string, &no_sign).unwrap();
+        assert_eq!(x.to_bits(), 0, "{:?}", string);
+    }
+    let x = f64::fast_path_complete::<STANDARD>(b"-0.0", &sign).unwrap();
+    assert_eq!(x.to_bits(), 0x8000_0000_0000_0000);
+    let x = f64::fast_path_complete::<STANDARD>(b"-0.0", &no_sign).unwrap();
+    assert_eq!(x.to_bits(), 0);
+
+    // Signed NaN.
+    let x = f64::from_lexical(b"-NaN").unwrap();
+    assert!(x.is_nan() && x.is_sign_negative());
+    let x = f32::from_lexical(b"-nan").unwrap();
+    assert!(x.is_nan() && x.is_sign_negative());
+    let x = f64::from_lexical(b"NaN").unwrap();
+    assert!(x.is_nan() && x.is_sign_positive());
+    let x = f64::from_lexical_with_options::<STANDARD>(b"-NaN", &no_sign).unwrap();
+    assert!(x.is_nan() && x.is_sign_positive());
+    let (x, count) =
+        f64::from_lexical_partial_with_options::<STANDARD>(b"-nan,", &no_sign).unwrap();
+    assert!(x.is_nan() && x.is_sign_positive());
+    assert_eq!(count, 4);
+
+    // Other values keep their sign.
+    assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"-1.5", &no_sign), Ok(-1.5));
+    assert_eq!(
+        f64::from_lexical_with_options::<STANDARD>(b"-inf", &no_sign),
+        Ok(f64::NEG_INFINITY)
+    );
+    assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"-5e-324", &no_sign), Ok(-5e-324));
+}
+
+#[test]
+fn flush_subnormals_test() {
+    let flush = Options::builder().flush_subnormals(true).build().unwrap();
+    let parse = |bytes: &[u8]| f64::from_lexical_with_options::<{ STANDARD }>(bytes, &flush);
+    assert_eq!(parse(b"1e-310").map(f64::to_bits), Ok(0));
+    assert_eq!(parse(b"-5e-324").map(f64::to_bits), Ok(0x8000_0000_0000_0000));
+    assert_eq!(parse(b"2.2250738585072009e-308").map(f64::to_bits), Ok(0));
+    assert_eq!(parse(b"2.2250738585072014e-308"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(parse(b"2.2250738585072013e-308"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"-inf"), Ok(f64::NEG_INFINITY));
+
+    let result = f32::from_lexical_with_options::<{ STANDARD }>(b"-1e-40", &flush);
+    assert_eq!(result.map(f32::to_bits), Ok(0x8000_0000));
+    let result = f32::from_lexical_with_options::<{ STANDARD }>(b"1.2e-38", &flush);
+    assert_eq!(result, Ok(1.2e-38));
+    let (x, count) =
+        f64::from_lexical_partial_with_options::<{ STANDARD }>(b"-1e-320,", &flush).unwrap();
+    assert_eq!((x.to_bits(), count), (0x8000_0000_0000_0000, 7));
+
+    // The sign is cleared after flushing, unless the options preserve it.
+    let flush_no_sign =
+        Options::builder().flush_subnormals(true).preserve_sign(false).build().unwrap();
+    let result = f64::from_lexical_with_options::<{ STANDARD }>(b"-1e-310", &flush_no_sign);
+    assert_eq!(result.map(f64::to_bits), Ok(0));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn flush_subnormals_f16_test() {
+    let flush = Options::builder().flush_subnormals(true).build().unwrap();
+    let result = f16::from_lexical_with_options::<{ STANDARD }>(b"-1e-6", &flush);
+    assert_eq!(result.map(f16::to_bits), Ok(0x8000));
+    let result = f16::from_lexical_with_options::<{ STANDARD }>(b"1e-4", &flush);
+    assert_eq!(result, Ok(f16::from_f32(1e-4)));
+    let result = bf16::from_lexical_with_options::<{ STANDARD }>(b"1e-39", &flush);
+    assert_eq!(result.map(bf16::to_bits), Ok(0));
+}
+
 fn float_equal<F: Float>(x: F, y: F) -> bool {
     if x.is_nan() {
         y.is_nan()
@@ -1348,7 +1630,58 @@ quickcheck! {
     }
 }
 
+#[test]
+fn f64_parts_test() {
+    use lexical_parse_float::parse::ParseFloat;
+
+    let options = Options::new();
+    let parse = |integer, fraction, exponent| {
+        f64::parse_parts::<STANDARD>(integer, fraction, exponent, &options)
+    };
+    assert_eq!(parse(b"-12", b"5", 3), Ok(-12.5e3));
+    assert_eq!(parse(b"17976931348623157", b"", 292), Ok(f64::MAX));
+    assert_eq!(parse(b"-0", b"0", 0).map(f64::to_bits), Ok(0x8000000000000000));
+    assert_eq!(parse(b"1", b"5", 0x7FFFFFFF), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1", b"5", -0x80000000), Ok(0.0));
+
+    let options = Options::builder().preserve_sign(false).build().unwrap();
+    let result = f64::parse_parts::<STANDARD>(b"-0", b"0", 0, &options);
+    assert_eq!(result.map(f64::to_bits), Ok(0));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn f64_binary_parts_test() {
+    use lexical_parse_float::parse::ParseFloat;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    let result = f64::parse_parts::<BINARY>(b"101", b"01", 0, &options);
+    assert_eq!(result, Ok(5.25));
+    let result = f64::parse_parts::<BINARY>(b"1", b"", -1074, &options);
+    assert_eq!(result, Ok(5e-324));
+    let result = f64::parse_parts::<BINARY>(b"12", b"", 0, &options);
+    assert_eq!(result, Err(Error::InvalidDigit(1)));
+}
+
 proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_parts_proptest(
+        integer in r"[+-]?[0-9]{0,30}",
+        fraction in r"[0-9]{1,30}",
+        exponent in -400i32..400,
+    ) {
+        use lexical_parse_float::parse::ParseFloat;
+
+        let options = Options::new();
+        let joined = format!("{}.{}e{}", integer, fraction, exponent);
+        let expected = f64::from_lexical(joined.as_bytes());
+        let actual =
+            f64::parse_parts::<STANDARD>(integer.as_bytes(), fraction.as_bytes(), exponent, &options);
+        prop_assert_eq!(actual, expected);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn f32_invalid_proptest(i in r"[+-]?[0-9]{2}[^\deE]?\.[^\deE]?[0-9]{2}[^\deE]?e[+-]?[0-9]+[^\deE]") {
@@ -24,3 +24,60 @@ fn bellerophon_radix_test() {
     bellerophon_test::<f64, { BASE3 }>(554, -684, false, 0, 0);
     bellerophon_test::<f64, { BASE3 }>(555, -684, false, 1, 0);
 }
+
+#[test]
+fn bellerophon_derived_radix_test() {
+    // These radixes derive their powers from a seed radix.
+    const BASE6: u128 = NumberFormatBuilder::from_radix(6);
+    const BASE9: u128 = NumberFormatBuilder::from_radix(9);
+    const BASE12: u128 = NumberFormatBuilder::from_radix(12);
+    const BASE27: u128 = NumberFormatBuilder::from_radix(27);
+    const BASE30: u128 = NumberFormatBuilder::from_radix(30);
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+
+    bellerophon_test::<f64, { BASE6 }>(5, -100, false, 3478435342761835, 766);
+    bellerophon_test::<f64, { BASE6 }>(7, 200, false, 3336835064846910, 1542);
+    bellerophon_test::<f64, { BASE9 }>(1, -300, false, 70786481399651, 72);
+    bellerophon_test::<f64, { BASE12 }>(7, -250, false, 2166962389997722, 129);
+    bellerophon_test::<f64, { BASE12 }>(3, 250, false, 3477935522285648, 1920);
+    bellerophon_test::<f64, { BASE27 }>(2, 100, false, 1815980868154555, 1499);
+    bellerophon_test::<f64, { BASE30 }>(29, 100, false, 2076544264973811, 1518);
+    bellerophon_test::<f64, { BASE36 }>(35, -190, false, 3577834410029061, 45);
+    bellerophon_test::<f64, { BASE36 }>(123456789, 150, false, 1309306964257636, 1825);
+}
+
+#[test]
+#[cfg(feature = "lazy-tables")]
+fn bellerophon_lazy_powers_test() {
+    use lexical_parse_float::bellerophon::BellerophonPowers;
+    use lexical_parse_float::table::*;
+
+    let embedded: [(u32, &BellerophonPowers); 14] = [
+        (3, &BASE3_POWERS),
+        (5, &BASE5_POWERS),
+        (7, &BASE7_POWERS),
+        (11, &BASE11_POWERS),
+        (13, &BASE13_POWERS),
+        (15, &BASE15_POWERS),
+        (17, &BASE17_POWERS),
+        (19, &BASE19_POWERS),
+        (21, &BASE21_POWERS),
+        (23, &BASE23_POWERS),
+        (29, &BASE29_POWERS),
+        (31, &BASE31_POWERS),
+        (33, &BASE33_POWERS),
+        (35, &BASE35_POWERS),
+    ];
+    for &(radix, expected) in embedded.iter() {
+        let powers = bellerophon_powers(radix);
+        assert_eq!(powers.small, expected.small);
+        assert_eq!(powers.large, expected.large);
+        assert_eq!(powers.small_int, expected.small_int);
+        assert_eq!(powers.step, expected.step);
+        assert_eq!(powers.bias, expected.bias);
+        assert_eq!(powers.log2, expected.log2);
+        assert_eq!(powers.log2_shift, expected.log2_shift);
+        // Ensure the cached powers are returned.
+        assert!(core::ptr::eq(powers, bellerophon_powers(radix)));
+    }
+}
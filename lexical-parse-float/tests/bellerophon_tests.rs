@@ -248,3 +248,40 @@ fn compute_float_f64_test() {
     assert_eq!(compute_float64(-3, 9007199254740995000), (1065 + INVALID_FP, 9223372036854778879));
     assert_eq!(compute_float64(-3, 9007199254740996000), (1076, 2));
 }
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn diagnostics_test() {
+    use lexical_parse_float::bellerophon::diagnostics;
+    use lexical_parse_float::number::Number;
+
+    let number = |mantissa, exponent| Number {
+        exponent,
+        mantissa,
+        is_negative: false,
+        many_digits: false,
+        integer: &[],
+        fraction: None,
+    };
+
+    // An exact integer is far from halfway.
+    let result = diagnostics::<f64, { STANDARD }>(&number(9007199254740992, 0)).unwrap();
+    assert_eq!(result.errors, 8);
+    assert_eq!(result.extrabits, 11);
+    assert_eq!((result.extra, result.halfway), (0, 1024));
+    assert!(result.is_accurate);
+
+    // Halfway between 2 floats, so the errors make rounding ambiguous.
+    let result = diagnostics::<f64, { STANDARD }>(&number(9007199254740993, 0)).unwrap();
+    assert_eq!((result.extra, result.halfway), (1024, 1024));
+    assert!(!result.is_accurate);
+
+    // Denormal floats truncate more bits.
+    let result = diagnostics::<f64, { STANDARD }>(&number(5, -324)).unwrap();
+    assert_eq!(result.extrabits, 63);
+    assert!(result.is_accurate);
+
+    // Literal underflow and overflow never need the slow path.
+    assert_eq!(diagnostics::<f64, { STANDARD }>(&number(1, -400)), None);
+    assert_eq!(diagnostics::<f64, { STANDARD }>(&number(1, 400)), None);
+}
@@ -1,8 +1,6 @@
-#![cfg(feature = "radix")]
-
 mod stackvec;
 
-use lexical_parse_float::bigint::{Bigfloat, LIMB_BITS};
+use lexical_parse_float::bigint::{self, Bigfloat, LIMB_BITS};
 use lexical_parse_float::float::ExtendedFloat80;
 use stackvec::vec_from_u32;
 
@@ -61,3 +59,62 @@ fn leading_zeros_test() {
     assert_eq!(Bigfloat::from_u32(0xF0).leading_zeros(), LIMB_BITS as u32 - 8);
     assert_eq!(Bigfloat::from_u64(0xF000000000).leading_zeros(), 24);
 }
+
+#[test]
+fn truncate_limbs_test() {
+    let mut x = Bigfloat::from_u32(1);
+    x.shl_limbs(2);
+    x.data.add_small(1);
+    assert_eq!(x.truncate_limbs(3), Some(false));
+    assert_eq!(&*x.data, &[1, 0, 1]);
+
+    assert_eq!(x.truncate_limbs(2), Some(true));
+    assert_eq!(&*x.data, &[0, 1]);
+    assert_eq!(x.exp, LIMB_BITS as i32);
+
+    assert_eq!(x.truncate_limbs(1), Some(false));
+    assert_eq!(&*x.data, &[1]);
+    assert_eq!(x.exp, 2 * LIMB_BITS as i32);
+
+    x.exp = i32::MAX;
+    x.shl_limbs(1);
+    assert_eq!(x.truncate_limbs(1), None);
+    assert_eq!(&*x.data, &[0, 1]);
+    assert_eq!(x.exp, i32::MAX);
+}
+
+#[test]
+fn pow_radix_test() {
+    // Exact, when the value fits.
+    let mut x = Bigfloat::from_u32(1);
+    let mut y = Bigfloat::from_u32(1);
+    assert_eq!(x.pow_radix(10, 300), Some(false));
+    y.pow(10, 300).unwrap();
+    assert!(x == y, "failed");
+
+    // Truncated, but keeps the significant digits and the magnitude.
+    let mut x = Bigfloat::from_u32(1);
+    assert_eq!(x.pow_radix(10, 1000), Some(true));
+    assert_eq!(x.data.hi64(), (0xf38db1f9dd3dac05, true));
+    assert_eq!(bigint::bit_length(&x.data) as i32 + x.exp, 3322);
+
+    let mut x = Bigfloat::from_u32(1);
+    assert_eq!(x.pow_radix(10, 100000), Some(true));
+    assert_eq!(x.data.hi64(), (0xe054e092d5b1ee72, true));
+    assert_eq!(bigint::bit_length(&x.data) as i32 + x.exp, 332193);
+
+    // Powers of two only change the exponent.
+    let mut x = Bigfloat::from_u32(3);
+    assert_eq!(x.pow_radix(2, 100000), Some(false));
+    assert_eq!(&*x.data, &[3]);
+    assert_eq!(x.exp, 100000);
+    assert_eq!(x.pow_radix(2, u32::MAX), None);
+
+    let mut x = Bigfloat::new();
+    assert_eq!(x.pow_radix(10, 1000), Some(false));
+    assert!(x.data.is_empty());
+
+    let mut x = Bigfloat::from_u32(1);
+    x.exp = i32::MAX - 2000;
+    assert_eq!(x.pow_radix(10, 1000), None);
+}
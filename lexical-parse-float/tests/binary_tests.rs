@@ -1,7 +1,10 @@
 #![cfg(feature = "power-of-two")]
 
+use core::num;
 use lexical_parse_float::binary::{binary, slow_binary};
 use lexical_parse_float::number::Number;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::parse;
 use lexical_util::format::NumberFormatBuilder;
 
 const BINARY: u128 = NumberFormatBuilder::from_radix(2);
@@ -9,6 +12,16 @@ const BASE4: u128 = NumberFormatBuilder::from_radix(4);
 const OCTAL: u128 = NumberFormatBuilder::from_radix(8);
 const HEX: u128 = NumberFormatBuilder::from_radix(16);
 const BASE32: u128 = NumberFormatBuilder::from_radix(32);
+const HEX_FLOAT: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .build();
+const OCTAL_FLOAT: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(8)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .build();
 
 fn compute_float32<const FORMAT: u128>(q: i64, w: u64, many_digits: bool) -> (i32, u64) {
     let num = Number {
@@ -159,3 +172,72 @@ fn test_slow() {
     let f = b"000000000000010000000";
     assert_eq!(compute_float64_slow::<BINARY>(i, Some(f), -10), (1076, 1));
 }
+
+fn parse_bits<const FORMAT: u128>(string: &[u8]) -> u64 {
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    parse::parse_complete::<f64, FORMAT>(string, &options).unwrap().to_bits()
+}
+
+#[test]
+fn hex_float_test() {
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.8p0"), 0x3FF8000000000000);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1p10"), 0x4090000000000000);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"0.1p-3"), 0x3F80000000000000);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.fffffffffffffp1023"), 0x7FEFFFFFFFFFFFFF);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"0.0000000000001p-1022"), 0x0000000000000001);
+    assert_eq!(parse_bits::<OCTAL_FLOAT>(b"0.4p0"), 0x3FE0000000000000);
+    assert_eq!(parse_bits::<OCTAL_FLOAT>(b"1.1p3"), 0x4022000000000000);
+}
+
+#[test]
+fn hex_float_halfway_test() {
+    // Exactly halfway, round-down to even.
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.00000000000008p0"), 0x3FF0000000000000);
+    assert_eq!(
+        parse_bits::<HEX_FLOAT>(b"1.000000000000080000000000000000000p0"),
+        0x3FF0000000000000
+    );
+    // Exactly halfway, round-up to even.
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.00000000000018p0"), 0x3FF0000000000002);
+    // Just above halfway, with an arbitrary number of fraction digits.
+    assert_eq!(
+        parse_bits::<HEX_FLOAT>(b"1.00000000000008000000000000000001p0"),
+        0x3FF0000000000001
+    );
+    assert_eq!(
+        parse_bits::<HEX_FLOAT>(
+            b"1.000000000000080000000000000000000000000000000000000000000000000000000000000001p0"
+        ),
+        0x3FF0000000000001
+    );
+    // Just below halfway.
+    assert_eq!(
+        parse_bits::<HEX_FLOAT>(b"1.00000000000007ffffffffffffffffffffp0"),
+        0x3FF0000000000000
+    );
+
+    // Octal digits don't evenly divide a 64-bit mantissa.
+    assert_eq!(
+        parse_bits::<OCTAL_FLOAT>(b"1.00000000000000000200000000000000000000p0"),
+        0x3FF0000000000000
+    );
+    assert_eq!(
+        parse_bits::<OCTAL_FLOAT>(b"1.00000000000000000200000000000000000001p0"),
+        0x3FF0000000000001
+    );
+    assert_eq!(
+        parse_bits::<OCTAL_FLOAT>(b"1.00000000000000000600000000000000000000p0"),
+        0x3FF0000000000002
+    );
+}
+
+#[test]
+fn hex_float_denormal_halfway_test() {
+    // Halfway to the smallest denormal float, round-down to even.
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1p-1075"), 0x0000000000000000);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.0000000000000000000000p-1075"), 0x0000000000000000);
+    // Above halfway, round-up to the smallest denormal float.
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.8p-1075"), 0x0000000000000001);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1.00000000000000000000001p-1075"), 0x0000000000000001);
+    assert_eq!(parse_bits::<HEX_FLOAT>(b"1p-1076"), 0x0000000000000000);
+}
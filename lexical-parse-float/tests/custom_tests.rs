@@ -0,0 +1,90 @@
+use lexical_parse_float::custom::parse_custom;
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::Options;
+use lexical_util::custom_float::LexicalFloat;
+use lexical_util::error::Error;
+
+/// An 8-bit float, with 4 exponent bits and 3 fraction bits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fp8(u8);
+
+impl LexicalFloat for Fp8 {
+    const EXPONENT_SIZE: i32 = 4;
+    const MANTISSA_SIZE: i32 = 3;
+
+    fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        Self(bits as u8)
+    }
+}
+
+fn parse<F: LexicalFloat>(bytes: &[u8]) -> F {
+    parse_custom::<F, { STANDARD }>(bytes, &Options::new()).unwrap()
+}
+
+#[test]
+fn parse_custom_test() {
+    assert_eq!(parse::<Fp8>(b"1"), Fp8(0x38));
+    assert_eq!(parse::<Fp8>(b"-3.0"), Fp8(0xC4));
+    assert_eq!(parse::<Fp8>(b"1.06"), Fp8(0x38));
+    assert_eq!(parse::<Fp8>(b"1.07"), Fp8(0x39));
+    assert_eq!(parse::<Fp8>(b"247.99"), Fp8(0x77));
+    assert_eq!(parse::<Fp8>(b"248"), Fp8(0x78));
+    assert_eq!(parse::<Fp8>(b"1e-5"), Fp8(0x00));
+    assert_eq!(parse::<Fp8>(b"-inf"), Fp8(0xF8));
+    assert!(parse::<Fp8>(b"NaN").is_nan());
+    assert_eq!(parse::<f64>(b"1.7976931348623157e308"), f64::MAX);
+    assert_eq!(parse::<f64>(b"5e-324"), f64::from_bits(1));
+}
+
+#[test]
+fn parse_f32_test() {
+    // Parsing to a custom `f32` must match parsing to an `f32`.
+    let strings = [
+        "0.1",
+        "3.4028235e38",
+        "3.4028236e38",
+        "1.4e-45",
+        "7e-46",
+        "7.000001e-46",
+        "1.1754942e-38",
+        "16777217",
+        "16777219",
+        "1.000000059604644775390625",
+        "1.0000000596046447753906250001",
+        "1.000000178813934326171875",
+        "8.589973e9",
+        "2.2250738585072014e-308",
+    ];
+    for string in strings.iter() {
+        let expected = string.parse::<f32>().unwrap();
+        assert_eq!(parse::<f32>(string.as_bytes()).to_bits(), expected.to_bits(), "{}", string);
+    }
+}
+
+#[test]
+fn parse_tie_test() {
+    // The nearest `f64` is exactly halfway between two `f32`s, so the
+    // digits after the `f64` decide the direction.
+    let halfway = b"1.000000059604644775390625";
+    let above = b"1.00000005960464477539062500000000000000000000000000000001";
+    let below = b"1.00000005960464477539062499999999999999999999999999999999";
+    assert_eq!(parse::<f32>(halfway), 1.0);
+    assert_eq!(parse::<f32>(above), 1.0000001);
+    assert_eq!(parse::<f32>(below), 1.0);
+    assert_eq!(parse::<f32>(b"-1.00000005960464477539062500000000000000001"), -1.0000001);
+
+    // Lossy parsing would break ties incorrectly, so it is ignored.
+    let options = Options::builder().lossy(true).build().unwrap();
+    assert_eq!(parse_custom::<f32, { STANDARD }>(above, &options), Ok(1.0000001));
+}
+
+#[test]
+fn parse_error_test() {
+    let options = Options::new();
+    assert_eq!(parse_custom::<Fp8, { STANDARD }>(b"", &options), Err(Error::Empty(0)));
+    assert_eq!(parse_custom::<Fp8, { STANDARD }>(b"1.0x", &options), Err(Error::InvalidDigit(3)));
+}
@@ -0,0 +1,72 @@
+use core::f64;
+use lexical_parse_float::double_double::parse_double_double;
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::Options;
+use lexical_util::error::Error;
+
+fn parse(bytes: &[u8]) -> (f64, f64) {
+    parse_double_double::<{ STANDARD }>(bytes, &Options::new()).unwrap()
+}
+
+fn check(string: &str, hi: f64, lo: f64) {
+    let result = parse(string.as_bytes());
+    assert_eq!(
+        (result.0.to_bits(), result.1.to_bits()),
+        (hi.to_bits(), lo.to_bits()),
+        "{}",
+        string
+    );
+}
+
+#[test]
+fn parse_double_double_test() {
+    check("1", 1.0, 0.0);
+    check("0.1", 0.1, -5.551115123125783e-18);
+    check("-0.1", -0.1, 5.551115123125783e-18);
+    check("0.3", 0.3, 1.1102230246251566e-17);
+    check("3.14159265358979323846264338327950288", f64::consts::PI, 1.2246467991473532e-16);
+    check("2.71828182845904523536028747135266250", f64::consts::E, 1.4456468917292502e-16);
+    check("0.693147180559945309417232121458176568", f64::consts::LN_2, 2.3190468138462996e-17);
+    check("1.00000000000000000000000000000001", 1.0, 1e-32);
+    check("9007199254740993", 9007199254740992.0, 1.0);
+    check("123456789012345678901234567890", 1.2345678901234568e29, 1023514970834.0);
+    check("1e22", 1e22, 0.0);
+    check("1e23", 1e23, 8388608.0);
+    check("1.7976931348623158e308", f64::MAX, 9.185472576268296e291);
+}
+
+#[test]
+fn parse_subnormal_test() {
+    check("1e-300", 1e-300, -2.5059094e-317);
+    check("2.2250738585072014e-308", 2.2250738585072014e-308, 0.0);
+    check("1e-310", 1e-310, 0.0);
+    check("4.9406564584124654e-324", 5e-324, -0.0);
+    check("7.5e-324", 1e-323, -0.0);
+    check("1e-400", 0.0, 0.0);
+}
+
+#[test]
+fn parse_tie_test() {
+    // The remainder is exactly halfway between two floats, and rounds to even.
+    let halfway = "1.0000000000000000008673617379884036435024594600577460219395221292463659269050824107694097619969397783279418945312";
+    check(&format!("{}5", halfway), 1.0, 8.673617379884035e-19);
+    check(&format!("{}51", halfway), 1.0, 8.673617379884037e-19);
+    check(&format!("{}49999999999999999999", halfway), 1.0, 8.673617379884035e-19);
+}
+
+#[test]
+fn parse_special_test() {
+    check("inf", f64::INFINITY, 0.0);
+    check("-1e400", f64::NEG_INFINITY, -0.0);
+    check("0.0", 0.0, 0.0);
+    check("-0.0", -0.0, -0.0);
+    assert!(parse(b"NaN").0.is_nan());
+    assert_eq!(parse(b"NaN").1, 0.0);
+}
+
+#[test]
+fn parse_error_test() {
+    let options = Options::new();
+    assert_eq!(parse_double_double::<{ STANDARD }>(b"", &options), Err(Error::Empty(0)));
+    assert_eq!(parse_double_double::<{ STANDARD }>(b"1.0x", &options), Err(Error::InvalidDigit(3)));
+}
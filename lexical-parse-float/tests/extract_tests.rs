@@ -0,0 +1,56 @@
+use lexical_parse_float::extract::Numbers;
+use lexical_parse_float::Options;
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+#[test]
+fn standard_test() {
+    let numbers: Vec<_> = Numbers::<f64>::new(b"took 12.5ms, retried 3 times").collect();
+    assert_eq!(numbers, vec![(12.5, 5..9), (3.0, 21..22)]);
+
+    let numbers: Vec<_> = Numbers::<f64>::new(b"x=-1.5e3,y=+.25,z=7.").collect();
+    assert_eq!(numbers, vec![(-1.5e3, 2..8), (0.25, 11..15), (7.0, 18..20)]);
+
+    let numbers: Vec<_> = Numbers::<f32>::new(b"v1.5 3e 4e+ 1e-3").collect();
+    assert_eq!(numbers, vec![(1.5, 1..4), (3.0, 5..6), (4.0, 8..9), (1e-3, 12..16)]);
+}
+
+#[test]
+fn empty_test() {
+    assert_eq!(Numbers::<f64>::new(b"").next(), None);
+    assert_eq!(Numbers::<f64>::new(b"no numbers here").next(), None);
+    assert_eq!(Numbers::<f64>::new(b"NaN inf . - e").next(), None);
+}
+
+#[test]
+fn options_test() {
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let numbers = Numbers::<f64>::with_options::<{ STANDARD }>(b"1,5; 2,25", &options).unwrap();
+    assert_eq!(numbers.collect::<Vec<_>>(), vec![(1.5, 0..3), (2.25, 5..9)]);
+
+    let options = Options::builder().decimal_point(b'e').build().unwrap();
+    let numbers = Numbers::<f64>::with_options::<{ STANDARD }>(b"1", &options);
+    assert_eq!(numbers.err(), Some(Error::InvalidPunctuation));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    use lexical_util::format::JSON;
+
+    let options = Options::new();
+    let haystack = b"[1, .5, 2.0, 01, -3e2]";
+    let numbers = Numbers::<f64>::with_options::<{ JSON }>(haystack, &options).unwrap();
+    let numbers: Vec<_> = numbers.map(|(value, span)| (value, &haystack[span])).collect();
+    assert_eq!(
+        numbers,
+        vec![
+            (1.0, &b"1"[..]),
+            (5.0, &b"5"[..]),
+            (2.0, &b"2.0"[..]),
+            (0.0, &b"0"[..]),
+            (1.0, &b"1"[..]),
+            (-3e2, &b"-3e2"[..]),
+        ]
+    );
+}
@@ -0,0 +1,85 @@
+#![cfg(all(feature = "float160", not(feature = "compact")))]
+
+use lexical_parse_float::float::ExtendedFloat160;
+use lexical_parse_float::float160;
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+fn parse_lossy<F: FromLexicalWithOptions<Options = Options>>(bytes: &[u8]) -> F {
+    let options = Options::builder().lossy(true).build().unwrap();
+    F::from_lexical_with_options::<STANDARD>(bytes, &options).unwrap()
+}
+
+#[test]
+fn normalize_test() {
+    let mut fp = ExtendedFloat160 {
+        mant: 1,
+        exp: 0,
+    };
+    assert_eq!(float160::normalize(&mut fp), 127);
+    assert_eq!(fp.mant, 1 << 127);
+    assert_eq!(fp.exp, -127);
+
+    let mut fp = ExtendedFloat160 {
+        mant: 0,
+        exp: 5,
+    };
+    assert_eq!(float160::normalize(&mut fp), 0);
+    assert_eq!(fp.exp, 5);
+}
+
+#[test]
+fn mul_test() {
+    // 0.5 * 0.5 = 0.25, exactly.
+    let half = ExtendedFloat160 {
+        mant: 1 << 127,
+        exp: -128,
+    };
+    let (fp, is_inexact) = float160::mul(&half, &half);
+    assert_eq!(fp.mant, 1 << 126);
+    assert_eq!(fp.exp, -128);
+    assert!(!is_inexact);
+
+    // (2^128 - 1)^2 = 2^256 - 2^129 + 1, so the low bits are inexact.
+    let max = ExtendedFloat160 {
+        mant: u128::MAX,
+        exp: 0,
+    };
+    let (fp, is_inexact) = float160::mul(&max, &max);
+    assert_eq!(fp.mant, u128::MAX - 1);
+    assert_eq!(fp.exp, 128);
+    assert!(is_inexact);
+}
+
+#[test]
+fn float160_test() {
+    // Digits after the first 19 are needed to round these correctly.
+    assert_eq!(parse_lossy::<f64>(b"9007199254740993.0000000000000000001"), 9007199254740994.0);
+    assert_eq!(parse_lossy::<f64>(b"9007199254740992.9999999999999999999"), 9007199254740992.0);
+    assert_eq!(parse_lossy::<f32>(b"16777217.000000000000000000001"), 16777218.0);
+    assert_eq!(parse_lossy::<f64>(b"1.00000000000000011102230246251565405"), 1.0000000000000002);
+    assert_eq!(parse_lossy::<f64>(b"1.00000000000000011102230246251565403"), 1.0);
+
+    // Overflow, underflow and denormals.
+    assert_eq!(parse_lossy::<f64>(b"1797693134862315807937289714053034150799e270"), f64::INFINITY);
+    assert_eq!(parse_lossy::<f64>(b"17976931348623157081452742373170435679e271"), f64::MAX);
+    assert_eq!(parse_lossy::<f64>(b"2.4703282292062327208828439643411068e-324"), 0.0);
+    assert_eq!(parse_lossy::<f64>(b"2.4703282292062327208828439643411069e-324"), 5e-324);
+    assert_eq!(parse_lossy::<f64>(b"4.9406564584124654417656879286822137e-324"), 5e-324);
+    assert_eq!(parse_lossy::<f64>(b"1.0000000000000000000000000000000001e-400"), 0.0);
+    assert_eq!(
+        parse_lossy::<f64>(b"2.2250738585072011360574097967091319759e-308"),
+        2.225073858507201e-308
+    );
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn float160_proptest(mantissa in 10u128.pow(19)..10u128.pow(38), exponent in -360i32..320) {
+        let string = format!("{}e{}", mantissa, exponent);
+        let expected = f64::from_lexical(string.as_bytes()).unwrap();
+        prop_assert_eq!(parse_lossy::<f64>(string.as_bytes()).to_bits(), expected.to_bits());
+    }
+}
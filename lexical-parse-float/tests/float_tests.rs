@@ -1,5 +1,6 @@
 use lexical_parse_float::float::{self, RawFloat};
 use lexical_parse_float::limits::ExactFloat;
+use lexical_util::exponent::{BiasedExponent, BinaryExponent};
 use lexical_util::num::Float;
 
 #[test]
@@ -163,3 +164,18 @@ fn extended_to_float_test() {
     extended_to_float::<f64>(0, 1076, 9007199254740992.0);
     extended_to_float::<f64>(1, 1076, 9007199254740994.0);
 }
+
+#[test]
+fn assemble_float_test() {
+    let biased = |exp: i32| BinaryExponent::new(exp).to_biased::<f64>();
+    assert_eq!(float::assemble_float::<f64>(0, biased(-52)), 1.0);
+    assert_eq!(float::assemble_float::<f64>(1 << 51, biased(-51)), 3.0);
+    assert_eq!(float::assemble_float::<f64>(1, BiasedExponent::new(0)), 5e-324);
+    assert_eq!(float::assemble_float::<f64>(1 << 52, BiasedExponent::new(0)), f64::MIN_POSITIVE);
+    assert_eq!(float::assemble_float::<f32>(0, BinaryExponent::new(-23).to_biased::<f32>()), 1.0);
+
+    // Biased floats from the typed exponent round-trip.
+    let fp = float::ExtendedFloat80::from_biased(1 << 51, biased(-52));
+    assert_eq!(fp.biased_exponent(), BiasedExponent::of(1.5f64));
+    assert_eq!(float::extended_to_float::<f64>(fp), 1.5);
+}
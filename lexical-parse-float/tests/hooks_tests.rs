@@ -0,0 +1,80 @@
+#![cfg(feature = "hooks")]
+
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use lexical_parse_float::hooks::{self, ParseEvent, Path};
+use lexical_parse_float::options::Options;
+use lexical_parse_float::parse;
+use lexical_util::format::STANDARD;
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static PATH: AtomicUsize = AtomicUsize::new(0);
+static INTEGER_DIGITS: AtomicUsize = AtomicUsize::new(0);
+static FRACTION_DIGITS: AtomicUsize = AtomicUsize::new(0);
+static MANY_DIGITS: AtomicBool = AtomicBool::new(false);
+
+const PATHS: [Path; 4] = [Path::Special, Path::Fast, Path::Moderate, Path::Slow];
+
+fn record(event: &ParseEvent) {
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    let index = PATHS.iter().position(|&path| path == event.path).unwrap();
+    PATH.store(index, Ordering::Relaxed);
+    INTEGER_DIGITS.store(event.integer_digits, Ordering::Relaxed);
+    FRACTION_DIGITS.store(event.fraction_digits, Ordering::Relaxed);
+    MANY_DIGITS.store(event.many_digits, Ordering::Relaxed);
+}
+
+fn last_event() -> ParseEvent {
+    ParseEvent {
+        path: PATHS[PATH.load(Ordering::Relaxed)],
+        integer_digits: INTEGER_DIGITS.load(Ordering::Relaxed),
+        fraction_digits: FRACTION_DIGITS.load(Ordering::Relaxed),
+        many_digits: MANY_DIGITS.load(Ordering::Relaxed),
+    }
+}
+
+fn parse_event(string: &[u8]) -> ParseEvent {
+    let options = Options::new();
+    parse::parse_complete::<f64, STANDARD>(string, &options).unwrap();
+    last_event()
+}
+
+// The hook is global, so everything is checked in a single test.
+#[test]
+fn hooks_test() {
+    assert!(hooks::hook().is_none());
+    hooks::set_hook(Some(record));
+    assert!(hooks::hook().is_some());
+
+    let event = parse_event(b"1.5");
+    assert_eq!(event.path, Path::Fast);
+    assert_eq!(event.integer_digits, 1);
+    assert_eq!(event.fraction_digits, 1);
+    assert!(!event.many_digits);
+
+    assert_eq!(parse_event(b"NaN").path, Path::Special);
+    assert_eq!(parse_event(b"-inf").path, Path::Special);
+    assert_eq!(parse_event(b"1e300").path, Path::Moderate);
+
+    let event = parse_event(b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324");
+    assert_eq!(event.path, Path::Slow);
+    assert_eq!(event.integer_digits, 1);
+    assert_eq!(event.fraction_digits, 751);
+    assert!(event.many_digits);
+
+    let options = Options::new();
+    let result = parse::parse_partial::<f32, STANDARD>(b"3.25 x", &options);
+    assert_eq!(result, Ok((3.25, 4)));
+    assert_eq!(last_event().path, Path::Fast);
+    parse::fast_path_complete::<f64, STANDARD>(b"1e300", &options).unwrap();
+    assert_eq!(last_event().path, Path::Fast);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+
+    // Errors don't invoke the hook.
+    assert!(parse::parse_complete::<f64, STANDARD>(b"1.5x", &options).is_err());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+
+    hooks::set_hook(None);
+    assert!(hooks::hook().is_none());
+    parse::parse_complete::<f64, STANDARD>(b"1.5", &options).unwrap();
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+}
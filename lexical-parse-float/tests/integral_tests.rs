@@ -0,0 +1,37 @@
+use lexical_parse_float::integral::check_integral;
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+#[test]
+fn check_integral_test() {
+    assert_eq!(check_integral::<f64, i64, STANDARD>(b"1500", 1500.0), Ok((1500.0, true)));
+    assert_eq!(check_integral::<f64, i64, STANDARD>(b"1.5", 1.5), Ok((1.5, false)));
+    assert_eq!(check_integral::<f64, u8, STANDARD>(b"256", 256.0), Ok((256.0, false)));
+    assert_eq!(check_integral::<f64, u8, STANDARD>(b"-1", -1.0), Ok((-1.0, false)));
+    assert_eq!(check_integral::<f64, i8, STANDARD>(b"-128", -128.0), Ok((-128.0, true)));
+
+    // The maximum value of a wide integer rounds up, so it's out of range.
+    let max = i64::MAX as f64;
+    assert_eq!(check_integral::<f64, i64, STANDARD>(b"9223372036854775807", max), Ok((max, false)));
+    assert_eq!(
+        check_integral::<f64, i32, STANDARD>(b"2147483647", 2147483647.0),
+        Ok((2147483647.0, true))
+    );
+}
+
+#[test]
+fn check_integral_special_test() {
+    assert_eq!(check_integral::<f64, i64, STANDARD>(b"NaN", f64::NAN), Err(Error::InvalidDigit(0)));
+    assert_eq!(
+        check_integral::<f64, i64, STANDARD>(b"-inf", f64::NEG_INFINITY),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        check_integral::<f64, i64, STANDARD>(b"1e999", f64::INFINITY),
+        Err(Error::Overflow(0))
+    );
+    assert_eq!(
+        check_integral::<f32, i64, STANDARD>(b"-1e99", f32::NEG_INFINITY),
+        Err(Error::Overflow(1))
+    );
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "format")]
+use core::num;
+use lexical_parse_float::mixed::{parse_number, parse_number_with_options, Number};
+use lexical_parse_float::{FromLexical, Options};
+use lexical_util::error::Error;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+#[test]
+fn parse_number_integer_test() {
+    assert_eq!(parse_number(b"0"), Ok(Number::Int(0)));
+    assert_eq!(parse_number(b"+0"), Ok(Number::Int(0)));
+    assert_eq!(parse_number(b"42"), Ok(Number::Int(42)));
+    assert_eq!(parse_number(b"-42"), Ok(Number::Int(-42)));
+    assert_eq!(parse_number(b"0000000000000000000000042"), Ok(Number::Int(42)));
+    assert_eq!(parse_number(b"9223372036854775807"), Ok(Number::Int(i64::MAX)));
+    assert_eq!(parse_number(b"-9223372036854775808"), Ok(Number::Int(i64::MIN)));
+    assert_eq!(parse_number(b"9223372036854775808"), Ok(Number::UInt(1 << 63)));
+    assert_eq!(parse_number(b"10000000000000000000"), Ok(Number::UInt(10000000000000000000)));
+    assert_eq!(parse_number(b"18446744073709551610"), Ok(Number::UInt(18446744073709551610)));
+    assert_eq!(parse_number(b"18446744073709551615"), Ok(Number::UInt(u64::MAX)));
+}
+
+#[test]
+fn parse_number_float_test() {
+    assert_eq!(parse_number(b"-9223372036854775809"), Ok(Number::Float(-9223372036854775809.0)));
+    assert_eq!(parse_number(b"18446744073709551616"), Ok(Number::Float(18446744073709551616.0)));
+    assert_eq!(parse_number(b"18446744073709551620"), Ok(Number::Float(18446744073709551620.0)));
+    assert_eq!(parse_number(b"100000000000000000000"), Ok(Number::Float(1e20)));
+    assert_eq!(parse_number(b"1.0"), Ok(Number::Float(1.0)));
+    assert_eq!(parse_number(b"1e3"), Ok(Number::Float(1000.0)));
+    assert_eq!(parse_number(b"-2.5E-3"), Ok(Number::Float(-2.5e-3)));
+    assert_eq!(parse_number(b"inf"), Ok(Number::Float(f64::INFINITY)));
+
+    let number = parse_number(b"-0");
+    assert!(matches!(number, Ok(Number::Float(x)) if x.to_bits() == (-0.0f64).to_bits()));
+    assert!(matches!(parse_number(b"NaN"), Ok(Number::Float(x)) if x.is_nan()));
+}
+
+#[test]
+fn parse_number_error_test() {
+    assert_eq!(parse_number(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_number(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_number(b"12a"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_number(b"1e"), Err(Error::EmptyExponent(2)));
+}
+
+#[test]
+fn parse_number_options_test() {
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let parse = |bytes| parse_number_with_options::<{ STANDARD }>(bytes, &options);
+    assert_eq!(parse(b"-17"), Ok(Number::Int(-17)));
+    assert_eq!(parse(b"1,5"), Ok(Number::Float(1.5)));
+    assert_eq!(parse(b"1.5"), Err(Error::InvalidDigit(1)));
+
+    let options = Options::builder().flush_subnormals(true).build().unwrap();
+    let result = parse_number_with_options::<{ STANDARD }>(b"1e-310", &options);
+    assert_eq!(result, Ok(Number::Float(0.0)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_number_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    let options = Options::new();
+    let parse = |bytes| parse_number_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(parse(b"1_000"), Ok(Number::Int(1000)));
+    assert_eq!(parse(b"9_223_372_036_854_775_807"), Ok(Number::Int(i64::MAX)));
+    assert_eq!(parse(b"1_0e1"), Ok(Number::Float(100.0)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_number_radix_test() {
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let parse = |bytes| parse_number_with_options::<HEX>(bytes, &options);
+    assert_eq!(parse(b"FF"), Ok(Number::Int(255)));
+    assert_eq!(parse(b"FFFFFFFFFFFFFFFF"), Ok(Number::UInt(u64::MAX)));
+    assert_eq!(parse(b"10000000000000000"), Ok(Number::Float(18446744073709551616.0)));
+    assert_eq!(parse(b"F.8"), Ok(Number::Float(15.5)));
+}
+
+proptest! {
+    #[test]
+    fn parse_number_proptest(s in r"[+-]?[0-9]{1,25}(\.[0-9]{1,5})?([eE][+-]?[0-9]{1,3})?") {
+        let expected = if s.contains(&['.', 'e', 'E'][..]) {
+            None
+        } else if let Ok(value) = s.parse::<i64>() {
+            Some(Number::Int(value)).filter(|_| value != 0 || !s.starts_with('-'))
+        } else {
+            s.trim_start_matches('+').parse::<u64>().ok().map(Number::UInt)
+        };
+        let expected = match expected {
+            Some(number) => number,
+            None => Number::Float(f64::from_lexical(s.as_bytes()).unwrap()),
+        };
+        prop_assert_eq!(parse_number(s.as_bytes()), Ok(expected));
+    }
+}
@@ -53,6 +53,7 @@ fn is_fast_path_test() {
 }
 
 #[test]
+#[cfg(not(feature = "deterministic"))]
 fn try_fast_path_test() {
     let mut number = Number {
         exponent: -4,
@@ -93,3 +94,73 @@ fn try_fast_path_test() {
     assert_eq!(number.try_fast_path::<f32, { STANDARD }>(), None);
     assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), None);
 }
+
+#[test]
+fn normalize_mantissa_test() {
+    let mut number = Number {
+        exponent: -32,
+        mantissa: 1000,
+        is_negative: false,
+        many_digits: false,
+        integer: &[],
+        fraction: None,
+    };
+    let normalized = number.normalize_mantissa::<{ STANDARD }>();
+    assert_eq!(normalized.mantissa, 1);
+    assert_eq!(normalized.exponent, -29);
+
+    number.mantissa = 0;
+    assert_eq!(number.normalize_mantissa::<{ STANDARD }>(), number);
+
+    number.mantissa = 12345;
+    assert_eq!(number.normalize_mantissa::<{ STANDARD }>(), number);
+}
+
+#[test]
+#[cfg(not(feature = "deterministic"))]
+fn try_fast_path_trailing_zeros_test() {
+    let mut number = Number {
+        exponent: -24,
+        mantissa: 100,
+        is_negative: false,
+        many_digits: false,
+        integer: &[],
+        fraction: None,
+    };
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
+    assert_eq!(number.try_fast_path::<f32, { STANDARD }>(), None);
+    assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), Some(1e-22));
+
+    number.exponent = -11;
+    assert_eq!(number.try_fast_path::<f32, { STANDARD }>(), Some(1e-9));
+
+    // The mantissa is out of range, until the trailing zeros are removed.
+    number.mantissa = 12345000000000000000;
+    number.exponent = -35;
+    assert!(!number.is_fast_path::<f64, { STANDARD }>());
+    assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), Some(1.2345e-16));
+
+    // `1e-29` can't be represented exactly with native floats.
+    number.mantissa = 1000;
+    number.exponent = -32;
+    assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), None);
+
+    number.many_digits = true;
+    number.exponent = -20;
+    assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), None);
+}
+
+#[test]
+#[cfg(feature = "deterministic")]
+fn try_fast_path_deterministic_test() {
+    let number = Number {
+        exponent: -4,
+        mantissa: 12345,
+        is_negative: false,
+        many_digits: false,
+        integer: &[],
+        fraction: None,
+    };
+    assert_eq!(number.try_fast_path::<f32, { STANDARD }>(), None);
+    assert_eq!(number.try_fast_path::<f64, { STANDARD }>(), None);
+}
@@ -1,4 +1,5 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+use core::num;
+use lexical_parse_float::options::{self, ExponentMode, Options, OptionsBuilder};
 
 #[test]
 fn invalid_exponent_test() {
@@ -98,6 +99,10 @@ fn builder_test() {
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.preserve_sign(false);
+    builder = builder.max_exponent_digits(num::NonZeroUsize::new(4));
+    builder = builder.exponent_mode(ExponentMode::Reject);
+    builder = builder.flush_subnormals(true);
 
     assert_eq!(builder.get_lossy(), true);
     assert_eq!(builder.get_exponent(), b'^');
@@ -105,6 +110,10 @@ fn builder_test() {
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_preserve_sign(), false);
+    assert_eq!(builder.get_max_exponent_digits(), num::NonZeroUsize::new(4));
+    assert_eq!(builder.get_exponent_mode(), ExponentMode::Reject);
+    assert!(builder.get_flush_subnormals());
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
@@ -121,6 +130,10 @@ fn options_test() {
         opts.set_nan_string(Some(b"nan"));
         opts.set_inf_string(Some(b"Infinity"));
         opts.set_infinity_string(Some(b"Infiniiiiiity"));
+        opts.set_preserve_sign(false);
+        opts.set_max_exponent_digits(num::NonZeroUsize::new(4));
+        opts.set_exponent_mode(ExponentMode::Reject);
+        opts.set_flush_subnormals(true);
     }
 
     assert_eq!(opts.lossy(), true);
@@ -129,8 +142,65 @@ fn options_test() {
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(opts.infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(opts.preserve_sign(), false);
+    assert_eq!(opts.max_exponent_digits(), num::NonZeroUsize::new(4));
+    assert_eq!(opts.exponent_mode(), ExponentMode::Reject);
+    assert!(opts.flush_subnormals());
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn preset_test() {
+    assert_eq!(Options::preset("STANDARD"), Some(options::STANDARD));
+    assert_eq!(Options::preset("JSON"), Some(options::JSON));
+    assert_eq!(Options::preset("json"), None);
+    for (name, preset) in options::PRESETS.iter() {
+        assert!(preset.is_valid(), "{}", name);
+        assert_eq!(Options::preset(name).as_ref(), Some(preset));
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_test() {
+    let json = serde_json::to_string(&Options::new()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"lossy":false,"exponent":"e","decimal_point":".","nan_string":"NaN","inf_string":"inf","infinity_string":"infinity","preserve_sign":true,"max_exponent_digits":null,"exponent_mode":"saturate","flush_subnormals":false}"#
+    );
+    assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), Options::new());
+    assert_eq!(serde_json::from_str::<Options>("{}").unwrap(), Options::new());
+    for (_, preset) in options::PRESETS.iter() {
+        let json = serde_json::to_string(preset).unwrap();
+        assert_eq!(&serde_json::from_str::<Options>(&json).unwrap(), preset);
+    }
+
+    let options: Options = serde_json::from_str(
+        r#"{"preset":"JSON","decimal_point":",","exponent_mode":"reject","max_exponent_digits":4}"#,
+    )
+    .unwrap();
+    assert_eq!(options.nan_string(), options::JSON.nan_string());
+    assert_eq!(options.decimal_point(), b',');
+    assert_eq!(options.exponent_mode(), ExponentMode::Reject);
+    assert_eq!(options.max_exponent_digits(), num::NonZeroUsize::new(4));
+
+    let options: Options = serde_json::from_str(
+        r#"{"nan_string":null,"inf_string":"Inf","infinity_string":"Infinity"}"#,
+    )
+    .unwrap();
+    assert_eq!(options.nan_string(), None);
+    assert_eq!(options.inf_string(), Some(&b"Inf"[..]));
+    assert_eq!(options.infinity_string(), Some(&b"Infinity"[..]));
+    let reloaded: Options = serde_json::from_str(r#"{"inf_string":"Inf"}"#).unwrap();
+    assert_eq!(reloaded.inf_string().unwrap().as_ptr(), options.inf_string().unwrap().as_ptr());
+
+    assert!(serde_json::from_str::<Options>(r#"{"preset":"UNKNOWN"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"lossy":true,"unknown":1}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"exponent":"é"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"exponent":"\u0000"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"nan_string":"inf"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"infinity_string":null}"#).is_err());
+}
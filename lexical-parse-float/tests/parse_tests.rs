@@ -1,5 +1,6 @@
 use lexical_parse_float::options::Options;
 use lexical_parse_float::parse;
+use lexical_util::error::Error;
 use lexical_util::format::STANDARD;
 use lexical_util::iterator::AsBytes;
 use lexical_util::step::u64_step;
@@ -21,6 +22,38 @@ fn parse_complete_test() {
     assert!(result.is_err());
 }
 
+#[test]
+fn parse_complete_bits_test() {
+    // These must be bit-identical on all targets, including x87 FPUs
+    // without SSE2, whether or not the fast-path is used.
+    const FORMAT: u128 = STANDARD;
+    let options = Options::new();
+    let cases: [(&[u8], u64); 7] = [
+        (b"0", 0x0000000000000000),
+        (b"-0", 0x8000000000000000),
+        (b"1.2345", 0x3FF3C083126E978D),
+        (b"1.2345e-6", 0x3EB4B6231ABFD271),
+        (b"9007199254740993", 0x4340000000000000),
+        (b"2.2250738585072011e-308", 0x000FFFFFFFFFFFFF),
+        (b"1.7976931348623157e308", 0x7FEFFFFFFFFFFFFF),
+    ];
+    for &(string, bits) in cases.iter() {
+        let result = parse::parse_complete::<f64, FORMAT>(string, &options).unwrap();
+        assert_eq!(result.to_bits(), bits);
+    }
+
+    let cases: [(&[u8], u32); 4] = [
+        (b"-0", 0x80000000),
+        (b"1.2345", 0x3F9E0419),
+        (b"16777217", 0x4B800000),
+        (b"3.4028235e38", 0x7F7FFFFF),
+    ];
+    for &(string, bits) in cases.iter() {
+        let result = parse::parse_complete::<f32, FORMAT>(string, &options).unwrap();
+        assert_eq!(result.to_bits(), bits);
+    }
+}
+
 #[test]
 fn fast_path_complete_test() {
     const FORMAT: u128 = STANDARD;
@@ -72,6 +105,51 @@ fn fast_path_partial_test() {
     assert_eq!(result, Ok((1.2345, 6)));
 }
 
+#[test]
+fn parse_parts_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::new();
+    let parse = |integer, fraction, exponent| {
+        parse::parse_parts::<f64, FORMAT>(integer, fraction, exponent, &options)
+    };
+    assert_eq!(parse(b"1", b"2345", 10), Ok(1.2345e10));
+    assert_eq!(parse(b"-0", b"", 0).map(f64::to_bits), Ok(0x8000000000000000));
+    assert_eq!(parse(b"+", b"5", -1), Ok(0.05));
+    assert_eq!(parse(b"000", b"000123", 0), Ok(0.000123));
+    assert_eq!(parse(b"1", b"", 400), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1", b"", -400), Ok(0.0));
+
+    // Halfway cases that need the slow path.
+    assert_eq!(parse(b"9007199254740993", b"", 0), Ok(9007199254740992.0));
+    assert_eq!(
+        parse(b"9007199254740993", b"0000000000000000000000000000001", 0),
+        Ok(9007199254740994.0)
+    );
+    assert_eq!(
+        parse(
+            b"",
+            b"247032822920623272088284396434110686182529901307162382212792841250337753635",
+            -323
+        ),
+        Ok(0.0)
+    );
+    assert_eq!(
+        parse(
+            b"",
+            b"2470328229206232720882843964341106861825299013071623822127928412503377536352",
+            -323
+        ),
+        Ok(5e-324)
+    );
+
+    // Errors are at the index in the joined digits.
+    assert_eq!(parse(b"", b"", 0), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parse(b"-", b"", 0), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse(b"1a", b"", 0), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"-12", b"3.", 0), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse(b"1", b"e5", 0), Err(Error::InvalidDigit(2)));
+}
+
 #[test]
 fn parse_number_test() {
     const FORMAT: u128 = STANDARD;
@@ -94,6 +172,52 @@ fn parse_number_test() {
     let byte = string.bytes::<{ FORMAT }>();
     let result = parse::parse_number(byte, false, &options);
     assert!(result.is_err());
+
+    // Truncated digits that are all zeros keep the mantissa exact.
+    let string = b"1.23450000000000000000000e-5";
+    let num = parse::parse_number(string.bytes::<{ FORMAT }>(), false, &options).unwrap();
+    assert_eq!(num.mantissa, 1234500000000000000);
+    assert_eq!(num.exponent, -23);
+    assert!(!num.many_digits);
+
+    let string = b"123450000000000000000000.00e-30";
+    let num = parse::parse_number(string.bytes::<{ FORMAT }>(), false, &options).unwrap();
+    assert_eq!(num.mantissa, 1234500000000000000);
+    assert_eq!(num.exponent, -25);
+    assert!(!num.many_digits);
+
+    let string = b"1.23450000000000000000001e-5";
+    let num = parse::parse_number(string.bytes::<{ FORMAT }>(), false, &options).unwrap();
+    assert!(num.many_digits);
+
+    let string = b"123450000000000000000001.00e-30";
+    let num = parse::parse_number(string.bytes::<{ FORMAT }>(), false, &options).unwrap();
+    assert!(num.many_digits);
+
+    let string = b"123450000000000000000000.01e-30";
+    let num = parse::parse_number(string.bytes::<{ FORMAT }>(), false, &options).unwrap();
+    assert!(num.many_digits);
+}
+
+#[test]
+fn fast_path_trailing_zeros_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::new();
+    let strings = [
+        &b"10.00e-30"[..],
+        b"100e-24",
+        b"1.2300e-25",
+        b"1.23450000000000000000000e-5",
+        b"123450000000000000000000.00e-30",
+        b"12345678901234567890000e-40",
+        b"1.23450000000000000000001e-5",
+    ];
+    for &string in strings.iter() {
+        let expected: f64 = core::str::from_utf8(string).unwrap().parse().unwrap();
+        assert_eq!(parse::parse_complete::<f64, FORMAT>(string, &options), Ok(expected));
+        let expected: f32 = core::str::from_utf8(string).unwrap().parse().unwrap();
+        assert_eq!(parse::parse_complete::<f32, FORMAT>(string, &options), Ok(expected));
+    }
 }
 
 #[test]
@@ -154,12 +278,32 @@ fn parse_digits_test() {
     let mut mantissa: u64 = 0;
     let digits = b"1234567890123456789012345";
     let mut byte = digits.bytes::<{ FORMAT }>();
-    parse::parse_digits::<_, _, FORMAT>(byte.integer_iter(), |digit| {
+    parse::parse_digits::<_, _, FORMAT>(byte.integer_iter(), 10, |digit| {
         mantissa = mantissa.wrapping_mul(10).wrapping_add(digit as _);
     });
     assert_eq!(mantissa, 1096246371337559929);
 }
 
+#[test]
+fn parse_exponent_digits_test() {
+    const FORMAT: u128 = STANDARD;
+    let parse = |digits: &[u8], limit: i64| {
+        let mut byte = digits.bytes::<{ FORMAT }>();
+        parse::parse_exponent_digits::<_, FORMAT>(byte.exponent_iter(), limit)
+    };
+    assert_eq!(parse(b"", i64::MAX), (0, false));
+    assert_eq!(parse(b"308", i64::MAX), (308, false));
+    assert_eq!(parse(b"308", 308), (308, false));
+    assert_eq!(parse(b"309", 308), (308, true));
+    assert_eq!(parse(b"5", 0), (0, true));
+    assert_eq!(parse(b"00000000000000000000000012", 100), (12, false));
+    assert_eq!(parse(b"4294967296", i64::MAX), (4294967296, false));
+    assert_eq!(parse(b"9223372036854775807", i64::MAX), (i64::MAX, false));
+    assert_eq!(parse(b"9223372036854775808", i64::MAX), (i64::MAX, true));
+    assert_eq!(parse(b"100000000000000000000", i64::MAX), (i64::MAX, true));
+    assert_eq!(parse(b"12a", i64::MAX), (12, false));
+}
+
 #[test]
 #[cfg(not(feature = "compact"))]
 fn parse_8digits_test() {
@@ -265,3 +409,28 @@ fn parse_parse_special_test() {
     let result = parse::parse_special::<f64, FORMAT>(byte, true, &options);
     assert_eq!(result, None);
 }
+
+#[test]
+fn parse_sign_special_test() {
+    use lexical_parse_float::{Sign, Special};
+    use lexical_util::error::Error;
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::new();
+    let result = parse::parse_sign_special::<FORMAT>(b"-1.5e3", &options);
+    assert_eq!(result, Ok((Sign::Negative, None, &b"1.5e3"[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"+.5", &options);
+    assert_eq!(result, Ok((Sign::Positive, None, &b".5"[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"12", &options);
+    assert_eq!(result, Ok((Sign::Positive, None, &b"12"[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"-NaN", &options);
+    assert_eq!(result, Ok((Sign::Negative, Some(Special::Nan), &b""[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"infinity,", &options);
+    assert_eq!(result, Ok((Sign::Positive, Some(Special::Infinity), &b","[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"-inf", &options);
+    assert_eq!(result, Ok((Sign::Negative, Some(Special::Infinity), &b""[..])));
+    let result = parse::parse_sign_special::<FORMAT>(b"-x", &options);
+    assert_eq!(result, Ok((Sign::Negative, None, &b"x"[..])));
+    assert_eq!(parse::parse_sign_special::<FORMAT>(b"", &options), Err(Error::Empty(0)));
+    assert_eq!(parse::parse_sign_special::<FORMAT>(b"-", &options), Err(Error::Empty(1)));
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "posit")]
+
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::posit::parse_posit;
+use lexical_parse_float::Options;
+use lexical_util::error::Error;
+use lexical_util::posit::{Posit16, Posit32};
+
+fn parse16(bytes: &[u8]) -> Posit16 {
+    parse_posit::<Posit16, { STANDARD }>(bytes, &Options::new()).unwrap()
+}
+
+fn parse32(bytes: &[u8]) -> Posit32 {
+    parse_posit::<Posit32, { STANDARD }>(bytes, &Options::new()).unwrap()
+}
+
+#[test]
+fn parse_posit_test() {
+    assert_eq!(parse16(b"1"), Posit16::from_bits(0x4000));
+    assert_eq!(parse16(b"-0.5"), Posit16::from_bits(0xC800));
+    assert_eq!(parse16(b"0.1"), Posit16::from_f64(0.1));
+    assert_eq!(parse32(b"3.141592653589793"), Posit32::from_f64(core::f64::consts::PI));
+    assert_eq!(parse16(b"0"), Posit16::ZERO);
+    assert_eq!(parse16(b"-0.0e10"), Posit16::ZERO);
+    assert_eq!(parse16(b"NaN"), Posit16::NAR);
+    assert_eq!(parse16(b"-inf"), Posit16::NAR);
+
+    // Non-zero values are clamped, even if the float overflows or underflows.
+    assert_eq!(parse16(b"1e400"), Posit16::MAX);
+    assert_eq!(parse32(b"1e37"), Posit32::MAX);
+    assert_eq!(parse16(b"1e-400"), Posit16::MIN_POSITIVE);
+    assert_eq!(parse16(b"-1e-400"), Posit16::from_bits(0xFFFF));
+}
+
+#[test]
+fn parse_posit_tie_test() {
+    // Halfway between 0x4000 and 0x4001, which is exactly `1 + 2^-12`.
+    assert_eq!(parse16(b"1.000244140625"), Posit16::from_bits(0x4000));
+    assert_eq!(parse16(b"1.0002441406250000000000000001"), Posit16::from_bits(0x4001));
+    assert_eq!(parse16(b"1.0002441406249999999999999999"), Posit16::from_bits(0x4000));
+    assert_eq!(parse16(b"-1.0002441406250000000000000001"), Posit16::from_bits(0xBFFF));
+
+    // Halfway between 0x4001 and 0x4002, which rounds up to even.
+    assert_eq!(parse16(b"1.000732421875"), Posit16::from_bits(0x4002));
+    assert_eq!(parse16(b"1.0007324218749999999999999999"), Posit16::from_bits(0x4001));
+
+    // Digits far past the tie still break it.
+    let mut digits = b"1.000244140625".to_vec();
+    digits.extend_from_slice(&[b'0'; 1000]);
+    digits.push(b'1');
+    assert_eq!(parse16(&digits), Posit16::from_bits(0x4001));
+
+    // Halfway between 2^52 and 2^56 in the bit pattern is 2^54.
+    assert_eq!(parse16(b"18014398509481984"), Posit16::from_bits(0x7FFE));
+    assert_eq!(parse16(b"18014398509481984.000000000000000001"), Posit16::MAX);
+}
+
+#[test]
+fn parse_posit_error_test() {
+    let options = Options::new();
+    assert_eq!(parse_posit::<Posit16, { STANDARD }>(b"1x", &options), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_posit::<Posit16, { STANDARD }>(b"", &options), Err(Error::Empty(0)));
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "power-of-two")]
+
+use lexical_parse_float::prefixed::{parse_prefixed, parse_prefixed_partial};
+use lexical_util::error::Error;
+use lexical_util::radix::Radix;
+
+#[test]
+fn parse_prefixed_test() {
+    assert_eq!(parse_prefixed::<f64>(b"0x1.8p1"), Ok((3.0, Radix::HEXADECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0X1.8P1"), Ok((3.0, Radix::HEXADECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0x.8"), Ok((0.5, Radix::HEXADECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0xFF"), Ok((255.0, Radix::HEXADECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0x1p-1074"), Ok((5e-324, Radix::HEXADECIMAL)));
+    assert_eq!(
+        parse_prefixed::<f64>(b"0x1.fffffffffffffp1023"),
+        Ok((f64::MAX, Radix::HEXADECIMAL))
+    );
+    assert_eq!(parse_prefixed::<f64>(b"0b101.1p2"), Ok((22.0, Radix::BINARY)));
+    assert_eq!(parse_prefixed::<f64>(b"+0B1p+3"), Ok((8.0, Radix::BINARY)));
+    assert_eq!(parse_prefixed::<f32>(b"0o7.4p1"), Ok((15.0, Radix::OCTAL)));
+    assert_eq!(parse_prefixed::<f32>(b"-0o7.4p-1"), Ok((-3.75, Radix::OCTAL)));
+    assert_eq!(parse_prefixed::<f64>(b"1.5e3"), Ok((1500.0, Radix::DECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"-0.5"), Ok((-0.5, Radix::DECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0"), Ok((0.0, Radix::DECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"-inf"), Ok((f64::NEG_INFINITY, Radix::DECIMAL)));
+
+    let (value, radix) = parse_prefixed::<f64>(b"-0x0p0").unwrap();
+    assert_eq!(value.to_bits(), (-0.0f64).to_bits());
+    assert_eq!(radix, Radix::HEXADECIMAL);
+}
+
+#[test]
+fn parse_prefixed_error_test() {
+    assert_eq!(parse_prefixed::<f64>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_prefixed::<f64>(b"0x"), Err(Error::Empty(2)));
+    assert_eq!(parse_prefixed::<f64>(b"-0x"), Err(Error::Empty(3)));
+    assert_eq!(parse_prefixed::<f64>(b"0x-1"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse_prefixed::<f64>(b"0xinf"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse_prefixed::<f64>(b"0b2"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse_prefixed::<f64>(b"0b12"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_prefixed::<f64>(b"0x1.8e1"), Ok((1.554931640625, Radix::HEXADECIMAL)));
+    assert_eq!(parse_prefixed::<f64>(b"0x1p"), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse_prefixed::<f64>(b"1p1"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+fn parse_prefixed_partial_test() {
+    assert_eq!(parse_prefixed_partial::<f64>(b"0x1p-2, r0"), Ok((0.25, Radix::HEXADECIMAL, 6)));
+    assert_eq!(parse_prefixed_partial::<f64>(b"-0b11;"), Ok((-3.0, Radix::BINARY, 5)));
+    assert_eq!(parse_prefixed_partial::<f32>(b"0o17 "), Ok((15.0, Radix::OCTAL, 4)));
+    assert_eq!(parse_prefixed_partial::<f64>(b"1.5p1"), Ok((1.5, Radix::DECIMAL, 3)));
+    assert_eq!(parse_prefixed_partial::<f64>(b"-2e1)"), Ok((-20.0, Radix::DECIMAL, 4)));
+    assert_eq!(parse_prefixed_partial::<f64>(b"0xg"), Err(Error::EmptyMantissa(2)));
+    assert_eq!(parse_prefixed_partial::<f64>(b"0x1p+"), Err(Error::EmptyExponent(5)));
+}
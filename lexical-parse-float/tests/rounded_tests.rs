@@ -0,0 +1,183 @@
+use core::num;
+use lexical_parse_float::rounded::{parse_rounded, parse_significant};
+use lexical_parse_float::{FromLexical, Options};
+use lexical_util::canonical::{NumericStr, Rounding};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+const ROUNDINGS: [Rounding; 7] = [
+    Rounding::HalfEven,
+    Rounding::HalfUp,
+    Rounding::HalfDown,
+    Rounding::Up,
+    Rounding::Down,
+    Rounding::Ceiling,
+    Rounding::Floor,
+];
+
+fn parse(bytes: &[u8], places: usize, rounding: Rounding) -> Result<f64, Error> {
+    parse_rounded::<f64, { STANDARD }>(bytes, places, rounding, &Options::new())
+}
+
+#[test]
+fn parse_rounded_test() {
+    assert_eq!(parse(b"2.675", 2, Rounding::HalfEven), Ok(2.68));
+    assert_eq!(parse(b"2.665", 2, Rounding::HalfEven), Ok(2.66));
+    assert_eq!(parse(b"2.665", 2, Rounding::HalfUp), Ok(2.67));
+    assert_eq!(parse(b"2.665", 2, Rounding::HalfDown), Ok(2.66));
+    assert_eq!(parse(b"2.6651", 2, Rounding::HalfDown), Ok(2.67));
+    assert_eq!(parse(b"2.661", 2, Rounding::Up), Ok(2.67));
+    assert_eq!(parse(b"2.669", 2, Rounding::Down), Ok(2.66));
+    assert_eq!(parse(b"-2.661", 2, Rounding::Ceiling), Ok(-2.66));
+    assert_eq!(parse(b"-2.661", 2, Rounding::Floor), Ok(-2.67));
+    assert_eq!(parse(b"0.5", 0, Rounding::HalfEven), Ok(0.0));
+    assert_eq!(parse(b"1.5", 0, Rounding::HalfEven), Ok(2.0));
+    assert_eq!(parse(b"9.995", 2, Rounding::HalfUp), Ok(10.0));
+    assert_eq!(parse(b"1234.5e-2", 1, Rounding::HalfEven), Ok(12.3));
+    assert_eq!(parse(b"1.25e2", 0, Rounding::HalfEven), Ok(125.0));
+
+    // Digits far below the decimal places round to zero or the last place.
+    assert_eq!(parse(b"0.004", 2, Rounding::HalfUp), Ok(0.0));
+    assert_eq!(parse(b"0.004", 2, Rounding::Up), Ok(0.01));
+    assert_eq!(parse(b"-4e-300", 3, Rounding::Floor), Ok(-0.001));
+    assert_eq!(parse(b"-0.004", 2, Rounding::HalfEven).map(f64::to_bits), Ok((-0.0f64).to_bits()));
+
+    // Numbers without digits after the decimal places are unchanged.
+    assert_eq!(parse(b"2.50000", 1, Rounding::Up), Ok(2.5));
+    assert_eq!(parse(b"0.1", usize::MAX, Rounding::Up), Ok(0.1));
+    assert_eq!(parse(b"1e-999", 2000, Rounding::Up), Ok(0.0));
+    assert_eq!(parse(b"-0.000", 1, Rounding::Floor).map(f64::to_bits), Ok((-0.0f64).to_bits()));
+    assert_eq!(parse(b"1e400", 0, Rounding::HalfEven), Ok(f64::INFINITY));
+}
+
+#[test]
+fn parse_rounded_tie_test() {
+    // `9007199254740993` is halfway between two floats, so only the
+    // rounded digits after the maximum number of digits break the tie.
+    let mut digits = b"9007199254740993.".to_vec();
+    digits.extend_from_slice(&[b'0'; 998]);
+    digits.push(b'1');
+    assert_eq!(parse(&digits, 998, Rounding::Down), Ok(9007199254740992.0));
+    assert_eq!(parse(&digits, 998, Rounding::Up), Ok(9007199254740994.0));
+    assert_eq!(parse(&digits, 999, Rounding::Down), Ok(9007199254740994.0));
+
+    let mut digits = b"9007199254740992.".to_vec();
+    digits.extend_from_slice(&[b'9'; 1000]);
+    assert_eq!(parse(&digits, 999, Rounding::Down), Ok(9007199254740992.0));
+    assert_eq!(parse(&digits, 999, Rounding::Up), Ok(9007199254740992.0));
+    assert_eq!(parse(&digits, 998, Rounding::HalfUp), Ok(9007199254740992.0));
+}
+
+fn parse_digits(bytes: &[u8], digits: usize, rounding: Rounding) -> Result<f64, Error> {
+    let digits = num::NonZeroUsize::new(digits).unwrap();
+    parse_significant::<f64, { STANDARD }>(bytes, digits, rounding, &Options::new())
+}
+
+#[test]
+fn parse_significant_test() {
+    assert_eq!(parse_digits(b"6.02214076", 4, Rounding::HalfUp), Ok(6.022));
+    assert_eq!(parse_digits(b"6.02214076", 1, Rounding::Up), Ok(7.0));
+    assert_eq!(parse_digits(b"123456", 4, Rounding::Down), Ok(123400.0));
+    assert_eq!(parse_digits(b"123450", 4, Rounding::HalfEven), Ok(123400.0));
+    assert_eq!(parse_digits(b"123350", 4, Rounding::HalfEven), Ok(123400.0));
+    assert_eq!(parse_digits(b"9.99", 2, Rounding::HalfUp), Ok(10.0));
+    assert_eq!(parse_digits(b"-0.000123456", 4, Rounding::HalfEven), Ok(-0.0001235));
+    assert_eq!(parse_digits(b"-0.000123456", 4, Rounding::Ceiling), Ok(-0.0001234));
+    assert_eq!(parse_digits(b"1.2345678901234567e300", 7, Rounding::HalfEven), Ok(1.234568e300));
+    assert_eq!(parse_digits(b"9.9999999e-310", 3, Rounding::HalfEven), Ok(1e-309));
+    assert_eq!(parse_digits(b"1.25", usize::MAX, Rounding::Down), Ok(1.25));
+    assert_eq!(parse_digits(b"-0.0", 1, Rounding::Up).map(f64::to_bits), Ok((-0.0f64).to_bits()));
+
+    // Emulate single-precision floats stored with 7 significant digits.
+    assert_eq!(parse_digits(b"0.1", 7, Rounding::HalfEven), Ok(0.1));
+    assert_eq!(parse_digits(b"0.100000001490116", 7, Rounding::HalfEven), Ok(0.1));
+    assert_eq!(parse_digits(b"16777217", 7, Rounding::Down), Ok(16777210.0));
+}
+
+#[test]
+fn parse_rounded_options_test() {
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let result = parse_rounded::<f32, { STANDARD }>(b"1,005", 2, Rounding::HalfUp, &options);
+    assert_eq!(result, Ok(1.01));
+
+    assert_eq!(parse(b"NaN", 2, Rounding::HalfEven).map(f64::is_nan), Ok(true));
+    assert_eq!(parse(b"-inf", 2, Rounding::HalfEven), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"", 2, Rounding::HalfEven), Err(Error::Empty(0)));
+    assert_eq!(parse(b"1.2x", 2, Rounding::HalfEven), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.2e", 2, Rounding::HalfEven), Err(Error::EmptyExponent(4)));
+}
+
+#[test]
+fn parse_rounded_signed_zero_test() {
+    let parse =
+        |bytes, options| parse_rounded::<f64, { STANDARD }>(bytes, 2, Rounding::HalfEven, options);
+    let options = Options::new();
+    let result = parse(b"-0.004", &options);
+    assert_eq!(result.map(f64::is_sign_negative), Ok(true));
+
+    let options = Options::builder().preserve_sign(false).build().unwrap();
+    let result = parse(b"-0.004", &options);
+    assert_eq!(result.map(f64::is_sign_negative), Ok(false));
+    let result = parse(b"-0.000", &options);
+    assert_eq!(result.map(f64::is_sign_negative), Ok(false));
+    let result = parse_significant::<f64, { STANDARD }>(
+        b"-1.25e-400",
+        num::NonZeroUsize::new(2).unwrap(),
+        Rounding::HalfEven,
+        &options,
+    );
+    assert_eq!(result.map(f64::is_sign_negative), Ok(false));
+}
+
+#[test]
+fn parse_rounded_flush_subnormals_test() {
+    let options = Options::builder().flush_subnormals(true).build().unwrap();
+    let result =
+        parse_rounded::<f64, { STANDARD }>(b"1.55e-310", 311, Rounding::HalfEven, &options);
+    assert_eq!(result, Ok(0.0));
+    let result =
+        parse_rounded::<f64, { STANDARD }>(b"-1.55e-310", 311, Rounding::HalfEven, &options);
+    assert_eq!(result.map(f64::is_sign_negative), Ok(true));
+    let digits = num::NonZeroUsize::new(2).unwrap();
+    let result =
+        parse_significant::<f64, { STANDARD }>(b"1.55e-310", digits, Rounding::HalfEven, &options);
+    assert_eq!(result, Ok(0.0));
+
+    // Normal floats are not flushed.
+    let result =
+        parse_rounded::<f64, { STANDARD }>(b"1.55e-300", 301, Rounding::HalfEven, &options);
+    assert_eq!(result, Ok(1.6e-300));
+}
+
+proptest! {
+    #[test]
+    fn parse_rounded_proptest(
+        s in r"-?[0-9]{1,20}\.[0-9]{1,20}",
+        places in 0usize..25,
+        index in 0usize..7,
+    ) {
+        let rounding = ROUNDINGS[index];
+        let number = NumericStr::parse::<{ STANDARD }>(s.as_bytes(), b'e', b'.').unwrap();
+        let mut buffer = [0u8; 64];
+        let count = number.quantize(places, rounding, &mut buffer).unwrap();
+        let expected = f64::from_lexical(&buffer[..count]).unwrap();
+        prop_assert_eq!(parse(s.as_bytes(), places, rounding), Ok(expected));
+    }
+
+    #[test]
+    fn parse_significant_proptest(
+        s in r"-?[0-9]{1,20}\.[0-9]{1,20}(e-?[0-9]{1,2})?",
+        digits in 1usize..25,
+        index in 0usize..7,
+    ) {
+        // Rounding to significant digits is rounding to the decimal
+        // places of the last significant digit.
+        let rounding = ROUNDINGS[index];
+        let number = NumericStr::parse::<{ STANDARD }>(s.as_bytes(), b'e', b'.').unwrap();
+        let places = digits as i64 - 1 - number.exponent();
+        prop_assume!(places >= 0);
+        let expected = parse(s.as_bytes(), places as usize, rounding);
+        prop_assert_eq!(parse_digits(s.as_bytes(), digits, rounding), expected);
+    }
+}
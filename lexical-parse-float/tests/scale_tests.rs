@@ -0,0 +1,62 @@
+use lexical_parse_float::scale::scale_by_pow10;
+use lexical_parse_float::FromLexical;
+use proptest::prelude::*;
+
+/// Parse the exact decimal value of a float, scaled by a power of ten.
+fn parse_scaled(value: f64, exponent: i32) -> f64 {
+    let string = format!("{:.800e}", value);
+    let index = string.find('e').unwrap();
+    let exponent = string[index + 1..].parse::<i32>().unwrap() + exponent;
+    let string = format!("{}e{}", &string[..index], exponent);
+    f64::from_lexical(string.as_bytes()).unwrap()
+}
+
+#[test]
+fn scale_by_pow10_test() {
+    // Special values and zeros are unchanged.
+    assert!(scale_by_pow10(f64::NAN, 5).is_nan());
+    assert_eq!(scale_by_pow10(f64::INFINITY, -400), f64::INFINITY);
+    assert_eq!(scale_by_pow10(f64::NEG_INFINITY, 5), f64::NEG_INFINITY);
+    assert_eq!(scale_by_pow10(-0.0f64, 400).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(scale_by_pow10(1.5f64, 0), 1.5);
+
+    // Fast path.
+    assert_eq!(scale_by_pow10(1.5f64, 3), 1500.0);
+    assert_eq!(scale_by_pow10(-1.5f64, -3), -0.0015);
+    assert_eq!(scale_by_pow10(1.5f32, 10), 1.5e10);
+    assert_eq!(scale_by_pow10(3.0f32, -10), 3e-10);
+
+    // Slow path.
+    assert_eq!(scale_by_pow10(1.5f64, 300), 1.5e300);
+    assert_eq!(scale_by_pow10(-1.5f64, -300), -1.5e-300);
+    assert_eq!(scale_by_pow10(3.0f64, -310), 3e-310);
+    assert_eq!(scale_by_pow10(123.0f64, -325), 123e-325);
+    assert_eq!(scale_by_pow10(9007199254740991.0f64, 23), 9007199254740991e23);
+    assert_eq!(scale_by_pow10(0.5f64, -323), 5e-324);
+    assert_eq!(scale_by_pow10(5e-324f64, 323), 0.49406564584124657);
+    assert_eq!(scale_by_pow10(3.0f32, -40), 3e-40);
+    assert_eq!(scale_by_pow10(3.0f32, 20), 3e20);
+
+    // Overflow and underflow preserve the sign.
+    assert_eq!(scale_by_pow10(1.8f64, 308), f64::INFINITY);
+    assert_eq!(scale_by_pow10(-1.0f64, 309), f64::NEG_INFINITY);
+    assert_eq!(scale_by_pow10(2.4f64, -324).to_bits(), 0);
+    assert_eq!(scale_by_pow10(-2.5f64, -324), -5e-324);
+    assert_eq!(scale_by_pow10(5e-324f64, i32::MAX), f64::INFINITY);
+    assert_eq!(scale_by_pow10(f64::MAX, i32::MIN).to_bits(), 0);
+    assert_eq!(scale_by_pow10(f32::MAX, -84).to_bits(), 0);
+
+    // Exact halfway cases round to even.
+    assert_eq!(scale_by_pow10(1801439850948199.0f64, 1), 18014398509481992.0);
+    assert_eq!(scale_by_pow10(1801439850948201.0f64, 1), 18014398509482008.0);
+    assert_eq!(scale_by_pow10(1.0f64, 23), 1e23);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn scale_by_pow10_proptest(bits in 1u64..0x7FF0_0000_0000_0000, exponent in -700i32..700) {
+        let value = f64::from_bits(bits);
+        prop_assert_eq!(scale_by_pow10(value, exponent).to_bits(), parse_scaled(value, exponent).to_bits());
+    }
+}
@@ -284,6 +284,26 @@ fn pow_test() {
     assert_eq!(&*x, &*expected);
 }
 
+#[test]
+fn pow_chunked_test() {
+    let mut x = VecType::from_u32(1);
+    let mut y = VecType::from_u32(1);
+    let mut calls = 0;
+    bigint::pow_chunked(&mut x, 5, 302, |_, limbs| {
+        assert!(limbs >= 1);
+        calls += 1;
+        Some(())
+    })
+    .unwrap();
+    bigint::pow(&mut y, 5, 302).unwrap();
+    assert_eq!(&*x, &*y);
+    assert!(calls >= 1);
+
+    let mut x = VecType::from_u32(1);
+    assert_eq!(bigint::pow_chunked(&mut x, 5, 302, |_, _| None), None);
+    assert_eq!(&*x, &[1]);
+}
+
 #[test]
 fn large_add_test() {
     // Overflow, both single values
@@ -322,6 +342,36 @@ fn large_add_test() {
     assert_eq!(&*x, &*expected);
 }
 
+#[test]
+fn large_sub_test() {
+    // Borrow, both single values
+    let mut x: VecType = vec_from_u32(&[4, 1]);
+    let y = VecType::from_u32(5);
+    bigint::large_sub(&mut x, &y);
+    let expected = VecType::from_u32(4294967295);
+    assert_eq!(&*x, &*expected);
+
+    // No borrow, single value
+    let mut x = VecType::from_u32(12);
+    let y = VecType::from_u32(7);
+    bigint::large_sub(&mut x, &y);
+    let expected = VecType::from_u32(5);
+    assert_eq!(&*x, &*expected);
+
+    // Borrow propagates past the end of y.
+    let mut x: VecType = vec_from_u32(&[0, 0, 1]);
+    let y = VecType::from_u32(1);
+    bigint::large_sub(&mut x, &y);
+    let expected: VecType = vec_from_u32(&[0xFFFFFFFF, 0xFFFFFFFF]);
+    assert_eq!(&*x, &*expected);
+
+    // Equal values are zero.
+    let mut x: VecType = vec_from_u32(&[6, 0x80000001]);
+    let y: VecType = vec_from_u32(&[6, 0x80000001]);
+    bigint::large_sub(&mut x, &y);
+    assert!(x.is_empty());
+}
+
 #[test]
 fn large_mul_test() {
     // Test by empty
@@ -426,6 +476,24 @@ fn shl_limbs_test() {
     assert_eq!(&*x, &*expected);
 }
 
+#[test]
+fn shr_limbs_test() {
+    let mut x: VecType = vec_from_u32(&[0, 0, 0, 0, 0xD2210408]);
+    let n = if LIMB_BITS == 32 {
+        2
+    } else {
+        1
+    };
+    assert!(!bigint::shr_limbs(&mut x, n));
+    let expected: VecType = vec_from_u32(&[0, 0, 0xD2210408]);
+    assert_eq!(&*x, &*expected);
+
+    let mut x: VecType = vec_from_u32(&[1, 0, 0xD2210408]);
+    assert!(bigint::shr_limbs(&mut x, 1));
+    assert_eq!(bigint::shr_limbs(&mut x, 5), LIMB_BITS == 64);
+    assert!(x.is_empty());
+}
+
 #[test]
 fn shl_test() {
     // Pattern generated via `''.join(["1" +"0"*i for i in range(20)])`
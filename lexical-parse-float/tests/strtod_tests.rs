@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use lexical_parse_float::strtod::{self, StrtodStatus};
+use std::ptr;
+
+#[test]
+fn is_c_space_test() {
+    for &c in b" \t\n\x0B\x0C\r".iter() {
+        assert!(strtod::is_c_space(c));
+    }
+    assert!(!strtod::is_c_space(b'x'));
+    assert!(!strtod::is_c_space(b'\0'));
+    assert!(!strtod::is_c_space(0xA0));
+}
+
+#[test]
+fn strtod_bytes_test() {
+    assert_eq!(strtod::strtod_bytes::<f64>(b"-2.5x"), (-2.5, 4, StrtodStatus::Success));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"1e+"), (1.0, 1, StrtodStatus::Success));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"x"), (0.0, 0, StrtodStatus::NoConversion));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"e5"), (0.0, 0, StrtodStatus::NoConversion));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"1e999"), (f64::INFINITY, 5, StrtodStatus::OutOfRange));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"inf"), (f64::INFINITY, 3, StrtodStatus::Success));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"0e-999"), (0.0, 6, StrtodStatus::Success));
+    assert_eq!(strtod::strtod_bytes::<f32>(b"1e-50"), (0.0, 5, StrtodStatus::OutOfRange));
+    assert_eq!(strtod::strtod_bytes::<f64>(b"1e-50"), (1e-50, 5, StrtodStatus::Success));
+
+    let (value, count, status) = strtod::strtod_bytes::<f64>(b"nan(0x1f)");
+    assert!(value.is_nan());
+    assert_eq!((count, status), (9, StrtodStatus::Success));
+    let (_, count, _) = strtod::strtod_bytes::<f64>(b"nan(x");
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn strtod_test() {
+    let string = b" \t1.5e3 apples\0";
+    let ptr = string.as_ptr() as *const _;
+    let (value, end, status) = unsafe { strtod::strtod::<f64>(ptr) };
+    assert_eq!((value, status), (1500.0, StrtodStatus::Success));
+    assert_eq!(end as usize - ptr as usize, 7);
+
+    let string = b"  x\0";
+    let ptr = string.as_ptr() as *const _;
+    assert_eq!(unsafe { strtod::strtod::<f32>(ptr) }, (0.0, ptr, StrtodStatus::NoConversion));
+    assert_eq!(
+        unsafe { strtod::strtod::<f64>(ptr::null()) },
+        (0.0, ptr::null(), StrtodStatus::NoConversion)
+    );
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "system-libc")]
+
+// Differential tests against the `strtod` and `strtof` of the system libc.
+// Only finite decimal strings are compared, since the spelling of special
+// values and hexadecimal floats differ between the parsers. Each mismatch
+// is reported with the string that reproduces it.
+
+use lexical_parse_float::vectors;
+use lexical_parse_float::FromLexical;
+use lexical_util::num::{AsPrimitive, Float};
+use proptest::prelude::*;
+use std::ffi::CString;
+use std::fmt::Write;
+use std::os::raw::c_char;
+use std::ptr;
+
+extern "C" {
+    fn strtod(string: *const c_char, end: *mut *mut c_char) -> f64;
+    fn strtof(string: *const c_char, end: *mut *mut c_char) -> f32;
+}
+
+/// Maximum number of mismatches to list in the report.
+const MAX_REPORTED: usize = 32;
+
+trait SystemFloat: Float + FromLexical {
+    /// Parse the entire string with the system libc.
+    fn parse_system(string: &str) -> Self;
+}
+
+macro_rules! system_float_impl {
+    ($($t:ident $strtod:ident ; )*) => ($(
+        impl SystemFloat for $t {
+            fn parse_system(string: &str) -> Self {
+                let cstring = CString::new(string).unwrap();
+                let mut end: *mut c_char = ptr::null_mut();
+                // SAFETY: safe, since the string is null-terminated, and the
+                // end pointer is valid for writes.
+                let value = unsafe { $strtod(cstring.as_ptr(), &mut end) };
+                let count = end as usize - cstring.as_ptr() as usize;
+                assert_eq!(count, string.len(), "strtod did not parse all of {}", string);
+                value
+            }
+        }
+    )*);
+}
+
+system_float_impl! {
+    f32 strtof ;
+    f64 strtod ;
+}
+
+/// Get the mismatch between lexical and the system libc, if any.
+fn mismatch<F: SystemFloat>(string: &str) -> Option<String> {
+    let width = F::BITS / 4;
+    let expected = F::parse_system(string);
+    let actual = F::from_lexical(string.as_bytes());
+    match actual {
+        Ok(actual) if actual.to_bits() == expected.to_bits() => None,
+        Ok(actual) => Some(format!(
+            "{}: lexical {:0width$X}, system {:0width$X}",
+            string,
+            actual.to_bits().as_u64(),
+            expected.to_bits().as_u64(),
+            width = width,
+        )),
+        Err(error) => Some(format!(
+            "{}: lexical {:?}, system {:0width$X}",
+            string,
+            error,
+            expected.to_bits().as_u64(),
+            width = width,
+        )),
+    }
+}
+
+/// Compare every string, and panic with a report of the mismatches.
+fn differential<F: SystemFloat>(strings: impl Iterator<Item = String>) {
+    let mut count = 0;
+    let mut mismatches = Vec::new();
+    for string in strings {
+        count += 1;
+        mismatches.extend(mismatch::<F>(&string));
+    }
+    if !mismatches.is_empty() {
+        let mut report = String::new();
+        writeln!(report, "{} of {} strings differ from the system libc:", mismatches.len(), count)
+            .unwrap();
+        for line in mismatches.iter().take(MAX_REPORTED) {
+            writeln!(report, "    {}", line).unwrap();
+        }
+        panic!("{}", report);
+    }
+}
+
+#[test]
+fn special_test() {
+    for &string in ["0", "-0", "1e999", "-1e999", "1e-999", "0.000e99999"].iter() {
+        assert_eq!(mismatch::<f32>(string), None);
+        assert_eq!(mismatch::<f64>(string), None);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn f32_stratified_test() {
+    differential::<f32>(vectors::stratified::<f32>(0x5EED, 4096).map(|x| x.string));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn f64_stratified_test() {
+    differential::<f64>(vectors::stratified::<f64>(0x5EED, 1024).map(|x| x.string));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn f64_halfway_test() {
+    // The halfway points of the boundaries of the binades.
+    let floats = (0..2046u64).map(|exp| f64::from_bits(exp << 52));
+    let strings = floats.flat_map(|x| vectors::halfway(x).to_vec()).map(|x| x.string);
+    differential::<f64>(strings);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_decimal_proptest(i in r"[+-]?[0-9]{1,40}(\.[0-9]{0,40})?([eE][+-]?[0-9]{1,3})?") {
+        prop_assert_eq!(mismatch::<f32>(&i), None);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_decimal_proptest(i in r"[+-]?[0-9]{1,40}(\.[0-9]{0,40})?([eE][+-]?[0-9]{1,3})?") {
+        prop_assert_eq!(mismatch::<f64>(&i), None);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_fraction_proptest(i in r"[+-]?\.[0-9]{1,800}([eE][+-]?[0-9]{1,3})?") {
+        prop_assert_eq!(mismatch::<f64>(&i), None);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_bits_proptest(bits in 0u64..0x7FF0_0000_0000_0000) {
+        let value = f64::from_bits(bits);
+        prop_assert_eq!(mismatch::<f64>(&vectors::exact(value).string), None);
+        for vector in vectors::halfway(value).iter() {
+            prop_assert_eq!(mismatch::<f64>(&vector.string), None);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::tune::recommend_options;
+
+#[test]
+fn decimal_point_test() {
+    let options = Options::new();
+    let corpus: [&[u8]; 4] = [b"1.5", b"-2.25", b"3", b"1e5"];
+    let recommendation = recommend_options::<f64, { STANDARD }>(&corpus, &options, 0);
+    assert_eq!(recommendation.options.decimal_point(), b'.');
+    assert_eq!(recommendation.exact.parsed, 4);
+    assert_eq!(recommendation.exact.errors, 0);
+
+    let corpus: [&[u8]; 4] = [b"1,5", b"-2,25", b"3", b"invalid"];
+    let recommendation = recommend_options::<f64, { STANDARD }>(&corpus, &options, 0);
+    assert_eq!(recommendation.options.decimal_point(), b',');
+    assert_eq!(recommendation.exact.parsed, 3);
+    assert_eq!(recommendation.exact.errors, 1);
+
+    // Ties prefer the existing decimal point.
+    let corpus: [&[u8]; 2] = [b"1", b"2"];
+    let recommendation = recommend_options::<f32, { STANDARD }>(&corpus, &options, 0);
+    assert_eq!(recommendation.options.decimal_point(), b'.');
+}
+
+#[test]
+fn lossy_test() {
+    // Short decimals are always correct with the lossy algorithms.
+    let options = Options::new();
+    let corpus: [&[u8]; 3] = [b"1.5", b"0.25", b"1e10"];
+    let recommendation = recommend_options::<f64, { STANDARD }>(&corpus, &options, 0);
+    assert_eq!(recommendation.lossy_mismatches, 0);
+    assert_eq!(recommendation.lossy_max_ulps, 0);
+    assert_eq!(recommendation.lossy.parsed, 3);
+
+    // Halfway cases need arbitrary-precision arithmetic, so the lossy
+    // algorithms are never chosen unless their error is allowed.
+    let corpus: [&[u8]; 3] = [
+        b"9007199254740993",
+        b"9007199254740992.9999999999999999999999999999999999999999999999999999999999999999",
+        b"9007199254740993.0000000000000000000000000000001",
+    ];
+    // The float160 algorithm keeps a sticky bit for the truncated digits,
+    // so only the Eisel-Lemire algorithm rounds the last sample down.
+    let recommendation = recommend_options::<f64, { STANDARD }>(&corpus, &options, 0);
+    if cfg!(all(feature = "float160", not(feature = "compact"))) {
+        assert_eq!(recommendation.lossy_mismatches, 0);
+    } else {
+        assert_eq!(recommendation.lossy_mismatches, 1);
+        assert_eq!(recommendation.lossy_max_ulps, 1);
+        assert!(!recommendation.options.lossy());
+    }
+}
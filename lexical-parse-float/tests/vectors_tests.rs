@@ -0,0 +1,135 @@
+#![cfg(feature = "test-vectors")]
+
+use lexical_parse_float::vectors::{self, TestVector};
+use lexical_parse_float::FromLexical;
+use lexical_util::num::{AsPrimitive, Float};
+use std::fmt::Write;
+
+fn check<F: Float + FromLexical>(vector: &TestVector<F>) {
+    let result = F::from_lexical(vector.string.as_bytes()).unwrap();
+    assert_eq!(result.to_bits(), vector.expected.to_bits(), "{}", vector.string);
+}
+
+/// Write the vectors as lines of the bits of the expected float and the string.
+fn golden<F: Float>(vectors: impl Iterator<Item = TestVector<F>>) -> String {
+    let width = F::BITS / 4;
+    let mut golden = String::new();
+    for vector in vectors {
+        let bits = vector.expected.to_bits().as_u64();
+        writeln!(golden, "{:0width$X} {}", bits, vector.string, width = width).unwrap();
+    }
+    golden
+}
+
+fn golden_f32() -> String {
+    golden(vectors::stratified::<f32>(1, 256))
+}
+
+fn golden_f64() -> String {
+    golden(vectors::stratified::<f64>(1, 64))
+}
+
+#[test]
+fn exact_test() {
+    assert_eq!(vectors::exact(1.0f64).string, "1e0");
+    assert_eq!(vectors::exact(0.0f64).string, "0e0");
+    assert_eq!(vectors::exact(-0.5f32).string, "-5e-1");
+    assert_eq!(vectors::exact(1024.0f32).string, "1.024e3");
+    assert_eq!(
+        vectors::exact(0.1f64).string,
+        "1.000000000000000055511151231257827021181583404541015625e-1"
+    );
+    assert_eq!(vectors::exact(f32::from_bits(1)).string.len(), 110);
+    assert_eq!(vectors::exact(f64::from_bits(1)).string.len(), 757);
+
+    for &value in [0.0, -0.0, 1.0, 0.1, 1e300, -1e-300, f64::MAX, f64::MIN_POSITIVE].iter() {
+        let vector = vectors::exact(value);
+        assert_eq!(vector.expected.to_bits(), value.to_bits());
+        check(&vector);
+    }
+    check(&vectors::exact(f64::from_bits(1)));
+}
+
+#[test]
+fn halfway_test() {
+    let [below, halfway, above] = vectors::halfway(1.0f64);
+    assert_eq!(below.string, "1.000000000000000111022302462515654042363166809082031249e0");
+    assert_eq!(halfway.string, "1.00000000000000011102230246251565404236316680908203125e0");
+    assert_eq!(above.string, "1.000000000000000111022302462515654042363166809082031251e0");
+    assert_eq!(below.expected, 1.0);
+    assert_eq!(halfway.expected, 1.0);
+    assert_eq!(above.expected, f64::from_bits(1.0f64.to_bits() + 1));
+
+    let [below, halfway, above] = vectors::halfway(9007199254740994.0f64);
+    assert_eq!(below.string, "9.0071992547409949e15");
+    assert_eq!(halfway.string, "9.007199254740995e15");
+    assert_eq!(above.string, "9.0071992547409951e15");
+    assert_eq!(halfway.expected, 9007199254740996.0);
+
+    let [below, halfway, above] = vectors::halfway(-f32::MAX);
+    assert_eq!(below.expected, -f32::MAX);
+    assert_eq!(halfway.expected, f32::NEG_INFINITY);
+    assert_eq!(above.expected, f32::NEG_INFINITY);
+
+    let values = [0.0, 1.0, 0.1, 1e22, 1e23, -5e-324, 2.2250738585072014e-308, f64::MAX];
+    for &value in values.iter() {
+        vectors::halfway(value).iter().for_each(check);
+    }
+}
+
+#[test]
+#[should_panic]
+fn halfway_special_test() {
+    vectors::halfway(f64::INFINITY);
+}
+
+#[test]
+fn all_f32_test() {
+    assert_eq!(vectors::all_f32().next().unwrap().string, "0e0");
+    assert_eq!(vectors::all_f32().nth(1).unwrap(), vectors::exact(f32::from_bits(1)));
+    vectors::all_f32().take(10000).for_each(|vector| check(&vector));
+    vectors::halfway_f32().take(30000).for_each(|vector| check(&vector));
+}
+
+#[test]
+fn stratified_test() {
+    let vectors = vectors::stratified::<f64>(5, 100).collect::<Vec<_>>();
+    assert_eq!(vectors.len(), 400);
+    assert_eq!(vectors, vectors::stratified::<f64>(5, 100).collect::<Vec<_>>());
+    vectors.iter().for_each(check);
+    vectors::stratified::<f32>(5, 1000).for_each(|vector| check(&vector));
+}
+
+#[test]
+fn golden_test() {
+    let f32_golden = include_str!("vectors/stratified-f32.txt");
+    let f64_golden = include_str!("vectors/stratified-f64.txt");
+    assert_eq!(f32_golden, golden_f32());
+    assert_eq!(f64_golden, golden_f64());
+
+    for line in f32_golden.lines() {
+        let (bits, string) = line.split_at(8);
+        let bits = u32::from_str_radix(bits, 16).unwrap();
+        check(&TestVector {
+            string: string[1..].to_string(),
+            expected: f32::from_bits(bits),
+        });
+    }
+    for line in f64_golden.lines() {
+        let (bits, string) = line.split_at(16);
+        let bits = u64::from_str_radix(bits, 16).unwrap();
+        check(&TestVector {
+            string: string[1..].to_string(),
+            expected: f64::from_bits(bits),
+        });
+    }
+}
+
+/// Regenerate the golden files, after changing the generators.
+#[test]
+#[ignore]
+fn write_golden_test() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors");
+    std::fs::write(format!("{}/stratified-f32.txt", dir), golden_f32()).unwrap();
+    std::fs::write(format!("{}/stratified-f64.txt", dir), golden_f64()).unwrap();
+}
@@ -0,0 +1,62 @@
+use lexical_parse_float::bigint::{Bigint, LIMB_BITS};
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::parse::ParseFloat;
+use lexical_parse_float::workspace::{Workspace, WORKSPACE_LIMBS, WORKSPACE_SIZE};
+use proptest::prelude::*;
+
+#[test]
+fn workspace_size_test() {
+    // The largest decimal mantissa for an `f64` is `log2(10**1091)` bits.
+    let capacity = Bigint::new().data.capacity();
+    assert_eq!(capacity, WORKSPACE_LIMBS);
+    assert!(capacity * LIMB_BITS >= 3600);
+    assert!(WORKSPACE_SIZE >= 2 * core::mem::size_of::<Bigint>());
+}
+
+#[test]
+fn parse_complete_in_test() {
+    let options = Options::new();
+    let mut workspace = Workspace::new();
+    let cases: [(&[u8], f64); 6] = [
+        (b"9007199254740993", 9007199254740992.0),
+        (b"9007199254740993.0000000000000000000000000000001", 9007199254740994.0),
+        (b"2.4703282292062327e-324", 0.0),
+        (b"2.4703282292062328e-324", 5e-324),
+        (b"-1.7976931348623158079e308", -1.7976931348623157e308),
+        (b"1.7976931348623158080e308", f64::INFINITY),
+    ];
+    // Reuse the same workspace for every number.
+    for &(bytes, expected) in cases.iter() {
+        let result = f64::parse_complete_in::<STANDARD>(bytes, &options, &mut workspace);
+        assert_eq!(result, Ok(expected));
+    }
+
+    let result = f32::parse_complete_in::<STANDARD>(
+        b"1.00000017881393432617187499",
+        &options,
+        &mut workspace,
+    );
+    assert_eq!(result, Ok(1.0000001));
+}
+
+#[test]
+fn parse_partial_in_test() {
+    let options = Options::new();
+    let mut workspace = Workspace::new();
+    let bytes = b"9007199254740993.0000000000000000000000000000001,";
+    let result = f64::parse_partial_in::<STANDARD>(bytes, &options, &mut workspace);
+    assert_eq!(result, Ok((9007199254740994.0, bytes.len() - 1)));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_complete_in_proptest(i in r"[+-]?[0-9]{1,40}\.[0-9]{0,40}(e[+-]?[0-9]{1,3})?") {
+        let options = Options::new();
+        let mut workspace = Workspace::new();
+        let expected = f64::parse_complete::<STANDARD>(i.as_bytes(), &options);
+        let result = f64::parse_complete_in::<STANDARD>(i.as_bytes(), &options, &mut workspace);
+        prop_assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,58 @@
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::x87::parse_x87;
+use lexical_util::error::Error;
+
+fn check(string: &str, sign_exponent: u16, mant: u64) {
+    let result = parse_x87::<{ STANDARD }>(string.as_bytes(), &Options::new());
+    assert_eq!(result, Ok((sign_exponent, mant)), "{}", string);
+}
+
+#[test]
+fn parse_x87_test() {
+    check("1", 0x3FFF, 0x8000000000000000);
+    check("-1", 0xBFFF, 0x8000000000000000);
+    check("0.1", 0x3FFB, 0xCCCCCCCCCCCCCCCD);
+    check("3.14159265358979323846264338327950288", 0x4000, 0xC90FDAA22168C235);
+    check("0.99999999999999999995", 0x3FFE, 0xFFFFFFFFFFFFFFFF);
+    check("18446744073709551615", 0x403E, 0xFFFFFFFFFFFFFFFF);
+    check("1e4000", 0x73E6, 0xD1BA8323FE558C61);
+    check("1e-4000", 0x0C17, 0x9C3D73864F3805C0);
+}
+
+#[test]
+fn parse_tie_test() {
+    // Halfway between `2^64 + 2k` and `2^64 + 2k + 2`, rounds to even.
+    check("18446744073709551617", 0x403F, 0x8000000000000000);
+    check("18446744073709551619", 0x403F, 0x8000000000000002);
+    check("18446744073709551617.000000000000000000000000000000001", 0x403F, 0x8000000000000001);
+    // Halfway between `1` and `1 + 2^-63`.
+    let halfway = "1.0000000000000000000542101086242752217003726400434970855712890625";
+    check(halfway, 0x3FFF, 0x8000000000000000);
+    check(&format!("{}{}1", halfway, "0".repeat(12000)), 0x3FFF, 0x8000000000000001);
+}
+
+#[test]
+fn parse_limits_test() {
+    check("1.189731495357231765e4932", 0x7FFE, 0xFFFFFFFFFFFFFFFF);
+    check("1.18973149535723176508e4932", 0x7FFF, 0x8000000000000000);
+    check("3.3621031431120935063e-4932", 0x0001, 0x8000000000000000);
+    check("3.362103143112093506e-4932", 0x0000, 0x7FFFFFFFFFFFFFFF);
+    check("4e-4951", 0x0000, 0x0000000000000001);
+    check("1.9e-4951", 0x0000, 0x0000000000000001);
+    check("1.8e-4951", 0x0000, 0x0000000000000000);
+    check("-1e-6000", 0x8000, 0x0000000000000000);
+    check("1e5000", 0x7FFF, 0x8000000000000000);
+}
+
+#[test]
+fn parse_special_test() {
+    check("0", 0x0000, 0x0000000000000000);
+    check("-0.0", 0x8000, 0x0000000000000000);
+    check("NaN", 0x7FFF, 0xC000000000000000);
+    check("inf", 0x7FFF, 0x8000000000000000);
+    check("-inf", 0xFFFF, 0x8000000000000000);
+
+    let result = parse_x87::<{ STANDARD }>(b"1e", &Options::new());
+    assert_eq!(result, Err(Error::EmptyExponent(2)));
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "zeroize")]
+
+use core::slice;
+use lexical_parse_float::bigint::Bigint;
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::options::Options;
+use lexical_parse_float::parse::ParseFloat;
+use lexical_parse_float::workspace::Workspace;
+use lexical_parse_float::zeroizing::{parse_partial_zeroizing, parse_zeroizing};
+use lexical_parse_float::Error;
+use proptest::prelude::*;
+use zeroize_crate::Zeroize;
+
+fn is_zeroed(bigint: &Bigint) -> bool {
+    // SAFETY: safe, since zeroizing initializes the entire buffer.
+    let limbs = unsafe { slice::from_raw_parts(bigint.data.as_ptr(), bigint.data.capacity()) };
+    bigint.data.is_empty() && limbs.iter().all(|&limb| limb == 0)
+}
+
+#[test]
+fn zeroize_workspace_test() {
+    let options = Options::new();
+    let mut workspace = Workspace::new();
+    let bytes = b"9007199254740993.0000000000000000000000000000001";
+    let result = f64::parse_complete_in::<STANDARD>(bytes, &options, &mut workspace);
+    assert_eq!(result, Ok(9007199254740994.0));
+    assert!(!workspace.real_digits.data.is_empty());
+
+    workspace.zeroize();
+    assert!(is_zeroed(&workspace.real_digits));
+    assert!(is_zeroed(&workspace.theor_digits));
+
+    // The workspace is still usable after zeroizing.
+    let result = f64::parse_complete_in::<STANDARD>(bytes, &options, &mut workspace);
+    assert_eq!(result, Ok(9007199254740994.0));
+}
+
+#[test]
+fn zeroize_bigint_test() {
+    let mut bigint = Bigint::from_u64(u64::MAX);
+    bigint.pow(10, 300).unwrap();
+    bigint.zeroize();
+    assert!(is_zeroed(&bigint));
+}
+
+#[test]
+fn parse_zeroizing_test() {
+    let options = Options::new();
+    let parse = |bytes| parse_zeroizing::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"9007199254740993.0000000000000000000000000000001"), Ok(9007199254740994.0));
+    assert_eq!(parse(b"-1.7976931348623158079e308"), Ok(-1.7976931348623157e308));
+    assert_eq!(parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+
+    let result = parse_zeroizing::<f32, STANDARD>(b"1.00000017881393432617187499", &options);
+    assert_eq!(result, Ok(1.0000001));
+}
+
+#[test]
+fn parse_partial_zeroizing_test() {
+    let options = Options::new();
+    let parse = |bytes| parse_partial_zeroizing::<f64, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1.5,2"), Ok((1.5, 3)));
+    assert_eq!(parse(b"2.4703282292062328e-324 "), Ok((5e-324, 23)));
+    assert_eq!(parse(b"x"), Err(Error::EmptyMantissa(0)));
+}
+
+proptest! {
+    #[test]
+    fn parse_zeroizing_proptest(s in r"[+-]?[0-9]{1,40}(\.[0-9]{1,40})?([eE][+-]?[0-9]{1,3})?") {
+        let options = Options::new();
+        let expected = f64::parse_complete::<STANDARD>(s.as_bytes(), &options);
+        prop_assert_eq!(parse_zeroizing::<f64, STANDARD>(s.as_bytes(), &options), expected);
+    }
+}
@@ -0,0 +1,159 @@
+//! Accumulate digits into an integer, with explicit overflow.
+//!
+//! Protocol parsers, such as for the numeric fields of ISO 8583 messages,
+//! check digit sums, or fields split across buffers, need to validate
+//! and accumulate digits like an integer parser, but handle overflow
+//! and invalid digits with their own semantics. The accumulator stops
+//! before any digit that would overflow, without consuming it, so the
+//! caller can reduce or store the value and resume from that digit.
+
+#![doc(hidden)]
+
+use lexical_util::digit::char_to_digit;
+use lexical_util::num::{as_cast, UnsignedInteger};
+use lexical_util::radix::Radix;
+
+/// Why an accumulator stopped consuming digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Accumulation {
+    /// All the bytes were valid digits, and were accumulated.
+    Complete,
+    /// The byte is not a valid digit in the radix.
+    InvalidDigit,
+    /// Accumulating the digit would overflow the integer.
+    Overflow,
+}
+
+/// Accumulate digits into an unsigned integer, with explicit overflow.
+///
+/// Each digit multiplies the value by the radix and adds the digit,
+/// like an integer parser. A digit that would overflow is rejected
+/// without changing the value, so the caller can apply custom semantics,
+/// such as reducing the value modulo a checksum, with [`set_value`],
+/// and resume accumulating from the same digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::accumulator::{Accumulation, DigitAccumulator};
+/// use lexical_util::radix::Radix;
+///
+/// # pub fn main() {
+/// let mut acc = DigitAccumulator::<u8>::new(Radix::DECIMAL);
+/// assert_eq!(acc.extend(b"25"), (2, Accumulation::Complete));
+/// assert_eq!(acc.extend(b"6"), (0, Accumulation::Overflow));
+/// assert_eq!(acc.value(), 25);
+///
+/// // Reduce modulo 7, and resume from the rejected digit.
+/// acc.set_value(acc.value() % 7);
+/// assert_eq!(acc.extend(b"6,"), (1, Accumulation::InvalidDigit));
+/// assert_eq!(acc.value(), 46);
+/// assert_eq!(acc.count(), 3);
+/// # }
+/// ```
+///
+/// [`set_value`]: Self::set_value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitAccumulator<T: UnsignedInteger> {
+    /// Accumulated value.
+    value: T,
+    /// Radix of the digits.
+    radix: Radix,
+    /// Number of digits accumulated.
+    count: usize,
+}
+
+impl<T: UnsignedInteger> DigitAccumulator<T> {
+    /// Create an accumulator for digits in a radix, starting from zero.
+    #[inline(always)]
+    pub fn new(radix: Radix) -> Self {
+        Self::with_value(T::ZERO, radix)
+    }
+
+    /// Create an accumulator for digits in a radix, resuming from a value.
+    #[inline(always)]
+    pub fn with_value(value: T, radix: Radix) -> Self {
+        Self {
+            value,
+            radix,
+            count: 0,
+        }
+    }
+
+    /// Get the accumulated value.
+    #[inline(always)]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Replace the accumulated value, such as after an overflow.
+    ///
+    /// The number of accumulated digits is unchanged.
+    #[inline(always)]
+    pub fn set_value(&mut self, value: T) {
+        self.value = value;
+    }
+
+    /// Get the radix of the digits.
+    #[inline(always)]
+    pub fn radix(&self) -> Radix {
+        self.radix
+    }
+
+    /// Get the number of digits accumulated.
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Accumulate the value of a digit, which must be below the radix.
+    ///
+    /// Returns [`Accumulation::Overflow`], without changing the value,
+    /// if the digit would overflow the integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit is not below the radix.
+    #[inline]
+    pub fn push_digit(&mut self, digit: u32) -> Accumulation {
+        assert!(digit < self.radix.get());
+        let radix: T = as_cast(self.radix.get());
+        let value = self.value.checked_mul(radix).and_then(|x| x.checked_add(as_cast(digit)));
+        match value {
+            Some(value) => {
+                self.value = value;
+                self.count += 1;
+                Accumulation::Complete
+            },
+            None => Accumulation::Overflow,
+        }
+    }
+
+    /// Accumulate a digit character.
+    ///
+    /// Returns [`Accumulation::InvalidDigit`] if the character is not a
+    /// digit in the radix, or [`Accumulation::Overflow`] if the digit
+    /// would overflow the integer, without changing the value.
+    #[inline]
+    pub fn push(&mut self, c: u8) -> Accumulation {
+        match char_to_digit(c, self.radix.get()) {
+            Some(digit) => self.push_digit(digit),
+            None => Accumulation::InvalidDigit,
+        }
+    }
+
+    /// Accumulate digit characters, until an invalid digit or overflow.
+    ///
+    /// Returns the number of bytes accumulated, and why the accumulator
+    /// stopped. The byte at the returned index, if any, was not consumed.
+    #[inline]
+    pub fn extend(&mut self, bytes: &[u8]) -> (usize, Accumulation) {
+        for (index, &c) in bytes.iter().enumerate() {
+            match self.push(c) {
+                Accumulation::Complete => (),
+                stopped => return (index, stopped),
+            }
+        }
+        (bytes.len(), Accumulation::Complete)
+    }
+}
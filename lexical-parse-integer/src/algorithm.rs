@@ -12,7 +12,8 @@
 #![doc(hidden)]
 
 use crate::shared::is_overflow;
-use lexical_util::digit::char_to_digit_const;
+use lexical_util::digit::{self, char_to_digit_const};
+use lexical_util::endian;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, BytesIter};
 use lexical_util::num::{as_cast, Integer, UnsignedInteger};
@@ -38,18 +39,15 @@ macro_rules! parse_8digits {
     (
         $value:ident,
         $iter:ident,
-        $format:ident,
-        $t:ident
+        $format:ident
     ) => {{
-        let radix: $t = as_cast(NumberFormat::<{ $format }>::MANTISSA_RADIX);
-        let radix2: $t = radix.wrapping_mul(radix);
-        let radix4: $t = radix2.wrapping_mul(radix2);
-        let radix8: $t = radix4.wrapping_mul(radix4);
+        // This can't overflow, since the radix is `<= 10`.
+        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        let radix8 = radix.pow(8);
 
         // Try our fast, 8-digit at a time optimizations.
-        while let Some(val8) = try_parse_8digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix8);
-            $value = $value.wrapping_add(val8);
+        while let Some(val8) = try_parse_8digits::<u32, _, $format>(&mut $iter) {
+            $value = $value.wrapping_mul_add(radix8, val8);
         }
     }};
 }
@@ -64,17 +62,14 @@ macro_rules! parse_4digits {
     (
         $value:ident,
         $iter:ident,
-        $format:ident,
-        $t:ident
+        $format:ident
     ) => {{
-        let radix: $t = as_cast(NumberFormat::<{ $format }>::MANTISSA_RADIX);
-        let radix2: $t = radix.wrapping_mul(radix);
-        let radix4: $t = radix2.wrapping_mul(radix2);
+        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        let radix4 = radix.pow(4);
 
         // Try our fast, 4-digit at a time optimizations.
-        while let Some(val4) = try_parse_4digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix4);
-            $value = $value.wrapping_add(val4);
+        while let Some(val4) = try_parse_4digits::<u32, _, $format>(&mut $iter) {
+            $value = $value.wrapping_mul_add(radix4, val4);
         }
     }};
 }
@@ -89,6 +84,7 @@ macro_rules! parse_digits {
         $format:ident,
         $is_negative:ident,
         $start_index:ident,
+        $shift:ident,
         $t:ident,
         $u:ident,
         $invalid_digit:ident
@@ -121,20 +117,20 @@ macro_rules! parse_digits {
         // Makes no sense to do 8 digits at a time for 32-bit values,
         // since it can only hold 8 digits for base 10.
         if <$t>::BITS == 128 && can_try_parse_multidigits!($iter, radix) {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $iter, $format);
         }
         if <$t>::BITS == 64 && can_try_parse_multidigits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $iter, $format);
         }
 
         // Optimizations for reading 4-digits at a time.
         // 36^4 is larger than a 16-bit integer. Likewise, 10^4 is almost
         // the limit of u16, so it's not worth it.
         if <$t>::BITS == 32 && can_try_parse_multidigits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_4digits!($value, $iter, $format, $u);
+            parse_4digits!($value, $iter, $format);
         }
 
-        parse_1digit!($value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit)
+        parse_1digit!($value, $iter, $format, $is_negative, $start_index, $shift, $t, $u, $invalid_digit)
     }};
 }
 
@@ -145,6 +141,9 @@ where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
+    if let Some(value) = try_parse_small::<T, FORMAT>(bytes) {
+        return Ok(value);
+    }
     algorithm!(bytes, FORMAT, T, Unsigned, parse_digits, invalid_digit_complete, into_ok_complete)
 }
 
@@ -155,9 +154,46 @@ where
     T: Integer,
     Unsigned: UnsignedInteger,
 {
+    if let Some(value) = try_parse_small::<T, FORMAT>(bytes) {
+        return Ok((value, bytes.len()));
+    }
     algorithm!(bytes, FORMAT, T, Unsigned, parse_digits, invalid_digit_partial, into_ok_partial)
 }
 
+/// Check if we can try to parse small inputs directly.
+///
+/// The input must not require a sign or reject or cap leading zeros, and
+/// the largest 4-digit value must fit in the type.
+#[inline(always)]
+fn can_try_parse_small<T: Integer, const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    T::BITS >= 16
+        && format.mantissa_radix() <= 10
+        && !format.required_mantissa_sign()
+        && !format.no_integer_leading_zeros()
+        && format.max_leading_zeros() == 0
+}
+
+/// Fast path for inputs of 1-4 digits, common in IDs, ports and status codes.
+///
+/// This pads the input with leading zeros to 4 bytes, and then validates
+/// and parses it with the 4-digit optimizations, avoiding the branching
+/// from the sign, base prefix and overflow checks. Returns None if the
+/// input is not 1-4 digits, so the full algorithm must be used.
+#[inline(always)]
+pub fn try_parse_small<T: Integer, const FORMAT: u128>(bytes: &[u8]) -> Option<T> {
+    if !can_try_parse_small::<T, FORMAT>() || bytes.is_empty() {
+        return None;
+    }
+
+    let v = endian::read_u32_le_padded(bytes, b'0')?;
+    if is_4digits::<FORMAT>(v) {
+        Some(T::as_cast(parse_4digits::<FORMAT>(v)))
+    } else {
+        None
+    }
+}
+
 // DIGIT OPTIMIZATIONS
 
 /// Determine if 4 bytes, read raw from bytes, are 4 digits for the radix.
@@ -232,18 +268,7 @@ where
 #[inline]
 pub fn is_8digits<const FORMAT: u128>(v: u64) -> bool {
     let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
-    debug_assert!(radix <= 10);
-
-    let add = 0x46 + 10 - radix;
-    let add = add + (add << 8) + (add << 16) + (add << 24);
-    let add = (add as u64) | ((add as u64) << 32);
-    // This aims to underflow if anything is below the min digit: if we have any
-    // values under `0x30`, then this underflows and wraps into the high bit.
-    let sub = 0x3030_3030_3030_3030;
-    let a = v.wrapping_add(add);
-    let b = v.wrapping_sub(sub);
-
-    (a | b) & 0x8080_8080_8080_8080 == 0
+    digit::is_8digits(v, radix)
 }
 
 /// Parse 8 bytes read from bytes into 8 digits.
@@ -0,0 +1,150 @@
+//! Parse integers from decimal floats with integral values.
+//!
+//! Floats such as `1e3` or `1000.0` are often used to store integers,
+//! for example, in JSON or in scientific data. Converting these through
+//! an `f64` loses precision above `2^53`, so these are parsed directly
+//! to an integer, and the value must be exactly representable.
+
+#![doc(hidden)]
+
+use lexical_util::error::Error;
+use lexical_util::num::{as_cast, Integer};
+use lexical_util::result::Result;
+
+/// Get the number of consecutive decimal digits starting at `index`.
+#[inline]
+fn count_digits(bytes: &[u8], index: usize) -> usize {
+    bytes.get(index..).map_or(0, |x| x.iter().take_while(|c| c.is_ascii_digit()).count())
+}
+
+/// Parse an integer from a decimal float with an integral value.
+///
+/// This accepts an optional sign, integer and fraction digits
+/// separated by `.`, and an optional exponent starting with `e` or
+/// `E`. The value must be an integer in the range of `T`, so `1.5e1`
+/// is valid, while `1.5` is an invalid digit at the `5`.
+///
+/// # Examples
+///
+/// ```
+/// use lexical_parse_integer::{parse_integer_valued_float, Error};
+///
+/// assert_eq!(parse_integer_valued_float::<i64>(b"1e3"), Ok(1000));
+/// assert_eq!(parse_integer_valued_float::<i64>(b"-1000.0"), Ok(-1000));
+/// assert_eq!(parse_integer_valued_float::<i64>(b"9.007199254740993e15"), Ok(9007199254740993));
+/// assert_eq!(parse_integer_valued_float::<i64>(b"1.5"), Err(Error::InvalidDigit(2)));
+/// assert_eq!(parse_integer_valued_float::<i64>(b"1e19"), Err(Error::Overflow(1)));
+/// ```
+pub fn parse_integer_valued_float<T: Integer>(bytes: &[u8]) -> Result<T> {
+    // Parse the sign.
+    let (is_negative, mut index) = match bytes.first() {
+        Some(&b'+') => (false, 1),
+        Some(&b'-') => (true, 1),
+        _ => (false, 0),
+    };
+    if index == bytes.len() {
+        return Err(Error::Empty(index));
+    }
+    if is_negative && !T::IS_SIGNED {
+        return Err(Error::InvalidDigit(0));
+    }
+
+    // Find the integer and fraction digits.
+    let integer_start = index;
+    let integer_count = count_digits(bytes, integer_start);
+    index += integer_count;
+    let mut fraction_start = index;
+    let mut fraction_count = 0;
+    if bytes.get(index) == Some(&b'.') {
+        fraction_start = index + 1;
+        fraction_count = count_digits(bytes, fraction_start);
+        index = fraction_start + fraction_count;
+    }
+    if integer_count == 0 && fraction_count == 0 {
+        return Err(Error::EmptyMantissa(index));
+    }
+
+    // Parse the exponent, saturating on overflow.
+    let exponent_index = index;
+    let mut exponent = 0i64;
+    if let Some(&b'e') | Some(&b'E') = bytes.get(index) {
+        index += 1;
+        let is_negative_exponent = match bytes.get(index) {
+            Some(&b'+') => {
+                index += 1;
+                false
+            },
+            Some(&b'-') => {
+                index += 1;
+                true
+            },
+            _ => false,
+        };
+        let exponent_count = count_digits(bytes, index);
+        if exponent_count == 0 {
+            return Err(Error::EmptyExponent(index));
+        }
+        for &c in bytes.iter().skip(index).take(exponent_count) {
+            exponent = exponent.saturating_mul(10).saturating_add((c - b'0') as i64);
+        }
+        if is_negative_exponent {
+            exponent = -exponent;
+        }
+        index += exponent_count;
+    }
+    if index != bytes.len() {
+        return Err(Error::InvalidDigit(index));
+    }
+
+    // Iterate over the significant digits with their indexes, and
+    // determine how many trailing digits are below the decimal point.
+    let integer = bytes.iter().enumerate().skip(integer_start).take(integer_count);
+    let fraction = bytes.iter().enumerate().skip(fraction_start).take(fraction_count);
+    let digits = integer.chain(fraction);
+    let digit_count = integer_count + fraction_count;
+    let shift = exponent.saturating_sub(fraction_count as i64);
+    let truncated = if shift < 0 {
+        digit_count.min(shift.unsigned_abs() as usize)
+    } else {
+        0
+    };
+
+    // Any truncated digits must be 0, otherwise, the value has a fraction.
+    if let Some((index, _)) = digits.clone().skip(digit_count - truncated).find(|x| *x.1 != b'0') {
+        return Err(Error::InvalidDigit(index));
+    }
+
+    // Accumulate the digits, as a negative value for signed integers to
+    // handle the minimum value.
+    let radix: T = as_cast(10u32);
+    let overflow = |index| {
+        if is_negative {
+            Error::Underflow(index)
+        } else {
+            Error::Overflow(index)
+        }
+    };
+    let mut value = T::ZERO;
+    for (index, &c) in digits.take(digit_count - truncated) {
+        let digit: T = as_cast(c - b'0');
+        value = value.checked_mul(radix).ok_or_else(|| overflow(index))?;
+        value = if is_negative {
+            value.checked_sub(digit)
+        } else {
+            value.checked_add(digit)
+        }
+        .ok_or_else(|| overflow(index))?;
+    }
+
+    // Scale by the remaining positive exponent, which must overflow
+    // within a few iterations for any non-zero value.
+    if value != T::ZERO {
+        let mut shift = shift;
+        while shift > 0 {
+            value = value.checked_mul(radix).ok_or_else(|| overflow(exponent_index))?;
+            shift -= 1;
+        }
+    }
+
+    Ok(value)
+}
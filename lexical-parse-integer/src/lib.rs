@@ -26,6 +26,8 @@
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `fuzz` - Promote debug assertions to checks, for fuzzing.
+//! * `serde` - Serialize and deserialize the options.
 //!
 //! `safe` is a no-op, since all parsers are memory-safe by default.
 //!
@@ -60,21 +62,38 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Promote debug assertions to checks when fuzzing, so internal invariants
+// are verified in optimized builds. These shadow the standard macros.
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert {
+    ($($arg:tt)*) => (assert!($($arg)*));
+}
+
 #[macro_use]
 mod shared;
 
+pub mod accumulator;
 pub mod algorithm;
 pub mod compact;
+pub mod integral;
+pub mod magnitude;
 pub mod options;
 pub mod parse;
+pub mod power;
 
 mod api;
 
 // Re-exports
+pub use self::accumulator::{Accumulation, DigitAccumulator};
 pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::integral::parse_integer_valued_float;
+pub use self::magnitude::{parse_magnitude, Sign};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+pub use self::power::parse_power_of_ten;
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
+pub use lexical_util::step::DigitCount;
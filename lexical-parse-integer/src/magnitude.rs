@@ -0,0 +1,118 @@
+//! Parse the magnitude and sign of an integer separately.
+//!
+//! Custom numeric types, such as saturating counters or big integers,
+//! need their own sign and overflow semantics, but can still reuse the
+//! optimized digit parser for the magnitude, rather than re-implementing
+//! the sign, digit separator, and base prefix handling of each format.
+
+#![doc(hidden)]
+
+// Select the correct back-end.
+#[cfg(not(feature = "compact"))]
+use crate::algorithm::algorithm_partial;
+#[cfg(feature = "compact")]
+use crate::compact::algorithm_partial;
+
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::result::Result;
+use lexical_util::wrapper::{self, shift_error};
+
+/// Sign of a parsed integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The integer has no sign, or a `+` sign.
+    Positive,
+    /// The integer has a `-` sign, or is wrapped in accounting parentheses.
+    Negative,
+}
+
+impl Sign {
+    /// Determine if the sign is negative.
+    #[inline(always)]
+    pub const fn is_negative(self) -> bool {
+        matches!(self, Self::Negative)
+    }
+}
+
+/// Parse the magnitude and sign of an integer, with a signed parser.
+#[inline]
+fn parse_signed<const FORMAT: u128>(bytes: &[u8]) -> Result<(u64, Sign, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if bytes.first() != Some(&b'-') {
+        // Unsigned parsers already handle the positive sign.
+        let (magnitude, count) = algorithm_partial::<u64, u64, FORMAT>(bytes)?;
+        return Ok((magnitude, Sign::Positive, count));
+    }
+
+    if format.required_mantissa_sign() {
+        // Unsigned parsers would require a second sign, so use the
+        // widest signed parser, which validates the sign itself.
+        let (value, count) = algorithm_partial::<i128, u128, FORMAT>(bytes)?;
+        let magnitude = value.unsigned_abs();
+        if magnitude > u64::MAX as u128 {
+            return Err(Error::Underflow(count - 1));
+        }
+        return Ok((magnitude as u64, Sign::Negative, count));
+    }
+
+    // Like a signed parser, a sign without any digits is empty.
+    if bytes.get(1) == Some(&b'+') {
+        return Err(Error::Empty(1));
+    }
+    match algorithm_partial::<u64, u64, FORMAT>(&bytes[1..]) {
+        Ok((_, 0)) => Err(Error::Empty(1)),
+        Ok((magnitude, count)) => Ok((magnitude, Sign::Negative, count + 1)),
+        Err(Error::Overflow(index)) => Err(Error::Underflow(index + 1)),
+        Err(error) => Err(shift_error(error, 1)),
+    }
+}
+
+/// Parse the magnitude and sign of an integer.
+///
+/// This parses as many digits as possible, like a partial parser for a
+/// signed integer, and returns the magnitude as a `u64`, the sign, and
+/// the number of processed bytes. Negative zero keeps its sign. If the
+/// magnitude overflows a `u64`, returns [`Error::Overflow`] for positive
+/// numbers, and [`Error::Underflow`] for negative numbers.
+///
+/// # Examples
+///
+/// ```
+/// use lexical_parse_integer::format::STANDARD;
+/// use lexical_parse_integer::{parse_magnitude, Error, Sign};
+///
+/// assert_eq!(parse_magnitude::<STANDARD>(b"42"), Ok((42, Sign::Positive, 2)));
+/// assert_eq!(parse_magnitude::<STANDARD>(b"-0,"), Ok((0, Sign::Negative, 2)));
+/// assert_eq!(
+///     parse_magnitude::<STANDARD>(b"-18446744073709551615"),
+///     Ok((u64::MAX, Sign::Negative, 21))
+/// );
+/// assert_eq!(parse_magnitude::<STANDARD>(b"18446744073709551616"), Err(Error::Overflow(19)));
+/// ```
+pub fn parse_magnitude<const FORMAT: u128>(bytes: &[u8]) -> Result<(u64, Sign, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    }
+    if !wrapper::is_wrapped::<FORMAT>() {
+        return parse_signed::<FORMAT>(bytes);
+    }
+
+    let mut sign = Sign::Positive;
+    let (magnitude, count) =
+        wrapper::parse_partial::<_, _, FORMAT>(bytes, |bytes, is_negative| {
+            if !is_negative {
+                let (magnitude, inner, count) = parse_signed::<FORMAT>(bytes)?;
+                sign = inner;
+                return Ok((magnitude, count));
+            }
+            // The unsigned parser rejects any signs within the parentheses.
+            sign = Sign::Negative;
+            match algorithm_partial::<u64, u64, FORMAT>(bytes) {
+                Err(Error::Overflow(index)) => Err(Error::Underflow(index)),
+                result => result,
+            }
+        })?;
+    Ok((magnitude, sign, count))
+}
@@ -62,6 +62,8 @@ impl Default for OptionsBuilder {
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", deny_unknown_fields))]
 pub struct Options {}
 
 impl Options {
@@ -104,6 +106,11 @@ impl ParseOptions for Options {
     fn is_valid(&self) -> bool {
         Self::is_valid(self)
     }
+
+    #[inline(always)]
+    fn validate(&self) -> Result<()> {
+        self.rebuild().build().map(|_| ())
+    }
 }
 
 // PRE-DEFINED CONSTANTS
@@ -8,15 +8,29 @@ use crate::algorithm::{algorithm_complete, algorithm_partial};
 #[cfg(feature = "compact")]
 use crate::compact::{algorithm_complete, algorithm_partial};
 
-use lexical_util::num::{Integer, UnsignedInteger};
+use lexical_util::error::Error;
+use lexical_util::num::{as_cast, Integer, UnsignedInteger};
 use lexical_util::result::Result;
+use lexical_util::sign::{self, Trailer};
+use lexical_util::wrapper;
 
 /// Parse integer trait, implemented in terms of the optimized back-end.
 pub trait ParseInteger: Integer {
     /// Forward complete parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_complete<Unsigned: UnsignedInteger, const FORMAT: u128>(bytes: &[u8]) -> Result<Self> {
-        algorithm_complete::<_, Unsigned, { FORMAT }>(bytes)
+        if wrapper::is_wrapped::<FORMAT>() {
+            return wrapper::parse_complete::<_, _, FORMAT>(bytes, |bytes, is_negative| {
+                if is_negative {
+                    let count = bytes.len();
+                    let magnitude = algorithm_complete::<Unsigned, Unsigned, { FORMAT }>(bytes);
+                    negate::<Self, Unsigned>(magnitude.map(|x| (x, count))).map(|(x, _)| x)
+                } else {
+                    complete::<_, Unsigned, { FORMAT }>(bytes)
+                }
+            });
+        }
+        complete::<_, Unsigned, { FORMAT }>(bytes)
     }
 
     /// Forward partial parser parameters to the backend.
@@ -24,7 +38,151 @@ pub trait ParseInteger: Integer {
     fn parse_partial<Unsigned: UnsignedInteger, const FORMAT: u128>(
         bytes: &[u8],
     ) -> Result<(Self, usize)> {
-        algorithm_partial::<_, Unsigned, { FORMAT }>(bytes)
+        if wrapper::is_wrapped::<FORMAT>() {
+            return wrapper::parse_partial::<_, _, FORMAT>(bytes, |bytes, is_negative| {
+                if is_negative {
+                    negate::<Self, Unsigned>(algorithm_partial::<Unsigned, Unsigned, { FORMAT }>(
+                        bytes,
+                    ))
+                } else {
+                    partial::<_, Unsigned, { FORMAT }>(bytes)
+                }
+            });
+        }
+        partial::<_, Unsigned, { FORMAT }>(bytes)
+    }
+}
+
+/// Parse an integer that may have a trailing sign, using a complete parser.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn complete<T: Integer, Unsigned: UnsignedInteger, const FORMAT: u128>(bytes: &[u8]) -> Result<T> {
+    if !sign::is_trailing::<FORMAT>() {
+        return algorithm_complete::<_, Unsigned, { FORMAT }>(bytes);
+    }
+    let trailer = match Trailer::parse::<FORMAT>(bytes.last()) {
+        Some(trailer) => trailer,
+        None => return algorithm_complete::<_, Unsigned, { FORMAT }>(bytes),
+    };
+    let index = bytes.len() - 1;
+    let digits = &bytes[..index];
+    if sign::has_leading_sign(digits) {
+        return Err(Error::InvalidDigit(index));
+    }
+    match trailer {
+        Trailer::Sign(false) => algorithm_complete::<_, Unsigned, { FORMAT }>(digits),
+        Trailer::Sign(true) if !T::IS_SIGNED => Err(Error::InvalidNegativeSign(index)),
+        Trailer::Sign(true) => {
+            let magnitude = algorithm_complete::<Unsigned, Unsigned, { FORMAT }>(digits);
+            negate::<T, Unsigned>(magnitude.map(|x| (x, bytes.len()))).map(|(x, _)| x)
+        },
+        Trailer::Overpunch(digit, is_negative) => {
+            // The number may only be the overpunched digit.
+            let magnitude = if digits.is_empty() {
+                Ok(Unsigned::ZERO)
+            } else {
+                algorithm_complete::<Unsigned, Unsigned, { FORMAT }>(digits)
+            };
+            overpunch::<T, Unsigned>(magnitude, digit, is_negative, index)
+        },
+    }
+}
+
+/// Parse an integer that may have a trailing sign, using a partial parser.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn partial<T: Integer, Unsigned: UnsignedInteger, const FORMAT: u128>(
+    bytes: &[u8],
+) -> Result<(T, usize)> {
+    if !sign::is_trailing::<FORMAT>() {
+        return algorithm_partial::<_, Unsigned, { FORMAT }>(bytes);
+    }
+    // The number may only be the overpunched digit.
+    if let Some(Trailer::Overpunch(digit, is_negative)) = Trailer::parse::<FORMAT>(bytes.first()) {
+        let value = overpunch::<T, Unsigned>(Ok(Unsigned::ZERO), digit, is_negative, 0)?;
+        return Ok((value, 1));
+    }
+
+    let has_sign = sign::has_leading_sign(bytes);
+    let (value, count) = match algorithm_partial::<T, Unsigned, { FORMAT }>(bytes) {
+        Ok(result) => result,
+        Err(Error::Overflow(index)) if T::IS_SIGNED && !has_sign => {
+            // The magnitude of the minimum value overflows, so check if
+            // it's followed by a negative sign.
+            let magnitude = algorithm_partial::<Unsigned, Unsigned, { FORMAT }>(bytes);
+            return match magnitude {
+                Ok((magnitude, count)) => match Trailer::parse::<FORMAT>(bytes.get(count)) {
+                    Some(Trailer::Sign(true)) => negate::<T, Unsigned>(Ok((magnitude, count + 1))),
+                    _ => Err(Error::Overflow(index)),
+                },
+                Err(_) => Err(Error::Overflow(index)),
+            };
+        },
+        Err(error) => return Err(error),
+    };
+    match Trailer::parse::<FORMAT>(bytes.get(count)) {
+        None => Ok((value, count)),
+        Some(_) if has_sign => Err(Error::InvalidDigit(count)),
+        Some(Trailer::Sign(false)) => Ok((value, count + 1)),
+        Some(Trailer::Sign(true)) if !T::IS_SIGNED => Err(Error::InvalidNegativeSign(count)),
+        // The value is non-negative, so the negated value cannot overflow.
+        Some(Trailer::Sign(true)) => Ok((T::ZERO.wrapping_sub(value), count + 1)),
+        Some(Trailer::Overpunch(digit, is_negative)) => {
+            let magnitude = as_cast::<Unsigned, _>(value);
+            let value = overpunch::<T, Unsigned>(Ok(magnitude), digit, is_negative, count)?;
+            Ok((value, count + 1))
+        },
+    }
+}
+
+/// Append an overpunched digit to the magnitude of an integer.
+///
+/// The index is the index of the overpunched digit.
+#[inline(always)]
+fn overpunch<T: Integer, Unsigned: UnsignedInteger>(
+    magnitude: Result<Unsigned>,
+    digit: u8,
+    is_negative: bool,
+    index: usize,
+) -> Result<T> {
+    if is_negative && !T::IS_SIGNED {
+        return Err(Error::InvalidNegativeSign(index));
+    }
+    let magnitude = magnitude.and_then(|x| {
+        x.checked_mul(Unsigned::from_u32(10))
+            .and_then(|x| x.checked_add(Unsigned::from_u32(digit as u32)))
+            .ok_or(Error::Overflow(index))
+    });
+    if is_negative {
+        return negate::<T, Unsigned>(magnitude.map(|x| (x, index + 1))).map(|(x, _)| x);
+    }
+    let magnitude = magnitude?;
+    if magnitude > as_cast::<Unsigned, _>(T::MAX) {
+        Err(Error::Overflow(index))
+    } else {
+        Ok(as_cast::<T, _>(magnitude))
+    }
+}
+
+/// Negate the magnitude of an integer wrapped in parentheses.
+///
+/// The magnitude is parsed as an unsigned integer, so overflow is
+/// converted to underflow, and the minimum signed value is valid.
+#[inline(always)]
+fn negate<T: Integer, Unsigned: UnsignedInteger>(
+    result: Result<(Unsigned, usize)>,
+) -> Result<(T, usize)> {
+    if !T::IS_SIGNED {
+        return Err(Error::InvalidNegativeSign(0));
+    }
+    let (magnitude, count) = match result {
+        Ok(result) => result,
+        Err(Error::Overflow(index)) => return Err(Error::Underflow(index)),
+        Err(error) => return Err(error),
+    };
+    let max = as_cast::<Unsigned, _>(T::MIN).wrapping_neg();
+    if magnitude > max {
+        Err(Error::Underflow(count.saturating_sub(1)))
+    } else {
+        Ok((as_cast::<T, _>(magnitude).wrapping_neg(), count))
     }
 }
 
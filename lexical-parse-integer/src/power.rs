@@ -0,0 +1,35 @@
+//! Parse the exponent of a power of ten, such as for unit prefixes.
+//!
+//! Unit prefixes and scale factors are often written as an exponent-only
+//! form, such as `e-3` or `1E+6`. The exponent is parsed separately, so
+//! it can be applied to a value while rounding only once.
+
+#![doc(hidden)]
+
+use crate::api::FromLexical;
+use lexical_util::error::Error;
+use lexical_util::result::Result;
+use lexical_util::wrapper::shift_error;
+
+/// Parse the exponent of a power of ten, such as `e-3` or `1E+6`.
+///
+/// This parses an optional `1` mantissa, the `e` or `E` exponent
+/// character, and the signed, decimal exponent.
+///
+/// * `bytes`   - Byte slice containing the power of ten.
+#[inline]
+pub fn parse_power_of_ten(bytes: &[u8]) -> Result<i32> {
+    let start = match bytes.first() {
+        Some(&b'1') => 1,
+        _ => 0,
+    };
+    match bytes.get(start) {
+        Some(&b'e') | Some(&b'E') => (),
+        Some(_) => return Err(Error::InvalidDigit(start)),
+        None => return Err(Error::EmptyExponent(start)),
+    }
+    match i32::from_lexical(&bytes[start + 1..]) {
+        Err(Error::Empty(index)) => Err(Error::EmptyExponent(start + 1 + index)),
+        result => result.map_err(|error| shift_error(error, start + 1)),
+    }
+}
@@ -26,12 +26,14 @@
 
 use lexical_util::format::NumberFormat;
 use lexical_util::num::{as_cast, Integer, UnsignedInteger};
-use lexical_util::step::max_step;
+use lexical_util::step::{max_step, min_step};
 
 /// Return an error, returning the index and the error.
+///
+/// The error is created out-of-line, since errors are rare.
 macro_rules! into_error {
     ($code:ident, $index:expr) => {
-        Err((lexical_util::error::Error::$code($index)))
+        lexical_util::hint::cold_error(lexical_util::error::Error::$code($index))
     };
 }
 
@@ -57,6 +59,7 @@ macro_rules! invalid_digit_complete {
         $format:ident,
         $is_negative:ident,
         $start_index:ident,
+        $shift:ident,
         $t:ident,
         $u:ident
     ) => {{
@@ -67,6 +70,7 @@ macro_rules! invalid_digit_complete {
 
 /// Return a value for a partial parser upon an invalid digit.
 /// This checks for numeric overflow, and returns the appropriate error.
+/// A sign without any digits is empty, rather than a zero.
 macro_rules! invalid_digit_partial {
     (
         $value:ident,
@@ -74,18 +78,24 @@ macro_rules! invalid_digit_partial {
         $format:ident,
         $is_negative:ident,
         $start_index:ident,
+        $shift:ident,
         $t:ident,
         $u:ident
     ) => {{
         let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
         let count = $iter.current_count() - $start_index - 1;
-        if is_overflow::<$t, $u, $format>($value, count, $is_negative) {
+        if count == 0 && $start_index == $shift && $shift != 0 {
+            // Only parsed a sign, without any digits or leading zeros.
+            into_error!(Empty, $shift)
+        } else if is_overflow::<$t, $u, $format>($value, count, $is_negative) {
             let min = min_step(radix, <$t as Integer>::BITS, <$t>::IS_SIGNED);
             if <$t>::IS_SIGNED && $is_negative {
                 into_error!(Underflow, (count - 1).min(min + 1))
             } else {
                 into_error!(Overflow, (count - 1).min(min + 1))
             }
+        } else if <$t>::IS_SIGNED && $is_negative {
+            into_ok_partial!(as_cast::<$t, _>($value.wrapping_neg()), $iter.cursor() - 1)
         } else {
             into_ok_partial!($value, $iter.cursor() - 1)
         }
@@ -151,6 +161,13 @@ where
 {
     let format = NumberFormat::<{ FORMAT }> {};
 
+    // Inputs with few enough digits can never overflow, so
+    // we can skip validating the wrapped value entirely.
+    let min = min_step(format.radix(), T::BITS, T::IS_SIGNED);
+    if count <= min {
+        return false;
+    }
+
     let max = max_step(format.radix(), T::BITS, T::IS_SIGNED);
     let radix: U = as_cast(format.radix());
     let min_value: U = radix.pow(max as u32 - 1);
@@ -182,6 +199,7 @@ macro_rules! parse_value {
         $is_negative:ident,
         $format:ident,
         $start_index:ident,
+        $shift:ident,
         $t:ident,
         $u:ident,
         $parser:ident,
@@ -195,7 +213,7 @@ macro_rules! parse_value {
         // improvements due to decreased branching for all but `i8`.
         let mut value = <$u>::ZERO;
         let format = NumberFormat::<{ $format }> {};
-        $parser!(value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit);
+        $parser!(value, $iter, $format, $is_negative, $start_index, $shift, $t, $u, $invalid_digit);
         let count = $iter.current_count() - $start_index;
 
         if is_overflow::<$t, $u, $format>(value, count, $is_negative) {
@@ -225,6 +243,7 @@ macro_rules! parse_1digit {
         $format:ident,
         $is_negative:ident,
         $start_index:ident,
+        $shift:ident,
         $t:ident,
         $u:ident,
         $invalid_digit:ident
@@ -266,13 +285,13 @@ macro_rules! parse_1digit {
                         $format,
                         $is_negative,
                         $start_index,
+                        $shift,
                         $t,
                         $u
                     );
                 },
             };
-            $value = $value.wrapping_mul(as_cast(radix));
-            $value = $value.wrapping_add(as_cast(digit));
+            $value = $value.wrapping_mul_add(radix, digit);
         }
     }};
 }
@@ -371,6 +390,21 @@ macro_rules! algorithm {
             };
         }
 
+        // If we have a format that caps leading zeros, check if we have
+        // more than the maximum. The last zero is a digit, not a leading
+        // zero, if no valid digit follows.
+        let max_zeros = format.max_leading_zeros() as usize;
+        if cfg!(feature = "format") && !is_prefix && max_zeros != 0 && zeros > max_zeros {
+            let is_digit = matches!(
+                iter.peek().map(|&c| char_to_digit_const(c, format.radix())),
+                Some(Some(_))
+            );
+            if zeros > max_zeros + !is_digit as usize {
+                let index = iter.cursor() - zeros + max_zeros;
+                return into_error!(InvalidLeadingZeros, index);
+            }
+        }
+
         //  NOTE:
         //      Don't add optimizations for 128-bit integers.
         //      128-bit multiplication is rather efficient, it's only division
@@ -383,6 +417,7 @@ macro_rules! algorithm {
             is_negative,
             $format,
             start_index,
+            shift,
             $t,
             $u,
             $parser,
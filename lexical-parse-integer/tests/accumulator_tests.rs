@@ -0,0 +1,84 @@
+use lexical_parse_integer::{Accumulation, DigitAccumulator};
+use lexical_util::radix::Radix;
+use proptest::prelude::*;
+
+#[test]
+fn push_test() {
+    let mut acc = DigitAccumulator::<u16>::new(Radix::HEXADECIMAL);
+    assert_eq!(acc.push(b'F'), Accumulation::Complete);
+    assert_eq!(acc.push(b'f'), Accumulation::Complete);
+    assert_eq!(acc.push(b'g'), Accumulation::InvalidDigit);
+    assert_eq!(acc.push_digit(0), Accumulation::Complete);
+    assert_eq!(acc.push(b'1'), Accumulation::Complete);
+    assert_eq!(acc.value(), 0xFF01);
+    assert_eq!(acc.push(b'0'), Accumulation::Overflow);
+    assert_eq!(acc.value(), 0xFF01);
+    assert_eq!(acc.count(), 4);
+    assert_eq!(acc.radix(), Radix::HEXADECIMAL);
+
+    // The extended alphabet is case-sensitive.
+    let mut acc = DigitAccumulator::<u64>::new(Radix::MAX);
+    assert_eq!(acc.extend(b"Zz"), (2, Accumulation::Complete));
+    assert_eq!(acc.value(), 35 * 62 + 61);
+}
+
+#[test]
+#[should_panic]
+fn push_digit_invalid_test() {
+    let mut acc = DigitAccumulator::<u32>::new(Radix::OCTAL);
+    acc.push_digit(8);
+}
+
+#[test]
+fn extend_test() {
+    let mut acc = DigitAccumulator::<u8>::new(Radix::DECIMAL);
+    assert_eq!(acc.extend(b""), (0, Accumulation::Complete));
+    assert_eq!(acc.extend(b"0025"), (4, Accumulation::Complete));
+    assert_eq!(acc.extend(b"5x"), (1, Accumulation::InvalidDigit));
+    assert_eq!(acc.value(), 255);
+    assert_eq!(acc.extend(b"0"), (0, Accumulation::Overflow));
+    assert_eq!(acc.count(), 5);
+
+    // Resume a field split across buffers.
+    let mut acc = DigitAccumulator::<u32>::with_value(12, Radix::DECIMAL);
+    assert_eq!(acc.extend(b"345"), (3, Accumulation::Complete));
+    assert_eq!(acc.value(), 12345);
+    assert_eq!(acc.count(), 3);
+}
+
+#[test]
+fn checksum_test() {
+    // ISO 7064 MOD 97-10, reducing the value on overflow.
+    let mut acc = DigitAccumulator::<u16>::new(Radix::DECIMAL);
+    let digits = b"3214282912345698765432161182";
+    let mut index = 0;
+    while index < digits.len() {
+        let (count, accumulation) = acc.extend(&digits[index..]);
+        index += count;
+        if accumulation == Accumulation::Overflow {
+            acc.set_value(acc.value() % 97);
+        }
+    }
+    assert_eq!(acc.value() % 97, 1);
+    assert_eq!(acc.count(), digits.len());
+}
+
+proptest! {
+    #[test]
+    fn extend_proptest(s in r"[0-9]{0,25}") {
+        let mut acc = DigitAccumulator::<u64>::new(Radix::DECIMAL);
+        let (count, accumulation) = acc.extend(s.as_bytes());
+        match s.parse::<u64>() {
+            Ok(value) => {
+                prop_assert_eq!(accumulation, Accumulation::Complete);
+                prop_assert_eq!(count, s.len());
+                prop_assert_eq!(acc.value(), value);
+            },
+            Err(_) if s.is_empty() => prop_assert_eq!(acc.value(), 0),
+            Err(_) => {
+                prop_assert_eq!(accumulation, Accumulation::Overflow);
+                prop_assert_eq!(acc.value(), s[..count].parse::<u64>().unwrap());
+            },
+        }
+    }
+}
@@ -53,6 +53,31 @@ fn test_try_parse_4digits() {
     assert_eq!(parse(b"1234_"), Some(1234));
 }
 
+#[test]
+fn test_try_parse_small() {
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"0"), Some(0));
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"7"), Some(7));
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"42"), Some(42));
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"404"), Some(404));
+    assert_eq!(algorithm::try_parse_small::<u16, STANDARD>(b"8080"), Some(8080));
+    assert_eq!(algorithm::try_parse_small::<i16, STANDARD>(b"9999"), Some(9999));
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"0012"), Some(12));
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b""), None);
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"12345"), None);
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"-1"), None);
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"+1"), None);
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"1a"), None);
+    assert_eq!(algorithm::try_parse_small::<u32, STANDARD>(b"1 "), None);
+    // 4 digits may not fit in 8-bit integers.
+    assert_eq!(algorithm::try_parse_small::<u8, STANDARD>(b"1"), None);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(algorithm::try_parse_small::<u32, { from_radix(2) }>(b"101"), Some(5));
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(algorithm::try_parse_small::<u32, { from_radix(2) }>(b"102"), None);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(algorithm::try_parse_small::<u32, { from_radix(16) }>(b"10"), None);
+}
+
 #[test]
 fn test_is_8digits() {
     let value: u64 = 0x31_32_33_34_35_36_37_38;
@@ -198,6 +198,45 @@ fn radix_test() {
     radix_to_u32::<{ from_radix(36) }>(b"11", 37);
 }
 
+#[test]
+#[cfg(feature = "radix")]
+fn extended_radix_test() {
+    const BASE36: u128 = from_radix(36);
+    const BASE40: u128 = from_radix(40);
+    const BASE62: u128 = from_radix(62);
+    let options = Options::new();
+    radix_to_u32::<{ from_radix(37) }>(b"10", 37);
+    radix_to_u32::<{ BASE40 }>(b"11bSYMF", u32::MAX);
+    radix_to_u32::<{ BASE62 }>(b"z", 61);
+    radix_to_u32::<{ BASE62 }>(b"Z", 35);
+
+    // Lowercase letters are only case-insensitive up to radix 36.
+    assert_eq!(u32::from_lexical_with_options::<BASE36>(b"z", &options), Ok(35));
+    assert_eq!(
+        u32::from_lexical_with_options::<BASE40>(b"e", &options),
+        Err(Error::InvalidDigit(0))
+    );
+
+    assert_eq!(i32::from_lexical_with_options::<BASE62>(b"-2LKcb2", &options), Ok(i32::MIN));
+    assert_eq!(
+        i32::from_lexical_with_options::<BASE62>(b"2LKcb2", &options),
+        Err(Error::Overflow(5))
+    );
+    assert_eq!(u64::from_lexical_with_options::<BASE62>(b"LygHa16AHYF", &options), Ok(u64::MAX));
+    assert_eq!(
+        u64::from_lexical_with_options::<BASE62>(b"LygHa16AHYG", &options),
+        Err(Error::Overflow(10))
+    );
+    assert_eq!(
+        u128::from_lexical_with_options::<BASE62>(b"7n42DGM5Tflk9n8mt7Fhc7", &options),
+        Ok(u128::MAX)
+    );
+    assert_eq!(
+        u128::from_lexical_with_options::<BASE62>(b"7n42DGM5Tflk9n8mt7Fhc8", &options),
+        Err(Error::Overflow(21))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn i32_no_leading_zeros_test() {
@@ -210,6 +249,31 @@ fn i32_no_leading_zeros_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"010", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn i32_max_leading_zeros_test() {
+    let options = Options::new();
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().max_leading_zeros(core::num::NonZeroU8::new(2)).build();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"0", &options), Ok(0));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"000", &options), Ok(0));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"007", &options), Ok(7));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-0070", &options), Ok(-70));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"0000", &options),
+        Err(Error::InvalidLeadingZeros(2))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"-0007", &options),
+        Err(Error::InvalidLeadingZeros(3))
+    );
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"0007", &options),
+        Err(Error::InvalidLeadingZeros(2))
+    );
+    assert_eq!(i32::from_lexical_partial_with_options::<FORMAT>(b"00.", &options), Ok((0, 2)));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn i32_integer_internal_digit_separator_test() {
@@ -279,6 +343,188 @@ fn i32_json_no_leading_zero() {
     assert!(i32::from_lexical_with_options::<{ JSON }>(b"-012", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn i32_negative_parentheses_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().negative_parentheses(true).build();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"(12)", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-12", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"(-12)", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"(+12)", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"(12", &options), Err(Error::Empty(3)));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"(1x)", &options),
+        Err(Error::InvalidDigit(2))
+    );
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"()", &options), Err(Error::Empty(1)));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12)", &options),
+        Err(Error::InvalidDigit(2))
+    );
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"(128)", &options), Ok(-128));
+    assert_eq!(
+        i8::from_lexical_with_options::<FORMAT>(b"(129)", &options),
+        Err(Error::Underflow(3))
+    );
+    assert!(i8::from_lexical_with_options::<FORMAT>(b"(1000)", &options).is_err());
+    assert!(u32::from_lexical_with_options::<FORMAT>(b"(12)", &options).is_err());
+
+    assert_eq!(i32::from_lexical_partial_with_options::<FORMAT>(b"(12) ", &options), Ok((-12, 4)));
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"(12 ", &options),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"(12", &options),
+        Err(Error::Empty(3))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_trailing_sign_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().trailing_sign(true).build();
+    let parse = |bytes| i32::from_lexical_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(parse(b"12-"), Ok(-12));
+    assert_eq!(parse(b"12+"), Ok(12));
+    assert_eq!(parse(b"-12"), Ok(-12));
+    assert_eq!(parse(b"12"), Ok(12));
+    assert_eq!(parse(b"-12-"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"+12-"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"12--"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"1-2"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"-"), Err(Error::Empty(0)));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"128-", &options), Ok(-128));
+    assert_eq!(
+        i8::from_lexical_with_options::<FORMAT>(b"129-", &options),
+        Err(Error::Underflow(3))
+    );
+    assert_eq!(
+        u8::from_lexical_with_options::<FORMAT>(b"12-", &options),
+        Err(Error::InvalidNegativeSign(2))
+    );
+
+    let partial = |bytes| i32::from_lexical_partial_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(partial(b"12- 3"), Ok((-12, 3)));
+    assert_eq!(partial(b"12+,"), Ok((12, 3)));
+    assert_eq!(partial(b"12 -"), Ok((12, 2)));
+    assert_eq!(partial(b"-12-"), Err(Error::InvalidDigit(3)));
+    assert_eq!(i8::from_lexical_partial_with_options::<FORMAT>(b"128- ", &options), Ok((-128, 4)));
+    assert!(i8::from_lexical_partial_with_options::<FORMAT>(b"128+", &options).is_err());
+    assert!(i8::from_lexical_partial_with_options::<FORMAT>(b"129-", &options).is_err());
+    assert_eq!(
+        u8::from_lexical_partial_with_options::<FORMAT>(b"12-", &options),
+        Err(Error::InvalidNegativeSign(2))
+    );
+
+    // Trailing signs cannot be used in parentheses.
+    const ACCOUNTING: u128 =
+        NumberFormatBuilder::new().negative_parentheses(true).trailing_sign(true).build();
+    assert_eq!(i32::from_lexical_with_options::<ACCOUNTING>(b"(12)", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<ACCOUNTING>(b"12-", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<ACCOUNTING>(b"(12-)", &options),
+        Err(Error::InvalidDigit(3))
+    );
+
+    // A trailing `+` is invalid without positive signs.
+    const NO_POSITIVE: u128 =
+        NumberFormatBuilder::new().trailing_sign(true).no_positive_mantissa_sign(true).build();
+    assert_eq!(i32::from_lexical_with_options::<NO_POSITIVE>(b"12-", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<NO_POSITIVE>(b"12+", &options),
+        Err(Error::InvalidDigit(2))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_overpunch_sign_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().overpunch_sign(true).build();
+    let parse = |bytes| i32::from_lexical_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(parse(b"12C"), Ok(123));
+    assert_eq!(parse(b"12L"), Ok(-123));
+    assert_eq!(parse(b"12{"), Ok(120));
+    assert_eq!(parse(b"12}"), Ok(-120));
+    assert_eq!(parse(b"12I"), Ok(129));
+    assert_eq!(parse(b"12R"), Ok(-129));
+    assert_eq!(parse(b"A"), Ok(1));
+    assert_eq!(parse(b"}"), Ok(0));
+    assert_eq!(parse(b"123"), Ok(123));
+    assert_eq!(parse(b"-123"), Ok(-123));
+    assert_eq!(parse(b"-12L"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"12S"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"1L2"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"12c"), Err(Error::InvalidDigit(2)));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"12G", &options), Ok(127));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"12H", &options), Err(Error::Overflow(2)));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"12Q", &options), Ok(-128));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"12R", &options), Err(Error::Underflow(2)));
+    assert_eq!(u8::from_lexical_with_options::<FORMAT>(b"25E", &options), Ok(255));
+    assert_eq!(u8::from_lexical_with_options::<FORMAT>(b"25F", &options), Err(Error::Overflow(2)));
+    assert_eq!(
+        u8::from_lexical_with_options::<FORMAT>(b"2J", &options),
+        Err(Error::InvalidNegativeSign(1))
+    );
+
+    let partial = |bytes| i32::from_lexical_partial_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(partial(b"12L0034"), Ok((-123, 3)));
+    assert_eq!(partial(b"J,"), Ok((-1, 1)));
+    assert_eq!(partial(b"12 C"), Ok((12, 2)));
+    assert_eq!(partial(b"-12L"), Err(Error::InvalidDigit(3)));
+    assert_eq!(i8::from_lexical_partial_with_options::<FORMAT>(b"12Q", &options), Ok((-128, 3)));
+    assert_eq!(
+        i8::from_lexical_partial_with_options::<FORMAT>(b"12R", &options),
+        Err(Error::Underflow(2))
+    );
+
+    // Overpunched digits are decimal.
+    #[cfg(feature = "power-of-two")]
+    {
+        const HEX: u128 = NumberFormatBuilder::new().overpunch_sign(true).radix(16).build();
+        assert!(i32::from_lexical_with_options::<HEX>(b"12", &options).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_optional_quotes_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().optional_quotes(true).build();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"\"-12\"", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"\"12", &options), Err(Error::Empty(3)));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"\"\"", &options), Err(Error::Empty(1)));
+    assert!(i32::from_lexical_with_options::<FORMAT>(b"\"(12)\"", &options).is_err());
+
+    const ACCOUNTING: u128 =
+        NumberFormatBuilder::new().negative_parentheses(true).optional_quotes(true).build();
+    assert_eq!(i32::from_lexical_with_options::<ACCOUNTING>(b"\"(12)\"", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<ACCOUNTING>(b"(12)", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<ACCOUNTING>(b"(\"12\")", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<ACCOUNTING>(b"\"(12\"", &options),
+        Err(Error::InvalidDigit(4))
+    );
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<ACCOUNTING>(b"\"(12)\",", &options),
+        Ok((-12, 6))
+    );
+}
+
 #[test]
 #[cfg(all(feature = "power-of-two", feature = "format"))]
 fn base_prefix_test() {
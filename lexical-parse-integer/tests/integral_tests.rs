@@ -0,0 +1,75 @@
+use lexical_parse_integer::parse_integer_valued_float;
+use lexical_util::error::Error;
+use proptest::prelude::*;
+
+#[test]
+fn integral_test() {
+    assert_eq!(parse_integer_valued_float::<i64>(b"0"), Ok(0));
+    assert_eq!(parse_integer_valued_float::<i64>(b"-0.0e-5"), Ok(0));
+    assert_eq!(parse_integer_valued_float::<i64>(b"0e999999999999999999999"), Ok(0));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1000.0"), Ok(1000));
+    assert_eq!(parse_integer_valued_float::<i64>(b"+1E3"), Ok(1000));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1.5e1"), Ok(15));
+    assert_eq!(parse_integer_valued_float::<i64>(b"150e-1"), Ok(15));
+    assert_eq!(parse_integer_valued_float::<i64>(b".5e1"), Ok(5));
+    assert_eq!(parse_integer_valued_float::<i64>(b"5."), Ok(5));
+    assert_eq!(parse_integer_valued_float::<i64>(b"9007199254740993"), Ok(9007199254740993));
+    assert_eq!(parse_integer_valued_float::<i64>(b"9.223372036854775807e18"), Ok(i64::MAX));
+    assert_eq!(parse_integer_valued_float::<i64>(b"-9.223372036854775808e18"), Ok(i64::MIN));
+    assert_eq!(parse_integer_valued_float::<i8>(b"-1.28e2"), Ok(-128));
+    assert_eq!(parse_integer_valued_float::<u8>(b"2.55e2"), Ok(255));
+    assert_eq!(
+        parse_integer_valued_float::<u128>(b"3.40282366920938463463374607431768211455e38"),
+        Ok(u128::MAX)
+    );
+}
+
+#[test]
+fn integral_error_test() {
+    assert_eq!(parse_integer_valued_float::<i64>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"."), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"e5"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1e+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1x"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1.0.0"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"1.25"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_integer_valued_float::<i64>(b"125e-2"), Err(Error::InvalidDigit(1)));
+    assert_eq!(
+        parse_integer_valued_float::<i64>(b"1e-999999999999999999999"),
+        Err(Error::InvalidDigit(0))
+    );
+    assert_eq!(parse_integer_valued_float::<i64>(b"9223372036854775808"), Err(Error::Overflow(18)));
+    assert_eq!(
+        parse_integer_valued_float::<i64>(b"-9223372036854775809"),
+        Err(Error::Underflow(19))
+    );
+    assert_eq!(
+        parse_integer_valued_float::<i64>(b"1e999999999999999999999"),
+        Err(Error::Overflow(1))
+    );
+    assert_eq!(parse_integer_valued_float::<i8>(b"-1.29e2"), Err(Error::Underflow(4)));
+    assert_eq!(parse_integer_valued_float::<u8>(b"-1"), Err(Error::InvalidDigit(0)));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i64_roundtrip_proptest(i in i64::MIN..i64::MAX, zeros in 0usize..5) {
+        let digits = i.to_string();
+        let zeros = "0".repeat(zeros);
+        let exact = format!("{}.{}", digits, zeros);
+        prop_assert_eq!(parse_integer_valued_float::<i64>(exact.as_bytes()), Ok(i));
+        let scientific = format!("{}{}e-{}", digits, zeros, zeros.len());
+        prop_assert_eq!(parse_integer_valued_float::<i64>(scientific.as_bytes()), Ok(i));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i64_fraction_proptest(i in i64::MIN..i64::MAX, f in 1u32..10) {
+        let string = format!("{}.{}", i, f);
+        let index = string.len() - 1;
+        prop_assert_eq!(parse_integer_valued_float::<i64>(string.as_bytes()), Err(Error::InvalidDigit(index)));
+    }
+}
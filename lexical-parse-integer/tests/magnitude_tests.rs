@@ -0,0 +1,70 @@
+use lexical_parse_integer::{parse_magnitude, Sign};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+#[test]
+fn parse_magnitude_test() {
+    assert_eq!(parse_magnitude::<STANDARD>(b"0"), Ok((0, Sign::Positive, 1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-0"), Ok((0, Sign::Negative, 2)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"+123"), Ok((123, Sign::Positive, 4)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-123abc"), Ok((123, Sign::Negative, 4)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"a"), Ok((0, Sign::Positive, 0)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-a"), Err(Error::Empty(1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"+a"), Err(Error::Empty(1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-+5"), Err(Error::Empty(1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"--5"), Err(Error::Empty(1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"+"), Err(Error::Empty(1)));
+    assert_eq!(
+        parse_magnitude::<STANDARD>(b"18446744073709551615"),
+        Ok((u64::MAX, Sign::Positive, 20))
+    );
+    assert_eq!(
+        parse_magnitude::<STANDARD>(b"-18446744073709551615"),
+        Ok((u64::MAX, Sign::Negative, 21))
+    );
+    assert_eq!(parse_magnitude::<STANDARD>(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_magnitude::<STANDARD>(b"-18446744073709551616"), Err(Error::Underflow(20)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_magnitude_format_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const REQUIRED: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    assert_eq!(parse_magnitude::<REQUIRED>(b"-5"), Ok((5, Sign::Negative, 2)));
+    assert_eq!(parse_magnitude::<REQUIRED>(b"+5"), Ok((5, Sign::Positive, 2)));
+    assert_eq!(parse_magnitude::<REQUIRED>(b"5"), Err(Error::MissingSign(0)));
+    assert_eq!(
+        parse_magnitude::<REQUIRED>(b"-18446744073709551615"),
+        Ok((u64::MAX, Sign::Negative, 21))
+    );
+    assert_eq!(parse_magnitude::<REQUIRED>(b"-18446744073709551616"), Err(Error::Underflow(20)));
+
+    const NO_POSITIVE: u128 = NumberFormatBuilder::new().no_positive_mantissa_sign(true).build();
+    assert_eq!(parse_magnitude::<NO_POSITIVE>(b"+5"), Err(Error::InvalidPositiveSign(0)));
+    assert_eq!(parse_magnitude::<NO_POSITIVE>(b"-5"), Ok((5, Sign::Negative, 2)));
+
+    const PARENTHESES: u128 = NumberFormatBuilder::new().negative_parentheses(true).build();
+    assert_eq!(parse_magnitude::<PARENTHESES>(b"(5) "), Ok((5, Sign::Negative, 3)));
+    assert_eq!(parse_magnitude::<PARENTHESES>(b"(0)"), Ok((0, Sign::Negative, 3)));
+    assert_eq!(parse_magnitude::<PARENTHESES>(b"-5"), Ok((5, Sign::Negative, 2)));
+    assert!(parse_magnitude::<PARENTHESES>(b"(-5)").is_err());
+    assert_eq!(
+        parse_magnitude::<PARENTHESES>(b"(18446744073709551616)"),
+        Err(Error::Underflow(20))
+    );
+}
+
+proptest! {
+    #[test]
+    fn parse_magnitude_proptest(i in i64::MIN..i64::MAX) {
+        let string = i.to_string();
+        let sign = if i < 0 { Sign::Negative } else { Sign::Positive };
+        let expected = (i.unsigned_abs(), sign, string.len());
+        prop_assert_eq!(parse_magnitude::<STANDARD>(string.as_bytes()), Ok(expected));
+    }
+}
@@ -15,3 +15,12 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_test() {
+    let json = serde_json::to_string(&Options::new()).unwrap();
+    assert_eq!(json, "{}");
+    assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), Options::new());
+    assert!(serde_json::from_str::<Options>(r#"{"unknown":1}"#).is_err());
+}
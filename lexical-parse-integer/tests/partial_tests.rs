@@ -32,3 +32,20 @@ fn u8_decimal_format_test() {
         u8::from_lexical_partial_with_options::<FORMAT>(b"0", &options)
     );
 }
+
+#[test]
+fn i32_decimal_test() {
+    assert_eq!(Ok((-1, 2)), i32::from_lexical_partial(b"-1"));
+    assert_eq!(Ok((-1, 2)), i32::from_lexical_partial(b"-1a"));
+    assert_eq!(Ok((-12, 3)), i32::from_lexical_partial(b"-12}"));
+    assert_eq!(Ok((12, 3)), i32::from_lexical_partial(b"+12}"));
+    assert_eq!(Ok((-2147483648, 11)), i32::from_lexical_partial(b"-2147483648,"));
+    assert_eq!(Err(Error::Underflow(9)), i32::from_lexical_partial(b"-2147483649,"));
+    assert_eq!(Ok((-1234567890123, 14)), i64::from_lexical_partial(b"-1234567890123 "));
+    assert_eq!(Ok((-12, 3)), i128::from_lexical_partial(b"-12."));
+    assert_eq!(Err(Error::Empty(1)), i32::from_lexical_partial(b"-,"));
+    assert_eq!(Err(Error::Empty(1)), i32::from_lexical_partial(b"+,"));
+    assert_eq!(Err(Error::Empty(1)), u32::from_lexical_partial(b"+a"));
+    assert_eq!(Ok((0, 0)), u32::from_lexical_partial(b"-,"));
+    assert_eq!(Ok((0, 2)), i32::from_lexical_partial(b"-0,"));
+}
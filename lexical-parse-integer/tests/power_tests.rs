@@ -0,0 +1,24 @@
+use lexical_parse_integer::parse_power_of_ten;
+use lexical_util::error::Error;
+
+#[test]
+fn parse_power_of_ten_test() {
+    assert_eq!(parse_power_of_ten(b"e-3"), Ok(-3));
+    assert_eq!(parse_power_of_ten(b"E+6"), Ok(6));
+    assert_eq!(parse_power_of_ten(b"1e12"), Ok(12));
+    assert_eq!(parse_power_of_ten(b"1E0"), Ok(0));
+    assert_eq!(parse_power_of_ten(b"e2147483647"), Ok(i32::MAX));
+    assert_eq!(parse_power_of_ten(b"e-2147483648"), Ok(i32::MIN));
+}
+
+#[test]
+fn parse_power_of_ten_error_test() {
+    assert_eq!(parse_power_of_ten(b""), Err(Error::EmptyExponent(0)));
+    assert_eq!(parse_power_of_ten(b"1"), Err(Error::EmptyExponent(1)));
+    assert_eq!(parse_power_of_ten(b"10"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_power_of_ten(b"2e3"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_power_of_ten(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse_power_of_ten(b"1e-"), Err(Error::EmptyExponent(3)));
+    assert_eq!(parse_power_of_ten(b"1e5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_power_of_ten(b"e2147483648"), Err(Error::Overflow(10)));
+}
@@ -0,0 +1,252 @@
+//! Cached powers for the Bellerophon algorithm.
+//!
+//! Exact versions of `radix^n` as 80-bit extended-precision floats, with
+//! both large and small powers. The large powers minimize the amount of
+//! compounded error, while the small powers are exact. The binary
+//! exponents are not stored, and are instead calculated from a scaled,
+//! fixed-point `log2(radix)`.
+//!
+//! This generates `table_bellerophon_decimal.rs` and
+//! `table_bellerophon_radix.rs` in `lexical-parse-float`. The powers can
+//! also be generated as 160-bit extended-precision floats, with 128-bit
+//! mantissas, for algorithms that need more than 64 bits of precision,
+//! such as `float160`.
+
+use crate::bigint::Uint;
+use crate::{parallel_map, write_array, write_array_inline, FloatType};
+use core::fmt::Write;
+
+/// Radixes with stored powers in `table_bellerophon_radix.rs`.
+///
+/// The powers for all other radixes are derived from these radixes,
+/// since scaling by a power of two is exact: for example, `6^e` uses
+/// the powers for `3^e`, and `36^e` uses the powers for `3^(2e)`.
+pub const SEED_RADIXES: [u32; 14] = [3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35];
+
+/// Get the largest power of the radix that fits in a 32-bit integer.
+///
+/// This is the step between large powers, and the number of small powers.
+#[inline]
+pub fn step(radix: u32) -> i32 {
+    (1e10f64.ln() / (radix as f64).ln()).floor() as i32
+}
+
+/// Calculate `ceil(log2(x))`.
+#[inline]
+fn ceil_log2(x: &Uint) -> usize {
+    let bits = x.bit_length();
+    if *x == Uint::power_of_two(bits - 1) {
+        bits - 1
+    } else {
+        bits
+    }
+}
+
+/// Get the high `mantissa_bits` bits of a value, truncating the rest.
+#[inline]
+fn normalize(mut x: Uint, mantissa_bits: usize) -> u128 {
+    let bits = x.bit_length();
+    assert!(bits >= mantissa_bits, "value does not have enough significant bits");
+    x.shr(bits - mantissa_bits);
+    x.to_u128().unwrap()
+}
+
+/// Calculate the `log2(radix)` multiplier for a given bitshift.
+#[inline]
+fn log2_mult(radix: u32, shift: i32) -> i64 {
+    ((radix as f64).log2() * (1u64 << shift) as f64).ceil() as i64
+}
+
+/// Determine if the `log2(radix)` multiplier is exact for all powers
+/// with an absolute exponent smaller than or equal to `bias`.
+fn is_exact_log2(radix: u32, bias: i32, mult: i64, shift: i32) -> bool {
+    let mut power = Uint::from_u64(1);
+    for exp in 1..=bias as i64 {
+        power.mul_small(radix as u64);
+        // floor(log2(radix^exp)) and floor(log2(radix^-exp)).
+        let positive = power.bit_length() as i64 - 1;
+        let negative = -(ceil_log2(&power) as i64);
+        if (mult * exp) >> shift != positive || (mult * -exp) >> shift != negative {
+            return false;
+        }
+    }
+    true
+}
+
+/// Cached powers of a radix for the Bellerophon algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Powers {
+    /// Radix for the powers.
+    pub radix: u32,
+    /// Number of bits in each mantissa: 64 or 128.
+    pub mantissa_bits: u32,
+    /// Normalized mantissas for `radix^0` to `radix^(step-1)`.
+    pub small: Vec<u128>,
+    /// Normalized mantissas for `radix^(i*step - bias)`.
+    pub large: Vec<u128>,
+    /// Exact powers for `radix^0` to `radix^(step-1)`.
+    pub small_int: Vec<u64>,
+    /// Step between large powers and number of small powers.
+    pub step: i32,
+    /// Exponent bias for the large powers.
+    pub bias: i32,
+    /// `ceil(log2(radix))` scaled as a multiplier.
+    pub log2: i64,
+    /// Bitshift for the log2 multiplier.
+    pub log2_shift: i32,
+}
+
+impl Powers {
+    /// Calculate the cached powers for a radix and float type, as 80-bit floats.
+    #[inline]
+    pub fn new(radix: u32, float: FloatType) -> Self {
+        Self::with_mantissa_bits(radix, float, 64)
+    }
+
+    /// Calculate the cached powers for a radix and float type, with
+    /// 64-bit mantissas for 80-bit floats, or 128-bit mantissas for
+    /// 160-bit floats.
+    pub fn with_mantissa_bits(radix: u32, float: FloatType, mantissa_bits: u32) -> Self {
+        assert!((3..=36).contains(&radix) && !radix.is_power_of_two(), "invalid radix");
+        assert!(mantissa_bits == 64 || mantissa_bits == 128, "invalid mantissa bits");
+        let bits = mantissa_bits as usize;
+        let step = step(radix);
+        let step_power = Uint::pow(radix as u64, step as u32);
+        let step_int = step_power.to_u128().unwrap() as u64;
+
+        // Small powers are exact, so the scale only needs to ensure the
+        // largest power is normalized.
+        let bitshift = bits - 1 + ceil_log2(&step_power);
+        let mut fp = Uint::power_of_two(bitshift);
+        let mut small = Vec::new();
+        let mut small_int = Vec::new();
+        for exp in 0..step {
+            small.push(normalize(fp.clone(), bits));
+            small_int.push((radix as u64).pow(exp as u32));
+            fp.mul_small(radix as u64);
+        }
+
+        // Large powers must go below the minimum exponent, since we need
+        // all the biased exponents to be positive. Negative powers are
+        // calculated by division, so scale them enough to remain accurate.
+        // Wider mantissas scale the smallest denormal value by a smaller power.
+        let extra_bits = FloatType::log(mantissa_bits as i32 - 64, radix);
+        let min_exp = (float.min_radix_exponent(radix) - extra_bits).floor() as i32;
+        let max_exp = float.max_radix_exponent(radix).ceil() as i32;
+        let min_power = Uint::pow(radix as u64, (min_exp - step).unsigned_abs());
+        let bitshift = bits - 1 + ceil_log2(&min_power);
+
+        let mut negative = Vec::new();
+        let mut fp = Uint::power_of_two(bitshift);
+        let mut exp = -step;
+        while exp > min_exp - step {
+            fp.div_small(step_int);
+            negative.push(normalize(fp.clone(), bits));
+            exp -= step;
+        }
+        let bias = -(exp + step);
+        let mut large: Vec<u128> = negative.into_iter().rev().collect();
+
+        let mut fp = Uint::power_of_two(bitshift);
+        large.push(normalize(fp.clone(), bits));
+        let mut exp = step;
+        while exp < max_exp {
+            fp.mul_small(step_int);
+            large.push(normalize(fp.clone(), bits));
+            exp += step;
+        }
+
+        // Use the smallest shift that gives exact exponents for the range.
+        let log2_shift = match is_exact_log2(radix, bias, log2_mult(radix, 16), 16) {
+            true => 16,
+            false => 32,
+        };
+        let log2 = log2_mult(radix, log2_shift);
+        assert!(is_exact_log2(radix, bias, log2, log2_shift), "inexact log2 multiplier");
+
+        Self {
+            radix,
+            mantissa_bits,
+            small,
+            large,
+            small_int,
+            step,
+            bias,
+            log2,
+            log2_shift,
+        }
+    }
+
+    /// Get the Rust definitions for the arrays and constants.
+    pub fn low_level(&self) -> String {
+        let radix = self.radix;
+        let bits = self.mantissa_bits;
+        let mut out = String::new();
+        let comments: Vec<String> = (0..self.step).map(|e| format!("{}^{}", radix, e)).collect();
+        let definition =
+            format!("const BASE{}_SMALL_MANTISSA: [u{}; {}]", radix, bits, self.small.len());
+        write_array(&mut out, &definition, &self.small, &comments);
+
+        let comments: Vec<String> = (0..self.large.len())
+            .map(|i| format!("{}^{}", radix, i as i32 * self.step - self.bias))
+            .collect();
+        let definition =
+            format!("const BASE{}_LARGE_MANTISSA: [u{}; {}]", radix, bits, self.large.len());
+        write_array(&mut out, &definition, &self.large, &comments);
+
+        let definition =
+            format!("const BASE{}_SMALL_INT_POWERS: [u64; {}]", radix, self.small_int.len());
+        write_array_inline(&mut out, &definition, &self.small_int);
+        writeln!(out, "const BASE{}_STEP: i32 = {};", radix, self.step).unwrap();
+        writeln!(out, "const BASE{}_BIAS: i32 = {};", radix, self.bias).unwrap();
+        writeln!(out, "const BASE{}_LOG2_MULT: i64 = {};", radix, self.log2).unwrap();
+        writeln!(out, "const BASE{}_LOG2_SHIFT: i32 = {};", radix, self.log2_shift).unwrap();
+        out
+    }
+
+    /// Get the Rust definition for the `BellerophonPowers`.
+    ///
+    /// The powers for 160-bit floats use `BellerophonPowers160`, which
+    /// has the same fields, with 128-bit mantissas.
+    pub fn high_level(&self) -> String {
+        let radix = self.radix;
+        let name = match self.mantissa_bits {
+            64 => "BellerophonPowers",
+            _ => "BellerophonPowers160",
+        };
+        let mut out = String::new();
+        writeln!(out, "pub const BASE{}_POWERS: {} = {} {{", radix, name, name).unwrap();
+        writeln!(out, "    small: &BASE{}_SMALL_MANTISSA,", radix).unwrap();
+        writeln!(out, "    large: &BASE{}_LARGE_MANTISSA,", radix).unwrap();
+        writeln!(out, "    small_int: &BASE{}_SMALL_INT_POWERS,", radix).unwrap();
+        writeln!(out, "    step: BASE{}_STEP,", radix).unwrap();
+        writeln!(out, "    bias: BASE{}_BIAS,", radix).unwrap();
+        writeln!(out, "    log2: BASE{}_LOG2_MULT,", radix).unwrap();
+        writeln!(out, "    log2_shift: BASE{}_LOG2_SHIFT,", radix).unwrap();
+        writeln!(out, "}};").unwrap();
+        out
+    }
+}
+
+/// Generate the cached powers for each radix, in parallel, as 80-bit floats.
+#[inline]
+pub fn generate(radixes: &[u32], float: FloatType) -> String {
+    generate_with_mantissa_bits(radixes, float, 64)
+}
+
+/// Generate the cached powers for each radix, in parallel, with
+/// 64-bit mantissas for 80-bit floats, or 128-bit mantissas for
+/// 160-bit floats.
+pub fn generate_with_mantissa_bits(radixes: &[u32], float: FloatType, bits: u32) -> String {
+    let powers = parallel_map(radixes, move |radix| Powers::with_mantissa_bits(radix, float, bits));
+    let mut out = String::new();
+    writeln!(out, "// HIGH LEVEL\n// ----------\n").unwrap();
+    for power in &powers {
+        writeln!(out, "{}", power.high_level()).unwrap();
+    }
+    writeln!(out, "// LOW-LEVEL\n// ---------").unwrap();
+    for power in &powers {
+        writeln!(out, "\n// BASE{}\n\n{}", power.radix, power.low_level()).unwrap();
+    }
+    out
+}
@@ -0,0 +1,246 @@
+//! Minimal arbitrary-precision unsigned integer.
+//!
+//! This is only used to calculate exact powers, so it favors simplicity
+//! over speed: the tables only need a few thousand bits of precision.
+
+use core::cmp::Ordering;
+
+/// Arbitrary-precision unsigned integer.
+///
+/// The limbs are stored in little-endian order, and the most-significant
+/// limb is never zero.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Uint {
+    limbs: Vec<u64>,
+}
+
+impl Uint {
+    /// Create an integer from a native value.
+    #[inline]
+    pub fn from_u64(value: u64) -> Self {
+        let mut x = Self {
+            limbs: vec![value],
+        };
+        x.normalize();
+        x
+    }
+
+    /// Create an integer from a power of two.
+    #[inline]
+    pub fn power_of_two(exp: usize) -> Self {
+        let mut x = Self::from_u64(1);
+        x.shl(exp);
+        x
+    }
+
+    /// Calculate the exact value of `base^exp`.
+    pub fn pow(base: u64, exp: u32) -> Self {
+        let mut x = Self::from_u64(1);
+        for _ in 0..exp {
+            x.mul_small(base);
+        }
+        x
+    }
+
+    /// Get if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Get the number of bits required to store the value.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        match self.limbs.last() {
+            Some(&hi) => 64 * self.limbs.len() - hi.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Get if the bit at the index is set.
+    #[inline]
+    pub fn bit(&self, index: usize) -> bool {
+        match self.limbs.get(index / 64) {
+            Some(&limb) => limb & (1 << (index % 64)) != 0,
+            None => false,
+        }
+    }
+
+    /// Get the value as a native integer, if it fits.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u128),
+            2 => Some(self.limbs[0] as u128 | (self.limbs[1] as u128) << 64),
+            _ => None,
+        }
+    }
+
+    /// Split the value into little-endian limbs of `bits` bits each.
+    ///
+    /// The number of bits must be a power of two, from 8 to 128.
+    pub fn to_limbs(&self, bits: u32) -> Vec<u128> {
+        assert!(bits.is_power_of_two() && (8..=128).contains(&bits));
+        let mask = u128::MAX >> (128 - bits);
+        let mut x = self.clone();
+        let mut limbs = Vec::new();
+        while !x.is_zero() {
+            let lo = x.limbs[0] as u128 | (*x.limbs.get(1).unwrap_or(&0) as u128) << 64;
+            limbs.push(lo & mask);
+            x.shr(bits as usize);
+        }
+        limbs
+    }
+
+    /// Add a small value to the integer.
+    pub fn add_small(&mut self, y: u64) {
+        let mut carry = y;
+        for limb in self.limbs.iter_mut() {
+            let (value, overflow) = limb.overflowing_add(carry);
+            *limb = value;
+            carry = overflow as u64;
+            if carry == 0 {
+                break;
+            }
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    /// Multiply the integer by a small value.
+    pub fn mul_small(&mut self, y: u64) {
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut() {
+            let z = (*limb as u128) * (y as u128) + (carry as u128);
+            *limb = z as u64;
+            carry = (z >> 64) as u64;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+        self.normalize();
+    }
+
+    /// Divide the integer by a small value, rounding down.
+    ///
+    /// Returns the remainder.
+    pub fn div_small(&mut self, y: u64) -> u64 {
+        assert!(y != 0, "division by zero");
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let z = (rem as u128) << 64 | (*limb as u128);
+            *limb = (z / y as u128) as u64;
+            rem = (z % y as u128) as u64;
+        }
+        self.normalize();
+        rem
+    }
+
+    /// Divide the integer by another integer, rounding down.
+    pub fn div(&self, y: &Self) -> Self {
+        assert!(!y.is_zero(), "division by zero");
+        let mut quotient = Self::default();
+        let mut rem = Self::default();
+        for index in (0..self.bit_length()).rev() {
+            rem.shl(1);
+            if self.bit(index) {
+                rem.add_small(1);
+            }
+            if rem >= *y {
+                rem.sub(y);
+                quotient.set_bit(index);
+            }
+        }
+        quotient
+    }
+
+    /// Shift the integer left by `n` bits.
+    pub fn shl(&mut self, n: usize) {
+        if self.is_zero() {
+            return;
+        }
+        let (limbs, bits) = (n / 64, n % 64);
+        if bits != 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut() {
+                let value = *limb;
+                *limb = (value << bits) | carry;
+                carry = value >> (64 - bits);
+            }
+            if carry != 0 {
+                self.limbs.push(carry);
+            }
+        }
+        self.limbs.splice(0..0, vec![0; limbs]);
+    }
+
+    /// Shift the integer right by `n` bits, truncating the shifted bits.
+    pub fn shr(&mut self, n: usize) {
+        let (limbs, bits) = (n / 64, n % 64);
+        if limbs >= self.limbs.len() {
+            self.limbs.clear();
+            return;
+        }
+        self.limbs.drain(0..limbs);
+        if bits != 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut().rev() {
+                let value = *limb;
+                *limb = (value >> bits) | carry;
+                carry = value << (64 - bits);
+            }
+        }
+        self.normalize();
+    }
+
+    /// Set the bit at the index.
+    fn set_bit(&mut self, index: usize) {
+        let limb = index / 64;
+        if limb >= self.limbs.len() {
+            self.limbs.resize(limb + 1, 0);
+        }
+        self.limbs[limb] |= 1 << (index % 64);
+    }
+
+    /// Subtract a smaller or equal integer from the integer.
+    fn sub(&mut self, y: &Self) {
+        debug_assert!(*self >= *y);
+        let mut borrow = false;
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let rhs = *y.limbs.get(index).unwrap_or(&0);
+            let (value, overflow1) = limb.overflowing_sub(rhs);
+            let (value, overflow2) = value.overflowing_sub(borrow as u64);
+            *limb = value;
+            borrow = overflow1 || overflow2;
+        }
+        debug_assert!(!borrow);
+        self.normalize();
+    }
+
+    /// Remove any leading zero limbs.
+    #[inline]
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl PartialOrd for Uint {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uint {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
@@ -0,0 +1,101 @@
+//! Large powers as big-integer limbs.
+//!
+//! These are used to efficiently scale big integers in the slow path
+//! algorithms, by multiplying by a large power at a time. The power is
+//! `radix^(5*n)`, where `radix^n` is the largest power that fits in a
+//! 64-bit integer.
+//!
+//! This generates the `LARGE_POW*` constants in `table_decimal.rs` and
+//! `table_radix.rs` in `lexical-parse-float`.
+
+use crate::bigint::Uint;
+use crate::parallel_map;
+use core::fmt::Write;
+
+/// Get the largest exponent where `radix^exp` fits in a 64-bit integer.
+#[inline]
+pub fn u64_power_limit(radix: u32) -> u32 {
+    let mut exp = 0;
+    let mut value = 1u64;
+    while let Some(next) = value.checked_mul(radix as u64) {
+        value = next;
+        exp += 1;
+    }
+    exp
+}
+
+/// Large power of a radix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Power {
+    /// Radix for the power.
+    pub radix: u32,
+    /// Exponent for the power.
+    pub step: u32,
+    /// Exact value of the power.
+    pub value: Uint,
+}
+
+impl Power {
+    /// Calculate the large power for a radix.
+    pub fn new(radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "invalid radix");
+        let step = 5 * u64_power_limit(radix);
+        Self {
+            radix,
+            step,
+            value: Uint::pow(radix as u64, step),
+        }
+    }
+
+    /// Get the Rust definition of the power as limbs of `bits` bits.
+    pub fn limbs(&self, bits: u32) -> String {
+        let limbs = self.value.to_limbs(bits);
+        let values: Vec<String> = limbs.iter().map(|x| format!("    {},", x)).collect();
+        format!(
+            "pub const LARGE_POW{}: [u{}; {}] = [\n{}\n];\n",
+            self.radix,
+            bits,
+            limbs.len(),
+            values.join("\n")
+        )
+    }
+
+    /// Get the Rust definitions for the power with native limbs.
+    ///
+    /// This uses 64-bit limbs on 64-bit architectures that support
+    /// efficient 128-bit multiplication, and 32-bit limbs otherwise.
+    pub fn to_rust(&self) -> String {
+        let radix = self.radix;
+        let mut out = String::new();
+        writeln!(out, "/// Pre-computed large power-of-{} for 32-bit limbs.", radix).unwrap();
+        writeln!(
+            out,
+            "#[cfg(not(all(target_pointer_width = \"64\", not(target_arch = \"sparc\"))))]"
+        )
+        .unwrap();
+        writeln!(out, "{}", self.limbs(32)).unwrap();
+        writeln!(out, "/// Pre-computed large power-of-{} for 64-bit limbs.", radix).unwrap();
+        writeln!(out, "#[cfg(all(target_pointer_width = \"64\", not(target_arch = \"sparc\")))]")
+            .unwrap();
+        writeln!(out, "{}", self.limbs(64)).unwrap();
+        writeln!(out, "/// Step for large power-of-{} for 32-bit limbs.", radix).unwrap();
+        writeln!(out, "pub const LARGE_POW{}_STEP: u32 = {};", radix, self.step).unwrap();
+        out
+    }
+}
+
+/// Generate the large powers for each radix, in parallel.
+///
+/// If `bits` is provided, only limbs of that size are generated,
+/// otherwise, both 32-bit and 64-bit limbs are generated.
+pub fn generate(radixes: &[u32], bits: Option<u32>) -> String {
+    let powers = parallel_map(radixes, Power::new);
+    let tables: Vec<String> = powers
+        .iter()
+        .map(|power| match bits {
+            Some(bits) => power.limbs(bits),
+            None => power.to_rust(),
+        })
+        .collect();
+    tables.join("\n")
+}
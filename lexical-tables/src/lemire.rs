@@ -0,0 +1,101 @@
+//! Powers of 5 for the Eisel-Lemire algorithm.
+//!
+//! Each power is stored as a 128-bit significand, normalized so the
+//! most-significant bit is set. Positive powers are truncated, and
+//! negative powers are calculated as `2^b / 5^-q`, rounded up, where
+//! `b` is chosen to give at least 128 bits of precision. The binary
+//! exponents can be inferred from a logarithmic slope.
+//!
+//! This generates `table_lemire.rs` in `lexical-parse-float`, and is
+//! adapted from Daniel Lemire's fast_float `table_generation.py`.
+
+use crate::bigint::Uint;
+use crate::FloatType;
+use core::fmt::Write;
+
+/// Powers of 5 for the Eisel-Lemire algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Powers {
+    /// Exponent of the first power.
+    pub smallest_power: i32,
+    /// Exponent of the last power.
+    pub largest_power: i32,
+    /// Normalized 128-bit significands for each power.
+    pub powers: Vec<u128>,
+}
+
+impl Powers {
+    /// Calculate the powers of 5 for a float type.
+    pub fn new(float: FloatType) -> Self {
+        let smallest_power = float.min_radix_exponent(10).ceil() as i32;
+        let largest_power = float.max_radix_exponent(10).floor() as i32;
+        let mut powers = Vec::new();
+
+        for q in smallest_power..0 {
+            let power5 = Uint::pow(5, q.unsigned_abs());
+            // Smallest `z` such that `2^z >= 5^-q`.
+            let z = power5.bit_length();
+            let b = match q >= -27 {
+                true => z + 127,
+                false => 2 * z + 2 * 64,
+            };
+            let mut c = Uint::power_of_two(b).div(&power5);
+            c.add_small(1);
+            let bits = c.bit_length();
+            if bits > 128 {
+                c.shr(bits - 128);
+            }
+            powers.push(c.to_u128().unwrap());
+        }
+
+        for q in 0..=largest_power {
+            let mut power5 = Uint::pow(5, q as u32);
+            let bits = power5.bit_length();
+            match bits < 128 {
+                true => power5.shl(128 - bits),
+                false => power5.shr(bits - 128),
+            }
+            powers.push(power5.to_u128().unwrap());
+        }
+
+        Self {
+            smallest_power,
+            largest_power,
+            powers,
+        }
+    }
+
+    /// Get the Rust definitions for the constants and table.
+    pub fn to_rust(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "pub const SMALLEST_POWER_OF_FIVE: i32 = {};", self.smallest_power).unwrap();
+        writeln!(out, "pub const LARGEST_POWER_OF_FIVE: i32 = {};", self.largest_power).unwrap();
+        writeln!(
+            out,
+            "pub const N_POWERS_OF_FIVE: usize = (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize;"
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "// Use static to avoid long compile times: Rust compiler errors").unwrap();
+        writeln!(out, "// can have the entire table compiled multiple times, and then").unwrap();
+        writeln!(out, "// emit code multiple times, even if it's stripped out in").unwrap();
+        writeln!(out, "// the final binary.").unwrap();
+        writeln!(out, "#[rustfmt::skip]").unwrap();
+        writeln!(out, "pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [").unwrap();
+        for (index, power) in self.powers.iter().enumerate() {
+            let hi = (power >> 64) as u64;
+            let lo = *power as u64;
+            let value = format!("({:#x}, {:#x}),", hi, lo);
+            let exp = self.smallest_power + index as i32;
+            writeln!(out, "    {:<41} // 5^{}", value, exp).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        out
+    }
+}
+
+/// Generate the powers of 5 for a float type.
+#[inline]
+pub fn generate(float: FloatType) -> String {
+    Powers::new(float).to_rust()
+}
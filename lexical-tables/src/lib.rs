@@ -0,0 +1,131 @@
+//! Generate the pre-computed power tables used by lexical.
+//!
+//! All values are calculated from exact, arbitrary-precision integers,
+//! and written as Rust source, so they can be checked in, or generated
+//! from a build script and embedded with [`include!`]. This can also
+//! generate tables for other float types or big-integer limb sizes.
+//!
+//! The following tables are supported:
+//!
+//! * [`bellerophon`] - Cached powers as 80-bit or 160-bit extended floats,
+//!   for the Bellerophon algorithm (`table_bellerophon_*.rs`).
+//! * [`lemire`] - Powers of 5 as 128-bit significands, for the
+//!   Eisel-Lemire algorithm (`table_lemire.rs`).
+//! * [`large`] - Large powers as big-integer limbs, for the slow path
+//!   algorithms (`LARGE_POW*`).
+//!
+//! The generated code is not formatted, so run `rustfmt` over it before
+//! checking it in.
+//!
+//! # Example
+//!
+//! ```rust
+//! use lexical_tables::{bellerophon, FloatType};
+//!
+//! let powers = bellerophon::Powers::new(10, FloatType::F64);
+//! assert_eq!(powers.step, 10);
+//! assert_eq!(powers.bias, 350);
+//! assert!(powers.low_level().contains("const BASE10_STEP: i32 = 10;"));
+//! ```
+
+pub mod bellerophon;
+pub mod bigint;
+pub mod large;
+pub mod lemire;
+
+use core::fmt::{Display, Write};
+use std::sync::Arc;
+use std::thread;
+
+/// Default radixes for tables of non-decimal powers.
+///
+/// These are all the radixes from 3 to 36 that are not powers of two,
+/// since powers of two can be calculated exactly.
+pub const RADIXES: [u32; 30] = [
+    3, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+    31, 33, 34, 35, 36,
+];
+
+/// Exponent limits of a binary float type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatType {
+    /// Binary exponent of the smallest denormal value.
+    pub min_exponent: i32,
+    /// Binary exponent where all finite values are smaller than `2^max_exponent`.
+    pub max_exponent: i32,
+}
+
+impl FloatType {
+    /// Limits for an IEEE-754 single-precision float.
+    pub const F32: Self = Self {
+        min_exponent: -149,
+        max_exponent: 128,
+    };
+
+    /// Limits for an IEEE-754 double-precision float.
+    pub const F64: Self = Self {
+        min_exponent: -1074,
+        max_exponent: 1024,
+    };
+
+    /// Calculate `log(2^exp, radix)` as a float.
+    #[inline]
+    fn log(exp: i32, radix: u32) -> f64 {
+        exp as f64 * core::f64::consts::LN_2 / (radix as f64).ln()
+    }
+
+    /// Get the smallest exponent for a radix required to represent
+    /// a denormal float as a 64-bit mantissa scaled by a power.
+    ///
+    /// This is the exponent for the 64-bit mantissa `2^64 - 1`,
+    /// scaled to the smallest denormal value.
+    #[inline]
+    pub fn min_radix_exponent(&self, radix: u32) -> f64 {
+        Self::log(self.min_exponent, radix) - (u64::MAX as f64).ln() / (radix as f64).ln()
+    }
+
+    /// Get the largest exponent for a radix with a finite float.
+    #[inline]
+    pub fn max_radix_exponent(&self, radix: u32) -> f64 {
+        Self::log(self.max_exponent, radix)
+    }
+}
+
+/// Calculate a table for each radix on a separate thread.
+///
+/// The results are returned in the same order as the radixes.
+pub fn parallel_map<T, F>(radixes: &[u32], f: F) -> Vec<T>
+where
+    T: Send + 'static,
+    F: Fn(u32) -> T + Send + Sync + 'static,
+{
+    let f = Arc::new(f);
+    let handles: Vec<_> = radixes
+        .iter()
+        .map(|&radix| {
+            let f = Arc::clone(&f);
+            thread::spawn(move || f(radix))
+        })
+        .collect();
+    handles.into_iter().map(|handle| handle.join().expect("unable to generate table")).collect()
+}
+
+/// Write an array with a comment after each value.
+///
+/// The comments are aligned to the longest value.
+fn write_array<T: Display>(out: &mut String, definition: &str, values: &[T], comments: &[String]) {
+    debug_assert!(values.len() == comments.len());
+    let values: Vec<String> = values.iter().map(|x| format!("{},", x)).collect();
+    let width = values.iter().map(String::len).max().unwrap_or(0);
+    writeln!(out, "{} = [", definition).unwrap();
+    for (value, comment) in values.iter().zip(comments) {
+        writeln!(out, "    {:<width$} // {}", value, comment, width = width).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+/// Write an array on a single line.
+fn write_array_inline<T: Display>(out: &mut String, definition: &str, values: &[T]) {
+    let values: Vec<String> = values.iter().map(T::to_string).collect();
+    writeln!(out, "{} = [{}];", definition, values.join(", ")).unwrap();
+}
@@ -0,0 +1,99 @@
+//! Command-line tool to generate lexical's pre-computed power tables.
+//!
+//! The tables are written to stdout. For example, to regenerate the
+//! large powers for radix 5 with 32-bit limbs:
+//!
+//! ```bash
+//! cargo run --release -- large --limb-bits 32 5
+//! ```
+
+use lexical_tables::{bellerophon, large, lemire, FloatType, RADIXES};
+use std::env;
+use std::process;
+
+const USAGE: &str = "\
+Usage: lexical-tables <TABLE> [OPTIONS] [RADIX]...
+
+Tables:
+    bellerophon         Cached powers as 80-bit or 160-bit extended floats.
+    lemire              Powers of 5 as 128-bit significands.
+    large               Large powers as big-integer limbs.
+
+Options:
+    --float <TYPE>      Float type for the powers: f32 or f64 [default: f64].
+    --extended <BITS>   Size of the bellerophon extended floats: 80 or 160 [default: 80].
+    --limb-bits <BITS>  Only generate limbs of 8, 16, 32, 64, or 128 bits.
+    -h, --help          Print this message.
+
+If no radixes are provided, bellerophon uses the seed radixes, and large
+uses all radixes that are not powers of two.";
+
+/// Print an error message and exit.
+fn error(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    process::exit(1);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut table = None;
+    let mut float = FloatType::F64;
+    let mut mantissa_bits = 64;
+    let mut limb_bits = None;
+    let mut radixes = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            "--float" => {
+                float = match args.next().as_deref() {
+                    Some("f32") => FloatType::F32,
+                    Some("f64") => FloatType::F64,
+                    _ => error("--float must be f32 or f64"),
+                };
+            },
+            "--extended" => {
+                mantissa_bits = match args.next().as_deref() {
+                    Some("80") => 64,
+                    Some("160") => 128,
+                    _ => error("--extended must be 80 or 160"),
+                };
+            },
+            "--limb-bits" => {
+                limb_bits = match args.next().and_then(|x| x.parse::<u32>().ok()) {
+                    Some(bits) if [8, 16, 32, 64, 128].contains(&bits) => Some(bits),
+                    _ => error("--limb-bits must be 8, 16, 32, 64, or 128"),
+                };
+            },
+            _ if table.is_none() => table = Some(arg),
+            _ => match arg.parse::<u32>() {
+                Ok(radix @ 2..=36) => radixes.push(radix),
+                _ => error(&format!("invalid radix \"{}\"", arg)),
+            },
+        }
+    }
+
+    let code = match table.as_deref() {
+        Some("bellerophon") => {
+            if radixes.is_empty() {
+                radixes.extend_from_slice(&bellerophon::SEED_RADIXES);
+            }
+            if radixes.iter().any(|radix| radix.is_power_of_two()) {
+                error("bellerophon radixes must not be powers of two");
+            }
+            bellerophon::generate_with_mantissa_bits(&radixes, float, mantissa_bits)
+        },
+        Some("lemire") => lemire::generate(float),
+        Some("large") => {
+            if radixes.is_empty() {
+                radixes.extend_from_slice(&RADIXES);
+            }
+            large::generate(&radixes, limb_bits)
+        },
+        Some(table) => error(&format!("unknown table \"{}\"", table)),
+        None => error("a table is required"),
+    };
+    print!("{}", code);
+}
@@ -0,0 +1,94 @@
+mod source;
+
+use lexical_tables::bellerophon::{self, Powers};
+use lexical_tables::{lemire, FloatType};
+
+fn check_powers(source: &str, radix: u32) {
+    let powers = Powers::new(radix, FloatType::F64);
+    let name = |x: &str| format!("BASE{}_{}", radix, x);
+    let array = |x: &str| source::array(source, &format!("{}: [u64", name(x)));
+    let constant = |x: &str| source::constant(source, &format!("{}: ", name(x)));
+    let to_u128 = |x: &[u64]| x.iter().map(|&y| y as u128).collect::<Vec<_>>();
+
+    assert_eq!(powers.small, array("SMALL_MANTISSA"));
+    assert_eq!(powers.large, array("LARGE_MANTISSA"));
+    assert_eq!(to_u128(&powers.small_int), array("SMALL_INT_POWERS"));
+    assert_eq!(powers.step as i64, constant("STEP"));
+    assert_eq!(powers.bias as i64, constant("BIAS"));
+    assert_eq!(powers.log2, constant("LOG2_MULT"));
+    assert_eq!(powers.log2_shift as i64, constant("LOG2_SHIFT"));
+}
+
+#[test]
+fn decimal_test() {
+    let source = source::read("table_bellerophon_decimal.rs");
+    check_powers(&source, 10);
+}
+
+#[test]
+fn radix_test() {
+    let source = source::read("table_bellerophon_radix.rs");
+    for &radix in bellerophon::SEED_RADIXES.iter() {
+        check_powers(&source, radix);
+    }
+}
+
+#[test]
+fn step_test() {
+    assert_eq!(bellerophon::step(3), 20);
+    assert_eq!(bellerophon::step(10), 10);
+    assert_eq!(bellerophon::step(36), 6);
+}
+
+#[test]
+fn f32_test() {
+    let powers = Powers::new(10, FloatType::F32);
+    let f64_powers = Powers::new(10, FloatType::F64);
+    assert_eq!(powers.small, f64_powers.small);
+    assert!(powers.large.len() < f64_powers.large.len());
+    assert_eq!(powers.bias, 70);
+}
+
+#[test]
+fn generate_test() {
+    let code = bellerophon::generate(&[3, 5], FloatType::F64);
+    assert!(code.starts_with("// HIGH LEVEL\n"));
+    assert!(code.contains("pub const BASE5_POWERS: BellerophonPowers = BellerophonPowers {"));
+    assert!(code.contains("const BASE3_SMALL_INT_POWERS: [u64; 20] = [1, 3, 9,"));
+    assert!(code.contains("    9223372036854775808,  // 3^0\n"));
+    assert!(code.find("// BASE3").unwrap() < code.find("// BASE5").unwrap());
+}
+
+#[test]
+fn extended160_test() {
+    let powers = Powers::with_mantissa_bits(10, FloatType::F64, 128);
+    let f64_powers = Powers::new(10, FloatType::F64);
+    assert_eq!(powers.mantissa_bits, 128);
+    assert_eq!(powers.step, f64_powers.step);
+    assert_eq!(powers.log2, f64_powers.log2);
+
+    // Exact powers have the same high 64 bits as the 80-bit powers.
+    let small: Vec<u128> = powers.small.iter().map(|x| x >> 64).collect();
+    assert_eq!(small, f64_powers.small);
+    let one = (powers.bias / powers.step) as usize;
+    let f64_one = (f64_powers.bias / f64_powers.step) as usize;
+    assert_eq!(powers.large[one], 1 << 127);
+    let large: Vec<u128> = powers.large[one..].iter().map(|x| x >> 64).collect();
+    assert_eq!(large, f64_powers.large[f64_one..]);
+
+    // The positive powers of ten have the same mantissas as the powers of 5.
+    let lemire = lemire::Powers::new(FloatType::F64);
+    for (index, &mantissa) in powers.large[one..].iter().enumerate() {
+        let exp = index as i32 * powers.step;
+        if exp > lemire.largest_power {
+            break;
+        }
+        let power5 = lemire.powers[(exp - lemire.smallest_power) as usize];
+        assert_eq!(mantissa, power5);
+    }
+
+    let code = bellerophon::generate_with_mantissa_bits(&[3], FloatType::F64, 128);
+    assert!(code.contains("pub const BASE3_POWERS: BellerophonPowers160 = BellerophonPowers160 {"));
+    assert!(code.contains("const BASE3_SMALL_MANTISSA: [u128; 20] = ["));
+    assert!(code.contains("    170141183460469231731687303715884105728, // 3^0\n"));
+}
@@ -0,0 +1,46 @@
+use lexical_tables::bigint::Uint;
+
+#[test]
+fn pow_test() {
+    assert_eq!(Uint::pow(10, 0).to_u128(), Some(1));
+    assert_eq!(Uint::pow(10, 19).to_u128(), Some(10u128.pow(19)));
+    assert_eq!(Uint::pow(10, 38).to_u128(), Some(10u128.pow(38)));
+    assert_eq!(Uint::pow(10, 39).to_u128(), None);
+    assert_eq!(Uint::pow(3, 100).bit_length(), 159);
+}
+
+#[test]
+fn shift_test() {
+    let mut x = Uint::from_u64(0xF0);
+    x.shl(124);
+    assert_eq!(x.bit_length(), 132);
+    x.shr(64);
+    assert_eq!(x.to_u128(), Some(0xF0 << 60));
+    x.shr(200);
+    assert!(x.is_zero());
+    assert_eq!(Uint::power_of_two(127).to_u128(), Some(1 << 127));
+}
+
+#[test]
+fn div_test() {
+    let mut x = Uint::pow(7, 50);
+    assert_eq!(x.div_small(7u64.pow(20)), 0);
+    assert_eq!(x, Uint::pow(7, 30));
+    assert_eq!(x.div_small(10), 7u128.pow(30) as u64 % 10);
+
+    let x = Uint::pow(3, 200);
+    assert_eq!(x.div(&Uint::pow(3, 150)), Uint::pow(3, 50));
+    let mut y = Uint::pow(3, 200);
+    y.add_small(5);
+    assert_eq!(y.div(&Uint::pow(3, 199)).to_u128(), Some(3));
+    assert!(Uint::from_u64(5).div(&Uint::from_u64(6)).is_zero());
+}
+
+#[test]
+fn to_limbs_test() {
+    let x = Uint::from_u64(0x0102_0304_0506_0708);
+    assert_eq!(x.to_limbs(8), [8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(x.to_limbs(32), [0x0506_0708, 0x0102_0304]);
+    assert_eq!(x.to_limbs(128), [0x0102_0304_0506_0708]);
+    assert!(Uint::default().to_limbs(64).is_empty());
+}
@@ -0,0 +1,43 @@
+mod source;
+
+use lexical_tables::large::{self, Power};
+
+fn check_power(source: &str, radix: u32) {
+    let power = Power::new(radix);
+    let array = |bits: u32| source::array(source, &format!("LARGE_POW{}: [u{}", radix, bits));
+    assert_eq!(power.value.to_limbs(32), array(32));
+    assert_eq!(power.value.to_limbs(64), array(64));
+    assert_eq!(power.step as i64, source::constant(source, &format!("LARGE_POW{}_STEP: ", radix)));
+}
+
+#[test]
+fn decimal_test() {
+    check_power(&source::read("table_decimal.rs"), 5);
+}
+
+#[test]
+fn radix_test() {
+    let source = source::read("table_radix.rs");
+    for radix in (3..=35).step_by(2).filter(|&radix| radix != 5) {
+        check_power(&source, radix);
+    }
+}
+
+#[test]
+fn u64_power_limit_test() {
+    assert_eq!(large::u64_power_limit(2), 63);
+    assert_eq!(large::u64_power_limit(3), 40);
+    assert_eq!(large::u64_power_limit(10), 19);
+    assert_eq!(large::u64_power_limit(36), 12);
+}
+
+#[test]
+fn generate_test() {
+    let code = large::generate(&[5], Some(16));
+    assert!(code.starts_with("pub const LARGE_POW5: [u16; 20] = [\n    5933,\n    65307,\n"));
+
+    let code = large::generate(&[3, 5], None);
+    assert!(code.contains("pub const LARGE_POW3: [u32; 10] = [\n"));
+    assert!(code.contains("pub const LARGE_POW5: [u64; 5] = [\n"));
+    assert!(code.contains("pub const LARGE_POW5_STEP: u32 = 135;\n"));
+}
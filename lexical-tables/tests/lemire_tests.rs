@@ -0,0 +1,29 @@
+mod source;
+
+use lexical_tables::{lemire, FloatType};
+
+#[test]
+fn f64_test() {
+    let source = source::read("table_lemire.rs");
+    let code = lemire::generate(FloatType::F64);
+    let powers = |x: &str| {
+        x.lines().filter(|line| line.starts_with("    (0x")).map(String::from).collect::<Vec<_>>()
+    };
+    assert_eq!(powers(&code), powers(&source));
+    assert_eq!(source::constant(&source, "SMALLEST_POWER_OF_FIVE: "), -342);
+    assert!(code.contains("pub const SMALLEST_POWER_OF_FIVE: i32 = -342;\n"));
+    assert!(code.contains("pub const LARGEST_POWER_OF_FIVE: i32 = 308;\n"));
+}
+
+#[test]
+fn f32_test() {
+    let powers = lemire::Powers::new(FloatType::F32);
+    let f64_powers = lemire::Powers::new(FloatType::F64);
+    assert_eq!(powers.smallest_power, -64);
+    assert_eq!(powers.largest_power, 38);
+    assert_eq!(powers.powers.len(), 103);
+
+    // The powers are the same, just for a smaller range.
+    let offset = (powers.smallest_power - f64_powers.smallest_power) as usize;
+    assert_eq!(powers.powers[..], f64_powers.powers[offset..offset + 103]);
+}
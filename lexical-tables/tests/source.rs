@@ -0,0 +1,38 @@
+//! Read pre-computed tables from the lexical-parse-float sources.
+
+#![allow(dead_code)]
+
+use std::fs;
+
+/// Read a source file from lexical-parse-float.
+pub fn read(file: &str) -> String {
+    let path = format!("{}/../lexical-parse-float/src/{}", env!("CARGO_MANIFEST_DIR"), file);
+    fs::read_to_string(path).unwrap()
+}
+
+/// Get the source after the first occurrence of the definition.
+fn find<'a>(source: &'a str, definition: &str) -> &'a str {
+    let index = source.find(definition).unwrap_or_else(|| panic!("{} not found", definition));
+    &source[index + definition.len()..]
+}
+
+/// Parse the integer values of an array, such as `BASE3_STEP: [u64`.
+pub fn array(source: &str, definition: &str) -> Vec<u128> {
+    let body = find(source, definition);
+    let body = &body[body.find('=').unwrap()..];
+    let body = &body[body.find('[').unwrap() + 1..body.find("];").unwrap()];
+    body.lines()
+        .map(|line| line.split("//").next().unwrap())
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<u128>().unwrap())
+        .collect()
+}
+
+/// Parse the value of an integer constant, such as `BASE3_STEP: i32`.
+pub fn constant(source: &str, definition: &str) -> i64 {
+    let body = find(source, definition);
+    let body = &body[body.find('=').unwrap() + 1..body.find(';').unwrap()];
+    body.trim().parse::<i64>().unwrap()
+}
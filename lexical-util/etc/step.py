@@ -79,7 +79,7 @@ def main():
     '''Generate all the step sizes for given radixes.'''
 
     print_comment()
-    for radix in range(2, 37):
+    for radix in range(2, 63):
         print_power(radix)
 
 if __name__ == '__main__':
@@ -8,12 +8,17 @@
 // FROM LEXICAL
 
 /// Define FromLexical trait.
+///
+/// The supertraits may be given, and default to `Number`.
 #[macro_export]
 #[cfg(feature = "parse")]
 macro_rules! from_lexical {
     () => {
+        $crate::from_lexical!(lexical_util::num::Number);
+    };
+    ($($bound:tt)+) => {
         /// Trait for numerical types that can be parsed from bytes.
-        pub trait FromLexical: lexical_util::num::Number {
+        pub trait FromLexical: $($bound)+ {
             /// Checked parser for a string-to-number conversion.
             ///
             /// This method parses the entire string, returning an error if
@@ -40,14 +45,19 @@ macro_rules! from_lexical {
 }
 
 /// Define FromLexicalWithOptions trait.
+///
+/// The supertraits may be given, and default to `Number`.
 #[macro_export]
 #[cfg(feature = "parse")]
 macro_rules! from_lexical_with_options {
     () => {
+        $crate::from_lexical_with_options!(lexical_util::num::Number);
+    };
+    ($($bound:tt)+) => {
         /// Trait for numerical types that can be parsed from bytes with custom options.
         ///
         /// The `Options` type specifies the configurable options to provide.
-        pub trait FromLexicalWithOptions: lexical_util::num::Number {
+        pub trait FromLexicalWithOptions: $($bound)+ {
             /// Custom formatting options for parsing a number.
             type Options: lexical_util::options::ParseOptions;
 
@@ -108,10 +118,15 @@ macro_rules! from_lexical_with_options {
 // TO LEXICAL
 
 /// Define ToLexical trait.
+///
+/// The supertraits may be given, and default to `FormattedSize + Number`.
 #[macro_export]
 #[cfg(feature = "write")]
 macro_rules! to_lexical {
     () => {
+        $crate::to_lexical!(lexical_util::constants::FormattedSize + lexical_util::num::Number);
+    };
+    ($($bound:tt)+) => {
         /// Trait for numerical types that can be serialized to bytes.
         ///
         /// To determine the number of bytes required to serialize a value to
@@ -121,9 +136,7 @@ macro_rules! to_lexical {
         ///
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexical:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexical: $($bound)+ {
             /// Serializer for a number-to-string conversion.
             ///
             /// Returns a subslice of the input buffer containing the written bytes,
@@ -158,15 +171,34 @@ macro_rules! to_lexical {
             ///
             /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+            /// Checked serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written bytes,
+            /// starting from the same address in memory as the input slice, or
+            /// `None` if the buffer is not of sufficient size.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            ///
+            /// This never panics, and nothing is written if `None` is returned.
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8]) -> Option<&'a mut [u8]>;
         }
     };
 }
 
 /// Define ToLexicalWithOptions trait.
+///
+/// The supertraits may be given, and default to `FormattedSize + Number`.
 #[macro_export]
 #[cfg(feature = "write")]
 macro_rules! to_lexical_with_options {
     () => {
+        $crate::to_lexical_with_options!(
+            lexical_util::constants::FormattedSize + lexical_util::num::Number
+        );
+    };
+    ($($bound:tt)+) => {
         /// Trait for numerical types that can be serialized to bytes with custom options.
         ///
         /// To determine the number of bytes required to serialize a value to
@@ -178,9 +210,7 @@ macro_rules! to_lexical_with_options {
         ///
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexicalWithOptions:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexicalWithOptions: $($bound)+ {
             /// Custom formatting options for writing a number.
             type Options: lexical_util::options::WriteOptions;
 
@@ -288,6 +318,31 @@ macro_rules! to_lexical_with_options {
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8];
+
+            /// Checked serializer for a number-to-string conversion.
+            ///
+            /// Returns a subslice of the input buffer containing the written bytes,
+            /// starting from the same address in memory as the input slice, or
+            /// `None` if the number cannot be written.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `options` - Options for number formatting.
+            ///
+            /// This never panics: every case where [`to_lexical_with_options`]
+            /// would panic returns `None` instead, including a buffer smaller
+            /// than [`WriteOptions::buffer_size`], an invalid number format,
+            /// or writing NaN or Inf when the NaN or Inf string is disabled.
+            /// Nothing is written if `None` is returned.
+            ///
+            /// [`to_lexical_with_options`]: Self::to_lexical_with_options
+            /// [`WriteOptions::buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Option<&'a mut [u8]>;
         }
     };
 }
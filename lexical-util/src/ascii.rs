@@ -43,3 +43,14 @@ pub const fn is_valid_letter_slice(slc: &[u8]) -> bool {
     }
     true
 }
+
+/// Trim the leading and trailing bytes for which `skip` returns `true`.
+///
+/// Returns the index of the first untrimmed byte, and the untrimmed bytes.
+/// If all bytes are trimmed, the bytes are empty.
+#[inline]
+pub fn trim_skipped(bytes: &[u8], skip: fn(u8) -> bool) -> (usize, &[u8]) {
+    let start = bytes.iter().position(|&c| !skip(c)).unwrap_or(bytes.len());
+    let end = bytes.iter().rposition(|&c| !skip(c)).map_or(start, |index| index + 1);
+    (start, &bytes[start..end])
+}
@@ -6,11 +6,11 @@ use crate::format::NumberFormat;
 
 // RADIX
 
-/// Check radix is in range `[2, 36]` in debug builds.
+/// Check radix is in range `[2, 62]` in debug builds.
 #[inline]
 #[cfg(feature = "radix")]
 pub fn debug_assert_radix(radix: u32) {
-    debug_assert!((2..=36).contains(&radix), "Numerical base must be from 2-36.");
+    debug_assert!((2..=62).contains(&radix), "Numerical base must be from 2-62.");
 }
 
 /// Check radix is is 10 or a power of 2.
@@ -27,13 +27,13 @@ pub fn debug_assert_radix(radix: u32) {
     debug_assert!(radix == 10, "Numerical base must be 10.");
 }
 
-/// Assert radix is in range `[2, 36]`.
+/// Assert radix is in range `[2, 62]`.
 #[inline]
 #[cfg(feature = "radix")]
 pub fn assert_radix<const FORMAT: u128>() {
     assert!(
-        (2..=36).contains(&NumberFormat::<{ FORMAT }>::RADIX),
-        "Numerical base must be from 2-36."
+        (2..=62).contains(&NumberFormat::<{ FORMAT }>::RADIX),
+        "Numerical base must be from 2-62."
     );
 }
 
@@ -91,3 +91,16 @@ pub fn assert_buffer<T: FormattedSize>(_: u32, len: usize) {
         "Buffer is too small: may overwrite buffer, panicking!"
     );
 }
+
+// INVARIANTS
+
+/// Debug assertion an operation that cannot fail succeeded.
+///
+/// This is used for invariants the compiler cannot prove, such as
+/// big integers having enough capacity for any input, so release
+/// builds do not contain unreachable panics.
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn debug_assert_some<T>(value: Option<T>) {
+    debug_assert!(value.is_some(), "Operation failed despite its invariants.");
+}
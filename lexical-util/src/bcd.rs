@@ -0,0 +1,335 @@
+//! Big-endian BCD and packed decimal encodings.
+//!
+//! Mainframe records store decimals as binary-coded decimal (BCD), with
+//! two digits per byte, most-significant first. Packed decimal, or COBOL
+//! `COMP-3`, also stores the sign in the low nibble of the last byte,
+//! so a field of `n` bytes has `2n - 1` digits. Neither stores a decimal
+//! point: the number of fraction digits, or scale, is implied by the
+//! record layout, such as `2` for `PIC S9(7)V99`.
+//!
+//! Decimal strings are an optional sign, the integer digits, and an
+//! optional decimal point and fraction digits, such as `-1234.50`.
+//! Conversions are exact: fraction digits beyond the scale must be
+//! zeros, and numbers with too many digits are errors.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_util::bcd;
+//!
+//! # pub fn main() {
+//! // `PIC S9(5)V99 COMP-3`, or 7 digits and a sign in 4 bytes.
+//! let mut packed = [0u8; 4];
+//! bcd::to_packed(b"-123.4", 2, &mut packed).unwrap();
+//! assert_eq!(packed, [0x00, 0x12, 0x34, 0x0D]);
+//!
+//! let mut buffer = [0u8; bcd::text_size(4, 2)];
+//! let count = bcd::from_packed(&packed, 2, &mut buffer).unwrap();
+//! assert_eq!(&buffer[..count], b"-123.40");
+//! # }
+//! ```
+
+#![cfg(feature = "bcd")]
+
+use crate::digit::char_to_digit_const;
+use crate::error::Error;
+use crate::result::Result;
+
+/// Sign nibble for positive packed decimals.
+pub const POSITIVE: u8 = 0xC;
+
+/// Sign nibble for negative packed decimals.
+pub const NEGATIVE: u8 = 0xD;
+
+/// Sign nibble for unsigned packed decimals, which are positive.
+pub const UNSIGNED: u8 = 0xF;
+
+/// Get an upper bound on the length of the decimal string for a field.
+///
+/// This is large enough for any BCD or packed decimal field of `len`
+/// bytes, with `scale` fraction digits.
+#[inline(always)]
+pub const fn text_size(len: usize, scale: usize) -> usize {
+    // Sign, digits, decimal point, leading zero, and padding for the scale.
+    2 * len + scale + 3
+}
+
+/// Convert a decimal string to a packed decimal field, or `COMP-3`.
+///
+/// The digits are right-aligned in the field and padded with leading
+/// zeros, and the sign nibble is [`POSITIVE`] or [`NEGATIVE`]. The
+/// number is scaled by `10^scale`, so the decimal point is implied.
+///
+/// # Errors
+///
+/// Returns [`Error::Overflow`] if the number has more than `2n - 1`
+/// significant digits for a field of `n` bytes, or the field is empty,
+/// [`Error::InvalidDigit`] if the string is invalid or has non-zero
+/// digits beyond the scale.
+#[inline]
+pub fn to_packed(text: &[u8], scale: usize, packed: &mut [u8]) -> Result<()> {
+    encode(text, scale, packed, true)
+}
+
+/// Convert a packed decimal field, or `COMP-3`, to a decimal string.
+///
+/// Returns the number of bytes written. Leading zeros are removed, but
+/// all `scale` fraction digits are written, and the sign is kept, even
+/// for zero. The sign nibbles `A`, `C`, `E`, and `F` are positive, and
+/// `B` and `D` are negative.
+///
+/// # Errors
+///
+/// Returns [`Error::Empty`] if the field is empty, or
+/// [`Error::InvalidDigit`], with the index of the byte, if a digit
+/// nibble is above 9 or the sign nibble is invalid.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than the written string. A buffer
+/// of [`text_size`] bytes is always large enough.
+#[inline]
+pub fn from_packed(packed: &[u8], scale: usize, text: &mut [u8]) -> Result<usize> {
+    decode(packed, scale, text, true)
+}
+
+/// Convert a decimal string to an unsigned, big-endian BCD field.
+///
+/// The digits are right-aligned in the field and padded with leading
+/// zeros. The number is scaled by `10^scale`, so the decimal point is
+/// implied.
+///
+/// # Errors
+///
+/// Returns [`Error::Overflow`] if the number has more than `2n`
+/// significant digits for a field of `n` bytes,
+/// [`Error::InvalidNegativeSign`] if the number is negative, or
+/// [`Error::InvalidDigit`] if the string is invalid or has non-zero
+/// digits beyond the scale.
+#[inline]
+pub fn to_bcd(text: &[u8], scale: usize, bcd: &mut [u8]) -> Result<()> {
+    encode(text, scale, bcd, false)
+}
+
+/// Convert an unsigned, big-endian BCD field to a decimal string.
+///
+/// Returns the number of bytes written. Leading zeros are removed, but
+/// all `scale` fraction digits are written.
+///
+/// # Errors
+///
+/// Returns [`Error::Empty`] if the field is empty, or
+/// [`Error::InvalidDigit`], with the index of the byte, if a nibble
+/// is above 9.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than the written string. A buffer
+/// of [`text_size`] bytes is always large enough.
+#[inline]
+pub fn from_bcd(bcd: &[u8], scale: usize, text: &mut [u8]) -> Result<usize> {
+    decode(bcd, scale, text, false)
+}
+
+// ENCODE
+// ------
+
+/// Bounds of the components of a decimal string.
+struct Decimal {
+    /// If the number has a negative sign.
+    is_negative: bool,
+    /// Index of the first integer digit.
+    integer: usize,
+    /// Number of integer digits.
+    integer_len: usize,
+    /// Index of the first fraction digit.
+    fraction: usize,
+    /// Number of fraction digits.
+    fraction_len: usize,
+}
+
+impl Decimal {
+    /// Validate the decimal string and find its components.
+    fn parse(text: &[u8]) -> Result<Self> {
+        let (is_negative, integer) = match text.first() {
+            None => return Err(Error::Empty(0)),
+            Some(&b'-') => (true, 1),
+            Some(&b'+') => (false, 1),
+            Some(_) => (false, 0),
+        };
+        let integer_len = count_digits(&text[integer..]);
+        let mut fraction = integer + integer_len;
+        let mut fraction_len = 0;
+        if text.get(fraction) == Some(&b'.') {
+            fraction += 1;
+            fraction_len = count_digits(&text[fraction..]);
+        }
+
+        let end = fraction + fraction_len;
+        if integer_len + fraction_len == 0 {
+            Err(Error::Empty(end))
+        } else if end != text.len() {
+            Err(Error::InvalidDigit(end))
+        } else {
+            Ok(Self {
+                is_negative,
+                integer,
+                integer_len,
+                fraction,
+                fraction_len,
+            })
+        }
+    }
+
+    /// Get the number of digits of the number scaled by `10^scale`.
+    #[inline(always)]
+    fn scaled_len(&self, scale: usize) -> usize {
+        self.integer_len + scale
+    }
+
+    /// Get the index of a digit of the number scaled by `10^scale`,
+    /// or `None` if it is a padding zero after the fraction digits.
+    #[inline(always)]
+    fn index(&self, index: usize) -> Option<usize> {
+        if index < self.integer_len {
+            Some(self.integer + index)
+        } else if index - self.integer_len < self.fraction_len {
+            Some(self.fraction + index - self.integer_len)
+        } else {
+            None
+        }
+    }
+}
+
+/// Count the leading decimal digits.
+#[inline(always)]
+fn count_digits(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&c| char_to_digit_const(c, 10).is_some()).count()
+}
+
+/// Get the value of a decimal digit, which must be valid.
+#[inline(always)]
+fn to_digit(c: u8) -> u8 {
+    char_to_digit_const(c, 10).unwrap_or(0) as u8
+}
+
+/// Convert a decimal string to a BCD or packed decimal field.
+fn encode(text: &[u8], scale: usize, bytes: &mut [u8], is_signed: bool) -> Result<()> {
+    let decimal = Decimal::parse(text)?;
+    if decimal.is_negative && !is_signed {
+        return Err(Error::InvalidNegativeSign(0));
+    }
+    // Fraction digits beyond the scale would be truncated.
+    if let Some(index) = (scale..decimal.fraction_len)
+        .map(|index| decimal.fraction + index)
+        .find(|&index| text[index] != b'0')
+    {
+        return Err(Error::InvalidDigit(index));
+    }
+
+    // Check the significant digits fit in the field.
+    let len = decimal.scaled_len(scale);
+    let leading_zeros = (0..len)
+        .take_while(|&index| match decimal.index(index) {
+            Some(index) => text[index] == b'0',
+            None => true,
+        })
+        .count();
+    let capacity = (2 * bytes.len()).saturating_sub(is_signed as usize);
+    if len - leading_zeros > capacity || (is_signed && bytes.is_empty()) {
+        // The first significant digit is never a padding zero.
+        let index = decimal.index(leading_zeros).unwrap_or(decimal.integer);
+        return Err(Error::Overflow(index));
+    }
+
+    // Write the nibbles from the least-significant, after the sign.
+    for byte in bytes.iter_mut() {
+        *byte = 0;
+    }
+    let offset = is_signed as usize;
+    if is_signed {
+        set_nibble(
+            bytes,
+            0,
+            if decimal.is_negative {
+                NEGATIVE
+            } else {
+                POSITIVE
+            },
+        );
+    }
+    for (nibble, index) in (leading_zeros..len).rev().enumerate() {
+        let digit = decimal.index(index).map_or(0, |index| to_digit(text[index]));
+        set_nibble(bytes, nibble + offset, digit);
+    }
+    Ok(())
+}
+
+/// Set a nibble, counted from the least-significant nibble of the last byte.
+#[inline(always)]
+fn set_nibble(bytes: &mut [u8], nibble: usize, value: u8) {
+    let index = bytes.len() - 1 - nibble / 2;
+    bytes[index] |= value << (4 * (nibble % 2));
+}
+
+// DECODE
+// ------
+
+/// Get a nibble, counted from the most-significant nibble of the first byte.
+#[inline(always)]
+fn get_nibble(bytes: &[u8], nibble: usize) -> u8 {
+    let shift = 4 * (1 - nibble % 2);
+    (bytes[nibble / 2] >> shift) & 0xF
+}
+
+/// Convert a BCD or packed decimal field to a decimal string.
+fn decode(bytes: &[u8], scale: usize, text: &mut [u8], is_signed: bool) -> Result<usize> {
+    if bytes.is_empty() {
+        return Err(Error::Empty(0));
+    }
+    let digits = 2 * bytes.len() - is_signed as usize;
+    if let Some(nibble) = (0..digits).find(|&nibble| get_nibble(bytes, nibble) > 9) {
+        return Err(Error::InvalidDigit(nibble / 2));
+    }
+    let is_negative = if is_signed {
+        match bytes[bytes.len() - 1] & 0xF {
+            0xA | 0xC | 0xE | 0xF => false,
+            0xB | 0xD => true,
+            _ => return Err(Error::InvalidDigit(bytes.len() - 1)),
+        }
+    } else {
+        false
+    };
+
+    let mut count = 0;
+    let mut push = |c: u8| {
+        text[count] = c;
+        count += 1;
+    };
+    if is_negative {
+        push(b'-');
+    }
+
+    // Write the integer digits, without leading zeros.
+    let integer_len = digits.saturating_sub(scale);
+    let leading_zeros =
+        (0..integer_len).take_while(|&nibble| get_nibble(bytes, nibble) == 0).count();
+    if leading_zeros == integer_len {
+        push(b'0');
+    }
+    for nibble in leading_zeros..integer_len {
+        push(b'0' + get_nibble(bytes, nibble));
+    }
+
+    // Write the fraction digits, padded to the scale.
+    if scale != 0 {
+        push(b'.');
+        for _ in digits..scale {
+            push(b'0');
+        }
+        for nibble in integer_len..digits {
+            push(b'0' + get_nibble(bytes, nibble));
+        }
+    }
+    Ok(count)
+}
@@ -0,0 +1,428 @@
+//! Parse and write booleans, with configurable strings.
+//!
+//! Boolean columns in tables are commonly stored as `true` and `false`,
+//! `1` and `0`, or `yes` and `no`, next to the numeric columns. The
+//! strings are compared byte-by-byte, so parsing a boolean is a prefix
+//! comparison, and writing a boolean is a single copy.
+
+#![cfg(any(feature = "parse", feature = "write"))]
+
+#[cfg(feature = "write")]
+use crate::constants::FormattedSize;
+use crate::error::Error;
+#[cfg(feature = "parse")]
+use crate::options::ParseOptions;
+#[cfg(feature = "write")]
+use crate::options::WriteOptions;
+use crate::result::Result;
+use static_assertions::const_assert;
+
+/// Maximum length for a boolean string.
+const MAX_BOOL_STRING_LENGTH: usize = 50;
+
+/// Determine if two strings are equal, as a const fn.
+const fn is_equal(x: &[u8], y: &[u8]) -> bool {
+    if x.len() != y.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < x.len() {
+        if x[index] != y[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Builder for `BoolOptions`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BoolOptionsBuilder {
+    /// String for a true value.
+    true_string: &'static [u8],
+    /// String for a false value.
+    false_string: &'static [u8],
+    /// Compare the strings case-sensitively when parsing.
+    case_sensitive: bool,
+}
+
+impl BoolOptionsBuilder {
+    /// Create new options builder with default options.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            true_string: b"true",
+            false_string: b"false",
+            case_sensitive: true,
+        }
+    }
+
+    // GETTERS
+
+    /// Get the string for a true value.
+    #[inline(always)]
+    pub const fn get_true_string(&self) -> &'static [u8] {
+        self.true_string
+    }
+
+    /// Get the string for a false value.
+    #[inline(always)]
+    pub const fn get_false_string(&self) -> &'static [u8] {
+        self.false_string
+    }
+
+    /// Get if the strings are compared case-sensitively when parsing.
+    #[inline(always)]
+    pub const fn get_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    // SETTERS
+
+    /// Set the string for a true value.
+    #[inline(always)]
+    pub const fn true_string(mut self, true_string: &'static [u8]) -> Self {
+        self.true_string = true_string;
+        self
+    }
+
+    /// Set the string for a false value.
+    #[inline(always)]
+    pub const fn false_string(mut self, false_string: &'static [u8]) -> Self {
+        self.false_string = false_string;
+        self
+    }
+
+    /// Set if the strings are compared case-sensitively when parsing.
+    ///
+    /// Case-insensitive comparisons only ignore the case of ASCII letters.
+    #[inline(always)]
+    pub const fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    // BUILDERS
+
+    /// Check if the builder state is valid.
+    #[inline(always)]
+    pub const fn is_valid(&self) -> bool {
+        let true_length = self.true_string.len();
+        let false_length = self.false_string.len();
+        true_length != 0
+            && false_length != 0
+            && true_length <= MAX_BOOL_STRING_LENGTH
+            && false_length <= MAX_BOOL_STRING_LENGTH
+            && !is_equal(self.true_string, self.false_string)
+    }
+
+    /// Build the BoolOptions struct without validation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    /// The result may be invalid if `is_valid` is not true.
+    #[inline(always)]
+    pub const unsafe fn build_unchecked(&self) -> BoolOptions {
+        BoolOptions {
+            true_string: self.true_string,
+            false_string: self.false_string,
+            case_sensitive: self.case_sensitive,
+        }
+    }
+
+    /// Build the BoolOptions struct.
+    ///
+    /// Case-insensitive strings that only differ by case are accepted,
+    /// and are parsed as the true value.
+    #[inline(always)]
+    pub const fn build(&self) -> Result<BoolOptions> {
+        let true_length = self.true_string.len();
+        let false_length = self.false_string.len();
+        if true_length == 0 || false_length == 0 || is_equal(self.true_string, self.false_string) {
+            Err(Error::InvalidBoolString)
+        } else if true_length > MAX_BOOL_STRING_LENGTH || false_length > MAX_BOOL_STRING_LENGTH {
+            Err(Error::BoolStringTooLong)
+        } else {
+            // SAFETY: always safe, since it must be valid.
+            Ok(unsafe { self.build_unchecked() })
+        }
+    }
+}
+
+impl Default for BoolOptionsBuilder {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Immutable options to customize parsing and writing booleans.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::boolean::BoolOptions;
+///
+/// # pub fn main() {
+/// let options = BoolOptions::builder()
+///     .true_string(b"yes")
+///     .false_string(b"no")
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BoolOptions {
+    /// String for a true value.
+    true_string: &'static [u8],
+    /// String for a false value.
+    false_string: &'static [u8],
+    /// Compare the strings case-sensitively when parsing.
+    case_sensitive: bool,
+}
+
+impl BoolOptions {
+    /// Create options with default values.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        // SAFETY: always safe since it uses the default arguments.
+        unsafe { Self::builder().build_unchecked() }
+    }
+
+    /// Check if the options state is valid.
+    #[inline(always)]
+    pub const fn is_valid(&self) -> bool {
+        self.rebuild().is_valid()
+    }
+
+    // GETTERS
+
+    /// Get the string for a true value.
+    #[inline(always)]
+    pub const fn true_string(&self) -> &'static [u8] {
+        self.true_string
+    }
+
+    /// Get the string for a false value.
+    #[inline(always)]
+    pub const fn false_string(&self) -> &'static [u8] {
+        self.false_string
+    }
+
+    /// Get if the strings are compared case-sensitively when parsing.
+    #[inline(always)]
+    pub const fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    // SETTERS
+
+    /// Set the string for a true value.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the string is
+    /// empty, too long, or equal to the false string.
+    #[inline(always)]
+    pub unsafe fn set_true_string(&mut self, true_string: &'static [u8]) {
+        self.true_string = true_string
+    }
+
+    /// Set the string for a false value.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the string is
+    /// empty, too long, or equal to the true string.
+    #[inline(always)]
+    pub unsafe fn set_false_string(&mut self, false_string: &'static [u8]) {
+        self.false_string = false_string
+    }
+
+    /// Set if the strings are compared case-sensitively when parsing.
+    #[inline(always)]
+    pub fn set_case_sensitive(&mut self, case_sensitive: bool) {
+        self.case_sensitive = case_sensitive
+    }
+
+    // BUILDERS
+
+    /// Get BoolOptionsBuilder as a static function.
+    #[inline(always)]
+    pub const fn builder() -> BoolOptionsBuilder {
+        BoolOptionsBuilder::new()
+    }
+
+    /// Create BoolOptionsBuilder using existing values.
+    #[inline(always)]
+    pub const fn rebuild(&self) -> BoolOptionsBuilder {
+        BoolOptionsBuilder {
+            true_string: self.true_string,
+            false_string: self.false_string,
+            case_sensitive: self.case_sensitive,
+        }
+    }
+
+    /// Get the string for a value.
+    #[inline(always)]
+    pub const fn string(&self, value: bool) -> &'static [u8] {
+        if value {
+            self.true_string
+        } else {
+            self.false_string
+        }
+    }
+}
+
+impl Default for BoolOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ParseOptions for BoolOptions {
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        Self::is_valid(self)
+    }
+
+    #[inline(always)]
+    fn validate(&self) -> Result<()> {
+        self.rebuild().build().map(|_| ())
+    }
+}
+
+#[cfg(feature = "write")]
+impl WriteOptions for BoolOptions {
+    #[inline(always)]
+    fn is_valid(&self) -> bool {
+        Self::is_valid(self)
+    }
+
+    #[inline(always)]
+    fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
+        self.true_string.len().max(self.false_string.len())
+    }
+}
+
+// PARSE
+// -----
+
+/// Get the number of leading bytes that match the string.
+#[cfg(feature = "parse")]
+#[inline(always)]
+fn matching_prefix(bytes: &[u8], string: &[u8], case_sensitive: bool) -> usize {
+    bytes
+        .iter()
+        .zip(string.iter())
+        .take_while(|&(x, y)| {
+            if case_sensitive {
+                x == y
+            } else {
+                x.eq_ignore_ascii_case(y)
+            }
+        })
+        .count()
+}
+
+/// Parse a boolean from the start of the bytes.
+///
+/// Returns the value and the number of bytes processed. If both strings
+/// match, such as `t` and `tt`, the longest string is used. If neither
+/// string matches, returns [`Error::InvalidDigit`] at the index of the
+/// first byte that matches neither string.
+#[cfg(feature = "parse")]
+pub fn parse_bool_partial(bytes: &[u8], options: &BoolOptions) -> Result<(bool, usize)> {
+    if bytes.is_empty() {
+        return Err(Error::Empty(0));
+    }
+    let true_string = options.true_string();
+    let false_string = options.false_string();
+    let true_count = matching_prefix(bytes, true_string, options.case_sensitive());
+    let false_count = matching_prefix(bytes, false_string, options.case_sensitive());
+    let is_true = true_count == true_string.len();
+    let is_false = false_count == false_string.len();
+    match (is_true, is_false) {
+        (true, true) if false_count > true_count => Ok((false, false_count)),
+        (true, _) => Ok((true, true_count)),
+        (false, true) => Ok((false, false_count)),
+        (false, false) => Err(Error::InvalidDigit(true_count.max(false_count))),
+    }
+}
+
+/// Parse a boolean from the complete bytes.
+///
+/// Returns [`Error::InvalidDigit`] at the index of the first byte that
+/// does not match the string, or any byte after the string.
+#[cfg(feature = "parse")]
+pub fn parse_bool(bytes: &[u8], options: &BoolOptions) -> Result<bool> {
+    let (value, count) = parse_bool_partial(bytes, options)?;
+    if count == bytes.len() {
+        Ok(value)
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
+
+// WRITE
+// -----
+
+/// Write a boolean, returning the written bytes, or `None` if the buffer is too small.
+#[cfg(feature = "write")]
+#[inline]
+pub fn try_write_bool<'a>(
+    value: bool,
+    bytes: &'a mut [u8],
+    options: &BoolOptions,
+) -> Option<&'a mut [u8]> {
+    let string = options.string(value);
+    let bytes = bytes.get_mut(..string.len())?;
+    bytes.copy_from_slice(string);
+    Some(bytes)
+}
+
+/// Write a boolean, returning the written bytes.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than the string for the value.
+#[cfg(feature = "write")]
+#[inline]
+pub fn write_bool<'a>(value: bool, bytes: &'a mut [u8], options: &BoolOptions) -> &'a mut [u8] {
+    let string = options.string(value);
+    let bytes = &mut bytes[..string.len()];
+    bytes.copy_from_slice(string);
+    bytes
+}
+
+// PRE-DEFINED CONSTANTS
+// ---------------------
+
+/// Booleans as `true` and `false`.
+#[rustfmt::skip]
+pub const STANDARD: BoolOptions = BoolOptions::new();
+const_assert!(STANDARD.is_valid());
+
+/// Booleans as `1` and `0`.
+#[rustfmt::skip]
+pub const NUMERIC: BoolOptions = unsafe {
+    BoolOptions::builder()
+        .true_string(b"1")
+        .false_string(b"0")
+        .build_unchecked()
+};
+const_assert!(NUMERIC.is_valid());
+
+/// Booleans as `yes` and `no`, in any case.
+#[rustfmt::skip]
+pub const YES_NO: BoolOptions = unsafe {
+    BoolOptions::builder()
+        .true_string(b"yes")
+        .false_string(b"no")
+        .case_sensitive(false)
+        .build_unchecked()
+};
+const_assert!(YES_NO.is_valid());
@@ -0,0 +1,27 @@
+//! Read NUL-terminated C strings.
+//!
+//! Parsers replacing `strtod` or `strtol` receive pointers to strings
+//! terminated by a NUL byte, rather than byte slices with a length.
+
+#![cfg(all(feature = "parse", feature = "std"))]
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Get the bytes in a NUL-terminated C string, excluding the terminator.
+///
+/// A null pointer is treated as an empty string.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a NUL-terminated string that is
+/// valid for reads and not modified for the lifetime `'a`.
+#[inline(always)]
+pub unsafe fn c_str_to_bytes<'a>(ptr: *const c_char) -> &'a [u8] {
+    if ptr.is_null() {
+        &[]
+    } else {
+        // SAFETY: safe, since the pointer is a valid, NUL-terminated string.
+        unsafe { CStr::from_ptr(ptr).to_bytes() }
+    }
+}
@@ -0,0 +1,522 @@
+//! Canonical forms of decimal numeric strings.
+//!
+//! Numbers stored as text may represent the same value with different
+//! strings, such as `1000`, `1e3`, `+1000.00`, or `01E+03`. This parses
+//! the structure of a decimal number, without converting it to binary,
+//! so values can be compared exactly, regardless of precision.
+//!
+//! The canonical form is in scientific notation, with a single leading
+//! digit, no trailing zeros in the fraction, and a lowercase `e`, which
+//! is omitted if the exponent is 0. Zero is always written as `0`, and
+//! has no sign. For example, `-0012.50e1` is written as `-1.25e2`.
+//!
+//! Exponents larger than `2^62` in magnitude, far beyond the range of any
+//! float, are rejected, so numbers are always compared exactly. They may
+//! be saturated instead when converting to floats, which is exact, since
+//! the number overflows or underflows either way.
+//!
+//! Numbers may also be quantized, or rounded to a fixed number of
+//! fractional digits, exactly in decimal, as required for currencies.
+
+#![cfg(feature = "parse")]
+#![doc(hidden)]
+
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::format_flags::is_valid_options_punctuation;
+use crate::result::Result;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::hash::Hasher;
+
+/// Maximum magnitude of an exponent, to avoid overflow when scaling it.
+const MAX_EXPONENT: i64 = 1 << 62;
+
+/// How to round a number to a fixed number of fractional digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest value, with ties to the even digit.
+    HalfEven,
+    /// Round to the nearest value, with ties away from zero.
+    HalfUp,
+    /// Round to the nearest value, with ties toward zero.
+    HalfDown,
+    /// Round away from zero.
+    Up,
+    /// Round toward zero, truncating the digits.
+    Down,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+}
+
+/// The structure of a decimal numeric string.
+///
+/// The digit separator of the format is ignored anywhere within the
+/// digits. The significant digits are stored as a slice of the original
+/// bytes, which may contain the decimal point or digit separators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumericStr<'a> {
+    /// If the number is negative.
+    is_negative: bool,
+    /// Slice from the first to the last non-zero digit, empty for zero.
+    significant: &'a [u8],
+    /// Decimal exponent of the first significant digit.
+    exponent: i64,
+    /// Decimal exponent of the last digit, including trailing zeros.
+    quantum: i64,
+}
+
+/// Get the length of the leading digits and digit separators.
+#[inline]
+fn scan_digits(bytes: &[u8], separator: u8) -> (usize, usize) {
+    let mut length = 0;
+    let mut count = 0;
+    for &c in bytes {
+        if c.is_ascii_digit() {
+            count += 1;
+        } else if separator == 0 || c != separator {
+            break;
+        }
+        length += 1;
+    }
+    (length, count)
+}
+
+impl<'a> NumericStr<'a> {
+    /// Parse the structure of a decimal numeric string.
+    ///
+    /// This accepts an optional sign, integer and fraction digits
+    /// separated by `decimal_point`, and an optional exponent with a
+    /// sign. Special values, such as `NaN` or `inf`, are not accepted.
+    /// The format must have a mantissa radix and exponent base of 10.
+    ///
+    /// Either the integer or the fraction digits may be empty, as in
+    /// `.5` or `5.`, unless the format requires integer or fraction digits.
+    /// Returns [`Error::ExponentOverflow`] if the exponent is larger than
+    /// `2^62` in magnitude.
+    #[inline]
+    pub fn parse<const FORMAT: u128>(
+        bytes: &'a [u8],
+        exponent: u8,
+        decimal_point: u8,
+    ) -> Result<Self> {
+        Self::parse_impl::<FORMAT>(bytes, exponent, decimal_point, false)
+    }
+
+    /// Parse the structure of a decimal numeric string, saturating the exponent.
+    ///
+    /// This is like [`parse`](Self::parse), but saturates exponents larger
+    /// than `2^62` in magnitude, so numbers with larger exponents may be
+    /// considered equal. This is only meant for conversions to floats.
+    #[inline]
+    pub fn parse_saturating<const FORMAT: u128>(
+        bytes: &'a [u8],
+        exponent: u8,
+        decimal_point: u8,
+    ) -> Result<Self> {
+        Self::parse_impl::<FORMAT>(bytes, exponent, decimal_point, true)
+    }
+
+    /// Parse the structure of a decimal numeric string.
+    fn parse_impl<const FORMAT: u128>(
+        bytes: &'a [u8],
+        exponent: u8,
+        decimal_point: u8,
+        is_saturating: bool,
+    ) -> Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if format.mantissa_radix() != 10 {
+            return Err(Error::InvalidMantissaRadix);
+        } else if format.exponent_base() != 10 {
+            return Err(Error::InvalidExponentBase);
+        } else if !is_valid_options_punctuation(FORMAT, exponent, decimal_point) {
+            return Err(Error::InvalidPunctuation);
+        }
+        let separator = format.digit_separator();
+
+        // Parse the sign and the integer and fraction digits.
+        let (is_negative, mut index) = match bytes.first() {
+            Some(&b'+') => (false, 1),
+            Some(&b'-') => (true, 1),
+            _ => (false, 0),
+        };
+        let mantissa_start = index;
+        let (length, integer_count) = scan_digits(&bytes[index..], separator);
+        if format.required_integer_digits() && integer_count == 0 && index != bytes.len() {
+            return Err(Error::EmptyInteger(index));
+        }
+        index += length;
+        let mut fraction_count = 0;
+        if bytes.get(index) == Some(&decimal_point) {
+            let (length, count) = scan_digits(&bytes[index + 1..], separator);
+            index += 1 + length;
+            fraction_count = count;
+            if format.required_fraction_digits() && fraction_count == 0 {
+                return Err(Error::EmptyFraction(index));
+            }
+        }
+        let mantissa = &bytes[mantissa_start..index];
+        let fraction_count = fraction_count as i64;
+        if integer_count == 0 && fraction_count == 0 {
+            return Err(if index == bytes.len() {
+                Error::Empty(index)
+            } else {
+                Error::EmptyMantissa(index)
+            });
+        }
+
+        // Parse the exponent, which is either rejected or saturated on overflow.
+        let mut explicit_exponent = 0i64;
+        if let Some(&c) = bytes.get(index) {
+            let is_exponent = if format.case_sensitive_exponent() {
+                c == exponent
+            } else {
+                c.eq_ignore_ascii_case(&exponent)
+            };
+            if is_exponent {
+                index += 1;
+                let is_negative_exponent = match bytes.get(index) {
+                    Some(&b'+') => {
+                        index += 1;
+                        false
+                    },
+                    Some(&b'-') => {
+                        index += 1;
+                        true
+                    },
+                    _ => false,
+                };
+                let (length, count) = scan_digits(&bytes[index..], separator);
+                if count == 0 {
+                    return Err(Error::EmptyExponent(index));
+                }
+                for &c in bytes[index..index + length].iter().filter(|c| c.is_ascii_digit()) {
+                    let digit = (c - b'0') as i64;
+                    let value = explicit_exponent.saturating_mul(10).saturating_add(digit);
+                    if value > MAX_EXPONENT && !is_saturating {
+                        return Err(Error::ExponentOverflow(index));
+                    }
+                    explicit_exponent = value.min(MAX_EXPONENT);
+                }
+                if is_negative_exponent {
+                    explicit_exponent = -explicit_exponent;
+                }
+                index += length;
+            }
+        }
+        if index != bytes.len() {
+            return Err(Error::InvalidDigit(index));
+        }
+
+        // Find the significant digits, and the exponent of the first digit.
+        let is_significant = |c: &u8| matches!(c, b'1'..=b'9');
+        let first = match mantissa.iter().position(is_significant) {
+            Some(first) => first,
+            None => {
+                return Ok(Self {
+                    is_negative,
+                    significant: &[],
+                    exponent: 0,
+                    quantum: explicit_exponent - fraction_count,
+                })
+            },
+        };
+        let last = mantissa.iter().rposition(is_significant).unwrap_or(first);
+        let point = mantissa.iter().position(|&c| c == decimal_point).unwrap_or(mantissa.len());
+        let digits_between = |start: usize, end: usize| {
+            mantissa[start..end].iter().filter(|c| c.is_ascii_digit()).count() as i64
+        };
+        let scale = if first < point {
+            digits_between(first, point) - 1
+        } else {
+            -digits_between(point, first + 1)
+        };
+
+        Ok(Self {
+            is_negative,
+            significant: &mantissa[first..=last],
+            exponent: explicit_exponent + scale,
+            quantum: explicit_exponent - fraction_count,
+        })
+    }
+
+    /// Determine if the number is zero.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.significant.is_empty()
+    }
+
+    /// Determine if the number is negative, which is false for zero.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.is_negative && !self.is_zero()
+    }
+
+    /// Determine if the number has a negative sign, including negative zero.
+    #[inline(always)]
+    pub fn is_sign_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get the decimal exponent of the first significant digit.
+    #[inline(always)]
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Get the decimal exponent of the last digit, including trailing zeros.
+    ///
+    /// This is the quantum of the number as written, so `1.50` has a
+    /// quantum of `-2`, while `1.5` has a quantum of `-1`.
+    #[inline(always)]
+    pub fn quantum(&self) -> i64 {
+        self.quantum
+    }
+
+    /// Iterate over the significant digits, without leading or trailing zeros.
+    #[inline]
+    pub fn digits(&self) -> impl Iterator<Item = u8> + 'a {
+        self.significant.iter().copied().filter(u8::is_ascii_digit)
+    }
+
+    /// Compare the values of two numbers exactly.
+    ///
+    /// Zeros are equal regardless of sign, and numbers are otherwise
+    /// ordered by sign, the exponent, and then the significant digits.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        // Compare the signs, where zero is between negative and positive numbers.
+        let sign = |x: &Self| match (x.is_zero(), x.is_negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let (x_sign, y_sign) = (sign(self), sign(other));
+        if x_sign != y_sign || x_sign == 0 {
+            return x_sign.cmp(&y_sign);
+        }
+
+        // Compare the magnitudes, which are reversed for negative numbers.
+        let ordering =
+            self.exponent.cmp(&other.exponent).then_with(|| self.digits().cmp(other.digits()));
+        if x_sign < 0 {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    /// Feed the value of the number into a hasher.
+    ///
+    /// Numbers with equal values, as determined by [`compare`](Self::compare),
+    /// produce identical hashes, regardless of formatting.
+    pub fn hash_value<H: Hasher>(&self, state: &mut H) {
+        if self.is_zero() {
+            state.write_u8(0);
+            return;
+        }
+
+        state.write_u8(if self.is_negative {
+            2
+        } else {
+            1
+        });
+        state.write_i64(self.exponent);
+        let mut count = 0;
+        for digit in self.digits() {
+            state.write_u8(digit);
+            count += 1;
+        }
+        state.write_usize(count);
+    }
+
+    /// Write the canonical form of the number to the buffer.
+    ///
+    /// Returns the number of bytes written. A buffer that is 24 bytes
+    /// longer than the original string is always sufficient. If the
+    /// buffer is too small, returns [`Error::BufferTooSmall`] with the
+    /// required length, without writing to the buffer.
+    pub fn write(&self, buffer: &mut [u8]) -> Result<usize> {
+        if self.is_zero() {
+            return match buffer.first_mut() {
+                Some(first) => {
+                    *first = b'0';
+                    Ok(1)
+                },
+                None => Err(Error::BufferTooSmall(1)),
+            };
+        }
+
+        // Get the exponent digits in reverse order, and the exact length.
+        let mut exponent = self.exponent.unsigned_abs();
+        let mut exponent_digits = [0u8; 20];
+        let mut exponent_count = 0;
+        while exponent != 0 {
+            exponent_digits[exponent_count] = b'0' + (exponent % 10) as u8;
+            exponent /= 10;
+            exponent_count += 1;
+        }
+        let count = self.digits().count();
+        let mut length = self.is_negative as usize + count + (count > 1) as usize;
+        if self.exponent != 0 {
+            length += 1 + (self.exponent < 0) as usize + exponent_count;
+        }
+        if buffer.len() < length {
+            return Err(Error::BufferTooSmall(length));
+        }
+
+        let mut output = buffer.iter_mut();
+        let mut push = |c: u8| {
+            if let Some(byte) = output.next() {
+                *byte = c;
+            }
+        };
+        if self.is_negative {
+            push(b'-');
+        }
+        for (i, digit) in self.digits().enumerate() {
+            if i == 1 {
+                push(b'.');
+            }
+            push(digit);
+        }
+        if self.exponent != 0 {
+            push(b'e');
+            if self.exponent < 0 {
+                push(b'-');
+            }
+            for &digit in exponent_digits[..exponent_count].iter().rev() {
+                push(digit);
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Write the number rounded to `decimals` fractional digits.
+    ///
+    /// The number is written without an exponent, with exactly `decimals`
+    /// digits after the decimal point, and without a decimal point if
+    /// `decimals` is 0. Rounding is exact, since the digits are never
+    /// converted to binary. Numbers that round to zero are written
+    /// without a sign. Returns the number of bytes written.
+    ///
+    /// The written number has `max(exponent, 0) + decimals + 4` bytes at
+    /// most. If the buffer is too small, returns [`Error::BufferTooSmall`]
+    /// with the required length, saturated at `usize::MAX`, and the
+    /// contents of the buffer are unspecified.
+    pub fn quantize(
+        &self,
+        decimals: usize,
+        rounding: Rounding,
+        buffer: &mut [u8],
+    ) -> Result<usize> {
+        // Get the number of digits and decimal points, before rounding,
+        // checking for overflow so the exponents below cannot overflow.
+        const TOO_LARGE: Error = Error::BufferTooSmall(usize::MAX);
+        let top = if self.is_zero() {
+            0
+        } else {
+            self.exponent.max(0)
+        };
+        let last_exp = match i64::try_from(decimals) {
+            Ok(decimals) => -decimals,
+            Err(_) => return Err(TOO_LARGE),
+        };
+        let unsigned_length = usize::try_from(top)
+            .ok()
+            .and_then(|top| top.checked_add(decimals))
+            .and_then(|length| length.checked_add(1 + (decimals != 0) as usize))
+            .ok_or(TOO_LARGE)?;
+        if unsigned_length > buffer.len() {
+            return Err(Error::BufferTooSmall(
+                unsigned_length.saturating_add(self.is_negative() as usize),
+            ));
+        }
+
+        // Find the significant digits kept, the first truncated digit, and
+        // if any later digits are truncated. The count of kept digits is
+        // negative if every digit is below the first truncated digit.
+        let kept = self.exponent - last_exp + 1;
+        let mut last_kept = 0;
+        let mut first = 0;
+        let mut is_above = false;
+        for (index, digit) in self.digits().enumerate() {
+            let digit = digit - b'0';
+            match (index as i64).cmp(&kept) {
+                Ordering::Less => last_kept = digit,
+                Ordering::Equal => first = digit,
+                Ordering::Greater => is_above |= digit != 0,
+            }
+        }
+        let kept = kept.max(0);
+        let is_inexact = first != 0 || is_above;
+        let round_up = match rounding {
+            Rounding::HalfEven => first > 5 || (first == 5 && (is_above || last_kept % 2 == 1)),
+            Rounding::HalfUp => first >= 5,
+            Rounding::HalfDown => first > 5 || (first == 5 && is_above),
+            Rounding::Up => is_inexact,
+            Rounding::Down => false,
+            Rounding::Ceiling => is_inexact && !self.is_negative,
+            Rounding::Floor => is_inexact && self.is_negative,
+        };
+        let start = (self.is_negative() && (kept > 0 || round_up)) as usize;
+        let mut length = start + unsigned_length;
+        if length > buffer.len() {
+            return Err(Error::BufferTooSmall(length));
+        }
+
+        // Write the sign and the kept digits, padded with zeros.
+        let mut output = buffer[..length].iter_mut();
+        let mut push = |c: u8| {
+            if let Some(byte) = output.next() {
+                *byte = c;
+            }
+        };
+        if start != 0 {
+            push(b'-');
+        }
+        let mut digits = self.digits();
+        for exp in (last_exp..=top).rev() {
+            if exp == -1 {
+                push(b'.');
+            }
+            let position = self.exponent - exp;
+            push(if !self.is_zero() && position >= 0 && position < kept {
+                digits.next().unwrap_or(b'0')
+            } else {
+                b'0'
+            });
+        }
+
+        // Round up the digits, carrying into a new leading digit, which
+        // shifts the digits, now all zeros, one byte to the right.
+        if round_up {
+            let is_carry =
+                buffer[start..length].iter_mut().rev().filter(|c| **c != b'.').all(|c| {
+                    if *c == b'9' {
+                        *c = b'0';
+                        true
+                    } else {
+                        *c += 1;
+                        false
+                    }
+                });
+            if is_carry {
+                length += 1;
+                match buffer.get_mut(start..length) {
+                    Some(written) => {
+                        written.rotate_right(1);
+                        if let Some(leading) = written.first_mut() {
+                            *leading = b'1';
+                        }
+                    },
+                    None => return Err(Error::BufferTooSmall(length)),
+                }
+            }
+        }
+
+        Ok(length)
+    }
+}
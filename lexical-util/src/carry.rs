@@ -0,0 +1,137 @@
+//! Carry over the start of a number between two buffers.
+//!
+//! Readers of memory-mapped files or other chunked inputs may find a
+//! number split across the end of one chunk and the start of the next.
+//! The previous chunk may be unmapped or reused before the next chunk
+//! is available, so the start of the number is copied into a small,
+//! fixed-size buffer, and later parsed together with the next chunk.
+
+#![cfg(feature = "parse")]
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// Maximum number of bytes in a number split across two buffers.
+///
+/// This is enough for any number written by lexical, and for most
+/// numbers with excess digits.
+pub const CARRY_SIZE: usize = 128;
+
+/// Start of a number, carried over from the end of a previous buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lexical_util::carry::Carry;
+/// let mut carry = Carry::new();
+/// carry.extend(b"-12.").unwrap();
+/// carry.extend(b"5").unwrap();
+/// assert_eq!(carry.as_slice(), b"-12.5");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Carry {
+    /// Bytes of the number, up to `len`.
+    data: [u8; CARRY_SIZE],
+    /// Number of bytes in the carry.
+    len: usize,
+}
+
+impl Carry {
+    /// Create an empty carry.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; CARRY_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Get the number of carried bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determine if no bytes are carried.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the carried bytes.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Remove all carried bytes.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Append bytes to the carry.
+    ///
+    /// Returns [`Error::NumberTooLong`] if the carry would exceed
+    /// [`CARRY_SIZE`] bytes, with the index of the first byte that
+    /// does not fit, in which case the carry is unchanged.
+    #[inline]
+    pub fn extend(&mut self, bytes: &[u8]) -> Result<()> {
+        let len = self.len + bytes.len();
+        if len > CARRY_SIZE {
+            return Err(Error::NumberTooLong(CARRY_SIZE));
+        }
+        self.data[self.len..len].copy_from_slice(bytes);
+        self.len = len;
+        Ok(())
+    }
+
+    /// Copy the carried bytes followed by the start of the next buffer.
+    ///
+    /// Returns the bytes and if the next buffer was truncated to fit.
+    #[inline]
+    pub fn join<'a>(&self, bytes: &[u8], buffer: &'a mut [u8; CARRY_SIZE]) -> (&'a [u8], bool) {
+        let count = bytes.len().min(CARRY_SIZE - self.len);
+        let len = self.len + count;
+        buffer[..self.len].copy_from_slice(self.as_slice());
+        buffer[self.len..len].copy_from_slice(&bytes[..count]);
+        (&buffer[..len], count < bytes.len())
+    }
+
+    /// Parse a partial number from the carried bytes followed by the next buffer.
+    ///
+    /// Returns the value and the number of processed bytes, counted from
+    /// the start of the carry. If the carry is empty, `bytes` is parsed
+    /// directly. Otherwise, if the number may continue past the bytes
+    /// that fit after the carry, returns [`Error::NumberTooLong`].
+    ///
+    /// * `bytes`   - Byte slice containing the rest of the numeric string.
+    /// * `parse`   - Partial parser for the number.
+    #[inline]
+    pub fn parse_partial<N, Parse>(&self, bytes: &[u8], parse: Parse) -> Result<(N, usize)>
+    where
+        Parse: FnOnce(&[u8]) -> Result<(N, usize)>,
+    {
+        if self.is_empty() {
+            return parse(bytes);
+        }
+        let mut buffer = [0u8; CARRY_SIZE];
+        let (bytes, is_truncated) = self.join(bytes, &mut buffer);
+        let len = bytes.len();
+        // The parser stopped at the end of the truncated bytes.
+        match parse(bytes) {
+            Ok((_, count)) if is_truncated && count == len => Err(Error::NumberTooLong(len)),
+            Err(error) if is_truncated && error.index() == Some(&len) => {
+                Err(Error::NumberTooLong(len))
+            },
+            result => result,
+        }
+    }
+}
+
+impl Default for Carry {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,84 @@
+//! Shared utilities to serialize and deserialize options.
+//!
+//! The options are serialized as a map of every field, with characters and
+//! special strings as strings. When deserialized, every field is optional,
+//! and overrides the options of a named preset before the options are
+//! validated by the builder.
+//!
+//! The options only store static strings, so deserialized special strings
+//! that differ from the preset are interned: each distinct string is
+//! allocated once, and reused every time the options are loaded again.
+
+#![cfg(feature = "serde")]
+#![doc(hidden)]
+
+use core::ptr;
+use core::str;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use serde_crate::de::{self, Deserialize, Deserializer, Unexpected};
+use serde_crate::ser;
+use std::boxed::Box;
+use std::collections::BTreeSet;
+use std::string::String;
+use std::sync::{Mutex, Once, PoisonError};
+
+/// Set of the interned strings.
+type Interned = Mutex<BTreeSet<&'static [u8]>>;
+
+/// Interned strings, initialized on first use.
+static INTERNED: AtomicPtr<Interned> = AtomicPtr::new(ptr::null_mut());
+
+/// Get the set of interned strings.
+fn interned() -> &'static Interned {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let interned = Box::new(Mutex::new(BTreeSet::new()));
+        INTERNED.store(Box::into_raw(interned), Ordering::Release);
+    });
+    // SAFETY: safe, since the pointer is set once, to a leaked box, before
+    // `call_once` returns.
+    unsafe { &*INTERNED.load(Ordering::Acquire) }
+}
+
+/// Get a static copy of a string, allocating it only the first time.
+pub fn intern(string: String) -> &'static [u8] {
+    let mut interned = interned().lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(&bytes) = interned.get(string.as_bytes()) {
+        return bytes;
+    }
+    let bytes: &'static [u8] = Box::leak(string.into_bytes().into_boxed_slice());
+    interned.insert(bytes);
+    bytes
+}
+
+/// Deserialize a field that is present, so `null` overrides the preset.
+pub fn present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Convert a character to a byte, which must be ASCII.
+pub fn to_byte<E: de::Error>(c: char) -> Result<u8, E> {
+    if c.is_ascii() {
+        Ok(c as u8)
+    } else {
+        Err(E::invalid_value(Unexpected::Char(c), &"an ASCII character"))
+    }
+}
+
+/// Convert a special string to a static string, interning it if it differs from the preset.
+pub fn to_static(string: Option<String>, preset: Option<&'static [u8]>) -> Option<&'static [u8]> {
+    match string {
+        Some(string) if Some(string.as_bytes()) == preset => preset,
+        Some(string) => Some(intern(string)),
+        None => None,
+    }
+}
+
+/// Convert a special string to a `str`, which must be UTF-8.
+pub fn to_str<E: ser::Error>(string: Option<&'static [u8]>) -> Result<Option<&'static str>, E> {
+    string.map(|string| str::from_utf8(string).map_err(E::custom)).transpose()
+}
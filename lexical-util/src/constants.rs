@@ -8,6 +8,25 @@ use crate::bf16::bf16;
 use crate::f16::f16;
 
 /// The size, in bytes, of formatted values.
+///
+/// This is implemented for every integer and float type, and these are
+/// the minimum buffer sizes checked by the write APIs. The constants can
+/// size arrays for concrete types, or buffers for generic types:
+///
+/// ```rust
+/// use lexical_util::constants::FormattedSize;
+///
+/// let buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL];
+/// assert_eq!(buffer.len(), 64);
+///
+/// fn new_buffer<T: FormattedSize>() -> Vec<u8> {
+///     vec![b'0'; T::FORMATTED_SIZE]
+/// }
+/// assert!(new_buffer::<u64>().len() >= u64::FORMATTED_SIZE_DECIMAL);
+/// ```
+///
+/// Arrays cannot be sized by a generic parameter, as in `[u8; T::FORMATTED_SIZE]`,
+/// until generic constant expressions are stable.
 pub trait FormattedSize {
     /// Maximum number of bytes required to serialize a number to string.
     ///
@@ -71,6 +90,10 @@ formatted_size_impl! {
     //f256 256 1024 ;
 }
 
+// Booleans are written as `false` with the default options, and
+// custom strings may need a larger buffer.
+formatted_size_impl! { bool 5 5 ; }
+
 #[cfg(feature = "f16")]
 formatted_size_impl! {
     f16 64 256 ;
@@ -0,0 +1,343 @@
+//! Custom binary float formats, such as software floats.
+//!
+//! Types implementing [`LexicalFloat`] describe their layout, and can
+//! reuse the correctly rounded parsers and the shortest round-trip
+//! writers. Every value of the format must be exactly representable
+//! as an `f64`, as must the value halfway between two adjacent values,
+//! so a float is rounded to the format by first rounding to an `f64`.
+//! This can only round incorrectly if the `f64` is exactly halfway
+//! between two values of the format, so the parsers only compare the
+//! significant digits with arbitrary-precision arithmetic for ties.
+
+#![cfg(feature = "floats")]
+#![doc(hidden)]
+
+#[cfg(feature = "f16")]
+use crate::bf16::bf16;
+#[cfg(feature = "f16")]
+use crate::f16::f16;
+use core::cmp::Ordering;
+
+/// A binary float format that can be parsed and written by lexical.
+///
+/// The format must be IEEE-754-like: a sign bit, followed by
+/// `EXPONENT_SIZE` bits of biased exponent and `MANTISSA_SIZE` bits
+/// of fraction, with a hidden bit, subnormals, infinities, and NaNs.
+/// The format must have at most 10 exponent bits and 51 fraction
+/// bits, or have the same layout as an `f64`. Only the constants for
+/// the sizes and the bit conversions need to be implemented.
+///
+/// # Example
+///
+/// ```rust
+/// use lexical_util::custom_float::LexicalFloat;
+///
+/// /// A 24-bit float, with 7 exponent bits and 16 fraction bits.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Fp24(u32);
+///
+/// impl LexicalFloat for Fp24 {
+///     const EXPONENT_SIZE: i32 = 7;
+///     const MANTISSA_SIZE: i32 = 16;
+///
+///     fn to_bits(self) -> u64 {
+///         self.0 as u64
+///     }
+///
+///     fn from_bits(bits: u64) -> Self {
+///         Self(bits as u32)
+///     }
+/// }
+///
+/// let one = Fp24::from_parts(false, 63, 0);
+/// assert_eq!(one.to_f64(), 1.0);
+/// assert_eq!(one.next().to_f64(), 1.0 + 2f64.powi(-16));
+/// ```
+pub trait LexicalFloat: Copy {
+    /// Size of the exponent.
+    const EXPONENT_SIZE: i32;
+    /// Size of the significand (mantissa) without hidden bit.
+    const MANTISSA_SIZE: i32;
+    /// Bias of the exponent, including the size of the significand.
+    ///
+    /// This is `2^(EXPONENT_SIZE-1) - 1 + MANTISSA_SIZE`, the same as
+    /// `Float::EXPONENT_BIAS`, so `2^(1 - EXPONENT_BIAS)` is the smallest
+    /// positive subnormal value.
+    const EXPONENT_BIAS: i32 = (1 << (Self::EXPONENT_SIZE - 1)) - 1 + Self::MANTISSA_SIZE;
+    /// Bitmask for the sign bit.
+    const SIGN_MASK: u64 = 1 << (Self::EXPONENT_SIZE + Self::MANTISSA_SIZE);
+    /// Bitmask for the exponent, including the hidden bit.
+    const EXPONENT_MASK: u64 = ((1 << Self::EXPONENT_SIZE) - 1) << Self::MANTISSA_SIZE;
+    /// Bitmask for the hidden bit in exponent, which is an implicit 1 in the fraction.
+    const HIDDEN_BIT_MASK: u64 = 1 << Self::MANTISSA_SIZE;
+    /// Bitmask for the mantissa (fraction), excluding the hidden bit.
+    const MANTISSA_MASK: u64 = Self::HIDDEN_BIT_MASK - 1;
+
+    /// Get the raw bits of the float, with the sign bit at `SIGN_MASK`.
+    fn to_bits(self) -> u64;
+
+    /// Create a float from the raw bits, where bits above `SIGN_MASK` are zero.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Assemble a float from the sign, the biased exponent, and the fraction bits.
+    #[inline]
+    fn from_parts(is_negative: bool, biased_exponent: u64, fraction: u64) -> Self {
+        let sign = if is_negative {
+            Self::SIGN_MASK
+        } else {
+            0
+        };
+        let exponent = (biased_exponent << Self::MANTISSA_SIZE) & Self::EXPONENT_MASK;
+        Self::from_bits(sign | exponent | (fraction & Self::MANTISSA_MASK))
+    }
+
+    /// Returns true if the sign bit is set.
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.to_bits() & Self::SIGN_MASK != 0
+    }
+
+    /// Returns true if the float is a NaN or Infinite.
+    #[inline]
+    fn is_special(self) -> bool {
+        self.to_bits() & Self::EXPONENT_MASK == Self::EXPONENT_MASK
+    }
+
+    /// Returns true if the float is NaN.
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_special() && self.to_bits() & Self::MANTISSA_MASK != 0
+    }
+
+    /// Returns true if the float is infinite.
+    #[inline]
+    fn is_inf(self) -> bool {
+        self.is_special() && self.to_bits() & Self::MANTISSA_MASK == 0
+    }
+
+    /// Get next greater float.
+    #[inline]
+    fn next(self) -> Self {
+        let bits = self.to_bits();
+        if bits == Self::SIGN_MASK {
+            // -0.0
+            Self::from_bits(0)
+        } else if bits == Self::EXPONENT_MASK || self.is_nan() {
+            self
+        } else if self.is_sign_negative() {
+            Self::from_bits(bits - 1)
+        } else {
+            Self::from_bits(bits + 1)
+        }
+    }
+
+    /// Get previous greater float, such that `self.prev().next() == self`.
+    #[inline]
+    fn prev(self) -> Self {
+        let bits = self.to_bits();
+        if bits == 0 {
+            // +0.0
+            Self::from_bits(Self::SIGN_MASK)
+        } else if bits == Self::SIGN_MASK | Self::EXPONENT_MASK || self.is_nan() {
+            self
+        } else if self.is_sign_negative() {
+            Self::from_bits(bits + 1)
+        } else {
+            Self::from_bits(bits - 1)
+        }
+    }
+
+    /// Convert the float to an `f64` exactly.
+    #[inline]
+    fn to_f64(self) -> f64 {
+        to_f64(self)
+    }
+}
+
+/// Determine if the layout of the float is the same as an `f64`.
+#[inline(always)]
+const fn is_f64_layout<F: LexicalFloat>() -> bool {
+    F::EXPONENT_SIZE == 11 && F::MANTISSA_SIZE == 52
+}
+
+/// Assert the format can be exactly represented as an `f64`.
+#[inline(always)]
+fn assert_valid<F: LexicalFloat>() {
+    assert!(
+        is_f64_layout::<F>()
+            || (F::EXPONENT_SIZE >= 2
+                && F::EXPONENT_SIZE <= 10
+                && F::MANTISSA_SIZE >= 1
+                && F::MANTISSA_SIZE <= 51),
+        "float format must be narrower than an f64"
+    );
+}
+
+/// Get the float value of `2^exp`, which must be a normal `f64`.
+#[inline(always)]
+fn pow2(exp: i32) -> f64 {
+    debug_assert!((-1022..=1023).contains(&exp));
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Get the magnitude of a float, without requiring the standard library.
+#[inline(always)]
+fn abs(value: f64) -> f64 {
+    f64::from_bits(value.to_bits() & !(1 << 63))
+}
+
+/// Convert a float to an `f64` exactly.
+pub fn to_f64<F: LexicalFloat>(float: F) -> f64 {
+    assert_valid::<F>();
+    let bits = float.to_bits();
+    if is_f64_layout::<F>() {
+        return f64::from_bits(bits);
+    }
+
+    let fraction = bits & F::MANTISSA_MASK;
+    let biased = (bits & F::EXPONENT_MASK) >> F::MANTISSA_SIZE;
+    let value = if float.is_nan() {
+        f64::NAN
+    } else if float.is_inf() {
+        f64::INFINITY
+    } else if biased == 0 {
+        fraction as f64 * pow2(1 - F::EXPONENT_BIAS)
+    } else {
+        (fraction | F::HIDDEN_BIT_MASK) as f64 * pow2(biased as i32 - F::EXPONENT_BIAS)
+    };
+    if float.is_sign_negative() {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Round the magnitude of a float down to the format.
+///
+/// Returns the bits of the rounded value, without the sign, and the
+/// value halfway between it and the next larger value of the format.
+fn round_down<F: LexicalFloat>(value: f64) -> (u64, f64) {
+    // Get the significant digits and the exponent of the float. The
+    // value is a normal `f64`, unless it is far below the format.
+    let float_bits = value.to_bits();
+    let biased = ((float_bits >> 52) & 0x7FF) as i32;
+    let fraction = float_bits & ((1 << 52) - 1);
+    let (mant, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    };
+    let scale = exp + 63 - mant.leading_zeros() as i32;
+
+    // Get the exponent of the last bit of the format, which is fixed for
+    // subnormals, and clamp values above the format to the largest value.
+    let denormal_exp = 1 - F::EXPONENT_BIAS;
+    let quantum = (scale - F::MANTISSA_SIZE).max(denormal_exp);
+    let max_exp = (1 << F::EXPONENT_SIZE) - 2 - F::EXPONENT_BIAS;
+    let (bits, quantum) = if quantum > max_exp {
+        (F::EXPONENT_MASK - 1, max_exp)
+    } else {
+        let shift = (quantum - exp) as u32;
+        let significand = if shift < 64 {
+            mant >> shift
+        } else {
+            0
+        };
+        // The hidden bit carries into the biased exponent.
+        (significand + (((quantum - denormal_exp) as u64) << F::MANTISSA_SIZE), quantum)
+    };
+
+    let significand = (bits & F::MANTISSA_MASK)
+        | if bits & F::EXPONENT_MASK != 0 {
+            F::HIDDEN_BIT_MASK
+        } else {
+            0
+        };
+    let halfway = (2 * significand + 1) as f64 * pow2(quantum - 1);
+    (bits, halfway)
+}
+
+/// Round a positive, finite float to the bits of the format, and if it is a tie.
+fn from_f64_bits<F: LexicalFloat>(value: f64, direction: Ordering) -> (u64, bool) {
+    let (bits, halfway) = round_down::<F>(value);
+    let is_above = match value.partial_cmp(&halfway) {
+        Some(Ordering::Greater) => true,
+        Some(Ordering::Equal) => match direction {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => bits & 1 == 1,
+        },
+        Some(Ordering::Less) | None => false,
+    };
+    // Rounding up the largest value carries into infinity.
+    (bits + is_above as u64, value == halfway)
+}
+
+/// Round a float to the nearest value of the format, with ties to even.
+///
+/// `direction` is the ordering of the exact value relative to `value`,
+/// in magnitude, which breaks ties if `value` was itself rounded. The
+/// sign of zeros and NaNs is preserved.
+pub fn from_f64<F: LexicalFloat>(value: f64, direction: Ordering) -> F {
+    assert_valid::<F>();
+    if is_f64_layout::<F>() {
+        return F::from_bits(value.to_bits());
+    }
+
+    let sign = if value.is_sign_negative() {
+        F::SIGN_MASK
+    } else {
+        0
+    };
+    let bits = if value.is_nan() {
+        // Use the quiet bit, the highest bit of the fraction.
+        F::EXPONENT_MASK | (F::HIDDEN_BIT_MASK >> 1)
+    } else if value.is_infinite() {
+        F::EXPONENT_MASK
+    } else if value == 0.0 {
+        0
+    } else {
+        from_f64_bits::<F>(abs(value), direction).0
+    };
+    F::from_bits(sign | bits)
+}
+
+/// Determine if the float is exactly halfway between two values of the format.
+pub fn is_tie<F: LexicalFloat>(value: f64) -> bool {
+    assert_valid::<F>();
+    if is_f64_layout::<F>() || !value.is_finite() || value == 0.0 {
+        false
+    } else {
+        from_f64_bits::<F>(abs(value), Ordering::Equal).1
+    }
+}
+
+macro_rules! lexical_float_impl {
+    ($($t:ty, $bits:ty, $exponent:literal, $mantissa:literal ;)*) => ($(
+        impl LexicalFloat for $t {
+            const EXPONENT_SIZE: i32 = $exponent;
+            const MANTISSA_SIZE: i32 = $mantissa;
+
+            #[inline(always)]
+            fn to_bits(self) -> u64 {
+                <$t>::to_bits(self) as u64
+            }
+
+            #[inline(always)]
+            fn from_bits(bits: u64) -> Self {
+                <$t>::from_bits(bits as $bits)
+            }
+        }
+    )*);
+}
+
+lexical_float_impl! {
+    f32, u32, 8, 23 ;
+    f64, u64, 11, 52 ;
+}
+
+#[cfg(feature = "f16")]
+lexical_float_impl! {
+    bf16, u16, 8, 7 ;
+    f16, u16, 5, 10 ;
+}
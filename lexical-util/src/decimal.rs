@@ -0,0 +1,734 @@
+//! IEEE-754 decimal floating-point interchange formats.
+//!
+//! Financial protocols, such as ISO 20022, exchange amounts as
+//! `decimal64` or `decimal128` bit patterns, which store a decimal
+//! coefficient and exponent, rather than a binary significand. Both
+//! the binary integer decimal (BID) and the densely packed decimal
+//! (DPD) encodings of the coefficient are supported.
+//!
+//! Decimal numbers keep their quantum, or the exponent of the last
+//! digit, so `1.50` and `1.5` are equal values with different
+//! representations. Parsing rounds to the nearest representable
+//! number, with ties to even, and numbers are written using the
+//! `to-scientific-string` conversion from the IEEE-754 standard.
+
+#![cfg(feature = "floats")]
+#![doc(hidden)]
+
+#[cfg(feature = "parse")]
+use crate::canonical::NumericStr;
+#[cfg(feature = "parse")]
+use crate::error::Error;
+#[cfg(feature = "parse")]
+use crate::format::STANDARD;
+#[cfg(feature = "parse")]
+use crate::result::Result;
+use core::fmt;
+#[cfg(feature = "parse")]
+use core::str::FromStr;
+
+/// Parameters of a decimal interchange format.
+struct Params {
+    /// Number of digits in the coefficient.
+    digits: u32,
+    /// Bias of the stored exponent.
+    bias: i64,
+    /// Largest exponent of the last digit of the coefficient.
+    max_exponent: i64,
+    /// Number of bits in the stored exponent.
+    exponent_bits: u32,
+    /// Number of bits in the trailing significand field.
+    trailing_bits: u32,
+    /// Number of bits in the interchange format.
+    width: u32,
+}
+
+impl Params {
+    /// Get the smallest exponent of the last digit of the coefficient.
+    #[inline(always)]
+    const fn min_exponent(&self) -> i64 {
+        -self.bias
+    }
+
+    /// Get the exclusive upper bound of the coefficient.
+    #[inline(always)]
+    fn max_coefficient(&self) -> u128 {
+        10u128.pow(self.digits)
+    }
+}
+
+/// Parameters of the `decimal64` format.
+const DECIMAL64: Params = Params {
+    digits: 16,
+    bias: 398,
+    max_exponent: 369,
+    exponent_bits: 10,
+    trailing_bits: 50,
+    width: 64,
+};
+
+/// Parameters of the `decimal128` format.
+const DECIMAL128: Params = Params {
+    digits: 34,
+    bias: 6176,
+    max_exponent: 6111,
+    exponent_bits: 14,
+    trailing_bits: 110,
+    width: 128,
+};
+
+/// Classification of a decimal number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Class {
+    Finite,
+    Infinite,
+    QuietNan,
+    SignalingNan,
+}
+
+/// Decimal number unpacked from any interchange format.
+///
+/// The coefficient of a NaN is its payload, and the exponent of
+/// a special value is always 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Unpacked {
+    is_negative: bool,
+    class: Class,
+    coefficient: u128,
+    exponent: i64,
+}
+
+/// Get a mask with the low `bits` bits set.
+#[inline(always)]
+const fn mask(bits: u32) -> u128 {
+    (1u128 << bits) - 1
+}
+
+/// Get the number of decimal digits in the value, which is 1 for zero.
+#[inline]
+fn count_digits(mut value: u128) -> u32 {
+    let mut count = 1;
+    while value >= 10 {
+        value /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Encode 3 decimal digits as a 10-bit declet.
+fn encode_declet(value: u128) -> u128 {
+    let (d2, d1, d0) = (value / 100, value / 10 % 10, value % 10);
+    let low = d0 & 1;
+    match (d2 >= 8, d1 >= 8, d0 >= 8) {
+        (false, false, false) => d2 << 7 | d1 << 4 | d0,
+        (false, false, true) => d2 << 7 | d1 << 4 | 0b1000 | low,
+        (false, true, false) => d2 << 7 | (d0 >> 1) << 5 | (d1 & 1) << 4 | 0b1010 | low,
+        (true, false, false) => (d0 >> 1) << 8 | (d2 & 1) << 7 | d1 << 4 | 0b1100 | low,
+        (true, true, false) => (d0 >> 1) << 8 | (d2 & 1) << 7 | (d1 & 1) << 4 | 0b1110 | low,
+        (true, false, true) => {
+            (d1 >> 1) << 8 | (d2 & 1) << 7 | 0b01 << 5 | (d1 & 1) << 4 | 0b1110 | low
+        },
+        (false, true, true) => d2 << 7 | 0b10 << 5 | (d1 & 1) << 4 | 0b1110 | low,
+        (true, true, true) => (d2 & 1) << 7 | 0b11 << 5 | (d1 & 1) << 4 | 0b1110 | low,
+    }
+}
+
+/// Decode a 10-bit declet to 3 decimal digits.
+///
+/// Non-canonical declets decode to the same value as the canonical declet.
+fn decode_declet(declet: u128) -> u128 {
+    let (c, f, i) = (declet >> 7 & 1, declet >> 4 & 1, declet & 1);
+    let (d2, d1, d0) = if declet & 0b1000 == 0 {
+        (declet >> 7 & 7, declet >> 4 & 7, declet & 7)
+    } else {
+        match (declet >> 1 & 3, declet >> 5 & 3) {
+            (0b00, _) => (declet >> 7 & 7, declet >> 4 & 7, 8 + i),
+            (0b01, _) => (declet >> 7 & 7, 8 + f, (declet >> 5 & 3) << 1 | i),
+            (0b10, _) => (8 + c, declet >> 4 & 7, (declet >> 8 & 3) << 1 | i),
+            (_, 0b00) => (8 + c, 8 + f, (declet >> 8 & 3) << 1 | i),
+            (_, 0b01) => (8 + c, (declet >> 8 & 3) << 1 | f, 8 + i),
+            (_, 0b10) => (declet >> 7 & 7, 8 + f, 8 + i),
+            _ => (8 + c, 8 + f, 8 + i),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+/// Encode the low digits of a value as declets, 3 digits at a time.
+fn encode_declets(mut value: u128, count: u32) -> u128 {
+    let mut bits = 0;
+    for index in 0..count {
+        bits |= encode_declet(value % 1000) << (10 * index);
+        value /= 1000;
+    }
+    bits
+}
+
+/// Decode declets to a value, 3 digits at a time.
+fn decode_declets(bits: u128, count: u32) -> u128 {
+    let mut value = 0;
+    for index in (0..count).rev() {
+        value = value * 1000 + decode_declet(bits >> (10 * index) & mask(10));
+    }
+    value
+}
+
+impl Unpacked {
+    /// Create a number from the sign, coefficient, and exponent.
+    fn finite(is_negative: bool, coefficient: u128, exponent: i64) -> Self {
+        Self {
+            is_negative,
+            class: Class::Finite,
+            coefficient,
+            exponent,
+        }
+    }
+
+    /// Create a special value, with the payload of a NaN.
+    fn special(is_negative: bool, class: Class, payload: u128) -> Self {
+        Self {
+            is_negative,
+            class,
+            coefficient: payload,
+            exponent: 0,
+        }
+    }
+
+    /// Decode a special value, if the combination field is special.
+    fn decode_special(bits: u128, params: &Params) -> Option<Self> {
+        let width = params.width;
+        let is_negative = bits >> (width - 1) == 1;
+        match bits >> (width - 6) & 0b11111 {
+            0b11110 => Some(Self::special(is_negative, Class::Infinite, 0)),
+            0b11111 => {
+                let class = if bits >> (width - 7) & 1 == 1 {
+                    Class::SignalingNan
+                } else {
+                    Class::QuietNan
+                };
+                Some(Self::special(is_negative, class, bits & mask(params.trailing_bits)))
+            },
+            _ => None,
+        }
+    }
+
+    /// Decode a number in the binary integer decimal encoding.
+    fn from_bid(bits: u128, params: &Params) -> Self {
+        let trailing = params.trailing_bits;
+        let mut number = match Self::decode_special(bits, params) {
+            Some(number) => number,
+            None => {
+                let is_negative = bits >> (params.width - 1) == 1;
+                let (exponent, coefficient) = if bits >> (params.width - 3) & 0b11 == 0b11 {
+                    let coefficient = 0b100 << (trailing + 1) | bits & mask(trailing + 1);
+                    (bits >> (trailing + 1), coefficient)
+                } else {
+                    (bits >> (trailing + 3), bits & mask(trailing + 3))
+                };
+                let exponent = (exponent & mask(params.exponent_bits)) as i64 - params.bias;
+                Self::finite(is_negative, coefficient, exponent)
+            },
+        };
+        number.canonicalize(params);
+        number
+    }
+
+    /// Encode the number in the binary integer decimal encoding.
+    fn to_bid(self, params: &Params) -> u128 {
+        let width = params.width;
+        let trailing = params.trailing_bits;
+        let sign = (self.is_negative as u128) << (width - 1);
+        match self.class {
+            Class::Infinite => sign | 0b11110 << (width - 6),
+            Class::QuietNan => sign | 0b11111 << (width - 6) | self.coefficient,
+            Class::SignalingNan => sign | 0b111111 << (width - 7) | self.coefficient,
+            Class::Finite => {
+                let exponent = (self.exponent + params.bias) as u128;
+                if self.coefficient >> (trailing + 3) == 0 {
+                    sign | exponent << (trailing + 3) | self.coefficient
+                } else {
+                    let coefficient = self.coefficient & mask(trailing + 1);
+                    sign | 0b11 << (width - 3) | exponent << (trailing + 1) | coefficient
+                }
+            },
+        }
+    }
+
+    /// Decode a number in the densely packed decimal encoding.
+    fn from_dpd(bits: u128, params: &Params) -> Self {
+        let trailing = params.trailing_bits;
+        let declets = trailing / 10;
+        let mut number = match Self::decode_special(bits, params) {
+            Some(mut number) => {
+                number.coefficient = decode_declets(number.coefficient, declets);
+                number
+            },
+            None => {
+                let is_negative = bits >> (params.width - 1) == 1;
+                let combination = bits >> (params.width - 6) & 0b11111;
+                let (high, leading) = if combination >> 3 == 0b11 {
+                    (combination >> 1 & 0b11, 8 + (combination & 1))
+                } else {
+                    (combination >> 3, combination & 0b111)
+                };
+                let continuation_bits = params.exponent_bits - 2;
+                let exponent =
+                    high << continuation_bits | bits >> trailing & mask(continuation_bits);
+                let coefficient = leading * 10u128.pow(params.digits - 1)
+                    + decode_declets(bits & mask(trailing), declets);
+                Self::finite(is_negative, coefficient, exponent as i64 - params.bias)
+            },
+        };
+        number.canonicalize(params);
+        number
+    }
+
+    /// Encode the number in the densely packed decimal encoding.
+    fn to_dpd(self, params: &Params) -> u128 {
+        let width = params.width;
+        let trailing = params.trailing_bits;
+        let declets = trailing / 10;
+        let sign = (self.is_negative as u128) << (width - 1);
+        match self.class {
+            Class::Infinite => sign | 0b11110 << (width - 6),
+            Class::QuietNan => {
+                sign | 0b11111 << (width - 6) | encode_declets(self.coefficient, declets)
+            },
+            Class::SignalingNan => {
+                sign | 0b111111 << (width - 7) | encode_declets(self.coefficient, declets)
+            },
+            Class::Finite => {
+                let power = 10u128.pow(params.digits - 1);
+                let (leading, rest) = (self.coefficient / power, self.coefficient % power);
+                let exponent = (self.exponent + params.bias) as u128;
+                let continuation_bits = params.exponent_bits - 2;
+                let high = exponent >> continuation_bits;
+                let combination = if leading < 8 {
+                    high << 3 | leading
+                } else {
+                    0b11000 | high << 1 | (leading & 1)
+                };
+                sign | combination << (width - 6)
+                    | (exponent & mask(continuation_bits)) << trailing
+                    | encode_declets(rest, declets)
+            },
+        }
+    }
+
+    /// Replace non-canonical coefficients and payloads with zero.
+    fn canonicalize(&mut self, params: &Params) {
+        let limit = match self.class {
+            Class::Finite => params.max_coefficient(),
+            Class::Infinite => 1,
+            Class::QuietNan | Class::SignalingNan => params.max_coefficient() / 10,
+        };
+        if self.coefficient >= limit {
+            self.coefficient = 0;
+        }
+    }
+
+    /// Parse a special value, such as `NaN`, `sNaN123`, or `-Infinity`.
+    ///
+    /// Returns `None` if the bytes are not a special value, or if the
+    /// payload of a NaN is too large.
+    #[cfg(feature = "parse")]
+    fn parse_special(bytes: &[u8], params: &Params) -> Option<Self> {
+        let (is_negative, rest) = match bytes.first() {
+            Some(&b'+') => (false, &bytes[1..]),
+            Some(&b'-') => (true, &bytes[1..]),
+            _ => (false, bytes),
+        };
+        let starts_with = |prefix: &[u8]| {
+            rest.len() >= prefix.len() && rest[..prefix.len()].eq_ignore_ascii_case(prefix)
+        };
+        if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity") {
+            return Some(Self::special(is_negative, Class::Infinite, 0));
+        }
+        let (class, payload) = if starts_with(b"nan") {
+            (Class::QuietNan, &rest[3..])
+        } else if starts_with(b"snan") {
+            (Class::SignalingNan, &rest[4..])
+        } else {
+            return None;
+        };
+        if payload.len() >= params.digits as usize || !payload.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let payload = payload.iter().fold(0, |value, &c| value * 10 + (c - b'0') as u128);
+        Some(Self::special(is_negative, class, payload))
+    }
+
+    /// Round a decimal numeric string to the nearest number, with ties to even.
+    ///
+    /// The quantum of the string is kept, unless the coefficient has
+    /// too many digits, or the exponent is out of range.
+    #[cfg(feature = "parse")]
+    fn round(number: &NumericStr, params: &Params) -> Self {
+        let is_negative = number.is_sign_negative();
+        let quantum = number.quantum();
+        let min_exponent = params.min_exponent();
+        if number.is_zero() {
+            let exponent = quantum.max(min_exponent).min(params.max_exponent);
+            return Self::finite(is_negative, 0, exponent);
+        }
+
+        // Drop digits if the coefficient is too long, or the exponent too small.
+        let count = number.exponent() - quantum + 1;
+        let dropped = (count - params.digits as i64).max(min_exponent - quantum).max(0);
+        let kept = count - dropped;
+        let mut digits = number.digits().map(|c| (c - b'0') as u128);
+        let mut coefficient = 0u128;
+        for _ in 0..kept.max(0) {
+            coefficient = coefficient * 10 + digits.next().unwrap_or(0);
+        }
+        let (round_digit, is_sticky) = if kept >= 0 {
+            (digits.next().unwrap_or(0), digits.any(|digit| digit != 0))
+        } else {
+            (0, true)
+        };
+        if round_digit > 5 || (round_digit == 5 && (is_sticky || coefficient & 1 == 1)) {
+            coefficient += 1;
+        }
+        let mut exponent = quantum + dropped;
+        if coefficient == params.max_coefficient() {
+            coefficient /= 10;
+            exponent += 1;
+        }
+
+        // Pad the coefficient with zeros if the exponent is too large.
+        if exponent > params.max_exponent {
+            let shift = exponent - params.max_exponent;
+            if coefficient == 0 {
+                exponent = params.max_exponent;
+            } else if count_digits(coefficient) as i64 + shift <= params.digits as i64 {
+                coefficient *= 10u128.pow(shift as u32);
+                exponent = params.max_exponent;
+            } else {
+                return Self::special(is_negative, Class::Infinite, 0);
+            }
+        }
+
+        Self::finite(is_negative, coefficient, exponent)
+    }
+
+    /// Parse a decimal numeric string or special value.
+    #[cfg(feature = "parse")]
+    fn parse<const FORMAT: u128>(
+        bytes: &[u8],
+        exponent: u8,
+        decimal_point: u8,
+        params: &Params,
+    ) -> Result<Self> {
+        match NumericStr::parse_saturating::<FORMAT>(bytes, exponent, decimal_point) {
+            Ok(number) => Ok(Self::round(&number, params)),
+            Err(error) => Self::parse_special(bytes, params).ok_or(error),
+        }
+    }
+
+    /// Write the number using the `to-scientific-string` conversion.
+    fn write(&self, buffer: &mut [u8]) -> usize {
+        let mut index = 0;
+        let mut push = |c: u8| {
+            buffer[index] = c;
+            index += 1;
+        };
+        if self.is_negative {
+            push(b'-');
+        }
+
+        // Write the digits of the coefficient to a temporary buffer.
+        let mut digits = [0u8; 40];
+        let count = count_digits(self.coefficient) as usize;
+        let mut value = self.coefficient;
+        for digit in digits[..count].iter_mut().rev() {
+            *digit = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        let digits = &digits[..count];
+
+        match self.class {
+            Class::Infinite => b"Infinity".iter().for_each(|&c| push(c)),
+            Class::QuietNan | Class::SignalingNan => {
+                if self.class == Class::SignalingNan {
+                    push(b's');
+                }
+                b"NaN".iter().for_each(|&c| push(c));
+                if self.coefficient != 0 {
+                    digits.iter().for_each(|&c| push(c));
+                }
+            },
+            Class::Finite => {
+                let adjusted = self.exponent + count as i64 - 1;
+                if self.exponent <= 0 && adjusted >= -6 {
+                    // Plain notation, with a decimal point if the exponent is negative.
+                    let point = count as i64 + self.exponent;
+                    if self.exponent == 0 {
+                        digits.iter().for_each(|&c| push(c));
+                    } else if point > 0 {
+                        let (integer, fraction) = digits.split_at(point as usize);
+                        integer.iter().for_each(|&c| push(c));
+                        push(b'.');
+                        fraction.iter().for_each(|&c| push(c));
+                    } else {
+                        push(b'0');
+                        push(b'.');
+                        (0..-point).for_each(|_| push(b'0'));
+                        digits.iter().for_each(|&c| push(c));
+                    }
+                } else {
+                    // Scientific notation, with a single leading digit.
+                    push(digits[0]);
+                    if count > 1 {
+                        push(b'.');
+                        digits[1..].iter().for_each(|&c| push(c));
+                    }
+                    push(b'E');
+                    push(if adjusted < 0 {
+                        b'-'
+                    } else {
+                        b'+'
+                    });
+                    let mut exponent = [0u8; 40];
+                    let length = count_digits(adjusted.unsigned_abs() as u128) as usize;
+                    let mut value = adjusted.unsigned_abs();
+                    for digit in exponent[..length].iter_mut().rev() {
+                        *digit = b'0' + (value % 10) as u8;
+                        value /= 10;
+                    }
+                    exponent[..length].iter().for_each(|&c| push(c));
+                }
+            },
+        }
+
+        index
+    }
+}
+
+macro_rules! decimal_impl {
+    ($(#[$meta:meta])* $name:ident, $bits:ty, $params:ident, $size:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name {
+            number: Unpacked,
+        }
+
+        impl $name {
+            /// Maximum number of bytes written by [`write`](Self::write).
+            pub const FORMATTED_SIZE: usize = $size;
+
+            /// Create a finite number from the sign, coefficient, and exponent.
+            ///
+            /// Returns `None` if the coefficient has too many digits, or
+            /// the exponent is out of range. The exponent is the exponent
+            /// of the last digit of the coefficient, so the value is
+            /// `coefficient * 10^exponent`.
+            #[inline]
+            pub fn new(is_negative: bool, coefficient: $bits, exponent: i32) -> Option<Self> {
+                let coefficient = coefficient as u128;
+                let exponent = exponent as i64;
+                if coefficient >= $params.max_coefficient()
+                    || exponent < $params.min_exponent()
+                    || exponent > $params.max_exponent
+                {
+                    return None;
+                }
+                Some(Self {
+                    number: Unpacked::finite(is_negative, coefficient, exponent),
+                })
+            }
+
+            /// Create an infinity with the given sign.
+            #[inline]
+            pub fn infinity(is_negative: bool) -> Self {
+                Self {
+                    number: Unpacked::special(is_negative, Class::Infinite, 0),
+                }
+            }
+
+            /// Create a quiet NaN, without a payload.
+            #[inline]
+            pub fn nan() -> Self {
+                Self {
+                    number: Unpacked::special(false, Class::QuietNan, 0),
+                }
+            }
+
+            /// Decode a number in the binary integer decimal (BID) encoding.
+            ///
+            /// Non-canonical coefficients and payloads are decoded as 0.
+            #[inline]
+            pub fn from_bid(bits: $bits) -> Self {
+                Self {
+                    number: Unpacked::from_bid(bits as u128, &$params),
+                }
+            }
+
+            /// Encode the number in the binary integer decimal (BID) encoding.
+            #[inline]
+            pub fn to_bid(self) -> $bits {
+                self.number.to_bid(&$params) as $bits
+            }
+
+            /// Decode a number in the densely packed decimal (DPD) encoding.
+            ///
+            /// Non-canonical coefficients and payloads are decoded as 0.
+            #[inline]
+            pub fn from_dpd(bits: $bits) -> Self {
+                Self {
+                    number: Unpacked::from_dpd(bits as u128, &$params),
+                }
+            }
+
+            /// Encode the number in the densely packed decimal (DPD) encoding.
+            #[inline]
+            pub fn to_dpd(self) -> $bits {
+                self.number.to_dpd(&$params) as $bits
+            }
+
+            /// Determine if the sign bit is set, including for zeros and NaNs.
+            #[inline(always)]
+            pub fn is_sign_negative(self) -> bool {
+                self.number.is_negative
+            }
+
+            /// Determine if the number is neither infinite nor NaN.
+            #[inline(always)]
+            pub fn is_finite(self) -> bool {
+                self.number.class == Class::Finite
+            }
+
+            /// Determine if the number is positive or negative infinity.
+            #[inline(always)]
+            pub fn is_infinite(self) -> bool {
+                self.number.class == Class::Infinite
+            }
+
+            /// Determine if the number is a quiet or signaling NaN.
+            #[inline(always)]
+            pub fn is_nan(self) -> bool {
+                matches!(self.number.class, Class::QuietNan | Class::SignalingNan)
+            }
+
+            /// Determine if the number is a signaling NaN.
+            #[inline(always)]
+            pub fn is_signaling_nan(self) -> bool {
+                self.number.class == Class::SignalingNan
+            }
+
+            /// Get the coefficient of a finite number, or the payload of a NaN.
+            #[inline(always)]
+            pub fn coefficient(self) -> $bits {
+                self.number.coefficient as $bits
+            }
+
+            /// Get the exponent of the last digit of the coefficient.
+            ///
+            /// This is 0 for infinities and NaNs.
+            #[inline(always)]
+            pub fn exponent(self) -> i32 {
+                self.number.exponent as i32
+            }
+
+            /// Parse a decimal numeric string, rounding to the nearest number.
+            ///
+            /// This accepts the same numbers as [`NumericStr::parse`], and
+            /// the special values `Infinity`, `Inf`, `NaN`, and `sNaN`,
+            /// ignoring case, with an optional sign and NaN payload. Numbers
+            /// that are too large are rounded to infinity.
+            #[inline]
+            #[cfg(feature = "parse")]
+            pub fn parse<const FORMAT: u128>(
+                bytes: &[u8],
+                exponent: u8,
+                decimal_point: u8,
+            ) -> Result<Self> {
+                Ok(Self {
+                    number: Unpacked::parse::<FORMAT>(bytes, exponent, decimal_point, &$params)?,
+                })
+            }
+
+            /// Write the number to the buffer, returning the number of bytes written.
+            ///
+            /// This uses the `to-scientific-string` conversion, so the
+            /// quantum is kept: numbers with a small negative exponent are
+            /// written with a decimal point, otherwise scientific notation
+            /// with an uppercase `E` and a signed exponent is used.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is smaller than the written number.
+            /// A buffer of [`FORMATTED_SIZE`](Self::FORMATTED_SIZE) bytes
+            /// is always large enough.
+            #[inline]
+            pub fn write(self, buffer: &mut [u8]) -> usize {
+                self.number.write(buffer)
+            }
+        }
+
+        #[cfg(feature = "parse")]
+        impl FromStr for $name {
+            type Err = Error;
+
+            #[inline]
+            fn from_str(string: &str) -> Result<Self> {
+                Self::parse::<STANDARD>(string.as_bytes(), b'e', b'.')
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buffer = [0u8; $size];
+                let count = self.write(&mut buffer);
+                f.write_str(core::str::from_utf8(&buffer[..count]).map_err(|_| fmt::Error)?)
+            }
+        }
+    };
+}
+
+decimal_impl!(
+    /// IEEE-754 `decimal64` number, with 16 digits of precision.
+    ///
+    /// Equality compares the representation, so `1.5` and `1.50` are
+    /// not equal, and NaNs are equal if their payloads are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")] {
+    /// use lexical_util::decimal::Decimal64;
+    ///
+    /// let number: Decimal64 = "-1.50".parse().unwrap();
+    /// assert_eq!(number.to_bid(), 0xB180_0000_0000_0096);
+    /// assert_eq!(number.to_dpd(), 0xA230_0000_0000_00D0);
+    /// assert_eq!(Decimal64::from_dpd(0xA230_0000_0000_00D0), number);
+    /// assert_eq!(number.to_string(), "-1.50");
+    /// # }
+    /// ```
+    Decimal64,
+    u64,
+    DECIMAL64,
+    24
+);
+decimal_impl!(
+    /// IEEE-754 `decimal128` number, with 34 digits of precision.
+    ///
+    /// Equality compares the representation, so `1.5` and `1.50` are
+    /// not equal, and NaNs are equal if their payloads are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")] {
+    /// use lexical_util::decimal::Decimal128;
+    ///
+    /// let number: Decimal128 = "1E+3".parse().unwrap();
+    /// assert_eq!(number.to_bid(), 0x3046_0000_0000_0000_0000_0000_0000_0001);
+    /// assert_eq!(number.to_dpd(), 0x2208_C000_0000_0000_0000_0000_0000_0001);
+    /// assert_eq!(number.to_string(), "1E+3");
+    /// # }
+    /// ```
+    Decimal128,
+    u128,
+    DECIMAL128,
+    43
+);
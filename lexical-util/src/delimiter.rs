@@ -0,0 +1,98 @@
+//! Sets of bytes that may terminate a number.
+//!
+//! These are stored as a 256-bit lookup table, so checking if a byte
+//! is a delimiter is a single shift and mask, which is useful for
+//! tokenizers parsing numbers embedded in JSON, CSV, or similar data.
+
+#![cfg(feature = "parse")]
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// Set of bytes that are valid terminators after a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Delimiters {
+    /// Bits for the bytes `[0x00, 0x80)`.
+    lo: u128,
+    /// Bits for the bytes `[0x80, 0xFF]`.
+    hi: u128,
+}
+
+impl Delimiters {
+    /// ASCII whitespace: space, tab, newline, form feed, and carriage return.
+    pub const WHITESPACE: Self = Self::from_bytes(b" \t\n\x0C\r");
+
+    /// Delimiters after a number in JSON: whitespace, `,`, `]`, and `}`.
+    pub const JSON: Self = Self::WHITESPACE.add(b',').add(b']').add(b'}');
+
+    /// Delimiters after a number in CSV: `,`, newline, and carriage return.
+    pub const CSV: Self = Self::from_bytes(b",\n\r");
+
+    /// Create an empty set of delimiters.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            lo: 0,
+            hi: 0,
+        }
+    }
+
+    /// Create a set of delimiters from each byte in the slice.
+    /// Modified to be used in a const fn, since for loops and iter don't work.
+    #[inline]
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut delimiters = Self::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            delimiters = delimiters.add(bytes[index]);
+            index += 1;
+        }
+        delimiters
+    }
+
+    /// Add a byte to the set of delimiters.
+    #[inline(always)]
+    pub const fn add(self, byte: u8) -> Self {
+        let bit = 1u128 << (byte & 0x7F);
+        if byte < 0x80 {
+            Self {
+                lo: self.lo | bit,
+                hi: self.hi,
+            }
+        } else {
+            Self {
+                lo: self.lo,
+                hi: self.hi | bit,
+            }
+        }
+    }
+
+    /// Determine if the byte is a delimiter.
+    #[inline(always)]
+    pub const fn contains(&self, byte: u8) -> bool {
+        let bits = if byte < 0x80 {
+            self.lo
+        } else {
+            self.hi
+        };
+        bits & (1u128 << (byte & 0x7F)) != 0
+    }
+
+    /// Check a partially parsed number was followed by a delimiter.
+    ///
+    /// The number must either be at the end of the bytes, or followed by
+    /// a delimiter. Partial parsers may not consume any bytes, so this
+    /// also rejects delimiters without any preceding digits.
+    ///
+    /// * `bytes`   - Bytes the number was parsed from.
+    /// * `value`   - Parsed value.
+    /// * `count`   - Number of bytes consumed by the partial parser.
+    #[inline]
+    pub fn check_partial<N>(&self, bytes: &[u8], value: N, count: usize) -> Result<(N, usize)> {
+        match bytes.get(count) {
+            _ if count == 0 => Err(Error::Empty(0)),
+            Some(&c) if !self.contains(c) => Err(Error::InvalidDigit(count)),
+            _ => Ok((value, count)),
+        }
+    }
+}
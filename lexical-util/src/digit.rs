@@ -10,6 +10,10 @@
 // which is **most** of our cases. There are cases where for codegen, using a
 // runtime algorithm is preferable.
 
+// Radixes up to 36 use the case-insensitive alphabet `0-9A-Z`. Larger
+// radixes, up to 62, use the extended, case-sensitive alphabet `0-9A-Za-z`,
+// where `a` has the value 36.
+
 /// Unchecked, highly optimized algorithm to convert a char to a digit.
 /// This only works if the input character is known to be a valid digit.
 #[inline]
@@ -17,7 +21,7 @@ pub const fn char_to_valid_digit_const(c: u8, radix: u32) -> u32 {
     if radix <= 10 {
         // Optimize for small radixes.
         (c.wrapping_sub(b'0')) as u32
-    } else {
+    } else if radix <= 36 {
         // Fallback, still decently fast.
         let digit = match c {
             b'0'..=b'9' => c - b'0',
@@ -26,6 +30,15 @@ pub const fn char_to_valid_digit_const(c: u8, radix: u32) -> u32 {
             _ => 0xFF,
         };
         digit as u32
+    } else {
+        // Extended alphabet, where letters are case-sensitive.
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'A'..=b'Z' => c - b'A' + 10,
+            b'a'..=b'z' => c - b'a' + 36,
+            _ => 0xFF,
+        };
+        digit as u32
     }
 }
 
@@ -59,8 +72,10 @@ pub const fn digit_to_char_const(digit: u32, radix: u32) -> u8 {
     if radix <= 10 || digit < 10 {
         // Can short-circuit if we know the radix is small at compile time.
         digit as u8 + b'0'
-    } else {
+    } else if radix <= 36 || digit < 36 {
         digit as u8 + b'A' - 10
+    } else {
+        digit as u8 + b'a' - 36
     }
 }
 
@@ -79,7 +94,8 @@ pub const fn char_to_digit(c: u8, radix: u32) -> Option<u32> {
     let digit = match c {
         b'0'..=b'9' => c - b'0',
         b'A'..=b'Z' => c - b'A' + 10,
-        b'a'..=b'z' => c - b'a' + 10,
+        b'a'..=b'z' if radix <= 36 => c - b'a' + 10,
+        b'a'..=b'z' => c - b'a' + 36,
         _ => 0xFF,
     } as u32;
     if digit < radix {
@@ -96,19 +112,140 @@ pub const fn char_is_digit(c: u8, radix: u32) -> bool {
     char_to_digit(c, radix).is_some()
 }
 
+/// Characters for each digit, in the extended alphabet `0-9A-Za-z`.
+#[cfg(feature = "write")]
+const DIGIT_TO_CHAR: [u8; 62] = [
+    b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F',
+    b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V',
+    b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l',
+    b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z',
+];
+
 /// Convert a digit to a character. This uses a pre-computed table to avoid branching.
 ///
 /// # Safety
 ///
-/// Safe as long as `digit < 36`.
+/// Safe as long as `digit < 62`.
 #[inline]
 #[cfg(feature = "write")]
 pub unsafe fn digit_to_char(digit: u32) -> u8 {
-    const TABLE: [u8; 36] = [
-        b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E',
-        b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T',
-        b'U', b'V', b'W', b'X', b'Y', b'Z',
-    ];
-    debug_assert!(digit < 36, "digit_to_char() invalid character.");
-    unsafe { *TABLE.get_unchecked(digit as usize) }
+    debug_assert!(digit < 62, "digit_to_char() invalid character.");
+    unsafe { *DIGIT_TO_CHAR.get_unchecked(digit as usize) }
+}
+
+/// The case of the letters for digits from 10 to 35.
+///
+/// Radixes above 36 use the case-sensitive extended alphabet, so
+/// the case only applies to radixes up to 36.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg(feature = "write")]
+pub enum DigitCase {
+    /// Uppercase letters, like `0-9A-Z`, as written by lexical.
+    Upper,
+    /// Lowercase letters, like `0-9a-z`.
+    Lower,
+}
+
+/// Convert a digit to a character, with the case of the letters.
+///
+/// Returns `None` if the radix is not from 2 to 62, or the digit is not
+/// below the radix. Lowercase letters are selected by offsetting the
+/// index into the table, so the conversion does not branch on the digit.
+#[inline]
+#[cfg(feature = "write")]
+pub const fn digit_to_char_case(digit: u32, radix: u32, case: DigitCase) -> Option<u8> {
+    if radix < 2 || radix > 62 || digit >= radix {
+        return None;
+    }
+    let is_lower = matches!(case, DigitCase::Lower) && radix <= 36 && digit >= 10;
+    Some(DIGIT_TO_CHAR[digit as usize + 26 * is_lower as usize])
+}
+
+// DIGIT RUNS
+// ----------
+
+/// Determine if 8 bytes, read raw from bytes, are 8 digits for the radix.
+///
+/// This validates all 8 bytes at once, using only integer instructions
+/// (SWAR), so it works on every target. The radix must be `<= 10`.
+#[inline]
+#[cfg(feature = "parse")]
+pub const fn is_8digits(v: u64, radix: u32) -> bool {
+    debug_assert!(radix <= 10);
+
+    // We want to have a wrapping add and sub such that only values from the
+    // range `[0x30, 0x30 + radix)` will not overflow into the high bit.
+    let add = 0x46 + 10 - radix;
+    let add = add + (add << 8) + (add << 16) + (add << 24);
+    let add = (add as u64) | ((add as u64) << 32);
+    // This aims to underflow if anything is below the min digit: if we have any
+    // values under `0x30`, then this underflows and wraps into the high bit.
+    let sub = 0x3030_3030_3030_3030;
+    let a = v.wrapping_add(add);
+    let b = v.wrapping_sub(sub);
+
+    (a | b) & 0x8080_8080_8080_8080 == 0
+}
+
+/// Count the number of leading digits in the radix.
+///
+/// This is useful for tokenizers to find the end of a run of digits
+/// before calling a parser. For radixes `<= 10`, this validates
+/// 8 bytes at a time.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn count_digits(bytes: &[u8], radix: u32) -> usize {
+    let mut index = 0;
+    if radix <= 10 {
+        #[cfg(feature = "nightly")]
+        {
+            index = count_16digits(bytes, radix);
+        }
+        while let Some(v) = crate::endian::read_u64_le(&bytes[index..]) {
+            if !is_8digits(v, radix) {
+                break;
+            }
+            index += 8;
+        }
+    }
+    while index < bytes.len() && char_is_digit_const(bytes[index], radix) {
+        index += 1;
+    }
+    index
+}
+
+/// Count the number of leading digits, 16 bytes at a time.
+///
+/// This uses portable SIMD, so it is vectorized on every target
+/// with SIMD support, and stops at the first chunk with a non-digit.
+/// The radix must be `<= 10`.
+#[inline]
+#[cfg(all(feature = "parse", feature = "nightly"))]
+fn count_16digits(bytes: &[u8], radix: u32) -> usize {
+    use core::simd::cmp::SimdPartialOrd;
+    use core::simd::u8x16;
+
+    debug_assert!(radix <= 10);
+    // Subtracting `0` wraps any byte below it, so a single comparison
+    // validates the range `[0x30, 0x30 + radix)`.
+    let zero = u8x16::splat(b'0');
+    let radix = u8x16::splat(radix as u8);
+    let mut index = 0;
+    for chunk in bytes.chunks_exact(16) {
+        let is_digit = (u8x16::from_slice(chunk) - zero).simd_lt(radix);
+        if !is_digit.all() {
+            return index + (!is_digit.to_bitmask()).trailing_zeros() as usize;
+        }
+        index += 16;
+    }
+    index
+}
+
+/// Determine if all bytes are digits in the radix.
+///
+/// Returns true for an empty slice.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn is_all_digits(bytes: &[u8], radix: u32) -> bool {
+    count_digits(bytes, radix) == bytes.len()
 }
@@ -40,9 +40,6 @@
 //!
 //! [`etc/div128.py`]: https://github.com/Alexhuszagh/rust-lexical/blob/main/lexical-util/etc/div128.py
 
-#![cfg(not(feature = "compact"))]
-#![cfg(feature = "write")]
-
 use crate::assert::debug_assert_radix;
 use crate::mul::mulhi;
 
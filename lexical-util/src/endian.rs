@@ -0,0 +1,74 @@
+//! Endian-aware utilities to read words from bytes.
+//!
+//! The word-at-a-time algorithms, such as validating or parsing 8 digits
+//! at a time, assume the first byte of the input is the least-significant
+//! byte of the word. Reading the bytes with the native byte order is only
+//! correct on little-endian architectures, so these utilities always read
+//! in a fixed byte order, and are correct on big-endian architectures,
+//! such as s390x or powerpc64.
+//!
+//! These are safe, and return `None` if the buffer is too short. Any
+//! bytes after the word are ignored.
+
+/// Read the first 4 bytes as a little-endian 32-bit integer.
+#[inline]
+pub fn read_u32_le(bytes: &[u8]) -> Option<u32> {
+    let mut buffer = [0u8; 4];
+    buffer.copy_from_slice(bytes.get(..4)?);
+    Some(u32::from_le_bytes(buffer))
+}
+
+/// Read the first 4 bytes as a big-endian 32-bit integer.
+#[inline]
+pub fn read_u32_be(bytes: &[u8]) -> Option<u32> {
+    let mut buffer = [0u8; 4];
+    buffer.copy_from_slice(bytes.get(..4)?);
+    Some(u32::from_be_bytes(buffer))
+}
+
+/// Read the first 8 bytes as a little-endian 64-bit integer.
+#[inline]
+pub fn read_u64_le(bytes: &[u8]) -> Option<u64> {
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(bytes.get(..8)?);
+    Some(u64::from_le_bytes(buffer))
+}
+
+/// Read the first 8 bytes as a big-endian 64-bit integer.
+#[inline]
+pub fn read_u64_be(bytes: &[u8]) -> Option<u64> {
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(bytes.get(..8)?);
+    Some(u64::from_be_bytes(buffer))
+}
+
+/// Read up to 4 bytes as a little-endian 32-bit integer, right-aligned.
+///
+/// Missing leading bytes are filled with `pad`, so the bytes are the
+/// most-significant bytes of the word. For example, reading `b"12"`
+/// with `b'0'` padding is identical to reading `b"0012"`. Returns
+/// `None` if the buffer is longer than 4 bytes.
+#[inline]
+pub fn read_u32_le_padded(bytes: &[u8], pad: u8) -> Option<u32> {
+    if bytes.len() > 4 {
+        return None;
+    }
+    let mut buffer = [pad; 4];
+    buffer[4 - bytes.len()..].copy_from_slice(bytes);
+    Some(u32::from_le_bytes(buffer))
+}
+
+/// Read up to 8 bytes as a little-endian 64-bit integer, right-aligned.
+///
+/// Missing leading bytes are filled with `pad`, so the bytes are the
+/// most-significant bytes of the word. Returns `None` if the buffer
+/// is longer than 8 bytes.
+#[inline]
+pub fn read_u64_le_padded(bytes: &[u8], pad: u8) -> Option<u64> {
+    if bytes.len() > 8 {
+        return None;
+    }
+    let mut buffer = [pad; 8];
+    buffer[8 - bytes.len()..].copy_from_slice(bytes);
+    Some(u64::from_le_bytes(buffer))
+}
@@ -51,6 +51,18 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Number split across buffers is too long to carry over.
+    NumberTooLong(usize),
+    /// Number was parsed, but is outside the requested range.
+    OutOfRange(usize),
+    /// Exponent has more digits than allowed.
+    ExponentTooLong(usize),
+    /// Exponent is too large to be represented.
+    ExponentOverflow(usize),
+    /// Number was parsed, but could not be converted to the requested type.
+    ConversionFailed(usize),
+    /// Buffer is too small for the written number, with the required length.
+    BufferTooSmall(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -71,7 +83,8 @@ pub enum Error {
     InvalidBaseSuffix,
     /// Invalid punctuation characters: multiple symbols overlap.
     InvalidPunctuation,
-    /// Optional exponent flags were set while disabling exponent notation.
+    /// Optional exponent flags were set while disabling exponent notation,
+    /// or `d` is an exponent character while it is a valid digit.
     InvalidExponentFlags,
     /// Set no positive mantissa sign while requiring mantissa signs.
     InvalidMantissaSign,
@@ -113,12 +126,352 @@ pub enum Error {
     InvalidNegativeExponentBreak,
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
+    /// Invalid minimum exponent digits: too many digits.
+    InvalidExponentDigits,
+    /// Invalid boolean strings: must be non-empty and distinct.
+    InvalidBoolString,
+    /// Boolean string is too long.
+    BoolStringTooLong,
+
+    // UNKNOWN ERRORS
+    /// Error from an unknown error code, such as one added in a later version.
+    Unknown,
 
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
     Success,
 }
 
+/// Stable, numeric codes for each error.
+///
+/// The values are documented and never change, so they can be reported
+/// across a C ABI or language bindings. Parse errors are from 1 to 99,
+/// number format errors are from 100 to 199, and option errors are from
+/// 200 to 299. New codes may be added, but existing codes are never
+/// reused or renumbered.
+///
+/// Since new codes may be added, the enum is non-exhaustive. Codes
+/// received across an FFI boundary, which may be from a later version,
+/// should be converted with [`from_code_or_unknown`], which converts codes
+/// this version doesn't know to [`Unknown`], rather than rejecting them.
+/// Use [`ALL`] and [`Error::from_code`] to produce every error, such as to
+/// test that an error-mapping layer handles each code.
+///
+/// ```rust
+/// use lexical_util::error::{Error, ErrorCode};
+///
+/// fn map_error(code: u32) -> &'static str {
+///     match ErrorCode::from_code_or_unknown(code) {
+///         ErrorCode::Success => "ok",
+///         ErrorCode::Overflow | ErrorCode::Underflow => "out of range",
+///         ErrorCode::Unknown => "unknown",
+///         _ => "invalid",
+///     }
+/// }
+///
+/// assert_eq!(map_error(0), "ok");
+/// assert_eq!(map_error(2), "out of range");
+/// assert_eq!(map_error(3), "invalid");
+/// assert_eq!(map_error(299), "unknown");
+/// for &code in ErrorCode::ALL.iter() {
+///     assert_ne!(map_error(Error::from_code(code, 0).code()), "unknown");
+/// }
+/// ```
+///
+/// [`from_code_or_unknown`]: Self::from_code_or_unknown
+/// [`Unknown`]: Self::Unknown
+/// [`ALL`]: Self::ALL
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum ErrorCode {
+    // PARSE ERRORS
+    /// Integral overflow occurred during numeric parsing.
+    Overflow                                 = 1,
+    /// Integral underflow occurred during numeric parsing.
+    Underflow                                = 2,
+    /// Invalid digit found before string termination.
+    InvalidDigit                             = 3,
+    /// Empty byte array found.
+    Empty                                    = 4,
+    /// Empty mantissa found.
+    EmptyMantissa                            = 5,
+    /// Empty exponent found.
+    EmptyExponent                            = 6,
+    /// Empty integer found.
+    EmptyInteger                             = 7,
+    /// Empty fraction found.
+    EmptyFraction                            = 8,
+    /// Invalid positive mantissa sign was found.
+    InvalidPositiveMantissaSign              = 9,
+    /// Mantissa sign was required(usize), but not found.
+    MissingMantissaSign                      = 10,
+    /// Exponent was present but not allowed.
+    InvalidExponent                          = 11,
+    /// Invalid positive exponent sign was found.
+    InvalidPositiveExponentSign              = 12,
+    /// Exponent sign was required(usize), but not found.
+    MissingExponentSign                      = 13,
+    /// Exponent was present without fraction component.
+    ExponentWithoutFraction                  = 14,
+    /// Integer or integer component of float had invalid leading zeros.
+    InvalidLeadingZeros                      = 15,
+    /// No exponent with required exponent notation.
+    MissingExponent                          = 16,
+    /// Integral sign was required(usize), but not found.
+    MissingSign                              = 17,
+    /// Invalid positive sign for an integer was found.
+    InvalidPositiveSign                      = 18,
+    /// Invalid negative sign for an unsigned type was found.
+    InvalidNegativeSign                      = 19,
+    /// Number split across buffers is too long to carry over.
+    NumberTooLong                            = 20,
+    /// Number was parsed, but is outside the requested range.
+    OutOfRange                               = 21,
+    /// Exponent has more digits than allowed.
+    ExponentTooLong                          = 22,
+    /// Exponent is too large to be represented.
+    ExponentOverflow                         = 23,
+    /// Number was parsed, but could not be converted to the requested type.
+    ConversionFailed                         = 24,
+    /// Buffer is too small for the written number, with the required length.
+    BufferTooSmall                           = 25,
+
+    // NUMBER FORMAT ERRORS
+    /// Invalid radix for the mantissa (significant) digits.
+    InvalidMantissaRadix                     = 100,
+    /// Invalid base for the exponent.
+    InvalidExponentBase                      = 101,
+    /// Invalid radix for the exponent digits.
+    InvalidExponentRadix                     = 102,
+    /// Invalid digit separator character.
+    InvalidDigitSeparator                    = 103,
+    /// Invalid decimal point character.
+    InvalidDecimalPoint                      = 104,
+    /// Invalid symbol to represent exponent notation.
+    InvalidExponentSymbol                    = 105,
+    /// Invalid character for a base prefix.
+    InvalidBasePrefix                        = 106,
+    /// Invalid character for a base suffix.
+    InvalidBaseSuffix                        = 107,
+    /// Invalid punctuation characters: multiple symbols overlap.
+    InvalidPunctuation                       = 108,
+    /// Optional exponent flags were set while disabling exponent notation,
+    /// or `d` is an exponent character while it is a valid digit.
+    InvalidExponentFlags                     = 109,
+    /// Set no positive mantissa sign while requiring mantissa signs.
+    InvalidMantissaSign                      = 110,
+    /// Set no positive exponent sign while requiring exponent signs.
+    InvalidExponentSign                      = 111,
+    /// Set optional special float flags while disable special floats.
+    InvalidSpecial                           = 112,
+    /// Invalid consecutive integer digit separator.
+    InvalidConsecutiveIntegerDigitSeparator  = 113,
+    /// Invalid consecutive fraction digit separator.
+    InvalidConsecutiveFractionDigitSeparator = 114,
+    /// Invalid consecutive exponent digit separator.
+    InvalidConsecutiveExponentDigitSeparator = 115,
+    /// Invalid flags were set without the format feature.
+    InvalidFlags                             = 116,
+
+    // OPTION ERRORS
+    /// Invalid NaN string: must start with an `n` character.
+    InvalidNanString                         = 200,
+    /// NaN string is too long.
+    NanStringTooLong                         = 201,
+    /// Invalid short infinity string: must start with an `i` character.
+    InvalidInfString                         = 202,
+    /// Short infinity string is too long.
+    InfStringTooLong                         = 203,
+    /// Invalid long infinity string: must start with an `i` character.
+    InvalidInfinityString                    = 204,
+    /// Long infinity string is too long.
+    InfinityStringTooLong                    = 205,
+    /// Long infinity string is too short: it must be as long as short infinity.
+    InfinityStringTooShort                   = 206,
+    /// Invalid float parsing algorithm.
+    InvalidFloatParseAlgorithm               = 207,
+    /// Invalid radix for the significant digits.
+    InvalidRadix                             = 208,
+    /// Invalid precision flags for writing floats.
+    InvalidFloatPrecision                    = 209,
+    /// Invalid negative exponent break: break is above 0.
+    InvalidNegativeExponentBreak             = 210,
+    /// Invalid positive exponent break: break is below 0.
+    InvalidPositiveExponentBreak             = 211,
+    /// Invalid minimum exponent digits: too many digits.
+    InvalidExponentDigits                    = 212,
+    /// Invalid boolean strings: must be non-empty and distinct.
+    InvalidBoolString                        = 213,
+    /// Boolean string is too long.
+    BoolStringTooLong                        = 214,
+
+    // UNKNOWN ERRORS
+    /// Unknown error code, such as one added in a later version.
+    ///
+    /// This is never returned by [`ErrorCode::from_code`], and no error
+    /// produced by this version has this code.
+    Unknown                                  = u32::MAX,
+
+    // NOT AN ERROR
+    /// An error did not actually occur, and the result was successful.
+    Success                                  = 0,
+}
+
+impl ErrorCode {
+    /// Every error code produced by this version, in numeric order.
+    ///
+    /// This includes [`Success`](Self::Success), but not
+    /// [`Unknown`](Self::Unknown). This is a slice, so later versions can
+    /// add codes without changing its type.
+    pub const ALL: &'static [Self] = &[
+        Self::Success,
+        Self::Overflow,
+        Self::Underflow,
+        Self::InvalidDigit,
+        Self::Empty,
+        Self::EmptyMantissa,
+        Self::EmptyExponent,
+        Self::EmptyInteger,
+        Self::EmptyFraction,
+        Self::InvalidPositiveMantissaSign,
+        Self::MissingMantissaSign,
+        Self::InvalidExponent,
+        Self::InvalidPositiveExponentSign,
+        Self::MissingExponentSign,
+        Self::ExponentWithoutFraction,
+        Self::InvalidLeadingZeros,
+        Self::MissingExponent,
+        Self::MissingSign,
+        Self::InvalidPositiveSign,
+        Self::InvalidNegativeSign,
+        Self::NumberTooLong,
+        Self::OutOfRange,
+        Self::ExponentTooLong,
+        Self::ExponentOverflow,
+        Self::ConversionFailed,
+        Self::BufferTooSmall,
+        Self::InvalidMantissaRadix,
+        Self::InvalidExponentBase,
+        Self::InvalidExponentRadix,
+        Self::InvalidDigitSeparator,
+        Self::InvalidDecimalPoint,
+        Self::InvalidExponentSymbol,
+        Self::InvalidBasePrefix,
+        Self::InvalidBaseSuffix,
+        Self::InvalidPunctuation,
+        Self::InvalidExponentFlags,
+        Self::InvalidMantissaSign,
+        Self::InvalidExponentSign,
+        Self::InvalidSpecial,
+        Self::InvalidConsecutiveIntegerDigitSeparator,
+        Self::InvalidConsecutiveFractionDigitSeparator,
+        Self::InvalidConsecutiveExponentDigitSeparator,
+        Self::InvalidFlags,
+        Self::InvalidNanString,
+        Self::NanStringTooLong,
+        Self::InvalidInfString,
+        Self::InfStringTooLong,
+        Self::InvalidInfinityString,
+        Self::InfinityStringTooLong,
+        Self::InfinityStringTooShort,
+        Self::InvalidFloatParseAlgorithm,
+        Self::InvalidRadix,
+        Self::InvalidFloatPrecision,
+        Self::InvalidNegativeExponentBreak,
+        Self::InvalidPositiveExponentBreak,
+        Self::InvalidExponentDigits,
+        Self::InvalidBoolString,
+        Self::BoolStringTooLong,
+    ];
+
+    /// Get the numeric value of the error code.
+    #[inline(always)]
+    pub const fn to_code(self) -> u32 {
+        self as u32
+    }
+
+    /// Get the error code from the numeric value, if the value is valid.
+    pub const fn from_code(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(Self::Overflow),
+            2 => Some(Self::Underflow),
+            3 => Some(Self::InvalidDigit),
+            4 => Some(Self::Empty),
+            5 => Some(Self::EmptyMantissa),
+            6 => Some(Self::EmptyExponent),
+            7 => Some(Self::EmptyInteger),
+            8 => Some(Self::EmptyFraction),
+            9 => Some(Self::InvalidPositiveMantissaSign),
+            10 => Some(Self::MissingMantissaSign),
+            11 => Some(Self::InvalidExponent),
+            12 => Some(Self::InvalidPositiveExponentSign),
+            13 => Some(Self::MissingExponentSign),
+            14 => Some(Self::ExponentWithoutFraction),
+            15 => Some(Self::InvalidLeadingZeros),
+            16 => Some(Self::MissingExponent),
+            17 => Some(Self::MissingSign),
+            18 => Some(Self::InvalidPositiveSign),
+            19 => Some(Self::InvalidNegativeSign),
+            20 => Some(Self::NumberTooLong),
+            21 => Some(Self::OutOfRange),
+            22 => Some(Self::ExponentTooLong),
+            23 => Some(Self::ExponentOverflow),
+            24 => Some(Self::ConversionFailed),
+            25 => Some(Self::BufferTooSmall),
+            100 => Some(Self::InvalidMantissaRadix),
+            101 => Some(Self::InvalidExponentBase),
+            102 => Some(Self::InvalidExponentRadix),
+            103 => Some(Self::InvalidDigitSeparator),
+            104 => Some(Self::InvalidDecimalPoint),
+            105 => Some(Self::InvalidExponentSymbol),
+            106 => Some(Self::InvalidBasePrefix),
+            107 => Some(Self::InvalidBaseSuffix),
+            108 => Some(Self::InvalidPunctuation),
+            109 => Some(Self::InvalidExponentFlags),
+            110 => Some(Self::InvalidMantissaSign),
+            111 => Some(Self::InvalidExponentSign),
+            112 => Some(Self::InvalidSpecial),
+            113 => Some(Self::InvalidConsecutiveIntegerDigitSeparator),
+            114 => Some(Self::InvalidConsecutiveFractionDigitSeparator),
+            115 => Some(Self::InvalidConsecutiveExponentDigitSeparator),
+            116 => Some(Self::InvalidFlags),
+            200 => Some(Self::InvalidNanString),
+            201 => Some(Self::NanStringTooLong),
+            202 => Some(Self::InvalidInfString),
+            203 => Some(Self::InfStringTooLong),
+            204 => Some(Self::InvalidInfinityString),
+            205 => Some(Self::InfinityStringTooLong),
+            206 => Some(Self::InfinityStringTooShort),
+            207 => Some(Self::InvalidFloatParseAlgorithm),
+            208 => Some(Self::InvalidRadix),
+            209 => Some(Self::InvalidFloatPrecision),
+            210 => Some(Self::InvalidNegativeExponentBreak),
+            211 => Some(Self::InvalidPositiveExponentBreak),
+            212 => Some(Self::InvalidExponentDigits),
+            213 => Some(Self::InvalidBoolString),
+            214 => Some(Self::BoolStringTooLong),
+            0 => Some(Self::Success),
+            _ => None,
+        }
+    }
+
+    /// Get the error code from the numeric value, or [`Unknown`] if the
+    /// value is not valid.
+    ///
+    /// Use this for codes from other versions, such as across an FFI
+    /// boundary, so codes added later are handled as unknown errors.
+    ///
+    /// [`Unknown`]: Self::Unknown
+    #[inline]
+    pub const fn from_code_or_unknown(code: u32) -> Self {
+        match Self::from_code(code) {
+            Some(code) => code,
+            None => Self::Unknown,
+        }
+    }
+}
+
 // Ensure we don't have extra padding on the structure.
 const_assert!(mem::size_of::<Error>() <= 2 * mem::size_of::<usize>());
 
@@ -160,6 +513,12 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::NumberTooLong(index) => Some(index),
+            Self::OutOfRange(index) => Some(index),
+            Self::ExponentTooLong(index) => Some(index),
+            Self::ExponentOverflow(index) => Some(index),
+            Self::ConversionFailed(index) => Some(index),
+            Self::BufferTooSmall(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -193,12 +552,284 @@ impl Error {
             Self::InvalidFloatPrecision => None,
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidExponentDigits => None,
+            Self::InvalidBoolString => None,
+            Self::BoolStringTooLong => None,
+
+            // UNKNOWN ERRORS
+            Self::Unknown => None,
 
             // NOT AN ERROR
             Self::Success => None,
         }
     }
 
+    /// Get the index for the parsing error as a `u32`.
+    ///
+    /// This avoids `usize` in the API on 16-bit and 32-bit targets. Indexes
+    /// larger than `u32::MAX`, which only occur for inputs longer than
+    /// 4 GiB, saturate to `u32::MAX`.
+    #[inline]
+    pub fn index_u32(&self) -> Option<u32> {
+        self.index().map(|&index| {
+            if index as u64 > u32::MAX as u64 {
+                u32::MAX
+            } else {
+                index as u32
+            }
+        })
+    }
+
+    /// Get a stable, numeric code for the error.
+    ///
+    /// See [`ErrorCode`] for the values of each error.
+    pub const fn code(&self) -> u32 {
+        self.error_code().to_code()
+    }
+
+    /// Get the error code, without the index.
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::Overflow(_) => ErrorCode::Overflow,
+            Self::Underflow(_) => ErrorCode::Underflow,
+            Self::InvalidDigit(_) => ErrorCode::InvalidDigit,
+            Self::Empty(_) => ErrorCode::Empty,
+            Self::EmptyMantissa(_) => ErrorCode::EmptyMantissa,
+            Self::EmptyExponent(_) => ErrorCode::EmptyExponent,
+            Self::EmptyInteger(_) => ErrorCode::EmptyInteger,
+            Self::EmptyFraction(_) => ErrorCode::EmptyFraction,
+            Self::InvalidPositiveMantissaSign(_) => ErrorCode::InvalidPositiveMantissaSign,
+            Self::MissingMantissaSign(_) => ErrorCode::MissingMantissaSign,
+            Self::InvalidExponent(_) => ErrorCode::InvalidExponent,
+            Self::InvalidPositiveExponentSign(_) => ErrorCode::InvalidPositiveExponentSign,
+            Self::MissingExponentSign(_) => ErrorCode::MissingExponentSign,
+            Self::ExponentWithoutFraction(_) => ErrorCode::ExponentWithoutFraction,
+            Self::InvalidLeadingZeros(_) => ErrorCode::InvalidLeadingZeros,
+            Self::MissingExponent(_) => ErrorCode::MissingExponent,
+            Self::MissingSign(_) => ErrorCode::MissingSign,
+            Self::InvalidPositiveSign(_) => ErrorCode::InvalidPositiveSign,
+            Self::InvalidNegativeSign(_) => ErrorCode::InvalidNegativeSign,
+            Self::NumberTooLong(_) => ErrorCode::NumberTooLong,
+            Self::OutOfRange(_) => ErrorCode::OutOfRange,
+            Self::ExponentTooLong(_) => ErrorCode::ExponentTooLong,
+            Self::ExponentOverflow(_) => ErrorCode::ExponentOverflow,
+            Self::ConversionFailed(_) => ErrorCode::ConversionFailed,
+            Self::BufferTooSmall(_) => ErrorCode::BufferTooSmall,
+            Self::InvalidMantissaRadix => ErrorCode::InvalidMantissaRadix,
+            Self::InvalidExponentBase => ErrorCode::InvalidExponentBase,
+            Self::InvalidExponentRadix => ErrorCode::InvalidExponentRadix,
+            Self::InvalidDigitSeparator => ErrorCode::InvalidDigitSeparator,
+            Self::InvalidDecimalPoint => ErrorCode::InvalidDecimalPoint,
+            Self::InvalidExponentSymbol => ErrorCode::InvalidExponentSymbol,
+            Self::InvalidBasePrefix => ErrorCode::InvalidBasePrefix,
+            Self::InvalidBaseSuffix => ErrorCode::InvalidBaseSuffix,
+            Self::InvalidPunctuation => ErrorCode::InvalidPunctuation,
+            Self::InvalidExponentFlags => ErrorCode::InvalidExponentFlags,
+            Self::InvalidMantissaSign => ErrorCode::InvalidMantissaSign,
+            Self::InvalidExponentSign => ErrorCode::InvalidExponentSign,
+            Self::InvalidSpecial => ErrorCode::InvalidSpecial,
+            Self::InvalidConsecutiveIntegerDigitSeparator => {
+                ErrorCode::InvalidConsecutiveIntegerDigitSeparator
+            },
+            Self::InvalidConsecutiveFractionDigitSeparator => {
+                ErrorCode::InvalidConsecutiveFractionDigitSeparator
+            },
+            Self::InvalidConsecutiveExponentDigitSeparator => {
+                ErrorCode::InvalidConsecutiveExponentDigitSeparator
+            },
+            Self::InvalidFlags => ErrorCode::InvalidFlags,
+            Self::InvalidNanString => ErrorCode::InvalidNanString,
+            Self::NanStringTooLong => ErrorCode::NanStringTooLong,
+            Self::InvalidInfString => ErrorCode::InvalidInfString,
+            Self::InfStringTooLong => ErrorCode::InfStringTooLong,
+            Self::InvalidInfinityString => ErrorCode::InvalidInfinityString,
+            Self::InfinityStringTooLong => ErrorCode::InfinityStringTooLong,
+            Self::InfinityStringTooShort => ErrorCode::InfinityStringTooShort,
+            Self::InvalidFloatParseAlgorithm => ErrorCode::InvalidFloatParseAlgorithm,
+            Self::InvalidRadix => ErrorCode::InvalidRadix,
+            Self::InvalidFloatPrecision => ErrorCode::InvalidFloatPrecision,
+            Self::InvalidNegativeExponentBreak => ErrorCode::InvalidNegativeExponentBreak,
+            Self::InvalidPositiveExponentBreak => ErrorCode::InvalidPositiveExponentBreak,
+            Self::InvalidExponentDigits => ErrorCode::InvalidExponentDigits,
+            Self::InvalidBoolString => ErrorCode::InvalidBoolString,
+            Self::BoolStringTooLong => ErrorCode::BoolStringTooLong,
+            Self::Unknown => ErrorCode::Unknown,
+            Self::Success => ErrorCode::Success,
+        }
+    }
+
+    /// Create an error from an error code and index.
+    ///
+    /// The index is ignored for errors without an index.
+    pub const fn from_code(code: ErrorCode, index: usize) -> Self {
+        match code {
+            ErrorCode::Overflow => Self::Overflow(index),
+            ErrorCode::Underflow => Self::Underflow(index),
+            ErrorCode::InvalidDigit => Self::InvalidDigit(index),
+            ErrorCode::Empty => Self::Empty(index),
+            ErrorCode::EmptyMantissa => Self::EmptyMantissa(index),
+            ErrorCode::EmptyExponent => Self::EmptyExponent(index),
+            ErrorCode::EmptyInteger => Self::EmptyInteger(index),
+            ErrorCode::EmptyFraction => Self::EmptyFraction(index),
+            ErrorCode::InvalidPositiveMantissaSign => Self::InvalidPositiveMantissaSign(index),
+            ErrorCode::MissingMantissaSign => Self::MissingMantissaSign(index),
+            ErrorCode::InvalidExponent => Self::InvalidExponent(index),
+            ErrorCode::InvalidPositiveExponentSign => Self::InvalidPositiveExponentSign(index),
+            ErrorCode::MissingExponentSign => Self::MissingExponentSign(index),
+            ErrorCode::ExponentWithoutFraction => Self::ExponentWithoutFraction(index),
+            ErrorCode::InvalidLeadingZeros => Self::InvalidLeadingZeros(index),
+            ErrorCode::MissingExponent => Self::MissingExponent(index),
+            ErrorCode::MissingSign => Self::MissingSign(index),
+            ErrorCode::InvalidPositiveSign => Self::InvalidPositiveSign(index),
+            ErrorCode::InvalidNegativeSign => Self::InvalidNegativeSign(index),
+            ErrorCode::NumberTooLong => Self::NumberTooLong(index),
+            ErrorCode::OutOfRange => Self::OutOfRange(index),
+            ErrorCode::ExponentTooLong => Self::ExponentTooLong(index),
+            ErrorCode::ExponentOverflow => Self::ExponentOverflow(index),
+            ErrorCode::ConversionFailed => Self::ConversionFailed(index),
+            ErrorCode::BufferTooSmall => Self::BufferTooSmall(index),
+            ErrorCode::InvalidMantissaRadix => Self::InvalidMantissaRadix,
+            ErrorCode::InvalidExponentBase => Self::InvalidExponentBase,
+            ErrorCode::InvalidExponentRadix => Self::InvalidExponentRadix,
+            ErrorCode::InvalidDigitSeparator => Self::InvalidDigitSeparator,
+            ErrorCode::InvalidDecimalPoint => Self::InvalidDecimalPoint,
+            ErrorCode::InvalidExponentSymbol => Self::InvalidExponentSymbol,
+            ErrorCode::InvalidBasePrefix => Self::InvalidBasePrefix,
+            ErrorCode::InvalidBaseSuffix => Self::InvalidBaseSuffix,
+            ErrorCode::InvalidPunctuation => Self::InvalidPunctuation,
+            ErrorCode::InvalidExponentFlags => Self::InvalidExponentFlags,
+            ErrorCode::InvalidMantissaSign => Self::InvalidMantissaSign,
+            ErrorCode::InvalidExponentSign => Self::InvalidExponentSign,
+            ErrorCode::InvalidSpecial => Self::InvalidSpecial,
+            ErrorCode::InvalidConsecutiveIntegerDigitSeparator => {
+                Self::InvalidConsecutiveIntegerDigitSeparator
+            },
+            ErrorCode::InvalidConsecutiveFractionDigitSeparator => {
+                Self::InvalidConsecutiveFractionDigitSeparator
+            },
+            ErrorCode::InvalidConsecutiveExponentDigitSeparator => {
+                Self::InvalidConsecutiveExponentDigitSeparator
+            },
+            ErrorCode::InvalidFlags => Self::InvalidFlags,
+            ErrorCode::InvalidNanString => Self::InvalidNanString,
+            ErrorCode::NanStringTooLong => Self::NanStringTooLong,
+            ErrorCode::InvalidInfString => Self::InvalidInfString,
+            ErrorCode::InfStringTooLong => Self::InfStringTooLong,
+            ErrorCode::InvalidInfinityString => Self::InvalidInfinityString,
+            ErrorCode::InfinityStringTooLong => Self::InfinityStringTooLong,
+            ErrorCode::InfinityStringTooShort => Self::InfinityStringTooShort,
+            ErrorCode::InvalidFloatParseAlgorithm => Self::InvalidFloatParseAlgorithm,
+            ErrorCode::InvalidRadix => Self::InvalidRadix,
+            ErrorCode::InvalidFloatPrecision => Self::InvalidFloatPrecision,
+            ErrorCode::InvalidNegativeExponentBreak => Self::InvalidNegativeExponentBreak,
+            ErrorCode::InvalidPositiveExponentBreak => Self::InvalidPositiveExponentBreak,
+            ErrorCode::InvalidExponentDigits => Self::InvalidExponentDigits,
+            ErrorCode::InvalidBoolString => Self::InvalidBoolString,
+            ErrorCode::BoolStringTooLong => Self::BoolStringTooLong,
+            ErrorCode::Unknown => Self::Unknown,
+            ErrorCode::Success => Self::Success,
+        }
+    }
+
+    /// Get a human-readable description of the error.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(_) => "numeric overflow occurred",
+            Self::Underflow(_) => "numeric underflow occurred",
+            Self::InvalidDigit(_) => "invalid digit found",
+            Self::Empty(_) => "the string to parse was empty",
+            Self::EmptyMantissa(_) => "no significant digits found",
+            Self::EmptyExponent(_) => "exponent notation found without an exponent",
+            Self::EmptyInteger(_) => "invalid float with no integer digits",
+            Self::EmptyFraction(_) => "invalid float with no fraction digits",
+            Self::InvalidPositiveMantissaSign(_) => "invalid `+` sign before significant digits",
+            Self::MissingMantissaSign(_) => "missing required `+/-` sign for significant digits",
+            Self::InvalidExponent(_) => "exponent found but not allowed",
+            Self::InvalidPositiveExponentSign(_) => "invalid `+` sign in exponent",
+            Self::MissingExponentSign(_) => "missing required `+/-` sign for exponent",
+            Self::ExponentWithoutFraction(_) => "invalid float containing exponent without fraction",
+            Self::InvalidLeadingZeros(_) => "invalid number with leading zeros before digits",
+            Self::MissingExponent(_) => "missing required exponent",
+            Self::MissingSign(_) => "missing required `+/-` sign for integer",
+            Self::InvalidPositiveSign(_) => "invalid `+` sign for an integer was found",
+            Self::InvalidNegativeSign(_) => "invalid `-` sign for an unsigned type was found",
+            Self::NumberTooLong(_) => "number is too long to carry over between buffers",
+            Self::OutOfRange(_) => "number is outside the requested range",
+            Self::ExponentTooLong(_) => "exponent has too many digits",
+            Self::ExponentOverflow(_) => "exponent is too large to be represented",
+            Self::ConversionFailed(_) => "number cannot be converted to the requested type",
+            Self::BufferTooSmall(_) => "buffer is too small for the written number",
+
+            // NUMBER FORMAT ERRORS
+            Self::InvalidMantissaRadix => "invalid radix for mantissa digits",
+            Self::InvalidExponentBase => "invalid exponent base",
+            Self::InvalidExponentRadix => "invalid radix for exponent digits",
+            Self::InvalidDigitSeparator => "invalid digit separator: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidDecimalPoint => "invalid decimal point: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidExponentSymbol => "invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidBasePrefix => "invalid base prefix character",
+            Self::InvalidBaseSuffix => "invalid base suffix character",
+            Self::InvalidPunctuation => "invalid punctuation: multiple characters overlap",
+            Self::InvalidExponentFlags => "exponent flags set while disabling exponent notation",
+            Self::InvalidMantissaSign => "disabled the `+` sign while requiring a sign for significant digits",
+            Self::InvalidExponentSign => "disabled the `+` sign while requiring a sign for exponent digits",
+            Self::InvalidSpecial => "special flags set while disabling special floats",
+            Self::InvalidConsecutiveIntegerDigitSeparator => "enabled consecutive digit separators in the integer without setting a valid location",
+            Self::InvalidConsecutiveFractionDigitSeparator => "enabled consecutive digit separators in the fraction without setting a valid location",
+            Self::InvalidConsecutiveExponentDigitSeparator => "enabled consecutive digit separators in the exponent without setting a valid location",
+            Self::InvalidFlags => "invalid flags enabled without the format feature",
+
+            // OPTION ERRORS
+            Self::InvalidNanString => "NaN string must start with `n`",
+            Self::NanStringTooLong => "NaN string is too long",
+            Self::InvalidInfString => "short infinity string must start with `i`",
+            Self::InfStringTooLong => "short infinity string is too long",
+            Self::InvalidInfinityString => "long infinity string must start with `i`",
+            Self::InfinityStringTooLong => "long infinity string is too long",
+            Self::InfinityStringTooShort => "long infinity string is too short",
+            Self::InvalidFloatParseAlgorithm => "invalid combination of float parse algorithms",
+            Self::InvalidRadix => "invalid radix for significant digits",
+            Self::InvalidFloatPrecision => "invalid float precision: min digits is larger than max digits",
+            Self::InvalidNegativeExponentBreak => "invalid negative exponent break: value is above 0",
+            Self::InvalidPositiveExponentBreak => "invalid positive exponent break: value is below 0",
+            Self::InvalidExponentDigits => "invalid minimum exponent digits: too many digits",
+            Self::InvalidBoolString => "boolean strings must be non-empty and distinct",
+            Self::BoolStringTooLong => "boolean string is too long",
+
+            // UNKNOWN ERRORS
+            Self::Unknown => "unknown error code",
+
+            // NOT AN ERROR
+            Self::Success => "not actually an error",
+        }
+    }
+
+    /// Get what was expected at the index of a parsing error, if known.
+    pub const fn expected(&self) -> Option<&'static str> {
+        match self {
+            // PARSE ERRORS
+            Self::InvalidDigit(_) => Some("a digit"),
+            Self::Empty(_) => Some("a number"),
+            Self::EmptyMantissa(_) => Some("significant digits"),
+            Self::EmptyExponent(_) => Some("exponent digits"),
+            Self::EmptyInteger(_) => Some("integer digits"),
+            Self::EmptyFraction(_) => Some("fraction digits"),
+            Self::InvalidPositiveMantissaSign(_) => Some("significant digits or a `-` sign"),
+            Self::MissingMantissaSign(_) => Some("a `+` or `-` sign"),
+            Self::InvalidExponent(_) => Some("the end of the number"),
+            Self::InvalidPositiveExponentSign(_) => Some("exponent digits or a `-` sign"),
+            Self::MissingExponentSign(_) => Some("a `+` or `-` sign"),
+            Self::ExponentWithoutFraction(_) => Some("fraction digits"),
+            Self::InvalidLeadingZeros(_) => Some("a non-zero digit"),
+            Self::MissingExponent(_) => Some("an exponent"),
+            Self::MissingSign(_) => Some("a `+` or `-` sign"),
+            Self::InvalidPositiveSign(_) => Some("digits or a `-` sign"),
+            Self::InvalidNegativeSign(_) => Some("a digit"),
+            _ => None,
+        }
+    }
+
     is_error_type!(is_overflow, Overflow(_));
     is_error_type!(is_underflow, Underflow(_));
     is_error_type!(is_invalid_digit, InvalidDigit(_));
@@ -218,6 +849,12 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_number_too_long, NumberTooLong(_));
+    is_error_type!(is_out_of_range, OutOfRange(_));
+    is_error_type!(is_exponent_too_long, ExponentTooLong(_));
+    is_error_type!(is_exponent_overflow, ExponentOverflow(_));
+    is_error_type!(is_conversion_failed, ConversionFailed(_));
+    is_error_type!(is_buffer_too_small, BufferTooSmall(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -256,90 +893,30 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_invalid_exponent_digits, InvalidExponentDigits);
+    is_error_type!(is_invalid_bool_string, InvalidBoolString);
+    is_error_type!(is_bool_string_too_long, BoolStringTooLong);
+    is_error_type!(is_unknown, Unknown);
     is_error_type!(is_success, Success);
 }
 
-/// Add an error message for parsing errors.
-macro_rules! write_parse_error {
-    ($formatter:ident, $message:literal, $index:ident) => {
-        write!($formatter, "lexical parse error: {} at index {}", $message, $index)
-    };
-}
-
-/// Add an error message for number format errors.
-macro_rules! format_message {
-    ($formatter:ident, $message:literal) => {
-        write!($formatter, "lexical number format error: {}", $message)
-    };
-}
-
-/// Add an error message for options errors.
-macro_rules! options_message {
-    ($formatter:ident, $message:literal) => {
-        write!($formatter, "lexical options error: {}", $message)
-    };
-}
-
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            // PARSE ERRORS
-            Self::Overflow(index) => write_parse_error!(formatter, "'numeric overflow occurred'", index),
-            Self::Underflow(index) => write_parse_error!(formatter, "'numeric underflow occurred'", index),
-            Self::InvalidDigit(index) => write_parse_error!(formatter, "'invalid digit found'", index),
-            Self::Empty(index) => write_parse_error!(formatter, "'the string to parse was empty'", index),
-            Self::EmptyMantissa(index) => write_parse_error!(formatter, "'no significant digits found'", index),
-            Self::EmptyExponent(index) => write_parse_error!(formatter, "'exponent notation found without an exponent'", index),
-            Self::EmptyInteger(index) => write_parse_error!(formatter, "'invalid float with no integer digits'", index),
-            Self::EmptyFraction(index) => write_parse_error!(formatter, "'invalid float with no fraction digits'", index),
-            Self::InvalidPositiveMantissaSign(index) => write_parse_error!(formatter, "'invalid `+` sign before significant digits'", index),
-            Self::MissingMantissaSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for significant digits'", index),
-            Self::InvalidExponent(index) => write_parse_error!(formatter, "'exponent found but not allowed'", index),
-            Self::InvalidPositiveExponentSign(index) => write_parse_error!(formatter, "'invalid `+` sign in exponent'", index),
-            Self::MissingExponentSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for exponent'", index),
-            Self::ExponentWithoutFraction(index) => write_parse_error!(formatter,  "'invalid float containing exponent without fraction'", index),
-            Self::InvalidLeadingZeros(index) => write_parse_error!(formatter, "'invalid number with leading zeros before digits'", index),
-            Self::MissingExponent(index) => write_parse_error!(formatter, "'missing required exponent'", index),
-            Self::MissingSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for integer'", index),
-            Self::InvalidPositiveSign(index) => write_parse_error!(formatter, "'invalid `+` sign for an integer was found'", index),
-            Self::InvalidNegativeSign(index) => write_parse_error!(formatter, "'invalid `-` sign for an unsigned type was found'", index),
-
-            // NUMBER FORMAT ERRORS
-            Self::InvalidMantissaRadix => format_message!(formatter, "'invalid radix for mantissa digits'"),
-            Self::InvalidExponentBase => format_message!(formatter, "'invalid exponent base'"),
-            Self::InvalidExponentRadix => format_message!(formatter, "'invalid radix for exponent digits'"),
-            Self::InvalidDigitSeparator => format_message!(formatter, "'invalid digit separator: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidDecimalPoint => format_message!(formatter, "'invalid decimal point: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidExponentSymbol => format_message!(formatter, "'invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidBasePrefix => format_message!(formatter, "'invalid base prefix character'"),
-            Self::InvalidBaseSuffix => format_message!(formatter, "'invalid base suffix character'"),
-            Self::InvalidPunctuation => format_message!(formatter, "'invalid punctuation: multiple characters overlap'"),
-            Self::InvalidExponentFlags => format_message!(formatter, "'exponent flags set while disabling exponent notation'"),
-            Self::InvalidMantissaSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for significant digits'"),
-            Self::InvalidExponentSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for exponent digits'"),
-            Self::InvalidSpecial => format_message!(formatter, "'special flags set while disabling special floats'"),
-            Self::InvalidConsecutiveIntegerDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the integer without setting a valid location'"),
-            Self::InvalidConsecutiveFractionDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the fraction without setting a valid location'"),
-            Self::InvalidConsecutiveExponentDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the exponent without setting a valid location'"),
-            Self::InvalidFlags => format_message!(formatter, "'invalid flags enabled without the format feature'"),
-
-            // OPTION ERRORS
-            Self::InvalidNanString => options_message!(formatter, "'NaN string must started with `n`'"),
-            Self::NanStringTooLong => options_message!(formatter, "'NaN string is too long'"),
-            Self::InvalidInfString => options_message!(formatter, "'short infinity string must started with `i`'"),
-            Self::InfStringTooLong => options_message!(formatter, "'short infinity string is too long'"),
-            Self::InvalidInfinityString => options_message!(formatter, "'long infinity string must started with `i`'"),
-            Self::InfinityStringTooLong => options_message!(formatter, "'long infinity string is too long'"),
-            Self::InfinityStringTooShort => options_message!(formatter, "'long infinity string is too short'"),
-            Self::InvalidFloatParseAlgorithm => options_message!(formatter, "'invalid combination of float parse algorithms'"),
-            Self::InvalidRadix => options_message!(formatter, "'invalid radix for significant digits'"),
-            Self::InvalidFloatPrecision => options_message!(formatter, "'invalid float precision: min digits is larger than max digits'"),
-            Self::InvalidNegativeExponentBreak => options_message!(formatter, "'invalid negative exponent break: value is above 0'"),
-            Self::InvalidPositiveExponentBreak => options_message!(formatter, "'invalid positive exponent break: value is below 0'"),
-
-            // NOT AN ERROR
-            Self::Success => write!(formatter, "'not actually an error'"),
+        let kind = match self.code() {
+            0 => return formatter.write_str(self.description()),
+            1..=99 => "parse",
+            100..=199 => "number format",
+            200..=299 => "options",
+            _ => "unknown",
+        };
+        write!(formatter, "lexical {} error: {}", kind, self.description())?;
+        if let Some(index) = self.index() {
+            write!(formatter, " at index {}", index)?;
+        }
+        if let Some(expected) = self.expected() {
+            write!(formatter, ", expected {}", expected)?;
         }
+        write!(formatter, " (code {})", self.code())
     }
 }
 
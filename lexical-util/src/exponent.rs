@@ -0,0 +1,158 @@
+//! Typed exponents, to distinguish decimal and binary exponents.
+//!
+//! The low-level algorithms use `i32` for decimal exponents, binary
+//! exponents, and the biased exponents stored in the float bits, and
+//! mixing them up is a silent bug: passing a decimal exponent where a
+//! binary exponent is expected, or forgetting to add the bias, produces
+//! a float that is wrong by orders of magnitude, but is otherwise valid.
+//! The wrappers here make the kind of exponent part of the type, so
+//! integrations with the low-level APIs convert between them explicitly.
+//!
+//! The binary exponent of a float is the unbiased exponent for an
+//! integral significand, as in [`Float::exponent`], so `1.0f64` has a
+//! binary exponent of `-52` and a biased exponent of `1023`. The values
+//! are private, so every exponent is created with `new` or from a float,
+//! and converted with the methods, such as [`BinaryExponent::to_biased`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_util::exponent::{BiasedExponent, BinaryExponent, DecimalExponent};
+//!
+//! # pub fn main() {
+//! let binary = BinaryExponent::of(1.0f64);
+//! assert_eq!(binary.get(), -52);
+//! assert_eq!(binary.to_biased::<f64>(), BiasedExponent::of(1.0f64));
+//! assert_eq!(binary.to_biased::<f64>().get(), 1023);
+//! assert_eq!(DecimalExponent::new(3).to_binary().get(), 9);
+//! # }
+//! ```
+
+#![cfg(feature = "floats")]
+
+use crate::num::{AsCast, Float};
+
+/// Calculate `⌊q * log2(10)⌋` quickly.
+/// Generated by `etc/log.py`.
+/// Only needs to be valid for values from `[-1233, 1233]`
+#[inline(always)]
+pub const fn floor_log2_pow10(q: i32) -> i32 {
+    q.wrapping_mul(1741647) >> 19
+}
+
+/// Calculate `⌊q * log10(2)⌋` quickly.
+/// Generated by `etc/log.py`.
+/// Only needs to be valid for values from `[-1700, 1700]`
+#[inline(always)]
+pub const fn floor_log10_pow2(q: i32) -> i32 {
+    q.wrapping_mul(315653) >> 20
+}
+
+/// Power of ten of a number, such as the `-3` in `1.5e-3`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DecimalExponent(i32);
+
+impl DecimalExponent {
+    /// Create a decimal exponent.
+    #[inline(always)]
+    pub const fn new(exponent: i32) -> Self {
+        Self(exponent)
+    }
+
+    /// Get the value of the exponent.
+    #[inline(always)]
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Get the binary exponent of `10^exponent`, or `⌊exponent * log2(10)⌋`.
+    ///
+    /// This is exact for decimal exponents in `[-1233, 1233]`, which
+    /// includes the exponents of every finite `f64`.
+    #[inline(always)]
+    pub const fn to_binary(self) -> BinaryExponent {
+        BinaryExponent(floor_log2_pow10(self.0))
+    }
+}
+
+/// Unbiased power of two of a number, for an integral significand.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BinaryExponent(i32);
+
+impl BinaryExponent {
+    /// Create a binary exponent.
+    #[inline(always)]
+    pub const fn new(exponent: i32) -> Self {
+        Self(exponent)
+    }
+
+    /// Get the value of the exponent.
+    #[inline(always)]
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Get the decimal exponent of `2^exponent`, or `⌊exponent * log10(2)⌋`.
+    ///
+    /// This is exact for binary exponents in `[-1700, 1700]`, which
+    /// includes the exponents of every `f64`.
+    #[inline(always)]
+    pub const fn to_decimal(self) -> DecimalExponent {
+        DecimalExponent(floor_log10_pow2(self.0))
+    }
+
+    /// Get the binary exponent of a float.
+    ///
+    /// Denormal floats and zero have the exponent of the smallest normal
+    /// float, since their significand has no hidden bit.
+    #[inline(always)]
+    pub fn of<F: Float>(float: F) -> Self {
+        Self(float.exponent())
+    }
+
+    /// Add the exponent bias of the float type, to store the exponent.
+    ///
+    /// The binary exponent of denormal floats has a biased exponent of
+    /// `1`, which is only stored as `0` if the significand has no hidden bit.
+    #[inline(always)]
+    pub fn to_biased<F: Float>(self) -> BiasedExponent {
+        BiasedExponent(self.0 + F::EXPONENT_BIAS)
+    }
+}
+
+/// Biased exponent, as stored in the exponent bits of a float.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BiasedExponent(i32);
+
+impl BiasedExponent {
+    /// Create a biased exponent.
+    #[inline(always)]
+    pub const fn new(exponent: i32) -> Self {
+        Self(exponent)
+    }
+
+    /// Get the value of the exponent.
+    #[inline(always)]
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Get the biased exponent of a float.
+    #[inline(always)]
+    pub fn of<F: Float>(float: F) -> Self {
+        let bits = (float.to_bits() & F::EXPONENT_MASK) >> F::MANTISSA_SIZE;
+        Self(i32::as_cast(bits))
+    }
+
+    /// Remove the exponent bias of the float type.
+    ///
+    /// A biased exponent of `0`, for denormal floats and zero, has the
+    /// binary exponent of the smallest normal float, like [`Float::exponent`].
+    #[inline(always)]
+    pub fn to_binary<F: Float>(self) -> BinaryExponent {
+        BinaryExponent(self.0.max(1) - F::EXPONENT_BIAS)
+    }
+}
@@ -9,13 +9,20 @@
 
 #![cfg(feature = "floats")]
 
+use crate::exponent::{floor_log10_pow2, BiasedExponent, BinaryExponent};
+use crate::log;
 use crate::num::UnsignedInteger;
 
 /// Extended precision floating-point type.
 ///
-/// This doesn't have any methods because it's used for **very** different
+/// This doesn't have any arithmetic because it's used for **very** different
 /// things for the Lemire, Bellepheron, and other algorithms. In Grisu,
 /// it's an unbiased representation, for Lemire, it's a biased representation.
+/// The typed constructors and accessors, such as [`from_binary`] and
+/// [`biased_exponent`], document which representation is expected.
+///
+/// [`from_binary`]: Self::from_binary
+/// [`biased_exponent`]: Self::biased_exponent
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ExtendedFloat<M: UnsignedInteger> {
     /// Mantissa for the extended-precision float.
@@ -36,4 +43,59 @@ impl<M: UnsignedInteger> ExtendedFloat<M> {
     pub fn exponent(&self) -> i32 {
         self.exp
     }
+
+    /// Create an unbiased extended-precision float, with the value `mant * 2^exp`.
+    #[inline]
+    pub fn from_binary(mant: M, exp: BinaryExponent) -> Self {
+        Self {
+            mant,
+            exp: exp.get(),
+        }
+    }
+
+    /// Create a biased extended-precision float, with the fraction bits
+    /// and the biased exponent, as stored in the float bits.
+    #[inline]
+    pub fn from_biased(mant: M, exp: BiasedExponent) -> Self {
+        Self {
+            mant,
+            exp: exp.get(),
+        }
+    }
+
+    /// Get the exponent component of an unbiased extended-precision float.
+    #[inline]
+    pub fn binary_exponent(&self) -> BinaryExponent {
+        BinaryExponent::new(self.exp)
+    }
+
+    /// Get the exponent component of a biased extended-precision float.
+    #[inline]
+    pub fn biased_exponent(&self) -> BiasedExponent {
+        BiasedExponent::new(self.exp)
+    }
+
+    /// Calculate `⌊log2(mant * 2^exp)⌋` of an unbiased extended-precision float.
+    ///
+    /// This is exact, and is `None` if the mantissa is zero.
+    #[inline]
+    pub fn floor_log2(&self) -> Option<i32> {
+        if self.mant == M::ZERO {
+            None
+        } else {
+            Some(log::floor_log2(self.mant) as i32 + self.exp)
+        }
+    }
+
+    /// Estimate `⌊log10(mant * 2^exp)⌋` of an unbiased extended-precision float.
+    ///
+    /// This is never above the exact value, and at most 1 below it, if
+    /// [`floor_log2`](Self::floor_log2) is within `[-1700, 1700]`, which
+    /// includes every `f64`. For values of at least 1, the number of
+    /// digits before the decimal point is the exact value plus 1. This is
+    /// `None` if the mantissa is zero.
+    #[inline]
+    pub fn estimate_log10(&self) -> Option<i32> {
+        self.floor_log2().map(floor_log10_pow2)
+    }
 }
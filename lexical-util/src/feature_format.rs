@@ -730,6 +730,27 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         self.error().is_success()
     }
 
+    /// Determine if the number format is valid for floats.
+    ///
+    /// This is like [`is_valid`](Self::is_valid), but also rejects radixes
+    /// above 36, which are only supported for integers.
+    pub const fn is_valid_float(&self) -> bool {
+        self.float_error().is_success()
+    }
+
+    /// Get the error type from the format, for floats.
+    pub const fn float_error(&self) -> Error {
+        if !flags::is_valid_float_radix(self.mantissa_radix()) {
+            Error::InvalidMantissaRadix
+        } else if !flags::is_valid_float_radix(self.exponent_base()) {
+            Error::InvalidExponentBase
+        } else if !flags::is_valid_float_radix(self.exponent_radix()) {
+            Error::InvalidExponentRadix
+        } else {
+            self.error()
+        }
+    }
+
     /// Get the error type from the format.
     #[allow(clippy::if_same_then_else)]
     pub const fn error(&self) -> Error {
@@ -941,6 +962,60 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If negative numbers may be wrapped in parentheses.
+    pub const NEGATIVE_PARENTHESES: bool = from_flag!(FORMAT, NEGATIVE_PARENTHESES);
+
+    /// Get if negative numbers may be wrapped in parentheses.
+    #[inline(always)]
+    pub const fn negative_parentheses(&self) -> bool {
+        Self::NEGATIVE_PARENTHESES
+    }
+
+    /// If numbers may be wrapped in double quotes.
+    pub const OPTIONAL_QUOTES: bool = from_flag!(FORMAT, OPTIONAL_QUOTES);
+
+    /// Get if numbers may be wrapped in double quotes.
+    #[inline(always)]
+    pub const fn optional_quotes(&self) -> bool {
+        Self::OPTIONAL_QUOTES
+    }
+
+    /// If the exponent character may also be `d` or `D`.
+    pub const D_EXPONENT_NOTATION: bool = from_flag!(FORMAT, D_EXPONENT_NOTATION);
+
+    /// Get if the exponent character may also be `d` or `D`.
+    #[inline(always)]
+    pub const fn d_exponent_notation(&self) -> bool {
+        Self::D_EXPONENT_NOTATION
+    }
+
+    /// If the exponent character may be omitted if the exponent has a sign.
+    pub const SIGN_EXPONENT_NOTATION: bool = from_flag!(FORMAT, SIGN_EXPONENT_NOTATION);
+
+    /// Get if the exponent character may be omitted if the exponent has a sign.
+    #[inline(always)]
+    pub const fn sign_exponent_notation(&self) -> bool {
+        Self::SIGN_EXPONENT_NOTATION
+    }
+
+    /// If the sign may follow the digits of the number.
+    pub const TRAILING_SIGN: bool = from_flag!(FORMAT, TRAILING_SIGN);
+
+    /// Get if the sign may follow the digits of the number.
+    #[inline(always)]
+    pub const fn trailing_sign(&self) -> bool {
+        Self::TRAILING_SIGN
+    }
+
+    /// If the last digit of an integer may be overpunched with the sign.
+    pub const OVERPUNCH_SIGN: bool = from_flag!(FORMAT, OVERPUNCH_SIGN);
+
+    /// Get if the last digit of an integer may be overpunched with the sign.
+    #[inline(always)]
+    pub const fn overpunch_sign(&self) -> bool {
+        Self::OVERPUNCH_SIGN
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -1109,6 +1184,17 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::DIGIT_SEPARATOR
     }
 
+    /// The maximum number of leading zeros in the packed struct.
+    pub const MAX_LEADING_ZEROS: u8 = flags::max_leading_zeros(FORMAT);
+
+    /// Get the maximum number of leading zeros in the integer component.
+    ///
+    /// If the value is 0, any number of leading zeros is allowed.
+    #[inline(always)]
+    pub const fn max_leading_zeros(&self) -> u8 {
+        Self::MAX_LEADING_ZEROS
+    }
+
     /// The base prefix character in the packed struct.
     pub const BASE_PREFIX: u8 = flags::base_prefix(FORMAT);
 
@@ -2297,6 +2383,7 @@ const_assert!(NumberFormat::<{ ELIXIR_STRING }> {}.is_valid());
 #[rustfmt::skip]
 pub const FORTRAN_LITERAL: u128 = NumberFormatBuilder::new()
     .no_special(true)
+    .d_exponent_notation(true)
     .build();
 
 const_assert!(NumberFormat::<{ FORTRAN_LITERAL }> {}.is_valid());
@@ -2304,7 +2391,10 @@ const_assert!(NumberFormat::<{ FORTRAN_LITERAL }> {}.is_valid());
 // FORTRAN STRING [0134567MN]
 /// Number format to parse a FORTRAN float from string.
 #[rustfmt::skip]
-pub const FORTRAN_STRING: u128 = NumberFormatBuilder::new().build();
+pub const FORTRAN_STRING: u128 = NumberFormatBuilder::new()
+    .d_exponent_notation(true)
+    .sign_exponent_notation(true)
+    .build();
 const_assert!(NumberFormat::<{ FORTRAN_STRING }> {}.is_valid());
 
 // D LITERAL [0134569ABFGHIJKN-_]
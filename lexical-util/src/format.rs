@@ -248,6 +248,31 @@
 //! - [CASE_SENSITIVE_EXPONENT](crate::format::CASE_SENSITIVE_EXPONENT)
 //! - [CASE_SENSITIVE_BASE_PREFIX](crate::format::CASE_SENSITIVE_BASE_PREFIX)
 //! - [CASE_SENSITIVE_BASE_SUFFIX](crate::format::CASE_SENSITIVE_BASE_SUFFIX)
+//! - [NEGATIVE_PARENTHESES](crate::format::NEGATIVE_PARENTHESES)
+//! - [OPTIONAL_QUOTES](crate::format::OPTIONAL_QUOTES)
+//! - [D_EXPONENT_NOTATION](crate::format::D_EXPONENT_NOTATION)
+//! - [SIGN_EXPONENT_NOTATION](crate::format::SIGN_EXPONENT_NOTATION)
+//! - [TRAILING_SIGN](crate::format::TRAILING_SIGN)
+//! - [OVERPUNCH_SIGN](crate::format::OVERPUNCH_SIGN)
+//!
+//! # Empty Integer and Fraction Digits
+//!
+//! By default, either the integer or fraction digits of a float may be
+//! empty, but not both, so `.5`, `5.`, and `5.e3` are valid, like in C.
+//! The integer and fraction digits can be required independently:
+//!
+//! | Format                      | `.5`  | `5.`  | `5.e3` | `.`   |
+//! |-----------------------------|-------|-------|--------|-------|
+//! | Default                     | Valid | Valid | Valid  | Error |
+//! | `required_integer_digits`   | Error | Valid | Valid  | Error |
+//! | `required_fraction_digits`  | Valid | Error | Error  | Error |
+//! | Both (such as JSON)         | Error | Error | Error  | Error |
+//!
+//! A float without a decimal point, such as `5` or `5e3`, never has
+//! empty fraction digits. Setting these flags requires the `format`
+//! feature, and the flags are
+//! [REQUIRED_INTEGER_DIGITS](crate::format::REQUIRED_INTEGER_DIGITS) and
+//! [REQUIRED_FRACTION_DIGITS](crate::format::REQUIRED_FRACTION_DIGITS).
 //!
 //! # Digit Separator Flags
 //!
@@ -279,6 +304,8 @@
 //!
 //! - [DIGIT_SEPARATOR_SHIFT](crate::format::DIGIT_SEPARATOR_SHIFT)
 //! - [DIGIT_SEPARATOR](crate::format::DIGIT_SEPARATOR)
+//! - [MAX_LEADING_ZEROS_SHIFT](crate::format::MAX_LEADING_ZEROS_SHIFT)
+//! - [MAX_LEADING_ZEROS](crate::format::MAX_LEADING_ZEROS)
 //! - [BASE_PREFIX_SHIFT](crate::format::BASE_PREFIX_SHIFT)
 //! - [BASE_PREFIX](crate::format::BASE_PREFIX)
 //! - [BASE_SUFFIX_SHIFT](crate::format::BASE_SUFFIX_SHIFT)
@@ -297,6 +324,7 @@
 //! Functions to get control characters from the format packed struct.
 //!
 //! - [digit_separator](crate::format::digit_separator)
+//! - [max_leading_zeros](crate::format::max_leading_zeros)
 //! - [base_prefix](crate::format::base_prefix)
 //! - [base_suffix](crate::format::base_suffix)
 //! - [mantissa_radix](crate::format::mantissa_radix)
@@ -313,6 +341,7 @@
 //! - [is_valid_base_suffix](is_valid_base_suffix)
 //! - [is_valid_punctuation](is_valid_punctuation)
 //! - [is_valid_radix](is_valid_radix)
+//! - [is_valid_float_radix](is_valid_float_radix)
 
 #[cfg(feature = "format")]
 pub use crate::feature_format::*;
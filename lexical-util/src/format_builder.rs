@@ -55,6 +55,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `exponent_radix`                          - Radix for the exponent digits.
 /// * `base_prefix`                             - Optional character for the base prefix.
 /// * `base_suffix`                             - Optional character for the base suffix.
+/// * `max_leading_zeros`                       - Optional maximum number of leading zeros.
 /// * `required_integer_digits`                 - If digits are required before the decimal point.
 /// * `required_fraction_digits`                - If digits are required after the decimal point.
 /// * `required_exponent_digits`                - If digits are required after the exponent character.
@@ -73,6 +74,12 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`                 - If exponent characters are case-sensitive.
 /// * `case_sensitive_base_prefix`              - If base prefixes are case-sensitive.
 /// * `case_sensitive_base_suffix`              - If base suffixes are case-sensitive.
+/// * `negative_parentheses`                    - If negative numbers may be wrapped in parentheses.
+/// * `optional_quotes`                         - If numbers may be wrapped in double quotes.
+/// * `d_exponent_notation`                     - If the exponent character may also be `d` or `D`.
+/// * `sign_exponent_notation`                  - If the exponent character may be omitted if the exponent has a sign.
+/// * `trailing_sign`                           - If the sign may follow the digits of the number.
+/// * `overpunch_sign`                          - If the last digit of an integer may be overpunched with the sign.
 /// * `integer_internal_digit_separator`        - If digit separators are allowed between integer digits.
 /// * `fraction_internal_digit_separator`       - If digit separators are allowed between fraction digits.
 /// * `exponent_internal_digit_separator`       - If digit separators are allowed between exponent digits.
@@ -98,6 +105,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `mantissa_radix`
 /// * `base_prefix`
 /// * `base_suffix`
+/// * `max_leading_zeros`
 /// * `no_positive_mantissa_sign`
 /// * `required_mantissa_sign`
 /// * `no_integer_leading_zeros`
@@ -130,6 +138,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `exponent_radix`
 /// * `base_prefix`
 /// * `base_suffix`
+/// * `max_leading_zeros`
 /// * `required_integer_digits`
 /// * `required_fraction_digits`
 /// * `required_exponent_digits`
@@ -147,6 +156,12 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`
 /// * `case_sensitive_base_prefix`
 /// * `case_sensitive_base_suffix`
+/// * `negative_parentheses`
+/// * `optional_quotes`
+/// * `d_exponent_notation`
+/// * `sign_exponent_notation`
+/// * `trailing_sign`
+/// * `overpunch_sign`
 /// * `integer_internal_digit_separator`
 /// * `fraction_internal_digit_separator`
 /// * `exponent_internal_digit_separator`
@@ -163,6 +178,7 @@ pub struct NumberFormatBuilder {
     digit_separator: OptionU8,
     base_prefix: OptionU8,
     base_suffix: OptionU8,
+    max_leading_zeros: OptionU8,
     mantissa_radix: u8,
     exponent_base: OptionU8,
     exponent_radix: OptionU8,
@@ -184,6 +200,12 @@ pub struct NumberFormatBuilder {
     case_sensitive_exponent: bool,
     case_sensitive_base_prefix: bool,
     case_sensitive_base_suffix: bool,
+    negative_parentheses: bool,
+    optional_quotes: bool,
+    d_exponent_notation: bool,
+    sign_exponent_notation: bool,
+    trailing_sign: bool,
+    overpunch_sign: bool,
     integer_internal_digit_separator: bool,
     fraction_internal_digit_separator: bool,
     exponent_internal_digit_separator: bool,
@@ -209,6 +231,7 @@ impl NumberFormatBuilder {
             digit_separator: None,
             base_prefix: None,
             base_suffix: None,
+            max_leading_zeros: None,
             mantissa_radix: 10,
             exponent_base: None,
             exponent_radix: None,
@@ -230,6 +253,12 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: false,
             case_sensitive_base_prefix: false,
             case_sensitive_base_suffix: false,
+            negative_parentheses: false,
+            optional_quotes: false,
+            d_exponent_notation: false,
+            sign_exponent_notation: false,
+            trailing_sign: false,
+            overpunch_sign: false,
             integer_internal_digit_separator: false,
             fraction_internal_digit_separator: false,
             exponent_internal_digit_separator: false,
@@ -321,6 +350,12 @@ impl NumberFormatBuilder {
         self.base_suffix
     }
 
+    /// Get the optional maximum number of leading zeros.
+    #[inline(always)]
+    pub const fn get_max_leading_zeros(&self) -> OptionU8 {
+        self.max_leading_zeros
+    }
+
     /// Get if digits are required before the decimal point.
     #[inline(always)]
     pub const fn get_required_integer_digits(&self) -> bool {
@@ -429,6 +464,42 @@ impl NumberFormatBuilder {
         self.case_sensitive_base_suffix
     }
 
+    /// Get if negative numbers may be wrapped in parentheses.
+    #[inline(always)]
+    pub const fn get_negative_parentheses(&self) -> bool {
+        self.negative_parentheses
+    }
+
+    /// Get if numbers may be wrapped in double quotes.
+    #[inline(always)]
+    pub const fn get_optional_quotes(&self) -> bool {
+        self.optional_quotes
+    }
+
+    /// Get if the exponent character may also be `d` or `D`.
+    #[inline(always)]
+    pub const fn get_d_exponent_notation(&self) -> bool {
+        self.d_exponent_notation
+    }
+
+    /// Get if the exponent character may be omitted if the exponent has a sign.
+    #[inline(always)]
+    pub const fn get_sign_exponent_notation(&self) -> bool {
+        self.sign_exponent_notation
+    }
+
+    /// Get if the sign may follow the digits of the number.
+    #[inline(always)]
+    pub const fn get_trailing_sign(&self) -> bool {
+        self.trailing_sign
+    }
+
+    /// Get if the last digit of an integer may be overpunched with the sign.
+    #[inline(always)]
+    pub const fn get_overpunch_sign(&self) -> bool {
+        self.overpunch_sign
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn get_integer_internal_digit_separator(&self) -> bool {
@@ -564,6 +635,18 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set the optional maximum number of leading zeros.
+    ///
+    /// Leading zeros are the zeros before the last digit of the integer
+    /// component, so `007` has 2 leading zeros, and `0.5` has none.
+    /// Numbers with more leading zeros are invalid.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn max_leading_zeros(mut self, max_leading_zeros: OptionU8) -> Self {
+        self.max_leading_zeros = max_leading_zeros;
+        self
+    }
+
     /// Set if digits are required before the decimal point.
     #[inline(always)]
     #[cfg(feature = "format")]
@@ -719,6 +802,54 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set if negative numbers may be wrapped in parentheses.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn negative_parentheses(mut self, flag: bool) -> Self {
+        self.negative_parentheses = flag;
+        self
+    }
+
+    /// Set if numbers may be wrapped in double quotes.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn optional_quotes(mut self, flag: bool) -> Self {
+        self.optional_quotes = flag;
+        self
+    }
+
+    /// Set if the exponent character may also be `d` or `D`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn d_exponent_notation(mut self, flag: bool) -> Self {
+        self.d_exponent_notation = flag;
+        self
+    }
+
+    /// Set if the exponent character may be omitted if the exponent has a sign.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn sign_exponent_notation(mut self, flag: bool) -> Self {
+        self.sign_exponent_notation = flag;
+        self
+    }
+
+    /// Set if the sign may follow the digits of the number.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn trailing_sign(mut self, flag: bool) -> Self {
+        self.trailing_sign = flag;
+        self
+    }
+
+    /// Set if the last digit of an integer may be overpunched with the sign.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn overpunch_sign(mut self, flag: bool) -> Self {
+        self.overpunch_sign = flag;
+        self
+    }
+
     /// Set if digit separators are allowed between integer digits.
     #[inline(always)]
     #[cfg(feature = "format")]
@@ -938,6 +1069,12 @@ impl NumberFormatBuilder {
             self.case_sensitive_exponent, CASE_SENSITIVE_EXPONENT ;
             self.case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX ;
             self.case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX ;
+            self.negative_parentheses, NEGATIVE_PARENTHESES ;
+            self.optional_quotes, OPTIONAL_QUOTES ;
+            self.d_exponent_notation, D_EXPONENT_NOTATION ;
+            self.sign_exponent_notation, SIGN_EXPONENT_NOTATION ;
+            self.trailing_sign, TRAILING_SIGN ;
+            self.overpunch_sign, OVERPUNCH_SIGN ;
             self.integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR ;
             self.fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR ;
             self.exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR ;
@@ -956,6 +1093,8 @@ impl NumberFormatBuilder {
             format |=
                 (unwrap_or_zero(self.digit_separator) as u128) << flags::DIGIT_SEPARATOR_SHIFT;
         }
+        format |=
+            (unwrap_or_zero(self.max_leading_zeros) as u128) << flags::MAX_LEADING_ZEROS_SHIFT;
         format |= (unwrap_or_zero(self.base_prefix) as u128) << flags::BASE_PREFIX_SHIFT;
         format |= (unwrap_or_zero(self.base_suffix) as u128) << flags::BASE_SUFFIX_SHIFT;
         format |= (self.mantissa_radix as u128) << flags::MANTISSA_RADIX_SHIFT;
@@ -972,6 +1111,7 @@ impl NumberFormatBuilder {
             digit_separator: num::NonZeroU8::new(flags::digit_separator(format)),
             base_prefix: num::NonZeroU8::new(flags::base_prefix(format)),
             base_suffix: num::NonZeroU8::new(flags::base_suffix(format)),
+            max_leading_zeros: num::NonZeroU8::new(flags::max_leading_zeros(format)),
             mantissa_radix: flags::mantissa_radix(format) as u8,
             exponent_base: num::NonZeroU8::new(flags::exponent_base(format) as u8),
             exponent_radix: num::NonZeroU8::new(flags::exponent_radix(format) as u8),
@@ -993,6 +1133,12 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: has_flag!(format, CASE_SENSITIVE_EXPONENT),
             case_sensitive_base_prefix: has_flag!(format, CASE_SENSITIVE_BASE_PREFIX),
             case_sensitive_base_suffix: has_flag!(format, CASE_SENSITIVE_BASE_SUFFIX),
+            negative_parentheses: has_flag!(format, NEGATIVE_PARENTHESES),
+            optional_quotes: has_flag!(format, OPTIONAL_QUOTES),
+            d_exponent_notation: has_flag!(format, D_EXPONENT_NOTATION),
+            sign_exponent_notation: has_flag!(format, SIGN_EXPONENT_NOTATION),
+            trailing_sign: has_flag!(format, TRAILING_SIGN),
+            overpunch_sign: has_flag!(format, OVERPUNCH_SIGN),
             integer_internal_digit_separator: has_flag!(format, INTEGER_INTERNAL_DIGIT_SEPARATOR),
             fraction_internal_digit_separator: has_flag!(format, FRACTION_INTERNAL_DIGIT_SEPARATOR),
             exponent_internal_digit_separator: has_flag!(format, EXPONENT_INTERNAL_DIGIT_SEPARATOR),
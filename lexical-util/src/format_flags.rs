@@ -25,7 +25,7 @@
 //!
 //! 16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31  32
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |e/P|e/S|                                                       |
+//! |e/P|e/S|(/N|"/Q|D/e|-/e|+/T|{/O|                               |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 32  33  34  35  36  37  38  39  40  41 42  43  44  45  46  47   48
@@ -58,6 +58,12 @@
 //!         e/C = Case-sensitive exponent character.
 //!         e/P = Case-sensitive base prefix.
 //!         e/S = Case-sensitive base suffix.
+//!         (/N = Negative numbers in parentheses.
+//!         "/Q = Optional wrapping quotes.
+//!         D/e = D exponent notation.
+//!         -/e = Sign exponent notation.
+//!         +/T = Trailing sign.
+//!         {/O = Overpunched sign.
 //!
 //!     Digit Separator Flags:
 //!         I/I = Integer internal digit separator.
@@ -82,7 +88,7 @@
 //! ```text
 //! 64  65  66  67  68  69  70  71  72  73  74  75  76  77  78  79  80
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |     Digit Separator       |                                   |
+//! |     Digit Separator       |       Max Leading Zeros       |   |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 80  81  82  83  84  85  86  87  88  89  90  91  92  93  94  95  96
@@ -242,10 +248,23 @@ macro_rules! check_masks_and_flags {
 // ---------------------------------
 
 /// Digits are required before the decimal point.
+///
+/// If not set, the integer digits may be empty if there are fraction
+/// digits, so `.5` is valid, like in C or Python. If set, `.5` is
+/// invalid with [`EmptyInteger`], like in JSON.
+///
+/// [`EmptyInteger`]: crate::error::Error::EmptyInteger
 pub const REQUIRED_INTEGER_DIGITS: u128 = 1 << 0;
 
 /// Digits are required after the decimal point.
 /// This check will only occur if the decimal point is present.
+///
+/// If not set, the fraction digits may be empty if there are integer
+/// digits, so `5.` and `5.e3` are valid, like in C or Python. If set,
+/// these are invalid with [`EmptyFraction`], like in JSON, while `5`
+/// and `5e3` are still valid.
+///
+/// [`EmptyFraction`]: crate::error::Error::EmptyFraction
 pub const REQUIRED_FRACTION_DIGITS: u128 = 1 << 1;
 
 /// Digits are required after the exponent character.
@@ -337,6 +356,50 @@ pub const CASE_SENSITIVE_BASE_PREFIX: u128 = 1 << 16;
 /// Base suffixes are case-sensitive.
 pub const CASE_SENSITIVE_BASE_SUFFIX: u128 = 1 << 17;
 
+/// Negative numbers may be wrapped in parentheses.
+///
+/// This is common in accounting formats, where `(123.45)` is
+/// equivalent to `-123.45`. The wrapped number cannot have a sign.
+pub const NEGATIVE_PARENTHESES: u128 = 1 << 18;
+
+/// Numbers may be wrapped in double quotes.
+///
+/// This is common in CSV exports, where `"123.45"` is equivalent
+/// to `123.45`. Quotes wrap any parentheses, as in `"(123.45)"`.
+pub const OPTIONAL_QUOTES: u128 = 1 << 19;
+
+/// The exponent character may also be `d` or `D`.
+///
+/// This is common in FORTRAN, where `1.5D+10` is a double-precision
+/// literal equivalent to `1.5e+10`. The exponent character from the
+/// options is still valid. If exponent characters are case-sensitive,
+/// the case of `d` must match the case of the exponent character.
+pub const D_EXPONENT_NOTATION: u128 = 1 << 20;
+
+/// The exponent character may be omitted if the exponent has a sign.
+///
+/// This is common in fixed-column FORTRAN data, where `1.5-10` is
+/// equivalent to `1.5e-10`. The exponent sign is always required
+/// without an exponent character.
+pub const SIGN_EXPONENT_NOTATION: u128 = 1 << 21;
+
+/// The sign may follow the digits of the number.
+///
+/// This is common in legacy fixed-width files, where `123-` is
+/// equivalent to `-123`. A number cannot have both a leading and a
+/// trailing sign, and a trailing `+` is invalid without positive signs.
+pub const TRAILING_SIGN: u128 = 1 << 22;
+
+/// The last digit of an integer may be overpunched with the sign.
+///
+/// This is the zoned decimal format of COBOL and EBCDIC files, as in
+/// ASCII: `{` and `A` through `I` are the digits 0 through 9 for a positive
+/// number, and `}` and `J` through `R` are the digits 0 through 9 for a
+/// negative number, so `12C` is `123` and `12L` is `-123`. The number
+/// cannot also have a leading sign. This is only used for integers, and
+/// the mantissa radix must be 10.
+pub const OVERPUNCH_SIGN: u128 = 1 << 23;
+
 // Non-digit separator flags.
 const_assert!(REQUIRED_INTEGER_DIGITS == 1);
 check_subsequent_flags!(REQUIRED_INTEGER_DIGITS, REQUIRED_FRACTION_DIGITS);
@@ -357,6 +420,12 @@ check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, REQUIRED_EXPONENT_NOTATION);
 check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, CASE_SENSITIVE_EXPONENT);
 check_subsequent_flags!(CASE_SENSITIVE_EXPONENT, CASE_SENSITIVE_BASE_PREFIX);
 check_subsequent_flags!(CASE_SENSITIVE_BASE_PREFIX, CASE_SENSITIVE_BASE_SUFFIX);
+check_subsequent_flags!(CASE_SENSITIVE_BASE_SUFFIX, NEGATIVE_PARENTHESES);
+check_subsequent_flags!(NEGATIVE_PARENTHESES, OPTIONAL_QUOTES);
+check_subsequent_flags!(OPTIONAL_QUOTES, D_EXPONENT_NOTATION);
+check_subsequent_flags!(D_EXPONENT_NOTATION, SIGN_EXPONENT_NOTATION);
+check_subsequent_flags!(SIGN_EXPONENT_NOTATION, TRAILING_SIGN);
+check_subsequent_flags!(TRAILING_SIGN, OVERPUNCH_SIGN);
 
 // DIGIT SEPARATOR FLAGS & MASKS
 // -----------------------------
@@ -448,6 +517,18 @@ pub const DIGIT_SEPARATOR_SHIFT: i32 = 64;
 /// Mask to extract the digit separator character.
 pub const DIGIT_SEPARATOR: u128 = 0xFF << DIGIT_SEPARATOR_SHIFT;
 
+/// Shift to convert to and from the maximum leading zeros as a `u8`.
+pub const MAX_LEADING_ZEROS_SHIFT: i32 = 72;
+
+/// Mask to extract the maximum number of leading zeros.
+///
+/// Leading zeros are the zeros before the last digit of the integer
+/// component, so `007` and `000` both have 2 leading zeros, while
+/// `0` and `0.07` have none. If the value is 0, any number of leading
+/// zeros is allowed, unless rejected by [`NO_INTEGER_LEADING_ZEROS`]
+/// or [`NO_FLOAT_LEADING_ZEROS`].
+pub const MAX_LEADING_ZEROS: u128 = 0xFF << MAX_LEADING_ZEROS_SHIFT;
+
 /// Shift to convert to and from a base prefix as a `u8`.
 pub const BASE_PREFIX_SHIFT: i32 = 88;
 
@@ -485,7 +566,8 @@ pub const EXPONENT_RADIX_SHIFT: i32 = 120;
 pub const EXPONENT_RADIX: u128 = 0xFF << EXPONENT_RADIX_SHIFT;
 
 // Masks do not overlap.
-check_subsequent_masks!(DIGIT_SEPARATOR, BASE_PREFIX);
+check_subsequent_masks!(DIGIT_SEPARATOR, MAX_LEADING_ZEROS);
+check_subsequent_masks!(MAX_LEADING_ZEROS, BASE_PREFIX);
 check_subsequent_masks!(BASE_PREFIX, BASE_SUFFIX);
 check_subsequent_masks!(BASE_SUFFIX, MANTISSA_RADIX);
 check_subsequent_masks!(MANTISSA_RADIX, EXPONENT_BASE);
@@ -493,6 +575,7 @@ check_subsequent_masks!(EXPONENT_BASE, EXPONENT_RADIX);
 
 // Check all our shifts shift the masks to a single byte.
 check_mask_shifts!(DIGIT_SEPARATOR, DIGIT_SEPARATOR_SHIFT);
+check_mask_shifts!(MAX_LEADING_ZEROS, MAX_LEADING_ZEROS_SHIFT);
 check_mask_shifts!(BASE_PREFIX, BASE_PREFIX_SHIFT);
 check_mask_shifts!(BASE_SUFFIX, BASE_SUFFIX_SHIFT);
 check_mask_shifts!(MANTISSA_RADIX, MANTISSA_RADIX_SHIFT);
@@ -523,6 +606,12 @@ pub const FLAG_MASK: u128 =
     CASE_SENSITIVE_EXPONENT |
     CASE_SENSITIVE_BASE_PREFIX |
     CASE_SENSITIVE_BASE_SUFFIX |
+    NEGATIVE_PARENTHESES |
+    OPTIONAL_QUOTES |
+    D_EXPONENT_NOTATION |
+    SIGN_EXPONENT_NOTATION |
+    TRAILING_SIGN |
+    OVERPUNCH_SIGN |
     INTERNAL_DIGIT_SEPARATOR |
     LEADING_DIGIT_SEPARATOR |
     TRAILING_DIGIT_SEPARATOR |
@@ -567,6 +656,8 @@ pub const EXPONENT_FLAG_MASK: u128 =
     REQUIRED_EXPONENT_SIGN |
     NO_EXPONENT_WITHOUT_FRACTION |
     REQUIRED_EXPONENT_NOTATION |
+    D_EXPONENT_NOTATION |
+    SIGN_EXPONENT_NOTATION |
     EXPONENT_INTERNAL_DIGIT_SEPARATOR |
     EXPONENT_LEADING_DIGIT_SEPARATOR |
     EXPONENT_TRAILING_DIGIT_SEPARATOR |
@@ -605,6 +696,12 @@ pub const fn digit_separator(format: u128) -> u8 {
     ((format & DIGIT_SEPARATOR) >> DIGIT_SEPARATOR_SHIFT) as u8
 }
 
+/// Extract the maximum number of leading zeros from the format packed struct.
+#[inline]
+pub const fn max_leading_zeros(format: u128) -> u8 {
+    ((format & MAX_LEADING_ZEROS) >> MAX_LEADING_ZEROS_SHIFT) as u8
+}
+
 /// Extract the base prefix character from the format packed struct.
 #[inline]
 pub const fn base_prefix(format: u128) -> u8 {
@@ -664,8 +761,16 @@ pub const fn radix_from_flags(format: u128, mask: u128, shift: i32) -> u32 {
 /// Determine if the provided exponent flags are valid.
 #[inline]
 pub const fn is_valid_exponent_flags(format: u128) -> bool {
-    // Both cannot be set.
-    format & NO_EXPONENT_NOTATION == 0 || format & REQUIRED_EXPONENT_NOTATION == 0
+    // Cannot require or extend exponent notation if it is disabled.
+    let extended = REQUIRED_EXPONENT_NOTATION | D_EXPONENT_NOTATION | SIGN_EXPONENT_NOTATION;
+    let valid = format & NO_EXPONENT_NOTATION == 0 || format & extended == 0;
+    // `d` cannot be an exponent character if it is a valid digit.
+    let d_exponent = format & D_EXPONENT_NOTATION != 0;
+    // A trailing sign would be ambiguous with an exponent without a character.
+    let trailing_sign = format & (TRAILING_SIGN | SIGN_EXPONENT_NOTATION);
+    valid
+        && !(d_exponent && mantissa_radix(format) > 13)
+        && trailing_sign != TRAILING_SIGN | SIGN_EXPONENT_NOTATION
 }
 
 /// Determine if an optional control character is valid.
@@ -750,17 +855,42 @@ pub const fn is_valid_punctuation(format: u128) -> bool {
         let prefix = base_prefix(format);
         let suffix = base_suffix(format);
         // Check all are optional, or enough are not present.
-        match (separator, prefix, suffix) {
+        let unique = match (separator, prefix, suffix) {
             (0, 0, 0) => true,
             (_, 0, 0) => true,
             (0, _, 0) => true,
             (0, 0, _) => true,
             // Can't have more than 1 0, check they're all different.
             (x, y, z) => x != y && x != z && y != z,
-        }
+        };
+        // Overpunched digits are decimal, and letters are digits above radix 10.
+        let overpunch = format & OVERPUNCH_SIGN == 0
+            || (mantissa_radix(format) == 10
+                && !is_overpunch(separator)
+                && !is_overpunch(prefix)
+                && !is_overpunch(suffix));
+        unique
+            && !is_wrapper(format, separator)
+            && !is_wrapper(format, prefix)
+            && !is_wrapper(format, suffix)
+            && overpunch
     }
 }
 
+/// Determine if the character is an overpunched digit.
+#[inline]
+const fn is_overpunch(value: u8) -> bool {
+    matches!(value, b'{' | b'}' | b'A'..=b'R')
+}
+
+/// Determine if the character is used to wrap numbers.
+#[inline]
+const fn is_wrapper(format: u128, value: u8) -> bool {
+    let parentheses = format & NEGATIVE_PARENTHESES != 0;
+    let quotes = format & OPTIONAL_QUOTES != 0;
+    (parentheses && (value == b'(' || value == b')')) || (quotes && value == b'"')
+}
+
 /// Determine if all of the "punctuation" characters for the options API are valid.
 #[inline]
 #[allow(clippy::if_same_then_else, clippy::needless_bool)]
@@ -785,18 +915,34 @@ pub const fn is_valid_options_punctuation(format: u128, exponent: u8, decimal_po
         false
     } else if cfg!(feature = "format") && base_suffix(format) == exponent {
         false
+    } else if cfg!(feature = "format")
+        && format & D_EXPONENT_NOTATION != 0
+        && (decimal_point == b'd' || decimal_point == b'D')
+    {
+        false
     } else {
         true
     }
 }
 
 /// Determine if the radix is valid.
+///
+/// Radixes above 36 use the extended, case-sensitive alphabet `0-9A-Za-z`,
+/// and are only supported for integers.
 pub const fn is_valid_radix(radix: u32) -> bool {
     if cfg!(feature = "radix") {
-        radix >= 2 && radix <= 36
+        radix >= 2 && radix <= 62
     } else if cfg!(feature = "power-of-two") {
         matches!(radix, 2 | 4 | 8 | 10 | 16 | 32)
     } else {
         radix == 10
     }
 }
+
+/// Determine if the radix is valid for floats.
+///
+/// This is like [`is_valid_radix`], but rejects the extended alphabet
+/// for radixes above 36, which is only supported for integers.
+pub const fn is_valid_float_radix(radix: u32) -> bool {
+    is_valid_radix(radix) && radix <= 36
+}
@@ -0,0 +1,38 @@
+//! Branch and inlining hints for hot code paths.
+//!
+//! Errors, special values, and slow algorithms are rare, so these are
+//! moved out-of-line to keep the common paths small. This improves
+//! instruction cache usage in binaries that parse or write many
+//! numbers, and lets the optimizer lay out the success path linearly.
+//!
+//! These use `#[cold]` functions, which are stable, rather than the
+//! nightly-only `likely` and `unlikely` intrinsics.
+
+#![doc(hidden)]
+
+use crate::error::Error;
+
+/// Mark the calling code path as unlikely to be taken.
+#[cold]
+#[inline(never)]
+pub fn cold() {
+}
+
+/// Hint that the condition is likely to be true.
+#[inline(always)]
+pub fn likely(condition: bool) -> bool {
+    if !condition {
+        cold();
+    }
+    condition
+}
+
+/// Create an error result on a cold path.
+///
+/// This keeps the construction of the error out-of-line, so the
+/// success path of the caller is not penalized.
+#[cold]
+#[inline(never)]
+pub fn cold_error<T>(error: Error) -> Result<T, Error> {
+    Err(error)
+}
@@ -122,6 +122,10 @@ pub trait BytesIter<'a>: Iterator<Item = &'a u8> {
 
     /// Try to read a value of a different type from the iterator.
     /// This advances the internal state of the iterator.
+    ///
+    /// The bytes are read in the native byte order, so integers must be
+    /// converted with `from_le` for word-at-a-time algorithms to be
+    /// correct on big-endian architectures. See [`endian`](crate::endian).
     fn read<V>(&self) -> Option<V>;
 
     /// Advance the internal slice by `N` elements.
@@ -15,6 +15,11 @@
 //! * `parse-integers` - Add support for parsing integers.
 //! * `parse-floats` - Add support for parsing floats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `nightly` - Use portable SIMD to scan for digits.
+//! * `fuzz` - Promote debug assertions to checks, for fuzzing.
+//! * `unicode` - Normalize exotic minus signs and non-breaking spaces.
+//! * `bcd` - Convert decimal strings to and from BCD and packed decimals.
+//! * `varint` - Encode floats in a canonical, variable-length binary encoding.
 //!
 //! # Note
 //!
@@ -40,24 +45,60 @@
 #![allow(unused_unsafe)]
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+
+// Promote debug assertions to checks when fuzzing, so internal invariants
+// are verified in optimized builds. These shadow the standard macros.
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert {
+    ($($arg:tt)*) => (assert!($($arg)*));
+}
+
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert_eq {
+    ($($arg:tt)*) => (assert_eq!($($arg)*));
+}
 
 pub mod algorithm;
 pub mod ascii;
 pub mod assert;
+pub mod bcd;
 pub mod bf16;
+pub mod boolean;
+pub mod c_str;
+pub mod canonical;
+pub mod carry;
+pub mod config;
 pub mod constants;
+pub mod custom_float;
+pub mod decimal;
+pub mod delimiter;
 pub mod digit;
 pub mod div128;
+pub mod endian;
 pub mod error;
+pub mod exponent;
 pub mod extended_float;
 pub mod f16;
 pub mod format;
+pub mod hint;
 pub mod iterator;
+pub mod log;
+pub mod matcher;
 pub mod mul;
 pub mod num;
 pub mod options;
+pub mod posit;
+pub mod pow;
+pub mod radix;
 pub mod result;
+pub mod sign;
 pub mod step;
+pub mod unicode;
+pub mod varint;
+pub mod wrapper;
 
 mod api;
 mod feature_format;
@@ -0,0 +1,102 @@
+//! Fast integral logarithms and decimal digit counts.
+//!
+//! Formatters need the number of digits of a value before writing it,
+//! to size buffers, pad or align columns, or choose between fixed and
+//! scientific notation. These use the leading zeros of the value, and
+//! a multiplication by an integral approximation of `log10(2)`, rather
+//! than repeated division or floating-point logarithms.
+//!
+//! The exact functions, such as [`floor_log10`] and [`digit_count`], are
+//! always exact. The estimates, such as [`estimate_log10`], are never
+//! above the exact value, and at most 1 below it, so they can be used
+//! for bounds without correcting them. Logarithms of zero are treated as
+//! logarithms of one, so zero has a single digit.
+
+use crate::num::UnsignedInteger;
+use crate::pow::{POW10_U128, POW10_U64};
+
+/// Calculate `⌊log2(x)⌋`, or `0` if `x` is `0`.
+///
+/// This is exact, since it's the position of the most significant bit.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::log::floor_log2;
+///
+/// assert_eq!(floor_log2(1u32), 0);
+/// assert_eq!(floor_log2(255u8), 7);
+/// assert_eq!(floor_log2(256u64), 8);
+/// ```
+#[inline(always)]
+pub fn floor_log2<T: UnsignedInteger>(x: T) -> u32 {
+    T::BITS as u32 - 1 - (x | T::ONE).leading_zeros()
+}
+
+/// Estimate `⌊log10(x)⌋`, or `0` if `x` is `0`.
+///
+/// This is `⌊⌊log2(x)⌋ * log10(2)⌋`, which is never above the exact
+/// value, and at most 1 below it. For example, `9` and `10` both have
+/// a `⌊log2(x)⌋` of `3`, and an estimate of `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::log::estimate_log10;
+///
+/// assert_eq!(estimate_log10(9u32), 0);
+/// assert_eq!(estimate_log10(10u32), 0);
+/// assert_eq!(estimate_log10(16u32), 1);
+/// ```
+#[inline(always)]
+pub fn estimate_log10<T: UnsignedInteger>(x: T) -> u32 {
+    // `1233 / 2^12` is within `log10(2)` for every `⌊log2(x)⌋ <= 127`.
+    (floor_log2(x) * 1233) >> 12
+}
+
+/// Calculate `⌊log10(x)⌋`, or `0` if `x` is `0`.
+///
+/// This corrects [`estimate_log10`] with a single comparison to a power
+/// of 10, so it is exact.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::log::floor_log10;
+///
+/// assert_eq!(floor_log10(9u32), 0);
+/// assert_eq!(floor_log10(10u32), 1);
+/// assert_eq!(floor_log10(u64::MAX), 19);
+/// ```
+#[inline]
+pub fn floor_log10<T: UnsignedInteger>(x: T) -> u32 {
+    let log10 = estimate_log10(x);
+    let index = log10 as usize + 1;
+    let is_above = if T::BITS <= 64 {
+        matches!(POW10_U64.get(index), Some(&y) if x.as_u64() >= y)
+    } else {
+        matches!(POW10_U128.get(index), Some(&y) if x.as_u128() >= y)
+    };
+    log10 + is_above as u32
+}
+
+/// Calculate the number of decimal digits in `x`.
+///
+/// This is exact, and is `1` for `0`. The sign of signed integers isn't
+/// included, so take the absolute value, such as with `unsigned_abs`, and
+/// add 1 for negative values.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::log::digit_count;
+///
+/// assert_eq!(digit_count(0u8), 1);
+/// assert_eq!(digit_count(999u16), 3);
+/// assert_eq!(digit_count(1000u16), 4);
+/// assert_eq!(digit_count(u128::MAX), 39);
+/// ```
+#[inline]
+pub fn digit_count<T: UnsignedInteger>(x: T) -> usize {
+    floor_log10(x) as usize + 1
+}
@@ -0,0 +1,605 @@
+//! Validate and locate numbers without converting them.
+//!
+//! The matcher is a deterministic finite automaton (DFA), generated from
+//! a number format, which recognizes the syntax of a number one byte at
+//! a time. This is useful to find numeric tokens in large texts, such as
+//! logs, where only the location of each number is needed, or to check
+//! if a token is a number before converting it.
+//!
+//! The bytes are first mapped to a small set of equivalence classes, so
+//! the transition table only has a column per class, rather than per byte.
+//!
+//! Special values, such as `NaN` or `inf`, are not matched, and a number
+//! must always have at least 1 digit in the mantissa.
+
+#![cfg(feature = "parse")]
+
+use crate::digit::char_is_digit_const;
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::format_flags::is_valid_options_punctuation;
+use crate::result::Result;
+
+// STATES
+// ------
+
+// The integer, fraction and exponent each have states for a leading
+// digit separator, after a digit, and after a trailing digit separator.
+// The fraction states track if the integer had any digits.
+
+/// Invalid number, no further transitions are possible.
+const DEAD: u8 = 0;
+/// Initial state, before any bytes.
+const START: u8 = 1;
+/// After the mantissa sign.
+const SIGN: u8 = 2;
+/// After a leading `0`, which may start a base prefix.
+const ZERO: u8 = 3;
+/// After the base prefix.
+const PREFIX: u8 = 4;
+const INTEGER_LEADING: u8 = 5;
+const INTEGER_DIGIT: u8 = 6;
+const INTEGER_TRAILING: u8 = 7;
+/// After the decimal point, without integer digits.
+const POINT: u8 = 8;
+/// After the decimal point, with integer digits.
+const POINT_INTEGER: u8 = 9;
+const FRACTION_LEADING: u8 = 10;
+const FRACTION_LEADING_INTEGER: u8 = 11;
+const FRACTION_DIGIT: u8 = 12;
+const FRACTION_TRAILING: u8 = 13;
+/// After the exponent character.
+const EXPONENT: u8 = 14;
+/// After the exponent sign.
+const EXPONENT_SIGN: u8 = 15;
+const EXPONENT_LEADING: u8 = 16;
+const EXPONENT_DIGIT: u8 = 17;
+const EXPONENT_TRAILING: u8 = 18;
+/// After the base suffix.
+const SUFFIX: u8 = 19;
+
+/// Number of states, including the dead state.
+const STATES: usize = 20;
+/// Maximum number of byte classes.
+const MAX_CLASSES: usize = 32;
+
+// GRAMMAR
+// -------
+
+/// Digit separator flags for a component of the number.
+#[derive(Clone, Copy)]
+struct Separators {
+    internal: bool,
+    leading: bool,
+    trailing: bool,
+    consecutive: bool,
+}
+
+/// The syntax of the number, extracted from the format.
+struct Grammar {
+    mantissa_radix: u32,
+    exponent_radix: u32,
+    exponent: u8,
+    decimal_point: u8,
+    separator: u8,
+    base_prefix: u8,
+    base_suffix: u8,
+    integer: Separators,
+    fraction: Separators,
+    exponents: Separators,
+    required_integer_digits: bool,
+    required_fraction_digits: bool,
+    required_exponent_digits: bool,
+    no_positive_mantissa_sign: bool,
+    required_mantissa_sign: bool,
+    no_exponent_notation: bool,
+    no_positive_exponent_sign: bool,
+    required_exponent_sign: bool,
+    no_exponent_without_fraction: bool,
+    no_float_leading_zeros: bool,
+    required_exponent_notation: bool,
+    case_sensitive_exponent: bool,
+    case_sensitive_base_prefix: bool,
+    case_sensitive_base_suffix: bool,
+    d_exponent_notation: bool,
+    sign_exponent_notation: bool,
+}
+
+/// Compare two ASCII characters, optionally ignoring case.
+#[inline]
+const fn is_char(byte: u8, expected: u8, case_sensitive: bool) -> bool {
+    // `u8::to_ascii_lowercase` isn't const as of 1.51.
+    const fn lowercase(c: u8) -> u8 {
+        if c >= b'A' && c <= b'Z' {
+            c + (b'a' - b'A')
+        } else {
+            c
+        }
+    }
+    if expected == 0 {
+        false
+    } else if case_sensitive {
+        byte == expected
+    } else {
+        lowercase(byte) == lowercase(expected)
+    }
+}
+
+impl Grammar {
+    const fn new<const FORMAT: u128>(exponent: u8, decimal_point: u8) -> Self {
+        let format = NumberFormat::<{ FORMAT }> {};
+        Self {
+            mantissa_radix: format.mantissa_radix(),
+            exponent_radix: format.exponent_radix(),
+            exponent,
+            decimal_point,
+            separator: format.digit_separator(),
+            base_prefix: format.base_prefix(),
+            base_suffix: format.base_suffix(),
+            integer: Separators {
+                internal: format.integer_internal_digit_separator(),
+                leading: format.integer_leading_digit_separator(),
+                trailing: format.integer_trailing_digit_separator(),
+                consecutive: format.integer_consecutive_digit_separator(),
+            },
+            fraction: Separators {
+                internal: format.fraction_internal_digit_separator(),
+                leading: format.fraction_leading_digit_separator(),
+                trailing: format.fraction_trailing_digit_separator(),
+                consecutive: format.fraction_consecutive_digit_separator(),
+            },
+            exponents: Separators {
+                internal: format.exponent_internal_digit_separator(),
+                leading: format.exponent_leading_digit_separator(),
+                trailing: format.exponent_trailing_digit_separator(),
+                consecutive: format.exponent_consecutive_digit_separator(),
+            },
+            required_integer_digits: format.required_integer_digits(),
+            required_fraction_digits: format.required_fraction_digits(),
+            required_exponent_digits: format.required_exponent_digits(),
+            no_positive_mantissa_sign: format.no_positive_mantissa_sign(),
+            required_mantissa_sign: format.required_mantissa_sign(),
+            no_exponent_notation: format.no_exponent_notation(),
+            no_positive_exponent_sign: format.no_positive_exponent_sign(),
+            required_exponent_sign: format.required_exponent_sign(),
+            no_exponent_without_fraction: format.no_exponent_without_fraction(),
+            no_float_leading_zeros: format.no_float_leading_zeros(),
+            required_exponent_notation: format.required_exponent_notation(),
+            case_sensitive_exponent: format.case_sensitive_exponent(),
+            case_sensitive_base_prefix: format.case_sensitive_base_prefix(),
+            case_sensitive_base_suffix: format.case_sensitive_base_suffix(),
+            d_exponent_notation: format.d_exponent_notation(),
+            sign_exponent_notation: format.sign_exponent_notation(),
+        }
+    }
+
+    /// Determine if the byte is an exponent character.
+    const fn is_exponent(&self, byte: u8) -> bool {
+        // With case-sensitive exponents, `d` must match the case of the exponent.
+        let d = if self.exponent >= b'A' && self.exponent <= b'Z' {
+            b'D'
+        } else {
+            b'd'
+        };
+        is_char(byte, self.exponent, self.case_sensitive_exponent)
+            || (self.d_exponent_notation && is_char(byte, d, self.case_sensitive_exponent))
+    }
+
+    /// Get the next state after the exponent character or sign.
+    const fn exponent_state(&self, state: u8, next: u8) -> u8 {
+        let is_integer = state <= INTEGER_TRAILING;
+        if self.no_exponent_notation || (is_integer && self.no_exponent_without_fraction) {
+            DEAD
+        } else {
+            next
+        }
+    }
+
+    /// Determine if the integer is complete, and may be followed by the decimal point.
+    const fn is_integer_complete(&self, state: u8) -> bool {
+        match state {
+            START | SIGN => !self.required_integer_digits,
+            ZERO | INTEGER_DIGIT => true,
+            INTEGER_TRAILING => self.integer.trailing,
+            _ => false,
+        }
+    }
+
+    /// Determine if the mantissa is complete, and has at least 1 digit.
+    const fn is_mantissa_complete(&self, state: u8) -> bool {
+        match state {
+            ZERO | INTEGER_DIGIT | FRACTION_DIGIT => true,
+            INTEGER_TRAILING => self.integer.trailing,
+            POINT_INTEGER => !self.required_fraction_digits,
+            FRACTION_TRAILING => self.fraction.trailing,
+            _ => false,
+        }
+    }
+
+    /// Determine if the exponent is complete.
+    const fn is_exponent_complete(&self, state: u8) -> bool {
+        match state {
+            EXPONENT | EXPONENT_SIGN => !self.required_exponent_digits,
+            EXPONENT_DIGIT => true,
+            EXPONENT_TRAILING => self.exponents.trailing,
+            _ => false,
+        }
+    }
+
+    /// Determine if the number is complete, ignoring the base suffix.
+    const fn is_number_complete(&self, state: u8) -> bool {
+        let mantissa = self.is_mantissa_complete(state) && !self.required_exponent_notation;
+        mantissa || self.is_exponent_complete(state)
+    }
+
+    /// Determine if the state is accepting, that is, a complete number.
+    const fn is_accepting(&self, state: u8) -> bool {
+        state == SUFFIX || self.is_number_complete(state)
+    }
+
+    /// Get the next state for a digit separator in a component of the number.
+    ///
+    /// `leading` and `trailing` are the separator states for the component,
+    /// and `is_digit` is if the current state is after a digit.
+    const fn separator(
+        &self,
+        state: u8,
+        separators: Separators,
+        leading: u8,
+        trailing: u8,
+        is_digit: bool,
+    ) -> u8 {
+        if state == leading || state == trailing {
+            if separators.consecutive {
+                state
+            } else {
+                DEAD
+            }
+        } else if is_digit {
+            if separators.internal || separators.trailing {
+                trailing
+            } else {
+                DEAD
+            }
+        } else if separators.leading {
+            leading
+        } else {
+            DEAD
+        }
+    }
+
+    /// Get the next state for a digit in a component of the number.
+    const fn digit(&self, state: u8, separators: Separators, trailing: u8, next: u8) -> u8 {
+        if state == trailing && !separators.internal {
+            DEAD
+        } else {
+            next
+        }
+    }
+
+    /// Get the next state from the current state and byte.
+    const fn transition(&self, state: u8, byte: u8) -> u8 {
+        let is_separator = self.separator != 0 && byte == self.separator;
+        let is_mantissa_digit = char_is_digit_const(byte, self.mantissa_radix);
+        let is_exponent_digit = char_is_digit_const(byte, self.exponent_radix);
+        let is_sign = byte == b'+' || byte == b'-';
+        match state {
+            START if self.required_mantissa_sign && !is_sign => DEAD,
+            START if is_sign => {
+                if byte == b'+' && self.no_positive_mantissa_sign {
+                    DEAD
+                } else {
+                    SIGN
+                }
+            },
+            START | SIGN if is_mantissa_digit && byte == b'0' => ZERO,
+            START | SIGN | PREFIX | INTEGER_LEADING | INTEGER_DIGIT | INTEGER_TRAILING
+                if is_mantissa_digit =>
+            {
+                self.digit(state, self.integer, INTEGER_TRAILING, INTEGER_DIGIT)
+            },
+            ZERO if is_mantissa_digit => {
+                if self.no_float_leading_zeros {
+                    DEAD
+                } else {
+                    INTEGER_DIGIT
+                }
+            },
+            ZERO if is_char(byte, self.base_prefix, self.case_sensitive_base_prefix) => PREFIX,
+            START | SIGN | PREFIX | INTEGER_LEADING if is_separator => {
+                self.separator(state, self.integer, INTEGER_LEADING, INTEGER_TRAILING, false)
+            },
+            // A separator would hide the leading zero from the next digit.
+            ZERO if is_separator && self.no_float_leading_zeros => DEAD,
+            ZERO | INTEGER_DIGIT | INTEGER_TRAILING if is_separator => {
+                self.separator(state, self.integer, INTEGER_LEADING, INTEGER_TRAILING, true)
+            },
+            _ if byte == self.decimal_point && self.is_integer_complete(state) => {
+                if state == START || state == SIGN {
+                    POINT
+                } else {
+                    POINT_INTEGER
+                }
+            },
+            POINT
+            | POINT_INTEGER
+            | FRACTION_LEADING
+            | FRACTION_LEADING_INTEGER
+            | FRACTION_DIGIT
+            | FRACTION_TRAILING
+                if is_mantissa_digit =>
+            {
+                self.digit(state, self.fraction, FRACTION_TRAILING, FRACTION_DIGIT)
+            },
+            POINT | FRACTION_LEADING if is_separator => {
+                self.separator(state, self.fraction, FRACTION_LEADING, FRACTION_TRAILING, false)
+            },
+            POINT_INTEGER | FRACTION_LEADING_INTEGER if is_separator => {
+                let leading = FRACTION_LEADING_INTEGER;
+                self.separator(state, self.fraction, leading, FRACTION_TRAILING, false)
+            },
+            FRACTION_DIGIT | FRACTION_TRAILING if is_separator => {
+                self.separator(state, self.fraction, FRACTION_LEADING, FRACTION_TRAILING, true)
+            },
+            _ if self.is_exponent(byte) && self.is_mantissa_complete(state) => {
+                self.exponent_state(state, EXPONENT)
+            },
+            _ if is_sign && self.sign_exponent_notation && self.is_mantissa_complete(state) => {
+                if byte == b'+' && self.no_positive_exponent_sign {
+                    DEAD
+                } else {
+                    self.exponent_state(state, EXPONENT_SIGN)
+                }
+            },
+            EXPONENT if is_sign => {
+                if byte == b'+' && self.no_positive_exponent_sign {
+                    DEAD
+                } else {
+                    EXPONENT_SIGN
+                }
+            },
+            EXPONENT if self.required_exponent_sign => DEAD,
+            EXPONENT | EXPONENT_SIGN | EXPONENT_LEADING | EXPONENT_DIGIT | EXPONENT_TRAILING
+                if is_exponent_digit =>
+            {
+                self.digit(state, self.exponents, EXPONENT_TRAILING, EXPONENT_DIGIT)
+            },
+            EXPONENT | EXPONENT_SIGN | EXPONENT_LEADING if is_separator => {
+                self.separator(state, self.exponents, EXPONENT_LEADING, EXPONENT_TRAILING, false)
+            },
+            EXPONENT_DIGIT | EXPONENT_TRAILING if is_separator => {
+                self.separator(state, self.exponents, EXPONENT_LEADING, EXPONENT_TRAILING, true)
+            },
+            _ if is_char(byte, self.base_suffix, self.case_sensitive_base_suffix)
+                && self.is_number_complete(state) =>
+            {
+                SUFFIX
+            },
+            _ => DEAD,
+        }
+    }
+}
+
+// MATCHER
+// -------
+
+/// DFA to validate and locate numbers in a given format.
+///
+/// The matcher only validates the syntax of the number, so it's much
+/// faster than parsing the number, and never fails due to overflow.
+/// Each number is matched as a float, so valid integers are a subset
+/// of the matches, without a fraction or exponent.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lexical_util::format::STANDARD;
+/// # use lexical_util::matcher::NumberMatcher;
+/// let matcher = NumberMatcher::new::<{ STANDARD }>(b'e', b'.').unwrap();
+/// assert!(matcher.is_match(b"-1.5e10"));
+/// assert!(!matcher.is_match(b"1.5e"));
+/// assert_eq!(matcher.match_len(b"1.5e10 ms"), Some(6));
+/// assert_eq!(matcher.find(b"took 12.5ms"), Some((5, 9)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberMatcher {
+    /// Equivalence class for each byte.
+    classes: [u8; 256],
+    /// Next state for each state and byte class.
+    transitions: [[u8; MAX_CLASSES]; STATES],
+    /// Bitmask of the accepting states.
+    accepting: u32,
+}
+
+impl NumberMatcher {
+    /// Invalid state, from which no number can be matched.
+    pub const DEAD: u8 = DEAD;
+
+    /// Initial state, before any bytes are processed.
+    pub const START: u8 = START;
+
+    /// Generate the matcher for a number format.
+    ///
+    /// The exponent character and decimal point are not part of the
+    /// packed format, so they must be provided, and are usually
+    /// the same as the parse options.
+    ///
+    /// Returns an error if the format is invalid, or if the exponent
+    /// or decimal point conflict with the format.
+    pub const fn new<const FORMAT: u128>(exponent: u8, decimal_point: u8) -> Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        } else if !is_valid_options_punctuation(FORMAT, exponent, decimal_point) {
+            return Err(Error::InvalidPunctuation);
+        }
+
+        let grammar = Grammar::new::<FORMAT>(exponent, decimal_point);
+        let mut classes = [0u8; 256];
+        let mut transitions = [[DEAD; MAX_CLASSES]; STATES];
+        let mut count = 0;
+        let mut byte = 0;
+        while byte < 256 {
+            // Get the transitions for the byte from every state.
+            let mut column = [DEAD; STATES];
+            let mut state = 0;
+            while state < STATES {
+                column[state] = grammar.transition(state as u8, byte as u8);
+                state += 1;
+            }
+
+            // Find a class with identical transitions, or add a new one.
+            let mut class = 0;
+            while class < count && !is_same_column(&transitions, class, &column) {
+                class += 1;
+            }
+            if class == count {
+                if count == MAX_CLASSES {
+                    return Err(Error::InvalidPunctuation);
+                }
+                let mut state = 0;
+                while state < STATES {
+                    transitions[state][class] = column[state];
+                    state += 1;
+                }
+                count += 1;
+            }
+            classes[byte] = class as u8;
+            byte += 1;
+        }
+
+        let mut accepting = 0;
+        let mut state = 0;
+        while state < STATES {
+            if grammar.is_accepting(state as u8) {
+                accepting |= 1 << state;
+            }
+            state += 1;
+        }
+
+        Ok(Self {
+            classes,
+            transitions,
+            accepting,
+        })
+    }
+
+    /// Get the next state after processing a byte.
+    ///
+    /// The state must be [`START`](Self::START), or returned from
+    /// a previous call. Once [`DEAD`](Self::DEAD) is returned, no
+    /// further bytes can form a valid number.
+    #[inline(always)]
+    pub const fn next_state(&self, state: u8, byte: u8) -> u8 {
+        let class = self.classes[byte as usize];
+        self.transitions[state as usize][class as usize]
+    }
+
+    /// Determine if the bytes processed to reach the state are a valid number.
+    #[inline(always)]
+    pub const fn is_accepting(&self, state: u8) -> bool {
+        self.accepting & (1 << state) != 0
+    }
+
+    /// Get the length of the longest valid number at the start of the bytes.
+    ///
+    /// Returns `None` if the bytes do not start with a valid number.
+    #[inline]
+    pub fn match_len(&self, bytes: &[u8]) -> Option<usize> {
+        let mut state = START;
+        let mut len = None;
+        for (index, &byte) in bytes.iter().enumerate() {
+            state = self.next_state(state, byte);
+            if state == DEAD {
+                break;
+            } else if self.is_accepting(state) {
+                len = Some(index + 1);
+            }
+        }
+        len
+    }
+
+    /// Determine if all the bytes are a single, valid number.
+    #[inline]
+    pub fn is_match(&self, bytes: &[u8]) -> bool {
+        let mut state = START;
+        for &byte in bytes {
+            state = self.next_state(state, byte);
+            if state == DEAD {
+                return false;
+            }
+        }
+        self.is_accepting(state)
+    }
+
+    /// Find the first valid number in the bytes.
+    ///
+    /// Returns the start and end indexes of the leftmost, longest number.
+    /// The number may be adjacent to other characters, so check the
+    /// surrounding bytes to only match delimited numbers.
+    #[inline]
+    pub fn find(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        let mut start = 0;
+        while start < bytes.len() {
+            // Skip bytes that cannot start a number.
+            if self.next_state(START, bytes[start]) != DEAD {
+                if let Some(len) = self.match_len(&bytes[start..]) {
+                    return Some((start, start + len));
+                }
+            }
+            start += 1;
+        }
+        None
+    }
+
+    /// Iterate over the start and end indexes of each number in the bytes.
+    ///
+    /// Numbers do not overlap, and each number is the leftmost, longest
+    /// number after the previous one.
+    #[inline]
+    pub fn find_iter<'a>(&'a self, bytes: &'a [u8]) -> Matches<'a> {
+        Matches {
+            matcher: self,
+            bytes,
+            index: 0,
+        }
+    }
+}
+
+/// Determine if the transitions for a class are identical to the column.
+const fn is_same_column(
+    transitions: &[[u8; MAX_CLASSES]; STATES],
+    class: usize,
+    column: &[u8; STATES],
+) -> bool {
+    let mut state = 0;
+    while state < STATES {
+        if transitions[state][class] != column[state] {
+            return false;
+        }
+        state += 1;
+    }
+    true
+}
+
+/// Iterator over the numbers in a buffer.
+///
+/// This is created by [`NumberMatcher::find_iter`].
+#[derive(Clone, Debug)]
+pub struct Matches<'a> {
+    matcher: &'a NumberMatcher,
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.matcher.find(&self.bytes[self.index..])?;
+        let result = (self.index + start, self.index + end);
+        self.index = result.1;
+        Some(result)
+    }
+}
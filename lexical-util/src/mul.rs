@@ -54,3 +54,50 @@ where
 
     x1 * y1 + w2 + w3
 }
+
+/// Calculate `x * y + z` for a 128-bit value, returning the wrapped result
+/// and if an overflow occurred.
+///
+/// This decomposes the 128-bit value into 64-bit halves, and only uses
+/// 64-bit multiplication. On targets without native 128-bit multiplication,
+/// such as wasm32 or thumb, this avoids a full 128-bit multiplication.
+#[inline(always)]
+pub fn overflowing_mul_add_u128(x: u128, y: u64, z: u64) -> (u128, bool) {
+    let x1 = (x >> 64) as u64;
+    let x0 = x as u64;
+
+    // This cannot overflow, since `(2^64-1)^2 + 2^64-1 < 2^128`.
+    let (p1, p0) = mul::<u64, u32>(x0, y);
+    let (lo, carry) = p0.overflowing_add(z);
+    let p1 = p1 + carry as u64;
+
+    let (q1, q0) = mul::<u64, u32>(x1, y);
+    let (hi, carry) = q0.overflowing_add(p1);
+    let value = ((hi as u128) << 64) | lo as u128;
+    (value, q1 != 0 || carry)
+}
+
+/// Calculate `x * y + z` for a 128-bit value, wrapping on overflow.
+///
+/// See [`overflowing_mul_add_u128`] for the algorithm description.
+#[inline(always)]
+pub fn wrapping_mul_add_u128(x: u128, y: u64, z: u64) -> u128 {
+    let x1 = (x >> 64) as u64;
+    let x0 = x as u64;
+
+    let (p1, p0) = mul::<u64, u32>(x0, y);
+    let (lo, carry) = p0.overflowing_add(z);
+    let hi = x1.wrapping_mul(y).wrapping_add(p1 + carry as u64);
+    ((hi as u128) << 64) | lo as u128
+}
+
+/// Calculate `x * y + z` for a 128-bit value, returning `None` on overflow.
+///
+/// See [`overflowing_mul_add_u128`] for the algorithm description.
+#[inline(always)]
+pub fn checked_mul_add_u128(x: u128, y: u64, z: u64) -> Option<u128> {
+    match overflowing_mul_add_u128(x, y, z) {
+        (value, false) => Some(value),
+        (_, true) => None,
+    }
+}
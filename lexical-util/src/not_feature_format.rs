@@ -30,28 +30,35 @@ use crate::format_flags as flags;
 ///     17. case_sensitive_exponent
 ///     18. case_sensitive_base_prefix
 ///     19. case_sensitive_base_suffix
-///     20. integer_internal_digit_separator
-///     21. fraction_internal_digit_separator
-///     22. exponent_internal_digit_separator
-///     23. internal_digit_separator
-///     24. integer_leading_digit_separator
-///     25. fraction_leading_digit_separator
-///     26. exponent_leading_digit_separator
-///     27. leading_digit_separator
-///     28. integer_trailing_digit_separator
-///     29. fraction_trailing_digit_separator
-///     30. exponent_trailing_digit_separator
-///     31. trailing_digit_separator
-///     32. integer_consecutive_digit_separator
-///     33. fraction_consecutive_digit_separator
-///     34. exponent_consecutive_digit_separator
-///     35. consecutive_digit_separator
-///     36. special_digit_separator
-///     37. digit_separator
-///     38. base_prefix
-///     39. base_suffix
-///     40. exponent_base
-///     41. exponent_radix
+///     20. negative_parentheses
+///     21. optional_quotes
+///     22. d_exponent_notation
+///     23. sign_exponent_notation
+///     24. trailing_sign
+///     25. overpunch_sign
+///     26. integer_internal_digit_separator
+///     27. fraction_internal_digit_separator
+///     28. exponent_internal_digit_separator
+///     29. internal_digit_separator
+///     30. integer_leading_digit_separator
+///     31. fraction_leading_digit_separator
+///     32. exponent_leading_digit_separator
+///     33. leading_digit_separator
+///     34. integer_trailing_digit_separator
+///     35. fraction_trailing_digit_separator
+///     36. exponent_trailing_digit_separator
+///     37. trailing_digit_separator
+///     38. integer_consecutive_digit_separator
+///     39. fraction_consecutive_digit_separator
+///     40. exponent_consecutive_digit_separator
+///     41. consecutive_digit_separator
+///     42. special_digit_separator
+///     43. digit_separator
+///     44. base_prefix
+///     45. base_suffix
+///     46. exponent_base
+///     47. exponent_radix
+///     48. max_leading_zeros
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
@@ -73,6 +80,27 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         self.error().is_success()
     }
 
+    /// Determine if the number format is valid for floats.
+    ///
+    /// This is like [`is_valid`](Self::is_valid), but also rejects radixes
+    /// above 36, which are only supported for integers.
+    pub const fn is_valid_float(&self) -> bool {
+        self.float_error().is_success()
+    }
+
+    /// Get the error type from the format, for floats.
+    pub const fn float_error(&self) -> Error {
+        if !flags::is_valid_float_radix(self.mantissa_radix()) {
+            Error::InvalidMantissaRadix
+        } else if !flags::is_valid_float_radix(self.exponent_base()) {
+            Error::InvalidExponentBase
+        } else if !flags::is_valid_float_radix(self.exponent_radix()) {
+            Error::InvalidExponentRadix
+        } else {
+            self.error()
+        }
+    }
+
     /// Get the error type from the format.
     pub const fn error(&self) -> Error {
         let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
@@ -270,6 +298,60 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If negative numbers may be wrapped in parentheses.
+    pub const NEGATIVE_PARENTHESES: bool = false;
+
+    /// Get if negative numbers may be wrapped in parentheses.
+    #[inline(always)]
+    pub const fn negative_parentheses(&self) -> bool {
+        Self::NEGATIVE_PARENTHESES
+    }
+
+    /// If numbers may be wrapped in double quotes.
+    pub const OPTIONAL_QUOTES: bool = false;
+
+    /// Get if numbers may be wrapped in double quotes.
+    #[inline(always)]
+    pub const fn optional_quotes(&self) -> bool {
+        Self::OPTIONAL_QUOTES
+    }
+
+    /// If the exponent character may also be `d` or `D`.
+    pub const D_EXPONENT_NOTATION: bool = false;
+
+    /// Get if the exponent character may also be `d` or `D`.
+    #[inline(always)]
+    pub const fn d_exponent_notation(&self) -> bool {
+        Self::D_EXPONENT_NOTATION
+    }
+
+    /// If the exponent character may be omitted if the exponent has a sign.
+    pub const SIGN_EXPONENT_NOTATION: bool = false;
+
+    /// Get if the exponent character may be omitted if the exponent has a sign.
+    #[inline(always)]
+    pub const fn sign_exponent_notation(&self) -> bool {
+        Self::SIGN_EXPONENT_NOTATION
+    }
+
+    /// If the sign may follow the digits of the number.
+    pub const TRAILING_SIGN: bool = false;
+
+    /// Get if the sign may follow the digits of the number.
+    #[inline(always)]
+    pub const fn trailing_sign(&self) -> bool {
+        Self::TRAILING_SIGN
+    }
+
+    /// If the last digit of an integer may be overpunched with the sign.
+    pub const OVERPUNCH_SIGN: bool = false;
+
+    /// Get if the last digit of an integer may be overpunched with the sign.
+    #[inline(always)]
+    pub const fn overpunch_sign(&self) -> bool {
+        Self::OVERPUNCH_SIGN
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -438,6 +520,17 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::DIGIT_SEPARATOR
     }
 
+    /// The maximum number of leading zeros in the packed struct.
+    pub const MAX_LEADING_ZEROS: u8 = 0;
+
+    /// Get the maximum number of leading zeros in the integer component.
+    ///
+    /// If the value is 0, any number of leading zeros is allowed.
+    #[inline(always)]
+    pub const fn max_leading_zeros(&self) -> u8 {
+        Self::MAX_LEADING_ZEROS
+    }
+
     /// The base prefix character in the packed struct.
     pub const BASE_PREFIX: u8 = 0;
 
@@ -350,6 +350,24 @@ number_impl! {
     bf16 true ;
 }
 
+// SCALAR
+// ------
+
+/// Scalar type trait, for numbers and booleans.
+///
+/// This has every bound of [`Number`] except the arithmetic operations
+/// and the `as` conversions, so it is also implemented for `bool`.
+pub trait Scalar:
+    'static + Copy + Default + fmt::Debug + fmt::Display + PartialEq + PartialOrd + Send + Sync
+{
+}
+
+impl<T: Number> Scalar for T {
+}
+
+impl Scalar for bool {
+}
+
 // INTEGER
 // -------
 
@@ -552,7 +570,16 @@ signed_integer_impl! { i8 i16 i32 i64 i128 isize }
 // ----------------
 
 /// Defines a trait that supports unsigned integral operations.
-pub trait UnsignedInteger: Integer {}
+pub trait UnsignedInteger: Integer {
+    /// Calculate `self * y + z`, wrapping on overflow.
+    ///
+    /// This is the core operation to accumulate parsed digits, and
+    /// may be specialized for types without native multiplication.
+    #[inline(always)]
+    fn wrapping_mul_add(self, y: u32, z: u32) -> Self {
+        self.wrapping_mul(as_cast(y)).wrapping_add(as_cast(z))
+    }
+}
 
 macro_rules! unsigned_integer_impl {
     ($($t:ty)*) => ($(
@@ -560,7 +587,20 @@ macro_rules! unsigned_integer_impl {
     )*)
 }
 
-unsigned_integer_impl! { u8 u16 u32 u64 u128 usize }
+unsigned_integer_impl! { u8 u16 u32 u64 usize }
+
+// Use native 128-bit multiplication on 64-bit architectures, otherwise,
+// 128-bit multiplication is a slow, library call.
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+unsigned_integer_impl! { u128 }
+
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+impl UnsignedInteger for u128 {
+    #[inline(always)]
+    fn wrapping_mul_add(self, y: u32, z: u32) -> Self {
+        crate::mul::wrapping_mul_add_u128(self, y as u64, z as u64)
+    }
+}
 
 // FLOAT
 // -----
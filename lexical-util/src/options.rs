@@ -2,6 +2,14 @@
 
 #[cfg(feature = "write")]
 use crate::constants::FormattedSize;
+#[cfg(feature = "parse")]
+use crate::result::Result;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::iter::FromIterator;
+#[cfg(feature = "std")]
+use std::string::String;
 
 // TRAITS
 // ------
@@ -21,6 +29,113 @@ pub trait WriteOptions: Default {
 pub trait ParseOptions: Default {
     /// Determine if the options are valid.
     fn is_valid(&self) -> bool;
+
+    /// Check if the options are valid, returning the error from the builder if not.
+    fn validate(&self) -> Result<()>;
+}
+
+// REGISTRY
+// --------
+
+/// Options registered under names, such as number dialects loaded from
+/// a configuration file.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::options::Registry;
+///
+/// # pub fn main() {
+/// let mut registry = Registry::new();
+/// registry.register("csv", b',');
+/// registry.register("json", b'.');
+/// assert_eq!(registry.get("csv"), Some(&b','));
+/// assert_eq!(registry.names().collect::<Vec<_>>(), ["csv", "json"]);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registry<T> {
+    options: BTreeMap<String, T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> Registry<T> {
+    /// Create an empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            options: BTreeMap::new(),
+        }
+    }
+
+    /// Register options under a name, returning the replaced options.
+    #[inline]
+    pub fn register<K: Into<String>>(&mut self, name: K, options: T) -> Option<T> {
+        self.options.insert(name.into(), options)
+    }
+
+    /// Get the options registered under a name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.options.get(name)
+    }
+
+    /// Remove the options registered under a name.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        self.options.remove(name)
+    }
+
+    /// Determine if options are registered under a name.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Get the registered names, in sorted order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.options.keys().map(|name| name.as_str())
+    }
+
+    /// Get the number of registered options.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    /// Determine if no options are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Default for Registry<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Into<String>, T> Extend<(K, T)> for Registry<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, T)>>(&mut self, iter: I) {
+        self.options.extend(iter.into_iter().map(|(name, options)| (name.into(), options)));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Into<String>, T> FromIterator<(K, T)> for Registry<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, T)>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
 }
 
 // PRE-DEFINED CONSTANTS
@@ -0,0 +1,288 @@
+//! Posit numbers, also known as type III unums.
+//!
+//! This follows the 2022 posit standard, where every posit has 2
+//! exponent bits. Posits have a tapered precision: numbers near 1 have
+//! more fraction bits than an IEEE-754 float of the same width, while
+//! very large or small numbers have fewer. There is a single zero, and
+//! a single "not a real" (NaR) value, rather than infinities and NaNs.
+//!
+//! Rounding is to the nearest posit, where ties round to the posit
+//! with an even bit pattern. Posits never overflow to NaR or underflow
+//! to zero: non-zero values are clamped to the largest and smallest
+//! positive posits. Every posit is exactly representable as an `f64`.
+
+#![cfg(feature = "posit")]
+#![doc(hidden)]
+
+use crate::extended_float::ExtendedFloat;
+use core::cmp::Ordering;
+use core::fmt;
+
+/// Number of bits in the exponent of every posit.
+const EXPONENT_BITS: i32 = 2;
+
+/// A posit type, with a bit pattern of up to 32 bits.
+pub trait Posit: Copy + PartialEq + fmt::Debug {
+    /// Number of bits in the posit.
+    const BITS: u32;
+
+    /// Create a posit from the low `BITS` bits of a pattern.
+    fn from_pattern(pattern: u32) -> Self;
+
+    /// Get the bit pattern, zero-extended to 32 bits.
+    fn to_pattern(self) -> u32;
+}
+
+/// Get the pattern of the NaR value for an `n`-bit posit.
+#[inline(always)]
+const fn nar_pattern(bits: u32) -> u32 {
+    1 << (bits - 1)
+}
+
+/// Get the pattern of the largest positive value for an `n`-bit posit.
+#[inline(always)]
+const fn max_pattern(bits: u32) -> u32 {
+    nar_pattern(bits) - 1
+}
+
+/// Negate a pattern, using the two's complement of the `n`-bit posit.
+#[inline(always)]
+fn negate_pattern(pattern: u32, bits: u32) -> u32 {
+    let mask = ((1u64 << bits) - 1) as u32;
+    pattern.wrapping_neg() & mask
+}
+
+/// Round a positive extended-precision float to the pattern of an `n`-bit posit.
+///
+/// The value is `fp.mant * 2^fp.exp`, where the high bit of the mantissa
+/// is set. `direction` is the ordering of the exact value relative to
+/// `fp`, and is only used to break ties. Returns the pattern, and if
+/// `fp` is exactly halfway between two posits.
+fn round_positive(fp: ExtendedFloat<u64>, direction: Ordering, bits: u32) -> (u32, bool) {
+    debug_assert!(fp.mant >> 63 == 1);
+
+    // Values outside of the range of regimes are clamped.
+    let scale = fp.exp + 63;
+    let max_scale = (1 << EXPONENT_BITS) * (bits as i32 - 2);
+    if scale >= max_scale {
+        return (max_pattern(bits), false);
+    } else if scale < -max_scale {
+        return (1, false);
+    }
+
+    // Create the infinite bit pattern, without the sign bit, of the
+    // regime, the exponent, and the fraction. The regime is a run
+    // of `k + 1` ones or `-k` zeros, terminated by the opposite bit.
+    let regime = scale.div_euclid(1 << EXPONENT_BITS);
+    let exponent = scale.rem_euclid(1 << EXPONENT_BITS) as u128;
+    let (regime_bits, regime_length) = if regime >= 0 {
+        (((1u128 << (regime + 1)) - 1) << 1, regime + 2)
+    } else {
+        (1u128, 1 - regime)
+    };
+    let fraction = (fp.mant & !(1 << 63)) as u128;
+    let length = (regime_length + EXPONENT_BITS + 63) as u32;
+    let string = regime_bits << (EXPONENT_BITS + 63) | exponent << 63 | fraction;
+
+    // Round the pattern to `n - 1` bits, with ties to even.
+    let shift = length - (bits - 1);
+    let mut pattern = (string >> shift) as u32;
+    let truncated = string & ((1 << shift) - 1);
+    let halfway = 1u128 << (shift - 1);
+    let is_tie = truncated == halfway;
+    let is_above = truncated > halfway
+        || (is_tie
+            && match direction {
+                Ordering::Greater => true,
+                Ordering::Less => false,
+                Ordering::Equal => pattern & 1 == 1,
+            });
+    if is_above {
+        pattern += 1;
+    }
+
+    (pattern.max(1).min(max_pattern(bits)), is_tie)
+}
+
+/// Convert a float to the pattern of an `n`-bit posit, and if it is a tie.
+fn from_f64_pattern(value: f64, direction: Ordering, bits: u32) -> (u32, bool) {
+    if !value.is_finite() {
+        return (nar_pattern(bits), false);
+    } else if value == 0.0 {
+        return (0, false);
+    }
+
+    // Normalize the float, so the high bit of the mantissa is set.
+    let float_bits = value.to_bits();
+    let biased = ((float_bits >> 52) & 0x7FF) as i32;
+    let fraction = float_bits & ((1 << 52) - 1);
+    let (mant, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    };
+    let shift = mant.leading_zeros();
+    let fp = ExtendedFloat {
+        mant: mant << shift,
+        exp: exp - shift as i32,
+    };
+
+    // Negative posits are the two's complement of the positive posit,
+    // which has the same parity, so the magnitude is rounded.
+    let (pattern, is_tie) = round_positive(fp, direction, bits);
+    if value < 0.0 {
+        (negate_pattern(pattern, bits), is_tie)
+    } else {
+        (pattern, is_tie)
+    }
+}
+
+/// Round a float to the nearest posit, with ties to an even bit pattern.
+///
+/// `direction` is the ordering of the exact value relative to `value`,
+/// in magnitude, which breaks ties if `value` was itself rounded. Both
+/// infinities and NaN are converted to NaR.
+#[inline]
+pub fn from_f64<P: Posit>(value: f64, direction: Ordering) -> P {
+    P::from_pattern(from_f64_pattern(value, direction, P::BITS).0)
+}
+
+/// Determine if the float is exactly halfway between two posits.
+#[inline]
+pub fn is_tie<P: Posit>(value: f64) -> bool {
+    from_f64_pattern(value, Ordering::Equal, P::BITS).1
+}
+
+/// Convert a posit to a float exactly, where NaR is converted to NaN.
+pub fn to_f64<P: Posit>(posit: P) -> f64 {
+    let bits = P::BITS;
+    let pattern = posit.to_pattern();
+    if pattern == 0 {
+        return 0.0;
+    } else if pattern == nar_pattern(bits) {
+        return f64::NAN;
+    }
+    let is_negative = pattern & nar_pattern(bits) != 0;
+    let pattern = if is_negative {
+        negate_pattern(pattern, bits)
+    } else {
+        pattern
+    };
+
+    // Align the bits after the sign bit to the top of the integer,
+    // and count the run of the regime.
+    let body = (pattern as u64) << (65 - bits);
+    let (regime, run) = if body >> 63 == 1 {
+        let run = (!body).leading_zeros() as i32;
+        (run - 1, run)
+    } else {
+        let run = body.leading_zeros() as i32;
+        (-run, run)
+    };
+    let rest = body << (run + 1);
+    let exponent = (rest >> (64 - EXPONENT_BITS)) as i32;
+    let fraction = rest << EXPONENT_BITS;
+    let scale = (1 << EXPONENT_BITS) * regime + exponent;
+
+    // The scale is at most `±120`, and the fraction at most 27 bits.
+    let float_bits = ((scale + 1023) as u64) << 52 | fraction >> 12;
+    let value = f64::from_bits(float_bits);
+    if is_negative {
+        -value
+    } else {
+        value
+    }
+}
+
+macro_rules! posit_impl {
+    ($(#[$meta:meta])* $name:ident, $bits:ty, $size:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name {
+            /// Raw bitwise representation of the posit.
+            bits: $bits,
+        }
+
+        impl $name {
+            /// Zero, which has no sign.
+            pub const ZERO: Self = Self::from_bits(0);
+            /// Not a real, for undefined results, infinities, and NaNs.
+            pub const NAR: Self = Self::from_bits(nar_pattern($size) as $bits);
+            /// Largest positive value, `2^(4 * (n - 2))`.
+            pub const MAX: Self = Self::from_bits(max_pattern($size) as $bits);
+            /// Smallest positive value, `2^(-4 * (n - 2))`.
+            pub const MIN_POSITIVE: Self = Self::from_bits(1);
+
+            /// Create a posit from the raw bit pattern.
+            #[inline(always)]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self {
+                    bits,
+                }
+            }
+
+            /// Get the raw bit pattern of the posit.
+            #[inline(always)]
+            pub const fn to_bits(self) -> $bits {
+                self.bits
+            }
+
+            /// Determine if the posit is NaR.
+            #[inline(always)]
+            pub const fn is_nar(self) -> bool {
+                self.bits == Self::NAR.bits
+            }
+
+            /// Round a float to the nearest posit, with ties to an even bit pattern.
+            ///
+            /// Both infinities and NaN are converted to NaR.
+            #[inline]
+            pub fn from_f64(value: f64) -> Self {
+                from_f64(value, Ordering::Equal)
+            }
+
+            /// Convert the posit to a float exactly, where NaR is converted to NaN.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                to_f64(self)
+            }
+        }
+
+        impl Posit for $name {
+            const BITS: u32 = $size;
+
+            #[inline(always)]
+            fn from_pattern(pattern: u32) -> Self {
+                Self::from_bits(pattern as $bits)
+            }
+
+            #[inline(always)]
+            fn to_pattern(self) -> u32 {
+                self.bits as u32
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if self.is_nar() {
+                    f.write_str("NaR")
+                } else {
+                    fmt::Debug::fmt(&self.to_f64(), f)
+                }
+            }
+        }
+    };
+}
+
+posit_impl!(
+    /// 16-bit posit, with up to 12 significant bits, from `2^-56` to `2^56`.
+    Posit16,
+    u16,
+    16
+);
+posit_impl!(
+    /// 32-bit posit, with up to 28 significant bits, from `2^-120` to `2^120`.
+    Posit32,
+    u32,
+    32
+);
@@ -0,0 +1,170 @@
+//! Powers of 10 for integers, and checked exponentiation.
+//!
+//! Fixed-point scaling multiplies by powers of 10, which quickly
+//! overflow: `10^19` is the largest power of 10 that fits in a `u64`.
+//! The tables contain every power of 10 that fits in each type, so
+//! scaling is a lookup and a checked multiplication.
+//!
+//! Formatting splits integers into chunks of digits, dividing by the
+//! largest power of 10 that fits in the smaller type, which is `10^8`
+//! for a `u32` chunk of a `u64`, and `10^19` for a `u64` chunk of a
+//! `u128`. These divisions use multiplication by the reciprocal, which
+//! is much faster than native division, especially for `u128`.
+
+use crate::div128::{moderate_u128_divrem, u128_divrem};
+use crate::num::Integer;
+
+/// Every power of 10 that fits in a `u32`.
+pub const POW10_U32: [u32; 10] =
+    [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000];
+
+/// Every power of 10 that fits in a `u64`.
+pub const POW10_U64: [u64; 20] = [
+    1,
+    10,
+    100,
+    1000,
+    10000,
+    100000,
+    1000000,
+    10000000,
+    100000000,
+    1000000000,
+    10000000000,
+    100000000000,
+    1000000000000,
+    10000000000000,
+    100000000000000,
+    1000000000000000,
+    10000000000000000,
+    100000000000000000,
+    1000000000000000000,
+    10000000000000000000,
+];
+
+/// Every power of 10 that fits in a `u128`.
+pub const POW10_U128: [u128; 39] = [
+    1,
+    10,
+    100,
+    1000,
+    10000,
+    100000,
+    1000000,
+    10000000,
+    100000000,
+    1000000000,
+    10000000000,
+    100000000000,
+    1000000000000,
+    10000000000000,
+    100000000000000,
+    1000000000000000,
+    10000000000000000,
+    100000000000000000,
+    1000000000000000000,
+    10000000000000000000,
+    100000000000000000000,
+    1000000000000000000000,
+    10000000000000000000000,
+    100000000000000000000000,
+    1000000000000000000000000,
+    10000000000000000000000000,
+    100000000000000000000000000,
+    1000000000000000000000000000,
+    10000000000000000000000000000,
+    100000000000000000000000000000,
+    1000000000000000000000000000000,
+    10000000000000000000000000000000,
+    100000000000000000000000000000000,
+    1000000000000000000000000000000000,
+    10000000000000000000000000000000000,
+    100000000000000000000000000000000000,
+    1000000000000000000000000000000000000,
+    10000000000000000000000000000000000000,
+    100000000000000000000000000000000000000,
+];
+
+/// Integers with a table of powers of 10.
+pub trait Pow10: Integer {
+    /// Every power of 10 that fits in the type, starting with `10^0`.
+    const POW10: &'static [Self];
+}
+
+impl Pow10 for u32 {
+    const POW10: &'static [Self] = &POW10_U32;
+}
+
+impl Pow10 for u64 {
+    const POW10: &'static [Self] = &POW10_U64;
+}
+
+impl Pow10 for u128 {
+    const POW10: &'static [Self] = &POW10_U128;
+}
+
+/// Calculate `base^exp`, returning `None` on overflow.
+///
+/// This uses exponentiation by squaring, and is valid for any integer.
+#[inline]
+pub fn checked_pow<T: Integer>(base: T, exp: u32) -> Option<T> {
+    let mut result = T::ONE;
+    let mut base = base;
+    let mut exp = exp;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            result = result.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp != 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(result)
+}
+
+/// Get `10^exp`, returning `None` if it does not fit in the type.
+#[inline(always)]
+pub fn checked_pow10<T: Pow10>(exp: u32) -> Option<T> {
+    T::POW10.get(exp as usize).copied()
+}
+
+/// Calculate `value * 10^exp`, returning `None` on overflow.
+///
+/// Zero never overflows, even if `10^exp` does not fit in the type.
+#[inline]
+pub fn checked_mul_pow10<T: Pow10>(value: T, exp: u32) -> Option<T> {
+    if value == T::ZERO {
+        Some(T::ZERO)
+    } else {
+        value.checked_mul(checked_pow10(exp)?)
+    }
+}
+
+// DIV
+// ---
+
+/// Calculate the quotient and remainder of `n / 10^8`.
+///
+/// This multiplies by the reciprocal of `10^8`, rounded up, with the
+/// division routines of the integer formatters, which is exact for every
+/// `u64`. The remainder has 8 decimal digits, and the quotient has at
+/// most 12.
+#[inline(always)]
+pub fn div_rem_1e8(n: u64) -> (u64, u32) {
+    // `⌈2^154 / 10^8⌉`, see "Division by Invariant Integers Using Multiplication".
+    const FACTOR: u128 = 228359630832953580969325755111919221822;
+    let (quot, rem) = moderate_u128_divrem(n as u128, 100000000, FACTOR, 26);
+    (quot as u64, rem as u32)
+}
+
+/// Calculate the quotient and remainder of `n / 10^19`.
+///
+/// This is the division routine of the decimal integer formatters:
+/// values below `2^83` use a native 64-bit division, and larger values
+/// multiply by the reciprocal of `10^19`. The remainder has 19 decimal
+/// digits, and the quotient has at most 20.
+#[inline(always)]
+pub fn div_rem_1e19(n: u128) -> (u128, u64) {
+    u128_divrem(n, 10)
+}
@@ -0,0 +1,113 @@
+//! A radix, validated at construction.
+//!
+//! Functions that take a raw `u32` radix must check the range at every
+//! call, or assume it is valid. A `Radix` is always from 2 to 62, so the
+//! range is checked once, when it is created, and invalid radixes are
+//! reported as errors rather than debug assertions.
+
+use crate::error::Error;
+use core::convert::TryFrom;
+
+/// Radix for the digits of a number, from 2 to 62.
+///
+/// Radixes up to 36 use the case-insensitive alphabet `0-9A-Z`, and the
+/// extended radixes from 37 to 62 use the case-sensitive alphabet
+/// `0-9A-Za-z`, where `a` has the value 36.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use lexical_util::error::Error;
+/// use lexical_util::radix::Radix;
+///
+/// # pub fn main() {
+/// const BASE_32: Radix = match Radix::new(32) {
+///     Some(radix) => radix,
+///     None => Radix::DECIMAL,
+/// };
+/// assert_eq!(BASE_32.get(), 32);
+/// assert_eq!(Radix::try_from(16u32), Ok(Radix::HEXADECIMAL));
+/// assert_eq!(Radix::try_from(63u32), Err(Error::InvalidRadix));
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Radix(u32);
+
+impl Radix {
+    /// Radix 2, for binary digits.
+    pub const BINARY: Self = Self(2);
+    /// Radix 8, for octal digits.
+    pub const OCTAL: Self = Self(8);
+    /// Radix 10, for decimal digits.
+    pub const DECIMAL: Self = Self(10);
+    /// Radix 16, for hexadecimal digits.
+    pub const HEXADECIMAL: Self = Self(16);
+    /// The smallest radix.
+    pub const MIN: Self = Self(2);
+    /// The largest radix with a case-insensitive alphabet.
+    pub const MAX_CASE_INSENSITIVE: Self = Self(36);
+    /// The largest radix, with a case-sensitive alphabet.
+    pub const MAX: Self = Self(62);
+
+    /// Create a radix, returning `None` if it is not from 2 to 62.
+    #[inline(always)]
+    pub const fn new(radix: u32) -> Option<Self> {
+        if radix >= Self::MIN.0 && radix <= Self::MAX.0 {
+            Some(Self(radix))
+        } else {
+            None
+        }
+    }
+
+    /// Get the radix as an integer.
+    #[inline(always)]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Determine if the radix is a power of two.
+    #[inline(always)]
+    pub const fn is_power_of_two(self) -> bool {
+        self.0 & (self.0 - 1) == 0
+    }
+
+    /// Determine if the radix uses the case-sensitive alphabet `0-9A-Za-z`.
+    #[inline(always)]
+    pub const fn is_extended(self) -> bool {
+        self.0 > Self::MAX_CASE_INSENSITIVE.0
+    }
+}
+
+impl Default for Radix {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::DECIMAL
+    }
+}
+
+impl TryFrom<u32> for Radix {
+    type Error = Error;
+
+    #[inline(always)]
+    fn try_from(radix: u32) -> Result<Self, Error> {
+        Self::new(radix).ok_or(Error::InvalidRadix)
+    }
+}
+
+impl TryFrom<u8> for Radix {
+    type Error = Error;
+
+    #[inline(always)]
+    fn try_from(radix: u8) -> Result<Self, Error> {
+        Self::try_from(radix as u32)
+    }
+}
+
+impl From<Radix> for u32 {
+    #[inline(always)]
+    fn from(radix: Radix) -> Self {
+        radix.get()
+    }
+}
@@ -1,7 +1,95 @@
 //! Result type for numeric parsing functions.
 
-use crate::error;
+use crate::error::Error;
+#[cfg(feature = "parse")]
+use core::convert::TryFrom;
 use core::result;
 
 /// A specialized Result type for lexical operations.
-pub type Result<T> = result::Result<T, error::Error>;
+pub type Result<T> = result::Result<T, Error>;
+
+/// Parse a partial number, returning the number of processed bytes as a `u32`.
+///
+/// Only the first `u32::MAX` bytes are parsed, so the count and the
+/// index of any error always fit in a `u32`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `parse`   - Partial parser for the number.
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn parse_partial_len32<N, Parse>(bytes: &[u8], parse: Parse) -> Result<(N, u32)>
+where
+    Parse: FnOnce(&[u8]) -> Result<(N, usize)>,
+{
+    // On 16-bit targets, `u32::MAX as usize` is `usize::MAX`.
+    let max = u32::MAX as usize;
+    let bytes = if bytes.len() > max {
+        &bytes[..max]
+    } else {
+        bytes
+    };
+    let (value, count) = parse(bytes)?;
+    Ok((value, count as u32))
+}
+
+/// Check a parsed value is within the range, inclusive.
+///
+/// Returns [`Error::OutOfRange`](Error::OutOfRange) at the start of the number if the value
+/// is outside the range, or if the value overflows or underflows the type.
+///
+/// * `result`  - Result of parsing the number.
+/// * `min`     - Smallest valid value, inclusive.
+/// * `max`     - Largest valid value, inclusive.
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn check_range<N: PartialOrd>(result: Result<N>, min: N, max: N) -> Result<N> {
+    match result {
+        Ok(value) if min <= value && value <= max => Ok(value),
+        Ok(_) | Err(Error::Overflow(_)) | Err(Error::Underflow(_)) => Err(Error::OutOfRange(0)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Check a partial parser consumed the entire string.
+///
+/// Returns [`Error::InvalidDigit`] at the first unconsumed byte.
+///
+/// Partial parsers for integers succeed without consuming any digits,
+/// while partial parsers for floats fail with an empty mantissa, so
+/// empty errors before the end of the string are reported as an invalid
+/// digit, like any other unconsumed byte.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `result`  - Result of the partial parser.
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn check_complete<N>(bytes: &[u8], result: Result<(N, usize)>) -> Result<N> {
+    match result {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err(Error::InvalidDigit(count)),
+        Err(error) => match error {
+            Error::Empty(index)
+            | Error::EmptyMantissa(index)
+            | Error::EmptyInteger(index)
+            | Error::EmptyFraction(index)
+            | Error::EmptyExponent(index)
+                if index < bytes.len() =>
+            {
+                Err(Error::InvalidDigit(index))
+            },
+            _ => Err(error),
+        },
+    }
+}
+
+/// Convert a parsed value to another type.
+///
+/// Parse errors are returned unchanged, and conversion failures return
+/// [`Error::ConversionFailed`] at the start of the number.
+///
+/// * `result`  - Result of parsing the number.
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn try_convert<T, U: TryFrom<T>>(result: Result<T>) -> Result<U> {
+    U::try_from(result?).map_err(|_| Error::ConversionFailed(0))
+}
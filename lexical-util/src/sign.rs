@@ -0,0 +1,126 @@
+//! Parse signs that follow the digits of a number.
+//!
+//! Legacy fixed-width files, such as those written by COBOL programs,
+//! often store the sign after the digits, either as a trailing sign, as
+//! in `123-`, or by overpunching the last digit with the sign, as in
+//! `12L`. These are only recognized if the format enables `TRAILING_SIGN`
+//! or `OVERPUNCH_SIGN`, respectively. A number with a trailing sign or
+//! an overpunched digit cannot also have a leading sign.
+//!
+//! Overpunched digits are only used for integers: since the digit is
+//! appended to the value, floats would need to parse the digits again.
+
+#![cfg(feature = "parse")]
+#![doc(hidden)]
+
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::result::Result;
+use core::ops::Neg;
+
+/// Determine if the format allows signs after the digits.
+#[inline(always)]
+pub const fn is_trailing<const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    format.trailing_sign() || format.overpunch_sign()
+}
+
+/// Determine if the number starts with a sign.
+#[inline(always)]
+pub fn has_leading_sign(bytes: &[u8]) -> bool {
+    matches!(bytes.first(), Some(&b'+') | Some(&b'-'))
+}
+
+/// Sign that follows the digits of a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trailer {
+    /// A `+` or `-` sign, and if the number is negative.
+    Sign(bool),
+    /// An overpunched digit, with the digit and if the number is negative.
+    Overpunch(u8, bool),
+}
+
+impl Trailer {
+    /// Parse a trailing sign or overpunched digit, if the format allows it.
+    #[inline]
+    pub fn parse<const FORMAT: u128>(byte: Option<&u8>) -> Option<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let byte = *byte?;
+        match byte {
+            b'-' if format.trailing_sign() => Some(Self::Sign(true)),
+            b'+' if format.trailing_sign() && !format.no_positive_mantissa_sign() => {
+                Some(Self::Sign(false))
+            },
+            _ if format.overpunch_sign() => overpunch(byte),
+            _ => None,
+        }
+    }
+}
+
+/// Decode an overpunched digit, as the digit and if the number is negative.
+///
+/// `{` and `A` through `I` are positive, and `}` and `J` through `R`
+/// are negative.
+#[inline]
+pub const fn overpunch(byte: u8) -> Option<Trailer> {
+    match byte {
+        b'{' => Some(Trailer::Overpunch(0, false)),
+        b'A'..=b'I' => Some(Trailer::Overpunch(byte - b'A' + 1, false)),
+        b'}' => Some(Trailer::Overpunch(0, true)),
+        b'J'..=b'R' => Some(Trailer::Overpunch(byte - b'J' + 1, true)),
+        _ => None,
+    }
+}
+
+/// Parse a number that may have a trailing sign, using a complete parser.
+///
+/// The `parse` callback is called with the digits before the sign, and
+/// the value is negated if the sign is `-`. Overpunched digits are ignored.
+#[inline]
+pub fn parse_complete<T, Parse, const FORMAT: u128>(bytes: &[u8], parse: Parse) -> Result<T>
+where
+    T: Neg<Output = T>,
+    Parse: FnOnce(&[u8]) -> Result<T>,
+{
+    if !NumberFormat::<{ FORMAT }>::TRAILING_SIGN {
+        return parse(bytes);
+    }
+    let is_negative = match Trailer::parse::<FORMAT>(bytes.last()) {
+        Some(Trailer::Sign(is_negative)) => is_negative,
+        _ => return parse(bytes),
+    };
+    let index = bytes.len() - 1;
+    let digits = &bytes[..index];
+    if has_leading_sign(digits) {
+        return Err(Error::InvalidDigit(index));
+    }
+    let value = parse(digits)?;
+    Ok(if is_negative {
+        -value
+    } else {
+        value
+    })
+}
+
+/// Parse a number that may have a trailing sign, using a partial parser.
+///
+/// The `parse` callback is called with the bytes, and the value is
+/// negated if it is followed by a `-` sign. The returned count includes
+/// the sign. Overpunched digits are ignored.
+#[inline]
+pub fn parse_partial<T, Parse, const FORMAT: u128>(bytes: &[u8], parse: Parse) -> Result<(T, usize)>
+where
+    T: Neg<Output = T>,
+    Parse: FnOnce(&[u8]) -> Result<(T, usize)>,
+{
+    let (value, count) = parse(bytes)?;
+    if !NumberFormat::<{ FORMAT }>::TRAILING_SIGN {
+        return Ok((value, count));
+    }
+    match Trailer::parse::<FORMAT>(bytes.get(count)) {
+        Some(Trailer::Sign(_)) if has_leading_sign(bytes) => Err(Error::InvalidDigit(count)),
+        Some(Trailer::Sign(true)) => Ok((-value, count + 1)),
+        Some(Trailer::Sign(false)) => Ok((value, count + 1)),
+        _ => Ok((value, count)),
+    }
+}
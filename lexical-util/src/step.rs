@@ -12,6 +12,8 @@
 
 #![cfg(any(feature = "parse", feature = "write"))]
 
+use crate::num::Integer;
+
 // NOTE:
 //  Fallback radixes use 1 for the value to avoid infinite loops,
 //  but allowing them in `const fn`.
@@ -58,6 +60,32 @@ pub const fn min_step(radix: u32, bits: usize, is_signed: bool) -> usize {
             34 => min_step_34(bits, is_signed),
             35 => min_step_35(bits, is_signed),
             36 => min_step_36(bits, is_signed),
+            37 => min_step_37(bits, is_signed),
+            38 => min_step_38(bits, is_signed),
+            39 => min_step_39(bits, is_signed),
+            40 => min_step_40(bits, is_signed),
+            41 => min_step_41(bits, is_signed),
+            42 => min_step_42(bits, is_signed),
+            43 => min_step_43(bits, is_signed),
+            44 => min_step_44(bits, is_signed),
+            45 => min_step_45(bits, is_signed),
+            46 => min_step_46(bits, is_signed),
+            47 => min_step_47(bits, is_signed),
+            48 => min_step_48(bits, is_signed),
+            49 => min_step_49(bits, is_signed),
+            50 => min_step_50(bits, is_signed),
+            51 => min_step_51(bits, is_signed),
+            52 => min_step_52(bits, is_signed),
+            53 => min_step_53(bits, is_signed),
+            54 => min_step_54(bits, is_signed),
+            55 => min_step_55(bits, is_signed),
+            56 => min_step_56(bits, is_signed),
+            57 => min_step_57(bits, is_signed),
+            58 => min_step_58(bits, is_signed),
+            59 => min_step_59(bits, is_signed),
+            60 => min_step_60(bits, is_signed),
+            61 => min_step_61(bits, is_signed),
+            62 => min_step_62(bits, is_signed),
             _ => 1,
         }
     } else if cfg!(feature = "power-of-two") {
@@ -118,6 +146,32 @@ pub const fn max_step(radix: u32, bits: usize, is_signed: bool) -> usize {
             34 => max_step_34(bits, is_signed),
             35 => max_step_35(bits, is_signed),
             36 => max_step_36(bits, is_signed),
+            37 => max_step_37(bits, is_signed),
+            38 => max_step_38(bits, is_signed),
+            39 => max_step_39(bits, is_signed),
+            40 => max_step_40(bits, is_signed),
+            41 => max_step_41(bits, is_signed),
+            42 => max_step_42(bits, is_signed),
+            43 => max_step_43(bits, is_signed),
+            44 => max_step_44(bits, is_signed),
+            45 => max_step_45(bits, is_signed),
+            46 => max_step_46(bits, is_signed),
+            47 => max_step_47(bits, is_signed),
+            48 => max_step_48(bits, is_signed),
+            49 => max_step_49(bits, is_signed),
+            50 => max_step_50(bits, is_signed),
+            51 => max_step_51(bits, is_signed),
+            52 => max_step_52(bits, is_signed),
+            53 => max_step_53(bits, is_signed),
+            54 => max_step_54(bits, is_signed),
+            55 => max_step_55(bits, is_signed),
+            56 => max_step_56(bits, is_signed),
+            57 => max_step_57(bits, is_signed),
+            58 => max_step_58(bits, is_signed),
+            59 => max_step_59(bits, is_signed),
+            60 => max_step_60(bits, is_signed),
+            61 => max_step_61(bits, is_signed),
+            62 => max_step_62(bits, is_signed),
             _ => 1,
         }
     } else if cfg!(feature = "power-of-two") {
@@ -142,6 +196,29 @@ pub const fn u64_step(radix: u32) -> usize {
     min_step(radix, 64, false)
 }
 
+/// Pre-computed digit counts for an integer type in a given radix.
+///
+/// These are evaluated at compile time, so they can be used to cheaply
+/// reject inputs by length before parsing any digits. The counts exclude
+/// any sign, base prefix or suffix, leading zeros, or digit separators.
+/// For example, `<u64 as DigitCount<10>>::MAX_DIGITS` is 20.
+///
+/// The radix must be valid for the enabled features, otherwise, the
+/// constants are meaningless.
+pub trait DigitCount<const RADIX: u32>: Integer {
+    /// Maximum number of digits that can be processed without always
+    /// overflowing. Any input with more significant digits overflows.
+    const MAX_DIGITS: usize = max_step(RADIX, Self::BITS, Self::IS_SIGNED);
+
+    /// Maximum number of digits that can always be processed without
+    /// overflowing. Any input with this many significant digits or
+    /// less never overflows.
+    const MIN_SAFE_DIGITS: usize = min_step(RADIX, Self::BITS, Self::IS_SIGNED);
+}
+
+impl<T: Integer, const RADIX: u32> DigitCount<RADIX> for T {
+}
+
 // AUTO-GENERATED
 // These functions were auto-generated by `etc/step.py`.
 // Do not edit them unless there is a good reason to.
@@ -1340,3 +1417,887 @@ const fn min_step_36(bits: usize, is_signed: bool) -> usize {
         _ => 1,
     }
 }
+
+#[inline]
+const fn max_step_37(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 4,
+        32 if is_signed => 6,
+        32 if !is_signed => 7,
+        64 if is_signed => 13,
+        64 if !is_signed => 13,
+        128 if is_signed => 25,
+        128 if !is_signed => 25,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_37(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 3,
+        32 if is_signed => 5,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_38(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 4,
+        32 if is_signed => 6,
+        32 if !is_signed => 7,
+        64 if is_signed => 13,
+        64 if !is_signed => 13,
+        128 if is_signed => 25,
+        128 if !is_signed => 25,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_38(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 3,
+        32 if is_signed => 5,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_39(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 4,
+        32 if is_signed => 6,
+        32 if !is_signed => 7,
+        64 if is_signed => 12,
+        64 if !is_signed => 13,
+        128 if is_signed => 25,
+        128 if !is_signed => 25,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_39(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 3,
+        32 if is_signed => 5,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_40(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 4,
+        32 if is_signed => 6,
+        32 if !is_signed => 7,
+        64 if is_signed => 12,
+        64 if !is_signed => 13,
+        128 if is_signed => 24,
+        128 if !is_signed => 25,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_40(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 3,
+        32 if is_signed => 5,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_41(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_41(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_42(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_42(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_43(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_43(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_44(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_44(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_45(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 24,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_45(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_46(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_46(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_47(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 24,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_47(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_48(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_48(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_49(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_49(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_50(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_50(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_51(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_51(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_52(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 12,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_52(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_53(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_53(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_54(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 23,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_54(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_55(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 22,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_55(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 11,
+        128 if is_signed => 21,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_56(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 12,
+        128 if is_signed => 22,
+        128 if !is_signed => 23,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_56(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 11,
+        128 if is_signed => 21,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_57(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_57(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_58(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_58(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_59(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_59(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_60(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_60(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_61(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_61(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn max_step_62(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 2,
+        8 if !is_signed => 2,
+        16 if is_signed => 3,
+        16 if !is_signed => 3,
+        32 if is_signed => 6,
+        32 if !is_signed => 6,
+        64 if is_signed => 11,
+        64 if !is_signed => 11,
+        128 if is_signed => 22,
+        128 if !is_signed => 22,
+        _ => 1,
+    }
+}
+
+#[inline]
+const fn min_step_62(bits: usize, is_signed: bool) -> usize {
+    match bits {
+        8 if is_signed => 1,
+        8 if !is_signed => 1,
+        16 if is_signed => 2,
+        16 if !is_signed => 2,
+        32 if is_signed => 5,
+        32 if !is_signed => 5,
+        64 if is_signed => 10,
+        64 if !is_signed => 10,
+        128 if is_signed => 21,
+        128 if !is_signed => 21,
+        _ => 1,
+    }
+}
@@ -0,0 +1,127 @@
+//! Normalize exotic minus signs and spaces copy-pasted from documents.
+//!
+//! Word processors and spreadsheets often replace the ASCII hyphen with
+//! `U+2212 MINUS SIGN`, and group digits with non-breaking spaces, as in
+//! `−1 234,5`. These are normalized to ASCII before parsing:
+//!
+//! - `U+2212 MINUS SIGN` is replaced with `-`.
+//! - `U+00A0 NO-BREAK SPACE` and `U+202F NARROW NO-BREAK SPACE` are
+//!   replaced with the digit separator of the format. If the format
+//!   has no digit separator, they are removed if they are between two
+//!   digits, otherwise, they are kept and are an invalid digit.
+//!
+//! A lone `0xA0` byte, which is invalid UTF-8, is treated as a
+//! Windows-1252 non-breaking space. Any other bytes are not modified.
+
+#![cfg(all(feature = "parse", feature = "unicode"))]
+#![doc(hidden)]
+
+use crate::digit::char_is_digit_const;
+use crate::error::Error;
+use crate::format::NumberFormat;
+
+/// UTF-8 encoding of `U+2212 MINUS SIGN`.
+const MINUS_SIGN: &[u8] = "\u{2212}".as_bytes();
+
+/// UTF-8 encoding of `U+00A0 NO-BREAK SPACE`.
+const NO_BREAK_SPACE: &[u8] = "\u{a0}".as_bytes();
+
+/// UTF-8 encoding of `U+202F NARROW NO-BREAK SPACE`.
+const NARROW_NO_BREAK_SPACE: &[u8] = "\u{202f}".as_bytes();
+
+/// Windows-1252 encoding of a non-breaking space.
+const WINDOWS_1252_NO_BREAK_SPACE: &[u8] = b"\xa0";
+
+/// Normalized bytes, with the index of each byte in the original bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Normalized {
+    /// The normalized, ASCII-compatible bytes.
+    bytes: Vec<u8>,
+    /// The original index of each normalized byte, and the original length.
+    offsets: Vec<usize>,
+}
+
+impl Normalized {
+    /// Normalize the bytes, returning `None` if no bytes must be changed.
+    pub fn new<const FORMAT: u128>(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_ascii() {
+            return None;
+        }
+
+        let format = NumberFormat::<{ FORMAT }> {};
+        let radix = format.mantissa_radix();
+        let separator = format.digit_separator();
+        let mut normalized = Self {
+            bytes: Vec::with_capacity(bytes.len()),
+            offsets: Vec::with_capacity(bytes.len() + 1),
+        };
+        let mut index = 0;
+        while index < bytes.len() {
+            let rest = &bytes[index..];
+            if rest.starts_with(MINUS_SIGN) {
+                normalized.push(b'-', index);
+                index += MINUS_SIGN.len();
+                continue;
+            }
+
+            let space = [NO_BREAK_SPACE, NARROW_NO_BREAK_SPACE, WINDOWS_1252_NO_BREAK_SPACE]
+                .iter()
+                .find(|space| rest.starts_with(space))
+                .map(|space| space.len());
+            match space {
+                Some(length) if separator != 0 => {
+                    normalized.push(separator, index);
+                    index += length;
+                },
+                Some(length) => {
+                    let is_digit =
+                        |c: Option<&u8>| matches!(c, Some(&c) if char_is_digit_const(c, radix));
+                    if is_digit(normalized.bytes.last()) && is_digit(bytes.get(index + length)) {
+                        index += length;
+                    } else {
+                        for _ in 0..length {
+                            normalized.push(bytes[index], index);
+                            index += 1;
+                        }
+                    }
+                },
+                None => {
+                    normalized.push(bytes[index], index);
+                    index += 1;
+                },
+            }
+        }
+        normalized.offsets.push(bytes.len());
+
+        Some(normalized)
+    }
+
+    /// Add a normalized byte from the original index.
+    #[inline(always)]
+    fn push(&mut self, byte: u8, index: usize) {
+        self.bytes.push(byte);
+        self.offsets.push(index);
+    }
+
+    /// Get the normalized bytes.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get the index in the original bytes from an index in the normalized bytes.
+    #[inline]
+    pub fn original_index(&self, index: usize) -> usize {
+        let last = self.offsets.len() - 1;
+        self.offsets[index.min(last)]
+    }
+
+    /// Convert the index of an error to the index in the original bytes.
+    #[inline]
+    pub fn map_error(&self, error: Error) -> Error {
+        match error.index() {
+            Some(&index) => Error::from_code(error.error_code(), self.original_index(index)),
+            None => error,
+        }
+    }
+}
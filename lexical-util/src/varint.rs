@@ -0,0 +1,266 @@
+//! Canonical, variable-length binary encoding of floats.
+//!
+//! Text is the interchange format for numbers, but parsing the same
+//! strings again in every stage of a pipeline is wasteful, and caching
+//! the native bytes of a float depends on the endianness of the target
+//! and the type of the float. This encodes the exact value of a float
+//! as bytes, which are independent of the target and of the float type,
+//! and can be decoded without any rounding.
+//!
+//! The encoding is a header byte, followed by two varints for finite,
+//! non-zero floats: the binary exponent, zigzag-encoded, and the odd
+//! mantissa, so the value is `mantissa * 2^exponent`. Varints are
+//! little-endian base 128, or LEB128, with the high bit of each byte
+//! set if more bytes follow. The header is:
+//!
+//! - `0x00` and `0x01`: positive and negative zero.
+//! - `0x02` and `0x03`: positive and negative finite numbers.
+//! - `0x04` and `0x05`: positive and negative infinity.
+//! - `0x06`: `NaN`. The sign and the payload are not kept.
+//!
+//! Every value has exactly one encoding, so encoded floats can be
+//! compared and hashed as bytes, and the same value encodes the same
+//! way for every float type, such as `1.5` as an `f32` or an `f64`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_util::varint;
+//!
+//! # pub fn main() {
+//! let mut buffer = [0u8; varint::VARINT_BUFFER_SIZE];
+//! let count = varint::encode_float(-1.5f64, &mut buffer);
+//! assert_eq!(&buffer[..count], &[0x03, 0x01, 0x03]);
+//! assert_eq!(varint::decode_float::<f32>(&buffer[..count]), Ok((-1.5, 3)));
+//! # }
+//! ```
+
+#![cfg(feature = "varint")]
+
+use core::convert::TryFrom;
+
+use crate::error::Error;
+use crate::num::{AsCast, AsPrimitive, Float};
+use crate::result::Result;
+
+/// Number of bytes needed to encode any float.
+///
+/// This is the header, the exponent of up to 5 bytes, and the mantissa
+/// of up to 10 bytes.
+pub const VARINT_BUFFER_SIZE: usize = 16;
+
+/// Header for positive zero, or `0x01` for negative zero.
+pub const ZERO: u8 = 0x00;
+
+/// Header for positive finite numbers, or `0x03` for negative.
+pub const FINITE: u8 = 0x02;
+
+/// Header for positive infinity, or `0x05` for negative infinity.
+pub const INFINITY: u8 = 0x04;
+
+/// Header for `NaN`.
+pub const NAN: u8 = 0x06;
+
+/// Encode a float in the canonical binary encoding.
+///
+/// Returns the number of bytes written. The float is encoded exactly,
+/// other than the sign and the payload of `NaN`.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than the encoded float. A buffer
+/// of [`VARINT_BUFFER_SIZE`] bytes is always large enough. Use
+/// [`try_encode_float`] to handle smaller buffers.
+#[inline]
+pub fn encode_float<F: Float>(float: F, bytes: &mut [u8]) -> usize {
+    match try_encode_float(float, bytes) {
+        Ok(count) => count,
+        Err(_) => panic!("Buffer is too small: may overwrite buffer, panicking!"),
+    }
+}
+
+/// Encode a float in the canonical binary encoding, if it fits in the buffer.
+///
+/// Returns the number of bytes written. If the buffer is too small,
+/// returns [`Error::BufferTooSmall`] with the required length, without
+/// writing to the buffer.
+#[inline]
+pub fn try_encode_float<F: Float>(float: F, bytes: &mut [u8]) -> Result<usize> {
+    let mut buffer = [0u8; VARINT_BUFFER_SIZE];
+    let count = encode(float, &mut buffer);
+    match bytes.get_mut(..count) {
+        Some(bytes) => {
+            bytes.copy_from_slice(&buffer[..count]);
+            Ok(count)
+        },
+        None => Err(Error::BufferTooSmall(count)),
+    }
+}
+
+/// Encode a float to a buffer that is always large enough.
+#[inline]
+fn encode<F: Float>(float: F, bytes: &mut [u8; VARINT_BUFFER_SIZE]) -> usize {
+    let sign = float.is_sign_negative() as u8;
+    if float.is_nan() {
+        bytes[0] = NAN;
+        return 1;
+    } else if float.is_inf() {
+        bytes[0] = INFINITY | sign;
+        return 1;
+    } else if float == F::ZERO {
+        bytes[0] = ZERO | sign;
+        return 1;
+    }
+
+    // Remove the trailing zeros, so the mantissa is odd, and the
+    // encoding is the same for every float type.
+    let mantissa = float.mantissa().as_u64();
+    let shift = mantissa.trailing_zeros();
+    let exponent = float.exponent() + shift as i32;
+    bytes[0] = FINITE | sign;
+    let mut count = 1;
+    count += write_varint(zigzag(exponent) as u64, &mut bytes[count..]);
+    count += write_varint(mantissa >> shift, &mut bytes[count..]);
+    count
+}
+
+/// Decode a float from the canonical binary encoding.
+///
+/// Returns the float and the number of bytes read, so encoded floats
+/// can be concatenated.
+///
+/// # Errors
+///
+/// Returns [`Error::Empty`] if the bytes are empty or end within the
+/// float, [`Error::InvalidDigit`], with the index of the byte, if the
+/// header or a varint is invalid or not canonical, such as for an even
+/// mantissa, and [`Error::Overflow`] or [`Error::Underflow`] if the value
+/// is too large, or too small or too precise, for the float type.
+#[inline]
+pub fn decode_float<F: Float>(bytes: &[u8]) -> Result<(F, usize)> {
+    let header = match bytes.first() {
+        Some(&header) => header,
+        None => return Err(Error::Empty(0)),
+    };
+    let is_negative = header & 1 == 1;
+    let float = match header & !1 {
+        ZERO => F::ZERO,
+        INFINITY => F::INFINITY,
+        NAN if !is_negative => F::NAN,
+        FINITE => {
+            let (exponent, count) = read_varint(bytes, 1)?;
+            let (mantissa, count) = read_varint(bytes, count)?;
+            if mantissa & 1 == 0 {
+                return Err(Error::InvalidDigit(count - 1));
+            }
+            let exponent = match u32::try_from(exponent) {
+                Ok(exponent) => unzigzag(exponent) as i64,
+                Err(_) => return Err(Error::InvalidDigit(1)),
+            };
+            let float = from_parts::<F>(mantissa, exponent, count)?;
+            return Ok((
+                if is_negative {
+                    -float
+                } else {
+                    float
+                },
+                count,
+            ));
+        },
+        _ => return Err(Error::InvalidDigit(0)),
+    };
+    Ok((
+        if is_negative {
+            -float
+        } else {
+            float
+        },
+        1,
+    ))
+}
+
+/// Create a float from an odd mantissa and a binary exponent.
+///
+/// `count` is the index of the end of the encoded float, for errors.
+#[inline]
+fn from_parts<F: Float>(mantissa: u64, exponent: i64, count: usize) -> Result<F> {
+    // Shift the mantissa so the hidden bit is set, which is always
+    // possible for an exact value, since the mantissa is odd.
+    let bits = 64 - mantissa.leading_zeros() as i32;
+    let shift = F::MANTISSA_SIZE + 1 - bits;
+    let exponent = exponent - shift as i64;
+    if exponent >= F::MAX_EXPONENT as i64 {
+        return Err(Error::Overflow(count));
+    } else if shift < 0 {
+        return Err(Error::Underflow(count));
+    }
+    let mantissa = mantissa << shift;
+
+    let bits = if exponent >= F::DENORMAL_EXPONENT as i64 {
+        let biased = (exponent + F::EXPONENT_BIAS as i64) as u64;
+        (biased << F::MANTISSA_SIZE) | (mantissa & F::MANTISSA_MASK.as_u64())
+    } else {
+        // Denormal floats can only have the bits above the denormal exponent.
+        let shift = F::DENORMAL_EXPONENT as i64 - exponent;
+        if shift > F::MANTISSA_SIZE as i64 || mantissa.trailing_zeros() < shift as u32 {
+            return Err(Error::Underflow(count));
+        }
+        mantissa >> shift
+    };
+    Ok(F::from_bits(F::Unsigned::as_cast(bits)))
+}
+
+/// Zigzag-encode a signed integer, so small magnitudes are small.
+#[inline(always)]
+const fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Decode a zigzag-encoded integer.
+#[inline(always)]
+const fn unzigzag(value: u32) -> i32 {
+    (value >> 1) as i32 ^ -((value & 1) as i32)
+}
+
+/// Write an unsigned LEB128 varint, returning the number of bytes written.
+#[inline]
+fn write_varint(mut value: u64, bytes: &mut [u8]) -> usize {
+    let mut count = 0;
+    while value >= 0x80 {
+        bytes[count] = (value as u8) | 0x80;
+        value >>= 7;
+        count += 1;
+    }
+    bytes[count] = value as u8;
+    count + 1
+}
+
+/// Read an unsigned LEB128 varint at the index.
+///
+/// Returns the value and the index after the varint. Varints with
+/// trailing zero bytes or values above `u64::MAX` are invalid, so every
+/// value has a single encoding.
+#[inline]
+fn read_varint(bytes: &[u8], start: usize) -> Result<(u64, usize)> {
+    let mut value = 0u64;
+    let mut index = start;
+    loop {
+        let byte = match bytes.get(index) {
+            Some(&byte) => byte,
+            None => return Err(Error::Empty(index)),
+        };
+        let shift = 7 * (index - start) as u32;
+        let digit = (byte & 0x7F) as u64;
+        if shift >= 64 || (shift == 63 && digit > 1) {
+            return Err(Error::InvalidDigit(index));
+        }
+        value |= digit << shift;
+        index += 1;
+        if byte & 0x80 == 0 {
+            if byte == 0 && index - start > 1 {
+                return Err(Error::InvalidDigit(index - 1));
+            }
+            return Ok((value, index));
+        }
+    }
+}
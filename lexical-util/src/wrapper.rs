@@ -0,0 +1,138 @@
+//! Strip quotes and accounting parentheses wrapping a number.
+//!
+//! Numbers exported from spreadsheets and financial software are often
+//! quoted, as in `"123.45"`, and negative values may be written in
+//! parentheses rather than with a sign, as in `(123.45)`. These are
+//! only recognized if the format enables `OPTIONAL_QUOTES` or
+//! `NEGATIVE_PARENTHESES`, respectively, and quotes always wrap the
+//! parentheses, as in `"(123.45)"`.
+
+#![cfg(feature = "parse")]
+#![doc(hidden)]
+
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::result::Result;
+
+/// Determine if the format allows numbers to be wrapped.
+#[inline(always)]
+pub const fn is_wrapped<const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    format.optional_quotes() || format.negative_parentheses()
+}
+
+/// Shift the index of an error by the number of leading wrapper bytes.
+#[inline]
+pub fn shift_error(error: Error, shift: usize) -> Error {
+    match error.index() {
+        Some(&index) => Error::from_code(error.error_code(), index + shift),
+        None => error,
+    }
+}
+
+/// Opening wrapper characters parsed from the start of a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Openers {
+    /// If the number is wrapped in quotes.
+    quoted: bool,
+    /// If the number is wrapped in parentheses, and therefore negative.
+    negative: bool,
+}
+
+impl Openers {
+    /// Parse the opening characters, returning an error for a sign after `(`.
+    #[inline]
+    fn parse<const FORMAT: u128>(bytes: &[u8]) -> Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let quoted = format.optional_quotes() && bytes.first() == Some(&b'"');
+        let index = quoted as usize;
+        let negative = format.negative_parentheses() && bytes.get(index) == Some(&b'(');
+        let openers = Self {
+            quoted,
+            negative,
+        };
+        match bytes.get(openers.len()) {
+            Some(&b'+') | Some(&b'-') if negative => Err(Error::InvalidDigit(openers.len())),
+            _ => Ok(openers),
+        }
+    }
+
+    /// Get the number of bytes in the opening or closing characters.
+    #[inline(always)]
+    const fn len(&self) -> usize {
+        self.quoted as usize + self.negative as usize
+    }
+
+    /// Check the closing characters starting at `index`.
+    ///
+    /// Returns an error at the first missing closing character.
+    #[inline]
+    fn check_closers(&self, bytes: &[u8], index: usize) -> Result<()> {
+        let closers: &[u8] = match (self.quoted, self.negative) {
+            (true, true) => b")\"",
+            (true, false) => b"\"",
+            (false, true) => b")",
+            (false, false) => b"",
+        };
+        for (offset, &closer) in closers.iter().enumerate() {
+            match bytes.get(index + offset) {
+                Some(&byte) if byte == closer => (),
+                Some(_) => return Err(Error::InvalidDigit(index + offset)),
+                None => return Err(Error::Empty(index + offset)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a number that may be wrapped, using a complete parser.
+///
+/// The `parse` callback is called with the unwrapped bytes, and if
+/// the number was wrapped in parentheses. The value must be negated
+/// by the callback, which must reject any signs.
+#[inline]
+pub fn parse_complete<T, Parse, const FORMAT: u128>(bytes: &[u8], parse: Parse) -> Result<T>
+where
+    Parse: FnOnce(&[u8], bool) -> Result<T>,
+{
+    let openers = Openers::parse::<FORMAT>(bytes)?;
+    let start = openers.len();
+    if start == 0 {
+        return parse(bytes, false);
+    }
+
+    let end = bytes.len().saturating_sub(start).max(start);
+    match openers.check_closers(bytes, end) {
+        Ok(()) => parse(&bytes[start..end], openers.negative).map_err(|e| shift_error(e, start)),
+        Err(_) => {
+            // Missing closing characters: report any error within the
+            // number first, otherwise, we expected the closers at the end.
+            parse(&bytes[start..], openers.negative).map_err(|e| shift_error(e, start))?;
+            Err(Error::Empty(bytes.len()))
+        },
+    }
+}
+
+/// Parse a number that may be wrapped, using a partial parser.
+///
+/// The `parse` callback is called with the unwrapped bytes, and if
+/// the number was wrapped in parentheses. The value must be negated
+/// by the callback, which must reject any signs. The returned count
+/// includes the closing characters.
+#[inline]
+pub fn parse_partial<T, Parse, const FORMAT: u128>(bytes: &[u8], parse: Parse) -> Result<(T, usize)>
+where
+    Parse: FnOnce(&[u8], bool) -> Result<(T, usize)>,
+{
+    let openers = Openers::parse::<FORMAT>(bytes)?;
+    let start = openers.len();
+    if start == 0 {
+        return parse(bytes, false);
+    }
+
+    let (value, count) =
+        parse(&bytes[start..], openers.negative).map_err(|e| shift_error(e, start))?;
+    let end = start + count;
+    openers.check_closers(bytes, end)?;
+    Ok((value, end + openers.len()))
+}
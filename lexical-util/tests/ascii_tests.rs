@@ -49,3 +49,12 @@ fn is_valid_letter_slice_test() {
     assert_eq!(ascii::is_valid_letter_slice(b" 09a"), false);
     assert_eq!(ascii::is_valid_letter_slice(b"aZAz"), true);
 }
+
+#[test]
+fn trim_skipped_test() {
+    let is_padding = |c: u8| c == b'*' || c == b' ';
+    assert_eq!(ascii::trim_skipped(b"** -15 *", is_padding), (3, &b"-15"[..]));
+    assert_eq!(ascii::trim_skipped(b"1 2", is_padding), (0, &b"1 2"[..]));
+    assert_eq!(ascii::trim_skipped(b"** *", is_padding), (4, &b""[..]));
+    assert_eq!(ascii::trim_skipped(b"", is_padding), (0, &b""[..]));
+}
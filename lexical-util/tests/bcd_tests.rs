@@ -0,0 +1,91 @@
+#![cfg(feature = "bcd")]
+
+use lexical_util::bcd;
+use lexical_util::error::Error;
+
+fn packed_to_string(packed: &[u8], scale: usize) -> Result<String, Error> {
+    let mut buffer = vec![0u8; bcd::text_size(packed.len(), scale)];
+    let count = bcd::from_packed(packed, scale, &mut buffer)?;
+    Ok(String::from_utf8(buffer[..count].to_vec()).unwrap())
+}
+
+fn bcd_to_string(bytes: &[u8], scale: usize) -> Result<String, Error> {
+    let mut buffer = vec![0u8; bcd::text_size(bytes.len(), scale)];
+    let count = bcd::from_bcd(bytes, scale, &mut buffer)?;
+    Ok(String::from_utf8(buffer[..count].to_vec()).unwrap())
+}
+
+#[test]
+fn to_packed_test() {
+    let mut packed = [0xFFu8; 3];
+    assert_eq!(bcd::to_packed(b"12345", 0, &mut packed), Ok(()));
+    assert_eq!(packed, [0x12, 0x34, 0x5C]);
+    assert_eq!(bcd::to_packed(b"-1.5", 2, &mut packed), Ok(()));
+    assert_eq!(packed, [0x00, 0x15, 0x0D]);
+    assert_eq!(bcd::to_packed(b"+.05", 2, &mut packed), Ok(()));
+    assert_eq!(packed, [0x00, 0x00, 0x5C]);
+    assert_eq!(bcd::to_packed(b"7.", 0, &mut packed), Ok(()));
+    assert_eq!(packed, [0x00, 0x00, 0x7C]);
+    assert_eq!(bcd::to_packed(b"0001.2300", 2, &mut packed), Ok(()));
+    assert_eq!(packed, [0x00, 0x12, 0x3C]);
+    assert_eq!(bcd::to_packed(b"-0", 1, &mut packed), Ok(()));
+    assert_eq!(packed, [0x00, 0x00, 0x0D]);
+
+    assert_eq!(bcd::to_packed(b"123456", 0, &mut packed), Err(Error::Overflow(0)));
+    assert_eq!(bcd::to_packed(b"-00123.4", 3, &mut packed), Err(Error::Overflow(3)));
+    assert_eq!(bcd::to_packed(b"1.234", 2, &mut packed), Err(Error::InvalidDigit(4)));
+    assert_eq!(bcd::to_packed(b"", 0, &mut packed), Err(Error::Empty(0)));
+    assert_eq!(bcd::to_packed(b"-.", 0, &mut packed), Err(Error::Empty(2)));
+    assert_eq!(bcd::to_packed(b"1e5", 0, &mut packed), Err(Error::InvalidDigit(1)));
+    assert_eq!(bcd::to_packed(b"1.2.3", 0, &mut packed), Err(Error::InvalidDigit(3)));
+    assert_eq!(bcd::to_packed(b"1", 0, &mut []), Err(Error::Overflow(0)));
+    assert_eq!(bcd::to_packed(b"0", 0, &mut []), Err(Error::Overflow(0)));
+    assert_eq!(bcd::to_bcd(b"0", 0, &mut []), Ok(()));
+}
+
+#[test]
+fn from_packed_test() {
+    assert_eq!(packed_to_string(&[0x12, 0x34, 0x5C], 0), Ok("12345".into()));
+    assert_eq!(packed_to_string(&[0x12, 0x34, 0x5D], 2), Ok("-123.45".into()));
+    assert_eq!(packed_to_string(&[0x00, 0x00, 0x5F], 2), Ok("0.05".into()));
+    assert_eq!(packed_to_string(&[0x00, 0x00, 0x0B], 0), Ok("-0".into()));
+    assert_eq!(packed_to_string(&[0x5A], 3), Ok("0.005".into()));
+    assert_eq!(packed_to_string(&[0x1E], 0), Ok("1".into()));
+
+    assert_eq!(packed_to_string(&[], 0), Err(Error::Empty(0)));
+    assert_eq!(packed_to_string(&[0x1A, 0x5C], 0), Err(Error::InvalidDigit(0)));
+    assert_eq!(packed_to_string(&[0x12, 0xF5], 0), Err(Error::InvalidDigit(1)));
+    assert_eq!(packed_to_string(&[0x12, 0x59], 0), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+fn bcd_test() {
+    let mut bytes = [0xFFu8; 2];
+    assert_eq!(bcd::to_bcd(b"12.34", 2, &mut bytes), Ok(()));
+    assert_eq!(bytes, [0x12, 0x34]);
+    assert_eq!(bcd::to_bcd(b"+5", 0, &mut bytes), Ok(()));
+    assert_eq!(bytes, [0x00, 0x05]);
+    assert_eq!(bcd::to_bcd(b"-5", 0, &mut bytes), Err(Error::InvalidNegativeSign(0)));
+    assert_eq!(bcd::to_bcd(b"12345", 0, &mut bytes), Err(Error::Overflow(0)));
+
+    assert_eq!(bcd_to_string(&[0x12, 0x34], 2), Ok("12.34".into()));
+    assert_eq!(bcd_to_string(&[0x00, 0x05], 0), Ok("5".into()));
+    assert_eq!(bcd_to_string(&[0x00, 0x00], 1), Ok("0.0".into()));
+    assert_eq!(bcd_to_string(&[0x0C], 0), Err(Error::InvalidDigit(0)));
+}
+
+#[test]
+fn roundtrip_test() {
+    for &(text, scale, len) in &[
+        ("-9999999.99", 2, 5),
+        ("0.000001", 6, 1),
+        ("123", 0, 2),
+        ("-0.5", 4, 3),
+        ("0.000000000009", 12, 1),
+    ] {
+        let mut packed = vec![0u8; len];
+        bcd::to_packed(text.as_bytes(), scale, &mut packed).unwrap();
+        let expected = format!("{:.*}", scale, text.parse::<f64>().unwrap());
+        assert_eq!(packed_to_string(&packed, scale), Ok(expected));
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::boolean::{self, BoolOptions};
+use lexical_util::error::Error;
+
+#[test]
+fn options_test() {
+    let builder = BoolOptions::builder();
+    assert!(builder.is_valid());
+    assert_eq!(builder.build(), Ok(BoolOptions::new()));
+
+    let builder = BoolOptions::builder().true_string(b"");
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidBoolString));
+
+    let builder = BoolOptions::builder().true_string(b"false");
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidBoolString));
+
+    let builder = BoolOptions::builder().false_string(&[b'f'; 51]);
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::BoolStringTooLong));
+
+    let options = boolean::YES_NO.rebuild().case_sensitive(true).build().unwrap();
+    assert_eq!(options.true_string(), b"yes");
+    assert_eq!(options.false_string(), b"no");
+    assert!(options.case_sensitive());
+}
+
+#[test]
+fn parse_bool_test() {
+    let options = BoolOptions::new();
+    assert_eq!(boolean::parse_bool(b"true", &options), Ok(true));
+    assert_eq!(boolean::parse_bool(b"false", &options), Ok(false));
+    assert_eq!(boolean::parse_bool(b"", &options), Err(Error::Empty(0)));
+    assert_eq!(boolean::parse_bool(b"True", &options), Err(Error::InvalidDigit(0)));
+    assert_eq!(boolean::parse_bool(b"fals", &options), Err(Error::InvalidDigit(4)));
+    assert_eq!(boolean::parse_bool(b"truex", &options), Err(Error::InvalidDigit(4)));
+    assert_eq!(boolean::parse_bool_partial(b"true,1", &options), Ok((true, 4)));
+    assert_eq!(boolean::parse_bool_partial(b"falsetto", &options), Ok((false, 5)));
+
+    let options = boolean::NUMERIC;
+    assert_eq!(boolean::parse_bool(b"1", &options), Ok(true));
+    assert_eq!(boolean::parse_bool(b"0", &options), Ok(false));
+    assert_eq!(boolean::parse_bool(b"01", &options), Err(Error::InvalidDigit(1)));
+    assert_eq!(boolean::parse_bool(b"2", &options), Err(Error::InvalidDigit(0)));
+
+    let options = boolean::YES_NO;
+    assert_eq!(boolean::parse_bool(b"YES", &options), Ok(true));
+    assert_eq!(boolean::parse_bool(b"No", &options), Ok(false));
+    assert_eq!(boolean::parse_bool(b"yeah", &options), Err(Error::InvalidDigit(2)));
+
+    // The longest matching string is used.
+    let options = BoolOptions::builder().true_string(b"t").false_string(b"tf").build().unwrap();
+    assert_eq!(boolean::parse_bool_partial(b"t", &options), Ok((true, 1)));
+    assert_eq!(boolean::parse_bool_partial(b"tf", &options), Ok((false, 2)));
+    assert_eq!(boolean::parse_bool_partial(b"tt", &options), Ok((true, 1)));
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn write_bool_test() {
+    let mut buffer = [0u8; 8];
+    let options = BoolOptions::new();
+    assert_eq!(boolean::write_bool(true, &mut buffer, &options), b"true");
+    assert_eq!(boolean::write_bool(false, &mut buffer, &options), b"false");
+    assert_eq!(boolean::write_bool(false, &mut buffer, &boolean::NUMERIC), b"0");
+    assert_eq!(boolean::write_bool(true, &mut buffer, &boolean::YES_NO), b"yes");
+    assert_eq!(boolean::try_write_bool(true, &mut buffer[..3], &options), None);
+    let result = boolean::try_write_bool(true, &mut buffer[..4], &options);
+    assert_eq!(result.map(|x| &*x), Some(&b"true"[..]));
+}
@@ -0,0 +1,14 @@
+#![cfg(all(feature = "parse", feature = "std"))]
+
+use lexical_util::c_str::c_str_to_bytes;
+use std::os::raw::c_char;
+use std::ptr;
+
+#[test]
+fn c_str_to_bytes_test() {
+    let string = b"1.5e3\0";
+    assert_eq!(unsafe { c_str_to_bytes(string.as_ptr() as *const c_char) }, b"1.5e3");
+    assert_eq!(unsafe { c_str_to_bytes(b"\0".as_ptr() as *const c_char) }, b"");
+    assert_eq!(unsafe { c_str_to_bytes(b"12\x0034\0".as_ptr() as *const c_char) }, b"12");
+    assert_eq!(unsafe { c_str_to_bytes(ptr::null()) }, b"");
+}
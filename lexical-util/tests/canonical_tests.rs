@@ -0,0 +1,239 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::canonical::{NumericStr, Rounding};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+fn canonical<const FORMAT: u128>(bytes: &[u8], decimal_point: u8) -> Result<String, Error> {
+    let number = NumericStr::parse::<FORMAT>(bytes, b'e', decimal_point)?;
+    let mut buffer = vec![0u8; bytes.len() + 24];
+    let count = number.write(&mut buffer)?;
+    Ok(String::from_utf8(buffer[..count].to_vec()).unwrap())
+}
+
+#[test]
+fn normalize_test() {
+    let normalize = |bytes: &[u8]| canonical::<{ STANDARD }>(bytes, b'.');
+    assert_eq!(normalize(b"1"), Ok("1".into()));
+    assert_eq!(normalize(b"1000"), Ok("1e3".into()));
+    assert_eq!(normalize(b"1e3"), Ok("1e3".into()));
+    assert_eq!(normalize(b"+0001000.000E+00"), Ok("1e3".into()));
+    assert_eq!(normalize(b"12.5"), Ok("1.25e1".into()));
+    assert_eq!(normalize(b"-0012.50e1"), Ok("-1.25e2".into()));
+    assert_eq!(normalize(b"0.025"), Ok("2.5e-2".into()));
+    assert_eq!(normalize(b".5"), Ok("5e-1".into()));
+    assert_eq!(normalize(b"5."), Ok("5".into()));
+    assert_eq!(normalize(b"1.0001"), Ok("1.0001".into()));
+    assert_eq!(normalize(b"100.001e-2"), Ok("1.00001".into()));
+    assert_eq!(normalize(b"0"), Ok("0".into()));
+    assert_eq!(normalize(b"-0.000e10"), Ok("0".into()));
+    assert_eq!(normalize(b"12345678901234567890123"), Ok("1.2345678901234567890123e22".into()));
+    assert_eq!(normalize(b"1e4611686018427387904"), Ok("1e4611686018427387904".into()));
+    assert_eq!(normalize(b"1e-4611686018427387904"), Ok("1e-4611686018427387904".into()));
+}
+
+#[test]
+fn error_test() {
+    let normalize = |bytes: &[u8]| canonical::<{ STANDARD }>(bytes, b'.');
+    assert_eq!(normalize(b""), Err(Error::Empty(0)));
+    assert_eq!(normalize(b"-"), Err(Error::Empty(1)));
+    assert_eq!(normalize(b"."), Err(Error::Empty(1)));
+    assert_eq!(normalize(b"-e5"), Err(Error::EmptyMantissa(1)));
+    assert_eq!(normalize(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(normalize(b"1e+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(normalize(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(normalize(b"NaN"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(normalize(b"1,5"), Err(Error::InvalidDigit(1)));
+    assert_eq!(canonical::<{ STANDARD }>(b"1,5", b','), Ok("1.5".into()));
+    assert_eq!(canonical::<{ STANDARD }>(b"1.5", b'e'), Err(Error::InvalidPunctuation));
+
+    // Exponents larger than 2^62 are rejected, rather than saturated.
+    assert_eq!(normalize(b"1e4611686018427387905"), Err(Error::ExponentOverflow(2)));
+    assert_eq!(normalize(b"1e-99999999999999999999"), Err(Error::ExponentOverflow(3)));
+    assert_eq!(normalize(b"1e9223372036854775807"), Err(Error::ExponentOverflow(2)));
+}
+
+#[test]
+fn saturating_test() {
+    let exponent = |bytes: &[u8]| {
+        NumericStr::parse_saturating::<{ STANDARD }>(bytes, b'e', b'.').map(|x| x.exponent())
+    };
+    assert_eq!(exponent(b"1e99999999999999999999"), Ok(1 << 62));
+    assert_eq!(exponent(b"-1e-99999999999999999999"), Ok(-(1 << 62)));
+    assert_eq!(exponent(b"0.01e-99999999999999999999"), Ok(-(1 << 62) - 2));
+    assert_eq!(exponent(b"1.5e3"), Ok(3));
+    assert_eq!(exponent(b"1e"), Err(Error::EmptyExponent(2)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    const FORMAT: u128 = lexical_util::format::NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build();
+    assert_eq!(canonical::<FORMAT>(b"1_000_000", b'.'), Ok("1e6".into()));
+    assert_eq!(canonical::<FORMAT>(b"0_0.0_5e1_0", b'.'), Ok("5e8".into()));
+
+    const CASE: u128 =
+        lexical_util::format::NumberFormatBuilder::new().case_sensitive_exponent(true).build();
+    assert_eq!(canonical::<CASE>(b"1e1", b'.'), Ok("1e1".into()));
+    assert_eq!(canonical::<CASE>(b"1E1", b'.'), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn required_digits_test() {
+    const INTEGER: u128 =
+        lexical_util::format::NumberFormatBuilder::new().required_integer_digits(true).build();
+    assert_eq!(canonical::<INTEGER>(b"5.", b'.'), Ok("5".into()));
+    assert_eq!(canonical::<INTEGER>(b".5", b'.'), Err(Error::EmptyInteger(0)));
+    assert_eq!(canonical::<INTEGER>(b"-.5", b'.'), Err(Error::EmptyInteger(1)));
+
+    const FRACTION: u128 =
+        lexical_util::format::NumberFormatBuilder::new().required_fraction_digits(true).build();
+    assert_eq!(canonical::<FRACTION>(b".5", b'.'), Ok("5e-1".into()));
+    assert_eq!(canonical::<FRACTION>(b"5e3", b'.'), Ok("5e3".into()));
+    assert_eq!(canonical::<FRACTION>(b"5.", b'.'), Err(Error::EmptyFraction(2)));
+    assert_eq!(canonical::<FRACTION>(b"5.e3", b'.'), Err(Error::EmptyFraction(2)));
+}
+
+#[test]
+fn parts_test() {
+    let number = NumericStr::parse::<{ STANDARD }>(b"-00120.0340e-1", b'e', b'.').unwrap();
+    assert!(number.is_negative());
+    assert!(!number.is_zero());
+    assert_eq!(number.exponent(), 1);
+    assert_eq!(number.digits().collect::<Vec<_>>(), b"120034");
+
+    let number = NumericStr::parse::<{ STANDARD }>(b"-0.0", b'e', b'.').unwrap();
+    assert!(!number.is_negative());
+    assert!(number.is_zero());
+    assert_eq!(number.digits().count(), 0);
+}
+
+#[test]
+fn compare_test() {
+    use core::cmp::Ordering;
+
+    let compare = |x: &[u8], y: &[u8]| {
+        let x = NumericStr::parse::<{ STANDARD }>(x, b'e', b'.').unwrap();
+        let y = NumericStr::parse::<{ STANDARD }>(y, b'e', b'.').unwrap();
+        x.compare(&y)
+    };
+    assert_eq!(compare(b"1e3", b"1000"), Ordering::Equal);
+    assert_eq!(compare(b"0", b"-0.0e5"), Ordering::Equal);
+    assert_eq!(compare(b"0", b"1e-999"), Ordering::Less);
+    assert_eq!(compare(b"0", b"-1e-999"), Ordering::Greater);
+    assert_eq!(compare(b"-1", b"1"), Ordering::Less);
+    assert_eq!(compare(b"2", b"10"), Ordering::Less);
+    assert_eq!(compare(b"-2", b"-10"), Ordering::Greater);
+    assert_eq!(compare(b"1.5", b"1.25"), Ordering::Greater);
+    assert_eq!(compare(b"-1.5", b"-1.25"), Ordering::Less);
+    assert_eq!(compare(b"1.2", b"1.20000000000000000000001"), Ordering::Less);
+    assert_eq!(compare(b"9007199254740993", b"9007199254740992"), Ordering::Greater);
+    assert_eq!(compare(b"123.456e2", b"12345.6"), Ordering::Equal);
+    assert_eq!(compare(b"1e100", b"9e99"), Ordering::Greater);
+    assert_eq!(compare(b"1e4611686018427387904", b"1e4611686018427387903"), Ordering::Greater);
+    assert_eq!(compare(b"1e-4611686018427387904", b"1e-4611686018427387903"), Ordering::Less);
+    assert_eq!(compare(b"10e4611686018427387904", b"1e4611686018427387904"), Ordering::Greater);
+}
+
+#[test]
+fn hash_test() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let hash = |bytes: &[u8]| {
+        let number = NumericStr::parse::<{ STANDARD }>(bytes, b'e', b'.').unwrap();
+        let mut hasher = DefaultHasher::new();
+        number.hash_value(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(b"1e3"), hash(b"1000"));
+    assert_eq!(hash(b"+0001000.000E+00"), hash(b"1000"));
+    assert_eq!(hash(b"0"), hash(b"-0.0e10"));
+    assert_eq!(hash(b"12.5"), hash(b"0.125e2"));
+    assert_ne!(hash(b"1"), hash(b"-1"));
+    assert_ne!(hash(b"1"), hash(b"10"));
+    assert_ne!(hash(b"1.1"), hash(b"11"));
+    assert_ne!(hash(b"0"), hash(b"1e-999"));
+    assert_ne!(hash(b"1e4611686018427387904"), hash(b"1e4611686018427387903"));
+}
+
+fn quantize(bytes: &[u8], decimals: usize, rounding: Rounding) -> String {
+    let number = NumericStr::parse::<{ STANDARD }>(bytes, b'e', b'.').unwrap();
+    let mut buffer = [0u8; 64];
+    let count = number.quantize(decimals, rounding, &mut buffer).unwrap();
+    String::from_utf8(buffer[..count].to_vec()).unwrap()
+}
+
+#[test]
+fn quantize_test() {
+    assert_eq!(quantize(b"1.005", 2, Rounding::HalfEven), "1.00");
+    assert_eq!(quantize(b"1.015", 2, Rounding::HalfEven), "1.02");
+    assert_eq!(quantize(b"1.0051", 2, Rounding::HalfEven), "1.01");
+    assert_eq!(quantize(b"1.005", 2, Rounding::HalfUp), "1.01");
+    assert_eq!(quantize(b"1.005", 2, Rounding::HalfDown), "1.00");
+    assert_eq!(quantize(b"1.0051", 2, Rounding::HalfDown), "1.01");
+    assert_eq!(quantize(b"1.001", 2, Rounding::Up), "1.01");
+    assert_eq!(quantize(b"1.009", 2, Rounding::Down), "1.00");
+    assert_eq!(quantize(b"-1.001", 2, Rounding::Ceiling), "-1.00");
+    assert_eq!(quantize(b"-1.001", 2, Rounding::Floor), "-1.01");
+    assert_eq!(quantize(b"1.001", 2, Rounding::Ceiling), "1.01");
+    assert_eq!(quantize(b"1.001", 2, Rounding::Floor), "1.00");
+
+    // Carries, padding, and integral results.
+    assert_eq!(quantize(b"9.995", 2, Rounding::HalfEven), "10.00");
+    assert_eq!(quantize(b"-999.5", 0, Rounding::HalfUp), "-1000");
+    assert_eq!(quantize(b"12.5", 0, Rounding::HalfEven), "12");
+    assert_eq!(quantize(b"1.5e2", 3, Rounding::HalfEven), "150.000");
+    assert_eq!(quantize(b"12345e-6", 4, Rounding::HalfEven), "0.0123");
+    assert_eq!(quantize(b"0.00005", 4, Rounding::HalfUp), "0.0001");
+    assert_eq!(quantize(b"5e-9", 2, Rounding::Up), "0.01");
+
+    // Zeros are written without a sign.
+    assert_eq!(quantize(b"-0.001", 2, Rounding::HalfEven), "0.00");
+    assert_eq!(quantize(b"-0", 0, Rounding::HalfEven), "0");
+    assert_eq!(quantize(b"-0.4", 0, Rounding::Ceiling), "0");
+    assert_eq!(quantize(b"-5e-9", 2, Rounding::Floor), "-0.01");
+}
+
+#[test]
+fn write_small_buffer_test() {
+    let write = |bytes: &[u8], buffer: &mut [u8]| {
+        NumericStr::parse::<{ STANDARD }>(bytes, b'e', b'.').unwrap().write(buffer)
+    };
+    assert_eq!(write(b"0", &mut []), Err(Error::BufferTooSmall(1)));
+    assert_eq!(write(b"-0", &mut [0u8; 1]), Ok(1));
+    assert_eq!(write(b"-12.5", &mut [0u8; 6]), Err(Error::BufferTooSmall(7)));
+    assert_eq!(write(b"-12.5", &mut [0u8; 7]), Ok(7));
+    assert_eq!(write(b"1e-10", &mut [0u8; 4]), Err(Error::BufferTooSmall(5)));
+    assert_eq!(write(b"1e-10", &mut [0u8; 5]), Ok(5));
+
+    // Nothing is written if the buffer is too small.
+    let mut buffer = [b'x'; 4];
+    assert_eq!(write(b"1.5e3", &mut buffer), Err(Error::BufferTooSmall(5)));
+    assert_eq!(&buffer, b"xxxx");
+}
+
+#[test]
+fn quantize_small_buffer_test() {
+    let quantize = |bytes: &[u8], decimals: usize, buffer: &mut [u8]| {
+        let number = NumericStr::parse::<{ STANDARD }>(bytes, b'e', b'.').unwrap();
+        number.quantize(decimals, Rounding::HalfEven, buffer)
+    };
+    assert_eq!(quantize(b"1e400", 1, &mut [0u8; 64]), Err(Error::BufferTooSmall(403)));
+    assert_eq!(quantize(b"-1.5", 2, &mut [0u8; 4]), Err(Error::BufferTooSmall(5)));
+    assert_eq!(quantize(b"-1.5", 2, &mut [0u8; 5]), Ok(5));
+    assert_eq!(quantize(b"0", usize::MAX, &mut [0u8; 64]), Err(Error::BufferTooSmall(usize::MAX)));
+    assert_eq!(quantize(b"1e99999", 0, &mut [0u8; 64]), Err(Error::BufferTooSmall(100000)));
+
+    // Carrying into a new leading digit needs another byte.
+    assert_eq!(quantize(b"9.995", 2, &mut [0u8; 4]), Err(Error::BufferTooSmall(5)));
+    let mut buffer = [0u8; 5];
+    assert_eq!(quantize(b"9.995", 2, &mut buffer), Ok(5));
+    assert_eq!(&buffer, b"10.00");
+    assert_eq!(quantize(b"-99.5", 0, &mut [0u8; 3]), Err(Error::BufferTooSmall(4)));
+    assert_eq!(quantize(b"-99.5", 0, &mut [0u8; 4]), Ok(4));
+}
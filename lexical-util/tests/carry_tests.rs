@@ -0,0 +1,58 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::carry::{Carry, CARRY_SIZE};
+use lexical_util::error::Error;
+
+#[test]
+fn extend_test() {
+    let mut carry = Carry::new();
+    assert!(carry.is_empty());
+    assert_eq!(carry.extend(b"1.5"), Ok(()));
+    assert_eq!(carry.extend(b"e"), Ok(()));
+    assert_eq!(carry.as_slice(), b"1.5e");
+    assert_eq!(carry.len(), 4);
+
+    let digits = [b'1'; CARRY_SIZE];
+    assert_eq!(carry.extend(&digits), Err(Error::NumberTooLong(CARRY_SIZE)));
+    assert_eq!(carry.as_slice(), b"1.5e");
+
+    carry.clear();
+    assert_eq!(carry.extend(&digits), Ok(()));
+    assert_eq!(carry.len(), CARRY_SIZE);
+}
+
+#[test]
+fn join_test() {
+    let mut carry = Carry::new();
+    carry.extend(b"-12").unwrap();
+    let mut buffer = [0u8; CARRY_SIZE];
+    assert_eq!(carry.join(b"34,5", &mut buffer), (&b"-1234,5"[..], false));
+
+    let digits = [b'9'; CARRY_SIZE];
+    let (bytes, is_truncated) = carry.join(&digits, &mut buffer);
+    assert_eq!(bytes.len(), CARRY_SIZE);
+    assert_eq!(&bytes[..4], b"-129");
+    assert!(is_truncated);
+}
+
+fn digits(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    match bytes.iter().take_while(|c| c.is_ascii_digit()).count() {
+        0 => Err(Error::Empty(0)),
+        count => Ok((count, count)),
+    }
+}
+
+#[test]
+fn parse_partial_test() {
+    let mut carry = Carry::new();
+    assert_eq!(carry.parse_partial(b"123,4", digits), Ok((3, 3)));
+    carry.extend(b"12").unwrap();
+    assert_eq!(carry.parse_partial(b"34,5", digits), Ok((4, 4)));
+    assert_eq!(carry.parse_partial(b",5", digits), Ok((2, 2)));
+
+    let nines = [b'9'; CARRY_SIZE];
+    assert_eq!(carry.parse_partial(&nines, digits), Err(Error::NumberTooLong(CARRY_SIZE)));
+    let mut bytes = nines[..CARRY_SIZE - 3].to_vec();
+    bytes.push(b',');
+    assert_eq!(carry.parse_partial(&bytes, digits), Ok((CARRY_SIZE - 1, CARRY_SIZE - 1)));
+}
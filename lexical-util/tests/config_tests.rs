@@ -0,0 +1,24 @@
+#![cfg(feature = "serde")]
+
+use lexical_util::config;
+
+#[test]
+fn intern_test() {
+    let nan = config::intern("nan".to_string());
+    assert_eq!(nan, b"nan");
+    assert_eq!(config::intern("nan".to_string()).as_ptr(), nan.as_ptr());
+    assert_ne!(config::intern("NAN".to_string()).as_ptr(), nan.as_ptr());
+}
+
+#[test]
+fn to_static_test() {
+    let preset: &'static [u8] = b"NaN";
+    assert_eq!(config::to_static(None, Some(preset)), None);
+    assert_eq!(
+        config::to_static(Some("NaN".to_string()), Some(preset)).unwrap().as_ptr(),
+        preset.as_ptr()
+    );
+    let nan = config::to_static(Some("nan".to_string()), Some(preset)).unwrap();
+    assert_eq!(nan, b"nan");
+    assert_eq!(config::to_static(Some("nan".to_string()), None).unwrap().as_ptr(), nan.as_ptr());
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "floats")]
+
+use core::cmp::Ordering;
+use lexical_util::custom_float::{self, LexicalFloat};
+
+/// An 8-bit float, with 4 exponent bits and 3 fraction bits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fp8(u8);
+
+impl LexicalFloat for Fp8 {
+    const EXPONENT_SIZE: i32 = 4;
+    const MANTISSA_SIZE: i32 = 3;
+
+    fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        Self(bits as u8)
+    }
+}
+
+#[test]
+fn to_f64_test() {
+    assert_eq!(Fp8::from_parts(false, 7, 0).to_f64(), 1.0);
+    assert_eq!(Fp8::from_parts(true, 8, 4).to_f64(), -3.0);
+    assert_eq!(Fp8::from_parts(false, 14, 7).to_f64(), 240.0);
+    assert_eq!(Fp8::from_parts(false, 0, 1).to_f64(), 2f64.powi(-9));
+    assert_eq!(Fp8::from_parts(false, 15, 0).to_f64(), f64::INFINITY);
+    assert!(Fp8::from_parts(false, 15, 1).to_f64().is_nan());
+    assert!(Fp8(0x80).to_f64().is_sign_negative());
+    assert_eq!(LexicalFloat::to_f64(1.5f32), 1.5);
+    assert_eq!(LexicalFloat::to_f64(f64::MIN_POSITIVE), f64::MIN_POSITIVE);
+}
+
+#[test]
+fn from_f64_test() {
+    let from_f64 = |x: f64| custom_float::from_f64::<Fp8>(x, Ordering::Equal);
+    assert_eq!(from_f64(1.0), Fp8(0x38));
+    assert_eq!(from_f64(-0.0), Fp8(0x80));
+    assert_eq!(from_f64(f64::NEG_INFINITY), Fp8(0xF8));
+    assert!(from_f64(f64::NAN).is_nan());
+
+    // Ties round to even, unless the direction is known.
+    assert!(custom_float::is_tie::<Fp8>(1.0625));
+    assert_eq!(from_f64(1.0625), Fp8(0x38));
+    assert_eq!(custom_float::from_f64::<Fp8>(1.0625, Ordering::Greater), Fp8(0x39));
+    assert_eq!(from_f64(1.1875), Fp8(0x3A));
+    assert!(!custom_float::is_tie::<Fp8>(1.07));
+
+    // Overflow to infinity, and underflow to zero or subnormals.
+    assert_eq!(from_f64(247.9), Fp8(0x77));
+    assert_eq!(from_f64(248.0), Fp8(0x78));
+    assert_eq!(from_f64(1e300), Fp8(0x78));
+    assert_eq!(from_f64(2f64.powi(-10)), Fp8(0x00));
+    assert_eq!(from_f64(2f64.powi(-10) * 1.01), Fp8(0x01));
+    assert_eq!(from_f64(-1e-300), Fp8(0x80));
+}
+
+#[test]
+fn roundtrip_test() {
+    for bits in 0..=u8::MAX {
+        let float = Fp8(bits);
+        if !float.is_nan() {
+            assert_eq!(custom_float::from_f64::<Fp8>(float.to_f64(), Ordering::Equal), float);
+        }
+    }
+}
+
+#[test]
+fn f32_test() {
+    // Rounding to an `f32` must match the native conversion.
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for _ in 0..10000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let value = f64::from_bits(state);
+        if value.is_nan() {
+            continue;
+        }
+        let float = custom_float::from_f64::<f32>(value, Ordering::Equal);
+        assert_eq!(float.to_bits(), (value as f32).to_bits(), "{:e}", value);
+    }
+    let tiny = f32::from_bits(1) as f64;
+    assert_eq!(custom_float::from_f64::<f32>(tiny * 0.5, Ordering::Equal), 0.0);
+    assert_eq!(custom_float::from_f64::<f32>(tiny * 0.5, Ordering::Greater), f32::from_bits(1));
+}
+
+#[test]
+fn next_prev_test() {
+    assert_eq!(Fp8(0x80).next(), Fp8(0x00));
+    assert_eq!(Fp8(0x00).prev(), Fp8(0x80));
+    assert_eq!(Fp8(0x38).next(), Fp8(0x39));
+    assert_eq!(Fp8(0xB8).next(), Fp8(0xB7));
+    assert_eq!(Fp8(0x77).next(), Fp8(0x78));
+    assert_eq!(Fp8(0x78).next(), Fp8(0x78));
+    assert_eq!(Fp8(0xF8).prev(), Fp8(0xF8));
+    assert_eq!(Fp8(0x39).prev().next(), Fp8(0x39));
+}
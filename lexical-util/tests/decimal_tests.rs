@@ -0,0 +1,152 @@
+#![cfg(all(feature = "parse", feature = "floats"))]
+
+use lexical_util::decimal::{Decimal128, Decimal64};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+fn parse64(string: &str) -> Decimal64 {
+    string.parse().unwrap()
+}
+
+fn parse128(string: &str) -> Decimal128 {
+    string.parse().unwrap()
+}
+
+#[test]
+fn decimal64_encoding_test() {
+    // (string, BID, DPD)
+    let cases: [(&str, u64, u64); 8] = [
+        ("1", 0x31C0_0000_0000_0001, 0x2238_0000_0000_0001),
+        ("-1", 0xB1C0_0000_0000_0001, 0xA238_0000_0000_0001),
+        ("0", 0x31C0_0000_0000_0000, 0x2238_0000_0000_0000),
+        ("9.999999999999999E+384", 0x77FB_86F2_6FC0_FFFF, 0x77FC_FF3F_CFF3_FCFF),
+        ("1E-398", 0x0000_0000_0000_0001, 0x0000_0000_0000_0001),
+        ("Infinity", 0x7800_0000_0000_0000, 0x7800_0000_0000_0000),
+        ("-Infinity", 0xF800_0000_0000_0000, 0xF800_0000_0000_0000),
+        ("NaN", 0x7C00_0000_0000_0000, 0x7C00_0000_0000_0000),
+    ];
+    for &(string, bid, dpd) in cases.iter() {
+        let number = parse64(string);
+        assert_eq!(number.to_bid(), bid, "{}", string);
+        assert_eq!(number.to_dpd(), dpd, "{}", string);
+        assert_eq!(Decimal64::from_bid(bid), number);
+        assert_eq!(Decimal64::from_dpd(dpd), number);
+        assert_eq!(number.to_string(), string);
+    }
+}
+
+#[test]
+fn decimal128_encoding_test() {
+    // (string, BID, DPD)
+    let cases: [(&str, u128, u128); 5] = [
+        ("1", 0x3040_0000_0000_0000_0000_0000_0000_0001, 0x2208_0000_0000_0000_0000_0000_0000_0001),
+        (
+            "-0.00",
+            0xB03C_0000_0000_0000_0000_0000_0000_0000,
+            0xA207_8000_0000_0000_0000_0000_0000_0000,
+        ),
+        (
+            "9.999999999999999999999999999999999E+6144",
+            0x5FFF_ED09_BEAD_87C0_378D_8E63_FFFF_FFFF,
+            0x77FF_CFF3_FCFF_3FCF_F3FC_FF3F_CFF3_FCFF,
+        ),
+        (
+            "1E-6176",
+            0x0000_0000_0000_0000_0000_0000_0000_0001,
+            0x0000_0000_0000_0000_0000_0000_0000_0001,
+        ),
+        (
+            "sNaN",
+            0x7E00_0000_0000_0000_0000_0000_0000_0000,
+            0x7E00_0000_0000_0000_0000_0000_0000_0000,
+        ),
+    ];
+    for &(string, bid, dpd) in cases.iter() {
+        let number = parse128(string);
+        assert_eq!(number.to_bid(), bid, "{}", string);
+        assert_eq!(number.to_dpd(), dpd, "{}", string);
+        assert_eq!(Decimal128::from_bid(bid), number);
+        assert_eq!(Decimal128::from_dpd(dpd), number);
+        assert_eq!(number.to_string(), string);
+    }
+}
+
+#[test]
+fn declet_roundtrip_test() {
+    for value in 0..1000u64 {
+        let number = Decimal64::new(false, value * 1_000_000_000_001, -3).unwrap();
+        assert_eq!(Decimal64::from_dpd(number.to_dpd()), number);
+        assert_eq!(Decimal64::from_bid(number.to_bid()), number);
+    }
+}
+
+#[test]
+fn quantum_test() {
+    let number = parse64("1.50");
+    assert_eq!((number.coefficient(), number.exponent()), (150, -2));
+    assert_ne!(number, parse64("1.5"));
+    assert_eq!(parse64("1000").to_string(), "1000");
+    assert_eq!(parse64("1e3").to_string(), "1E+3");
+    assert_eq!(parse64("0.000001").to_string(), "0.000001");
+    assert_eq!(parse64("0.0000001").to_string(), "1E-7");
+    assert_eq!(parse64("0E-10").to_string(), "0E-10");
+    assert_eq!(parse64("-0").to_string(), "-0");
+    assert_eq!(parse64("123.456e-2").to_string(), "1.23456");
+}
+
+#[test]
+fn rounding_test() {
+    // Round to 16 digits, with ties to even.
+    assert_eq!(parse64("12345678901234565").to_string(), "1.234567890123456E+16");
+    assert_eq!(parse64("12345678901234575").to_string(), "1.234567890123458E+16");
+    assert_eq!(parse64("123456789012345650001").to_string(), "1.234567890123457E+20");
+    assert_eq!(parse64("9999999999999999.5").to_string(), "1.000000000000000E+16");
+    // Trailing zeros are dropped exactly.
+    assert_eq!(parse64("1.0000000000000000000").to_string(), "1.000000000000000");
+
+    // Subnormal numbers round to the smallest exponent.
+    assert_eq!(parse64("1.5E-398").to_string(), "2E-398");
+    assert_eq!(parse64("2.5E-398").to_string(), "2E-398");
+    assert_eq!(parse64("4E-399").to_string(), "0E-398");
+    assert_eq!(parse64("-6E-399").to_string(), "-1E-398");
+    assert_eq!(parse64("1E-999999").to_string(), "0E-398");
+
+    // Large exponents are clamped, or overflow to infinity.
+    assert_eq!(parse64("1E+384").to_string(), "1.000000000000000E+384");
+    assert_eq!(parse64("1E+385").to_string(), "Infinity");
+    assert_eq!(parse64("-1E+999999").to_string(), "-Infinity");
+    assert_eq!(parse64("0E+999").to_string(), "0E+369");
+    assert!(parse128("1E+6144").is_finite());
+    assert!(parse128("1E+6145").is_infinite());
+}
+
+#[test]
+fn special_test() {
+    assert!(parse64("inf").is_infinite());
+    assert!(parse64("-INFINITY").is_sign_negative());
+    assert!(parse64("nan").is_nan());
+    assert!(parse64("-sNaN").is_signaling_nan());
+    let nan = parse64("NaN123");
+    assert_eq!(nan.coefficient(), 123);
+    assert_eq!(nan.to_string(), "NaN123");
+    assert_eq!(Decimal64::from_dpd(nan.to_dpd()), nan);
+    assert_eq!(Decimal64::from_bid(nan.to_bid()), nan);
+    assert_eq!(Decimal64::infinity(true).to_string(), "-Infinity");
+    assert_eq!(Decimal64::nan().to_string(), "NaN");
+
+    // Non-canonical coefficients are decoded as zero.
+    let number = Decimal64::from_bid(0x6C7F_FFFF_FFFF_FFFF);
+    assert_eq!((number.coefficient(), number.exponent()), (0, 1));
+}
+
+#[test]
+fn error_test() {
+    assert_eq!("".parse::<Decimal64>(), Err(Error::Empty(0)));
+    assert_eq!("1.5x".parse::<Decimal64>(), Err(Error::InvalidDigit(3)));
+    assert_eq!("nanx".parse::<Decimal64>(), Err(Error::EmptyMantissa(0)));
+    assert_eq!("NaN1234567890123456".parse::<Decimal64>(), Err(Error::EmptyMantissa(0)));
+    assert_eq!(Decimal64::parse::<STANDARD>(b"1,50", b'e', b','), Ok(parse64("1.50")));
+    assert_eq!(Decimal64::new(false, 10_000_000_000_000_000, 0), None);
+    assert_eq!(Decimal64::new(false, 1, 370), None);
+    assert_eq!(Decimal64::new(false, 1, -399), None);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::delimiter::Delimiters;
+use lexical_util::error::Error;
+
+#[test]
+fn contains_test() {
+    let delimiters = Delimiters::new();
+    for c in 0..=255u8 {
+        assert!(!delimiters.contains(c));
+    }
+
+    let delimiters = Delimiters::from_bytes(b",\x00\x7F\x80\xFF");
+    assert!(delimiters.contains(b','));
+    assert!(delimiters.contains(0x00));
+    assert!(delimiters.contains(0x7F));
+    assert!(delimiters.contains(0x80));
+    assert!(delimiters.contains(0xFF));
+    assert!(!delimiters.contains(b'.'));
+    assert!(!delimiters.contains(0x01));
+    assert!(!delimiters.contains(0x81));
+    assert!(!delimiters.contains(0xFE));
+    assert_eq!(delimiters, Delimiters::new().add(0xFF).add(0x80).add(0x7F).add(0).add(b','));
+}
+
+#[test]
+fn predefined_test() {
+    assert!(Delimiters::WHITESPACE.contains(b' '));
+    assert!(Delimiters::WHITESPACE.contains(b'\t'));
+    assert!(!Delimiters::WHITESPACE.contains(b','));
+
+    assert!(Delimiters::JSON.contains(b' '));
+    assert!(Delimiters::JSON.contains(b','));
+    assert!(Delimiters::JSON.contains(b']'));
+    assert!(Delimiters::JSON.contains(b'}'));
+    assert!(!Delimiters::JSON.contains(b'e'));
+    assert!(!Delimiters::JSON.contains(b'.'));
+
+    assert!(Delimiters::CSV.contains(b','));
+    assert!(Delimiters::CSV.contains(b'\n'));
+    assert!(!Delimiters::CSV.contains(b' '));
+}
+
+#[test]
+fn check_partial_test() {
+    let delimiters = Delimiters::JSON;
+    assert_eq!(delimiters.check_partial(b"12", 12, 2), Ok((12, 2)));
+    assert_eq!(delimiters.check_partial(b"12,", 12, 2), Ok((12, 2)));
+    assert_eq!(delimiters.check_partial(b"12a", 12, 2), Err(Error::InvalidDigit(2)));
+    assert_eq!(delimiters.check_partial(b",", 0, 0), Err(Error::Empty(0)));
+    assert_eq!(delimiters.check_partial(b"1,", 1, 1), Ok((1, 1)));
+}
@@ -65,6 +65,17 @@ fn char_to_digit_test() {
     char_to_digit(0x61, 16, Some(10));
     char_to_digit(0x67, 16, None);
     char_to_digit(0x7A, 16, None);
+
+    // Extended, case-sensitive alphabet.
+    char_to_digit(b'Z', 36, Some(35));
+    char_to_digit(b'z', 36, Some(35));
+    char_to_digit(b'Z', 62, Some(35));
+    char_to_digit(b'a', 62, Some(36));
+    char_to_digit(b'z', 62, Some(61));
+    char_to_digit(b'a', 40, Some(36));
+    char_to_digit(b'd', 40, Some(39));
+    char_to_digit(b'e', 40, None);
+    char_to_digit(b'-', 62, None);
 }
 
 #[cfg(feature = "parse")]
@@ -107,4 +118,86 @@ fn digit_to_char_const_test() {
     digit_to_char(9, 10, b'9');
     digit_to_char(10, 36, b'A');
     digit_to_char(11, 36, b'B');
+    digit_to_char(35, 62, b'Z');
+    digit_to_char(36, 62, b'a');
+    digit_to_char(61, 62, b'z');
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn digit_to_char_case_test() {
+    use lexical_util::digit::DigitCase;
+
+    assert_eq!(digit::digit_to_char_case(9, 10, DigitCase::Lower), Some(b'9'));
+    assert_eq!(digit::digit_to_char_case(10, 16, DigitCase::Upper), Some(b'A'));
+    assert_eq!(digit::digit_to_char_case(10, 16, DigitCase::Lower), Some(b'a'));
+    assert_eq!(digit::digit_to_char_case(35, 36, DigitCase::Lower), Some(b'z'));
+    assert_eq!(digit::digit_to_char_case(35, 37, DigitCase::Lower), Some(b'Z'));
+    assert_eq!(digit::digit_to_char_case(61, 62, DigitCase::Upper), Some(b'z'));
+    assert_eq!(digit::digit_to_char_case(16, 16, DigitCase::Upper), None);
+    assert_eq!(digit::digit_to_char_case(0, 1, DigitCase::Upper), None);
+    assert_eq!(digit::digit_to_char_case(0, 63, DigitCase::Upper), None);
+
+    // Every digit round-trips with the parser alphabet.
+    #[cfg(feature = "parse")]
+    for radix in 2..=62 {
+        for value in 0..radix {
+            for &case in [DigitCase::Upper, DigitCase::Lower].iter() {
+                let c = digit::digit_to_char_case(value, radix, case).unwrap();
+                assert_eq!(digit::char_to_digit(c, radix), Some(value));
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn is_8digits_test() {
+    assert!(digit::is_8digits(u64::from_le_bytes(*b"12345678"), 10));
+    assert!(!digit::is_8digits(u64::from_le_bytes(*b"12345678"), 8));
+    assert!(digit::is_8digits(u64::from_le_bytes(*b"01010101"), 2));
+    assert!(!digit::is_8digits(u64::from_le_bytes(*b"1234567a"), 10));
+    assert!(!digit::is_8digits(u64::from_le_bytes(*b"/1234567"), 10));
+    assert!(!digit::is_8digits(u64::from_le_bytes(*b"1234567\xb0"), 10));
+}
+
+#[cfg(feature = "parse")]
+fn count_digits_scalar(bytes: &[u8], radix: u32) -> usize {
+    bytes.iter().take_while(|&&c| digit::char_is_digit_const(c, radix)).count()
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn count_digits_test() {
+    assert_eq!(digit::count_digits(b"", 10), 0);
+    assert_eq!(digit::count_digits(b"1", 10), 1);
+    assert_eq!(digit::count_digits(b"12345678", 10), 8);
+    assert_eq!(digit::count_digits(b"123456789012345678,", 10), 18);
+    assert_eq!(digit::count_digits(b"1234567a9012345678", 10), 7);
+    assert_eq!(digit::count_digits(b"1234567a9012345678", 16), 18);
+    assert_eq!(digit::count_digits(b"1010101021", 2), 8);
+    assert_eq!(digit::count_digits(b"12345678\xb0", 10), 8);
+
+    // Compare to the scalar algorithm, for every byte at every position.
+    for radix in 2..=36 {
+        for index in 0..17 {
+            for byte in 0..=255u8 {
+                let mut bytes = *b"12345678901234567";
+                bytes[index] = byte;
+                let expected = count_digits_scalar(&bytes, radix);
+                assert_eq!(digit::count_digits(&bytes, radix), expected);
+                assert_eq!(digit::is_all_digits(&bytes, radix), expected == bytes.len());
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn is_all_digits_test() {
+    assert!(digit::is_all_digits(b"", 10));
+    assert!(digit::is_all_digits(b"0123456789", 10));
+    assert!(!digit::is_all_digits(b"0123456789", 9));
+    assert!(!digit::is_all_digits(b"01234567 9", 10));
+    assert!(digit::is_all_digits(b"deadBEEF", 16));
 }
@@ -0,0 +1,43 @@
+use lexical_util::endian;
+
+#[test]
+fn read_u32_test() {
+    assert_eq!(endian::read_u32_le(b"1234"), Some(0x3433_3231));
+    assert_eq!(endian::read_u32_be(b"1234"), Some(0x3132_3334));
+    assert_eq!(endian::read_u32_le(b"12345"), Some(0x3433_3231));
+    assert_eq!(endian::read_u32_be(b"12345"), Some(0x3132_3334));
+    assert_eq!(endian::read_u32_le(b"123"), None);
+    assert_eq!(endian::read_u32_be(b""), None);
+}
+
+#[test]
+fn read_u64_test() {
+    assert_eq!(endian::read_u64_le(b"12345678"), Some(0x3837_3635_3433_3231));
+    assert_eq!(endian::read_u64_be(b"12345678"), Some(0x3132_3334_3536_3738));
+    assert_eq!(endian::read_u64_le(b"123456789"), Some(0x3837_3635_3433_3231));
+    assert_eq!(endian::read_u64_le(b"1234567"), None);
+    assert_eq!(endian::read_u64_be(b"1234567"), None);
+}
+
+#[test]
+fn read_padded_test() {
+    assert_eq!(endian::read_u32_le_padded(b"12", b'0'), Some(0x3231_3030));
+    assert_eq!(endian::read_u32_le_padded(b"", b'0'), Some(0x3030_3030));
+    assert_eq!(endian::read_u32_le_padded(b"1234", b'0'), endian::read_u32_le(b"1234"));
+    assert_eq!(endian::read_u32_le_padded(b"12345", b'0'), None);
+    assert_eq!(endian::read_u64_le_padded(b"123", b'0'), endian::read_u64_le(b"00000123"));
+    assert_eq!(endian::read_u64_le_padded(b"123456789", b'0'), None);
+}
+
+#[test]
+#[cfg(feature = "parse")]
+fn is_8digits_endian_test() {
+    use lexical_util::digit::is_8digits;
+
+    // The first byte is the least-significant, regardless of the target.
+    assert!(is_8digits(endian::read_u64_le(b"12345678").unwrap(), 10));
+    assert!(!is_8digits(endian::read_u64_le(b"1234567a").unwrap(), 10));
+    assert!(!is_8digits(endian::read_u64_le(b"a2345678").unwrap(), 10));
+    assert!(is_8digits(endian::read_u64_le(b"01234567").unwrap(), 8));
+    assert!(!is_8digits(endian::read_u64_le(b"01234568").unwrap(), 8));
+}
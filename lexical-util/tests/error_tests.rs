@@ -0,0 +1,126 @@
+use lexical_util::error::{Error, ErrorCode};
+
+#[test]
+fn code_test() {
+    assert_eq!(Error::Success.code(), 0);
+    assert_eq!(Error::Overflow(0).code(), 1);
+    assert_eq!(Error::InvalidDigit(5).code(), 3);
+    assert_eq!(Error::InvalidNegativeSign(0).code(), 19);
+    assert_eq!(Error::InvalidMantissaRadix.code(), 100);
+    assert_eq!(Error::InvalidFlags.code(), 116);
+    assert_eq!(Error::InvalidNanString.code(), 200);
+    assert_eq!(Error::InvalidPositiveExponentBreak.code(), 211);
+}
+
+#[test]
+fn expected_test() {
+    assert_eq!(Error::InvalidDigit(3).expected(), Some("a digit"));
+    assert_eq!(Error::EmptyExponent(2).expected(), Some("exponent digits"));
+    assert_eq!(Error::Overflow(20).expected(), None);
+    assert_eq!(Error::InvalidRadix.expected(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn display_test() {
+    assert_eq!(
+        Error::InvalidDigit(3).to_string(),
+        "lexical parse error: invalid digit found at index 3, expected a digit (code 3)"
+    );
+    assert_eq!(
+        Error::Overflow(20).to_string(),
+        "lexical parse error: numeric overflow occurred at index 20 (code 1)"
+    );
+    assert_eq!(
+        Error::InvalidExponentBase.to_string(),
+        "lexical number format error: invalid exponent base (code 101)"
+    );
+    assert_eq!(
+        Error::NanStringTooLong.to_string(),
+        "lexical options error: NaN string is too long (code 201)"
+    );
+    assert_eq!(Error::Success.to_string(), "not actually an error");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn std_error_test() {
+    fn parse(bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Box::new(Error::InvalidDigit(bytes.len())))
+    }
+
+    let error = parse(b"12a").unwrap_err();
+    assert!(error.source().is_none());
+    assert_eq!(error.downcast_ref::<Error>(), Some(&Error::InvalidDigit(3)));
+}
+
+#[test]
+fn error_code_test() {
+    assert_eq!(ErrorCode::InvalidDigit.to_code(), 3);
+    assert_eq!(ErrorCode::from_code(3), Some(ErrorCode::InvalidDigit));
+    assert_eq!(ErrorCode::from_code(26), None);
+    assert_eq!(ErrorCode::from_code(u32::MAX), None);
+    assert_eq!(Error::InvalidDigit(5).error_code(), ErrorCode::InvalidDigit);
+    assert_eq!(Error::from_code(ErrorCode::InvalidDigit, 5), Error::InvalidDigit(5));
+    assert_eq!(Error::from_code(ErrorCode::InvalidRadix, 5), Error::InvalidRadix);
+
+    // Ensure all codes round-trip.
+    let mut count = 0;
+    for value in 0..300 {
+        if let Some(code) = ErrorCode::from_code(value) {
+            let error = Error::from_code(code, 7);
+            assert_eq!(code.to_code(), value);
+            assert_eq!(error.code(), value);
+            assert_eq!(error.error_code(), code);
+            count += 1;
+        }
+    }
+    assert_eq!(count, 58);
+}
+
+#[test]
+fn all_error_codes_test() {
+    // Every code is produced exactly once, in numeric order.
+    let codes: Vec<u32> = ErrorCode::ALL.iter().map(|code| code.to_code()).collect();
+    let expected: Vec<u32> =
+        (0..300).filter(|&value| ErrorCode::from_code(value).is_some()).collect();
+    assert_eq!(codes, expected);
+    assert!(!ErrorCode::ALL.contains(&ErrorCode::Unknown));
+
+    for &code in ErrorCode::ALL.iter() {
+        let error = Error::from_code(code, 3);
+        assert_eq!(error.error_code(), code);
+        assert_eq!(ErrorCode::from_code_or_unknown(code.to_code()), code);
+        assert!(!error.is_unknown());
+        if code.to_code() != 0 && code.to_code() < 100 {
+            assert_eq!(error.index(), Some(&3));
+        } else {
+            assert_eq!(error.index(), None);
+        }
+    }
+}
+
+#[test]
+fn unknown_error_code_test() {
+    assert_eq!(ErrorCode::Unknown.to_code(), u32::MAX);
+    assert_eq!(ErrorCode::from_code_or_unknown(26), ErrorCode::Unknown);
+    assert_eq!(ErrorCode::from_code_or_unknown(299), ErrorCode::Unknown);
+    assert_eq!(ErrorCode::from_code_or_unknown(u32::MAX), ErrorCode::Unknown);
+    assert_eq!(ErrorCode::from_code_or_unknown(0), ErrorCode::Success);
+
+    let error = Error::from_code(ErrorCode::Unknown, 5);
+    assert_eq!(error, Error::Unknown);
+    assert!(error.is_unknown());
+    assert_eq!(error.index(), None);
+    assert_eq!(error.code(), u32::MAX);
+    assert_eq!(error.error_code(), ErrorCode::Unknown);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn unknown_display_test() {
+    assert_eq!(
+        Error::Unknown.to_string(),
+        "lexical unknown error: unknown error code (code 4294967295)"
+    );
+}
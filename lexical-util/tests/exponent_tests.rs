@@ -0,0 +1,60 @@
+#![cfg(feature = "floats")]
+
+use lexical_util::exponent::{BiasedExponent, BinaryExponent, DecimalExponent};
+use lexical_util::num::Float;
+use proptest::prelude::*;
+
+#[test]
+fn decimal_to_binary_test() {
+    assert_eq!(DecimalExponent::new(0).to_binary(), BinaryExponent::new(0));
+    assert_eq!(DecimalExponent::new(1).to_binary(), BinaryExponent::new(3));
+    assert_eq!(DecimalExponent::new(3).to_binary(), BinaryExponent::new(9));
+    assert_eq!(DecimalExponent::new(-1).to_binary(), BinaryExponent::new(-4));
+    assert_eq!(DecimalExponent::new(308).to_binary(), BinaryExponent::new(1023));
+    assert_eq!(DecimalExponent::new(-324).to_binary(), BinaryExponent::new(-1077));
+}
+
+#[test]
+fn binary_to_decimal_test() {
+    assert_eq!(BinaryExponent::new(0).to_decimal(), DecimalExponent::new(0));
+    assert_eq!(BinaryExponent::new(10).to_decimal(), DecimalExponent::new(3));
+    assert_eq!(BinaryExponent::new(-1).to_decimal(), DecimalExponent::new(-1));
+    assert_eq!(BinaryExponent::new(1023).to_decimal(), DecimalExponent::new(307));
+    assert_eq!(BinaryExponent::new(-1074).to_decimal(), DecimalExponent::new(-324));
+}
+
+#[test]
+fn bias_test() {
+    assert_eq!(BinaryExponent::of(1.0f64), BinaryExponent::new(-52));
+    assert_eq!(BiasedExponent::of(1.0f64), BiasedExponent::new(1023));
+    assert_eq!(BinaryExponent::new(-52).to_biased::<f64>(), BiasedExponent::new(1023));
+    assert_eq!(BiasedExponent::new(1023).to_binary::<f64>(), BinaryExponent::new(-52));
+    assert_eq!(BinaryExponent::of(1.0f32), BinaryExponent::new(-23));
+    assert_eq!(BiasedExponent::of(1.0f32), BiasedExponent::new(127));
+
+    // Denormal floats have the exponent of the smallest normal float.
+    assert_eq!(BiasedExponent::of(5e-324f64), BiasedExponent::new(0));
+    assert_eq!(BinaryExponent::of(5e-324f64), BinaryExponent::new(f64::DENORMAL_EXPONENT));
+    assert_eq!(BiasedExponent::new(0).to_binary::<f64>(), BinaryExponent::of(5e-324f64));
+    assert_eq!(BiasedExponent::new(1).to_binary::<f64>(), BinaryExponent::of(f64::MIN_POSITIVE));
+}
+
+proptest! {
+    #[test]
+    fn float_exponent_proptest(bits in 0u64..0x7FF0_0000_0000_0000) {
+        let float = f64::from_bits(bits);
+        let biased = BiasedExponent::of(float);
+        let binary = BinaryExponent::of(float);
+        prop_assert_eq!(biased.to_binary::<f64>(), binary);
+        prop_assert_eq!(binary.to_biased::<f64>().get(), biased.get().max(1));
+    }
+
+    #[test]
+    fn log_proptest(q in -300i32..300) {
+        // The binary exponent of a normal float is for a 53-bit significand.
+        let binary = DecimalExponent::new(q).to_binary();
+        prop_assert_eq!(binary.get(), BinaryExponent::of(10f64.powi(q)).get() + 52);
+        let decimal = BinaryExponent::new(q).to_decimal();
+        prop_assert_eq!(decimal.get(), 2f64.powi(q).log10().floor() as i32);
+    }
+}
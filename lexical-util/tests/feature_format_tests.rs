@@ -29,6 +29,12 @@ fn ignore_test() {
     assert_eq!(fmt.case_sensitive_base_prefix(), false);
     #[cfg(feature = "power-of-two")]
     assert_eq!(fmt.case_sensitive_base_suffix(), false);
+    assert_eq!(fmt.negative_parentheses(), false);
+    assert_eq!(fmt.optional_quotes(), false);
+    assert_eq!(fmt.d_exponent_notation(), false);
+    assert_eq!(fmt.sign_exponent_notation(), false);
+    assert_eq!(fmt.trailing_sign(), false);
+    assert_eq!(fmt.overpunch_sign(), false);
     assert_eq!(fmt.integer_internal_digit_separator(), true);
     assert_eq!(fmt.fraction_internal_digit_separator(), true);
     assert_eq!(fmt.exponent_internal_digit_separator(), true);
@@ -103,6 +109,12 @@ fn flags_test() {
     test_flag!(case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX);
     #[cfg(feature = "power-of-two")]
     test_flag!(case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX);
+    test_flag!(negative_parentheses, NEGATIVE_PARENTHESES);
+    test_flag!(optional_quotes, OPTIONAL_QUOTES);
+    test_flag!(d_exponent_notation, D_EXPONENT_NOTATION);
+    test_flag!(sign_exponent_notation, SIGN_EXPONENT_NOTATION);
+    test_flag!(trailing_sign, TRAILING_SIGN);
+    test_flag!(overpunch_sign, OVERPUNCH_SIGN);
     test_flag!(integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR);
     test_flag!(fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR);
     test_flag!(exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR);
@@ -1,3 +1,5 @@
+#[cfg(feature = "radix")]
+use lexical_util::error::Error;
 use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 
 #[test]
@@ -58,3 +60,45 @@ fn from_radix_test() {
     assert_eq!(format.exponent_base(), 32);
     assert_eq!(format.exponent_radix(), 32);
 }
+
+#[test]
+#[cfg(feature = "radix")]
+fn extended_radix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(62);
+    let format = NumberFormat::<FORMAT> {};
+    assert!(format.is_valid());
+    assert_eq!(format.radix(), 62);
+
+    // The extended alphabet is only supported for integers.
+    assert!(!format.is_valid_float());
+    assert_eq!(format.float_error(), Error::InvalidMantissaRadix);
+    const DECIMAL_EXPONENT: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(36)
+        .exponent_base(core::num::NonZeroU8::new(40))
+        .build();
+    assert!(NumberFormat::<DECIMAL_EXPONENT> {}.is_valid());
+    assert_eq!(NumberFormat::<DECIMAL_EXPONENT> {}.float_error(), Error::InvalidExponentBase);
+    const RADIX_36: u128 = NumberFormatBuilder::from_radix(36);
+    assert!(NumberFormat::<RADIX_36> {}.is_valid_float());
+
+    const INVALID: u128 = NumberFormatBuilder::rebuild(FORMAT).radix(63).build();
+    assert!(!NumberFormat::<INVALID> {}.is_valid());
+    assert_eq!(NumberFormat::<INVALID> {}.float_error(), Error::InvalidMantissaRadix);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn max_leading_zeros_test() {
+    use lexical_util::format;
+
+    const FORMAT: u128 =
+        NumberFormatBuilder::new().max_leading_zeros(core::num::NonZeroU8::new(3)).build();
+    let format = NumberFormat::<FORMAT> {};
+    assert!(format.is_valid());
+    assert_eq!(format.max_leading_zeros(), 3);
+    assert_eq!(NumberFormat::<{ NumberFormatBuilder::decimal() }> {}.max_leading_zeros(), 0);
+
+    let builder = NumberFormatBuilder::rebuild(FORMAT);
+    assert_eq!(builder.get_max_leading_zeros(), core::num::NonZeroU8::new(3));
+    assert_eq!(format::max_leading_zeros(builder.build()), 3);
+}
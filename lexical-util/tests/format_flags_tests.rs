@@ -33,6 +33,14 @@ fn test_is_valid_digit_separator() {
         let format =
             format::NumberFormat::<{ from_digit_separator(b'e') }>::rebuild().radix(16).build();
         assert_eq!(format::is_valid_digit_separator(format), false);
+
+        // Lowercase letters are digits in the extended alphabet.
+        let format =
+            format::NumberFormat::<{ from_digit_separator(b'e') }>::rebuild().radix(62).build();
+        assert_eq!(format::is_valid_digit_separator(format), false);
+        let format =
+            format::NumberFormat::<{ from_digit_separator(b'_') }>::rebuild().radix(62).build();
+        assert_eq!(format::is_valid_digit_separator(format), true);
     }
 }
 
@@ -56,3 +64,54 @@ fn test_is_valid_punctuation() {
     assert_eq!(is_valid_punctuation(b'\'', b'h', 0), true);
     assert_eq!(is_valid_punctuation(b'\'', b'h', b'h'), false);
 }
+
+#[test]
+#[cfg(feature = "format")]
+fn test_is_valid_exponent_flags() {
+    let d_exponent = || format::NumberFormatBuilder::new().d_exponent_notation(true);
+    let sign_exponent = || format::NumberFormatBuilder::new().sign_exponent_notation(true);
+    assert_eq!(format::is_valid_exponent_flags(d_exponent().build()), true);
+    assert_eq!(format::is_valid_exponent_flags(sign_exponent().build()), true);
+    let fmt = d_exponent().no_exponent_notation(true).build();
+    assert_eq!(format::is_valid_exponent_flags(fmt), false);
+    let fmt = sign_exponent().no_exponent_notation(true).build();
+    assert_eq!(format::is_valid_exponent_flags(fmt), false);
+    assert_eq!(format::is_valid_options_punctuation(d_exponent().build(), b'e', b'd'), false);
+    assert_eq!(format::is_valid_options_punctuation(d_exponent().build(), b'e', b'.'), true);
+
+    // `d` is a digit for radixes above 13.
+    #[cfg(feature = "radix")]
+    {
+        let fmt = d_exponent().radix(13).build();
+        assert_eq!(format::is_valid_exponent_flags(fmt), true);
+        let fmt = d_exponent().radix(14).build();
+        assert_eq!(format::is_valid_exponent_flags(fmt), false);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn test_is_valid_trailing_sign_flags() {
+    let trailing = || format::NumberFormatBuilder::new().trailing_sign(true);
+    let overpunch = || format::NumberFormatBuilder::new().overpunch_sign(true);
+    assert_eq!(format::is_valid_exponent_flags(trailing().build()), true);
+    let fmt = trailing().sign_exponent_notation(true).build();
+    assert_eq!(format::is_valid_exponent_flags(fmt), false);
+    let fmt = overpunch().sign_exponent_notation(true).build();
+    assert_eq!(format::is_valid_exponent_flags(fmt), true);
+    assert_eq!(format::is_valid_punctuation(overpunch().build()), true);
+    let separator =
+        |x| overpunch().digit_separator(num::NonZeroU8::new(x)).digit_separator_flags(true);
+    assert_eq!(format::is_valid_punctuation(separator(b'_').build()), true);
+    assert_eq!(format::is_valid_punctuation(separator(b'{').build()), false);
+    assert_eq!(format::is_valid_punctuation(separator(b'J').build()), false);
+
+    // Letters are digits for radixes above 10.
+    #[cfg(feature = "radix")]
+    {
+        let fmt = overpunch().radix(16).build();
+        assert_eq!(format::is_valid_punctuation(fmt), false);
+        let fmt = trailing().radix(16).build();
+        assert_eq!(format::is_valid_punctuation(fmt), true);
+    }
+}
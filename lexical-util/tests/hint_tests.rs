@@ -0,0 +1,9 @@
+use lexical_util::error::Error;
+use lexical_util::hint;
+
+#[test]
+fn hint_test() {
+    assert!(hint::likely(true));
+    assert!(!hint::likely(false));
+    assert_eq!(hint::cold_error::<u8>(Error::InvalidDigit(1)), Err(Error::InvalidDigit(1)));
+}
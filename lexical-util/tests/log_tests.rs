@@ -0,0 +1,105 @@
+use lexical_util::log::{digit_count, estimate_log10, floor_log10, floor_log2};
+use proptest::prelude::*;
+
+fn naive_digit_count(x: u128) -> usize {
+    x.to_string().len()
+}
+
+#[test]
+fn floor_log2_test() {
+    assert_eq!(floor_log2(0u8), 0);
+    assert_eq!(floor_log2(1u8), 0);
+    assert_eq!(floor_log2(2u16), 1);
+    assert_eq!(floor_log2(3u16), 1);
+    assert_eq!(floor_log2(u32::MAX), 31);
+    assert_eq!(floor_log2(1u64 << 40), 40);
+    assert_eq!(floor_log2(u128::MAX), 127);
+    assert_eq!(floor_log2(usize::MAX), usize::MAX.count_ones() - 1);
+}
+
+#[test]
+fn floor_log10_test() {
+    assert_eq!(floor_log10(0u8), 0);
+    assert_eq!(floor_log10(255u8), 2);
+    assert_eq!(floor_log10(9999u16), 3);
+    assert_eq!(floor_log10(10000u16), 4);
+    assert_eq!(floor_log10(u32::MAX), 9);
+    assert_eq!(floor_log10(u64::MAX), 19);
+    assert_eq!(floor_log10(10u128.pow(38) - 1), 37);
+    assert_eq!(floor_log10(u128::MAX), 38);
+}
+
+#[test]
+fn digit_count_test() {
+    // Every power of 10, and the values around it.
+    let mut power = 1u128;
+    for exp in 0..39 {
+        assert_eq!(digit_count(power), exp + 1);
+        assert_eq!(digit_count(power - 1), naive_digit_count(power - 1));
+        assert_eq!(digit_count(power + 1), naive_digit_count(power + 1));
+        if power <= u64::MAX as u128 {
+            assert_eq!(digit_count(power as u64), exp + 1);
+            assert_eq!(digit_count(power as u64 - 1), naive_digit_count(power - 1));
+        }
+        power = power.wrapping_mul(10);
+    }
+    for x in 0..=u16::MAX {
+        assert_eq!(digit_count(x), naive_digit_count(x as u128));
+    }
+}
+
+#[test]
+#[cfg(feature = "floats")]
+fn extended_float_test() {
+    use lexical_util::extended_float::ExtendedFloat;
+
+    let fp = |mant: u64, exp: i32| ExtendedFloat {
+        mant,
+        exp,
+    };
+    assert_eq!(fp(0, 10).floor_log2(), None);
+    assert_eq!(fp(0, 10).estimate_log10(), None);
+    assert_eq!(fp(1, 0).floor_log2(), Some(0));
+    assert_eq!(fp(1, 0).estimate_log10(), Some(0));
+    assert_eq!(fp(1 << 63, -63).floor_log2(), Some(0));
+    assert_eq!(fp(1000, 0).estimate_log10(), Some(2));
+    assert_eq!(fp(1024, 0).estimate_log10(), Some(3));
+    assert_eq!(fp(1, -1).floor_log2(), Some(-1));
+    assert_eq!(fp(1, -1).estimate_log10(), Some(-1));
+    assert_eq!(fp(1, -1074).estimate_log10(), Some(-324));
+    // `f64::MAX` is `1.8e308`, and the estimate may be 1 below.
+    assert_eq!(fp(u64::MAX, 960).estimate_log10(), Some(307));
+}
+
+proptest! {
+    #[test]
+    fn floor_log10_proptest(x in any::<u64>()) {
+        let expected = naive_digit_count(x as u128) - 1;
+        let estimate = estimate_log10(x) as usize;
+        prop_assert_eq!(floor_log10(x) as usize, expected);
+        prop_assert!(estimate <= expected && estimate + 1 >= expected);
+    }
+
+    #[test]
+    fn digit_count_proptest(x in any::<u128>(), y in any::<u32>()) {
+        prop_assert_eq!(digit_count(x), naive_digit_count(x));
+        prop_assert_eq!(digit_count(y), naive_digit_count(y as u128));
+    }
+
+    #[test]
+    #[cfg(feature = "floats")]
+    fn extended_float_proptest(bits in 1u64..0x7FF0_0000_0000_0000) {
+        use lexical_util::extended_float::ExtendedFloat;
+        use lexical_util::num::Float;
+
+        let float = f64::from_bits(bits);
+        let fp = ExtendedFloat {
+            mant: float.mantissa(),
+            exp: float.exponent(),
+        };
+        let log10 = float.log10().floor() as i32;
+        let estimate = fp.estimate_log10().unwrap();
+        prop_assert_eq!(fp.floor_log2(), Some(float.log2().floor() as i32));
+        prop_assert!(estimate <= log10 && estimate + 1 >= log10);
+    }
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::matcher::NumberMatcher;
+
+const STANDARD_MATCHER: lexical_util::result::Result<NumberMatcher> =
+    NumberMatcher::new::<{ STANDARD }>(b'e', b'.');
+
+#[test]
+fn standard_test() {
+    let matcher = STANDARD_MATCHER.unwrap();
+    for valid in [&b"1"[..], b"0", b"-1", b"+1", b"1.5", b"1.", b".5", b"1e5", b"1.5E-5", b"00"] {
+        assert!(matcher.is_match(valid), "{:?}", valid);
+    }
+    for invalid in [&b""[..], b"-", b".", b"e5", b"1e", b"1e+", b"1.5.", b"1_0", b"NaN", b"0x1"] {
+        assert!(!matcher.is_match(invalid), "{:?}", invalid);
+    }
+}
+
+#[test]
+fn match_len_test() {
+    let matcher = STANDARD_MATCHER.unwrap();
+    assert_eq!(matcher.match_len(b"1.5e10 ms"), Some(6));
+    assert_eq!(matcher.match_len(b"1.5e"), Some(3));
+    assert_eq!(matcher.match_len(b"1.5e+"), Some(3));
+    assert_eq!(matcher.match_len(b"-.e5"), None);
+    assert_eq!(matcher.match_len(b"abc"), None);
+    assert_eq!(matcher.match_len(b""), None);
+}
+
+#[test]
+fn find_test() {
+    let matcher = STANDARD_MATCHER.unwrap();
+    assert_eq!(matcher.find(b"took 12.5ms"), Some((5, 9)));
+    assert_eq!(matcher.find(b"no numbers"), None);
+    assert_eq!(matcher.find(b"e-5"), Some((1, 3)));
+
+    let bytes = b"x=1, y=-2.5e3, z=.5e";
+    let matches: Vec<_> = matcher.find_iter(bytes).map(|(s, e)| &bytes[s..e]).collect();
+    assert_eq!(matches, [&b"1"[..], b"-2.5e3", b".5"]);
+}
+
+#[test]
+fn state_test() {
+    let matcher = STANDARD_MATCHER.unwrap();
+    let mut state = NumberMatcher::START;
+    assert!(!matcher.is_accepting(state));
+    state = matcher.next_state(state, b'-');
+    assert!(!matcher.is_accepting(state));
+    state = matcher.next_state(state, b'1');
+    assert!(matcher.is_accepting(state));
+    state = matcher.next_state(state, b'e');
+    assert!(!matcher.is_accepting(state));
+    state = matcher.next_state(state, b'x');
+    assert_eq!(state, NumberMatcher::DEAD);
+    assert_eq!(matcher.next_state(state, b'1'), NumberMatcher::DEAD);
+}
+
+#[test]
+fn invalid_test() {
+    assert_eq!(NumberMatcher::new::<{ STANDARD }>(b'e', b'e'), Err(Error::InvalidPunctuation));
+    assert_eq!(NumberMatcher::new::<{ STANDARD }>(b'1', b'.'), Err(Error::InvalidPunctuation));
+    assert_eq!(NumberMatcher::new::<{ STANDARD }>(b'e', 0), Err(Error::InvalidPunctuation));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn json_test() {
+    use lexical_util::format::JSON;
+
+    let matcher = NumberMatcher::new::<{ JSON }>(b'e', b'.').unwrap();
+    for valid in [&b"0"[..], b"-0.5", b"10", b"1e5", b"1.5E+5"] {
+        assert!(matcher.is_match(valid), "{:?}", valid);
+    }
+    for invalid in [&b"+1"[..], b"01", b"1.", b".5", b"00.5", b"1.e5"] {
+        assert!(!matcher.is_match(invalid), "{:?}", invalid);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    use lexical_util::format::RUST_LITERAL;
+
+    let matcher = NumberMatcher::new::<{ RUST_LITERAL }>(b'e', b'.').unwrap();
+    for valid in [&b"1_000"[..], b"1__0", b"1_", b"1_.5_", b"1.5e1_0", b"-1_0.0_1e-1_"] {
+        assert!(matcher.is_match(valid), "{:?}", valid);
+    }
+    for invalid in [&b"_1"[..], b"+1", b"1._5", b"1e_5", b"1.", b".5", b"_"] {
+        assert!(!matcher.is_match(invalid), "{:?}", invalid);
+    }
+
+    const FORMAT: u128 = lexical_util::format::NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'\''))
+        .integer_internal_digit_separator(true)
+        .build();
+    let matcher = NumberMatcher::new::<{ FORMAT }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"1'000'000.5"));
+    assert!(!matcher.is_match(b"1''000"));
+    assert!(!matcher.is_match(b"1'"));
+    assert!(!matcher.is_match(b"1.0'0"));
+    assert_eq!(matcher.match_len(b"1'000'"), Some(5));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn syntax_flags_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const SIGN: u128 = NumberFormatBuilder::new()
+        .required_mantissa_sign(true)
+        .required_exponent_sign(true)
+        .build();
+    let matcher = NumberMatcher::new::<{ SIGN }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"+1e-5"));
+    assert!(matcher.is_match(b"-1e+5"));
+    assert!(!matcher.is_match(b"1e-5"));
+    assert!(!matcher.is_match(b"+1e5"));
+
+    const NO_POSITIVE: u128 = NumberFormatBuilder::new()
+        .no_positive_mantissa_sign(true)
+        .no_positive_exponent_sign(true)
+        .build();
+    let matcher = NumberMatcher::new::<{ NO_POSITIVE }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"-1e-5"));
+    assert!(matcher.is_match(b"1e5"));
+    assert!(!matcher.is_match(b"+1e5"));
+    assert!(!matcher.is_match(b"1e+5"));
+
+    const EXPONENT: u128 = NumberFormatBuilder::new()
+        .required_exponent_notation(true)
+        .no_exponent_without_fraction(true)
+        .case_sensitive_exponent(true)
+        .build();
+    let matcher = NumberMatcher::new::<{ EXPONENT }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"1.0e5"));
+    assert!(!matcher.is_match(b"1.0E5"));
+    assert!(!matcher.is_match(b"1.0"));
+    assert!(!matcher.is_match(b"1e5"));
+
+    const DIGITS: u128 = NumberFormatBuilder::new()
+        .required_integer_digits(true)
+        .required_fraction_digits(true)
+        .build();
+    let matcher = NumberMatcher::new::<{ DIGITS }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"0.5"));
+    assert!(matcher.is_match(b"5e3"));
+    assert!(!matcher.is_match(b".5"));
+    assert!(!matcher.is_match(b"5."));
+    assert!(!matcher.is_match(b"5.e3"));
+
+    const NO_EXPONENT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    let matcher = NumberMatcher::new::<{ NO_EXPONENT }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"1.5"));
+    assert!(!matcher.is_match(b"1.5e5"));
+
+    const FORTRAN: u128 =
+        NumberFormatBuilder::new().d_exponent_notation(true).sign_exponent_notation(true).build();
+    let matcher = NumberMatcher::new::<{ FORTRAN }>(b'e', b'.').unwrap();
+    assert!(matcher.is_match(b"1.5D+10"));
+    assert!(matcher.is_match(b"1.5d-3"));
+    assert!(matcher.is_match(b"1.5-10"));
+    assert!(matcher.is_match(b"1.5e10"));
+    assert!(!matcher.is_match(b"1.5-"));
+    assert!(!matcher.is_match(b"-+1"));
+    assert_eq!(matcher.find(b"x=1.5-10,"), Some((2, 8)));
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn base_prefix_suffix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::new()
+        .radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .base_prefix(core::num::NonZeroU8::new(b'x'))
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .build();
+    let matcher = NumberMatcher::new::<{ HEX }>(b'p', b'.').unwrap();
+    for valid in [&b"0x1F"[..], b"-0X1f.8p-1", b"1Fh", b"0x1p10h", b"0"] {
+        assert!(matcher.is_match(valid), "{:?}", valid);
+    }
+    for invalid in [&b"0x"[..], b"x1", b"1p1F", b"0x.8", b"h", b"1hh"] {
+        assert!(!matcher.is_match(invalid), "{:?}", invalid);
+    }
+}
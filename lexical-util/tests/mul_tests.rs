@@ -1,4 +1,10 @@
-use lexical_util::mul::{mul, mulhi};
+use lexical_util::mul::{
+    checked_mul_add_u128,
+    mul,
+    mulhi,
+    overflowing_mul_add_u128,
+    wrapping_mul_add_u128,
+};
 use quickcheck::quickcheck;
 
 quickcheck! {
@@ -49,4 +55,28 @@ quickcheck! {
         let expected = (x as u128 * y as u128) >> 64;
         actual == expected as u64
     }
+
+    #[cfg_attr(miri, ignore)]
+    fn mul_add_u128_quickcheck(x: u128, y: u64, z: u64) -> bool {
+        let (value, overflow1) = x.overflowing_mul(y as u128);
+        let (value, overflow2) = value.overflowing_add(z as u128);
+        let overflow = overflow1 || overflow2;
+        overflowing_mul_add_u128(x, y, z) == (value, overflow)
+            && wrapping_mul_add_u128(x, y, z) == value
+            && checked_mul_add_u128(x, y, z) == Some(value).filter(|_| !overflow)
+    }
+}
+
+#[test]
+fn mul_add_u128_test() {
+    assert_eq!(overflowing_mul_add_u128(0, 10, 9), (9, false));
+    assert_eq!(
+        overflowing_mul_add_u128(u64::MAX as u128, u64::MAX, u64::MAX),
+        (u64::MAX as u128 * (1u128 << 64), false)
+    );
+    assert_eq!(overflowing_mul_add_u128(u128::MAX / 10, 10, 5), (u128::MAX, false));
+    assert_eq!(overflowing_mul_add_u128(u128::MAX / 10, 10, 6), (0, true));
+    assert_eq!(overflowing_mul_add_u128(u128::MAX / 10 + 1, 10, 0), (4, true));
+    assert_eq!(checked_mul_add_u128(u128::MAX, 1, 1), None);
+    assert_eq!(wrapping_mul_add_u128(u128::MAX, 1, 1), 0);
 }
@@ -33,6 +33,12 @@ fn format_properties_test() {
     assert_eq!(format.case_sensitive_exponent(), false);
     assert_eq!(format.case_sensitive_base_prefix(), false);
     assert_eq!(format.case_sensitive_base_suffix(), false);
+    assert_eq!(format.negative_parentheses(), false);
+    assert_eq!(format.optional_quotes(), false);
+    assert_eq!(format.d_exponent_notation(), false);
+    assert_eq!(format.sign_exponent_notation(), false);
+    assert_eq!(format.trailing_sign(), false);
+    assert_eq!(format.overpunch_sign(), false);
     assert_eq!(format.integer_internal_digit_separator(), false);
     assert_eq!(format.fraction_internal_digit_separator(), false);
     assert_eq!(format.exponent_internal_digit_separator(), false);
@@ -0,0 +1,25 @@
+#![cfg(feature = "std")]
+
+use lexical_util::options::Registry;
+
+#[test]
+fn registry_test() {
+    let mut registry = Registry::new();
+    assert!(registry.is_empty());
+    assert_eq!(registry.register("json", 1), None);
+    assert_eq!(registry.register(String::from("csv"), 2), None);
+    assert_eq!(registry.register("json", 3), Some(1));
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.get("json"), Some(&3));
+    assert_eq!(registry.get("toml"), None);
+    assert!(registry.contains("csv"));
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["csv", "json"]);
+    assert_eq!(registry.remove("csv"), Some(2));
+    assert!(!registry.contains("csv"));
+
+    let mut registry: Registry<_> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    assert_eq!(registry.get("b"), Some(&2));
+    registry.extend(vec![("b", 4), ("c", 5)]);
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(registry.get("b"), Some(&4));
+}
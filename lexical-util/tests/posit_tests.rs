@@ -0,0 +1,67 @@
+#![cfg(feature = "posit")]
+
+use core::cmp::Ordering;
+use lexical_util::posit::{self, Posit16, Posit32};
+
+#[test]
+fn to_f64_test() {
+    assert_eq!(Posit16::from_bits(0x4000).to_f64(), 1.0);
+    assert_eq!(Posit16::from_bits(0xC000).to_f64(), -1.0);
+    assert_eq!(Posit16::from_bits(0x4800).to_f64(), 2.0);
+    assert_eq!(Posit16::from_bits(0x3800).to_f64(), 0.5);
+    assert_eq!(Posit16::from_bits(0x4001).to_f64(), 1.0 + 2f64.powi(-11));
+    assert_eq!(Posit16::MAX.to_f64(), 2f64.powi(56));
+    assert_eq!(Posit16::MIN_POSITIVE.to_f64(), 2f64.powi(-56));
+    assert_eq!(Posit32::from_bits(0x4000_0000).to_f64(), 1.0);
+    assert_eq!(Posit32::from_bits(0x4000_0001).to_f64(), 1.0 + 2f64.powi(-27));
+    assert_eq!(Posit32::MAX.to_f64(), 2f64.powi(120));
+    assert_eq!(Posit32::from_bits(0x8000_0001).to_f64(), -2f64.powi(120));
+    assert_eq!(Posit32::MIN_POSITIVE.to_f64(), 2f64.powi(-120));
+    assert_eq!(Posit32::ZERO.to_f64(), 0.0);
+    assert!(Posit32::NAR.to_f64().is_nan());
+}
+
+#[test]
+fn from_f64_test() {
+    assert_eq!(Posit16::from_f64(1.0), Posit16::from_bits(0x4000));
+    assert_eq!(Posit16::from_f64(-0.5), Posit16::from_bits(0xC800));
+    assert_eq!(Posit16::from_f64(-0.0), Posit16::ZERO);
+    assert_eq!(Posit16::from_f64(f64::NAN), Posit16::NAR);
+    assert_eq!(Posit16::from_f64(f64::INFINITY), Posit16::NAR);
+
+    // Values are clamped, rather than overflowing or underflowing.
+    assert_eq!(Posit16::from_f64(1e300), Posit16::MAX);
+    assert_eq!(Posit16::from_f64(-1e300), Posit16::from_bits(0x8001));
+    assert_eq!(Posit16::from_f64(1e-300), Posit16::MIN_POSITIVE);
+    assert_eq!(Posit32::from_f64(5e-324), Posit32::MIN_POSITIVE);
+
+    // Ties round to an even bit pattern, unless the direction is known.
+    let halfway = 1.0 + 2f64.powi(-12);
+    assert!(posit::is_tie::<Posit16>(halfway));
+    assert_eq!(Posit16::from_f64(halfway), Posit16::from_bits(0x4000));
+    assert_eq!(posit::from_f64::<Posit16>(halfway, Ordering::Greater), Posit16::from_bits(0x4001));
+    assert_eq!(posit::from_f64::<Posit16>(-halfway, Ordering::Greater), Posit16::from_bits(0xBFFF));
+    assert_eq!(Posit16::from_f64(1.0 + 3.0 * 2f64.powi(-12)), Posit16::from_bits(0x4002));
+    assert!(!posit::is_tie::<Posit32>(halfway));
+
+    // Ties in the exponent bits of large regimes are in the bit pattern.
+    // 2^52 and 2^56 are adjacent, so 2^54 is a tie, rounding to even.
+    assert_eq!(Posit16::from_bits(0x7FFE).to_f64(), 2f64.powi(52));
+    assert!(posit::is_tie::<Posit16>(2f64.powi(54)));
+    assert_eq!(Posit16::from_f64(2f64.powi(54)), Posit16::from_bits(0x7FFE));
+    assert_eq!(Posit16::from_f64(2f64.powi(54) * 1.01), Posit16::MAX);
+}
+
+#[test]
+fn roundtrip_test() {
+    for bits in 0..=u16::MAX {
+        let posit = Posit16::from_bits(bits);
+        if !posit.is_nar() {
+            assert_eq!(Posit16::from_f64(posit.to_f64()), posit);
+        }
+    }
+    for &bits in [1u32, 0x1234_5678, 0x4000_0001, 0x7FFF_FFFE, 0x8000_0001, 0xDEAD_BEEF].iter() {
+        let posit = Posit32::from_bits(bits);
+        assert_eq!(Posit32::from_f64(posit.to_f64()), posit);
+    }
+}
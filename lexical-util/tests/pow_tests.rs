@@ -0,0 +1,91 @@
+use lexical_util::pow::{self, Pow10, POW10_U128, POW10_U32, POW10_U64};
+use proptest::prelude::*;
+
+#[test]
+fn pow10_table_test() {
+    for (exp, &value) in POW10_U32.iter().enumerate() {
+        assert_eq!(value, 10u32.pow(exp as u32));
+    }
+    for (exp, &value) in POW10_U64.iter().enumerate() {
+        assert_eq!(value, 10u64.pow(exp as u32));
+    }
+    for (exp, &value) in POW10_U128.iter().enumerate() {
+        assert_eq!(value, 10u128.pow(exp as u32));
+    }
+
+    // The tables contain every power that fits.
+    assert_eq!(10u32.checked_pow(POW10_U32.len() as u32), None);
+    assert_eq!(10u64.checked_pow(POW10_U64.len() as u32), None);
+    assert_eq!(10u128.checked_pow(POW10_U128.len() as u32), None);
+    assert_eq!(<u64 as Pow10>::POW10.len(), 20);
+}
+
+#[test]
+fn checked_pow_test() {
+    assert_eq!(pow::checked_pow(3u32, 0), Some(1));
+    assert_eq!(pow::checked_pow(3u32, 20), Some(3486784401));
+    assert_eq!(pow::checked_pow(3u32, 21), None);
+    assert_eq!(pow::checked_pow(-2i8, 7), Some(-128));
+    assert_eq!(pow::checked_pow(2i8, 7), None);
+    assert_eq!(pow::checked_pow(0u64, 1000), Some(0));
+    assert_eq!(pow::checked_pow(1u64, u32::MAX), Some(1));
+    for base in 0..20u64 {
+        for exp in 0..70 {
+            assert_eq!(pow::checked_pow(base, exp), base.checked_pow(exp));
+        }
+    }
+}
+
+#[test]
+fn checked_pow10_test() {
+    assert_eq!(pow::checked_pow10::<u32>(9), Some(1000000000));
+    assert_eq!(pow::checked_pow10::<u32>(10), None);
+    assert_eq!(pow::checked_pow10::<u64>(19), Some(10000000000000000000));
+    assert_eq!(pow::checked_pow10::<u128>(39), None);
+    assert_eq!(pow::checked_pow10::<u128>(u32::MAX), None);
+}
+
+#[test]
+fn checked_mul_pow10_test() {
+    assert_eq!(pow::checked_mul_pow10(12345u64, 2), Some(1234500));
+    assert_eq!(pow::checked_mul_pow10(18u64, 18), Some(18000000000000000000));
+    assert_eq!(pow::checked_mul_pow10(19u64, 18), None);
+    assert_eq!(pow::checked_mul_pow10(1u32, 10), None);
+    assert_eq!(pow::checked_mul_pow10(0u32, 100), Some(0));
+}
+
+#[test]
+fn div_rem_1e8_test() {
+    assert_eq!(pow::div_rem_1e8(0), (0, 0));
+    assert_eq!(pow::div_rem_1e8(99999999), (0, 99999999));
+    assert_eq!(pow::div_rem_1e8(100000000), (1, 0));
+    assert_eq!(pow::div_rem_1e8(123456789012345678), (1234567890, 12345678));
+    assert_eq!(pow::div_rem_1e8(u64::MAX), (184467440737, 9551615));
+}
+
+#[test]
+fn div_rem_1e19_test() {
+    const E19: u128 = 10000000000000000000;
+    assert_eq!(pow::div_rem_1e19(0), (0, 0));
+    assert_eq!(pow::div_rem_1e19(E19 - 1), (0, 9999999999999999999));
+    assert_eq!(pow::div_rem_1e19(E19), (1, 0));
+    assert_eq!(pow::div_rem_1e19((1 << 83) - 1), ((1 << 83) / E19, (((1 << 83) - 1) % E19) as u64));
+    assert_eq!(pow::div_rem_1e19(1 << 83), ((1 << 83) / E19, ((1 << 83) % E19) as u64));
+    assert_eq!(pow::div_rem_1e19(u128::MAX), (u128::MAX / E19, (u128::MAX % E19) as u64));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn div_rem_1e8_proptest(n in any::<u64>()) {
+        prop_assert_eq!(pow::div_rem_1e8(n), (n / 100000000, (n % 100000000) as u32));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn div_rem_1e19_proptest(n in any::<u128>(), shift in 0u32..128) {
+        const E19: u128 = 10000000000000000000;
+        let n = n >> shift;
+        prop_assert_eq!(pow::div_rem_1e19(n), (n / E19, (n % E19) as u64));
+    }
+}
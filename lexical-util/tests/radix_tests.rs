@@ -0,0 +1,33 @@
+use core::convert::TryFrom;
+use lexical_util::error::Error;
+use lexical_util::radix::Radix;
+
+#[test]
+fn new_test() {
+    assert_eq!(Radix::new(0), None);
+    assert_eq!(Radix::new(1), None);
+    assert_eq!(Radix::new(2), Some(Radix::MIN));
+    assert_eq!(Radix::new(62), Some(Radix::MAX));
+    assert_eq!(Radix::new(63), None);
+    for radix in 2..=62 {
+        assert_eq!(Radix::new(radix).map(Radix::get), Some(radix));
+    }
+}
+
+#[test]
+fn try_from_test() {
+    assert_eq!(Radix::try_from(8u8), Ok(Radix::OCTAL));
+    assert_eq!(Radix::try_from(255u8), Err(Error::InvalidRadix));
+    assert_eq!(Radix::try_from(16u32), Ok(Radix::HEXADECIMAL));
+    assert_eq!(Radix::try_from(u32::MAX), Err(Error::InvalidRadix));
+    assert_eq!(u32::from(Radix::BINARY), 2);
+    assert_eq!(Radix::default(), Radix::DECIMAL);
+}
+
+#[test]
+fn properties_test() {
+    let powers = (2..=62).filter(|&radix| Radix::new(radix).unwrap().is_power_of_two());
+    assert_eq!(powers.collect::<Vec<_>>(), [2, 4, 8, 16, 32]);
+    assert!(!Radix::new(36).unwrap().is_extended());
+    assert!(Radix::new(37).unwrap().is_extended());
+}
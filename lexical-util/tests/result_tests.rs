@@ -0,0 +1,63 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::error::Error;
+use lexical_util::result::{self, Result};
+
+fn digits(bytes: &[u8]) -> Result<(usize, usize)> {
+    match bytes.iter().take_while(|c| c.is_ascii_digit()).count() {
+        0 => Err(Error::Empty(0)),
+        count => Ok((count, count)),
+    }
+}
+
+#[test]
+fn parse_partial_len32_test() {
+    assert_eq!(result::parse_partial_len32(b"123x", digits), Ok((3, 3)));
+    assert_eq!(result::parse_partial_len32(b"x", digits), Err(Error::Empty(0)));
+}
+
+#[test]
+fn check_range_test() {
+    assert_eq!(result::check_range(Ok(5), 1, 10), Ok(5));
+    assert_eq!(result::check_range(Ok(1), 1, 10), Ok(1));
+    assert_eq!(result::check_range(Ok(10), 1, 10), Ok(10));
+    assert_eq!(result::check_range(Ok(11), 1, 10), Err(Error::OutOfRange(0)));
+    assert_eq!(result::check_range(Ok(f64::NAN), 0.0, 1.0), Err(Error::OutOfRange(0)));
+    assert_eq!(
+        result::check_range::<u8>(Err(Error::Overflow(3)), 1, 10),
+        Err(Error::OutOfRange(0))
+    );
+    assert_eq!(
+        result::check_range::<u8>(Err(Error::Underflow(3)), 1, 10),
+        Err(Error::OutOfRange(0))
+    );
+    assert_eq!(result::check_range::<u8>(Err(Error::Empty(0)), 1, 10), Err(Error::Empty(0)));
+}
+
+#[test]
+fn check_complete_test() {
+    assert_eq!(result::check_complete(b"123", Ok((123, 3))), Ok(123));
+    assert_eq!(result::check_complete(b"123x", Ok((123, 3))), Err(Error::InvalidDigit(3)));
+    assert_eq!(result::check_complete(b"x", Ok((0, 0))), Err(Error::InvalidDigit(0)));
+    assert_eq!(result::check_complete::<u8>(b"", Err(Error::Empty(0))), Err(Error::Empty(0)));
+    assert_eq!(
+        result::check_complete::<f64>(b"-x", Err(Error::EmptyMantissa(1))),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(
+        result::check_complete::<f64>(b"1e", Err(Error::EmptyExponent(2))),
+        Err(Error::EmptyExponent(2))
+    );
+    assert_eq!(
+        result::check_complete::<u8>(b"300", Err(Error::Overflow(2))),
+        Err(Error::Overflow(2))
+    );
+}
+
+#[test]
+fn try_convert_test() {
+    assert_eq!(result::try_convert::<u64, u8>(Ok(255)), Ok(255));
+    assert_eq!(result::try_convert::<u64, u8>(Ok(256)), Err(Error::ConversionFailed(0)));
+    assert_eq!(result::try_convert::<i64, u32>(Ok(-1)), Err(Error::ConversionFailed(0)));
+    assert_eq!(result::try_convert::<u64, u8>(Err(Error::Empty(1))), Err(Error::Empty(1)));
+}
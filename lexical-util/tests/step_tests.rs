@@ -0,0 +1,39 @@
+#![cfg(any(feature = "parse", feature = "write"))]
+
+use lexical_util::step::DigitCount;
+
+#[test]
+fn digit_count_test() {
+    assert_eq!(<u8 as DigitCount<10>>::MAX_DIGITS, 3);
+    assert_eq!(<u8 as DigitCount<10>>::MIN_SAFE_DIGITS, 2);
+    assert_eq!(<i8 as DigitCount<10>>::MAX_DIGITS, 3);
+    assert_eq!(<i8 as DigitCount<10>>::MIN_SAFE_DIGITS, 2);
+    assert_eq!(<u32 as DigitCount<10>>::MAX_DIGITS, 10);
+    assert_eq!(<i32 as DigitCount<10>>::MIN_SAFE_DIGITS, 9);
+    assert_eq!(<u64 as DigitCount<10>>::MAX_DIGITS, 20);
+    assert_eq!(<u64 as DigitCount<10>>::MIN_SAFE_DIGITS, 19);
+    assert_eq!(<i64 as DigitCount<10>>::MAX_DIGITS, 19);
+    assert_eq!(<i64 as DigitCount<10>>::MIN_SAFE_DIGITS, 18);
+    assert_eq!(<u128 as DigitCount<10>>::MAX_DIGITS, 39);
+    assert_eq!(<u128 as DigitCount<10>>::MIN_SAFE_DIGITS, 38);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn digit_count_power_of_two_test() {
+    assert_eq!(<u64 as DigitCount<2>>::MAX_DIGITS, 64);
+    assert_eq!(<u64 as DigitCount<2>>::MIN_SAFE_DIGITS, 64);
+    assert_eq!(<i64 as DigitCount<16>>::MAX_DIGITS, 16);
+    assert_eq!(<i64 as DigitCount<16>>::MIN_SAFE_DIGITS, 15);
+    assert_eq!(<u32 as DigitCount<32>>::MAX_DIGITS, 7);
+    assert_eq!(<u32 as DigitCount<32>>::MIN_SAFE_DIGITS, 6);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn digit_count_radix_test() {
+    assert_eq!(<u64 as DigitCount<36>>::MAX_DIGITS, 13);
+    assert_eq!(<u64 as DigitCount<36>>::MIN_SAFE_DIGITS, 12);
+    assert_eq!(<u64 as DigitCount<62>>::MAX_DIGITS, 11);
+    assert_eq!(<u64 as DigitCount<62>>::MIN_SAFE_DIGITS, 10);
+}
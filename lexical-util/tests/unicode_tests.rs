@@ -0,0 +1,59 @@
+#![cfg(all(feature = "parse", feature = "unicode"))]
+
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_util::unicode::Normalized;
+
+fn normalize<const FORMAT: u128>(string: &str) -> Option<Vec<u8>> {
+    Normalized::new::<FORMAT>(string.as_bytes()).map(|x| x.as_slice().to_vec())
+}
+
+#[test]
+fn minus_sign_test() {
+    assert_eq!(normalize::<{ STANDARD }>("-1"), None);
+    assert_eq!(normalize::<{ STANDARD }>("\u{2212}1"), Some(b"-1".to_vec()));
+    assert_eq!(normalize::<{ STANDARD }>("1e\u{2212}5"), Some(b"1e-5".to_vec()));
+    // Other non-ASCII characters are not modified.
+    assert_eq!(normalize::<{ STANDARD }>("\u{2013}1"), Some("\u{2013}1".as_bytes().to_vec()));
+}
+
+#[test]
+fn space_test() {
+    assert_eq!(normalize::<{ STANDARD }>("1\u{a0}234"), Some(b"1234".to_vec()));
+    assert_eq!(normalize::<{ STANDARD }>("1\u{202f}234\u{202f}567"), Some(b"1234567".to_vec()));
+    let windows_1252 = Normalized::new::<{ STANDARD }>(b"1\xa0234").unwrap();
+    assert_eq!(windows_1252.as_slice(), b"1234");
+
+    // Spaces not between digits are kept.
+    assert_eq!(normalize::<{ STANDARD }>("\u{a0}1"), Some("\u{a0}1".as_bytes().to_vec()));
+    assert_eq!(normalize::<{ STANDARD }>("1\u{a0}"), Some("1\u{a0}".as_bytes().to_vec()));
+    assert_eq!(
+        normalize::<{ STANDARD }>("1\u{a0}\u{a0}2"),
+        Some("1\u{a0}\u{a0}2".as_bytes().to_vec())
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_separator_test() {
+    const FORMAT: u128 = lexical_util::format::NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build();
+    assert_eq!(normalize::<FORMAT>("1\u{a0}234"), Some(b"1_234".to_vec()));
+    assert_eq!(normalize::<FORMAT>("\u{a0}1\u{a0}"), Some(b"_1_".to_vec()));
+}
+
+#[test]
+fn index_test() {
+    let normalized = Normalized::new::<{ STANDARD }>("\u{2212}1\u{a0}234x".as_bytes()).unwrap();
+    assert_eq!(normalized.as_slice(), b"-1234x");
+    assert_eq!(normalized.original_index(0), 0);
+    assert_eq!(normalized.original_index(1), 3);
+    assert_eq!(normalized.original_index(2), 6);
+    assert_eq!(normalized.original_index(5), 9);
+    assert_eq!(normalized.original_index(6), 10);
+    assert_eq!(normalized.original_index(100), 10);
+    assert_eq!(normalized.map_error(Error::InvalidDigit(5)), Error::InvalidDigit(9));
+    assert_eq!(normalized.map_error(Error::InvalidDecimalPoint), Error::InvalidDecimalPoint);
+}
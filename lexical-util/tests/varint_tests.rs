@@ -0,0 +1,129 @@
+#![cfg(feature = "varint")]
+
+use lexical_util::error::Error;
+use lexical_util::varint::{self, VARINT_BUFFER_SIZE};
+use proptest::prelude::*;
+
+fn encode<F: lexical_util::num::Float>(float: F) -> Vec<u8> {
+    let mut buffer = [0u8; VARINT_BUFFER_SIZE];
+    let count = varint::encode_float(float, &mut buffer);
+    buffer[..count].to_vec()
+}
+
+#[test]
+fn encode_float_test() {
+    assert_eq!(encode(0.0f64), [0x00]);
+    assert_eq!(encode(-0.0f64), [0x01]);
+    assert_eq!(encode(f64::INFINITY), [0x04]);
+    assert_eq!(encode(f64::NEG_INFINITY), [0x05]);
+    assert_eq!(encode(f64::NAN), [0x06]);
+    assert_eq!(encode(-f64::NAN), [0x06]);
+    assert_eq!(encode(1.0f64), [0x02, 0x00, 0x01]);
+    assert_eq!(encode(-1.5f64), [0x03, 0x01, 0x03]);
+    assert_eq!(encode(8.0f64), [0x02, 0x06, 0x01]);
+    assert_eq!(encode(5e-324f64), [0x02, 0xE3, 0x10, 0x01]);
+    assert!(encode(f64::MAX).len() <= VARINT_BUFFER_SIZE);
+
+    // The encoding doesn't depend on the float type.
+    assert_eq!(encode(1.5f32), encode(1.5f64));
+    assert_eq!(encode(f32::MAX), encode(f32::MAX as f64));
+    assert_eq!(encode(-1e-45f32), encode(-1e-45f32 as f64));
+}
+
+#[test]
+#[should_panic]
+fn encode_float_small_buffer_test() {
+    let mut buffer = [0u8; 2];
+    varint::encode_float(1.5f64, &mut buffer);
+}
+
+#[test]
+fn try_encode_float_test() {
+    let mut buffer = [0u8; 2];
+    assert_eq!(varint::try_encode_float(1.5f64, &mut buffer), Err(Error::BufferTooSmall(3)));
+    assert_eq!(buffer, [0, 0]);
+    assert_eq!(varint::try_encode_float(-0.0f64, &mut buffer), Ok(1));
+    assert_eq!(buffer, [0x01, 0]);
+    assert_eq!(varint::try_encode_float(1.5f64, &mut []), Err(Error::BufferTooSmall(3)));
+    assert_eq!(varint::try_encode_float(f64::NAN, &mut []), Err(Error::BufferTooSmall(1)));
+
+    let mut buffer = [0u8; 3];
+    assert_eq!(varint::try_encode_float(-1.5f64, &mut buffer), Ok(3));
+    assert_eq!(buffer, [0x03, 0x01, 0x03]);
+}
+
+#[test]
+fn decode_float_test() {
+    assert_eq!(varint::decode_float::<f64>(&[0x00]), Ok((0.0, 1)));
+    assert!(varint::decode_float::<f64>(&[0x01]).unwrap().0.is_sign_negative());
+    assert_eq!(varint::decode_float::<f64>(&[0x04]), Ok((f64::INFINITY, 1)));
+    assert_eq!(varint::decode_float::<f32>(&[0x05]), Ok((f32::NEG_INFINITY, 1)));
+    assert!(varint::decode_float::<f64>(&[0x06]).unwrap().0.is_nan());
+    assert_eq!(varint::decode_float::<f64>(&[0x03, 0x01, 0x03]), Ok((-1.5, 3)));
+    assert_eq!(varint::decode_float::<f64>(&[0x02, 0xE3, 0x10, 0x01]), Ok((5e-324, 4)));
+
+    // Trailing bytes aren't read, so floats can be concatenated.
+    let bytes = [0x02, 0x06, 0x01, 0x03, 0x01, 0x03];
+    assert_eq!(varint::decode_float::<f32>(&bytes), Ok((8.0, 3)));
+    assert_eq!(varint::decode_float::<f32>(&bytes[3..]), Ok((-1.5, 3)));
+
+    // Values from a wider float only decode if they're exact.
+    assert_eq!(varint::decode_float::<f32>(&encode(1e-45f32 as f64)), Ok((1e-45, 4)));
+    assert_eq!(varint::decode_float::<f32>(&encode(1e300f64)), Err(Error::Overflow(11)));
+    assert_eq!(varint::decode_float::<f32>(&encode(0.1f64)), Err(Error::Underflow(10)));
+    assert_eq!(varint::decode_float::<f32>(&encode(5e-324f64)), Err(Error::Underflow(4)));
+}
+
+#[test]
+fn decode_float_error_test() {
+    assert_eq!(varint::decode_float::<f64>(&[]), Err(Error::Empty(0)));
+    assert_eq!(varint::decode_float::<f64>(&[0x02]), Err(Error::Empty(1)));
+    assert_eq!(varint::decode_float::<f64>(&[0x02, 0x00]), Err(Error::Empty(2)));
+    assert_eq!(varint::decode_float::<f64>(&[0x02, 0x00, 0x81]), Err(Error::Empty(3)));
+    assert_eq!(varint::decode_float::<f64>(&[0x07]), Err(Error::InvalidDigit(0)));
+    assert_eq!(varint::decode_float::<f64>(&[0x08]), Err(Error::InvalidDigit(0)));
+
+    // Non-canonical encodings are invalid.
+    assert_eq!(varint::decode_float::<f64>(&[0x02, 0x00, 0x02]), Err(Error::InvalidDigit(2)));
+    assert_eq!(varint::decode_float::<f64>(&[0x02, 0x80, 0x00, 0x01]), Err(Error::InvalidDigit(2)));
+    assert_eq!(
+        varint::decode_float::<f64>(&[0x02, 0x80, 0x80, 0x80, 0x80, 0x10, 0x01]),
+        Err(Error::InvalidDigit(1))
+    );
+    let mut overlong = vec![0x02, 0x00];
+    overlong.extend_from_slice(&[0xFF; 10]);
+    overlong.push(0x01);
+    assert_eq!(varint::decode_float::<f64>(&overlong), Err(Error::InvalidDigit(11)));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_roundtrip_proptest(bits in u32::MIN..u32::MAX) {
+        let float = f32::from_bits(bits);
+        let bytes = encode(float);
+        let (value, count) = varint::decode_float::<f32>(&bytes).unwrap();
+        prop_assert_eq!(count, bytes.len());
+        if float.is_nan() {
+            prop_assert!(value.is_nan());
+        } else {
+            prop_assert_eq!(value.to_bits(), bits);
+            let (wide, _) = varint::decode_float::<f64>(&bytes).unwrap();
+            prop_assert_eq!(wide.to_bits(), (float as f64).to_bits());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_roundtrip_proptest(bits in u64::MIN..u64::MAX) {
+        let float = f64::from_bits(bits);
+        let bytes = encode(float);
+        let (value, count) = varint::decode_float::<f64>(&bytes).unwrap();
+        prop_assert_eq!(count, bytes.len());
+        if float.is_nan() {
+            prop_assert!(value.is_nan());
+        } else {
+            prop_assert_eq!(value.to_bits(), bits);
+        }
+    }
+}
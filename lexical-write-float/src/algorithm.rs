@@ -32,6 +32,7 @@ use crate::shared;
 use crate::table::*;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
+pub use lexical_util::exponent::{floor_log10_pow2, floor_log2_pow10};
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::{NumberFormat, STANDARD};
@@ -135,7 +136,7 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -898,22 +899,6 @@ pub const fn floor_log5_pow2(q: i32) -> i32 {
     q.wrapping_mul(225799) >> 19
 }
 
-/// Calculate `x * log10(2)` quickly.
-/// Generated by `etc/log.py`.
-/// Only needs to be valid for values from `[-1700, 1700]`
-#[inline(always)]
-pub const fn floor_log10_pow2(q: i32) -> i32 {
-    q.wrapping_mul(315653) >> 20
-}
-
-/// Calculate `x * log2(10)` quickly.
-/// Generated by `etc/log.py`.
-/// Only needs to be valid for values from `[-1233, 1233]`
-#[inline(always)]
-pub const fn floor_log2_pow10(q: i32) -> i32 {
-    q.wrapping_mul(1741647) >> 19
-}
-
 /// Calculate `x * log5(2) - log5(3)` quickly.
 /// Generated by `etc/log.py`.
 /// Only needs to be valid for values from `[-2427, 2427]`
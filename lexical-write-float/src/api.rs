@@ -10,6 +10,7 @@ use lexical_util::constants::FormattedSize;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::num::Float;
 use lexical_util::options::WriteOptions;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
@@ -23,6 +24,18 @@ where
     len >= size
 }
 
+/// Check if a special value can be written.
+#[inline]
+fn check_special<T: Float>(value: T, options: &Options) -> bool {
+    if value.is_nan() {
+        options.nan_string().is_some()
+    } else if value.is_inf() {
+        options.inf_string().is_some()
+    } else {
+        true
+    }
+}
+
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
@@ -51,6 +64,18 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe since `check_buffer::<STANDARD>(bytes.len(), &options)` passes.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8])
+                -> Option<&'a mut [u8]>
+            {
+                if check_buffer::<Self, { STANDARD }>(bytes.len(), &DEFAULT_OPTIONS) {
+                    // SAFETY: safe since `check_buffer::<STANDARD>(bytes.len(), &options)` passes.
+                    Some(unsafe { self.to_lexical_unchecked(bytes) })
+                } else {
+                    None
+                }
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -63,7 +88,7 @@ macro_rules! float_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                assert!(NumberFormat::<{ FORMAT }> {}.is_valid_float());
                 assert!(is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()));
                 debug_assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), &options));
                 // SAFETY: safe if `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
@@ -84,6 +109,32 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe since `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Option<&'a mut [u8]>
+            {
+                let is_valid = NumberFormat::<{ FORMAT }> {}.is_valid_float()
+                    && is_valid_options_punctuation(
+                        FORMAT,
+                        options.exponent(),
+                        options.decimal_point(),
+                    )
+                    && check_buffer::<Self, { FORMAT }>(bytes.len(), &options)
+                    && check_special(self, &options);
+                if !is_valid {
+                    return None;
+                }
+                // SAFETY: safe since `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
+                // This does not use the unchecked writer, since it panics on invalid options.
+                unsafe {
+                    let len = self.try_write_float::<{ FORMAT }>(bytes, &options)?;
+                    Some(&mut index_unchecked_mut!(bytes[..len]))
+                }
+            }
         }
     )*)
 }
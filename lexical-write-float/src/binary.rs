@@ -175,9 +175,7 @@ where
     // Now, write our scientific notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
     // SAFETY: safe if the buffer is large enough to hold the maximum written float.
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
@@ -107,7 +107,12 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
     sci_exp: i32,
     options: &Options,
 ) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
+    // Rounding to the maximum significant digits may leave trailing zeros.
+    debug_assert!(
+        rtrim_char_count(&digits[..digit_count], b'0') == 0
+            || digit_count == 1
+            || options.max_significant_digits().is_some()
+    );
     debug_assert!(digit_count <= 20);
 
     // Config options
@@ -155,7 +160,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store the largest float.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -175,7 +180,11 @@ pub unsafe fn write_float_negative_exponent<const FORMAT: u128>(
     sci_exp: i32,
     options: &Options,
 ) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0);
+    // Rounding to the maximum significant digits may leave trailing zeros.
+    debug_assert!(
+        rtrim_char_count(&digits[..digit_count], b'0') == 0
+            || options.max_significant_digits().is_some()
+    );
     debug_assert!(digit_count <= 20);
     debug_assert!(sci_exp < 0);
 
@@ -233,7 +242,12 @@ pub unsafe fn write_float_positive_exponent<const FORMAT: u128>(
     sci_exp: i32,
     options: &Options,
 ) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
+    // Rounding to the maximum significant digits may leave trailing zeros.
+    debug_assert!(
+        rtrim_char_count(&digits[..digit_count], b'0') == 0
+            || digit_count == 1
+            || options.max_significant_digits().is_some()
+    );
     debug_assert!(digit_count <= 20);
     debug_assert!(sci_exp >= 0);
 
@@ -0,0 +1,92 @@
+//! Write custom float formats to decimal strings, with the shortest representation.
+//!
+//! Every value of the format, and the value halfway between two adjacent
+//! values, is exactly representable as an `f64`, so the float is written
+//! as an `f64` with the fewest significant digits that parse back to the
+//! same value. A candidate that parses to a tie between two values is
+//! rejected, since the digits alone could not break the tie.
+
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::Options;
+use core::cmp::Ordering;
+use core::num;
+use core::str;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::custom_float::{self, LexicalFloat};
+use lexical_util::format::{NumberFormat, STANDARD};
+
+/// Maximum number of significant digits to uniquely identify an `f64`.
+const MAX_DIGITS: usize = 17;
+
+/// Get the fewest significant digits of a float that parse back to a
+/// value accepted by `is_roundtrip`.
+pub(crate) fn shortest_digits(
+    value: f64,
+    is_roundtrip: impl Fn(f64) -> bool,
+) -> Option<num::NonZeroUsize> {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    for count in 1..=MAX_DIGITS {
+        let digits = num::NonZeroUsize::new(count);
+        let builder = Options::builder().max_significant_digits(digits);
+        // SAFETY: safe, since the default special strings are valid.
+        let options = unsafe { builder.build_unchecked() };
+        let bytes = value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+        // The standard format is always valid UTF-8, and a valid float.
+        let parsed = str::from_utf8(bytes).ok().and_then(|x| x.parse::<f64>().ok());
+        if let Some(parsed) = parsed {
+            if is_roundtrip(parsed) {
+                return digits;
+            }
+        }
+    }
+    None
+}
+
+/// Write a finite, non-zero float with at most the shortest digits.
+pub(crate) fn write_shortest<'a, const FORMAT: u128>(
+    value: f64,
+    shortest: Option<num::NonZeroUsize>,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let digits = match (shortest, options.max_significant_digits()) {
+        (Some(shortest), Some(max)) => Some(shortest.min(max)),
+        (shortest, max) => shortest.or(max),
+    };
+    let builder = options.rebuild().max_significant_digits(digits);
+    // SAFETY: safe, since only the significant digits of valid options changed.
+    let options = unsafe { builder.build_unchecked() };
+    value.to_lexical_with_options::<FORMAT>(bytes, &options)
+}
+
+/// Write a custom float to a decimal string, returning the written bytes.
+///
+/// The float is written with the fewest significant digits that parse
+/// back to the same value, unless `options` limits the significant
+/// digits further. Special values are written like an `f64`. The buffer
+/// must fit an `f64`.
+///
+/// # Panics
+///
+/// Panics if the format is not decimal, or the buffer is too small.
+pub fn write_custom<'a, F: LexicalFloat, const FORMAT: u128>(
+    float: F,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.mantissa_radix() == 10 && format.exponent_base() == 10);
+
+    let value = custom_float::to_f64(float);
+    if !value.is_finite() || value == 0.0 {
+        return value.to_lexical_with_options::<FORMAT>(bytes, options);
+    }
+
+    let shortest = shortest_digits(value, |parsed| {
+        !custom_float::is_tie::<F>(parsed)
+            && custom_float::from_f64::<F>(parsed, Ordering::Equal).to_bits() == float.to_bits()
+    });
+    write_shortest::<FORMAT>(value, shortest, bytes, options)
+}
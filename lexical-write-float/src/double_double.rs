@@ -0,0 +1,389 @@
+//! Write double-double numbers to decimal strings, with the shortest representation.
+//!
+//! A double-double number is the unevaluated sum of two `f64`s, `hi + lo`.
+//! A string parses back to the same pair if the high part is its nearest
+//! `f64`, and the low part is the nearest `f64` to the remainder, which
+//! is true for every number in an interval around `hi + lo`. The bounds
+//! of the interval are calculated exactly, scaled to big decimal integers,
+//! and the shortest digits in the interval closest to `hi + lo` are written.
+
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::Options;
+use crate::shared;
+use core::cmp::Ordering;
+use lexical_util::format::NumberFormat;
+
+/// Maximum number of digits in the scaled bounds.
+///
+/// The bounds are scaled to integers with a resolution of `2^-1076`,
+/// so they have at most `309 + 1076` digits.
+const MAX_DIGITS: usize = 1400;
+
+/// Size of a buffer that can fit any double-double number.
+///
+/// This fits the significant digits, and the zeros written by the
+/// largest exponent breaks for an `f64`.
+pub const DOUBLE_DOUBLE_BUFFER_SIZE: usize = 2048;
+
+/// A big, unsigned integer, stored as decimal digits in little-endian order.
+#[derive(Clone)]
+pub(crate) struct Digits {
+    /// The digits, where only the first `len` digits are used.
+    data: [u8; MAX_DIGITS],
+    /// The number of digits, without leading zeros.
+    len: usize,
+}
+
+impl Digits {
+    /// Create an integer from a small value.
+    pub(crate) fn from_u64(mut value: u64) -> Self {
+        let mut digits = Self {
+            data: [0; MAX_DIGITS],
+            len: 0,
+        };
+        while value != 0 {
+            digits.data[digits.len] = (value % 10) as u8;
+            digits.len += 1;
+            value /= 10;
+        }
+        digits
+    }
+
+    /// Get the used digits.
+    #[inline(always)]
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Multiply by a small value, which must be at most `10^9`.
+    fn mul_small(&mut self, y: u64) {
+        let mut carry = 0;
+        for digit in self.data[..self.len].iter_mut() {
+            let value = *digit as u64 * y + carry;
+            *digit = (value % 10) as u8;
+            carry = value / 10;
+        }
+        while carry != 0 {
+            self.data[self.len] = (carry % 10) as u8;
+            self.len += 1;
+            carry /= 10;
+        }
+    }
+
+    /// Multiply by a power of a small base.
+    pub(crate) fn pow(&mut self, base: u64, mut exp: u32, step: u32) {
+        let large = base.pow(step);
+        while exp >= step {
+            self.mul_small(large);
+            exp -= step;
+        }
+        self.mul_small(base.pow(exp));
+    }
+
+    /// Add an integer.
+    fn add(&mut self, y: &Self) {
+        let len = self.len.max(y.len);
+        let mut carry = 0;
+        for index in 0..len {
+            let value = self.data[index] + y.data[index] + carry;
+            self.data[index] = value % 10;
+            carry = value / 10;
+        }
+        self.len = len;
+        if carry != 0 {
+            self.data[len] = carry;
+            self.len += 1;
+        }
+    }
+
+    /// Subtract an integer, which must be at most `self`.
+    fn sub(&mut self, y: &Self) {
+        debug_assert!(compare(self.as_slice(), y.as_slice()) != Ordering::Less);
+        let mut borrow = 0;
+        for index in 0..self.len {
+            let value = self.data[index] as i8 - y.data[index] as i8 - borrow;
+            borrow = (value < 0) as i8;
+            self.data[index] = (value + 10 * borrow) as u8;
+        }
+        self.normalize();
+    }
+
+    /// Remove leading zeros.
+    fn normalize(&mut self) {
+        while self.len > 0 && self.data[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    /// Get the integer divided by `10^count`, rounded down.
+    fn shr(&self, count: usize) -> Self {
+        let mut result = Self::from_u64(0);
+        if count < self.len {
+            result.len = self.len - count;
+            result.data[..result.len].copy_from_slice(&self.data[count..self.len]);
+        }
+        result
+    }
+
+    /// Determine if the integer is divisible by `10^count`.
+    fn is_divisible(&self, count: usize) -> bool {
+        self.data[..count.min(self.len)].iter().all(|&x| x == 0)
+    }
+}
+
+/// Compare two normalized integers.
+fn compare(x: &[u8], y: &[u8]) -> Ordering {
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+/// A bound of the interval, and if it is included.
+struct Bound {
+    value: Digits,
+    is_inclusive: bool,
+}
+
+/// Get the integer mantissa and binary exponent of the magnitude of a float.
+pub(crate) fn decompose(value: f64) -> (u64, i64) {
+    let float_bits = value.to_bits();
+    let biased = ((float_bits >> 52) & 0x7FF) as i64;
+    let fraction = float_bits & ((1 << 52) - 1);
+    if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased - 1075)
+    }
+}
+
+/// Get the binary exponents of the gaps below and above the magnitude.
+fn gaps(value: f64) -> (i64, i64) {
+    let (mant, exp) = decompose(value);
+    if mant == 1 << 52 && exp > -1074 {
+        (exp - 1, exp)
+    } else {
+        (exp, exp)
+    }
+}
+
+/// Determine if the low part is at most half the gap from the high part
+/// to the adjacent float in its direction, so the pair can be parsed.
+fn is_normalized(hi: f64, lo: f64) -> bool {
+    if lo == 0.0 {
+        return true;
+    } else if !hi.is_finite() || !lo.is_finite() {
+        return false;
+    }
+    let (below, above) = gaps(hi);
+    let gap = if lo.is_sign_negative() == hi.is_sign_negative() {
+        above
+    } else {
+        below
+    };
+    let (lo_mant, lo_exp) = decompose(lo);
+    let shift = gap - 1 - lo_exp;
+    shift >= 64 || (shift >= 0 && lo_mant <= 1 << shift)
+}
+
+/// Converts dyadic values to integers, scaled by a common decimal exponent.
+struct Scale {
+    /// Binary exponent of the smallest value to represent.
+    min_exp: i64,
+}
+
+impl Scale {
+    /// Get the decimal exponent of the scaled integers.
+    #[inline(always)]
+    fn exponent(&self) -> i64 {
+        self.min_exp.min(0)
+    }
+
+    /// Get `mant * 2^exp` as a scaled integer.
+    fn scale(&self, mant: u64, exp: i64) -> Digits {
+        let mut digits = Digits::from_u64(mant);
+        digits.pow(2, (exp - self.exponent()) as u32, 29);
+        digits.pow(5, (-self.exponent()) as u32, 12);
+        digits
+    }
+}
+
+/// Get the interval of numbers that parse to the normalized pair, and
+/// the scaled value of the pair, where `hi` is positive.
+fn interval(hi: f64, lo: f64) -> (Scale, Digits, Bound, Bound) {
+    let (hi_mant, hi_exp) = decompose(hi);
+    let (lo_mant, lo_exp) = decompose(lo);
+    let scale = Scale {
+        min_exp: hi_exp.min(lo_exp) - 2,
+    };
+
+    // Get the gaps to the adjacent floats, in the direction of the value.
+    let (hi_below, hi_above) = gaps(hi);
+    let (lo_below, lo_above) = match gaps(lo) {
+        (smaller, larger) if lo.is_sign_negative() => (larger, smaller),
+        gaps => gaps,
+    };
+    let half_gap = |exp: i64| scale.scale(1, exp - 1);
+
+    // The remainder is rounded to the low part, so the number can be
+    // anywhere within half a gap of `hi + lo`.
+    let hi_value = scale.scale(hi_mant, hi_exp);
+    let mut value = hi_value.clone();
+    let lo_value = scale.scale(lo_mant, lo_exp);
+    if lo.is_sign_negative() {
+        value.sub(&lo_value);
+    } else {
+        value.add(&lo_value);
+    }
+    let lo_inclusive = lo.to_bits() & 1 == 0;
+    let mut lower = value.clone();
+    lower.sub(&half_gap(lo_below));
+    let mut upper = value.clone();
+    upper.add(&half_gap(lo_above));
+
+    // The number must also round to the high part, which only limits the
+    // interval if the remainder is exactly half a gap.
+    let hi_inclusive = hi.to_bits() & 1 == 0;
+    let mut hi_lower = hi_value.clone();
+    hi_lower.sub(&half_gap(hi_below));
+    let mut hi_upper = hi_value;
+    hi_upper.add(&half_gap(hi_above));
+
+    let lower = match compare(lower.as_slice(), hi_lower.as_slice()) {
+        Ordering::Less => Bound {
+            value: hi_lower,
+            is_inclusive: hi_inclusive,
+        },
+        Ordering::Equal => Bound {
+            value: lower,
+            is_inclusive: lo_inclusive && hi_inclusive,
+        },
+        Ordering::Greater => Bound {
+            value: lower,
+            is_inclusive: lo_inclusive,
+        },
+    };
+    let upper = match compare(upper.as_slice(), hi_upper.as_slice()) {
+        Ordering::Greater => Bound {
+            value: hi_upper,
+            is_inclusive: hi_inclusive,
+        },
+        Ordering::Equal => Bound {
+            value: upper,
+            is_inclusive: lo_inclusive && hi_inclusive,
+        },
+        Ordering::Less => Bound {
+            value: upper,
+            is_inclusive: lo_inclusive,
+        },
+    };
+    (scale, value, lower, upper)
+}
+
+/// Get the first and last multiples of `10^count` in the interval, divided by `10^count`.
+fn multiples(lower: &Bound, upper: &Bound, count: usize) -> Option<(Digits, Digits)> {
+    let mut first = lower.value.shr(count);
+    if !lower.is_inclusive || !lower.value.is_divisible(count) {
+        first.add(&Digits::from_u64(1));
+    }
+    let mut last = upper.value.shr(count);
+    if !upper.is_inclusive && upper.value.is_divisible(count) {
+        if last.len == 0 {
+            return None;
+        }
+        last.sub(&Digits::from_u64(1));
+    }
+    match compare(first.as_slice(), last.as_slice()) {
+        Ordering::Greater => None,
+        _ => Some((first, last)),
+    }
+}
+
+/// Get the shortest digits in the interval closest to the value, and the
+/// decimal exponent of the last digit.
+fn shortest(value: &Digits, lower: &Bound, upper: &Bound) -> (Digits, usize) {
+    // Find the largest power of 10 with a multiple in the interval.
+    let mut count = upper.value.len;
+    let (first, last) = loop {
+        if let Some(multiples) = multiples(lower, upper, count) {
+            break multiples;
+        }
+        count -= 1;
+    };
+
+    // Round the value to the nearest multiple, with ties to even.
+    let mut nearest = value.shr(count);
+    let is_above = match count.checked_sub(1).and_then(|x| value.data.get(x)) {
+        Some(&digit) if digit > 5 => true,
+        Some(&5) => !value.is_divisible(count - 1) || nearest.data[0] % 2 == 1,
+        _ => false,
+    };
+    if is_above {
+        nearest.add(&Digits::from_u64(1));
+    }
+    if compare(nearest.as_slice(), first.as_slice()) == Ordering::Less {
+        (first, count)
+    } else if compare(nearest.as_slice(), last.as_slice()) == Ordering::Greater {
+        (last, count)
+    } else {
+        (nearest, count)
+    }
+}
+
+/// Write a double-double number to a decimal string, returning the written bytes.
+///
+/// The number is written with the fewest significant digits that parse
+/// back to the same pair, closest to `hi + lo`. If the low part is more
+/// than half the gap to the float adjacent to the high part, the pair is
+/// normalized first, so the high part is the nearest `f64` to the sum.
+/// Special values and zeros are written like an `f64`, and the
+/// significant digit options are ignored.
+///
+/// # Panics
+///
+/// Panics if the format is not decimal, or the buffer is too small,
+/// which is never the case for [`DOUBLE_DOUBLE_BUFFER_SIZE`].
+pub fn write_double_double<'a, const FORMAT: u128>(
+    hi: f64,
+    lo: f64,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.mantissa_radix() == 10 && format.exponent_base() == 10);
+
+    let (hi, lo) = if is_normalized(hi, lo) {
+        (hi, lo)
+    } else {
+        // Normalize the pair, with an exact two-sum.
+        let sum = hi + lo;
+        let hi_part = sum - lo;
+        let lo_part = sum - hi_part;
+        (sum, (hi - hi_part) + (lo - lo_part))
+    };
+    if !hi.is_finite() || !lo.is_finite() || hi == 0.0 {
+        return hi.to_lexical_with_options::<FORMAT>(bytes, options);
+    }
+
+    let mut cursor = 0;
+    let lo = if hi < 0.0 {
+        bytes[0] = b'-';
+        cursor += 1;
+        -lo
+    } else {
+        lo
+    };
+    let hi = if hi < 0.0 {
+        -hi
+    } else {
+        hi
+    };
+    let (scale, value, lower, upper) = interval(hi, lo);
+    let (digits, count) = shortest(&value, &lower, &upper);
+    let digit_count = digits.len;
+    let sci_exp = (scale.exponent() + (count + digit_count) as i64 - 1) as i32;
+    let digits = digits.data[..digit_count].iter().rev().map(|&x| x + b'0');
+    let cursor =
+        shared::write_digits::<_, FORMAT>(bytes, cursor, digits, digit_count, sci_exp, options);
+
+    &mut bytes[..cursor]
+}
@@ -198,9 +198,7 @@ where
     // Now, write our scientific notation.
     // SAFETY: safe if bytes is large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
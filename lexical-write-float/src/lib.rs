@@ -36,6 +36,8 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `fuzz` - Promote debug assertions to checks, for fuzzing.
+//! * `serde` - Serialize and deserialize the options.
 //!
 //! # Note
 //!
@@ -68,6 +70,20 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Promote debug assertions to checks when fuzzing, so internal invariants
+// are verified in optimized builds. These shadow the standard macros.
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert {
+    ($($arg:tt)*) => (assert!($($arg)*));
+}
+
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert_eq {
+    ($($arg:tt)*) => (assert_eq!($($arg)*));
+}
+
 #[macro_use]
 mod index;
 #[macro_use]
@@ -76,12 +92,19 @@ mod shared;
 pub mod algorithm;
 pub mod binary;
 pub mod compact;
+pub mod custom;
+pub mod double_double;
 pub mod float;
 pub mod hex;
 pub mod options;
+pub mod posit;
+pub mod printf;
 pub mod radix;
+pub mod small;
 pub mod table;
+pub mod width;
 pub mod write;
+pub mod x87;
 
 mod api;
 mod table_dragonbox;
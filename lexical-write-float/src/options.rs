@@ -21,6 +21,8 @@ const_assert!(mem::size_of::<OptionI32>() == mem::size_of::<i32>());
 
 /// Enumeration for how to round floats with precision control.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "lowercase"))]
 pub enum RoundMode {
     /// Round to the nearest float string with the given number of significant digits.
     Round,
@@ -32,6 +34,9 @@ pub enum RoundMode {
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 const_assert!(MAX_SPECIAL_STRING_LENGTH < f32::FORMATTED_SIZE_DECIMAL);
 
+/// Maximum number of exponent digits to pad to.
+const MAX_EXPONENT_DIGITS: usize = 20;
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OptionsBuilder {
@@ -65,6 +70,9 @@ pub struct OptionsBuilder {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Minimum number of exponent digits, padded with leading zeros.
+    /// If not set, the exponent is not padded.
+    min_exponent_digits: OptionUsize,
 }
 
 impl OptionsBuilder {
@@ -83,6 +91,7 @@ impl OptionsBuilder {
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
+            min_exponent_digits: None,
         }
     }
 
@@ -148,6 +157,12 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the minimum number of exponent digits.
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -220,6 +235,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the minimum number of exponent digits.
+    ///
+    /// Shorter exponents are padded with leading zeros, so `1e5` is
+    /// written as `1e05` with 2 digits. This is at most 20 digits.
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: OptionUsize) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -276,6 +301,8 @@ impl OptionsBuilder {
             false
         } else if !self.inf_str_is_valid() {
             false
+        } else if unwrap_or_zero_usize(self.min_exponent_digits) > MAX_EXPONENT_DIGITS {
+            false
         } else {
             true
         }
@@ -301,6 +328,7 @@ impl OptionsBuilder {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            min_exponent_digits: self.min_exponent_digits,
         }
     }
 
@@ -342,6 +370,8 @@ impl OptionsBuilder {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if unwrap_or_zero_usize(self.min_exponent_digits) > MAX_EXPONENT_DIGITS {
+            Err(Error::InvalidExponentDigits)
         } else {
             // SAFETY: always safe, since it must be valid.
             Ok(unsafe { self.build_unchecked() })
@@ -401,6 +431,9 @@ pub struct Options {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Minimum number of exponent digits, padded with leading zeros.
+    /// If not set, the exponent is not padded.
+    min_exponent_digits: OptionUsize,
 }
 
 impl Options {
@@ -413,6 +446,12 @@ impl Options {
         unsafe { Self::builder().build_unchecked() }
     }
 
+    /// Get the pre-defined options with a name from [`PRESETS`], like `"JSON"`.
+    #[inline]
+    pub fn preset(name: &str) -> Option<Self> {
+        PRESETS.iter().find(|preset| preset.0 == name).map(|preset| preset.1.clone())
+    }
+
     /// Create the default options for a given radix.
     #[inline(always)]
     #[cfg(feature = "power-of-two")]
@@ -496,6 +535,12 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the minimum number of exponent digits.
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -609,6 +654,19 @@ impl Options {
         self.inf_string = inf_string
     }
 
+    /// Set the minimum number of exponent digits.
+    /// Unsafe, use the builder API for option validation.
+    ///
+    /// # Safety
+    ///
+    /// Unsafe if `min_exponent_digits > 20`. This might cause an exponent
+    /// larger than the buffer length to be written, causing a buffer
+    /// overflow, potentially a severe security vulnerability.
+    #[inline(always)]
+    pub unsafe fn set_min_exponent_digits(&mut self, min_exponent_digits: OptionUsize) {
+        self.min_exponent_digits = min_exponent_digits
+    }
+
     // BUILDERS
 
     /// Get WriteFloatOptionsBuilder as a static function.
@@ -631,6 +689,7 @@ impl Options {
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            min_exponent_digits: self.min_exponent_digits,
         }
     }
 }
@@ -709,6 +768,11 @@ impl WriteOptions for Options {
         };
         count += digits;
 
+        // Add any padding for the exponent digits.
+        if let Some(min_digits) = self.min_exponent_digits() {
+            count += min_digits.get();
+        }
+
         count
     }
 }
@@ -1386,3 +1450,183 @@ pub const MONGODB: Options = unsafe {
         .build_unchecked()
 };
 const_assert!(MONGODB.is_valid());
+
+/// Pre-defined options, with the name of each constant.
+///
+/// Options can be loaded by name with [`Options::preset`].
+pub const PRESETS: [(&str, Options); 66] = [
+    ("STANDARD", STANDARD),
+    ("DECIMAL_COMMA", DECIMAL_COMMA),
+    ("HEX_FLOAT", HEX_FLOAT),
+    ("CARAT_EXPONENT", CARAT_EXPONENT),
+    ("RUST_LITERAL", RUST_LITERAL),
+    ("PYTHON_LITERAL", PYTHON_LITERAL),
+    ("CXX_LITERAL", CXX_LITERAL),
+    ("C_LITERAL", C_LITERAL),
+    ("RUBY_LITERAL", RUBY_LITERAL),
+    ("RUBY_STRING", RUBY_STRING),
+    ("SWIFT_LITERAL", SWIFT_LITERAL),
+    ("GO_LITERAL", GO_LITERAL),
+    ("HASKELL_LITERAL", HASKELL_LITERAL),
+    ("HASKELL_STRING", HASKELL_STRING),
+    ("JAVASCRIPT_LITERAL", JAVASCRIPT_LITERAL),
+    ("JAVASCRIPT_STRING", JAVASCRIPT_STRING),
+    ("PERL_LITERAL", PERL_LITERAL),
+    ("PHP_LITERAL", PHP_LITERAL),
+    ("JAVA_LITERAL", JAVA_LITERAL),
+    ("JAVA_STRING", JAVA_STRING),
+    ("R_LITERAL", R_LITERAL),
+    ("KOTLIN_LITERAL", KOTLIN_LITERAL),
+    ("KOTLIN_STRING", KOTLIN_STRING),
+    ("JULIA_LITERAL", JULIA_LITERAL),
+    ("CSHARP_LITERAL", CSHARP_LITERAL),
+    ("CSHARP_STRING", CSHARP_STRING),
+    ("KAWA_LITERAL", KAWA_LITERAL),
+    ("KAWA_STRING", KAWA_STRING),
+    ("GAMBITC_LITERAL", GAMBITC_LITERAL),
+    ("GAMBITC_STRING", GAMBITC_STRING),
+    ("GUILE_LITERAL", GUILE_LITERAL),
+    ("GUILE_STRING", GUILE_STRING),
+    ("CLOJURE_LITERAL", CLOJURE_LITERAL),
+    ("CLOJURE_STRING", CLOJURE_STRING),
+    ("ERLANG_LITERAL", ERLANG_LITERAL),
+    ("ERLANG_STRING", ERLANG_STRING),
+    ("ELM_LITERAL", ELM_LITERAL),
+    ("ELM_STRING", ELM_STRING),
+    ("SCALA_LITERAL", SCALA_LITERAL),
+    ("SCALA_STRING", SCALA_STRING),
+    ("ELIXIR_LITERAL", ELIXIR_LITERAL),
+    ("ELIXIR_STRING", ELIXIR_STRING),
+    ("FORTRAN_LITERAL", FORTRAN_LITERAL),
+    ("D_LITERAL", D_LITERAL),
+    ("COFFEESCRIPT_LITERAL", COFFEESCRIPT_LITERAL),
+    ("COFFEESCRIPT_STRING", COFFEESCRIPT_STRING),
+    ("COBOL_LITERAL", COBOL_LITERAL),
+    ("COBOL_STRING", COBOL_STRING),
+    ("FSHARP_LITERAL", FSHARP_LITERAL),
+    ("VB_LITERAL", VB_LITERAL),
+    ("VB_STRING", VB_STRING),
+    ("OCAML_LITERAL", OCAML_LITERAL),
+    ("OBJECTIVEC_LITERAL", OBJECTIVEC_LITERAL),
+    ("OBJECTIVEC_STRING", OBJECTIVEC_STRING),
+    ("REASONML_LITERAL", REASONML_LITERAL),
+    ("MATLAB_LITERAL", MATLAB_LITERAL),
+    ("ZIG_LITERAL", ZIG_LITERAL),
+    ("SAGE_LITERAL", SAGE_LITERAL),
+    ("JSON", JSON),
+    ("TOML", TOML),
+    ("YAML", YAML),
+    ("XML", XML),
+    ("SQLITE", SQLITE),
+    ("POSTGRESQL", POSTGRESQL),
+    ("MYSQL", MYSQL),
+    ("MONGODB", MONGODB),
+];
+
+// SERDE
+// -----
+
+// The options are deserialized as overrides for the options of the named
+// `preset`, or the standard options, using the shared utilities in
+// `lexical_util::config`.
+
+#[cfg(feature = "serde")]
+mod config {
+    use super::{OptionI32, OptionUsize, Options, RoundMode};
+    use lexical_util::config::{present, to_byte, to_static, to_str};
+    use serde_crate::de::{self, Deserializer, Unexpected};
+    use serde_crate::ser::{SerializeStruct, Serializer};
+    use serde_crate::{Deserialize, Serialize};
+    use std::string::String;
+
+    /// Overrides for the fields of preset options.
+    #[derive(Default, Deserialize)]
+    #[serde(crate = "serde_crate", default, deny_unknown_fields)]
+    struct Config {
+        preset: Option<String>,
+        #[serde(deserialize_with = "present")]
+        max_significant_digits: Option<OptionUsize>,
+        #[serde(deserialize_with = "present")]
+        min_significant_digits: Option<OptionUsize>,
+        #[serde(deserialize_with = "present")]
+        positive_exponent_break: Option<OptionI32>,
+        #[serde(deserialize_with = "present")]
+        negative_exponent_break: Option<OptionI32>,
+        round_mode: Option<RoundMode>,
+        trim_floats: Option<bool>,
+        exponent: Option<char>,
+        decimal_point: Option<char>,
+        #[serde(deserialize_with = "present")]
+        nan_string: Option<Option<String>>,
+        #[serde(deserialize_with = "present")]
+        inf_string: Option<Option<String>>,
+        #[serde(deserialize_with = "present")]
+        min_exponent_digits: Option<OptionUsize>,
+    }
+
+    impl Serialize for Options {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Options", 11)?;
+            state.serialize_field("max_significant_digits", &self.max_significant_digits())?;
+            state.serialize_field("min_significant_digits", &self.min_significant_digits())?;
+            state.serialize_field("positive_exponent_break", &self.positive_exponent_break())?;
+            state.serialize_field("negative_exponent_break", &self.negative_exponent_break())?;
+            state.serialize_field("round_mode", &self.round_mode())?;
+            state.serialize_field("trim_floats", &self.trim_floats())?;
+            state.serialize_field("exponent", &(self.exponent() as char))?;
+            state.serialize_field("decimal_point", &(self.decimal_point() as char))?;
+            state.serialize_field("nan_string", &to_str::<S::Error>(self.nan_string())?)?;
+            state.serialize_field("inf_string", &to_str::<S::Error>(self.inf_string())?)?;
+            state.serialize_field("min_exponent_digits", &self.min_exponent_digits())?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Options {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let config = Config::deserialize(deserializer)?;
+            let preset = match config.preset {
+                Some(name) => Options::preset(&name).ok_or_else(|| {
+                    de::Error::invalid_value(Unexpected::Str(&name), &"a name from `PRESETS`")
+                })?,
+                None => Options::new(),
+            };
+
+            let mut builder = preset.rebuild();
+            if let Some(max_significant_digits) = config.max_significant_digits {
+                builder = builder.max_significant_digits(max_significant_digits);
+            }
+            if let Some(min_significant_digits) = config.min_significant_digits {
+                builder = builder.min_significant_digits(min_significant_digits);
+            }
+            if let Some(positive_exponent_break) = config.positive_exponent_break {
+                builder = builder.positive_exponent_break(positive_exponent_break);
+            }
+            if let Some(negative_exponent_break) = config.negative_exponent_break {
+                builder = builder.negative_exponent_break(negative_exponent_break);
+            }
+            if let Some(round_mode) = config.round_mode {
+                builder = builder.round_mode(round_mode);
+            }
+            if let Some(trim_floats) = config.trim_floats {
+                builder = builder.trim_floats(trim_floats);
+            }
+            if let Some(exponent) = config.exponent {
+                builder = builder.exponent(to_byte(exponent)?);
+            }
+            if let Some(decimal_point) = config.decimal_point {
+                builder = builder.decimal_point(to_byte(decimal_point)?);
+            }
+            if let Some(nan_string) = config.nan_string {
+                builder = builder.nan_string(to_static(nan_string, preset.nan_string()));
+            }
+            if let Some(inf_string) = config.inf_string {
+                builder = builder.inf_string(to_static(inf_string, preset.inf_string()));
+            }
+            if let Some(min_exponent_digits) = config.min_exponent_digits {
+                builder = builder.min_exponent_digits(min_exponent_digits);
+            }
+            builder.build().map_err(de::Error::custom)
+        }
+    }
+}
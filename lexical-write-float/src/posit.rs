@@ -0,0 +1,46 @@
+//! Write posits to decimal strings, with the shortest representation.
+//!
+//! Every posit is exactly representable as an `f64`, as is the value
+//! halfway between two adjacent posits, so the posit is written as an
+//! `f64` with the fewest significant digits that parse back to the
+//! same posit. A candidate that parses to a tie between two posits is
+//! rejected, since the digits alone could not break the tie.
+
+#![cfg(feature = "posit")]
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::custom::{shortest_digits, write_shortest};
+use crate::options::Options;
+use core::cmp::Ordering;
+use lexical_util::format::NumberFormat;
+use lexical_util::posit::{self, Posit};
+
+/// Write a posit to a decimal string, returning the written bytes.
+///
+/// The posit is written with the fewest significant digits that parse
+/// back to the same posit, unless `options` limits the significant
+/// digits further. Zero is written as `0.0`, and NaR as the NaN string.
+/// The buffer must fit an `f64`.
+///
+/// # Panics
+///
+/// Panics if the format is not decimal, or the buffer is too small.
+pub fn write_posit<'a, P: Posit, const FORMAT: u128>(
+    posit: P,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.mantissa_radix() == 10 && format.exponent_base() == 10);
+
+    let value = posit::to_f64(posit);
+    if !value.is_finite() || value == 0.0 {
+        return value.to_lexical_with_options::<FORMAT>(bytes, options);
+    }
+
+    let shortest = shortest_digits(value, |parsed| {
+        !posit::is_tie::<P>(parsed) && posit::from_f64::<P>(parsed, Ordering::Equal) == posit
+    });
+    write_shortest::<FORMAT>(value, shortest, bytes, options)
+}
@@ -0,0 +1,345 @@
+//! Write floats like the `%g` conversion of C's `printf`, byte-for-byte.
+//!
+//! Golden files generated by C programs, typically with `%.17g`, use the
+//! exact decimal value of the float rounded to a fixed number of
+//! significant digits, and the exponent digits of the C runtime: glibc
+//! writes at least 2 exponent digits, like `1e+08`, and the Microsoft C
+//! runtime before Visual Studio 2015 writes at least 3, like `1e+008`.
+//! The exact digits are generated with big decimal integers, so the
+//! output matches for any precision.
+//!
+//! Hexadecimal floats, like the `%a` conversion, differ between C
+//! runtimes in the significand of subnormal floats, and in the number
+//! of hex digits written without a precision: glibc writes subnormals
+//! with a leading `0`, like `0x0.0000000000001p-1022`, while musl and
+//! the BSDs normalize them to a leading `1`, like `0x1p-1074`, and the
+//! Microsoft C runtime writes all 13 hex digits of an `f64`.
+
+#![doc(hidden)]
+
+use crate::double_double::{decompose, Digits};
+
+/// Maximum number of significant digits in the exact value of an `f64`.
+///
+/// Any further digits are zeros, which `%g` removes.
+const MAX_PRECISION: usize = 767;
+
+/// Size of a buffer that can fit any float written with any precision.
+///
+/// This fits the sign, the exact significant digits, and the leading
+/// zeros of a fractional number or the exponent.
+pub const PRINTF_BUFFER_SIZE: usize = 800;
+
+/// The C runtime to replicate the `printf` output of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrintfStyle {
+    /// glibc, and other C runtimes writing at least 2 exponent digits.
+    Glibc,
+    /// The Microsoft C runtime before Visual Studio 2015, which writes
+    /// at least 3 exponent digits.
+    Msvc,
+}
+
+impl PrintfStyle {
+    /// Get the minimum number of exponent digits.
+    #[inline(always)]
+    pub const fn exponent_digits(self) -> usize {
+        match self {
+            Self::Glibc => 2,
+            Self::Msvc => 3,
+        }
+    }
+}
+
+/// Get the exact significant digits of a positive, finite float, rounded
+/// to `precision` digits with ties to even, and without trailing zeros.
+/// Returns the number of digits, and the decimal exponent of the first digit.
+fn significant_digits(value: f64, precision: usize, digits: &mut [u8]) -> (usize, i32) {
+    // The float is `mant * 2^exp`, or `mant * 5^-exp * 10^exp`.
+    let (mant, exp) = decompose(value);
+    let mut exact = Digits::from_u64(mant);
+    if exp >= 0 {
+        exact.pow(2, exp as u32, 29);
+    } else {
+        exact.pow(5, (-exp) as u32, 12);
+    }
+    let exact = exact.as_slice();
+    let last_exp = if exp >= 0 {
+        0
+    } else {
+        exp
+    };
+    let mut sci_exp = (last_exp + exact.len() as i64 - 1) as i32;
+
+    // Copy the most significant digits, and round the truncated digits.
+    let count = precision.min(exact.len());
+    for (digit, &exact_digit) in digits.iter_mut().zip(exact.iter().rev().take(count)) {
+        *digit = exact_digit;
+    }
+    let truncated = &exact[..exact.len() - count];
+    let round_up = match truncated.split_last() {
+        Some((&first, rest)) => {
+            first > 5
+                || (first == 5 && (rest.iter().any(|&x| x != 0) || digits[count - 1] % 2 == 1))
+        },
+        None => false,
+    };
+    let mut count = count;
+    if round_up {
+        while count > 0 && digits[count - 1] == 9 {
+            count -= 1;
+        }
+        if count == 0 {
+            digits[0] = 1;
+            count = 1;
+            sci_exp += 1;
+        } else {
+            digits[count - 1] += 1;
+        }
+    }
+    while digits[count - 1] == 0 {
+        count -= 1;
+    }
+    for digit in digits[..count].iter_mut() {
+        *digit += b'0';
+    }
+    (count, sci_exp)
+}
+
+/// Write the exponent of scientific notation, after the exponent character,
+/// with a sign and at least `min_digits` digits.
+fn write_exponent(
+    bytes: &mut [u8],
+    mut cursor: usize,
+    exponent: u8,
+    sci_exp: i32,
+    min_digits: usize,
+) -> usize {
+    bytes[cursor] = exponent;
+    bytes[cursor + 1] = if sci_exp < 0 {
+        b'-'
+    } else {
+        b'+'
+    };
+    cursor += 2;
+    let mut exp = sci_exp.unsigned_abs();
+    let mut digits = [b'0'; 10];
+    let mut count = 0;
+    while exp != 0 || count < min_digits {
+        digits[count] = (exp % 10) as u8 + b'0';
+        exp /= 10;
+        count += 1;
+    }
+    for &digit in digits[..count].iter().rev() {
+        bytes[cursor] = digit;
+        cursor += 1;
+    }
+    cursor
+}
+
+/// Write a float like `printf("%.*g", precision, value)`, returning the written bytes.
+///
+/// The exact value of the float is rounded to `precision` significant
+/// digits with ties to even, or 1 digit if `precision` is 0. Like `%g`,
+/// scientific notation is used if the decimal exponent is below -4 or
+/// at least the precision, and trailing zeros and a trailing decimal
+/// point are removed. Special values are written as `inf`, `-inf`, `nan`
+/// and `-nan`, like glibc, for both styles.
+///
+/// # Panics
+///
+/// Panics if the buffer is too small, which is never the case for
+/// [`PRINTF_BUFFER_SIZE`].
+pub fn write_printf(
+    value: f64,
+    precision: usize,
+    style: PrintfStyle,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    let mut cursor = 0;
+    if value.is_sign_negative() {
+        bytes[0] = b'-';
+        cursor += 1;
+    }
+    if value.is_nan() || value.is_infinite() {
+        let special: &[u8] = if value.is_nan() {
+            b"nan"
+        } else {
+            b"inf"
+        };
+        bytes[cursor..cursor + 3].copy_from_slice(special);
+        return &mut bytes[..cursor + 3];
+    } else if value == 0.0 {
+        bytes[cursor] = b'0';
+        return &mut bytes[..cursor + 1];
+    }
+
+    let precision = precision.clamp(1, MAX_PRECISION);
+    let mut digits = [0u8; MAX_PRECISION];
+    let (count, sci_exp) = significant_digits(value.abs(), precision, &mut digits);
+    let digits = &digits[..count];
+    if sci_exp < -4 || sci_exp >= precision as i32 {
+        // Scientific notation, like `%e`.
+        bytes[cursor] = digits[0];
+        cursor += 1;
+        if count > 1 {
+            bytes[cursor] = b'.';
+            bytes[cursor + 1..cursor + count].copy_from_slice(&digits[1..]);
+            cursor += count;
+        }
+        cursor = write_exponent(bytes, cursor, b'e', sci_exp, style.exponent_digits());
+    } else if sci_exp < 0 {
+        // Fractional number, like `%f`.
+        let zeros = (-sci_exp) as usize;
+        bytes[cursor..cursor + zeros + 1].fill(b'0');
+        bytes[cursor + 1] = b'.';
+        cursor += zeros + 1;
+        bytes[cursor..cursor + count].copy_from_slice(digits);
+        cursor += count;
+    } else {
+        // Integral digits, and any fractional digits, like `%f`.
+        let integral = sci_exp as usize + 1;
+        let written = integral.min(count);
+        bytes[cursor..cursor + written].copy_from_slice(&digits[..written]);
+        bytes[cursor + written..cursor + integral].fill(b'0');
+        cursor += integral;
+        if count > integral {
+            bytes[cursor] = b'.';
+            bytes[cursor + 1..cursor + count - integral + 1].copy_from_slice(&digits[integral..]);
+            cursor += count - integral + 1;
+        }
+    }
+
+    &mut bytes[..cursor]
+}
+
+// HEX
+// ---
+
+/// Number of hex digits in the fraction of an `f64`.
+const HEX_FRACTION_DIGITS: usize = 13;
+
+/// Mask for the 52 bits of the fraction of an `f64`.
+const FRACTION_MASK: u64 = (1 << 52) - 1;
+
+/// Lowercase hex digits, like `%a`.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The significand to write hexadecimal floats with, like `%a`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HexSignificand {
+    /// Always write a leading `1`, like musl and the BSDs: subnormal
+    /// floats are normalized, like `0x1p-1074`, and rounding up to the
+    /// next power of two increments the exponent.
+    Normalized,
+    /// Write the significand as stored, like glibc and the Microsoft C
+    /// runtime: subnormal floats have a leading `0` and the exponent
+    /// `-1022`, like `0x0.0000000000001p-1022`, and rounding up to the
+    /// next power of two writes a leading `2`.
+    Denormalized,
+}
+
+/// Get the leading digit, the 52-bit fraction and the binary exponent
+/// of a positive, finite float.
+fn hex_components(value: f64, significand: HexSignificand) -> (u64, u64, i32) {
+    let bits = value.to_bits();
+    let biased_exp = (bits >> 52) as i32;
+    let fraction = bits & FRACTION_MASK;
+    if biased_exp != 0 {
+        (1, fraction, biased_exp - 1023)
+    } else if fraction == 0 {
+        (0, 0, 0)
+    } else if significand == HexSignificand::Denormalized {
+        (0, fraction, -1022)
+    } else {
+        // Shift the most-significant bit into the hidden bit.
+        let shift = fraction.leading_zeros() - 11;
+        (1, (fraction << shift) & FRACTION_MASK, -1022 - shift as i32)
+    }
+}
+
+/// Write a float like `printf("%.*a", precision, value)`, returning the written bytes.
+///
+/// The significand is written as a leading hex digit and the hex digits
+/// of the fraction, and the binary exponent is written in decimal with a
+/// sign, like `0x1.8p+1` for `3.0`. If `precision` is `None`, trailing
+/// zeros of the fraction are removed, like glibc and musl, otherwise
+/// exactly `precision` hex digits are written, rounding the significand
+/// with ties to even, such as `Some(13)` for the Microsoft C runtime.
+/// Zero is written as `0x0p+0`, and special values as `inf`, `-inf`,
+/// `nan` and `-nan`.
+///
+/// # Panics
+///
+/// Panics if the buffer is too small, which is never the case for
+/// [`PRINTF_BUFFER_SIZE`] if the precision is at most 780.
+pub fn write_printf_hex(
+    value: f64,
+    precision: Option<usize>,
+    significand: HexSignificand,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    let mut cursor = 0;
+    if value.is_sign_negative() {
+        bytes[0] = b'-';
+        cursor += 1;
+    }
+    if value.is_nan() || value.is_infinite() {
+        let special: &[u8] = if value.is_nan() {
+            b"nan"
+        } else {
+            b"inf"
+        };
+        bytes[cursor..cursor + 3].copy_from_slice(special);
+        return &mut bytes[..cursor + 3];
+    }
+
+    let (mut leading, mut fraction, mut exp) = hex_components(value.abs(), significand);
+    let digits = match precision {
+        Some(precision) if precision < HEX_FRACTION_DIGITS => {
+            // Round the significand to the precision, with ties to even.
+            let shift = 4 * (HEX_FRACTION_DIGITS - precision);
+            let bits = (leading << 52) | fraction;
+            let truncated = bits & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            let mut rounded = bits >> shift;
+            if truncated > halfway || (truncated == halfway && rounded & 1 == 1) {
+                rounded += 1;
+            }
+            leading = rounded >> (4 * precision);
+            fraction = (rounded << shift) & FRACTION_MASK;
+            if leading == 2 && significand == HexSignificand::Normalized {
+                // Rounded up to the next power of two, so the fraction is zero.
+                leading = 1;
+                exp += 1;
+            }
+            precision
+        },
+        Some(precision) => precision,
+        None => {
+            // Remove the trailing zeros, or all the digits of zero.
+            let zeros = fraction.trailing_zeros() as usize / 4;
+            HEX_FRACTION_DIGITS - zeros.min(HEX_FRACTION_DIGITS)
+        },
+    };
+
+    bytes[cursor..cursor + 2].copy_from_slice(b"0x");
+    bytes[cursor + 2] = b'0' + leading as u8;
+    cursor += 3;
+    if digits != 0 {
+        bytes[cursor] = b'.';
+        cursor += 1;
+        for index in 0..digits {
+            bytes[cursor] = if index < HEX_FRACTION_DIGITS {
+                let digit = (fraction >> (48 - 4 * index)) & 0xF;
+                HEX_DIGITS[digit as usize]
+            } else {
+                b'0'
+            };
+            cursor += 1;
+        }
+    }
+    cursor = write_exponent(bytes, cursor, b'p', exp, 1);
+
+    &mut bytes[..cursor]
+}
@@ -281,7 +281,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe if bytes is large enough to store the largest float with the smallest radix.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
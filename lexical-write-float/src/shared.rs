@@ -149,23 +149,119 @@ pub unsafe fn write_exponent_sign<const FORMAT: u128>(
 
 /// Write the symbol, sign, and digits for the exponent.
 ///
+/// The digits are padded with leading zeros to the minimum exponent
+/// digits in the options.
+///
 /// # Safety
 ///
 /// Safe if the buffer can hold all the significant digits and the sign
-/// starting from cursor.
+/// starting from cursor, and the padded exponent digits.
 #[cfg_attr(not(feature = "compact"), inline)]
 pub unsafe fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
-    *cursor += unsafe {
-        index_unchecked_mut!(bytes[*cursor]) = exponent_character;
+    let count = unsafe {
+        index_unchecked_mut!(bytes[*cursor]) = options.exponent();
         *cursor += 1;
         let positive_exp = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
         positive_exp.write_exponent::<u32, FORMAT>(&mut index_unchecked_mut!(bytes[*cursor..]))
     };
+    let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+    if count < min_digits {
+        // Shift the digits and write the leading zeros.
+        let zeros = min_digits - count;
+        // SAFETY: safe if the buffer can hold the padded exponent digits.
+        unsafe {
+            let digits = &mut index_unchecked_mut!(bytes[*cursor..*cursor + min_digits]);
+            let src = digits.as_mut_ptr();
+            core::ptr::copy(src, src.add(zeros), count);
+            slice_fill_unchecked!(index_unchecked_mut!(digits[..zeros]), b'0');
+        }
+        *cursor += min_digits;
+    } else {
+        *cursor += count;
+    }
+}
+
+/// Write the significant digits of a float, using the exponent breaks
+/// to choose between scientific and decimal notation.
+///
+/// `digits` are the ASCII significant digits, and `sci_exp` is the decimal
+/// exponent of the first digit. Returns the cursor after the written bytes.
+///
+/// # Panics
+///
+/// Panics if the buffer cannot fit the digits.
+pub fn write_digits<I: Iterator<Item = u8>, const FORMAT: u128>(
+    bytes: &mut [u8],
+    mut cursor: usize,
+    mut digits: I,
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let min_exp = options.negative_exponent_break().map_or(-5, |x| x.get());
+    let max_exp = options.positive_exponent_break().map_or(9, |x| x.get());
+    let outside_break = sci_exp < min_exp || sci_exp > max_exp;
+    let require_exponent = format.required_exponent_notation() || outside_break;
+    if !format.no_exponent_notation() && require_exponent {
+        // Write the digits in scientific notation.
+        bytes[cursor] = digits.next().unwrap_or(b'0');
+        cursor += 1;
+        if digit_count > 1 || !options.trim_floats() {
+            bytes[cursor] = options.decimal_point();
+            cursor += 1;
+        }
+        if digit_count == 1 && !options.trim_floats() {
+            bytes[cursor] = b'0';
+            cursor += 1;
+        }
+        for digit in digits {
+            bytes[cursor] = digit;
+            cursor += 1;
+        }
+        // Fits the exponent character, sign, and at most 4 or the padded digits.
+        let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+        assert!(bytes.len() >= cursor + 2 + min_digits.max(4));
+        // SAFETY: safe, since the buffer can hold the exponent.
+        unsafe { write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
+    } else if sci_exp >= 0 {
+        // Write the leading digits, and then any fraction digits.
+        let leading = sci_exp as usize + 1;
+        for index in 0..leading.max(digit_count) {
+            if index == leading {
+                bytes[cursor] = options.decimal_point();
+                cursor += 1;
+            }
+            bytes[cursor] = digits.next().unwrap_or(b'0');
+            cursor += 1;
+        }
+        if digit_count <= leading && !options.trim_floats() {
+            bytes[cursor] = options.decimal_point();
+            bytes[cursor + 1] = b'0';
+            cursor += 2;
+        }
+    } else {
+        // Write the leading zeros, and then the digits.
+        let zeros = (-sci_exp) as usize;
+        for index in 0..zeros + digit_count {
+            if index == 1 {
+                bytes[cursor] = options.decimal_point();
+                cursor += 1;
+            }
+            bytes[cursor] = if index < zeros {
+                b'0'
+            } else {
+                digits.next().unwrap_or(b'0')
+            };
+            cursor += 1;
+        }
+    }
+    cursor
 }
 
 /// Detect the notation to use for the float formatter and call the appropriate function..
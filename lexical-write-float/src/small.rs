@@ -0,0 +1,99 @@
+//! Fast path for small, integral decimal floats.
+//!
+//! Sparse numeric data, such as JSON arrays of counters or matrices,
+//! is dominated by zeros, single digits, and round powers of ten. The
+//! shortest representation of these floats is known, so they can be
+//! written from a lookup table, without generating digits with the
+//! float-to-string algorithms. The output is identical to the decimal
+//! algorithms, with or without trimmed floats, so the fast path is only
+//! taken when the options cannot change the output, such as a minimum
+//! number of significant digits or exponent breaks below the value.
+
+#![doc(hidden)]
+
+use crate::float::RawFloat;
+use crate::options::Options;
+use lexical_util::format::NumberFormat;
+
+/// Integral values with a cached representation, indexed by their spelling.
+///
+/// The value at each index is written as `SMALL_DIGITS[index]` followed
+/// by `SMALL_ZEROS[index]` zeros, so `0-9` and `10^1` to `10^9`.
+const SMALL_VALUES: [u64; 19] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000,
+    1000000000,
+];
+
+/// Leading digit of each cached value.
+const SMALL_DIGITS: [u8; 19] = *b"0123456789111111111";
+
+/// Number of trailing zeros of each cached value.
+const SMALL_ZEROS: [u8; 19] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+/// Find the index of a cached integral value, or `None` if it isn't cached.
+#[inline(always)]
+fn small_index<F: RawFloat>(float: F) -> Option<usize> {
+    let value = float.as_u64();
+    // Rejects values above `10^9` and all fractional values.
+    if value > SMALL_VALUES[SMALL_VALUES.len() - 1] || F::as_cast(value) != float {
+        return None;
+    }
+    if value < 10 {
+        Some(value as usize)
+    } else {
+        SMALL_VALUES[10..].iter().position(|&x| x == value).map(|index| index + 10)
+    }
+}
+
+/// Write a small, integral decimal float from the lookup table.
+///
+/// Returns `None` if the value isn't cached, or if the number format
+/// or options could change the output of the decimal algorithms.
+///
+/// # Safety
+///
+/// Safe as long as the float isn't special (NaN or Infinity), and `bytes`
+/// is large enough to hold the significant digits.
+#[inline(always)]
+pub unsafe fn write_small<F: RawFloat, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Option<usize> {
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.radix() != 10
+        || format.required_exponent_notation()
+        || options.min_significant_digits().is_some()
+    {
+        return None;
+    }
+    let index = small_index(float)?;
+
+    // The scientific exponent is the number of trailing zeros, so the
+    // value must be within the exponent breaks, like the algorithms.
+    let sci_exp = SMALL_ZEROS[index] as i32;
+    let min_exp = options.negative_exponent_break().map_or(-5, |x| x.get());
+    let max_exp = options.positive_exponent_break().map_or(9, |x| x.get());
+    let outside_break = sci_exp < min_exp || sci_exp > max_exp;
+    if outside_break && !format.no_exponent_notation() {
+        return None;
+    }
+
+    let zeros = sci_exp as usize;
+    // SAFETY: safe if the buffer can hold the significant digits, since
+    // the digits and optional fraction are at most 12 bytes.
+    unsafe {
+        index_unchecked_mut!(bytes[0]) = SMALL_DIGITS[index];
+        slice_fill_unchecked!(index_unchecked_mut!(bytes[1..zeros + 1]), b'0');
+        let mut cursor = zeros + 1;
+        if !options.trim_floats() {
+            index_unchecked_mut!(bytes[cursor]) = options.decimal_point();
+            index_unchecked_mut!(bytes[cursor + 1]) = b'0';
+            cursor += 2;
+        }
+        Some(cursor)
+    }
+}
@@ -0,0 +1,226 @@
+//! Write floats to fit a fixed total width.
+//!
+//! Fixed-width reports and embedded displays have a fixed number of
+//! bytes for each number, so the float is written with the most
+//! significant digits that fit, rather than truncating the string.
+//! Fewer significant digits are written until the float fits, first
+//! without scientific notation, and then with scientific notation,
+//! so `123456.789` is written in 6 bytes as `1.23e5`, or as `123457`
+//! if floats are trimmed. If no digits fit, a single digit is written
+//! with only an exponent, like `1e1`, and finally as a trimmed float,
+//! like `10`.
+
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::Options;
+use core::num;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::Float;
+
+/// Maximum number of significant digits to uniquely identify an `f64`.
+const MAX_DIGITS: usize = 17;
+
+/// Maximum exponent break to write a float without scientific notation.
+///
+/// Any decimal float with more leading or trailing zeros is too long to
+/// fit without scientific notation, since `10^-324` and `10^308` are the
+/// limits of an `f64`.
+const MAX_BREAK: usize = 330;
+
+/// Size of the buffer to write a float with at most [`MAX_BREAK`] leading
+/// or trailing zeros, without a minimum number of significant digits.
+///
+/// Formats without exponent notation need space for the 1075 leading
+/// zeros of `2^-1075` if the power-of-two feature is enabled.
+const WIDTH_BUFFER_SIZE: usize = 1200;
+
+/// Write a float into a buffer, and copy it if it fits in the width.
+///
+/// Returns the number of bytes written, or `None` if it doesn't fit.
+fn write_fitting<F, const FORMAT: u128>(
+    float: F,
+    width: usize,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Option<usize>
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    let mut buffer = [0u8; WIDTH_BUFFER_SIZE];
+    let written = float.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    let count = written.len();
+    if count <= width {
+        bytes[..count].copy_from_slice(written);
+        Some(count)
+    } else {
+        None
+    }
+}
+
+/// Clamp an exponent break to the maximum break.
+#[inline(always)]
+fn clamp_break(exp_break: i32) -> num::NonZeroI32 {
+    let max_break = MAX_BREAK as i32;
+    // SAFETY: safe, since the exponent break is non-zero.
+    unsafe { num::NonZeroI32::new_unchecked(exp_break.clamp(-max_break, max_break)) }
+}
+
+/// Write a float with the most significant digits that fit in a width.
+///
+/// The float is written with the shortest representation, using the
+/// exponent breaks of the options, if it fits. Otherwise, the digits
+/// are rounded to fewer significant digits, preferring the float without
+/// scientific notation for the same number of digits, until it fits.
+/// If it still doesn't fit, the float is written with a single digit
+/// and an exponent, like `1e1`, and then trimmed, like `10`, since
+/// trimmed floats look like integers.
+/// The minimum number of significant digits of the options is ignored,
+/// and the maximum limits the digits. Returns `None` if the float cannot
+/// fit in `width` bytes, or in the buffer, even with 1 significant digit.
+///
+/// # Panics
+///
+/// Panics if the format is not decimal, or the format or options are
+/// invalid.
+pub fn write_width<'a, F, const FORMAT: u128>(
+    float: F,
+    width: usize,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> Option<&'a mut [u8]>
+where
+    F: ToLexicalWithOptions<Options = Options> + Float,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.mantissa_radix() == 10 && format.exponent_base() == 10);
+
+    // Clamping the exponent breaks never changes the output, since the
+    // exponent of any float is within the maximum break.
+    let width = width.min(bytes.len());
+    let positive_break = options.positive_exponent_break().map(|x| clamp_break(x.get()));
+    let negative_break = options.negative_exponent_break().map(|x| clamp_break(x.get()));
+    let builder = options
+        .rebuild()
+        .min_significant_digits(None)
+        .positive_exponent_break(positive_break)
+        .negative_exponent_break(negative_break);
+    // SAFETY: safe, since only the digits and exponent breaks changed.
+    let shortest = unsafe { builder.build_unchecked() };
+    if let Some(count) = write_fitting::<_, FORMAT>(float, width, bytes, &shortest) {
+        return Some(&mut bytes[..count]);
+    } else if float.is_special() {
+        // Rounding cannot make special values shorter.
+        return None;
+    }
+
+    // Write without scientific notation if it could fit, and then with
+    // scientific notation, except for exponents from `-1` to `1`, which
+    // are never shorter with scientific notation.
+    let max_break = width.clamp(1, MAX_BREAK) as i32;
+    let trim = shortest.trim_floats();
+    let candidates = [(max_break, trim), (1, trim)];
+    if let Some(count) = write_digits::<_, FORMAT>(float, width, bytes, &shortest, &candidates) {
+        return Some(&mut bytes[..count]);
+    }
+
+    // The exponent marks the float, so a single digit needs no fraction.
+    if let Some(count) = write_exponent_only::<_, FORMAT>(float, width, bytes, &shortest) {
+        return Some(&mut bytes[..count]);
+    }
+
+    // Trimmed floats without scientific notation, like `10`, look like
+    // integers, so they're only written if nothing else fits.
+    let candidates = [(max_break, true)];
+    let count = write_digits::<_, FORMAT>(float, width, bytes, &shortest, &candidates)?;
+    Some(&mut bytes[..count])
+}
+
+/// Write a float with the most significant digits that fit in a width.
+///
+/// For each number of significant digits, from the most to the fewest,
+/// the float is written with each exponent break, and if floats are
+/// trimmed, until it fits. Returns the number of bytes written, or
+/// `None` if it doesn't fit.
+fn write_digits<F, const FORMAT: u128>(
+    float: F,
+    width: usize,
+    bytes: &mut [u8],
+    options: &Options,
+    candidates: &[(i32, bool)],
+) -> Option<usize>
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    let max_digits = options.max_significant_digits().map_or(MAX_DIGITS, |x| x.get());
+    for count in (1..=max_digits.min(MAX_DIGITS)).rev() {
+        let digits = num::NonZeroUsize::new(count);
+        for &(exp_break, trim_floats) in candidates.iter() {
+            let builder = options
+                .rebuild()
+                .max_significant_digits(digits)
+                .positive_exponent_break(num::NonZeroI32::new(exp_break))
+                .negative_exponent_break(num::NonZeroI32::new(-exp_break))
+                .trim_floats(trim_floats);
+            // SAFETY: safe, since only the digits, exponent breaks and
+            // trimming changed.
+            let options = unsafe { builder.build_unchecked() };
+            if let Some(count) = write_fitting::<_, FORMAT>(float, width, bytes, &options) {
+                return Some(count);
+            }
+        }
+    }
+    None
+}
+
+/// Write a float with a single significant digit and an exponent, like `1e1`.
+///
+/// Returns the number of bytes written, or `None` if it doesn't fit.
+fn write_exponent_only<F, const FORMAT: u128>(
+    float: F,
+    width: usize,
+    bytes: &mut [u8],
+    options: &Options,
+) -> Option<usize>
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.no_exponent_notation() {
+        return None;
+    }
+
+    let builder = options
+        .rebuild()
+        .max_significant_digits(num::NonZeroUsize::new(1))
+        .positive_exponent_break(num::NonZeroI32::new(1))
+        .negative_exponent_break(num::NonZeroI32::new(-1))
+        .trim_floats(true);
+    // SAFETY: safe, since only the digits, exponent breaks and trimming changed.
+    let options = unsafe { builder.build_unchecked() };
+    let mut buffer = [0u8; WIDTH_BUFFER_SIZE];
+    let written = float.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    let sign = (written.first() == Some(&b'-')) as usize;
+    let digits = &written[sign..];
+    if !digits.iter().all(u8::is_ascii_digit) {
+        // Already has a fraction or an exponent, like `0.1` or `1e5`.
+        let count = written.len();
+        return if count <= width {
+            bytes[..count].copy_from_slice(written);
+            Some(count)
+        } else {
+            None
+        };
+    }
+
+    // Exponents of `0` and `1` are written as `1` or `10`, so write the
+    // first digit and the exponent.
+    let count = sign + 3;
+    if count > width {
+        return None;
+    }
+    bytes[..sign + 1].copy_from_slice(&written[..sign + 1]);
+    bytes[sign + 1] = options.exponent();
+    bytes[sign + 2] = b'0' + (digits.len() - 1) as u8;
+    Some(count)
+}
@@ -13,6 +13,7 @@ use crate::compact::write_float as write_float_decimal;
 use crate::hex;
 #[cfg(feature = "radix")]
 use crate::radix;
+use crate::small;
 
 use crate::float::RawFloat;
 use crate::options::Options;
@@ -22,6 +23,7 @@ use lexical_util::constants::FormattedSize;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
+use lexical_util::hint::likely;
 use lexical_write_integer::write::WriteInteger;
 
 /// Write float trait.
@@ -41,7 +43,8 @@ pub trait WriteFloat: RawFloat {
     ///
     /// # Panics
     ///
-    /// Panics if the number format is invalid, or if scientific notation
+    /// Panics if the number format is invalid, if the mantissa radix
+    /// is larger than 36, or if scientific notation
     /// is used and the exponent base does not equal the mantissa radix
     /// and the format is not a hexadecimal float. It also panics
     /// if `options.nan_string` or `options.inf_string` is None and asked
@@ -51,25 +54,56 @@ pub trait WriteFloat: RawFloat {
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
     #[inline]
     unsafe fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
+    where
+        Self::Unsigned: FormattedSize + WriteInteger,
+    {
+        // PANIC: cannot serialize an invalid format.
+        assert!(is_valid_float_format::<FORMAT>());
+        // SAFETY: safe if the buffer can hold the significant digits.
+        match unsafe { self.try_write_float::<FORMAT>(bytes, options) } {
+            Some(count) => count,
+            // PANIC: cannot serialize NaN.
+            None if self.is_nan() => {
+                panic!("NaN explicitly disabled but asked to write NaN as string.")
+            },
+            // PANIC: cannot serialize inf.
+            None => panic!("Inf explicitly disabled but asked to write Inf as string."),
+        }
+    }
+
+    /// Forward write integer parameters to an unoptimized backend.
+    ///
+    /// Returns `None` rather than panicking if the number format is
+    /// invalid for floats, or if `options.nan_string` or
+    /// `options.inf_string` is None and asked to serialize a NaN or Inf
+    /// value, respectively. The sign may be written if `None` is returned.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold [`FORMATTED_SIZE`] elements
+    /// (or [`FORMATTED_SIZE_DECIMAL`] for decimal), with the same
+    /// requirements as [`write_float`].
+    ///
+    /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
+    /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+    /// [`write_float`]: Self::write_float
+    #[inline]
+    unsafe fn try_write_float<const FORMAT: u128>(
+        self,
+        bytes: &mut [u8],
+        options: &Options,
+    ) -> Option<usize>
     where
         Self::Unsigned: FormattedSize + WriteInteger,
     {
         // Validate our format options.
         let format = NumberFormat::<FORMAT> {};
-        assert!(format.is_valid());
+        if !is_valid_float_format::<FORMAT>() {
+            return None;
+        }
         // Avoid any false assumptions for 128-bit floats.
         assert!(Self::BITS <= 64);
 
-        #[cfg(feature = "power-of-two")]
-        {
-            if format.radix() != format.exponent_base() {
-                assert!(matches!(
-                    (format.radix(), format.exponent_base()),
-                    (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4)
-                ));
-            }
-        }
-
         let (float, count, bytes) = if self < Self::ZERO {
             // SAFETY: safe if `bytes.len() > 1`.
             unsafe { index_unchecked_mut!(bytes[0]) = b'-' };
@@ -83,7 +117,13 @@ pub trait WriteFloat: RawFloat {
         };
 
         // Handle special values.
-        if !self.is_special() {
+        let count = if likely(!self.is_special()) {
+            // Write small, integral values from a lookup table.
+            // SAFETY: safe if the buffer can hold the significant digits.
+            if let Some(small) = unsafe { small::write_small::<_, FORMAT>(float, bytes, options) } {
+                return Some(count + small);
+            }
+
             #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
             {
                 // SAFETY: safe if the buffer can hold the significant digits
@@ -121,41 +161,39 @@ pub trait WriteFloat: RawFloat {
                 // SAFETY: safe if the buffer can hold the significant digits
                 count + unsafe { write_float_decimal::<_, FORMAT>(float, bytes, options) }
             }
-        } else if self.is_nan() {
-            // SAFETY: safe if the buffer is longer than the NaN string.
-            // The NaN string must be <= 50 characters, so safe as long as
-            // the options were build using safe methods.
-            if let Some(nan_string) = options.nan_string() {
-                let length = nan_string.len();
-                unsafe {
-                    let src = nan_string.as_ptr();
-                    let dst = &mut index_unchecked_mut!(bytes[..length]);
-                    copy_nonoverlapping_unchecked!(dst, src, length);
-                }
-                count + length
-            } else {
-                // PANIC: cannot serialize NaN.
-                panic!("NaN explicitly disabled but asked to write NaN as string.");
-            }
         } else {
-            // is_inf
-            // SAFETY: safe if the buffer is longer than the Inf string.
-            // The Inf string must be <= 50 characters, so safe as long as
-            // the options were build using safe methods.
-            if let Some(inf_string) = options.inf_string() {
-                let length = inf_string.len();
-                unsafe {
-                    let src = inf_string.as_ptr();
-                    let dst = &mut index_unchecked_mut!(bytes[..length]);
-                    copy_nonoverlapping_unchecked!(dst, src, length);
-                }
-                count + length
+            // SAFETY: safe if the buffer is longer than the special string.
+            // The special strings must be <= 50 characters, so safe as long
+            // as the options were build using safe methods.
+            let string = if self.is_nan() {
+                options.nan_string()
             } else {
-                // PANIC: cannot serialize inf.
-                panic!("Inf explicitly disabled but asked to write Inf as string.");
-            }
-        }
+                options.inf_string()
+            };
+            count + unsafe { write_special(bytes, string)? }
+        };
+        Some(count)
+    }
+}
+
+/// Write a special, non-finite float string, kept out-of-line since it's rare.
+///
+/// Returns `None` if the string is `None`, since it cannot be serialized.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is longer than the special string.
+#[cold]
+unsafe fn write_special(bytes: &mut [u8], string: Option<&[u8]>) -> Option<usize> {
+    let string = string?;
+    let length = string.len();
+    // SAFETY: safe if the buffer is longer than the special string.
+    unsafe {
+        let src = string.as_ptr();
+        let dst = &mut index_unchecked_mut!(bytes[..length]);
+        copy_nonoverlapping_unchecked!(dst, src, length);
     }
+    Some(length)
 }
 
 macro_rules! write_float_impl {
@@ -171,10 +209,14 @@ macro_rules! write_float_as_f32 {
     ($($t:ty)*) => ($(
         impl WriteFloat for $t {
             #[inline]
-            unsafe fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
+            unsafe fn try_write_float<const FORMAT: u128>(
+                self,
+                bytes: &mut [u8],
+                options: &Options,
+            ) -> Option<usize>
             {
                 // SAFETY: safe if `bytes` is large enough to hold the written bytes.
-                unsafe { self.as_f32().write_float::<FORMAT>(bytes, options) }
+                unsafe { self.as_f32().try_write_float::<FORMAT>(bytes, options) }
             }
         }
     )*)
@@ -182,3 +224,19 @@ macro_rules! write_float_as_f32 {
 
 #[cfg(feature = "f16")]
 write_float_as_f32! { bf16 f16 }
+
+/// Determine if the number format can be used to write floats.
+///
+/// This rejects radixes above 36, and scientific notation with an exponent
+/// base that differs from the mantissa radix unless the format is a
+/// hexadecimal float. Since this only depends on `FORMAT`, it is
+/// evaluated at compile time.
+#[inline(always)]
+const fn is_valid_float_format<const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<FORMAT> {};
+    let radix = format.radix();
+    let exponent_base = format.exponent_base();
+    format.is_valid_float()
+        && (radix == exponent_base
+            || matches!((radix, exponent_base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4)))
+}
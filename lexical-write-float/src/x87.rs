@@ -0,0 +1,325 @@
+//! Write the 80-bit x87 extended format to decimal strings, with the shortest representation.
+//!
+//! The x87 extended format has a sign bit, a 15-bit biased exponent,
+//! and a 64-bit significand with an explicit integer bit, and is stored
+//! as a `(u16, u64)` pair of the sign and exponent, and the significand.
+//! The range is much larger than an `f64`, so the shortest digits are
+//! generated with big integers, using the free-format algorithm from
+//! "Printing Floating-Point Numbers Quickly and Accurately" by Burger
+//! and Dybvig.
+
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::Options;
+use crate::shared;
+use core::cmp::Ordering;
+use lexical_util::format::NumberFormat;
+
+/// Bias of the exponent, including the size of the significand.
+const EXPONENT_BIAS: i64 = 16383 + 63;
+
+/// Binary exponent of the last significand bit of the smallest float.
+const MIN_EXPONENT: i64 = 1 - EXPONENT_BIAS;
+
+/// Biased exponent of infinity and NaN.
+const MAX_BIASED_EXPONENT: u16 = 0x7FFF;
+
+/// Bitmask for the sign bit, in the sign and exponent.
+const SIGN_MASK: u16 = 0x8000;
+
+/// Bitmask for the explicit integer bit of the significand.
+const INTEGER_BIT: u64 = 1 << 63;
+
+/// Maximum number of significant digits for the shortest representation.
+const MAX_DIGITS: usize = 21;
+
+/// Number of 32-bit limbs in the big integers.
+///
+/// The scaled numbers have at most `log2(2**66 * 10**4951) + 4 ≅ 16520`
+/// bits, for the smallest denormal floats.
+const LIMBS: usize = 528;
+
+/// Size of a buffer that can fit any x87 extended float.
+///
+/// This fits the significant digits, and the zeros written by the
+/// largest exponent breaks for an x87 extended float.
+pub const X87_BUFFER_SIZE: usize = 5000;
+
+/// A big, unsigned integer, stored as 32-bit limbs in little-endian order.
+#[derive(Clone)]
+struct Big {
+    /// The limbs, where only the first `len` limbs are used.
+    data: [u32; LIMBS],
+    /// The number of limbs, without leading zeros.
+    len: usize,
+}
+
+impl Big {
+    /// Create an integer from a small value.
+    fn from_u64(value: u64) -> Self {
+        let mut big = Self {
+            data: [0; LIMBS],
+            len: 2,
+        };
+        big.data[0] = value as u32;
+        big.data[1] = (value >> 32) as u32;
+        big.normalize();
+        big
+    }
+
+    /// Get the used limbs.
+    #[inline(always)]
+    fn as_slice(&self) -> &[u32] {
+        &self.data[..self.len]
+    }
+
+    /// Remove leading zeros.
+    fn normalize(&mut self) {
+        while self.len > 0 && self.data[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    /// Multiply by a small value.
+    fn mul_small(&mut self, y: u32) {
+        let mut carry = 0;
+        for limb in self.data[..self.len].iter_mut() {
+            let value = *limb as u64 * y as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            self.data[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    /// Multiply by a power of 10.
+    fn mul_pow10(&mut self, mut exp: u32) {
+        while exp >= 9 {
+            self.mul_small(1_000_000_000);
+            exp -= 9;
+        }
+        self.mul_small(10u32.pow(exp));
+    }
+
+    /// Multiply by a power of 2.
+    fn shl(&mut self, n: u32) {
+        let limbs = (n / 32) as usize;
+        let bits = n % 32;
+        if bits != 0 {
+            let mut carry = 0;
+            for limb in self.data[..self.len].iter_mut() {
+                let value = *limb;
+                *limb = (value << bits) | carry;
+                carry = value >> (32 - bits);
+            }
+            if carry != 0 {
+                self.data[self.len] = carry;
+                self.len += 1;
+            }
+        }
+        if limbs != 0 && self.len != 0 {
+            self.data.copy_within(..self.len, limbs);
+            for limb in self.data[..limbs].iter_mut() {
+                *limb = 0;
+            }
+            self.len += limbs;
+        }
+    }
+
+    /// Add an integer.
+    fn add(&mut self, y: &Self) {
+        let len = self.len.max(y.len);
+        let mut carry = 0;
+        for index in 0..len {
+            let value = self.data[index] as u64 + y.data[index] as u64 + carry;
+            self.data[index] = value as u32;
+            carry = value >> 32;
+        }
+        self.len = len;
+        if carry != 0 {
+            self.data[len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    /// Subtract an integer, which must be at most `self`.
+    fn sub(&mut self, y: &Self) {
+        debug_assert!(compare(self, y) != Ordering::Less);
+        let mut borrow = 0;
+        for index in 0..self.len {
+            let value = self.data[index] as i64 - y.data[index] as i64 - borrow;
+            borrow = (value < 0) as i64;
+            self.data[index] = value as u32;
+        }
+        self.normalize();
+    }
+}
+
+/// Compare two normalized integers.
+fn compare(x: &Big, y: &Big) -> Ordering {
+    let x = x.as_slice();
+    let y = y.as_slice();
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+/// Determine if `r + m` is above the upper bound of `s`.
+fn is_high(r: &Big, m: &Big, s: &Big, is_even: bool) -> bool {
+    let mut sum = r.clone();
+    sum.add(m);
+    match compare(&sum, s) {
+        Ordering::Greater => true,
+        Ordering::Equal => is_even,
+        Ordering::Less => false,
+    }
+}
+
+/// Get the shortest significant digits of the non-zero, finite float
+/// `mant * 2^exp`, and the decimal exponent of the first digit.
+fn shortest(mant: u64, exp: i64, digits: &mut [u8; MAX_DIGITS]) -> (usize, i32) {
+    // Scale the value and the gaps to the adjacent floats, so the value
+    // is `r / s`, and the bounds are `(r - m_minus) / s` and `(r + m_plus) / s`.
+    // The gap below the smallest significand of a binade is half the size.
+    let is_even = mant & 1 == 0;
+    let is_boundary = mant == INTEGER_BIT && exp > MIN_EXPONENT;
+    let (mut r, mut s, mut m_plus, mut m_minus) = {
+        let (shift, gap_shift) = if is_boundary {
+            (2, 1)
+        } else {
+            (1, 0)
+        };
+        let mut r = Big::from_u64(mant);
+        let mut s = Big::from_u64(1);
+        let mut m_plus = Big::from_u64(1);
+        let mut m_minus = Big::from_u64(1);
+        r.shl(shift);
+        s.shl(shift);
+        m_plus.shl(gap_shift);
+        if exp >= 0 {
+            r.shl(exp as u32);
+            m_plus.shl(exp as u32);
+            m_minus.shl(exp as u32);
+        } else {
+            s.shl((-exp) as u32);
+        }
+        (r, s, m_plus, m_minus)
+    };
+
+    // Estimate the decimal exponent from below, and then correct it.
+    // `78913 / 2^18` is slightly below `log10(2)`, and the estimate is at
+    // most 1 above the floor for the exponents of any x87 extended float.
+    let bits = 64 - mant.leading_zeros() as i64;
+    let mut k = (((exp + bits - 1) * 78913) >> 18) as i32;
+    if k >= 0 {
+        s.mul_pow10(k as u32);
+    } else {
+        r.mul_pow10((-k) as u32);
+        m_plus.mul_pow10((-k) as u32);
+        m_minus.mul_pow10((-k) as u32);
+    }
+    while is_high(&r, &m_plus, &s, is_even) {
+        s.mul_small(10);
+        k += 1;
+    }
+
+    // Generate digits until the remainder is within the bounds.
+    let mut count = 0;
+    loop {
+        r.mul_small(10);
+        m_plus.mul_small(10);
+        m_minus.mul_small(10);
+        let mut digit = 0;
+        while compare(&r, &s) != Ordering::Less {
+            r.sub(&s);
+            digit += 1;
+        }
+        let is_low = match compare(&r, &m_minus) {
+            Ordering::Less => true,
+            Ordering::Equal => is_even,
+            Ordering::Greater => false,
+        };
+        let is_high = is_high(&r, &m_plus, &s, is_even);
+        if is_low || is_high {
+            // Round to the closest digit, with ties to even.
+            let mut twice = r.clone();
+            twice.shl(1);
+            let round_up = match compare(&twice, &s) {
+                _ if !is_high => false,
+                _ if !is_low => true,
+                Ordering::Greater => true,
+                Ordering::Equal => digit % 2 == 1,
+                Ordering::Less => false,
+            };
+            digits[count] = digit + round_up as u8 + b'0';
+            count += 1;
+            break;
+        }
+        digits[count] = digit + b'0';
+        count += 1;
+    }
+    (count, k - 1)
+}
+
+/// Write an x87 extended float to a decimal string, returning the written bytes.
+///
+/// The float is given as the sign and biased exponent, and the significand
+/// with the explicit integer bit, and is written with the fewest significant
+/// digits that parse back to the same float. Unnormal encodings are written
+/// as their value, and pseudo-NaNs and pseudo-infinities are written as NaN
+/// and infinity. Special values and zeros are written like an `f64`, and
+/// the significant digit options are ignored.
+///
+/// # Panics
+///
+/// Panics if the format is not decimal, or the buffer is too small,
+/// which is never the case for [`X87_BUFFER_SIZE`].
+pub fn write_x87<'a, const FORMAT: u128>(
+    sign_exponent: u16,
+    mant: u64,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8] {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.mantissa_radix() == 10 && format.exponent_base() == 10);
+
+    let is_negative = sign_exponent & SIGN_MASK != 0;
+    let biased_exp = sign_exponent & !SIGN_MASK;
+    if biased_exp == MAX_BIASED_EXPONENT {
+        let value = match mant & !INTEGER_BIT {
+            0 if is_negative => f64::NEG_INFINITY,
+            0 => f64::INFINITY,
+            _ => f64::NAN,
+        };
+        return value.to_lexical_with_options::<FORMAT>(bytes, options);
+    } else if mant == 0 {
+        let value = if is_negative {
+            -0.0
+        } else {
+            0.0
+        };
+        return value.to_lexical_with_options::<FORMAT>(bytes, options);
+    }
+
+    // Normalize the significand of unnormal and denormal encodings.
+    let mut exp = (biased_exp as i64).max(1) - EXPONENT_BIAS;
+    let mut mant = mant;
+    while mant & INTEGER_BIT == 0 && exp > MIN_EXPONENT {
+        mant <<= 1;
+        exp -= 1;
+    }
+
+    let mut cursor = 0;
+    if is_negative {
+        bytes[0] = b'-';
+        cursor += 1;
+    }
+    let mut digits = [0u8; MAX_DIGITS];
+    let (digit_count, sci_exp) = shortest(mant, exp, &mut digits);
+    let digits = digits[..digit_count].iter().copied();
+    let cursor =
+        shared::write_digits::<_, FORMAT>(bytes, cursor, digits, digit_count, sci_exp, options);
+
+    &mut bytes[..cursor]
+}
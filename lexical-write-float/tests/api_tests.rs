@@ -65,6 +65,25 @@ fn invalid_inf_test() {
     f64::INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
 }
 
+#[test]
+#[should_panic]
+#[cfg(feature = "radix")]
+fn invalid_extended_radix_test() {
+    const FORMAT: u128 = lexical_util::format::NumberFormatBuilder::from_radix(40);
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    1.5f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn try_invalid_extended_radix_test() {
+    const FORMAT: u128 = lexical_util::format::NumberFormatBuilder::from_radix(40);
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    assert_eq!(1.5f64.try_to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options), None);
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn hex_test() {
@@ -82,6 +101,50 @@ fn hex_test() {
     let float = 12345.0f64;
     let result = float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &HEX_OPTIONS);
     assert_eq!(result, b"3.039^12");
+
+    let options = HEX_OPTIONS.rebuild().min_exponent_digits(num::NonZeroUsize::new(4)).build();
+    let result = float.to_lexical_with_options::<BASE16_2_10>(&mut buffer, &options.unwrap());
+    assert_eq!(result, b"3.039^0012");
+}
+
+#[test]
+fn min_exponent_digits_test() {
+    use core::num;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options =
+        Options::builder().min_exponent_digits(num::NonZeroUsize::new(3)).build().unwrap();
+    let result = 1e10f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(result, b"1.0e010");
+    let result = 1.5e-7f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(result, b"1.5e-007");
+    let result = 1e300f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(result, b"1.0e300");
+    let result = 12345.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(result, b"12345.0");
+
+    let options =
+        Options::builder().min_exponent_digits(num::NonZeroUsize::new(20)).build().unwrap();
+    let result = 1e-300f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(result, b"1.0e-00000000000000000300");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn min_exponent_digits_sign_test() {
+    use core::num;
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_exponent_sign(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options =
+        Options::builder().min_exponent_digits(num::NonZeroUsize::new(2)).build().unwrap();
+    let result = 1e10f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(result, b"1.0e+10");
+    let result = 1e-7f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(result, b"1.0e-07");
+    let result = 1.5e15f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(result, b"1.5e+15");
 }
 
 quickcheck! {
@@ -538,6 +538,13 @@ fn write_float_test() {
     write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &truncate, "1234.0");
     write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &round, "1235.0");
 
+    // Rounding to the maximum digits may leave trailing zeros.
+    write_float::<_, DECIMAL>(1.20003f64, &round, "1.200");
+    write_float::<_, DECIMAL>(1.20003e20f64, &round, "1.200e20");
+    write_float::<_, DECIMAL>(1.20003e-20f64, &round, "1.200e-20");
+    write_float::<_, DECIMAL>(12000.3f64, &round, "12000.0");
+    write_float::<_, DECIMAL>(0.0012003f64, &round, "0.001200");
+
     // Check min and max digits
     let options = Options::builder()
         .min_significant_digits(num::NonZeroUsize::new(3))
@@ -0,0 +1,69 @@
+use core::num;
+use lexical_util::custom_float::LexicalFloat;
+use lexical_util::format::STANDARD;
+use lexical_write_float::custom::write_custom;
+use lexical_write_float::{Options, BUFFER_SIZE};
+
+/// An 8-bit float, with 4 exponent bits and 3 fraction bits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fp8(u8);
+
+impl LexicalFloat for Fp8 {
+    const EXPONENT_SIZE: i32 = 4;
+    const MANTISSA_SIZE: i32 = 3;
+
+    fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        Self(bits as u8)
+    }
+}
+
+fn write<F: LexicalFloat>(float: F, options: &Options) -> String {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = write_custom::<F, { STANDARD }>(float, &mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_custom_test() {
+    let options = Options::new();
+    assert_eq!(write(Fp8(0x38), &options), "1.0");
+    assert_eq!(write(Fp8(0x39), &options), "1.1");
+    assert_eq!(write(Fp8(0xC4), &options), "-3.0");
+    assert_eq!(write(Fp8(0x77), &options), "240.0");
+    assert_eq!(write(Fp8(0x01), &options), "0.002");
+    assert_eq!(write(Fp8(0x00), &options), "0.0");
+    assert_eq!(write(Fp8(0xF8), &options), "-inf");
+    assert_eq!(write(Fp8(0x79), &options), "NaN");
+    assert_eq!(write(0.1f64, &options), "0.1");
+
+    let digits = num::NonZeroUsize::new(1);
+    let options = Options::builder().max_significant_digits(digits).build().unwrap();
+    assert_eq!(write(Fp8(0x77), &options), "200.0");
+}
+
+#[test]
+fn write_f32_test() {
+    let options = Options::new();
+    assert_eq!(write(0.1f32, &options), "0.1");
+    assert_eq!(write(f32::MAX, &options), "3.4028235e38");
+    assert_eq!(write(f32::from_bits(1), &options), "1.0e-45");
+
+    // Digits that parse exactly halfway between two floats are rejected,
+    // so the output may be longer than the shortest, but must round-trip.
+    assert_eq!(write(338384384f32, &options), "338384380.0");
+    let mut state = 0x9E37_79B9u32;
+    for _ in 0..2000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let float = f32::from_bits(state);
+        if !float.is_nan() {
+            let parsed = write(float, &options).parse::<f32>().unwrap();
+            assert_eq!(parsed.to_bits(), float.to_bits());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use core::{f64, num};
+use lexical_util::format::STANDARD;
+use lexical_write_float::double_double::{write_double_double, DOUBLE_DOUBLE_BUFFER_SIZE};
+use lexical_write_float::Options;
+
+fn write(hi: f64, lo: f64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; DOUBLE_DOUBLE_BUFFER_SIZE];
+    let bytes = write_double_double::<{ STANDARD }>(hi, lo, &mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_double_double_test() {
+    let options = Options::new();
+    assert_eq!(write(1.0, 0.0, &options), "1.0");
+    assert_eq!(write(0.1, -5.551115123125783e-18, &options), "0.1");
+    assert_eq!(write(-0.1, 5.551115123125783e-18, &options), "-0.1");
+    assert_eq!(
+        write(0.1, 0.0, &options),
+        "0.1000000000000000055511151231257827021181583404541015625"
+    );
+    assert_eq!(
+        write(f64::consts::PI, 1.2246467991473532e-16, &options),
+        "3.1415926535897932384626433832795"
+    );
+    assert_eq!(write(1.0, 1e-32, &options), "1.00000000000000000000000000000001");
+    assert_eq!(write(1e23, 8388608.0, &options), "1.0e23");
+    assert_eq!(write(9007199254740992.0, 1.0, &options), "9.007199254740993e15");
+    assert_eq!(write(1e-300, -2.5059094e-317, &options), "1.0e-300");
+}
+
+#[test]
+fn write_tie_test() {
+    // The low part is exactly half the gap to the adjacent float.
+    let options = Options::new();
+    assert_eq!(write(1.0, 2f64.powi(-53), &options), "1.00000000000000011102230246251565");
+    assert_eq!(
+        write(1.0 + f64::EPSILON, 2f64.powi(-53), &options),
+        "1.00000000000000033306690738754696"
+    );
+    assert_eq!(write(1.0, -2f64.powi(-54), &options), "0.999999999999999944488848768742173");
+}
+
+#[test]
+fn write_normalize_test() {
+    let options = Options::new();
+    assert_eq!(write(1.0, 1.0, &options), "2.0");
+    assert_eq!(write(-2.0, 0.75, &options), "-1.25");
+    assert_eq!(write(1e308, 1e308, &options), "inf");
+}
+
+#[test]
+fn write_special_test() {
+    let options = Options::new();
+    assert_eq!(write(f64::NAN, 0.0, &options), "NaN");
+    assert_eq!(write(f64::NEG_INFINITY, 0.0, &options), "-inf");
+    assert_eq!(write(0.0, 0.0, &options), "0.0");
+}
+
+#[test]
+fn write_options_test() {
+    let options = Options::builder()
+        .decimal_point(b',')
+        .exponent(b'E')
+        .positive_exponent_break(num::NonZeroI32::new(2))
+        .build()
+        .unwrap();
+    assert_eq!(write(1e23, 8388608.0, &options), "1,0E23");
+    assert_eq!(write(1.5, 0.0, &options), "1,5");
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    assert_eq!(write(100.0, 0.0, &options), "100");
+    assert_eq!(write(1e10, 0.0, &options), "1e10");
+}
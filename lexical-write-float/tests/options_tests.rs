@@ -1,4 +1,5 @@
 use core::num;
+use lexical_util::error::Error;
 use lexical_write_float::options::{self, Options, OptionsBuilder};
 
 #[test]
@@ -61,6 +62,17 @@ fn invalid_inf_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_exponent_digits_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(21));
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidExponentDigits));
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(20));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn builder_test() {
     let mut builder = OptionsBuilder::default();
@@ -75,6 +87,7 @@ fn builder_test() {
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(2));
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
@@ -86,6 +99,7 @@ fn builder_test() {
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_min_exponent_digits().unwrap().get(), 2);
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
@@ -106,6 +120,7 @@ fn options_test() {
         opts.set_decimal_point(b',');
         opts.set_nan_string(Some(b"nan"));
         opts.set_inf_string(Some(b"Infinity"));
+        opts.set_min_exponent_digits(num::NonZeroUsize::new(2));
     }
 
     assert_eq!(opts.max_significant_digits().unwrap().get(), 10);
@@ -118,8 +133,56 @@ fn options_test() {
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(opts.min_exponent_digits().unwrap().get(), 2);
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn preset_test() {
+    assert_eq!(Options::preset("STANDARD"), Some(options::STANDARD));
+    assert_eq!(Options::preset("PYTHON_LITERAL"), Some(options::PYTHON_LITERAL));
+    assert_eq!(Options::preset("python_literal"), None);
+    for (name, preset) in options::PRESETS.iter() {
+        assert!(preset.is_valid(), "{}", name);
+        assert_eq!(Options::preset(name).as_ref(), Some(preset));
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_test() {
+    use lexical_write_float::options::RoundMode;
+
+    let json = serde_json::to_string(&Options::new()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"max_significant_digits":null,"min_significant_digits":null,"positive_exponent_break":null,"negative_exponent_break":null,"round_mode":"round","trim_floats":false,"exponent":"e","decimal_point":".","nan_string":"NaN","inf_string":"inf","min_exponent_digits":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), Options::new());
+    assert_eq!(serde_json::from_str::<Options>("{}").unwrap(), Options::new());
+    for (_, preset) in options::PRESETS.iter() {
+        let json = serde_json::to_string(preset).unwrap();
+        assert_eq!(&serde_json::from_str::<Options>(&json).unwrap(), preset);
+    }
+
+    let options: Options = serde_json::from_str(
+        r#"{"preset":"JSON","max_significant_digits":5,"round_mode":"truncate","negative_exponent_break":-3}"#,
+    )
+    .unwrap();
+    assert_eq!(options.nan_string(), options::JSON.nan_string());
+    assert_eq!(options.max_significant_digits(), num::NonZeroUsize::new(5));
+    assert_eq!(options.round_mode(), RoundMode::Truncate);
+    assert_eq!(options.negative_exponent_break(), num::NonZeroI32::new(-3));
+
+    assert!(serde_json::from_str::<Options>(r#"{"preset":"UNKNOWN"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"nan_string":"inf"}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"positive_exponent_break":-1}"#).is_err());
+    assert!(serde_json::from_str::<Options>(r#"{"min_exponent_digits":0}"#).is_err());
+    let result = serde_json::from_str::<Options>(
+        r#"{"min_significant_digits":10,"max_significant_digits":5}"#,
+    );
+    assert!(result.is_err());
+}
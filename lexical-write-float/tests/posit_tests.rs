@@ -0,0 +1,49 @@
+#![cfg(feature = "posit")]
+
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_util::posit::{Posit16, Posit32};
+use lexical_write_float::posit::write_posit;
+use lexical_write_float::{Options, BUFFER_SIZE};
+
+fn write<P: lexical_util::posit::Posit>(posit: P, options: &Options) -> String {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = write_posit::<P, { STANDARD }>(posit, &mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_posit_test() {
+    let options = Options::new();
+    assert_eq!(write(Posit16::from_bits(0x4000), &options), "1.0");
+    assert_eq!(write(Posit16::from_bits(0xC800), &options), "-0.5");
+    assert_eq!(write(Posit16::ZERO, &options), "0.0");
+    assert_eq!(write(Posit16::NAR, &options), "NaN");
+    assert_eq!(write(Posit16::MAX, &options), "7.0e16");
+    assert_eq!(write(Posit16::MIN_POSITIVE, &options), "1.0e-17");
+    assert_eq!(write(Posit16::from_f64(0.1), &options), "0.1");
+    assert_eq!(write(Posit32::from_f64(0.1), &options), "0.1");
+    assert_eq!(write(Posit32::from_f64(core::f64::consts::PI), &options), "3.14159265");
+
+    // Adjacent posits near 1 need more digits than `1.0` or `1.001`.
+    assert_eq!(write(Posit16::from_bits(0x4001), &options), "1.0005");
+    assert_eq!(write(Posit16::from_bits(0x4002), &options), "1.001");
+
+    // Options may shorten the digits further.
+    let digits = num::NonZeroUsize::new(3);
+    let options = Options::builder().max_significant_digits(digits).build().unwrap();
+    assert_eq!(write(Posit32::from_f64(core::f64::consts::PI), &options), "3.14");
+}
+
+#[test]
+fn roundtrip_test() {
+    let options = Options::new();
+    for bits in 0..=u16::MAX {
+        let posit = Posit16::from_bits(bits);
+        if !posit.is_nar() {
+            let string = write(posit, &options);
+            let value = string.parse::<f64>().unwrap();
+            assert_eq!(Posit16::from_f64(value), posit, "{}", string);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use lexical_write_float::printf::{
+    write_printf,
+    write_printf_hex,
+    HexSignificand,
+    PrintfStyle,
+    PRINTF_BUFFER_SIZE,
+};
+
+fn write(value: f64, precision: usize, style: PrintfStyle) -> String {
+    let mut buffer = [b'\x00'; PRINTF_BUFFER_SIZE];
+    let bytes = write_printf(value, precision, style, &mut buffer);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_glibc_test() {
+    // The expected strings are from `printf("%.17g", value)` with glibc.
+    let style = PrintfStyle::Glibc;
+    assert_eq!(write(0.1, 17, style), "0.10000000000000001");
+    assert_eq!(write(0.3, 17, style), "0.29999999999999999");
+    assert_eq!(write(2.0 / 3.0, 17, style), "0.66666666666666663");
+    assert_eq!(write(1.0, 17, style), "1");
+    assert_eq!(write(-2.5, 17, style), "-2.5");
+    assert_eq!(write(1e8, 17, style), "100000000");
+    assert_eq!(write(1e17, 17, style), "1e+17");
+    assert_eq!(write(1e-5, 17, style), "1.0000000000000001e-05");
+    assert_eq!(write(0.0001, 17, style), "0.0001");
+    assert_eq!(write(5e-324, 17, style), "4.9406564584124654e-324");
+    assert_eq!(write(1.7976931348623157e308, 17, style), "1.7976931348623157e+308");
+}
+
+#[test]
+fn write_msvc_test() {
+    let style = PrintfStyle::Msvc;
+    assert_eq!(write(0.1, 17, style), "0.10000000000000001");
+    assert_eq!(write(1e17, 17, style), "1e+017");
+    assert_eq!(write(1e-5, 17, style), "1.0000000000000001e-005");
+    assert_eq!(write(5e-324, 17, style), "4.9406564584124654e-324");
+    assert_eq!(write(123456789.0, 6, style), "1.23457e+008");
+}
+
+#[test]
+fn write_precision_test() {
+    // The expected strings are from `printf("%.*g", precision, value)` with glibc.
+    let style = PrintfStyle::Glibc;
+    assert_eq!(write(123456789.0, 6, style), "1.23457e+08");
+    assert_eq!(write(2.0 / 3.0, 6, style), "0.666667");
+    assert_eq!(write(1.7976931348623157e308, 0, style), "2e+308");
+    assert_eq!(write(2.0 / 3.0, 1, style), "0.7");
+    // Exact ties round to even.
+    assert_eq!(write(1.5, 1, style), "2");
+    assert_eq!(write(2.5, 1, style), "2");
+    assert_eq!(write(-2.5, 0, style), "-2");
+    assert_eq!(write(0.1, 60, style), "0.1000000000000000055511151231257827021181583404541015625");
+    assert_eq!(write(1e23, 30, style), "99999999999999991611392");
+    assert_eq!(write(1e23, 20, style), "9.9999999999999991611e+22");
+}
+
+#[test]
+fn write_special_test() {
+    let style = PrintfStyle::Msvc;
+    assert_eq!(write(0.0, 17, style), "0");
+    assert_eq!(write(-0.0, 17, style), "-0");
+    assert_eq!(write(f64::INFINITY, 17, style), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, 17, style), "-inf");
+    assert_eq!(write(f64::NAN, 17, style), "nan");
+    assert_eq!(write(-f64::NAN, 17, style), "-nan");
+}
+
+fn write_hex(value: f64, precision: Option<usize>, significand: HexSignificand) -> String {
+    let mut buffer = [b'\x00'; PRINTF_BUFFER_SIZE];
+    let bytes = write_printf_hex(value, precision, significand, &mut buffer);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_hex_test() {
+    // The expected strings are from `printf("%a", value)` with glibc.
+    let significand = HexSignificand::Denormalized;
+    assert_eq!(write_hex(1.0, None, significand), "0x1p+0");
+    assert_eq!(write_hex(3.0, None, significand), "0x1.8p+1");
+    assert_eq!(write_hex(-2.5, None, significand), "-0x1.4p+1");
+    assert_eq!(write_hex(0.1, None, significand), "0x1.999999999999ap-4");
+    assert_eq!(write_hex(0.0, None, significand), "0x0p+0");
+    assert_eq!(write_hex(-0.0, None, significand), "-0x0p+0");
+    assert_eq!(write_hex(f64::MAX, None, significand), "0x1.fffffffffffffp+1023");
+    assert_eq!(write_hex(f64::MIN_POSITIVE, None, significand), "0x1p-1022");
+    assert_eq!(write_hex(5e-324, None, significand), "0x0.0000000000001p-1022");
+    assert_eq!(write_hex(1e-310, None, significand), "0x0.012688b70e62bp-1022");
+    assert_eq!(write_hex(f64::INFINITY, None, significand), "inf");
+    assert_eq!(write_hex(f64::NEG_INFINITY, None, significand), "-inf");
+    assert_eq!(write_hex(f64::NAN, None, significand), "nan");
+
+    // Subnormals are normalized like musl.
+    let significand = HexSignificand::Normalized;
+    assert_eq!(write_hex(3.0, None, significand), "0x1.8p+1");
+    assert_eq!(write_hex(0.0, None, significand), "0x0p+0");
+    assert_eq!(write_hex(f64::MIN_POSITIVE, None, significand), "0x1p-1022");
+    assert_eq!(write_hex(5e-324, None, significand), "0x1p-1074");
+    assert_eq!(write_hex(1e-310, None, significand), "0x1.2688b70e62bp-1030");
+}
+
+#[test]
+fn write_hex_precision_test() {
+    // The Microsoft C runtime writes all 13 hex digits.
+    let significand = HexSignificand::Denormalized;
+    assert_eq!(write_hex(1.5, Some(13), significand), "0x1.8000000000000p+0");
+    assert_eq!(write_hex(0.0, Some(13), significand), "0x0.0000000000000p+0");
+    assert_eq!(write_hex(3.0, Some(15), significand), "0x1.800000000000000p+1");
+    assert_eq!(write_hex(1.0, Some(3), significand), "0x1.000p+0");
+    assert_eq!(write_hex(0.1, Some(1), significand), "0x1.ap-4");
+    assert_eq!(write_hex(0.1, Some(12), significand), "0x1.99999999999ap-4");
+    assert_eq!(write_hex(5e-324, Some(2), significand), "0x0.00p-1022");
+
+    // Ties round to even, and may carry into the leading digit.
+    assert_eq!(write_hex(2.5, Some(0), significand), "0x1p+1");
+    assert_eq!(write_hex(1.5, Some(0), significand), "0x2p+0");
+    assert_eq!(write_hex(-1.96875, Some(1), significand), "-0x2.0p+0");
+    assert_eq!(write_hex(1.9375, Some(1), significand), "0x1.fp+0");
+
+    // Normalized significands carry into the exponent instead.
+    let significand = HexSignificand::Normalized;
+    assert_eq!(write_hex(1.5, Some(0), significand), "0x1p+1");
+    assert_eq!(write_hex(-1.96875, Some(1), significand), "-0x1.0p+1");
+    assert_eq!(write_hex(5e-324, Some(2), significand), "0x1.00p-1074");
+    assert_eq!(write_hex(f64::MAX, Some(0), significand), "0x1p+1024");
+}
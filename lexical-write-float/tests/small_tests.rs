@@ -0,0 +1,91 @@
+use core::num;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::format::{NumberFormatBuilder, STANDARD};
+#[cfg(not(feature = "compact"))]
+use lexical_write_float::algorithm::write_float as write_float_decimal;
+#[cfg(feature = "compact")]
+use lexical_write_float::compact::write_float as write_float_decimal;
+use lexical_write_float::float::RawFloat;
+use lexical_write_float::{small, Options, ToLexical, ToLexicalWithOptions};
+
+const DECIMAL: u128 = NumberFormatBuilder::decimal();
+
+fn write_small<T: RawFloat, const FORMAT: u128>(f: T, options: &Options) -> Option<String> {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let count = unsafe { small::write_small::<_, FORMAT>(f, &mut buffer, options) }?;
+    Some(String::from_utf8(buffer[..count].to_vec()).unwrap())
+}
+
+fn write_decimal<T: RawFloat, const FORMAT: u128>(f: T, options: &Options) -> String {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let count = unsafe { write_float_decimal::<_, FORMAT>(f, &mut buffer, options) };
+    String::from_utf8(buffer[..count].to_vec()).unwrap()
+}
+
+fn check_small<T: RawFloat, const FORMAT: u128>(f: T, options: &Options) {
+    if let Some(actual) = write_small::<_, FORMAT>(f, options) {
+        assert_eq!(actual, write_decimal::<_, FORMAT>(f, options));
+    }
+}
+
+#[test]
+fn write_small_test() {
+    let options = Options::new();
+    assert_eq!(write_small::<_, DECIMAL>(0.0f64, &options), Some("0.0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(7.0f64, &options), Some("7.0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(1e3f32, &options), Some("1000.0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(1e9f64, &options), Some("1000000000.0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(1e10f64, &options), None);
+    assert_eq!(write_small::<_, DECIMAL>(11.0f64, &options), None);
+    assert_eq!(write_small::<_, DECIMAL>(0.5f64, &options), None);
+    assert_eq!(write_small::<_, DECIMAL>(1e-5f64, &options), None);
+
+    let options = Options::builder().trim_floats(true).decimal_point(b',').build().unwrap();
+    assert_eq!(write_small::<_, DECIMAL>(0.0f64, &options), Some("0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(100.0f64, &options), Some("100".into()));
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(write_small::<_, DECIMAL>(1.0f32, &options), Some("1,0".into()));
+
+    // Options that change the output of the algorithms aren't cached.
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(3)).build().unwrap();
+    assert_eq!(write_small::<_, DECIMAL>(1.0f64, &options), None);
+    let options =
+        Options::builder().positive_exponent_break(num::NonZeroI32::new(2)).build().unwrap();
+    assert_eq!(write_small::<_, DECIMAL>(100.0f64, &options), Some("100.0".into()));
+    assert_eq!(write_small::<_, DECIMAL>(1000.0f64, &options), None);
+}
+
+#[test]
+fn write_small_matches_decimal_test() {
+    let values: [f64; 13] = [0.0, 1.0, 2.0, 5.0, 9.0, 10.0, 100.0, 1e5, 1e8, 1e9, 1e10, 20.0, 0.1];
+    let builders = [
+        Options::builder(),
+        Options::builder().trim_floats(true),
+        Options::builder().decimal_point(b','),
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(1)),
+        Options::builder().positive_exponent_break(num::NonZeroI32::new(1)),
+        Options::builder().negative_exponent_break(num::NonZeroI32::new(-1)),
+    ];
+    for builder in builders.iter() {
+        let options = builder.build().unwrap();
+        for &value in values.iter() {
+            check_small::<_, DECIMAL>(value as f32, &options);
+            check_small::<_, DECIMAL>(value, &options);
+        }
+    }
+}
+
+#[test]
+fn to_lexical_small_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(0.0f64.to_lexical(&mut buffer), b"0.0");
+    assert_eq!((-0.0f64).to_lexical(&mut buffer), b"0.0");
+    assert_eq!((-5.0f32).to_lexical(&mut buffer), b"-5.0");
+    assert_eq!(1e6f64.to_lexical(&mut buffer), b"1000000.0");
+    assert_eq!(1e10f64.to_lexical(&mut buffer), b"1.0e10");
+
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    let result = 1e4f64.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+    assert_eq!(result, b"10000");
+}
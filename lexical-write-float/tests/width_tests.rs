@@ -0,0 +1,111 @@
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_write_float::width::write_width;
+use lexical_write_float::{Options, ToLexicalWithOptions, BUFFER_SIZE};
+
+fn write<F>(float: F, width: usize, options: &Options) -> Option<String>
+where
+    F: ToLexicalWithOptions<Options = Options> + lexical_util::num::Float,
+{
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = write_width::<F, { STANDARD }>(float, width, &mut buffer, options)?;
+    Some(String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[test]
+fn write_width_test() {
+    let options = Options::new();
+    assert_eq!(write(1.5f64, 8, &options), Some("1.5".into()));
+    assert_eq!(write(-0.1f64, 4, &options), Some("-0.1".into()));
+    assert_eq!(write(core::f64::consts::PI, 6, &options), Some("3.1416".into()));
+    assert_eq!(write(2.0f64 / 3.0, 5, &options), Some("0.667".into()));
+    assert_eq!(write(9.99f64, 3, &options), Some("1e1".into()));
+    assert_eq!(write(123456.789f64, 8, &options), Some("123456.8".into()));
+    assert_eq!(write(123456.789f64, 6, &options), Some("1.23e5".into()));
+    assert_eq!(write(1e20f64, 6, &options), Some("1.0e20".into()));
+    assert_eq!(write(1.2345e-10f32, 7, &options), Some("1.2e-10".into()));
+    assert_eq!(write(-1.2345e-300f64, 7, &options), Some("-1e-300".into()));
+    assert_eq!(write(-1.2345e-300f64, 6, &options), None);
+    assert_eq!(write(-1.2345e-300f64, 9, &options), Some("-1.2e-300".into()));
+    assert_eq!(write(f64::NAN, 3, &options), Some("NaN".into()));
+    assert_eq!(write(f64::INFINITY, 2, &options), None);
+    assert_eq!(write(1.0f64, 0, &options), None);
+}
+
+#[test]
+fn write_width_boundary_test() {
+    let options = Options::new();
+    // Exponent-only floats are written before trimmed floats.
+    assert_eq!(write(9.99f64, 4, &options), Some("9.99".into()));
+    assert_eq!(write(9.99f64, 2, &options), Some("10".into()));
+    assert_eq!(write(-9.99f64, 4, &options), Some("-1e1".into()));
+    assert_eq!(write(5.0f64, 3, &options), Some("5.0".into()));
+    assert_eq!(write(5.0f64, 2, &options), Some("5".into()));
+    assert_eq!(write(1e-300f64, 6, &options), Some("1e-300".into()));
+    assert_eq!(write(1e-300f64, 5, &options), None);
+    assert_eq!(write(1e20f64, 4, &options), Some("1e20".into()));
+    assert_eq!(write(123456.789f64, 3, &options), Some("1e5".into()));
+    assert_eq!(write(123456.789f64, 2, &options), None);
+    assert_eq!(write(0.1f64, 3, &options), Some("0.1".into()));
+    assert_eq!(write(0.1f64, 2, &options), None);
+
+    // Zero is only shorter when trimmed.
+    assert_eq!(write(0.0f64, 3, &options), Some("0.0".into()));
+    assert_eq!(write(0.0f64, 1, &options), Some("0".into()));
+    assert_eq!(write(0.0f64, 0, &options), None);
+}
+
+#[test]
+fn write_width_options_test() {
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    assert_eq!(write(123456.789f64, 6, &options), Some("123457".into()));
+    assert_eq!(write(9.96f64, 2, &options), Some("10".into()));
+    assert_eq!(write(1e20f64, 4, &options), Some("1e20".into()));
+    assert_eq!(write(-1.2345e-300f64, 7, &options), Some("-1e-300".into()));
+
+    // Scientific notation from the exponent breaks is kept if it fits.
+    let options =
+        Options::builder().positive_exponent_break(num::NonZeroI32::new(2)).build().unwrap();
+    assert_eq!(write(12345.0f64, 10, &options), Some("1.2345e4".into()));
+    assert_eq!(write(12345.0f64, 7, &options), Some("12345.0".into()));
+
+    // Large exponent breaks write floats without scientific notation.
+    let options =
+        Options::builder().negative_exponent_break(num::NonZeroI32::new(-1000)).build().unwrap();
+    assert_eq!(write(1e-8f64, 10, &options), Some("0.00000001".into()));
+    assert_eq!(write(1e-300f64, 8, &options), Some("1.0e-300".into()));
+
+    // The minimum significant digits are ignored, and the maximum is kept.
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(10)).build().unwrap();
+    assert_eq!(write(1.25f64, 10, &options), Some("1.25".into()));
+    let options =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(2)).build().unwrap();
+    assert_eq!(write(1.25f64, 10, &options), Some("1.2".into()));
+    assert_eq!(write(0.125f64, 4, &options), Some("0.12".into()));
+    assert_eq!(write(0.125f64, 3, &options), Some("0.1".into()));
+}
+
+#[test]
+fn write_width_buffer_test() {
+    let options = Options::new();
+    let mut buffer = [b'\x00'; 4];
+    let bytes = write_width::<_, { STANDARD }>(3.25f64, 10, &mut buffer, &options);
+    assert_eq!(bytes.map(|x| x.to_vec()), Some(b"3.25".to_vec()));
+    let bytes = write_width::<_, { STANDARD }>(0.0625f64, 10, &mut buffer, &options);
+    assert_eq!(bytes.map(|x| x.to_vec()), Some(b"0.06".to_vec()));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn write_width_no_exponent_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    let options = Options::new();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = write_width::<_, FORMAT>(9.99f64, 3, &mut buffer, &options);
+    assert_eq!(bytes.map(|x| x.to_vec()), Some(b"10".to_vec()));
+    let bytes = write_width::<_, FORMAT>(123456.789f64, 5, &mut buffer, &options);
+    assert_eq!(bytes.map(|x| x.to_vec()), None);
+}
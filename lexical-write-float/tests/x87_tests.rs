@@ -0,0 +1,66 @@
+use core::num;
+use lexical_util::format::STANDARD;
+use lexical_write_float::x87::{write_x87, X87_BUFFER_SIZE};
+use lexical_write_float::Options;
+
+fn write(sign_exponent: u16, mant: u64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; X87_BUFFER_SIZE];
+    let bytes = write_x87::<{ STANDARD }>(sign_exponent, mant, &mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn write_x87_test() {
+    let options = Options::new();
+    assert_eq!(write(0x3FFF, 0x8000000000000000, &options), "1.0");
+    assert_eq!(write(0xBFFF, 0x8000000000000000, &options), "-1.0");
+    assert_eq!(write(0x3FFB, 0xCCCCCCCCCCCCCCCD, &options), "0.1");
+    assert_eq!(write(0x4000, 0xC90FDAA22168C235, &options), "3.1415926535897932385");
+    assert_eq!(write(0x4034, 0x8000000000000000, &options), "9.007199254740992e15");
+    assert_eq!(write(0x3FFE, 0xFFFFFFFFFFFFFFFF, &options), "0.99999999999999999995");
+    assert_eq!(write(0x403E, 0xFFFFFFFFFFFFFFFF, &options), "1.8446744073709551615e19");
+}
+
+#[test]
+fn write_limits_test() {
+    let options = Options::new();
+    assert_eq!(write(0x7FFE, 0xFFFFFFFFFFFFFFFF, &options), "1.189731495357231765e4932");
+    assert_eq!(write(0x0001, 0x8000000000000000, &options), "3.3621031431120935063e-4932");
+    assert_eq!(write(0x0000, 0x7FFFFFFFFFFFFFFF, &options), "3.362103143112093506e-4932");
+    assert_eq!(write(0x0000, 0x0000000000000001, &options), "4.0e-4951");
+    assert_eq!(write(0x8000, 0x0000000000000001, &options), "-4.0e-4951");
+}
+
+#[test]
+fn write_unnormal_test() {
+    // The integer bit is clear, so the significand is normalized.
+    let options = Options::new();
+    assert_eq!(write(0x3FFF, 0x4000000000000000, &options), "0.5");
+    assert_eq!(write(0x0001, 0x4000000000000000, &options), "1.681051571556046753e-4932");
+}
+
+#[test]
+fn write_special_test() {
+    let options = Options::new();
+    assert_eq!(write(0x7FFF, 0xC000000000000000, &options), "NaN");
+    assert_eq!(write(0x7FFF, 0x4000000000000001, &options), "NaN");
+    assert_eq!(write(0x7FFF, 0x8000000000000000, &options), "inf");
+    assert_eq!(write(0xFFFF, 0x8000000000000000, &options), "-inf");
+    assert_eq!(write(0x0000, 0, &options), "0.0");
+    // Like an `f64`, negative zero is written without the sign.
+    assert_eq!(write(0x8000, 0, &options), "0.0");
+}
+
+#[test]
+fn write_options_test() {
+    let options = Options::builder()
+        .decimal_point(b',')
+        .exponent(b'E')
+        .positive_exponent_break(num::NonZeroI32::new(2))
+        .build()
+        .unwrap();
+    assert_eq!(write(0x403E, 0xFFFFFFFFFFFFFFFF, &options), "1,8446744073709551615E19");
+    assert_eq!(write(0x3FFF, 0xC000000000000000, &options), "1,5");
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    assert_eq!(write(0x4005, 0xC800000000000000, &options), "100");
+}
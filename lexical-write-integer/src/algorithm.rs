@@ -153,6 +153,41 @@ unsafe fn write_step_digits<T: UnsignedInteger>(
     end
 }
 
+/// Write integral digits to buffer using the extended alphabet.
+///
+/// Radixes above 36 have no pre-computed tables, since they would
+/// require too much static storage, so this writes 1 digit at a time.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is large enough to hold as many digits
+/// that can be in the largest value of `T`, in radix `N`.
+#[inline]
+pub unsafe fn algorithm_extended<T>(mut value: T, radix: u32, buffer: &mut [u8]) -> usize
+where
+    T: UnsignedInteger,
+{
+    debug_assert_radix(radix);
+
+    // SAFETY: All of these are safe for the buffer writes as long as
+    // the buffer is large enough to hold `T::MAX` digits in radix `N`,
+    // and the radix is valid, so `r < 62`.
+    let radix = T::from_u32(radix);
+    let mut index = buffer.len();
+    while value >= radix {
+        let r = value % radix;
+        value /= radix;
+        index -= 1;
+        unsafe { index_unchecked_mut!(buffer[index]) = digit_to_char(u32::as_cast(r)) };
+    }
+
+    // Decode last digit.
+    index -= 1;
+    unsafe { index_unchecked_mut!(buffer[index]) = digit_to_char(u32::as_cast(value)) };
+
+    index
+}
+
 /// Optimized implementation for radix-N numbers.
 ///
 /// # Safety
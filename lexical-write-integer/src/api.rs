@@ -5,10 +5,20 @@
 use crate::options::Options;
 use crate::write::WriteInteger;
 use lexical_util::assert::{assert_buffer, debug_assert_buffer};
+use lexical_util::constants::FormattedSize;
 use lexical_util::format::{NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
+/// Check if a buffer is sufficiently large.
+#[inline]
+fn check_buffer<T: FormattedSize>(radix: u32, len: usize) -> bool {
+    match radix {
+        10 => len >= T::FORMATTED_SIZE_DECIMAL,
+        _ => len >= T::FORMATTED_SIZE,
+    }
+}
+
 // UNSIGNED
 
 /// Callback for unsigned integer formatter.
@@ -110,6 +120,18 @@ macro_rules! unsigned_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8])
+                -> Option<&'a mut [u8]>
+            {
+                if check_buffer::<$narrow>(10, bytes.len()) {
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
+                    Some(unsafe { self.to_lexical_unchecked(bytes) })
+                } else {
+                    None
+                }
+            }
         }
 
         impl ToLexicalWithOptions for $narrow {
@@ -143,6 +165,24 @@ macro_rules! unsigned_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Option<&'a mut [u8]>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if format.is_valid() && check_buffer::<$narrow>(format.radix(), bytes.len()) {
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                    Some(unsafe {
+                        self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options)
+                    })
+                } else {
+                    None
+                }
+            }
         }
     )*)
 }
@@ -150,8 +190,8 @@ macro_rules! unsigned_to_lexical {
 to_lexical! {}
 to_lexical_with_options! {}
 unsigned_to_lexical! {
-    u8 u32 ;
-    u16 u32 ;
+    u8 u8 ;
+    u16 u16 ;
     u32 u32 ;
     u64 u64 ;
     u128 u128 ;
@@ -187,6 +227,18 @@ macro_rules! signed_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical<'a>(self, bytes: &'a mut [u8])
+                -> Option<&'a mut [u8]>
+            {
+                if check_buffer::<$narrow>(10, bytes.len()) {
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
+                    Some(unsafe { self.to_lexical_unchecked(bytes) })
+                } else {
+                    None
+                }
+            }
         }
 
         impl ToLexicalWithOptions for $narrow {
@@ -220,13 +272,31 @@ macro_rules! signed_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn try_to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> Option<&'a mut [u8]>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if format.is_valid() && check_buffer::<$narrow>(format.radix(), bytes.len()) {
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                    Some(unsafe {
+                        self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options)
+                    })
+                } else {
+                    None
+                }
+            }
         }
     )*)
 }
 
 signed_to_lexical! {
-    i8 i32 u32 ;
-    i16 i32 u32 ;
+    i8 i8 u8 ;
+    i16 i16 u16 ;
     i32 i32 u32 ;
     i64 i64 u64 ;
     i128 i128 u128 ;
@@ -32,7 +32,7 @@ pub unsafe fn write_digits<T: UnsignedInteger>(
 
     // SAFETY: All of these are safe for the buffer writes as long as
     // the buffer is large enough to hold `T::FORMATTED_SIZE` digits,
-    // and `radix <= 36`.
+    // and `radix <= 62`.
 
     // Decode all but the last digit.
     let radix = T::from_u32(radix);
@@ -3,7 +3,9 @@
 //! An optimization for decimal is pre-computing the number of digits written
 //! prior to actually writing digits, avoiding the use of temporary buffers.
 //! This scales well with integer size, short of `u128`, due to the slower
-//! division algorithms required.
+//! division algorithms required. 8-bit and 16-bit integers have at most
+//! 3 and 5 digits, so they're written with direct table lookups rather
+//! than the general loop.
 //!
 //! See [`Algorithm.md`] for a more detailed description of the algorithm
 //! choice here.
@@ -17,6 +19,7 @@ use crate::algorithm::{algorithm, algorithm_u128};
 use crate::table::DIGIT_TO_BASE10_SQUARED;
 use lexical_util::format::{RADIX, RADIX_SHIFT, STANDARD};
 use lexical_util::num::UnsignedInteger;
+use lexical_util::pow::{POW10_U128, POW10_U32, POW10_U64};
 
 /// Fast integral log2.
 ///
@@ -106,6 +109,16 @@ pub fn fast_digit_count(x: u32) -> usize {
     count as usize
 }
 
+/// Calculate the number of digits in a 32-bit integer, using only 32-bit arithmetic.
+///
+/// `fast_digit_count` requires a 64-bit addition, which takes multiple
+/// instructions on 16-bit and 32-bit microcontrollers, so this is used
+/// on targets without 64-bit pointers.
+#[inline]
+pub fn narrow_digit_count(x: u32) -> usize {
+    fallback_digit_count(x, &POW10_U32[1..])
+}
+
 /// Slightly slower algorithm to calculate the number of digits in an integer.
 ///
 /// This uses no static storage, and uses a fast log10(2) estimation
@@ -141,87 +154,50 @@ macro_rules! digit_count_unimpl {
     )*)
 }
 
-digit_count_unimpl! { u8 u16 usize }
+digit_count_unimpl! { usize }
+
+impl DigitCount for u8 {
+    #[inline]
+    fn digit_count(self) -> usize {
+        1 + (self >= 10) as usize + (self >= 100) as usize
+    }
+}
+
+impl DigitCount for u16 {
+    #[inline]
+    fn digit_count(self) -> usize {
+        1 + (self >= 10) as usize
+            + (self >= 100) as usize
+            + (self >= 1000) as usize
+            + (self >= 10000) as usize
+    }
+}
 
 impl DigitCount for u32 {
     #[inline]
+    #[cfg(target_pointer_width = "64")]
     fn digit_count(self) -> usize {
         fast_digit_count(self)
     }
+
+    #[inline]
+    #[cfg(not(target_pointer_width = "64"))]
+    fn digit_count(self) -> usize {
+        narrow_digit_count(self)
+    }
 }
 
 impl DigitCount for u64 {
     #[inline]
     fn digit_count(self) -> usize {
-        const TABLE: [u64; 19] = [
-            10,
-            100,
-            1000,
-            10000,
-            100000,
-            1000000,
-            10000000,
-            100000000,
-            1000000000,
-            10000000000,
-            100000000000,
-            1000000000000,
-            10000000000000,
-            100000000000000,
-            1000000000000000,
-            10000000000000000,
-            100000000000000000,
-            1000000000000000000,
-            10000000000000000000,
-        ];
-        fallback_digit_count(self, &TABLE)
+        fallback_digit_count(self, &POW10_U64[1..])
     }
 }
 
 impl DigitCount for u128 {
     #[inline]
     fn digit_count(self) -> usize {
-        const TABLE: [u128; 38] = [
-            10,
-            100,
-            1000,
-            10000,
-            100000,
-            1000000,
-            10000000,
-            100000000,
-            1000000000,
-            10000000000,
-            100000000000,
-            1000000000000,
-            10000000000000,
-            100000000000000,
-            1000000000000000,
-            10000000000000000,
-            100000000000000000,
-            1000000000000000000,
-            10000000000000000000,
-            100000000000000000000,
-            1000000000000000000000,
-            10000000000000000000000,
-            100000000000000000000000,
-            1000000000000000000000000,
-            10000000000000000000000000,
-            100000000000000000000000000,
-            1000000000000000000000000000,
-            10000000000000000000000000000,
-            100000000000000000000000000000,
-            1000000000000000000000000000000,
-            10000000000000000000000000000000,
-            100000000000000000000000000000000,
-            1000000000000000000000000000000000,
-            10000000000000000000000000000000000,
-            100000000000000000000000000000000000,
-            1000000000000000000000000000000000000,
-            10000000000000000000000000000000000000,
-            100000000000000000000000000000000000000,
-        ];
-        fallback_digit_count(self, &TABLE)
+        fallback_digit_count(self, &POW10_U128[1..])
     }
 }
 
@@ -250,7 +226,93 @@ macro_rules! decimal_unimpl {
     )*);
 }
 
-decimal_unimpl! { u8 u16 usize }
+decimal_unimpl! { usize }
+
+/// Write a single digit at the index.
+///
+/// # Safety
+///
+/// Safe if `index < buffer.len()` and `value < 10`.
+#[inline(always)]
+unsafe fn write_digit(value: u32, buffer: &mut [u8], index: usize) {
+    debug_assert!(value < 10);
+    unsafe { index_unchecked_mut!(buffer[index]) = b'0' + value as u8 };
+}
+
+/// Write 2 digits at the index, from the base10 squared table.
+///
+/// # Safety
+///
+/// Safe if `index + 1 < buffer.len()` and `value < 100`.
+#[inline(always)]
+unsafe fn write_digit_pair(value: u32, buffer: &mut [u8], index: usize) {
+    debug_assert!(value < 100);
+    let r = 2 * value as usize;
+    unsafe {
+        index_unchecked_mut!(buffer[index]) = index_unchecked!(DIGIT_TO_BASE10_SQUARED[r]);
+        index_unchecked_mut!(buffer[index + 1]) = index_unchecked!(DIGIT_TO_BASE10_SQUARED[r + 1]);
+    }
+}
+
+/// Write a value with at most 4 digits, without leading zeros.
+///
+/// # Safety
+///
+/// Safe if `value < 10000` and the buffer can hold `value.digit_count()`
+/// elements.
+#[inline(always)]
+unsafe fn write_4(value: u32, buffer: &mut [u8]) -> usize {
+    debug_assert!(value < 10000);
+    // SAFETY: safe since each branch writes exactly the number of digits.
+    unsafe {
+        if value < 10 {
+            write_digit(value, buffer, 0);
+            1
+        } else if value < 100 {
+            write_digit_pair(value, buffer, 0);
+            2
+        } else if value < 1000 {
+            write_digit(value / 100, buffer, 0);
+            write_digit_pair(value % 100, buffer, 1);
+            3
+        } else {
+            write_digit_pair(value / 100, buffer, 0);
+            write_digit_pair(value % 100, buffer, 2);
+            4
+        }
+    }
+}
+
+// Small types have at most 3 or 5 digits, so write them with direct
+// table lookups, rather than the general loop and digit count.
+impl Decimal for u8 {
+    #[inline(always)]
+    unsafe fn decimal(self, buffer: &mut [u8]) -> usize {
+        debug_assert!(self.digit_count() <= buffer.len());
+        // SAFETY: safe as long as buffer is large enough to hold the max value.
+        unsafe { write_4(self as u32, buffer) }
+    }
+}
+
+impl Decimal for u16 {
+    #[inline(always)]
+    unsafe fn decimal(self, buffer: &mut [u8]) -> usize {
+        debug_assert!(self.digit_count() <= buffer.len());
+        let value = self as u32;
+        // SAFETY: safe as long as buffer is large enough to hold the max value.
+        unsafe {
+            if value < 10000 {
+                write_4(value, buffer)
+            } else {
+                let low = value % 10000;
+                write_digit(value / 10000, buffer, 0);
+                write_digit_pair(low / 100, buffer, 1);
+                write_digit_pair(low % 100, buffer, 3);
+                5
+            }
+        }
+    }
+}
 
 // Implement decimal for type.
 macro_rules! decimal_impl {
@@ -262,7 +324,8 @@ macro_rules! decimal_impl {
                 let count = self.digit_count();
                 debug_assert!(count <= buffer.len());
                 unsafe {
-                    algorithm(self, 10, &DIGIT_TO_BASE10_SQUARED, &mut buffer[..count]);
+                    let buffer = &mut index_unchecked_mut!(buffer[..count]);
+                    algorithm(self, 10, &DIGIT_TO_BASE10_SQUARED, buffer);
                     count
                 }
             }
@@ -282,7 +345,7 @@ impl Decimal for u128 {
             algorithm_u128::<{ STANDARD }, { RADIX }, { RADIX_SHIFT }>(
                 self,
                 &DIGIT_TO_BASE10_SQUARED,
-                &mut buffer[..count],
+                &mut index_unchecked_mut!(buffer[..count]),
             );
             count
         }
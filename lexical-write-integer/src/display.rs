@@ -0,0 +1,101 @@
+//! Display integers in any radix, without allocating.
+//!
+//! Writing to a slice is awkward in formatting macros, such as for
+//! logging, which need a value that implements [`Display`]. The digits
+//! are written to a buffer on the stack, so no allocation is required,
+//! and the width, fill, alignment and sign flags of the formatter are
+//! applied like for the integer formatting of the standard library.
+//!
+//! [`Display`]: core::fmt::Display
+
+use core::fmt;
+use core::str;
+
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::num::{AsCast, UnsignedInteger};
+use lexical_util::radix::Radix;
+
+/// Display an integer in a radix, without allocating.
+///
+/// The digits use the alphabet of lexical, which is `0-9A-Z` up to radix
+/// 36, and `0-9A-Za-z` for larger radixes. The alternate flag, `{:#}`,
+/// adds the prefixes `0b`, `0o` and `0x` for radixes 2, 8, and 16.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::radix::Radix;
+/// use lexical_write_integer::display::RadixDisplay;
+///
+/// # pub fn main() {
+/// assert_eq!(format!("{}", RadixDisplay(255u8, Radix::HEXADECIMAL)), "FF");
+/// assert_eq!(format!("{:#}", RadixDisplay(-5i32, Radix::BINARY)), "-0b101");
+/// assert_eq!(format!("{:>6}", RadixDisplay(35u64, Radix::new(36).unwrap())), "     Z");
+/// assert_eq!(format!("{:+08}", RadixDisplay(4095i16, Radix::OCTAL)), "+0007777");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RadixDisplay<T>(pub T, pub Radix);
+
+/// Write the digits of an unsigned integer, right-aligned in the buffer.
+/// Returns the index of the first digit.
+fn write_digits<T: UnsignedInteger>(mut value: T, radix: u32, buffer: &mut [u8; 128]) -> usize {
+    let divisor = T::from_u32(radix);
+    let mut index = buffer.len();
+    loop {
+        let digit = u32::as_cast(value % divisor);
+        value /= divisor;
+        index -= 1;
+        buffer[index] = digit_to_char_const(digit, radix);
+        if value == T::ZERO {
+            return index;
+        }
+    }
+}
+
+/// Pad the digits of an integer, with the sign and prefix for the radix.
+fn pad_integral<T: UnsignedInteger>(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    value: T,
+    radix: Radix,
+) -> fmt::Result {
+    // The largest integers have 128 binary digits.
+    let mut buffer = [0u8; 128];
+    let index = write_digits(value, radix.get(), &mut buffer);
+    // SAFETY: safe, since the digits are always ASCII.
+    let digits = unsafe { str::from_utf8_unchecked(&buffer[index..]) };
+    let prefix = match radix.get() {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => "",
+    };
+    f.pad_integral(is_nonnegative, prefix, digits)
+}
+
+macro_rules! unsigned_display_impl {
+    ($($t:ty)*) => ($(
+        impl fmt::Display for RadixDisplay<$t> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                pad_integral(f, true, self.0, self.1)
+            }
+        }
+    )*)
+}
+
+unsigned_display_impl! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! signed_display_impl {
+    ($($t:ty)*) => ($(
+        impl fmt::Display for RadixDisplay<$t> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                pad_integral(f, self.0 >= 0, self.0.unsigned_abs(), self.1)
+            }
+        }
+    )*)
+}
+
+signed_display_impl! { i8 i16 i32 i64 i128 isize }
@@ -16,6 +16,8 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `fuzz` - Promote debug assertions to checks, for fuzzing.
+//! * `serde` - Serialize and deserialize the options.
 //!
 //! # Note
 //!
@@ -48,14 +50,24 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Promote debug assertions to checks when fuzzing, so internal invariants
+// are verified in optimized builds. These shadow the standard macros.
+#[cfg(feature = "fuzz")]
+#[allow(unused_macros)]
+macro_rules! debug_assert {
+    ($($arg:tt)*) => (assert!($($arg)*));
+}
+
 #[macro_use]
 mod index;
 
 pub mod algorithm;
 pub mod compact;
 pub mod decimal;
+pub mod display;
 pub mod options;
 pub mod radix;
+pub mod reserve;
 pub mod table;
 pub mod write;
 
@@ -71,3 +83,4 @@ pub use self::options::{Options, OptionsBuilder};
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::WriteOptions;
+pub use lexical_util::step::DigitCount;
@@ -65,6 +65,8 @@ impl Default for OptionsBuilder {
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", deny_unknown_fields))]
 pub struct Options {}
 
 impl Options {
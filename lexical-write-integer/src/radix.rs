@@ -12,7 +12,7 @@
 #![cfg(feature = "power-of-two")]
 #![doc(hidden)]
 
-use crate::algorithm::{algorithm, algorithm_u128};
+use crate::algorithm::{algorithm, algorithm_extended, algorithm_u128};
 use crate::table::get_table;
 use core::mem;
 use lexical_util::algorithm::copy_to_dst;
@@ -44,7 +44,25 @@ macro_rules! radix_unimpl {
     )*);
 }
 
-radix_unimpl! { u8 u16 usize }
+radix_unimpl! { usize }
+
+// Small types are written as 32-bit values, which cannot overflow.
+macro_rules! radix_small_impl {
+    ($($t:ty)*) => ($(
+        impl Radix for $t {
+            #[inline(always)]
+            unsafe fn radix<const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
+                self,
+                buffer: &mut [u8]
+            ) -> usize {
+                // SAFETY: safe as long as buffer is large enough to hold the max value.
+                unsafe { (self as u32).radix::<FORMAT, MASK, SHIFT>(buffer) }
+            }
+        }
+    )*);
+}
+
+radix_small_impl! { u8 u16 }
 
 // Implement radix for type.
 macro_rules! radix_impl {
@@ -62,8 +80,12 @@ macro_rules! radix_impl {
                 unsafe {
                     let digits = &mut *digits.as_mut_ptr();
                     let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
-                    let table = get_table::<FORMAT, MASK, SHIFT>();
-                    let index = algorithm(self, radix, table, digits);
+                    let index = if cfg!(feature = "radix") && radix > 36 {
+                        algorithm_extended(self, radix, digits)
+                    } else {
+                        let table = get_table::<FORMAT, MASK, SHIFT>();
+                        algorithm(self, radix, table, digits)
+                    };
                     copy_to_dst(buffer, &mut index_unchecked_mut!(digits[index..]))
                 }
             }
@@ -85,8 +107,13 @@ impl Radix for u128 {
         let mut digits: mem::MaybeUninit<[u8; 128]> = mem::MaybeUninit::uninit();
         unsafe {
             let digits = &mut *digits.as_mut_ptr();
-            let table = get_table::<FORMAT, MASK, SHIFT>();
-            let index = algorithm_u128::<FORMAT, MASK, SHIFT>(self, table, digits);
+            let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
+            let index = if cfg!(feature = "radix") && radix > 36 {
+                algorithm_extended(self, radix, digits)
+            } else {
+                let table = get_table::<FORMAT, MASK, SHIFT>();
+                algorithm_u128::<FORMAT, MASK, SHIFT>(self, table, digits)
+            };
             copy_to_dst(buffer, &mut index_unchecked_mut!(digits[index..]))
         }
     }
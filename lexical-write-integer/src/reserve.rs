@@ -0,0 +1,195 @@
+//! Two-phase writes, which reserve the exact number of bytes first.
+//!
+//! Ring buffers and length-prefixed wire protocols need to know how many
+//! bytes a number takes before writing it, but the writers require a
+//! buffer of [`FORMATTED_SIZE_DECIMAL`] bytes, so the digits would
+//! otherwise be written to a temporary buffer and copied. Instead, the
+//! exact length is calculated by counting the digits, which is cheap
+//! compared to writing them, and the digits are later written directly
+//! into a slot of exactly that length.
+//!
+//! Only decimal integers in the standard format are supported: the
+//! length of a float requires generating its shortest digits, which is
+//! most of the work of writing it.
+//!
+//! [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+
+use crate::write::WriteInteger;
+use lexical_util::format::STANDARD;
+use lexical_util::log::digit_count;
+
+/// Integer which can be written into exactly as many bytes as it needs.
+pub trait WriteReserved: Copy {
+    /// Get the number of bytes written by [`to_lexical`](crate::ToLexical::to_lexical).
+    fn formatted_len(self) -> usize;
+
+    /// Write the number to the start of the buffer, returning the number of bytes written.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold [`formatted_len`] elements.
+    ///
+    /// [`formatted_len`]: Self::formatted_len
+    #[doc(hidden)]
+    unsafe fn write_reserved_unchecked(self, bytes: &mut [u8]) -> usize;
+}
+
+macro_rules! unsigned_reserved_impl {
+    ($($t:tt $wide:tt ; )*) => ($(
+        impl WriteReserved for $t {
+            #[inline]
+            fn formatted_len(self) -> usize {
+                digit_count(self as $wide)
+            }
+
+            #[inline]
+            unsafe fn write_reserved_unchecked(self, bytes: &mut [u8]) -> usize {
+                // SAFETY: safe as long as the buffer can hold `formatted_len`
+                // elements, since the decimal back-ends only write the digits.
+                unsafe { (self as $wide).write_mantissa::<$wide, { STANDARD }>(bytes) }
+            }
+        }
+    )*);
+}
+
+unsigned_reserved_impl! {
+    u8 u8 ;
+    u16 u16 ;
+    u32 u32 ;
+    u64 u64 ;
+    u128 u128 ;
+}
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+unsigned_reserved_impl! { usize u32 ; }
+
+#[cfg(target_pointer_width = "64")]
+unsigned_reserved_impl! { usize u64 ; }
+
+macro_rules! signed_reserved_impl {
+    ($($t:ty)*) => ($(
+        impl WriteReserved for $t {
+            #[inline]
+            fn formatted_len(self) -> usize {
+                (self < 0) as usize + self.unsigned_abs().formatted_len()
+            }
+
+            #[inline]
+            unsafe fn write_reserved_unchecked(self, bytes: &mut [u8]) -> usize {
+                let unsigned = self.unsigned_abs();
+                // SAFETY: safe as long as the buffer can hold `formatted_len` elements.
+                unsafe {
+                    if self < 0 {
+                        index_unchecked_mut!(bytes[0]) = b'-';
+                        let bytes = &mut index_unchecked_mut!(bytes[1..]);
+                        unsigned.write_reserved_unchecked(bytes) + 1
+                    } else {
+                        unsigned.write_reserved_unchecked(bytes)
+                    }
+                }
+            }
+        }
+    )*);
+}
+
+signed_reserved_impl! { i8 i16 i32 i64 i128 isize }
+
+/// Integer with the exact number of bytes needed to write it.
+///
+/// This is the first phase of a two-phase write: the length can be used
+/// to reserve space in a ring buffer, or to write a length prefix, and
+/// the number is then written into the reserved bytes with [`commit`],
+/// without a temporary buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::reserve::Reservation;
+///
+/// # pub fn main() {
+/// // Write a length-prefixed number.
+/// let reservation = Reservation::new(-1234i32);
+/// let mut frame = vec![reservation.formatted_len() as u8];
+/// frame.resize(1 + reservation.formatted_len(), 0);
+/// reservation.commit(&mut frame[1..]);
+/// assert_eq!(frame, b"\x05-1234");
+/// # }
+/// ```
+///
+/// [`commit`]: Self::commit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Reservation<T> {
+    value: T,
+    len: usize,
+}
+
+impl<T: WriteReserved> Reservation<T> {
+    /// Calculate the number of bytes needed to write the number.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            len: value.formatted_len(),
+        }
+    }
+
+    /// Get the number to write.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Get the number of bytes needed to write the number.
+    #[inline]
+    pub fn formatted_len(&self) -> usize {
+        self.len
+    }
+
+    /// Write the number to the start of the buffer, returning the written bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than [`formatted_len`].
+    ///
+    /// [`formatted_len`]: Self::formatted_len
+    #[inline]
+    pub fn commit(self, bytes: &mut [u8]) -> &mut [u8] {
+        assert!(bytes.len() >= self.len, "Buffer is too small: may overwrite buffer, panicking!");
+        // SAFETY: safe since the buffer can hold `formatted_len` elements.
+        unsafe { self.commit_unchecked(bytes) }
+    }
+
+    /// Write the number to the start of the buffer, returning the written bytes.
+    ///
+    /// Returns `None` if the buffer is shorter than [`formatted_len`].
+    ///
+    /// [`formatted_len`]: Self::formatted_len
+    #[inline]
+    pub fn try_commit(self, bytes: &mut [u8]) -> Option<&mut [u8]> {
+        if bytes.len() >= self.len {
+            // SAFETY: safe since the buffer can hold `formatted_len` elements.
+            Some(unsafe { self.commit_unchecked(bytes) })
+        } else {
+            None
+        }
+    }
+
+    /// Write the number to the start of the buffer, returning the written bytes.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the buffer can hold [`formatted_len`] elements.
+    ///
+    /// [`formatted_len`]: Self::formatted_len
+    #[inline]
+    pub unsafe fn commit_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+        debug_assert!(bytes.len() >= self.len);
+        // SAFETY: safe as long as the buffer can hold `formatted_len` elements.
+        unsafe {
+            let bytes = &mut index_unchecked_mut!(bytes[..self.len]);
+            let count = self.value.write_reserved_unchecked(bytes);
+            debug_assert_eq!(count, self.len);
+            bytes
+        }
+    }
+}
@@ -90,6 +90,20 @@ fn i16_test() {
     assert_eq!(b"-1", (-1i16).to_lexical(&mut buffer));
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn small_exhaustive_test() {
+    let mut buffer = [b'\x00'; 16];
+    for i in i8::MIN..=i8::MAX {
+        assert_eq!(i.to_string().as_bytes(), i.to_lexical(&mut buffer));
+        assert_eq!((i as u8).to_string().as_bytes(), (i as u8).to_lexical(&mut buffer));
+    }
+    for i in i16::MIN..=i16::MAX {
+        assert_eq!(i.to_string().as_bytes(), i.to_lexical(&mut buffer));
+        assert_eq!((i as u16).to_string().as_bytes(), (i as u16).to_lexical(&mut buffer));
+    }
+}
+
 #[test]
 fn u32_test() {
     let mut buffer = [b'\x00'; 16];
@@ -217,6 +231,30 @@ fn options_radix_test() {
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
 }
 
+#[test]
+#[cfg(feature = "radix")]
+fn extended_radix_test() {
+    const BASE37: u128 = from_radix(37);
+    const BASE40: u128 = from_radix(40);
+    const BASE62: u128 = from_radix(62);
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    assert_eq!(b"6X", 255u8.to_lexical_with_options::<{ BASE37 }>(&mut buffer, &options));
+    assert_eq!(b"11bSYMF", u32::MAX.to_lexical_with_options::<{ BASE40 }>(&mut buffer, &options));
+    assert_eq!(b"0", 0u32.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"z", 61u32.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"10", 62u32.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"-2LKcb2", i32::MIN.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(
+        b"LygHa16AHYF",
+        u64::MAX.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"7n42DGM5Tflk9n8mt7Fhc7",
+        u128::MAX.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options)
+    );
+}
+
 fn roundtrip<T>(x: T) -> T
 where
     T: Roundtrip,
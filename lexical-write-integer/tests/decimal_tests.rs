@@ -45,6 +45,19 @@ fn u32_digit_count_test() {
     assert_eq!(u32::digit_count(u32::MAX), 10);
 }
 
+#[test]
+fn narrow_digit_count_test() {
+    assert_eq!(decimal::narrow_digit_count(0), 1);
+    assert_eq!(decimal::narrow_digit_count(u32::MAX), 10);
+    let mut power = 1u32;
+    for _ in 0..9 {
+        power *= 10;
+        for &x in [power - 1, power, power + 1].iter() {
+            assert_eq!(decimal::narrow_digit_count(x), decimal::fast_digit_count(x));
+        }
+    }
+}
+
 #[test]
 fn u64_digit_count_test() {
     assert_eq!(u64::digit_count(0), 1);
@@ -66,6 +79,50 @@ fn u128_digit_count_test() {
     assert_eq!(u128::digit_count(u128::MAX), 39);
 }
 
+#[test]
+fn u8_digit_count_test() {
+    assert_eq!(u8::digit_count(0), 1);
+    assert_eq!(u8::digit_count(9), 1);
+    assert_eq!(u8::digit_count(10), 2);
+    assert_eq!(u8::digit_count(99), 2);
+    assert_eq!(u8::digit_count(100), 3);
+    assert_eq!(u8::digit_count(u8::MAX), 3);
+}
+
+#[test]
+fn u16_digit_count_test() {
+    assert_eq!(u16::digit_count(0), 1);
+    assert_eq!(u16::digit_count(999), 3);
+    assert_eq!(u16::digit_count(1000), 4);
+    assert_eq!(u16::digit_count(9999), 4);
+    assert_eq!(u16::digit_count(10000), 5);
+    assert_eq!(u16::digit_count(u16::MAX), 5);
+}
+
+#[test]
+fn u8toa_test() {
+    // Exhaustively check every value, since there are so few.
+    let mut buffer = [b'\x00'; 16];
+    for x in 0..=u8::MAX {
+        let actual = x.to_string();
+        assert_eq!(unsafe { x.decimal(&mut buffer) }, actual.len());
+        assert_eq!(&buffer[..actual.len()], actual.as_bytes());
+        assert_eq!(x.digit_count(), actual.len());
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn u16toa_test() {
+    let mut buffer = [b'\x00'; 16];
+    for x in 0..=u16::MAX {
+        let actual = x.to_string();
+        assert_eq!(unsafe { x.decimal(&mut buffer) }, actual.len());
+        assert_eq!(&buffer[..actual.len()], actual.as_bytes());
+        assert_eq!(x.digit_count(), actual.len());
+    }
+}
+
 #[test]
 fn u32toa_test() {
     let mut buffer = [b'\x00'; 16];
@@ -0,0 +1,55 @@
+use lexical_util::radix::Radix;
+use lexical_write_integer::display::RadixDisplay;
+use proptest::prelude::*;
+
+const BASE_36: Radix = match Radix::new(36) {
+    Some(radix) => radix,
+    None => Radix::DECIMAL,
+};
+
+#[test]
+fn display_test() {
+    assert_eq!(format!("{}", RadixDisplay(0u8, Radix::BINARY)), "0");
+    assert_eq!(format!("{}", RadixDisplay(255u8, Radix::HEXADECIMAL)), "FF");
+    assert_eq!(format!("{}", RadixDisplay(-128i8, Radix::HEXADECIMAL)), "-80");
+    assert_eq!(format!("{}", RadixDisplay(1295u16, BASE_36)), "ZZ");
+    assert_eq!(format!("{}", RadixDisplay(61u32, Radix::MAX)), "z");
+    assert_eq!(format!("{}", RadixDisplay(-1234567isize, Radix::DECIMAL)), "-1234567");
+    assert_eq!(format!("{}", RadixDisplay(u128::MAX, Radix::BINARY)), "1".repeat(128));
+    assert_eq!(
+        format!("{}", RadixDisplay(i128::MIN, Radix::BINARY)),
+        format!("-1{}", "0".repeat(127))
+    );
+    assert_eq!(format!("{}", RadixDisplay(i64::MIN, Radix::OCTAL)), "-1000000000000000000000");
+}
+
+#[test]
+fn display_flags_test() {
+    assert_eq!(format!("{:#}", RadixDisplay(5u8, Radix::BINARY)), "0b101");
+    assert_eq!(format!("{:#}", RadixDisplay(-8i32, Radix::OCTAL)), "-0o10");
+    assert_eq!(format!("{:#}", RadixDisplay(255u32, Radix::HEXADECIMAL)), "0xFF");
+    assert_eq!(format!("{:#}", RadixDisplay(35u32, BASE_36)), "Z");
+    assert_eq!(format!("{:#010}", RadixDisplay(255u32, Radix::HEXADECIMAL)), "0x000000FF");
+    assert_eq!(format!("{:+}", RadixDisplay(3u64, Radix::BINARY)), "+11");
+    assert_eq!(format!("{:<5}|", RadixDisplay(-1i16, Radix::BINARY)), "-1   |");
+    assert_eq!(format!("{:*^7}", RadixDisplay(10u8, Radix::HEXADECIMAL)), "***A***");
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn display_proptest(value in any::<u64>()) {
+        prop_assert_eq!(format!("{}", RadixDisplay(value, Radix::BINARY)), format!("{:b}", value));
+        prop_assert_eq!(format!("{}", RadixDisplay(value, Radix::OCTAL)), format!("{:o}", value));
+        prop_assert_eq!(format!("{}", RadixDisplay(value, Radix::DECIMAL)), format!("{}", value));
+        prop_assert_eq!(format!("{:#}", RadixDisplay(value, Radix::HEXADECIMAL)), format!("{:#X}", value));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn display_signed_proptest(value in any::<i64>(), width in 0usize..30) {
+        let expected = format!("{:+0width$}", value, width = width);
+        let actual = format!("{:+0width$}", RadixDisplay(value, Radix::DECIMAL), width = width);
+        prop_assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,93 @@
+use lexical_write_integer::reserve::{Reservation, WriteReserved};
+use lexical_write_integer::{ToLexical, BUFFER_SIZE};
+use proptest::prelude::*;
+
+/// Check the reservation matches the regular writer.
+fn check<T: WriteReserved + ToLexical>(value: T) {
+    let mut expected = [b'\x00'; BUFFER_SIZE];
+    let expected = value.to_lexical(&mut expected);
+    let reservation = Reservation::new(value);
+    assert_eq!(reservation.formatted_len(), expected.len());
+
+    // Commit into a buffer of exactly the reserved size.
+    let mut actual = vec![b'\x00'; reservation.formatted_len()];
+    assert_eq!(reservation.commit(&mut actual), expected);
+}
+
+#[test]
+fn formatted_len_test() {
+    assert_eq!(0u8.formatted_len(), 1);
+    assert_eq!(255u8.formatted_len(), 3);
+    assert_eq!(9999u16.formatted_len(), 4);
+    assert_eq!(10000u16.formatted_len(), 5);
+    assert_eq!(u32::MAX.formatted_len(), 10);
+    assert_eq!(u64::MAX.formatted_len(), 20);
+    assert_eq!(u128::MAX.formatted_len(), 39);
+    assert_eq!(i8::MIN.formatted_len(), 4);
+    assert_eq!((-1i32).formatted_len(), 2);
+    assert_eq!(i64::MIN.formatted_len(), 20);
+    assert_eq!(i128::MIN.formatted_len(), 40);
+    assert_eq!(usize::MAX.formatted_len(), usize::MAX.to_string().len());
+}
+
+#[test]
+fn commit_test() {
+    check(0u8);
+    check(u8::MAX);
+    check(u16::MAX);
+    check(1000000000u32);
+    check(u64::MAX);
+    check(u128::MAX);
+    check(usize::MAX);
+    check(i8::MIN);
+    check(-10000i16);
+    check(i32::MIN);
+    check(i64::MIN);
+    check(i128::MIN);
+    check(isize::MIN);
+    check(0i64);
+}
+
+#[test]
+fn commit_larger_test() {
+    let mut buffer = [b'x'; 8];
+    let reservation = Reservation::new(-42i32);
+    assert_eq!(reservation.value(), -42);
+    assert_eq!(reservation.commit(&mut buffer), b"-42");
+    assert_eq!(&buffer, b"-42xxxxx");
+}
+
+#[test]
+fn try_commit_test() {
+    let mut buffer = [b'x'; 4];
+    assert_eq!(Reservation::new(12345u32).try_commit(&mut buffer), None);
+    assert_eq!(&buffer, b"xxxx");
+    assert_eq!(Reservation::new(1234u32).try_commit(&mut buffer).map(|x| &*x), Some(&b"1234"[..]));
+}
+
+#[test]
+#[should_panic]
+fn commit_panic_test() {
+    let mut buffer = [b'\x00'; 2];
+    Reservation::new(100u8).commit(&mut buffer);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u64_proptest(value in any::<u64>()) {
+        let reservation = Reservation::new(value);
+        let mut buffer = vec![b'\x00'; reservation.formatted_len()];
+        let expected = value.to_string();
+        prop_assert_eq!(reservation.commit(&mut buffer), expected.as_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i128_proptest(value in any::<i128>()) {
+        let reservation = Reservation::new(value);
+        let mut buffer = vec![b'\x00'; reservation.formatted_len()];
+        let expected = value.to_string();
+        prop_assert_eq!(reservation.commit(&mut buffer), expected.as_bytes());
+    }
+}
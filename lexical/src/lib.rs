@@ -41,6 +41,17 @@
 #![cfg_attr(feature = "write", doc = "")]
 #![cfg_attr(feature = "write", doc = " - [`to_string`]")]
 #![cfg_attr(feature = "write", doc = " - [`to_string_with_options`]")]
+#![cfg_attr(all(feature = "posit", feature = "write-floats"), doc = " - [`to_string_posit`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`to_string_custom_float`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`to_string_double_double`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`to_string_x87`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`to_string_printf`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`to_string_printf_hex`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_width`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_width_with_options`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`write_reserve`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`RadixDisplay`]")]
+#![cfg_attr(feature = "write-integers", doc = " - [`Reservation`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -48,6 +59,52 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_complete`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_complete_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_carry`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_then`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_skipping`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_skipping_with_options`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_magnitude`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`DigitAccumulator`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`parse_power_of_ten`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`scale_by_pow10`]")]
+#![cfg_attr(
+    all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")),
+    doc = " - [`parse_prefixed`]"
+)]
+#![cfg_attr(
+    all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")),
+    doc = " - [`parse_prefixed_partial`]"
+)]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_rounded`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_rounded_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_significant`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_significant_with_options`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_zeroizing`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_zeroizing_with_options`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_partial_zeroizing`]")]
+#![cfg_attr(feature = "zeroize", doc = " - [`parse_partial_zeroizing_with_options`]")]
+#![cfg_attr(feature = "varint", doc = " - [`parse_varint`]")]
+#![cfg_attr(feature = "varint", doc = " - [`parse_varint_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_number`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_number_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_float_parts`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_float_parts_with_options`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_unicode`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_partial_unicode`]")]
+#![cfg_attr(all(feature = "parse", feature = "unicode"), doc = " - [`parse_unicode_with_options`]")]
+#![cfg_attr(
+    all(feature = "parse", feature = "unicode"),
+    doc = " - [`parse_partial_unicode_with_options`]"
+)]
+#![cfg_attr(all(feature = "posit", feature = "parse-floats"), doc = " - [`parse_posit`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_custom_float`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_double_double`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_x87`]")]
 //!
 //! # Features
 //!
@@ -136,6 +193,10 @@
 //! for esoteric programming languages which use duodecimal floats, for
 //! example.
 //!
+//! Integers may also use radixes from 37 to 62, with the extended,
+//! case-sensitive alphabet `0-9A-Za-z`, which is useful for decoding
+//! identifiers like short links. Floats only support radixes up to 36.
+//!
 //! ### compact
 //!
 //! Reduce the generated code size at the cost of performance. This minimizes
@@ -153,6 +214,103 @@
 //! safe feature enabled and disabled, with the tests verified by Miri
 //! and Valgrind.
 //!
+//! ### deterministic
+//!
+//! Only use integer arithmetic when parsing floats, avoiding the native
+//! float fast-path. On targets with only an x87 FPU, native float
+//! arithmetic may use extended-precision intermediates, leading to
+//! double rounding. With this feature, parsed floats are bit-identical
+//! across all targets, which is useful for lockstep simulations, at
+//! a small performance cost for simple floats.
+//!
+//! ### lazy-tables
+//!
+//! Compute the cached powers for non-decimal radixes at runtime, the first
+//! time each radix is used, rather than embedding the tables in the binary.
+//! This trades a one-time initialization cost for a smaller binary, which
+//! is useful for size-sensitive targets like WebAssembly that still need
+//! the `radix` feature. This has no effect without the `radix` feature.
+//!
+//! ### unicode
+//!
+//! Add [`parse_unicode`] and related functions, which accept the
+//! `U+2212 MINUS SIGN` as a minus sign, and non-breaking spaces as digit
+//! separators, which are common in data copy-pasted from documents. A lone
+//! `0xA0` byte is accepted as a Windows-1252 non-breaking space. This
+//! requires the `std` feature, and never changes the behavior of [`parse`].
+//!
+//! ### posit
+//!
+//! Add support for 16-bit and 32-bit posits, or type III unums, which
+//! are an alternative to IEEE-754 floats with tapered precision. Parsing
+//! is correctly rounded, and writing uses the shortest digits that parse
+//! back to the same posit. This is meant for research and experimentation.
+//!
+//! ### serde
+//!
+//! Implement `Serialize` and `Deserialize` for `ParseFloatOptions` and
+//! `WriteFloatOptions`, so number dialects can be loaded from configuration
+//! files, and collected by name in a `Registry`. Every field is optional,
+//! and overrides the options of a named `preset`, such as `"JSON"`.
+//!
+//! ### hooks
+//!
+//! Add the `parse_float_hooks` module, to set a global callback invoked
+//! with the algorithm used to parse each float and the number of digits,
+//! such as to export latency histograms by path.
+//!
+//! ### float160
+//!
+//! Parse floats with more than 19 significant digits using 160-bit extended
+//! floats when the `lossy` option is set, which is almost always correctly
+//! rounded, and much faster than parsing without `lossy`.
+//!
+//! ### zeroize
+//!
+//! Add [`parse_zeroizing`] and the related functions, which clear the big
+//! integers used for the digits in the slow path before returning, for
+//! parsing secrets without leaving their digits in memory.
+//!
+//! ### bcd
+//!
+//! Add the `bcd` module, to convert decimal strings to and from big-endian
+//! BCD and packed decimal fields, or COBOL `COMP-3`, for mainframe records.
+//!
+//! ### varint
+//!
+//! Add the `varint` module, with a canonical binary encoding of floats,
+//! and [`parse_varint`], for caching parse results between pipeline stages.
+//!
+//! ### fuzz
+//!
+//! Promote the debug assertions for internal invariants to checks, so
+//! they are verified in optimized builds. This is meant for fuzzing, and
+//! has a performance cost, so it should not be enabled otherwise.
+//!
+//! ### panic-free
+//!
+//! Guarantee the parsers and the checked writers, such as `lexical_core::try_write`,
+//! never panic, which is verified by tests that fail to link if any
+//! panic is reachable. This is useful for FFI and kernels, where
+//! unwinding is undefined behavior or unavailable. The `safe` and `fuzz`
+//! features intentionally add panicking checks, so the guarantee does
+//! not hold with them: check [`Capabilities::PANIC_FREE`] to confirm it
+//! at runtime.
+//!
+//! [`Capabilities::PANIC_FREE`]: lexical_core::Capabilities::PANIC_FREE
+//!
+//! ### minimal
+//!
+//! Restrict the build to a minimal profile, for size-constrained firmware
+//! without the standard library. This enables `compact`, and [`MINIMAL`]
+//! is only `true` if no other crate in the build enables a feature that
+//! adds tables or code paths, such as `radix` or `format`, so size budgets
+//! can be enforced at compile time (this requires Rust 1.57 or later):
+//!
+//! ```rust,ignore
+//! const _: () = assert!(lexical::MINIMAL);
+//! ```
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -200,6 +358,7 @@
 #![cfg_attr(feature = "parse-integers", doc = " - [`ParseIntegerOptions`]")]
 #![cfg_attr(feature = "write-floats", doc = " - [`WriteFloatOptions`]")]
 #![cfg_attr(feature = "write-integers", doc = " - [`WriteIntegerOptions`]")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " - [`BoolOptions`]")]
 //!
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules.
@@ -257,12 +416,57 @@
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
+//! [`parse_complete`]: crate::parse_complete
+//! [`parse_complete_with_options`]: crate::parse_complete_with_options
+//! [`parse_until`]: crate::parse_until
+//! [`parse_until_with_options`]: crate::parse_until_with_options
+//! [`parse_partial_carry`]: crate::parse_partial_carry
+//! [`parse_in_range`]: crate::parse_in_range
+//! [`parse_then`]: crate::parse_then
+//! [`parse_skipping`]: crate::parse_skipping
+//! [`parse_skipping_with_options`]: crate::parse_skipping_with_options
+//! [`parse_magnitude`]: crate::parse_magnitude
+//! [`DigitAccumulator`]: crate::DigitAccumulator
+//! [`parse_power_of_ten`]: crate::parse_power_of_ten
+//! [`scale_by_pow10`]: crate::scale_by_pow10
+//! [`parse_prefixed`]: crate::parse_prefixed
+//! [`parse_prefixed_partial`]: crate::parse_prefixed_partial
+//! [`parse_rounded`]: crate::parse_rounded
+//! [`parse_rounded_with_options`]: crate::parse_rounded_with_options
+//! [`parse_significant`]: crate::parse_significant
+//! [`parse_significant_with_options`]: crate::parse_significant_with_options
+//! [`parse_zeroizing`]: crate::parse_zeroizing
+//! [`parse_zeroizing_with_options`]: crate::parse_zeroizing_with_options
+//! [`parse_partial_zeroizing`]: crate::parse_partial_zeroizing
+//! [`parse_partial_zeroizing_with_options`]: crate::parse_partial_zeroizing_with_options
+//! [`parse_varint`]: crate::parse_varint
+//! [`parse_varint_with_options`]: crate::parse_varint_with_options
+//! [`parse_number`]: crate::parse_number
+//! [`parse_number_with_options`]: crate::parse_number_with_options
+//! [`parse_float_parts`]: crate::parse_float_parts
+//! [`parse_float_parts_with_options`]: crate::parse_float_parts_with_options
+//! [`to_string_posit`]: crate::to_string_posit
+//! [`parse_posit`]: crate::parse_posit
+//! [`to_string_custom_float`]: crate::to_string_custom_float
+//! [`to_string_double_double`]: crate::to_string_double_double
+//! [`to_string_x87`]: crate::to_string_x87
+//! [`to_string_printf`]: crate::to_string_printf
+//! [`to_string_printf_hex`]: crate::to_string_printf_hex
+//! [`write_width`]: crate::write_width
+//! [`write_width_with_options`]: crate::write_width_with_options
+//! [`write_reserve`]: crate::write_reserve
+//! [`RadixDisplay`]: crate::RadixDisplay
+//! [`Reservation`]: crate::Reservation
+//! [`parse_custom_float`]: crate::parse_custom_float
+//! [`parse_double_double`]: crate::parse_double_double
+//! [`parse_x87`]: crate::parse_x87
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
 //! [`ParseIntegerOptions`]: crate::ParseIntegerOptions
 //! [`WriteFloatOptions`]: crate::WriteFloatOptions
 //! [`WriteIntegerOptions`]: crate::WriteIntegerOptions
+//! [`BoolOptions`]: crate::BoolOptions
 
 // We want to have the same safety guarantees as Rust core,
 // so we allow unused unsafe to clearly document safety guarantees.
@@ -283,29 +487,127 @@ use std::string::String;
 #[cfg(all(feature = "write", feature = "std"))]
 use std::vec::Vec;
 
+#[cfg(feature = "bcd")]
+pub use lexical_core::bcd;
 pub use lexical_core::format::{self, format_error, format_is_valid, NumberFormatBuilder};
+pub use lexical_core::log;
+#[cfg(feature = "hooks")]
+pub use lexical_core::parse_float_hooks;
+#[cfg(feature = "parse-integers")]
+pub use lexical_core::parse_integer_valued_float;
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::scale_by_pow10;
+#[cfg(feature = "varint")]
+pub use lexical_core::varint;
 #[cfg(feature = "parse")]
-pub use lexical_core::Error;
+pub use lexical_core::Delimiters;
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_core::DigitCount;
+#[cfg(feature = "floats")]
+pub use lexical_core::LexicalFloat;
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::Numbers;
 #[cfg(feature = "parse")]
 pub use lexical_core::ParseOptions;
 #[cfg(feature = "parse")]
+pub use lexical_core::Parser;
+#[cfg(feature = "write-integers")]
+pub use lexical_core::RadixDisplay;
+#[cfg(feature = "std")]
+pub use lexical_core::Registry;
+#[cfg(feature = "parse")]
 pub use lexical_core::Result;
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+pub use lexical_core::Sign;
 #[cfg(feature = "write")]
 pub use lexical_core::WriteOptions;
 #[cfg(feature = "f16")]
 pub use lexical_core::{bf16, f16};
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_core::{bool_options, BoolOptions, BoolOptionsBuilder};
+pub use lexical_core::{capabilities, Capabilities, Radix, MINIMAL};
+#[cfg(feature = "parse")]
+pub use lexical_core::{char_to_digit, count_digits, is_all_digits};
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_core::{
+    checked_mul_pow10,
+    checked_pow,
+    checked_pow10,
+    div_rem_1e19,
+    div_rem_1e8,
+    Pow10,
+    POW10_U128,
+    POW10_U32,
+    POW10_U64,
+};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{
+    cmp_numeric,
+    cmp_numeric_with_options,
+    hash_numeric,
+    hash_numeric_with_options,
+    normalize,
+    normalize_with_options,
+    quantize,
+    quantize_with_options,
+    Rounding,
+};
+#[cfg(feature = "write")]
+pub use lexical_core::{digit_to_char, DigitCase};
 #[cfg(feature = "parse-floats")]
 pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_float_parts, parse_float_parts_with_options};
 #[cfg(feature = "parse-integers")]
 pub use lexical_core::{parse_integer_options, ParseIntegerOptions, ParseIntegerOptionsBuilder};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_number, parse_number_with_options, Number};
+#[cfg(feature = "zeroize")]
+pub use lexical_core::{
+    parse_partial_zeroizing,
+    parse_partial_zeroizing_with_options,
+    parse_zeroizing,
+    parse_zeroizing_with_options,
+};
+#[cfg(all(feature = "parse-floats", any(feature = "power-of-two", feature = "radix")))]
+pub use lexical_core::{parse_prefixed, parse_prefixed_partial};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{
+    parse_rounded,
+    parse_rounded_with_options,
+    parse_significant,
+    parse_significant_with_options,
+};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_sign_special, parse_sign_special_with_options, Special};
+#[cfg(feature = "varint")]
+pub use lexical_core::{parse_varint, parse_varint_with_options};
 #[cfg(feature = "write-floats")]
 pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptionsBuilder};
 #[cfg(feature = "write-integers")]
 pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
+#[cfg(feature = "write-integers")]
+pub use lexical_core::{write_reserve, Reservation, WriteReserved};
+#[cfg(feature = "write-floats")]
+pub use lexical_core::{write_width, write_width_with_options};
+#[cfg(feature = "parse-integers")]
+pub use lexical_core::{Accumulation, DigitAccumulator};
+#[cfg(feature = "parse")]
+pub use lexical_core::{Carry, CARRY_SIZE};
+#[cfg(feature = "floats")]
+pub use lexical_core::{Decimal128, Decimal64};
+#[cfg(feature = "parse")]
+pub use lexical_core::{Error, ErrorCode};
 #[cfg(feature = "write")]
 pub use lexical_core::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "parse")]
 pub use lexical_core::{FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "write-floats")]
+pub use lexical_core::{HexSignificand, PrintfStyle};
+#[cfg(feature = "parse")]
+pub use lexical_core::{Matches, NumberMatcher};
+#[cfg(feature = "posit")]
+pub use lexical_core::{Posit, Posit16, Posit32};
 #[cfg(feature = "write")]
 pub use lexical_core::{ToLexical, ToLexicalWithOptions};
 
@@ -390,6 +692,189 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     }
 }
 
+/// High-level conversion of a posit to a decimal string.
+///
+/// The posit is written with the fewest significant digits that parse
+/// back to the same posit. Zero is written as `0.0`, and NaR as `NaN`.
+///
+/// * `n`       - Posit to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "write-floats"))] {
+/// use lexical::Posit16;
+///
+/// assert_eq!(lexical::to_string_posit(Posit16::from_bits(0x4001)), "1.0005");
+/// assert_eq!(lexical::to_string_posit(Posit16::NAR), "NaN");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "write-floats"))]
+pub fn to_string_posit<P: Posit>(n: P) -> String {
+    let mut buf = [0u8; BUFFER_SIZE];
+    let bytes = lexical_core::write_posit(n, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
+/// High-level conversion of a custom float to a decimal string.
+///
+/// The float is written with the fewest significant digits that parse
+/// back to the same value, for third-party float types implementing
+/// [`LexicalFloat`].
+///
+/// * `n`       - Float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// assert_eq!(lexical::to_string_custom_float(0.1f32), "0.1");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn to_string_custom_float<F: LexicalFloat>(n: F) -> String {
+    let mut buf = [0u8; BUFFER_SIZE];
+    let bytes = lexical_core::write_custom_float(n, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
+/// High-level conversion of a double-double number to a decimal string.
+///
+/// The pair `hi + lo` is written with the fewest significant digits that
+/// parse back to the same pair with [`parse_double_double`].
+///
+/// * `hi`      - High part of the number to convert to string.
+/// * `lo`      - Low part of the number to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// assert_eq!(lexical::to_string_double_double(0.1, -5.551115123125783e-18), "0.1");
+/// assert_eq!(lexical::to_string_double_double(0.1, 0.0), "0.1000000000000000055511151231257827021181583404541015625");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn to_string_double_double(hi: f64, lo: f64) -> String {
+    let mut buf = [0u8; lexical_core::DOUBLE_DOUBLE_BUFFER_SIZE];
+    let bytes = lexical_core::write_double_double(hi, lo, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
+/// High-level conversion of an x87 extended float to a decimal string.
+///
+/// The float is given as the raw sign and biased exponent, and the
+/// significand, and is written with the fewest significant digits that
+/// parse back to the same float with [`parse_x87`].
+///
+/// * `sign_exponent`   - Sign bit and 15-bit biased exponent.
+/// * `mant`            - Significand, with the explicit integer bit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// assert_eq!(lexical::to_string_x87(0x3FFB, 0xCCCCCCCCCCCCCCCD), "0.1");
+/// assert_eq!(lexical::to_string_x87(0x7FFE, 0xFFFFFFFFFFFFFFFF), "1.189731495357231765e4932");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn to_string_x87(sign_exponent: u16, mant: u64) -> String {
+    let mut buf = [0u8; lexical_core::X87_BUFFER_SIZE];
+    let bytes = lexical_core::write_x87(sign_exponent, mant, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
+/// High-level conversion of a float to a string, like the `%g` conversion of C's `printf`.
+///
+/// This writes the same string as `printf("%.*g", precision, value)`
+/// with the C runtime of the style, such as glibc or MSVC.
+///
+/// * `value`       - Float to convert to string.
+/// * `precision`   - Number of significant digits, as in `%.17g`.
+/// * `style`       - C runtime to replicate the output of.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical::PrintfStyle;
+///
+/// assert_eq!(lexical::to_string_printf(0.1, 17, PrintfStyle::Glibc), "0.10000000000000001");
+/// assert_eq!(lexical::to_string_printf(1e20, 17, PrintfStyle::Glibc), "1e+20");
+/// assert_eq!(lexical::to_string_printf(1e20, 17, PrintfStyle::Msvc), "1e+020");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn to_string_printf(value: f64, precision: usize, style: PrintfStyle) -> String {
+    let mut buf = [0u8; lexical_core::PRINTF_BUFFER_SIZE];
+    let bytes = lexical_core::write_printf(value, precision, style, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
+/// High-level conversion of a float to a string, like the `%a` conversion of C's `printf`.
+///
+/// This writes the same hexadecimal float as `printf("%.*a", precision, value)`,
+/// with the significand of subnormal floats written like glibc or musl.
+///
+/// * `value`       - Float to convert to string.
+/// * `precision`   - Number of hex digits after the point, or `None`.
+/// * `significand` - Whether to normalize the significand of subnormals.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical::HexSignificand;
+///
+/// let glibc = HexSignificand::Denormalized;
+/// assert_eq!(lexical::to_string_printf_hex(3.0, None, glibc), "0x1.8p+1");
+/// assert_eq!(lexical::to_string_printf_hex(3.0, Some(13), glibc), "0x1.8000000000000p+1");
+/// let musl = HexSignificand::Normalized;
+/// assert_eq!(lexical::to_string_printf_hex(5e-324, None, musl), "0x1p-1074");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn to_string_printf_hex(
+    value: f64,
+    precision: Option<usize>,
+    significand: HexSignificand,
+) -> String {
+    let mut buf = [0u8; lexical_core::PRINTF_BUFFER_SIZE];
+    let bytes = lexical_core::write_printf_hex(value, precision, significand, &mut buf);
+    // SAFETY: safe since the written bytes are always valid ASCII.
+    unsafe { String::from_utf8_unchecked(bytes.to_vec()) }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
@@ -554,3 +1039,602 @@ pub fn parse_partial_with_options<
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes.as_ref(), options)
 }
+
+/// High-level conversion of bytes to a number, requiring all bytes are consumed.
+///
+/// This functions parses as many digits as possible, like [`parse_partial`],
+/// and returns an error with the index of the first unconsumed byte if
+/// any bytes are left. Errors are identical for integers and floats:
+/// strings that end before the number is complete return an empty
+/// error at the end of the string, and any other invalid byte returns
+/// [`Error::InvalidDigit`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::Error;
+///
+/// assert_eq!(lexical::parse_complete::<i32, _>("-12"), Ok(-12));
+/// assert_eq!(lexical::parse_complete::<f32, _>("infx"), Err(Error::InvalidDigit(3)));
+/// assert_eq!(lexical::parse_complete::<i32, _>("--1"), Err(Error::InvalidDigit(1)));
+/// assert_eq!(lexical::parse_complete::<f32, _>("--1"), Err(Error::InvalidDigit(1)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_complete<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
+    lexical_core::parse_complete(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom options, requiring all bytes are consumed.
+///
+/// This functions parses as many digits as possible, like
+/// [`parse_partial_with_options`], and returns an error with the index
+/// of the first unconsumed byte if any bytes are left, like
+/// [`parse_complete`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::builder()
+///     .exponent(b'^')
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::parse_complete_with_options::<f32, _, FORMAT>("1^2", &options), Ok(100.0));
+/// assert_eq!(
+///     lexical::parse_complete_with_options::<f32, _, FORMAT>("1e2", &options),
+///     Err(lexical::Error::InvalidDigit(1))
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_complete_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<N> {
+    lexical_core::parse_complete_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes to a number, up to a delimiter.
+///
+/// This functions parses as many digits as possible, like [`parse_partial`],
+/// but returns an error if the number is not followed by one of the
+/// delimiters or the end of the input.
+///
+/// * `bytes`       - Byte slice to convert to number.
+/// * `delimiters`  - Set of bytes that may follow the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::Delimiters;
+///
+/// assert_eq!(lexical::parse_until::<i32, _>("5,", &Delimiters::CSV), Ok((5, 1)));
+/// assert_eq!(lexical::parse_until::<i32, _>("5", &Delimiters::CSV), Ok((5, 1)));
+/// assert_eq!(lexical::parse_until::<f32, _>("1.5]", &Delimiters::JSON), Ok((1.5, 3)));
+/// assert_eq!(
+///     lexical::parse_until::<i32, _>("1a", &Delimiters::JSON),
+///     Err(lexical::Error::InvalidDigit(1))
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until<N: FromLexical, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+    delimiters: &Delimiters,
+) -> Result<(N, usize)> {
+    lexical_core::parse_until(bytes.as_ref(), delimiters)
+}
+
+/// High-level, partial conversion of bytes to a number with custom options, up to a delimiter.
+///
+/// This functions parses as many digits as possible, like
+/// [`parse_partial_with_options`], but returns an error if the number
+/// is not followed by one of the delimiters or the end of the input.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Byte slice to convert to number.
+/// * `delimiters`  - Set of bytes that may follow the number.
+/// * `options`     - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// use lexical::Delimiters;
+///
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let delimiters = Delimiters::from_bytes(b";");
+/// assert_eq!(
+///     lexical::parse_until_with_options::<f32, _, FORMAT>("1,5;", &delimiters, &options),
+///     Ok((1.5, 3))
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    delimiters: &Delimiters,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    lexical_core::parse_until_with_options::<N, FORMAT>(bytes.as_ref(), delimiters, options)
+}
+
+/// High-level, partial conversion of bytes to a number, split across a carry and the next buffer.
+///
+/// This functions parses as many digits as possible from the carried
+/// bytes followed by the next buffer, as if they were contiguous, like
+/// [`parse_partial`]. The number of processed bytes is counted from the
+/// start of the carry. See [`lexical_core::parse_partial_carry`] for details.
+///
+/// * `carry`   - Start of the number, from the end of the previous buffer.
+/// * `bytes`   - Byte slice with the rest of the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::Carry;
+///
+/// let mut carry = Carry::new();
+/// carry.extend(b"12").unwrap();
+/// assert_eq!(lexical::parse_partial_carry::<i32, _>(&carry, "34,5"), Ok((1234, 4)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_carry<N: FromLexical, Bytes: AsRef<[u8]>>(
+    carry: &Carry,
+    bytes: Bytes,
+) -> Result<(N, usize)> {
+    lexical_core::parse_partial_carry(carry, bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number, within a range of values.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed, like [`parse`], and the value is within `min`
+/// and `max`, inclusive. Otherwise, it returns [`Error::OutOfRange`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `min`     - Smallest valid value, inclusive.
+/// * `max`     - Largest valid value, inclusive.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_in_range::<u8, _>("42", 1, 100), Ok(42));
+/// assert_eq!(lexical::parse_in_range::<u8, _>("420", 1, 100), Err(lexical::Error::OutOfRange(0)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_in_range<N: FromLexical + PartialOrd, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+    min: N,
+    max: N,
+) -> Result<N> {
+    lexical_core::parse_in_range(bytes.as_ref(), min, max)
+}
+
+/// High-level conversion of bytes to a number, converted to another type.
+///
+/// This function parses the entire string as a `T`, like [`parse`], and
+/// converts the value to a `U` with [`TryFrom`]. Parse errors are
+/// returned unchanged, and conversion failures return
+/// [`Error::ConversionFailed`].
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// [`TryFrom`]: core::convert::TryFrom
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_then::<u64, u16, _>("8080"), Ok(8080));
+/// assert_eq!(lexical::parse_then::<u64, u16, _>("80800"), Err(lexical::Error::ConversionFailed(0)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_then<T: FromLexical, U: core::convert::TryFrom<T>, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+) -> Result<U> {
+    lexical_core::parse_then(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number, skipping padding before and after it.
+///
+/// This function skips the leading and trailing bytes for which `skip`
+/// returns `true`, such as the padding of fixed-format files, and parses
+/// the remaining bytes like [`parse`]. Padding is never skipped within the
+/// number. Error indexes refer to the original bytes.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `skip`    - Predicate for the padding bytes to skip.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let is_padding = |c: u8| c == b'*';
+/// assert_eq!(lexical::parse_skipping::<f32, _>("**1.5*", is_padding), Ok(1.5));
+/// assert_eq!(
+///     lexical::parse_skipping::<i32, _>("*1*5", is_padding),
+///     Err(lexical::Error::InvalidDigit(2))
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_skipping<N: FromLexical, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+    skip: fn(u8) -> bool,
+) -> Result<N> {
+    lexical_core::parse_skipping(bytes.as_ref(), skip)
+}
+
+/// High-level conversion of bytes to a number with custom options, skipping padding around it.
+///
+/// This function skips the leading and trailing bytes for which `skip`
+/// returns `true`, and parses the remaining bytes like
+/// [`parse_with_options`]. Padding is never skipped within the number.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `skip`    - Predicate for the padding bytes to skip.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseIntegerOptions::new();
+/// assert_eq!(
+///     lexical::parse_skipping_with_options::<u8, _, FORMAT>("  42 ", |c| c == b' ', &options),
+///     Ok(42)
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_skipping_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    skip: fn(u8) -> bool,
+    options: &N::Options,
+) -> Result<N> {
+    lexical_core::parse_skipping_with_options::<N, FORMAT>(bytes.as_ref(), skip, options)
+}
+
+/// High-level conversion of bytes to the magnitude and sign of an integer.
+///
+/// This parses as many digits as possible, and returns the magnitude,
+/// the sign, and the number of processed bytes. See
+/// [`lexical_core::parse_magnitude`] for details.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::Sign;
+///
+/// assert_eq!(lexical::parse_magnitude("-5 apples"), Ok((5, Sign::Negative, 2)));
+/// assert_eq!(lexical::parse_magnitude(b"-0"), Ok((0, Sign::Negative, 2)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-integers")]
+pub fn parse_magnitude<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(u64, Sign, usize)> {
+    lexical_core::parse_magnitude(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to the exponent of a power of ten, such as `e-3`.
+///
+/// This parses an optional `1` mantissa, the `e` or `E` exponent
+/// character, and the signed exponent, such as for unit prefixes.
+/// The exponent can be applied with [`scale_by_pow10`], which rounds
+/// only once. See [`lexical_core::parse_power_of_ten`] for details.
+///
+/// * `bytes`   - Byte slice with the power of ten.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let exponent = lexical::parse_power_of_ten("e-6").unwrap();
+/// assert_eq!(exponent, -6);
+/// assert_eq!(lexical::scale_by_pow10(4.7, exponent), 4.7e-6);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-integers")]
+pub fn parse_power_of_ten<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<i32> {
+    lexical_core::parse_power_of_ten(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse`], but accepts the `U+2212 MINUS SIGN` as a minus
+/// sign, and removes non-breaking spaces between digits. Error indexes
+/// refer to the original bytes.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_unicode::<i32, _>("\u{2212}1\u{a0}234"), Ok(-1234));
+/// assert_eq!(lexical::parse_unicode::<f64, _>("\u{2212}1.5"), Ok(-1.5));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_unicode<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
+    lexical_core::parse_unicode(bytes.as_ref())
+}
+
+/// High-level, partial conversion of bytes to a number, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_partial`], but normalizes the bytes like
+/// [`parse_unicode`]. The number of processed bytes refers to the
+/// original bytes.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_partial_unicode::<i32, _>("\u{2212}5 km"), Ok((-5, 4)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_partial_unicode<N: FromLexical, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+) -> Result<(N, usize)> {
+    lexical_core::parse_partial_unicode(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom options, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_unicode`], but uses a custom number format and
+/// options. If the format has a digit separator, non-breaking spaces
+/// are replaced with it, so the format determines where they are valid.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     lexical::parse_unicode_with_options::<f32, _, FORMAT>("\u{2212}1\u{202f}000,5", &options),
+///     Ok(-1000.5)
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_unicode_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<N> {
+    lexical_core::parse_unicode_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes to a number with custom options, accepting exotic minus signs and spaces.
+///
+/// This is like [`parse_partial_unicode`], but uses a custom number
+/// format and options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseIntegerOptions::new();
+/// assert_eq!(
+///     lexical::parse_partial_unicode_with_options::<i32, _, FORMAT>("\u{2212}12;", &options),
+///     Ok((-12, 5))
+/// );
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "unicode"))]
+pub fn parse_partial_unicode_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    lexical_core::parse_partial_unicode_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level conversion of decimal-encoded bytes to the nearest posit.
+///
+/// Ties round to the posit with an even bit pattern, using the exact
+/// value of the string. Non-zero numbers are clamped to the smallest and
+/// largest positive posits, while NaN and infinity are parsed as NaR.
+///
+/// * `bytes`   - Byte slice to convert to a posit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(all(feature = "posit", feature = "parse-floats"))] {
+/// use lexical::Posit16;
+///
+/// assert_eq!(lexical::parse_posit::<Posit16, _>("1.0005"), Ok(Posit16::from_bits(0x4001)));
+/// assert_eq!(lexical::parse_posit::<Posit16, _>("1e100"), Ok(Posit16::MAX));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "posit", feature = "parse-floats"))]
+pub fn parse_posit<P: Posit, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<P> {
+    lexical_core::parse_posit(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to the nearest value of a custom float.
+///
+/// Ties round to even, using the exact value of the string, for
+/// third-party float types implementing [`LexicalFloat`].
+///
+/// * `bytes`   - Byte slice to convert to a float.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical::parse_custom_float::<f32, _>("0.1"), Ok(0.1));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_custom_float<F: LexicalFloat, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<F> {
+    lexical_core::parse_custom_float(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to the nearest double-double number.
+///
+/// Returns the high and low parts of the unevaluated sum `hi + lo`,
+/// where both parts are correctly rounded.
+///
+/// * `bytes`   - Byte slice to convert to a double-double number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical::parse_double_double("0.1"), Ok((0.1, -5.551115123125783e-18)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_double_double<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(f64, f64)> {
+    lexical_core::parse_double_double(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to the nearest x87 extended float.
+///
+/// Returns the raw sign and biased exponent, and the significand with
+/// the explicit integer bit, as stored in memory.
+///
+/// * `bytes`   - Byte slice to convert to an x87 extended float.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical::parse_x87("0.1"), Ok((0x3FFB, 0xCCCCCCCCCCCCCCCD)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_x87<Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(u16, u64)> {
+    lexical_core::parse_x87(bytes.as_ref())
+}
@@ -45,3 +45,15 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(all(feature = "parse", feature = "write"))]
+fn bool_test() {
+    assert_eq!(lexical::to_string(true), "true");
+    assert_eq!(lexical::parse("false"), Ok(false));
+
+    let options = lexical::bool_options::NUMERIC;
+    const FORMAT: u128 = lexical::format::STANDARD;
+    assert_eq!(lexical::to_string_with_options::<_, FORMAT>(false, &options), "0");
+    assert_eq!(lexical::parse_with_options::<bool, _, FORMAT>("1", &options), Ok(true));
+}
@@ -9,6 +9,7 @@ cd "$script_dir"/..
 
 WORKSPACES=(
     "lexical"
+    "lexical-capi"
     "lexical-core"
     "lexical-parse-float"
     "lexical-parse-integer"